@@ -13,7 +13,7 @@ mod helper;
 use boa_engine::{
     builtins::promise::PromiseState,
     context::ContextBuilder,
-    job::{Job, JobExecutor, NativeAsyncJob, PromiseJob},
+    job::{GenericJob, Job, JobExecutor, NativeAsyncJob, PromiseJob},
     module::{Module, SimpleModuleLoader},
     optimizer::OptimizerOptions,
     script::Script,
@@ -454,6 +454,7 @@ fn add_runtime(context: &mut Context) {
 struct Executor {
     promise_jobs: RefCell<VecDeque<PromiseJob>>,
     async_jobs: RefCell<VecDeque<NativeAsyncJob>>,
+    generic_jobs: RefCell<VecDeque<GenericJob>>,
 }
 
 impl JobExecutor for Executor {
@@ -461,13 +462,17 @@ impl JobExecutor for Executor {
         match job {
             Job::PromiseJob(job) => self.promise_jobs.borrow_mut().push_back(job),
             Job::AsyncJob(job) => self.async_jobs.borrow_mut().push_back(job),
+            Job::GenericJob(job) => self.generic_jobs.borrow_mut().push_back(job),
             job => eprintln!("unsupported job type {job:?}"),
         }
     }
 
     fn run_jobs(&self, context: &mut Context) -> JsResult<()> {
         loop {
-            if self.promise_jobs.borrow().is_empty() && self.async_jobs.borrow().is_empty() {
+            if self.promise_jobs.borrow().is_empty()
+                && self.async_jobs.borrow().is_empty()
+                && self.generic_jobs.borrow().is_empty()
+            {
                 return Ok(());
             }
 
@@ -490,6 +495,13 @@ impl JobExecutor for Executor {
                     }
                 }
             }
+
+            let generic_jobs = std::mem::take(&mut *self.generic_jobs.borrow_mut());
+            for job in generic_jobs {
+                if let Err(e) = job.call(context) {
+                    eprintln!("Uncaught {e}");
+                }
+            }
         }
     }
 }
@@ -17,7 +17,7 @@ use crate::{
 };
 use boa_interner::Sym;
 
-use super::ModuleSpecifier;
+use super::{ImportAttribute, ImportPhase, ModuleRequest, ModuleSpecifier};
 
 /// The kind of import in an [`ImportDeclaration`].
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -87,6 +87,10 @@ pub struct ImportDeclaration {
     kind: ImportKind,
     /// Module specifier.
     specifier: ModuleSpecifier,
+    /// Import attributes of the `with` clause.
+    attributes: Box<[ImportAttribute]>,
+    /// See [`ImportPhase`].
+    phase: ImportPhase,
 }
 
 impl ImportDeclaration {
@@ -97,11 +101,15 @@ impl ImportDeclaration {
         default: Option<Identifier>,
         kind: ImportKind,
         specifier: ModuleSpecifier,
+        attributes: Box<[ImportAttribute]>,
+        phase: ImportPhase,
     ) -> Self {
         Self {
             default,
             kind,
             specifier,
+            attributes,
+            phase,
         }
     }
 
@@ -125,6 +133,20 @@ impl ImportDeclaration {
     pub const fn kind(&self) -> &ImportKind {
         &self.kind
     }
+
+    /// Gets the import attributes of the import declaration.
+    #[inline]
+    #[must_use]
+    pub const fn attributes(&self) -> &[ImportAttribute] {
+        &self.attributes
+    }
+
+    /// Gets the phase of the import declaration.
+    #[inline]
+    #[must_use]
+    pub const fn phase(&self) -> ImportPhase {
+        self.phase
+    }
 }
 
 impl VisitWith for ImportDeclaration {
@@ -136,7 +158,11 @@ impl VisitWith for ImportDeclaration {
             visitor.visit_identifier(default)?;
         }
         visitor.visit_import_kind(&self.kind)?;
-        visitor.visit_module_specifier(&self.specifier)
+        visitor.visit_module_specifier(&self.specifier)?;
+        for attribute in &*self.attributes {
+            visitor.visit_import_attribute(attribute)?;
+        }
+        ControlFlow::Continue(())
     }
 
     fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
@@ -147,7 +173,11 @@ impl VisitWith for ImportDeclaration {
             visitor.visit_identifier_mut(default)?;
         }
         visitor.visit_import_kind_mut(&mut self.kind)?;
-        visitor.visit_module_specifier_mut(&mut self.specifier)
+        visitor.visit_module_specifier_mut(&mut self.specifier)?;
+        for attribute in &mut *self.attributes {
+            visitor.visit_import_attribute_mut(attribute)?;
+        }
+        ControlFlow::Continue(())
     }
 }
 
@@ -221,9 +251,9 @@ pub enum ImportName {
 /// [`ImportEntry`][spec] record.
 ///
 /// [spec]: https://tc39.es/ecma262/#table-importentry-record-fields
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct ImportEntry {
-    module_request: Sym,
+    module_request: ModuleRequest,
     import_name: ImportName,
     local_name: Identifier,
 }
@@ -231,7 +261,11 @@ pub struct ImportEntry {
 impl ImportEntry {
     /// Creates a new `ImportEntry`.
     #[must_use]
-    pub const fn new(module_request: Sym, import_name: ImportName, local_name: Identifier) -> Self {
+    pub const fn new(
+        module_request: ModuleRequest,
+        import_name: ImportName,
+        local_name: Identifier,
+    ) -> Self {
         Self {
             module_request,
             import_name,
@@ -241,8 +275,8 @@ impl ImportEntry {
 
     /// Gets the module from where the binding must be imported.
     #[must_use]
-    pub const fn module_request(&self) -> Sym {
-        self.module_request
+    pub const fn module_request(&self) -> &ModuleRequest {
+        &self.module_request
     }
 
     /// Gets the import name of the imported binding.
@@ -156,3 +156,131 @@ impl VisitWith for ModuleSpecifier {
         visitor.visit_sym_mut(&mut self.module)
     }
 }
+
+/// An import attribute, of the form `key: "value"`, inside the `with` clause of an import or
+/// re-export declaration.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AttributeEntry
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub struct ImportAttribute {
+    key: Sym,
+    value: Sym,
+}
+
+impl ImportAttribute {
+    /// Creates a new `ImportAttribute` from its key and its value.
+    #[inline]
+    #[must_use]
+    pub const fn new(key: Sym, value: Sym) -> Self {
+        Self { key, value }
+    }
+
+    /// Gets the key of the import attribute.
+    #[inline]
+    #[must_use]
+    pub const fn key(self) -> Sym {
+        self.key
+    }
+
+    /// Gets the value of the import attribute.
+    #[inline]
+    #[must_use]
+    pub const fn value(self) -> Sym {
+        self.value
+    }
+}
+
+impl VisitWith for ImportAttribute {
+    fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        visitor.visit_sym(&self.key)?;
+        visitor.visit_sym(&self.value)
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        visitor.visit_sym_mut(&mut self.key)?;
+        visitor.visit_sym_mut(&mut self.value)
+    }
+}
+
+/// [`ModuleRequest`][spec] record.
+///
+/// Represents a request to import a module, identified by its specifier, its import attributes
+/// and the phase of the import.
+///
+/// [spec]: https://tc39.es/ecma262/#modulerequest-record
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ModuleRequest {
+    specifier: Sym,
+    attributes: Box<[ImportAttribute]>,
+    phase: ImportPhase,
+}
+
+impl ModuleRequest {
+    /// Creates a new `ModuleRequest` from a module specifier, its import attributes and its phase.
+    ///
+    /// The attributes are sorted by key, which makes two requests with the same specifier and
+    /// the same set of attributes compare equal, as required by [`ModuleRequestsEqual`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ModuleRequestsEqual
+    #[must_use]
+    pub fn new(specifier: Sym, attributes: &[ImportAttribute], phase: ImportPhase) -> Self {
+        let mut attributes = attributes.to_vec();
+        attributes.sort_unstable_by_key(|attribute| attribute.key());
+
+        Self {
+            specifier,
+            attributes: attributes.into_boxed_slice(),
+            phase,
+        }
+    }
+
+    /// Gets the module specifier of the request.
+    #[inline]
+    #[must_use]
+    pub const fn specifier(&self) -> Sym {
+        self.specifier
+    }
+
+    /// Gets the import attributes of the request.
+    #[inline]
+    #[must_use]
+    pub const fn attributes(&self) -> &[ImportAttribute] {
+        &self.attributes
+    }
+
+    /// Gets the phase of the request.
+    #[inline]
+    #[must_use]
+    pub const fn phase(&self) -> ImportPhase {
+        self.phase
+    }
+}
+
+/// The phase in which an imported module is requested.
+///
+/// More information:
+///  - [Import defer proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-defer-import-eval/#sec-modulerequest-record
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ImportPhase {
+    /// The module is evaluated before the importing module (`import * as ns from "mod"`).
+    #[default]
+    Evaluation,
+    /// The evaluation of the module is deferred until one of its exports is first accessed
+    /// (`import defer * as ns from "mod"`).
+    Defer,
+}
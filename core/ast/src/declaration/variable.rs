@@ -101,6 +101,23 @@ pub enum LexicalDeclaration {
     ///
     /// [let]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/let
     Let(VariableList),
+
+    /// A <code>[using]</code> declaration declares block-scoped constants that are disposed,
+    /// by calling their `[Symbol.dispose]()` method, when the block exits.
+    ///
+    /// Every binding must be an identifier with an initializer.
+    ///
+    /// [using]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/using
+    Using(VariableList),
+
+    /// An <code>[await using]</code> declaration declares block-scoped constants that are
+    /// asynchronously disposed, by awaiting their `[Symbol.asyncDispose]()` method, when the
+    /// block exits.
+    ///
+    /// Every binding must be an identifier with an initializer.
+    ///
+    /// [await using]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/await_using
+    AwaitUsing(VariableList),
 }
 
 impl LexicalDeclaration {
@@ -108,14 +125,29 @@ impl LexicalDeclaration {
     #[must_use]
     pub const fn variable_list(&self) -> &VariableList {
         match self {
-            Self::Const(list) | Self::Let(list) => list,
+            Self::Const(list) | Self::Let(list) | Self::Using(list) | Self::AwaitUsing(list) => {
+                list
+            }
         }
     }
 
-    /// Returns `true` if the declaration is a `const` declaration.
+    /// Returns `true` if the declaration declares immutable bindings, which is the case for
+    /// `const`, `using` and `await using` declarations.
     #[must_use]
     pub const fn is_const(&self) -> bool {
-        matches!(self, Self::Const(_))
+        matches!(self, Self::Const(_) | Self::Using(_) | Self::AwaitUsing(_))
+    }
+
+    /// Returns `true` if the declaration is a `using` or `await using` declaration.
+    #[must_use]
+    pub const fn is_using(&self) -> bool {
+        matches!(self, Self::Using(_) | Self::AwaitUsing(_))
+    }
+
+    /// Returns `true` if the declaration is an `await using` declaration.
+    #[must_use]
+    pub const fn is_await_using(&self) -> bool {
+        matches!(self, Self::AwaitUsing(_))
     }
 }
 
@@ -132,6 +164,8 @@ impl ToInternedString for LexicalDeclaration {
             match &self {
                 Self::Let(_) => "let",
                 Self::Const(_) => "const",
+                Self::Using(_) => "using",
+                Self::AwaitUsing(_) => "await using",
             },
             self.variable_list().to_interned_string(interner)
         )
@@ -144,7 +178,9 @@ impl VisitWith for LexicalDeclaration {
         V: Visitor<'a>,
     {
         match self {
-            Self::Const(vars) | Self::Let(vars) => visitor.visit_variable_list(vars),
+            Self::Const(vars) | Self::Let(vars) | Self::Using(vars) | Self::AwaitUsing(vars) => {
+                visitor.visit_variable_list(vars)
+            }
         }
     }
 
@@ -153,7 +189,9 @@ impl VisitWith for LexicalDeclaration {
         V: VisitorMut<'a>,
     {
         match self {
-            Self::Const(vars) | Self::Let(vars) => visitor.visit_variable_list_mut(vars),
+            Self::Const(vars) | Self::Let(vars) | Self::Using(vars) | Self::AwaitUsing(vars) => {
+                visitor.visit_variable_list_mut(vars)
+            }
         }
     }
 }
@@ -197,27 +197,35 @@ impl VisitWith for SuperCall {
 #[derive(Clone, Debug, PartialEq)]
 pub struct ImportCall {
     arg: Box<Expression>,
+    options: Option<Box<Expression>>,
     span: Span,
 }
 
 impl ImportCall {
     /// Creates a new `ImportCall` AST node.
-    pub fn new<A>(arg: A, span: Span) -> Self
+    pub fn new<A>(arg: A, options: Option<Expression>, span: Span) -> Self
     where
         A: Into<Expression>,
     {
         Self {
             arg: Box::new(arg.into()),
+            options: options.map(Box::new),
             span,
         }
     }
 
-    /// Retrieves the single argument of the import call.
+    /// Retrieves the specifier argument of the import call.
     #[must_use]
     pub const fn argument(&self) -> &Expression {
         &self.arg
     }
 
+    /// Retrieves the optional options argument of the import call.
+    #[must_use]
+    pub fn options(&self) -> Option<&Expression> {
+        self.options.as_deref()
+    }
+
     /// Get the [`Span`] of the [`ImportCall`] node.
     #[inline]
     #[must_use]
@@ -229,7 +237,15 @@ impl ImportCall {
 impl ToInternedString for ImportCall {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
-        format!("import({})", self.arg.to_interned_string(interner))
+        if let Some(options) = &self.options {
+            format!(
+                "import({}, {})",
+                self.arg.to_interned_string(interner),
+                options.to_interned_string(interner)
+            )
+        } else {
+            format!("import({})", self.arg.to_interned_string(interner))
+        }
     }
 }
 
@@ -245,13 +261,21 @@ impl VisitWith for ImportCall {
     where
         V: Visitor<'a>,
     {
-        visitor.visit_expression(&self.arg)
+        visitor.visit_expression(&self.arg)?;
+        if let Some(options) = &self.options {
+            visitor.visit_expression(options)?;
+        }
+        ControlFlow::Continue(())
     }
 
     fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
         V: VisitorMut<'a>,
     {
-        visitor.visit_expression_mut(&mut self.arg)
+        visitor.visit_expression_mut(&mut self.arg)?;
+        if let Some(options) = &mut self.options {
+            visitor.visit_expression_mut(options)?;
+        }
+        ControlFlow::Continue(())
     }
 }
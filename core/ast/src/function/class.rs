@@ -1,4 +1,7 @@
-use super::{FormalParameterList, FunctionBody, FunctionExpression};
+use super::{
+    decorator::decorators_to_string, Decorator, FormalParameterList, FunctionBody,
+    FunctionExpression,
+};
 use crate::{
     block_to_string,
     expression::{Expression, Identifier},
@@ -29,6 +32,7 @@ pub struct ClassDeclaration {
     pub(crate) super_ref: Option<Expression>,
     pub(crate) constructor: Option<FunctionExpression>,
     pub(crate) elements: Box<[ClassElement]>,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) name_scope: Scope,
@@ -43,12 +47,14 @@ impl ClassDeclaration {
         super_ref: Option<Expression>,
         constructor: Option<FunctionExpression>,
         elements: Box<[ClassElement]>,
+        decorators: Box<[Decorator]>,
     ) -> Self {
         Self {
             name,
             super_ref,
             constructor,
             elements,
+            decorators,
             name_scope: Scope::default(),
         }
     }
@@ -81,6 +87,13 @@ impl ClassDeclaration {
         &self.elements
     }
 
+    /// Gets the list of decorators applied to the class declaration.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Gets the scope containing the class name binding.
     #[inline]
     #[must_use]
@@ -91,7 +104,8 @@ impl ClassDeclaration {
 
 impl ToIndentedString for ClassDeclaration {
     fn to_indented_string(&self, interner: &Interner, indent_n: usize) -> String {
-        let mut buf = format!("class {}", interner.resolve_expect(self.name.sym()));
+        let mut buf = decorators_to_string(&self.decorators, interner);
+        let _ = write!(buf, "class {}", interner.resolve_expect(self.name.sym()));
         if let Some(super_ref) = self.super_ref.as_ref() {
             let _ = write!(buf, " extends {}", super_ref.to_interned_string(interner));
         }
@@ -122,6 +136,9 @@ impl VisitWith for ClassDeclaration {
     where
         V: Visitor<'a>,
     {
+        for decorator in &*self.decorators {
+            visitor.visit_decorator(decorator)?;
+        }
         visitor.visit_identifier(&self.name)?;
         if let Some(expr) = &self.super_ref {
             visitor.visit_expression(expr)?;
@@ -139,6 +156,9 @@ impl VisitWith for ClassDeclaration {
     where
         V: VisitorMut<'a>,
     {
+        for decorator in &mut *self.decorators {
+            visitor.visit_decorator_mut(decorator)?;
+        }
         visitor.visit_identifier_mut(&mut self.name)?;
         if let Some(expr) = &mut self.super_ref {
             visitor.visit_expression_mut(expr)?;
@@ -175,6 +195,7 @@ pub struct ClassExpression {
     pub(crate) super_ref: Option<Expression>,
     pub(crate) constructor: Option<FunctionExpression>,
     pub(crate) elements: Box<[ClassElement]>,
+    pub(crate) decorators: Box<[Decorator]>,
 
     span: Span,
 
@@ -191,6 +212,7 @@ impl ClassExpression {
         super_ref: Option<Expression>,
         constructor: Option<FunctionExpression>,
         elements: Box<[ClassElement]>,
+        decorators: Box<[Decorator]>,
         has_binding_identifier: bool,
         span: Span,
     ) -> Self {
@@ -204,6 +226,7 @@ impl ClassExpression {
             super_ref,
             constructor,
             elements,
+            decorators,
             span,
             name_scope,
         }
@@ -237,6 +260,13 @@ impl ClassExpression {
         &self.elements
     }
 
+    /// Gets the list of decorators applied to the class expression.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Gets the scope containing the class name binding if it exists.
     #[inline]
     #[must_use]
@@ -254,7 +284,8 @@ impl ClassExpression {
 
 impl ToIndentedString for ClassExpression {
     fn to_indented_string(&self, interner: &Interner, indent_n: usize) -> String {
-        let mut buf = "class".to_string();
+        let mut buf = decorators_to_string(&self.decorators, interner);
+        buf.push_str("class");
         if self.name_scope.is_some() {
             if let Some(name) = self.name {
                 let _ = write!(buf, " {}", interner.resolve_expect(name.sym()));
@@ -296,6 +327,9 @@ impl VisitWith for ClassExpression {
     where
         V: Visitor<'a>,
     {
+        for decorator in &*self.decorators {
+            visitor.visit_decorator(decorator)?;
+        }
         if let Some(ident) = &self.name {
             visitor.visit_identifier(ident)?;
         }
@@ -315,6 +349,9 @@ impl VisitWith for ClassExpression {
     where
         V: VisitorMut<'a>,
     {
+        for decorator in &mut *self.decorators {
+            visitor.visit_decorator_mut(decorator)?;
+        }
         if let Some(ident) = &mut self.name {
             visitor.visit_identifier_mut(ident)?;
         }
@@ -385,7 +422,7 @@ pub enum ClassElement {
     /// A method definition.
     MethodDefinition(ClassMethodDefinition),
 
-    /// A field definition.
+    /// A field definition, or an auto-accessor if the field is marked as such.
     FieldDefinition(ClassFieldDefinition),
 
     /// A static field definition, accessible from the class constructor object
@@ -402,6 +439,24 @@ pub enum ClassElement {
     StaticBlock(StaticBlockBody),
 }
 
+impl ClassElement {
+    /// Gets the decorators applied to the class element.
+    ///
+    /// Static blocks cannot be decorated, so an empty list is returned for them.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        match self {
+            Self::MethodDefinition(method) => method.decorators(),
+            Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => field.decorators(),
+            Self::PrivateFieldDefinition(field) | Self::PrivateStaticFieldDefinition(field) => {
+                field.decorators()
+            }
+            Self::StaticBlock(_) => &[],
+        }
+    }
+}
+
 /// A non-private class element field definition.
 ///
 /// More information:
@@ -414,6 +469,8 @@ pub enum ClassElement {
 pub struct ClassFieldDefinition {
     pub(crate) name: PropertyName,
     pub(crate) initializer: Option<Expression>,
+    pub(crate) is_accessor: bool,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Scope,
@@ -423,10 +480,17 @@ impl ClassFieldDefinition {
     /// Creates a new class field definition.
     #[inline]
     #[must_use]
-    pub fn new(name: PropertyName, initializer: Option<Expression>) -> Self {
+    pub fn new(
+        name: PropertyName,
+        initializer: Option<Expression>,
+        is_accessor: bool,
+        decorators: Box<[Decorator]>,
+    ) -> Self {
         Self {
             name,
             initializer,
+            is_accessor,
+            decorators,
             scope: Scope::default(),
         }
     }
@@ -445,6 +509,20 @@ impl ClassFieldDefinition {
         self.initializer.as_ref()
     }
 
+    /// Returns `true` if the class field definition is an `accessor` auto-accessor.
+    #[inline]
+    #[must_use]
+    pub const fn is_accessor(&self) -> bool {
+        self.is_accessor
+    }
+
+    /// Returns the decorators applied to the class field definition.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Returns the scope of the class field definition.
     #[inline]
     #[must_use]
@@ -465,6 +543,8 @@ impl ClassFieldDefinition {
 pub struct PrivateFieldDefinition {
     pub(crate) name: PrivateName,
     pub(crate) initializer: Option<Expression>,
+    pub(crate) is_accessor: bool,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Scope,
@@ -474,10 +554,17 @@ impl PrivateFieldDefinition {
     /// Creates a new private field definition.
     #[inline]
     #[must_use]
-    pub fn new(name: PrivateName, initializer: Option<Expression>) -> Self {
+    pub fn new(
+        name: PrivateName,
+        initializer: Option<Expression>,
+        is_accessor: bool,
+        decorators: Box<[Decorator]>,
+    ) -> Self {
         Self {
             name,
             initializer,
+            is_accessor,
+            decorators,
             scope: Scope::default(),
         }
     }
@@ -496,6 +583,20 @@ impl PrivateFieldDefinition {
         self.initializer.as_ref()
     }
 
+    /// Returns `true` if the private field definition is an `accessor` auto-accessor.
+    #[inline]
+    #[must_use]
+    pub const fn is_accessor(&self) -> bool {
+        self.is_accessor
+    }
+
+    /// Returns the decorators applied to the private field definition.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Returns the scope of the private field definition.
     #[inline]
     #[must_use]
@@ -507,80 +608,51 @@ impl PrivateFieldDefinition {
 impl ToIndentedString for ClassElement {
     fn to_indented_string(&self, interner: &Interner, indent_n: usize) -> String {
         let indentation = "    ".repeat(indent_n + 1);
-        match self {
-            Self::MethodDefinition(m) => m.to_indented_string(interner, indent_n),
-            Self::FieldDefinition(field) => match &field.initializer {
-                Some(expr) => {
-                    format!(
-                        "{indentation}{} = {};\n",
-                        field.name.to_interned_string(interner),
-                        expr.to_no_indent_string(interner, indent_n + 1)
-                    )
-                }
-                None => {
-                    format!(
-                        "{indentation}{};\n",
-                        field.name.to_interned_string(interner),
-                    )
-                }
-            },
-            Self::StaticFieldDefinition(field) => match &field.initializer {
-                Some(expr) => {
-                    format!(
-                        "{indentation}static {} = {};\n",
-                        field.name.to_interned_string(interner),
-                        expr.to_no_indent_string(interner, indent_n + 1)
-                    )
-                }
-                None => {
-                    format!(
-                        "{indentation}static {};\n",
-                        field.name.to_interned_string(interner),
-                    )
-                }
-            },
-            Self::PrivateFieldDefinition(PrivateFieldDefinition {
-                name, initializer, ..
-            }) => match initializer {
-                Some(expr) => {
-                    format!(
-                        "{indentation}#{} = {};\n",
-                        interner.resolve_expect(name.description()),
-                        expr.to_no_indent_string(interner, indent_n + 1)
-                    )
-                }
-                None => {
-                    format!(
-                        "{indentation}#{};\n",
-                        interner.resolve_expect(name.description()),
-                    )
-                }
-            },
-            Self::PrivateStaticFieldDefinition(PrivateFieldDefinition {
-                name,
-                initializer,
-                ..
-            }) => match initializer {
-                Some(expr) => {
-                    format!(
-                        "{indentation}static #{} = {};\n",
-                        interner.resolve_expect(name.description()),
-                        expr.to_no_indent_string(interner, indent_n + 1)
-                    )
-                }
-                None => {
-                    format!(
-                        "{indentation}static #{};\n",
-                        interner.resolve_expect(name.description()),
-                    )
-                }
-            },
+        let (prefix, decorators, is_accessor, name, initializer) = match self {
+            Self::MethodDefinition(m) => return m.to_indented_string(interner, indent_n),
             Self::StaticBlock(block) => {
-                format!(
+                return format!(
                     "{indentation}static {}\n",
                     block_to_string(&block.body.statements, interner, indent_n + 1)
-                )
+                );
             }
+            Self::FieldDefinition(field) => (
+                "",
+                &field.decorators,
+                field.is_accessor,
+                field.name.to_interned_string(interner),
+                &field.initializer,
+            ),
+            Self::StaticFieldDefinition(field) => (
+                "static ",
+                &field.decorators,
+                field.is_accessor,
+                field.name.to_interned_string(interner),
+                &field.initializer,
+            ),
+            Self::PrivateFieldDefinition(field) => (
+                "",
+                &field.decorators,
+                field.is_accessor,
+                format!("#{}", interner.resolve_expect(field.name.description())),
+                &field.initializer,
+            ),
+            Self::PrivateStaticFieldDefinition(field) => (
+                "static ",
+                &field.decorators,
+                field.is_accessor,
+                format!("#{}", interner.resolve_expect(field.name.description())),
+                &field.initializer,
+            ),
+        };
+        let decorators = decorators_to_string(decorators, interner);
+        let accessor = if is_accessor { "accessor " } else { "" };
+        match initializer {
+            Some(expr) => format!(
+                "{indentation}{decorators}{prefix}{accessor}{name} = {};\n",
+                expr.to_no_indent_string(interner, indent_n + 1)
+            ),
+            None => format!("{indentation}{decorators}{prefix}{accessor}{name};\n"),
         }
     }
 }
@@ -592,6 +664,9 @@ impl VisitWith for ClassElement {
     {
         match self {
             Self::MethodDefinition(m) => {
+                for decorator in &*m.decorators {
+                    visitor.visit_decorator(decorator)?;
+                }
                 match &m.name {
                     ClassElementName::PropertyName(pn) => {
                         visitor.visit_property_name(pn)?;
@@ -604,6 +679,9 @@ impl VisitWith for ClassElement {
                 visitor.visit_function_body(&m.body)
             }
             Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => {
+                for decorator in &*field.decorators {
+                    visitor.visit_decorator(decorator)?;
+                }
                 visitor.visit_property_name(&field.name)?;
                 if let Some(expr) = &field.initializer {
                     visitor.visit_expression(expr)
@@ -612,13 +690,20 @@ impl VisitWith for ClassElement {
                 }
             }
             Self::PrivateFieldDefinition(PrivateFieldDefinition {
-                name, initializer, ..
+                name,
+                initializer,
+                decorators,
+                ..
             })
             | Self::PrivateStaticFieldDefinition(PrivateFieldDefinition {
                 name,
                 initializer,
+                decorators,
                 ..
             }) => {
+                for decorator in &**decorators {
+                    visitor.visit_decorator(decorator)?;
+                }
                 visitor.visit_private_name(name)?;
                 if let Some(expr) = initializer {
                     visitor.visit_expression(expr)
@@ -636,6 +721,9 @@ impl VisitWith for ClassElement {
     {
         match self {
             Self::MethodDefinition(m) => {
+                for decorator in &mut *m.decorators {
+                    visitor.visit_decorator_mut(decorator)?;
+                }
                 match m.name {
                     ClassElementName::PropertyName(ref mut pn) => {
                         visitor.visit_property_name_mut(pn)?;
@@ -648,6 +736,9 @@ impl VisitWith for ClassElement {
                 visitor.visit_function_body_mut(&mut m.body)
             }
             Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => {
+                for decorator in &mut *field.decorators {
+                    visitor.visit_decorator_mut(decorator)?;
+                }
                 visitor.visit_property_name_mut(&mut field.name)?;
                 if let Some(expr) = &mut field.initializer {
                     visitor.visit_expression_mut(expr)
@@ -656,13 +747,20 @@ impl VisitWith for ClassElement {
                 }
             }
             Self::PrivateFieldDefinition(PrivateFieldDefinition {
-                name, initializer, ..
+                name,
+                initializer,
+                decorators,
+                ..
             })
             | Self::PrivateStaticFieldDefinition(PrivateFieldDefinition {
                 name,
                 initializer,
+                decorators,
                 ..
             }) => {
+                for decorator in &mut **decorators {
+                    visitor.visit_decorator_mut(decorator)?;
+                }
                 visitor.visit_private_name_mut(name)?;
                 if let Some(expr) = initializer {
                     visitor.visit_expression_mut(expr)
@@ -694,6 +792,7 @@ pub struct ClassMethodDefinition {
     pub(crate) contains_direct_eval: bool,
     kind: MethodDefinitionKind,
     is_static: bool,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
@@ -710,6 +809,7 @@ impl ClassMethodDefinition {
         body: FunctionBody,
         kind: MethodDefinitionKind,
         is_static: bool,
+        decorators: Box<[Decorator]>,
         start_linear_pos: LinearPosition,
     ) -> Self {
         let contains_direct_eval = contains(&parameters, ContainsSymbol::DirectEval)
@@ -724,6 +824,7 @@ impl ClassMethodDefinition {
             contains_direct_eval,
             kind,
             is_static,
+            decorators,
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
         }
@@ -771,6 +872,13 @@ impl ClassMethodDefinition {
         self.name.is_private()
     }
 
+    /// Returns the decorators applied to the class method definition.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Gets the scopes of the class method definition.
     #[inline]
     #[must_use]
@@ -810,10 +918,11 @@ impl ToIndentedString for ClassMethodDefinition {
             (false, MethodDefinitionKind::AsyncGenerator) => "async *",
             (false, MethodDefinitionKind::Async) => "async ",
         };
+        let decorators = decorators_to_string(&self.decorators, interner);
         let name = self.name.to_interned_string(interner);
         let parameters = join_nodes(interner, self.parameters.as_ref());
         let body = block_to_string(&self.body.statements, interner, indent_n + 1);
-        format!("{indentation}{prefix}{name}({parameters}) {body}\n")
+        format!("{indentation}{decorators}{prefix}{name}({parameters}) {body}\n")
     }
 }
 
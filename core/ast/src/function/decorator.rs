@@ -0,0 +1,76 @@
+use crate::{
+    expression::Expression,
+    visitor::{VisitWith, Visitor, VisitorMut},
+};
+use boa_interner::{Interner, ToInternedString};
+use core::ops::ControlFlow;
+
+/// A decorator applied to a class or to one of its elements.
+///
+/// A decorator is a function that is called with the decorated value and a context object
+/// describing it, and may replace the value or add initializers to it.
+///
+/// More information:
+///  - [TC39 proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-decorators/
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Decorator {
+    expression: Expression,
+}
+
+impl Decorator {
+    /// Creates a new `Decorator` AST node.
+    #[inline]
+    #[must_use]
+    pub const fn new(expression: Expression) -> Self {
+        Self { expression }
+    }
+
+    /// Gets the expression that evaluates to the decorator function.
+    #[inline]
+    #[must_use]
+    pub const fn expression(&self) -> &Expression {
+        &self.expression
+    }
+}
+
+impl ToInternedString for Decorator {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        let expression = self.expression.to_interned_string(interner);
+        match self.expression {
+            Expression::Identifier(_)
+            | Expression::PropertyAccess(_)
+            | Expression::Call(_)
+            | Expression::Parenthesized(_) => format!("@{expression}"),
+            _ => format!("@({expression})"),
+        }
+    }
+}
+
+impl VisitWith for Decorator {
+    fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        visitor.visit_expression(&self.expression)
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        visitor.visit_expression_mut(&mut self.expression)
+    }
+}
+
+/// Converts a list of decorators to a string, with each decorator followed by a space.
+pub(crate) fn decorators_to_string(decorators: &[Decorator], interner: &Interner) -> String {
+    decorators.iter().fold(String::new(), |mut buf, decorator| {
+        buf.push_str(&decorator.to_interned_string(interner));
+        buf.push(' ');
+        buf
+    })
+}
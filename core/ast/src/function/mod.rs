@@ -22,12 +22,14 @@
 //! - Classes
 //!   - [`ClassDeclaration`]
 //!   - [`ClassExpression`]
+//!   - [`Decorator`]
 
 mod arrow_function;
 mod async_arrow_function;
 mod async_function;
 mod async_generator;
 mod class;
+mod decorator;
 mod generator;
 mod ordinary_function;
 mod parameters;
@@ -43,6 +45,7 @@ pub use class::{
     ClassDeclaration, ClassElement, ClassElementName, ClassExpression, ClassFieldDefinition,
     ClassMethodDefinition, PrivateFieldDefinition, PrivateName, StaticBlockBody,
 };
+pub use decorator::Decorator;
 pub use generator::{GeneratorDeclaration, GeneratorExpression};
 pub use ordinary_function::{FunctionDeclaration, FunctionExpression};
 pub use parameters::{FormalParameter, FormalParameterList, FormalParameterListFlags};
@@ -8,7 +8,7 @@
 use crate::{
     declaration::{
         ExportDeclaration, ExportEntry, ExportSpecifier, ImportDeclaration, ImportEntry,
-        ImportKind, ImportName, IndirectExportEntry, LocalExportEntry, ModuleSpecifier,
+        ImportKind, ImportName, ImportPhase, IndirectExportEntry, LocalExportEntry, ModuleRequest,
         ReExportImportName, ReExportKind,
     },
     operations::{bound_names, BoundNamesVisitor},
@@ -201,9 +201,11 @@ impl ModuleItemList {
     /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-modulerequests
     #[inline]
     #[must_use]
-    pub fn requests(&self) -> IndexSet<Sym, BuildHasherDefault<FxHasher>> {
+    pub fn requests(&self) -> IndexSet<ModuleRequest, BuildHasherDefault<FxHasher>> {
         #[derive(Debug)]
-        struct RequestsVisitor<'vec>(&'vec mut IndexSet<Sym, BuildHasherDefault<FxHasher>>);
+        struct RequestsVisitor<'vec>(
+            &'vec mut IndexSet<ModuleRequest, BuildHasherDefault<FxHasher>>,
+        );
 
         impl<'ast> Visitor<'ast> for RequestsVisitor<'_> {
             type BreakTy = Infallible;
@@ -214,11 +216,33 @@ impl ModuleItemList {
             ) -> ControlFlow<Self::BreakTy> {
                 ControlFlow::Continue(())
             }
-            fn visit_module_specifier(
+            fn visit_import_declaration(
                 &mut self,
-                node: &'ast ModuleSpecifier,
+                node: &'ast ImportDeclaration,
             ) -> ControlFlow<Self::BreakTy> {
-                self.0.insert(node.sym());
+                self.0.insert(ModuleRequest::new(
+                    node.specifier().sym(),
+                    node.attributes(),
+                    node.phase(),
+                ));
+                ControlFlow::Continue(())
+            }
+            fn visit_export_declaration(
+                &mut self,
+                node: &'ast ExportDeclaration,
+            ) -> ControlFlow<Self::BreakTy> {
+                if let ExportDeclaration::ReExport {
+                    specifier,
+                    attributes,
+                    ..
+                } = node
+                {
+                    self.0.insert(ModuleRequest::new(
+                        specifier.sym(),
+                        attributes,
+                        ImportPhase::Evaluation,
+                    ));
+                }
                 ControlFlow::Continue(())
             }
         }
@@ -257,11 +281,12 @@ impl ModuleItemList {
                 &mut self,
                 node: &'ast ImportDeclaration,
             ) -> ControlFlow<Self::BreakTy> {
-                let module = node.specifier().sym();
+                let module =
+                    ModuleRequest::new(node.specifier().sym(), node.attributes(), node.phase());
 
                 if let Some(default) = node.default() {
                     self.0.push(ImportEntry::new(
-                        module,
+                        module.clone(),
                         ImportName::Name(Sym::DEFAULT),
                         default,
                     ));
@@ -276,7 +301,7 @@ impl ModuleItemList {
                     ImportKind::Named { names } => {
                         for name in &**names {
                             self.0.push(ImportEntry::new(
-                                module,
+                                module.clone(),
                                 ImportName::Name(name.export_name()),
                                 name.binding(),
                             ));
@@ -323,8 +348,16 @@ impl ModuleItemList {
                 node: &'ast ExportDeclaration,
             ) -> ControlFlow<Self::BreakTy> {
                 let name = match node {
-                    ExportDeclaration::ReExport { kind, specifier } => {
-                        let module = specifier.sym();
+                    ExportDeclaration::ReExport {
+                        kind,
+                        specifier,
+                        attributes,
+                    } => {
+                        let module = ModuleRequest::new(
+                            specifier.sym(),
+                            attributes,
+                            ImportPhase::Evaluation,
+                        );
 
                         match kind {
                             ReExportKind::Namespaced { name } => {
@@ -347,7 +380,7 @@ impl ModuleItemList {
                                 for name in &**names {
                                     self.0.push(
                                         IndirectExportEntry::new(
-                                            module,
+                                            module.clone(),
                                             ReExportImportName::Name(name.private_name()),
                                             name.alias(),
                                         )
@@ -208,6 +208,10 @@ where
                         return ControlFlow::Continue(());
                     }
 
+                    for decorator in m.decorators() {
+                        decorator.visit_with(self)?;
+                    }
+
                     if let ClassElementName::PropertyName(name) = m.name() {
                         name.visit_with(self)
                     } else {
@@ -215,8 +219,20 @@ where
                     }
                 }
                 ClassElement::FieldDefinition(field)
-                | ClassElement::StaticFieldDefinition(field) => field.name.visit_with(self),
-                _ => ControlFlow::Continue(()),
+                | ClassElement::StaticFieldDefinition(field) => {
+                    for decorator in field.decorators() {
+                        decorator.visit_with(self)?;
+                    }
+                    field.name.visit_with(self)
+                }
+                ClassElement::PrivateFieldDefinition(field)
+                | ClassElement::PrivateStaticFieldDefinition(field) => {
+                    for decorator in field.decorators() {
+                        decorator.visit_with(self)?;
+                    }
+                    ControlFlow::Continue(())
+                }
+                ClassElement::StaticBlock(_) => ControlFlow::Continue(()),
             }
         }
 
@@ -412,6 +428,9 @@ where
 
         fn visit_class_element(&mut self, node: &'ast ClassElement) -> ControlFlow<Self::BreakTy> {
             if let ClassElement::MethodDefinition(m) = node {
+                for decorator in m.decorators() {
+                    decorator.visit_with(self)?;
+                }
                 if let ClassElementName::PropertyName(name) = m.name() {
                     return name.visit_with(self);
                 }
@@ -1169,6 +1188,10 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         &mut self,
         node: &'ast ClassExpression,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in node.decorators() {
+            self.visit(decorator)?;
+        }
+
         if let Some(node) = node.super_ref() {
             self.visit(node)?;
         }
@@ -1200,6 +1223,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         for element in node.elements() {
             match element {
                 ClassElement::MethodDefinition(m) => {
+                    for decorator in m.decorators() {
+                        visitor.visit(decorator)?;
+                    }
                     if let ClassElementName::PropertyName(name) = m.name() {
                         visitor.visit(name)?;
                     }
@@ -1208,6 +1234,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
                 }
                 ClassElement::FieldDefinition(field)
                 | ClassElement::StaticFieldDefinition(field) => {
+                    for decorator in field.decorators() {
+                        visitor.visit(decorator)?;
+                    }
                     visitor.visit(&field.name)?;
                     if let Some(expression) = &field.initializer {
                         visitor.visit(expression)?;
@@ -1215,12 +1244,17 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
                 }
                 ClassElement::PrivateFieldDefinition(PrivateFieldDefinition {
                     initializer,
+                    decorators,
                     ..
                 })
                 | ClassElement::PrivateStaticFieldDefinition(PrivateFieldDefinition {
                     initializer,
+                    decorators,
                     ..
                 }) => {
+                    for decorator in &**decorators {
+                        visitor.visit(decorator)?;
+                    }
                     if let Some(expression) = initializer {
                         visitor.visit(expression)?;
                     }
@@ -1238,6 +1272,10 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         &mut self,
         node: &'ast ClassDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in node.decorators() {
+            self.visit(decorator)?;
+        }
+
         if let Some(node) = node.super_ref() {
             self.visit(node)?;
         }
@@ -1269,6 +1307,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         for element in node.elements() {
             match element {
                 ClassElement::MethodDefinition(m) => {
+                    for decorator in m.decorators() {
+                        visitor.visit(decorator)?;
+                    }
                     if let ClassElementName::PropertyName(name) = m.name() {
                         visitor.visit(name)?;
                     }
@@ -1277,6 +1318,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
                 }
                 ClassElement::FieldDefinition(field)
                 | ClassElement::StaticFieldDefinition(field) => {
+                    for decorator in field.decorators() {
+                        visitor.visit(decorator)?;
+                    }
                     visitor.visit(&field.name)?;
                     if let Some(expression) = &field.initializer {
                         visitor.visit(expression)?;
@@ -1284,12 +1328,17 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
                 }
                 ClassElement::PrivateFieldDefinition(PrivateFieldDefinition {
                     initializer,
+                    decorators,
                     ..
                 })
                 | ClassElement::PrivateStaticFieldDefinition(PrivateFieldDefinition {
                     initializer,
+                    decorators,
                     ..
                 }) => {
+                    for decorator in &**decorators {
+                        visitor.visit(decorator)?;
+                    }
                     if let Some(expression) = initializer {
                         visitor.visit(expression)?;
                     }
@@ -2444,7 +2493,10 @@ impl<'ast> Visitor<'ast> for AnnexBFunctionDeclarationNamesVisitor<'_> {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
-        if let IterableLoopInitializer::Const(node) = node.initializer() {
+        if let IterableLoopInitializer::Const(node)
+        | IterableLoopInitializer::Using(node)
+        | IterableLoopInitializer::AwaitUsing(node) = node.initializer()
+        {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
@@ -2462,7 +2514,10 @@ impl<'ast> Visitor<'ast> for AnnexBFunctionDeclarationNamesVisitor<'_> {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
-        if let IterableLoopInitializer::Const(node) = node.initializer() {
+        if let IterableLoopInitializer::Const(node)
+        | IterableLoopInitializer::Using(node)
+        | IterableLoopInitializer::AwaitUsing(node) = node.initializer()
+        {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
@@ -61,6 +61,8 @@ pub enum Punctuator {
     AssignURightSh,
     /// `^=`
     AssignXor,
+    /// `@`
+    At,
     /// `&&`
     BoolAnd,
     /// `||`
@@ -223,6 +225,7 @@ impl Punctuator {
             Self::AssignSub => "-=",
             Self::AssignURightSh => ">>>=",
             Self::AssignXor => "^=",
+            Self::At => "@",
             Self::BoolAnd => "&&",
             Self::BoolOr => "||",
             Self::Coalesce => "??",
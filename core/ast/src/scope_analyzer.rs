@@ -379,6 +379,9 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         &mut self,
         node: &'ast mut ClassDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         node.name_scope.escape_all_bindings();
         std::mem::swap(&mut self.scope, &mut node.name_scope);
         if let Some(super_ref) = &mut node.super_ref {
@@ -399,6 +402,9 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         &mut self,
         node: &'ast mut ClassExpression,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         if let Some(name_scope) = &mut node.name_scope {
             if self.direct_eval {
                 name_scope.escape_all_bindings();
@@ -426,6 +432,7 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         &mut self,
         node: &'ast mut ClassElement,
     ) -> ControlFlow<Self::BreakTy> {
+        visit_class_element_decorators_mut(self, node)?;
         match node {
             ClassElement::MethodDefinition(node) => self.visit_function_like(
                 &mut node.parameters,
@@ -477,9 +484,17 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         node: &'ast mut ExportDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
         match node {
-            ExportDeclaration::ReExport { specifier, kind } => {
+            ExportDeclaration::ReExport {
+                specifier,
+                kind,
+                attributes,
+            } => {
                 self.visit_module_specifier_mut(specifier)?;
-                self.visit_re_export_kind_mut(kind)
+                self.visit_re_export_kind_mut(kind)?;
+                for attribute in &mut **attributes {
+                    self.visit_import_attribute_mut(attribute)?;
+                }
+                ControlFlow::Continue(())
             }
             ExportDeclaration::List(list) => {
                 for item in &mut **list {
@@ -763,6 +778,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
         &mut self,
         node: &'ast mut ClassDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         let mut name_scope = Scope::new(self.scope.clone(), false);
         let name = node.name().to_js_string(self.interner);
         name_scope.create_immutable_binding(name, true);
@@ -785,6 +803,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
         &mut self,
         node: &'ast mut ClassExpression,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         let mut name_scope = None;
         if let Some(name) = node.name {
             if node.name_scope.is_some() {
@@ -815,6 +836,7 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
         &mut self,
         node: &'ast mut ClassElement,
     ) -> ControlFlow<Self::BreakTy> {
+        visit_class_element_decorators_mut(self, node)?;
         match node {
             ClassElement::MethodDefinition(node) => {
                 let strict = node.body.strict();
@@ -993,7 +1015,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
     fn visit_for_in_loop_mut(&mut self, node: &'ast mut ForInLoop) -> ControlFlow<Self::BreakTy> {
         let initializer_bound_names = match node.initializer() {
             IterableLoopInitializer::Let(declaration)
-            | IterableLoopInitializer::Const(declaration) => bound_names(declaration),
+            | IterableLoopInitializer::Const(declaration)
+            | IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => bound_names(declaration),
             _ => Vec::new(),
         };
         if initializer_bound_names.is_empty() {
@@ -1026,7 +1050,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
                 }
                 Some(scope)
             }
-            IterableLoopInitializer::Const(declaration) => {
+            IterableLoopInitializer::Const(declaration)
+            | IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => {
                 let scope = Scope::new(self.scope.clone(), false);
                 match declaration {
                     Binding::Identifier(ident) => {
@@ -1060,7 +1086,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
     fn visit_for_of_loop_mut(&mut self, node: &'ast mut ForOfLoop) -> ControlFlow<Self::BreakTy> {
         let initializer_bound_names = match node.initializer() {
             IterableLoopInitializer::Let(declaration)
-            | IterableLoopInitializer::Const(declaration) => bound_names(declaration),
+            | IterableLoopInitializer::Const(declaration)
+            | IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => bound_names(declaration),
             _ => Vec::new(),
         };
         if initializer_bound_names.is_empty() {
@@ -1093,7 +1121,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
                 }
                 Some(scope)
             }
-            IterableLoopInitializer::Const(declaration) => {
+            IterableLoopInitializer::Const(declaration)
+            | IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => {
                 let scope = Scope::new(self.scope.clone(), false);
                 match declaration {
                     Binding::Identifier(ident) => {
@@ -1375,6 +1405,9 @@ impl<'ast> VisitorMut<'ast> for ScopeIndexVisitor {
         &mut self,
         node: &'ast mut ClassDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         let index = self.index;
         if !node.name_scope.all_bindings_local() {
             self.index += 1;
@@ -1397,6 +1430,9 @@ impl<'ast> VisitorMut<'ast> for ScopeIndexVisitor {
         &mut self,
         node: &'ast mut ClassExpression,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         let index = self.index;
         if let Some(scope) = &node.name_scope {
             if !scope.all_bindings_local() {
@@ -1421,6 +1457,7 @@ impl<'ast> VisitorMut<'ast> for ScopeIndexVisitor {
         &mut self,
         node: &'ast mut ClassElement,
     ) -> ControlFlow<Self::BreakTy> {
+        visit_class_element_decorators_mut(self, node)?;
         match node {
             ClassElement::MethodDefinition(node) => {
                 let contains_direct_eval = node.contains_direct_eval();
@@ -1673,6 +1710,31 @@ impl ScopeIndexVisitor {
     }
 }
 
+/// Visits the decorators of a class element.
+///
+/// Decorators are evaluated in the class scope, outside of the scopes of the element itself.
+fn visit_class_element_decorators_mut<'ast, V>(
+    visitor: &mut V,
+    node: &'ast mut ClassElement,
+) -> ControlFlow<V::BreakTy>
+where
+    V: VisitorMut<'ast>,
+{
+    let decorators = match node {
+        ClassElement::MethodDefinition(method) => &mut method.decorators,
+        ClassElement::FieldDefinition(field) | ClassElement::StaticFieldDefinition(field) => {
+            &mut field.decorators
+        }
+        ClassElement::PrivateFieldDefinition(field)
+        | ClassElement::PrivateStaticFieldDefinition(field) => &mut field.decorators,
+        ClassElement::StaticBlock(_) => return ControlFlow::Continue(()),
+    };
+    for decorator in &mut **decorators {
+        visitor.visit_decorator_mut(decorator)?;
+    }
+    ControlFlow::Continue(())
+}
+
 /// `GlobalDeclarationInstantiation ( script, env )`
 ///
 /// More information:
@@ -1740,7 +1802,11 @@ fn global_declaration_instantiation(
                         drop(env.create_mutable_binding(name, false));
                     }
                 }
-                Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                Declaration::Lexical(
+                    LexicalDeclaration::Const(declaration)
+                    | LexicalDeclaration::Using(declaration)
+                    | LexicalDeclaration::AwaitUsing(declaration),
+                ) => {
                     for name in bound_names(declaration) {
                         let name = name.to_js_string(interner);
                         env.create_immutable_binding(name, true);
@@ -1779,7 +1845,12 @@ where
     // 3. For each element d of declarations, do
     for d in &declarations {
         // i. If IsConstantDeclaration of d is true, then
-        if let LexicallyScopedDeclaration::LexicalDeclaration(LexicalDeclaration::Const(d)) = d {
+        if let LexicallyScopedDeclaration::LexicalDeclaration(
+            LexicalDeclaration::Const(d)
+            | LexicalDeclaration::Using(d)
+            | LexicalDeclaration::AwaitUsing(d),
+        ) = d
+        {
             // a. For each element dn of the BoundNames of d, do
             for dn in bound_names::<'_, VariableList>(d) {
                 // 1. Perform ! env.CreateImmutableBinding(dn, true).
@@ -2120,7 +2191,11 @@ fn function_declaration_instantiation(
                         drop(lex_env.create_mutable_binding(name, false));
                     }
                 }
-                Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                Declaration::Lexical(
+                    LexicalDeclaration::Const(declaration)
+                    | LexicalDeclaration::Using(declaration)
+                    | LexicalDeclaration::AwaitUsing(declaration),
+                ) => {
                     for name in bound_names(declaration) {
                         let name = name.to_js_string(interner);
                         lex_env.create_immutable_binding(name, true);
@@ -2189,7 +2264,11 @@ fn module_instantiation(module: &Module, env: &Scope, interner: &Interner) {
                     drop(env.create_mutable_binding(name, false));
                 }
             }
-            LexicallyScopedDeclaration::LexicalDeclaration(LexicalDeclaration::Const(c)) => {
+            LexicallyScopedDeclaration::LexicalDeclaration(
+                LexicalDeclaration::Const(c)
+                | LexicalDeclaration::Using(c)
+                | LexicalDeclaration::AwaitUsing(c),
+            ) => {
                 for name in bound_names(c) {
                     let name = name.to_js_string(interner);
                     env.create_immutable_binding(name, true);
@@ -2423,7 +2502,11 @@ pub(crate) fn eval_declaration_instantiation_scope(
                         drop(lex_env.create_mutable_binding(name, false));
                     }
                 }
-                Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                Declaration::Lexical(
+                    LexicalDeclaration::Const(declaration)
+                    | LexicalDeclaration::Using(declaration)
+                    | LexicalDeclaration::AwaitUsing(declaration),
+                ) => {
                     for name in bound_names(declaration) {
                         let name = name.to_js_string(interner);
                         lex_env.create_immutable_binding(name, true);
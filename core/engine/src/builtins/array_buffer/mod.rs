@@ -233,6 +233,11 @@ impl ArrayBuffer {
         self.data.as_mut()
     }
 
+    /// Gets the maximum byte length of the buffer, or `None` if the buffer is not resizable.
+    pub(crate) const fn max_byte_len(&self) -> Option<u64> {
+        self.max_byte_len
+    }
+
     /// Sets the maximum byte length of the buffer, returning the previous value if present.
     pub(crate) fn set_max_byte_length(&mut self, max_byte_len: u64) -> Option<u64> {
         self.max_byte_len.replace(max_byte_len)
@@ -146,14 +146,39 @@ fn sort_negative_zero() {
                 return a.length === b.length && a.every((v, i) => v === b[i]);
             }
 
-            var TypedArrayCtor = [Float32Array, Float64Array];
+            var TypedArrayCtor = [Float16Array, Float32Array, Float64Array];
             var negativeZero = TypedArrayCtor.map((ctor) => new ctor([1, 0, -0, 2]).sort());
             var infinities = TypedArrayCtor.map((ctor) => new ctor([3, 4, Infinity, -Infinity, 1, 2]).sort());
         "#,
         ),
         TestAction::assert("cmp(negativeZero[0], [-0, 0, 1, 2])"),
         TestAction::assert("cmp(negativeZero[1], [-0, 0, 1, 2])"),
+        TestAction::assert("cmp(negativeZero[2], [-0, 0, 1, 2])"),
         TestAction::assert("cmp(infinities[0], [-Infinity, 1, 2, 3, 4, Infinity])"),
         TestAction::assert("cmp(infinities[1], [-Infinity, 1, 2, 3, 4, Infinity])"),
+        TestAction::assert("cmp(infinities[2], [-Infinity, 1, 2, 3, 4, Infinity])"),
+    ]);
+}
+
+#[test]
+fn float16() {
+    run_test_actions([
+        TestAction::run(
+            r#"
+            var array = new Float16Array([1.337, 65520, -0.5]);
+            var view = new DataView(new ArrayBuffer(4));
+            view.setFloat16(0, 1.5);
+            view.setFloat16(2, 1.5, true);
+        "#,
+        ),
+        TestAction::assert_eq("Float16Array.BYTES_PER_ELEMENT", 2),
+        TestAction::assert_eq("array.byteLength", 6),
+        TestAction::assert_eq("array[0]", 1.336_914_062_5),
+        TestAction::assert_eq("array[1]", f64::INFINITY),
+        TestAction::assert_eq("array[2]", -0.5),
+        TestAction::assert_eq("view.getUint16(0)", 0x3E00),
+        TestAction::assert_eq("view.getUint16(2, true)", 0x3E00),
+        TestAction::assert_eq("view.getFloat16(0)", 1.5),
+        TestAction::assert_eq("view.getFloat16(2)", 0.000_003_695_487_976_074_218_8),
     ]);
 }
@@ -0,0 +1,399 @@
+//! Boa's implementation of ECMAScript's `AsyncDisposableStack` object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncDisposableStack
+
+use crate::{
+    builtins::{
+        disposable_stack::{
+            adopt_closure,
+            capability::{create_disposable_resource, DisposeCapability, DisposeHint},
+            DisposableState,
+        },
+        promise::PromiseCapability,
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    error::JsNativeError,
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+
+/// The internal representation of an `AsyncDisposableStack` object.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct AsyncDisposableStack {
+    /// The `[[AsyncDisposableState]]` internal slot.
+    state: DisposableState,
+    /// The `[[DisposeCapability]]` internal slot.
+    capability: DisposeCapability,
+}
+
+impl AsyncDisposableStack {
+    /// Creates a new pending stack with the given dispose capability.
+    fn new(capability: DisposeCapability) -> Self {
+        Self {
+            state: DisposableState::Pending,
+            capability,
+        }
+    }
+}
+
+impl IntrinsicObject for AsyncDisposableStack {
+    fn init(realm: &Realm) {
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name(js_string!("get disposed"))
+            .build();
+
+        let dispose_async_function = BuiltInBuilder::callable(realm, Self::dispose_async)
+            .name(js_string!("disposeAsync"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .method(Self::adopt, js_string!("adopt"), 2)
+            .method(Self::defer, js_string!("defer"), 1)
+            .property(
+                js_string!("disposeAsync"),
+                dispose_async_function.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_string!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::r#move, js_string!("move"), 0)
+            .method(Self::r#use, js_string!("use"), 1)
+            .property(
+                JsSymbol::async_dispose(),
+                dispose_async_function,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for AsyncDisposableStack {
+    const NAME: JsString = StaticJsStrings::ASYNC_DISPOSABLE_STACK;
+}
+
+impl BuiltInConstructor for AsyncDisposableStack {
+    const LENGTH: usize = 0;
+    const P: usize = 8;
+    const SP: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::async_disposable_stack;
+
+    /// [`AsyncDisposableStack ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "calling a builtin AsyncDisposableStack constructor without new is forbidden",
+                )
+                .into());
+        }
+
+        // 2. Let asyncDisposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::async_disposable_stack,
+            context,
+        )?;
+
+        // 3. Set asyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 4. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 5. Return asyncDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self::new(DisposeCapability::new()),
+        )
+        .into())
+    }
+}
+
+impl AsyncDisposableStack {
+    /// Performs `RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]])` on the
+    /// `this` value.
+    fn this_stack(this: &JsValue, method: &str) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|o| o.is::<Self>())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "AsyncDisposableStack.prototype.{method}: `this` is not an AsyncDisposableStack"
+                    ))
+                    .into()
+            })
+    }
+
+    /// Throws a `ReferenceError` if the stack was already disposed.
+    fn require_pending(stack: &JsObject, method: &str) -> JsResult<()> {
+        let state = stack
+            .downcast_ref::<Self>()
+            .expect("must be an AsyncDisposableStack")
+            .state;
+
+        if state == DisposableState::Disposed {
+            return Err(JsNativeError::reference()
+                .with_message(format!(
+                    "AsyncDisposableStack.prototype.{method}: the stack has already been disposed"
+                ))
+                .into());
+        }
+
+        Ok(())
+    }
+
+    /// Creates a resource for `value` and adds it to the dispose capability of `stack`.
+    fn add_resource(
+        stack: &JsObject,
+        value: &JsValue,
+        method: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        if let Some(resource) =
+            create_disposable_resource(value, DisposeHint::Async, method, context)?
+        {
+            stack
+                .downcast_mut::<Self>()
+                .expect("must be an AsyncDisposableStack")
+                .capability
+                .push(resource);
+        }
+
+        Ok(())
+    }
+
+    /// [`get AsyncDisposableStack.prototype.disposed`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-asyncdisposablestack.prototype.disposed
+    fn get_disposed(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "disposed")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        let state = stack
+            .downcast_ref::<Self>()
+            .expect("must be an AsyncDisposableStack")
+            .state;
+        Ok((state == DisposableState::Disposed).into())
+    }
+
+    /// [`AsyncDisposableStack.prototype.disposeAsync ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.disposeAsync
+    #[allow(clippy::unnecessary_wraps)]
+    fn dispose_async(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("creating a capability from %Promise% cannot fail");
+
+        // 3. If asyncDisposableStack does not have an [[AsyncDisposableState]] internal slot, then
+        let stack = match Self::this_stack(this, "disposeAsync") {
+            Ok(stack) => stack,
+            Err(err) => {
+                // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+                let err = err.to_opaque(context);
+                promise_capability
+                    .reject()
+                    .call(&JsValue::undefined(), &[err], context)
+                    .expect("cannot fail per the spec");
+
+                // b. Return promiseCapability.[[Promise]].
+                return Ok(promise_capability.promise().clone().into());
+            }
+        };
+
+        let capability = {
+            let mut stack = stack
+                .downcast_mut::<Self>()
+                .expect("must be an AsyncDisposableStack");
+
+            // 4. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, then
+            if stack.state == DisposableState::Disposed {
+                drop(stack);
+
+                // a. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+                promise_capability
+                    .resolve()
+                    .call(&JsValue::undefined(), &[JsValue::undefined()], context)
+                    .expect("cannot fail per the spec");
+
+                // b. Return promiseCapability.[[Promise]].
+                return Ok(promise_capability.promise().clone().into());
+            }
+
+            // 5. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+            stack.state = DisposableState::Disposed;
+            std::mem::take(&mut stack.capability)
+        };
+
+        // 6. Let result be DisposeResources(asyncDisposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        // 7. IfAbruptRejectPromise(result, promiseCapability).
+        // 8. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result »).
+        // 9. Return promiseCapability.[[Promise]].
+        let result = capability.dispose_resources_async(Ok(()), context);
+        promise_capability
+            .resolve()
+            .call(&JsValue::undefined(), &[result.into()], context)
+            .expect("cannot fail per the spec");
+
+        Ok(promise_capability.promise().clone().into())
+    }
+
+    /// [`AsyncDisposableStack.prototype.use ( value )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.use
+    fn r#use(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "use")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_pending(&stack, "use")?;
+
+        // 4. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], value, async-dispose).
+        let value = args.get_or_undefined(0);
+        Self::add_resource(&stack, value, None, context)?;
+
+        // 5. Return value.
+        Ok(value.clone())
+    }
+
+    /// [`AsyncDisposableStack.prototype.adopt ( value, onDisposeAsync )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.adopt
+    fn adopt(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "adopt")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_pending(&stack, "adopt")?;
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let value = args.get_or_undefined(0);
+        let on_dispose = args
+            .get_or_undefined(1)
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "AsyncDisposableStack.prototype.adopt: `onDisposeAsync` is not callable",
+                )
+            })?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures value and onDisposeAsync and performs the following steps when called:
+        //     a. Return ? Call(onDisposeAsync, undefined, « value »).
+        // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        let f = adopt_closure(on_dispose, value.clone(), context);
+
+        // 7. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, F).
+        Self::add_resource(&stack, &JsValue::undefined(), Some(&f), context)?;
+
+        // 8. Return value.
+        Ok(value.clone())
+    }
+
+    /// [`AsyncDisposableStack.prototype.defer ( onDisposeAsync )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.defer
+    fn defer(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "defer")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_pending(&stack, "defer")?;
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let on_dispose = args.get_or_undefined(0);
+        if !on_dispose.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "AsyncDisposableStack.prototype.defer: `onDisposeAsync` is not callable",
+                )
+                .into());
+        }
+
+        // 5. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, onDisposeAsync).
+        Self::add_resource(&stack, &JsValue::undefined(), Some(on_dispose), context)?;
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// [`AsyncDisposableStack.prototype.move ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.move
+    fn r#move(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "move")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_pending(&stack, "move")?;
+
+        let capability = {
+            let mut stack = stack
+                .downcast_mut::<Self>()
+                .expect("must be an AsyncDisposableStack");
+
+            // 7. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+            // 8. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+            stack.state = DisposableState::Disposed;
+            std::mem::take(&mut stack.capability)
+        };
+
+        // 4. Let newAsyncDisposableStack be ? OrdinaryCreateFromConstructor(%AsyncDisposableStack%, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        // 5. Set newAsyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 6. Set newAsyncDisposableStack.[[DisposeCapability]] to asyncDisposableStack.[[DisposeCapability]].
+        // 9. Return newAsyncDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .constructors()
+                .async_disposable_stack()
+                .prototype(),
+            Self::new(capability),
+        )
+        .into())
+    }
+}
@@ -0,0 +1,463 @@
+//! This module implements the `DisposeCapability` record and its abstract operations, shared by
+//! `using` declarations, `DisposableStack` and `AsyncDisposableStack`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposecapability-records
+
+use crate::{
+    builtins::promise::{if_abrupt_reject_promise, PromiseCapability},
+    error::JsNativeError,
+    js_string,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsObject},
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsError, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+
+use crate::builtins::Promise;
+
+/// The hint used to dispose a resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Trace, Finalize)]
+#[boa_gc(empty_trace)]
+pub(crate) enum DisposeHint {
+    /// `sync-dispose`, used by `using` and `DisposableStack`.
+    Sync,
+    /// `async-dispose`, used by `await using` and `AsyncDisposableStack`.
+    Async,
+}
+
+/// The `DisposableResource` record.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposableresource-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct DisposableResource {
+    /// The `[[ResourceValue]]` field.
+    value: JsValue,
+    /// The `[[Hint]]` field.
+    hint: DisposeHint,
+    /// The `[[DisposeMethod]]` field.
+    method: Option<JsObject>,
+}
+
+impl DisposableResource {
+    /// Abstract operation [`CreateDisposableResource ( V, hint [ , method ] )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-createdisposableresource
+    fn create(
+        value: &JsValue,
+        hint: DisposeHint,
+        method: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let Some(method) = method else {
+            // 1. If method is not present, then
+            //     a. If V is either null or undefined, then
+            if value.is_null_or_undefined() {
+                // i. Set V to undefined.
+                // ii. Set method to undefined.
+                return Ok(Self {
+                    value: JsValue::undefined(),
+                    hint,
+                    method: None,
+                });
+            }
+
+            // b. Else,
+            //     i. If V is not an Object, throw a TypeError exception.
+            if !value.is_object() {
+                return Err(JsNativeError::typ()
+                    .with_message("value of a disposable resource must be an object")
+                    .into());
+            }
+
+            //     ii. Set method to ? GetDisposeMethod(V, hint).
+            //     iii. If method is undefined, throw a TypeError exception.
+            let method = get_dispose_method(value, hint, context)?.ok_or_else(|| {
+                JsNativeError::typ().with_message("disposable resource has no dispose method")
+            })?;
+
+            return Ok(Self {
+                value: value.clone(),
+                hint,
+                method: Some(method),
+            });
+        };
+
+        // 2. Else,
+        //     a. If IsCallable(method) is false, throw a TypeError exception.
+        let method = method.as_callable().cloned().ok_or_else(|| {
+            JsNativeError::typ().with_message("dispose method of a resource must be callable")
+        })?;
+
+        // 3. Return the DisposableResource Record { [[ResourceValue]]: V, [[Hint]]: hint, [[DisposeMethod]]: method }.
+        Ok(Self {
+            value: value.clone(),
+            hint,
+            method: Some(method),
+        })
+    }
+}
+
+/// Steps 1 and 2 of the abstract operation [`AddDisposableResource ( disposeCapability, V, hint [ , method ] )`][spec].
+///
+/// Creating the resource can call into user code, so the caller performs step 3 by pushing the
+/// returned resource with [`DisposeCapability::push`] once the resource was created. Returns
+/// `None` if there is nothing to record.
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource
+pub(crate) fn create_disposable_resource(
+    value: &JsValue,
+    hint: DisposeHint,
+    method: Option<&JsValue>,
+    context: &mut Context,
+) -> JsResult<Option<DisposableResource>> {
+    // 1. If method is not present, then
+    //     a. If V is either null or undefined and hint is sync-dispose, return unused.
+    if method.is_none() && value.is_null_or_undefined() && hint == DisposeHint::Sync {
+        return Ok(None);
+    }
+
+    //     b. NOTE: When V is either null or undefined and hint is async-dispose, we record that
+    //        a resource was added to ensure that we will await before the block exits.
+    //     c. Let resource be ? CreateDisposableResource(V, hint).
+    // 2. Else,
+    //     a. Assert: V is undefined.
+    //     b. Let resource be ? CreateDisposableResource(undefined, hint, method).
+    DisposableResource::create(value, hint, method, context).map(Some)
+}
+
+/// Abstract operation [`GetDisposeMethod ( V, hint )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-getdisposemethod
+fn get_dispose_method(
+    value: &JsValue,
+    hint: DisposeHint,
+    context: &mut Context,
+) -> JsResult<Option<JsObject>> {
+    // 1. If hint is async-dispose, then
+    if hint == DisposeHint::Async {
+        // a. Let method be ? GetMethod(V, @@asyncDispose).
+        if let Some(method) = value.get_method(JsSymbol::async_dispose(), context)? {
+            return Ok(Some(method));
+        }
+
+        // b. If method is undefined, then
+        //     i. Set method to ? GetMethod(V, @@dispose).
+        let Some(method) = value.get_method(JsSymbol::dispose(), context)? else {
+            return Ok(None);
+        };
+
+        //     ii. If method is not undefined, then
+        //         1. Let closure be a new Abstract Closure with no parameters that captures method and performs the following steps when called:
+        //         2. NOTE: This function is not observable to user code. It is used to ensure that a Promise returned from a synchronous @@dispose method will not be awaited and that any exception thrown will not be thrown synchronously.
+        //         3. Set method to CreateBuiltinFunction(closure, 0, "", « »).
+        let closure = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |this, _, method, context| {
+                    // a. Let O be the this value.
+                    // b. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+                    let promise_capability = PromiseCapability::new(
+                        &context.intrinsics().constructors().promise().constructor(),
+                        context,
+                    )
+                    .expect("creating a capability from %Promise% cannot fail");
+
+                    // c. Let result be Completion(Call(method, O)).
+                    let result = method.call(this, &[], context);
+
+                    // d. IfAbruptRejectPromise(result, promiseCapability).
+                    if_abrupt_reject_promise!(result, promise_capability, context);
+
+                    // e. Perform ? Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+                    promise_capability.resolve().call(
+                        &JsValue::undefined(),
+                        &[JsValue::undefined()],
+                        context,
+                    )?;
+
+                    // f. Return promiseCapability.[[Promise]].
+                    Ok(promise_capability.promise().clone().into())
+                },
+                method,
+            ),
+        )
+        .name(js_string!())
+        .length(0)
+        .build();
+
+        return Ok(Some(closure.into()));
+    }
+
+    // 2. Else,
+    //     a. Let method be ? GetMethod(V, @@dispose).
+    // 3. Return method.
+    value.get_method(JsSymbol::dispose(), context)
+}
+
+/// Combines the thrown `error` with the current `completion`, as in step 3.c.iii of
+/// `DisposeResources`.
+fn chain_error(completion: JsResult<()>, error: JsError) -> JsResult<()> {
+    match completion {
+        // 1. If completion is a throw completion, then
+        //     a. Set result to result.[[Value]].
+        //     b. Let suppressed be completion.[[Value]].
+        //     c. Let error be a newly created SuppressedError object.
+        //     d. Perform CreateNonEnumerableDataPropertyOrThrow(error, "error", result).
+        //     e. Perform CreateNonEnumerableDataPropertyOrThrow(error, "suppressed", suppressed).
+        //     f. Set completion to ThrowCompletion(error).
+        Err(suppressed) => Err(JsNativeError::suppressed(error, suppressed).into()),
+        // 2. Else,
+        //     a. Set completion to result.
+        Ok(()) => Err(error),
+    }
+}
+
+/// The `DisposeCapability` record.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposecapability-records
+#[derive(Debug, Default, Clone, Trace, Finalize, JsData)]
+pub(crate) struct DisposeCapability {
+    /// The `[[DisposableResourceStack]]` field.
+    stack: Vec<DisposableResource>,
+}
+
+impl DisposeCapability {
+    /// Abstract operation [`NewDisposeCapability ( )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-newdisposecapability
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a resource created by [`create_disposable_resource`] to the
+    /// `[[DisposableResourceStack]]`.
+    pub(crate) fn push(&mut self, resource: DisposableResource) {
+        self.stack.push(resource);
+    }
+
+    /// Abstract operation [`DisposeResources ( disposeCapability, completion )`][spec], for
+    /// capabilities that only hold `sync-dispose` resources.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn dispose_resources(
+        mut self,
+        mut completion: JsResult<()>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 3. For each element resource of disposeCapability.[[DisposableResourceStack]], in reverse list order, do
+        for resource in std::mem::take(&mut self.stack).into_iter().rev() {
+            // a. Let value be resource.[[ResourceValue]].
+            // b. Let hint be resource.[[Hint]].
+            // c. Let method be resource.[[DisposeMethod]].
+            debug_assert_eq!(resource.hint, DisposeHint::Sync);
+
+            // d. If method is not undefined, then
+            if let Some(method) = &resource.method {
+                //     i. Let result be Completion(Call(method, value)).
+                //     iii. If result is a throw completion, then
+                if let Err(err) = method.call(&resource.value, &[], context) {
+                    completion = chain_error(completion, err);
+                }
+            }
+        }
+
+        // 5. NOTE: After disposeResources completes, disposeCapability will not be used.
+        // 6. Set disposeCapability.[[DisposableResourceStack]] to a new empty List.
+        // 7. Return ? completion.
+        completion
+    }
+
+    /// Abstract operation [`DisposeResources ( disposeCapability, completion )`][spec], for
+    /// capabilities that can hold `async-dispose` resources.
+    ///
+    /// Returns a promise that settles with the final completion once every resource was disposed.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn dispose_resources_async(
+        mut self,
+        completion: JsResult<()>,
+        context: &mut Context,
+    ) -> JsObject {
+        let capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("creating a capability from %Promise% cannot fail");
+        let promise = capability.promise().clone();
+
+        AsyncDisposal {
+            stack: std::mem::take(&mut self.stack),
+            completion: completion.err(),
+            // 1. Let needsAwait be false.
+            needs_await: false,
+            // 2. Let hasAwaited be false.
+            has_awaited: false,
+            capability,
+        }
+        .resume(context);
+
+        promise
+    }
+}
+
+/// The state of a `DisposeResources` operation that is suspended at an `Await`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct AsyncDisposal {
+    /// The resources that are still to be disposed.
+    stack: Vec<DisposableResource>,
+    /// The error of the current completion, if it is a throw completion.
+    completion: Option<JsError>,
+    needs_await: bool,
+    has_awaited: bool,
+    /// The capability of the promise returned by `dispose_resources_async`.
+    capability: PromiseCapability,
+}
+
+impl AsyncDisposal {
+    /// Continues disposing the remaining resources until the next `Await`, or until all
+    /// resources were disposed.
+    fn resume(mut self, context: &mut Context) {
+        // 3. For each element resource of disposeCapability.[[DisposableResourceStack]], in reverse list order, do
+        while let Some(resource) = self.stack.pop() {
+            // a. Let value be resource.[[ResourceValue]].
+            // b. Let hint be resource.[[Hint]].
+            // c. Let method be resource.[[DisposeMethod]].
+            // d. If hint is sync-dispose and needsAwait is true and hasAwaited is false, then
+            if resource.hint == DisposeHint::Sync && self.needs_await && !self.has_awaited {
+                // i. Perform ! Await(undefined).
+                // ii. Set needsAwait to false.
+                self.needs_await = false;
+                self.stack.push(resource);
+                if let Err(err) = self.clone().await_value(JsValue::undefined(), context) {
+                    self.record_error(err);
+                    continue;
+                }
+                return;
+            }
+
+            // e. If method is not undefined, then
+            if let Some(method) = &resource.method {
+                // i. Let result be Completion(Call(method, value)).
+                match method.call(&resource.value, &[], context) {
+                    // ii. If result is a normal completion and hint is async-dispose, then
+                    Ok(result) if resource.hint == DisposeHint::Async => {
+                        // 1. Set result to Completion(Await(result.[[Value]])).
+                        // 2. Set hasAwaited to true.
+                        self.has_awaited = true;
+                        match self.clone().await_value(result, context) {
+                            Ok(()) => return,
+                            Err(err) => self.record_error(err),
+                        }
+                    }
+                    Ok(_) => {}
+                    // iii. If result is a throw completion, then
+                    Err(err) => self.record_error(err),
+                }
+            } else {
+                // f. Else,
+                //     i. Assert: hint is async-dispose.
+                debug_assert_eq!(resource.hint, DisposeHint::Async);
+
+                //     ii. Set needsAwait to true.
+                //     iii. NOTE: This can only indicate a case where either null or undefined was the initialized value of an await using declaration.
+                self.needs_await = true;
+            }
+        }
+
+        // 4. If needsAwait is true and hasAwaited is false, then
+        if self.needs_await && !self.has_awaited {
+            // a. Perform ! Await(undefined).
+            self.needs_await = false;
+            match self.clone().await_value(JsValue::undefined(), context) {
+                Ok(()) => return,
+                Err(err) => self.record_error(err),
+            }
+        }
+
+        // 5. NOTE: After disposeResources completes, disposeCapability will not be used.
+        // 6. Set disposeCapability.[[DisposableResourceStack]] to a new empty List.
+        // 7. Return ? completion.
+        let result = match self.completion.take() {
+            Some(err) => {
+                let err = err.to_opaque(context);
+                self.capability
+                    .reject()
+                    .call(&JsValue::undefined(), &[err], context)
+            }
+            None => self.capability.resolve().call(
+                &JsValue::undefined(),
+                &[JsValue::undefined()],
+                context,
+            ),
+        };
+        result.expect("resolving functions of %Promise% cannot fail");
+    }
+
+    /// Records a throw completion of a dispose method, as in step 3.e.iii of `DisposeResources`.
+    fn record_error(&mut self, error: JsError) {
+        let completion = self.completion.take().map_or(Ok(()), Err);
+        self.completion = chain_error(completion, error).err();
+    }
+
+    /// Awaits `value`, resuming the disposal once it settles.
+    ///
+    /// Returns an error if `value` could not be converted to a promise, in which case the
+    /// disposal must continue synchronously.
+    fn await_value(self, value: JsValue, context: &mut Context) -> JsResult<()> {
+        let promise = Promise::promise_resolve(
+            &context.intrinsics().constructors().promise().constructor(),
+            value,
+            context,
+        )?;
+
+        let on_fulfilled = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, _, state: &Self, context| {
+                    state.clone().resume(context);
+                    Ok(JsValue::undefined())
+                },
+                self.clone(),
+            ),
+        )
+        .name(js_string!())
+        .length(1)
+        .build();
+
+        let on_rejected = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, state: &Self, context| {
+                    let mut state = state.clone();
+                    state.record_error(JsError::from_opaque(args.get_or_undefined(0).clone()));
+                    state.resume(context);
+                    Ok(JsValue::undefined())
+                },
+                self,
+            ),
+        )
+        .name(js_string!())
+        .length(1)
+        .build();
+
+        Promise::perform_promise_then(
+            &promise,
+            Some(on_fulfilled),
+            Some(on_rejected),
+            None,
+            context,
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,381 @@
+//! Boa's implementation of ECMAScript's `DisposableStack` object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack
+
+pub(crate) mod capability;
+
+#[cfg(test)]
+mod tests;
+
+use self::capability::{create_disposable_resource, DisposeCapability, DisposeHint};
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    error::JsNativeError,
+    js_string,
+    native_function::NativeFunction,
+    object::{internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+
+/// The `[[DisposableState]]` of a disposable stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Trace, Finalize)]
+#[boa_gc(empty_trace)]
+pub(crate) enum DisposableState {
+    Pending,
+    Disposed,
+}
+
+/// The internal representation of a `DisposableStack` object.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct DisposableStack {
+    /// The `[[DisposableState]]` internal slot.
+    state: DisposableState,
+    /// The `[[DisposeCapability]]` internal slot.
+    capability: DisposeCapability,
+}
+
+impl DisposableStack {
+    /// Creates a new pending stack with an empty dispose capability.
+    fn new(capability: DisposeCapability) -> Self {
+        Self {
+            state: DisposableState::Pending,
+            capability,
+        }
+    }
+}
+
+impl IntrinsicObject for DisposableStack {
+    fn init(realm: &Realm) {
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name(js_string!("get disposed"))
+            .build();
+
+        let dispose_function = BuiltInBuilder::callable(realm, Self::dispose)
+            .name(js_string!("dispose"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .method(Self::adopt, js_string!("adopt"), 2)
+            .method(Self::defer, js_string!("defer"), 1)
+            .property(
+                js_string!("dispose"),
+                dispose_function.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_string!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::r#move, js_string!("move"), 0)
+            .method(Self::r#use, js_string!("use"), 1)
+            .property(
+                JsSymbol::dispose(),
+                dispose_function,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DisposableStack {
+    const NAME: JsString = StaticJsStrings::DISPOSABLE_STACK;
+}
+
+impl BuiltInConstructor for DisposableStack {
+    const LENGTH: usize = 0;
+    const P: usize = 8;
+    const SP: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::disposable_stack;
+
+    /// [`DisposableStack ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "calling a builtin DisposableStack constructor without new is forbidden",
+                )
+                .into());
+        }
+
+        // 2. Let disposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::disposable_stack,
+            context,
+        )?;
+
+        // 3. Set disposableStack.[[DisposableState]] to pending.
+        // 4. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 5. Return disposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self::new(DisposeCapability::new()),
+        )
+        .into())
+    }
+}
+
+impl DisposableStack {
+    /// Performs `RequireInternalSlot(disposableStack, [[DisposableState]])` on the `this` value.
+    fn this_stack(this: &JsValue, method: &str) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|o| o.is::<Self>())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "DisposableStack.prototype.{method}: `this` is not a DisposableStack"
+                    ))
+                    .into()
+            })
+    }
+
+    /// Throws a `ReferenceError` if the stack was already disposed.
+    fn require_pending(stack: &JsObject, method: &str) -> JsResult<()> {
+        let state = stack
+            .downcast_ref::<Self>()
+            .expect("must be a DisposableStack")
+            .state;
+
+        if state == DisposableState::Disposed {
+            return Err(JsNativeError::reference()
+                .with_message(format!(
+                    "DisposableStack.prototype.{method}: the stack has already been disposed"
+                ))
+                .into());
+        }
+
+        Ok(())
+    }
+
+    /// Creates a resource for `value` and adds it to the dispose capability of `stack`.
+    fn add_resource(
+        stack: &JsObject,
+        value: &JsValue,
+        method: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        if let Some(resource) =
+            create_disposable_resource(value, DisposeHint::Sync, method, context)?
+        {
+            stack
+                .downcast_mut::<Self>()
+                .expect("must be a DisposableStack")
+                .capability
+                .push(resource);
+        }
+
+        Ok(())
+    }
+
+    /// [`get DisposableStack.prototype.disposed`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-disposablestack.prototype.disposed
+    fn get_disposed(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "disposed")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        let state = stack
+            .downcast_ref::<Self>()
+            .expect("must be a DisposableStack")
+            .state;
+        Ok((state == DisposableState::Disposed).into())
+    }
+
+    /// [`DisposableStack.prototype.dispose ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.dispose
+    fn dispose(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "dispose")?;
+
+        let capability = {
+            let mut stack = stack
+                .downcast_mut::<Self>()
+                .expect("must be a DisposableStack");
+
+            // 3. If disposableStack.[[DisposableState]] is disposed, return undefined.
+            if stack.state == DisposableState::Disposed {
+                return Ok(JsValue::undefined());
+            }
+
+            // 4. Set disposableStack.[[DisposableState]] to disposed.
+            stack.state = DisposableState::Disposed;
+            std::mem::take(&mut stack.capability)
+        };
+
+        // 5. Return ? DisposeResources(disposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        capability.dispose_resources(Ok(()), context)?;
+        Ok(JsValue::undefined())
+    }
+
+    /// [`DisposableStack.prototype.use ( value )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.use
+    fn r#use(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "use")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_pending(&stack, "use")?;
+
+        // 4. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], value, sync-dispose).
+        let value = args.get_or_undefined(0);
+        Self::add_resource(&stack, value, None, context)?;
+
+        // 5. Return value.
+        Ok(value.clone())
+    }
+
+    /// [`DisposableStack.prototype.adopt ( value, onDispose )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.adopt
+    fn adopt(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "adopt")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_pending(&stack, "adopt")?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let value = args.get_or_undefined(0);
+        let on_dispose = args
+            .get_or_undefined(1)
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("DisposableStack.prototype.adopt: `onDispose` is not callable")
+            })?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures value and onDispose and performs the following steps when called:
+        //     a. Return ? Call(onDispose, undefined, « value »).
+        // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        let f = adopt_closure(on_dispose, value.clone(), context);
+
+        // 7. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, F).
+        Self::add_resource(&stack, &JsValue::undefined(), Some(&f), context)?;
+
+        // 8. Return value.
+        Ok(value.clone())
+    }
+
+    /// [`DisposableStack.prototype.defer ( onDispose )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.defer
+    fn defer(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "defer")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_pending(&stack, "defer")?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let on_dispose = args.get_or_undefined(0);
+        if !on_dispose.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message("DisposableStack.prototype.defer: `onDispose` is not callable")
+                .into());
+        }
+
+        // 5. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, onDispose).
+        Self::add_resource(&stack, &JsValue::undefined(), Some(on_dispose), context)?;
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// [`DisposableStack.prototype.move ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.move
+    fn r#move(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "move")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_pending(&stack, "move")?;
+
+        let capability = {
+            let mut stack = stack
+                .downcast_mut::<Self>()
+                .expect("must be a DisposableStack");
+
+            // 7. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+            // 8. Set disposableStack.[[DisposableState]] to disposed.
+            stack.state = DisposableState::Disposed;
+            std::mem::take(&mut stack.capability)
+        };
+
+        // 4. Let newDisposableStack be ? OrdinaryCreateFromConstructor(%DisposableStack%, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        // 5. Set newDisposableStack.[[DisposableState]] to pending.
+        // 6. Set newDisposableStack.[[DisposeCapability]] to disposableStack.[[DisposeCapability]].
+        // 9. Return newDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .constructors()
+                .disposable_stack()
+                .prototype(),
+            Self::new(capability),
+        )
+        .into())
+    }
+}
+
+/// Creates the closure used by `adopt` to call `onDispose` with the adopted `value`.
+pub(crate) fn adopt_closure(on_dispose: JsObject, value: JsValue, context: &Context) -> JsValue {
+    FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, _, (on_dispose, value), context| {
+                on_dispose.call(&JsValue::undefined(), std::slice::from_ref(value), context)
+            },
+            (on_dispose, value),
+        ),
+    )
+    .name(js_string!())
+    .length(0)
+    .build()
+    .into()
+}
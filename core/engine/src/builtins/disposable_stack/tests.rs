@@ -0,0 +1,168 @@
+use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn disposable_stack() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                var log = [];
+                var stack = new DisposableStack();
+                stack.use({ [Symbol.dispose]() { log.push("use"); } });
+                stack.adopt(1, (v) => log.push("adopt " + v));
+                stack.defer(() => log.push("defer"));
+            "#}),
+        TestAction::assert("!stack.disposed"),
+        TestAction::run("stack.dispose()"),
+        TestAction::assert("stack.disposed"),
+        TestAction::assert(r#"arrayEquals(log, ["defer", "adopt 1", "use"])"#),
+        TestAction::assert_native_error(
+            "stack.defer(() => {})",
+            JsNativeErrorKind::Reference,
+            "DisposableStack.prototype.defer: the stack has already been disposed",
+        ),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(stack)",
+            js_str!("[object DisposableStack]"),
+        ),
+        TestAction::assert(
+            "DisposableStack.prototype.dispose === DisposableStack.prototype[Symbol.dispose]",
+        ),
+    ]);
+}
+
+#[test]
+fn disposable_stack_move() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var disposed = false;
+                var stack = new DisposableStack();
+                stack.defer(() => { disposed = true; });
+                var moved = stack.move();
+                stack.dispose();
+            "#}),
+        TestAction::assert("stack.disposed"),
+        TestAction::assert("!moved.disposed"),
+        TestAction::assert("!disposed"),
+        TestAction::run("moved.dispose()"),
+        TestAction::assert("disposed"),
+    ]);
+}
+
+#[test]
+fn suppressed_error() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var stack = new DisposableStack();
+                stack.defer(() => { throw 1; });
+                stack.defer(() => { throw 2; });
+                var error;
+                try {
+                    stack.dispose();
+                } catch (e) {
+                    error = e;
+                }
+            "#}),
+        TestAction::assert("error instanceof SuppressedError"),
+        TestAction::assert_eq("error.error", 1),
+        TestAction::assert_eq("error.suppressed", 2),
+        TestAction::assert_eq("new SuppressedError(1, 2, 'msg').message", js_str!("msg")),
+    ]);
+}
+
+#[test]
+fn using_declaration() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                var log = [];
+                function resource(name) {
+                    return { [Symbol.dispose]() { log.push(name); } };
+                }
+                {
+                    using a = resource("a"), b = resource("b");
+                    using c = null;
+                    log.push("body");
+                }
+            "#}),
+        TestAction::assert(r#"arrayEquals(log, ["body", "b", "a"])"#),
+        TestAction::assert_native_error(
+            "{ using x = 1; }",
+            JsNativeErrorKind::Type,
+            "value of a disposable resource must be an object",
+        ),
+    ]);
+}
+
+#[test]
+fn using_declaration_exits() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                var log = [];
+                function resource(name) {
+                    return { [Symbol.dispose]() { log.push(name); } };
+                }
+                function f() {
+                    using r = resource("return");
+                    return "value";
+                }
+                var result = f();
+                for (const i of [1, 2]) {
+                    using r = resource("loop " + i);
+                    if (i === 2) break;
+                }
+                for (using r of [resource("of 1"), resource("of 2")]) {
+                    log.push("iteration");
+                }
+            "#}),
+        TestAction::assert_eq("result", js_str!("value")),
+        TestAction::assert(indoc! {r#"
+                arrayEquals(
+                    log,
+                    ["return", "loop 1", "loop 2", "iteration", "of 1", "iteration", "of 2"]
+                )
+            "#}),
+    ]);
+}
+
+#[test]
+fn using_declaration_errors() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var error;
+                try {
+                    using r = { [Symbol.dispose]() { throw "dispose"; } };
+                    throw "body";
+                } catch (e) {
+                    error = e;
+                }
+            "#}),
+        TestAction::assert("error instanceof SuppressedError"),
+        TestAction::assert_eq("error.error", js_str!("dispose")),
+        TestAction::assert_eq("error.suppressed", js_str!("body")),
+    ]);
+}
+
+#[test]
+fn await_using_declaration() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                var log = [];
+                async function f() {
+                    await using a = { async [Symbol.asyncDispose]() { log.push("a"); } };
+                    using b = { [Symbol.dispose]() { log.push("b"); } };
+                    log.push("body");
+                }
+                f();
+                var stack = new AsyncDisposableStack();
+                stack.defer(() => { log.push("stack"); });
+                stack.disposeAsync().then(() => log.push("done"));
+            "#}),
+        TestAction::inspect_context(|ctx| ctx.run_jobs().unwrap()),
+        TestAction::assert(r#"arrayEquals(log, ["body", "b", "a", "stack", "done"])"#),
+        TestAction::assert("stack.disposed"),
+    ]);
+}
@@ -26,6 +26,7 @@ use crate::{
 use boa_ast::{
     operations::{contains, contains_arguments, ContainsSymbol},
     scope::Scope,
+    Script,
 };
 use boa_gc::Gc;
 use boa_parser::{Parser, Source};
@@ -74,7 +75,7 @@ impl Eval {
         x: &JsValue,
         direct: bool,
         lexical_scope: Option<Scope>,
-        mut strict: bool,
+        strict: bool,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         bitflags::bitflags! {
@@ -88,14 +89,6 @@ impl Eval {
             }
         }
 
-        /// Possible actions that can be executed after exiting this function to restore the environment to its
-        /// original state.
-        #[derive(Debug)]
-        enum EnvStackAction {
-            Truncate(usize),
-            Restore(Vec<Environment>),
-        }
-
         // 1. Assert: If direct is false, then strictCaller is also false.
         debug_assert!(direct || !strict);
 
@@ -128,7 +121,7 @@ impl Eval {
         if strict {
             parser.set_strict();
         }
-        let (mut body, source) = parser.parse_eval(direct, context.interner_mut())?;
+        let (body, source) = parser.parse_eval(direct, context.interner_mut())?;
 
         // 6. Let inFunction be false.
         // 7. Let inMethod be false.
@@ -193,6 +186,31 @@ impl Eval {
                 .into());
         }
 
+        Self::evaluate_body(body, source, direct, lexical_scope, strict, context)
+    }
+
+    /// Compiles and runs the already parsed `body` of an eval call in the current realm.
+    ///
+    /// Contains the steps 12 to 33 of [`PerformEval`][spec], which are shared with
+    /// `PerformShadowRealmEval`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performeval
+    pub(crate) fn evaluate_body(
+        mut body: Script,
+        source: boa_ast::SourceText,
+        direct: bool,
+        lexical_scope: Option<Scope>,
+        mut strict: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        /// Possible actions that can be executed after exiting this function to restore the environment to its
+        /// original state.
+        #[derive(Debug)]
+        enum EnvStackAction {
+            Truncate(usize),
+            Restore(Vec<Environment>),
+        }
+
         strict |= body.strict();
 
         // Because our environment model does not map directly to the spec, this section looks very different.
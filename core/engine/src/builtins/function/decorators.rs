@@ -0,0 +1,886 @@
+//! Boa's implementation of the runtime semantics of the decorators proposal.
+//!
+//! Decorators of a class and of its elements are collected in a [`ClassDecorations`] record while
+//! the class is being defined, and applied once all of its elements have been defined.
+//!
+//! More information:
+//!  - [TC39 proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-decorators/
+
+use crate::{
+    builtins::{
+        function::{ClassFieldDefinition, OrdinaryFunction},
+        OrdinaryObject,
+    },
+    error::JsNativeError,
+    js_string,
+    native_function::NativeFunction,
+    object::{
+        internal_methods::InternalMethodContext, FunctionObjectBuilder, JsFunction, JsObject,
+        PrivateElement, PrivateName,
+    },
+    property::{PropertyDescriptor, PropertyKey},
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsResult, JsString, JsValue,
+};
+use boa_gc::{empty_trace, Finalize, Gc, GcRefCell, Trace};
+use boa_macros::js_str;
+use thin_vec::ThinVec;
+
+/// The kind of a decorated class element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ClassElementKind {
+    Method,
+    Getter,
+    Setter,
+    Field,
+    Accessor,
+}
+
+impl ClassElementKind {
+    /// The bit of an encoded element kind that marks static elements.
+    const STATIC: u32 = 0b1000;
+
+    /// Encodes the kind of an element and whether it is static into an opcode operand.
+    pub(crate) const fn encode(self, is_static: bool) -> u32 {
+        let kind = self as u32;
+        if is_static {
+            kind | Self::STATIC
+        } else {
+            kind
+        }
+    }
+
+    /// Decodes an opcode operand created with [`ClassElementKind::encode`].
+    pub(crate) fn decode(value: u32) -> (Self, bool) {
+        let kind = match value & !Self::STATIC {
+            0 => Self::Method,
+            1 => Self::Getter,
+            2 => Self::Setter,
+            3 => Self::Field,
+            4 => Self::Accessor,
+            _ => unreachable!("invalid class element kind"),
+        };
+        (kind, value & Self::STATIC != 0)
+    }
+
+    /// Returns the value of the `kind` property of the decorator context object.
+    fn name(self) -> JsString {
+        match self {
+            Self::Method => js_string!("method"),
+            Self::Getter => js_string!("getter"),
+            Self::Setter => js_string!("setter"),
+            Self::Field => js_string!("field"),
+            Self::Accessor => js_string!("accessor"),
+        }
+    }
+}
+
+/// The name of a decorated class element.
+#[derive(Debug, Clone, Finalize)]
+pub(crate) enum ElementKey {
+    Public(PropertyKey),
+    Private(PrivateName),
+}
+
+// SAFETY: Property keys and private names do not contain any garbage collected values.
+unsafe impl Trace for ElementKey {
+    empty_trace!();
+}
+
+impl ElementKey {
+    /// Returns the value of the `name` property of the decorator context object.
+    fn to_value(&self) -> JsValue {
+        match self {
+            Self::Public(key) => key.clone().into(),
+            Self::Private(name) => js_string!(js_str!("#"), name.description()).into(),
+        }
+    }
+}
+
+/// A class element that has decorators.
+#[derive(Debug, Trace, Finalize)]
+struct DecoratedElement {
+    #[unsafe_ignore_trace]
+    kind: ClassElementKind,
+    is_static: bool,
+    key: ElementKey,
+    decorators: Vec<JsValue>,
+
+    /// The index of the definition in the `[[Fields]]` of the class, for instance fields and
+    /// auto-accessors.
+    field_index: Option<usize>,
+
+    /// The initializers returned by the decorators of fields and auto-accessors.
+    initializers: ThinVec<JsFunction>,
+
+    /// The initializers added with `context.addInitializer` by the decorators of fields.
+    extra_initializers: ThinVec<JsFunction>,
+}
+
+/// The state of a single decorator call, shared with its `context.addInitializer` function.
+#[derive(Debug, Default, Trace, Finalize)]
+struct DecorationState {
+    finished: bool,
+    initializers: ThinVec<JsFunction>,
+}
+
+/// The decorators of a class and of its elements, collected while the class is being defined.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct ClassDecorations {
+    class: JsObject,
+    metadata: JsObject,
+    elements: Vec<DecoratedElement>,
+    class_initializers: ThinVec<JsFunction>,
+}
+
+impl ClassDecorations {
+    /// Creates the decorations record of `class`, along with its metadata object.
+    ///
+    /// The metadata object inherits from the metadata of `superclass`, if it has any.
+    pub(crate) fn new(
+        class: JsObject,
+        superclass: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let parent = match superclass.as_object() {
+            Some(superclass) => superclass
+                .get(JsSymbol::metadata(), context)?
+                .as_object()
+                .cloned(),
+            None => None,
+        };
+
+        Ok(Self {
+            class,
+            metadata: JsObject::from_proto_and_data(parent, OrdinaryObject),
+            elements: Vec::new(),
+            class_initializers: ThinVec::new(),
+        })
+    }
+
+    /// Returns the class being decorated.
+    pub(crate) const fn class(&self) -> &JsObject {
+        &self.class
+    }
+
+    /// Records the decorators of a class element that has just been defined.
+    pub(crate) fn push_element(
+        &mut self,
+        kind: ClassElementKind,
+        is_static: bool,
+        key: ElementKey,
+        decorators: Vec<JsValue>,
+    ) {
+        // The definition of instance fields and of the storage of instance auto-accessors is
+        // always the last one pushed to the class.
+        let field_index =
+            if !is_static && matches!(kind, ClassElementKind::Field | ClassElementKind::Accessor) {
+                self.class
+                    .downcast_ref::<OrdinaryFunction>()
+                    .expect("class must be function object")
+                    .get_fields()
+                    .len()
+                    .checked_sub(1)
+            } else {
+                None
+            };
+
+        self.elements.push(DecoratedElement {
+            kind,
+            is_static,
+            key,
+            decorators,
+            field_index,
+            initializers: ThinVec::new(),
+            extra_initializers: ThinVec::new(),
+        });
+    }
+
+    /// Applies the decorators of the class elements, then the decorators of the class itself.
+    ///
+    /// Returns the decorated class, after running the initializers added by the decorators of
+    /// static methods and accessors.
+    pub(crate) fn apply(
+        decorations: &JsObject,
+        class_decorators: &[JsValue],
+        class_name: &JsString,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        let (class, metadata, mut elements) = {
+            let mut state = decorations
+                .downcast_mut::<Self>()
+                .expect("must be class decorations");
+            (
+                state.class.clone(),
+                state.metadata.clone(),
+                std::mem::take(&mut state.elements),
+            )
+        };
+        let prototype = class
+            .get(js_string!("prototype"), context)?
+            .as_object()
+            .cloned()
+            .expect("class prototype must be an object");
+
+        // Methods and accessors are decorated before fields, and static elements before instance
+        // elements.
+        let mut static_initializers = ThinVec::new();
+        let mut instance_initializers = ThinVec::new();
+        for (fields, is_static) in [(false, true), (false, false), (true, true), (true, false)] {
+            for element in elements.iter_mut().filter(|e| {
+                e.is_static == is_static && (e.kind == ClassElementKind::Field) == fields
+            }) {
+                let (home, method_initializers) = if is_static {
+                    (&class, &mut static_initializers)
+                } else {
+                    (&prototype, &mut instance_initializers)
+                };
+                apply_element_decorators(
+                    element,
+                    &class,
+                    home,
+                    &metadata,
+                    method_initializers,
+                    context,
+                )?;
+            }
+        }
+
+        class.define_property_or_throw(
+            JsSymbol::metadata(),
+            PropertyDescriptor::builder()
+                .value(metadata.clone())
+                .writable(false)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+
+        {
+            let mut function = class
+                .downcast_mut::<OrdinaryFunction>()
+                .expect("class must be function object");
+            for initializer in instance_initializers {
+                function.push_initializer(initializer);
+            }
+            for element in &elements {
+                let Some(index) = element.field_index else {
+                    continue;
+                };
+                if element.initializers.is_empty() && element.extra_initializers.is_empty() {
+                    continue;
+                }
+                let field = &mut function.fields_mut()[index];
+                *field = ClassFieldDefinition::Decorated {
+                    field: Box::new(field.clone()),
+                    initializers: element.initializers.clone(),
+                    extra_initializers: element.extra_initializers.clone(),
+                };
+            }
+        }
+
+        let mut decorated_class = class.clone();
+        let mut class_initializers = ThinVec::new();
+        let name = if class_name.is_empty() {
+            JsValue::undefined()
+        } else {
+            class_name.clone().into()
+        };
+        for decorator in class_decorators.iter().rev() {
+            let state = Gc::new(GcRefCell::new(DecorationState::default()));
+            let context_object = create_context_object(
+                js_string!("class"),
+                name.clone(),
+                None,
+                &state,
+                &metadata,
+                context,
+            );
+            let result = call_decorator(
+                decorator,
+                decorated_class.clone().into(),
+                context_object,
+                &state,
+                &mut class_initializers,
+                context,
+            )?;
+            if !result.is_undefined() {
+                decorated_class = result.as_callable().cloned().ok_or_else(|| {
+                    JsNativeError::typ()
+                        .with_message("class decorators must return a function or undefined")
+                })?;
+            }
+        }
+
+        {
+            let mut state = decorations
+                .downcast_mut::<Self>()
+                .expect("must be class decorations");
+            state.elements = elements;
+            state.class_initializers = class_initializers;
+        }
+
+        for initializer in static_initializers {
+            initializer.call(&class.clone().into(), &[], context)?;
+        }
+
+        Ok(decorated_class)
+    }
+
+    /// Passes the value of a static field through the initializers returned by its decorators.
+    pub(crate) fn apply_field_initializers(
+        decorations: &JsObject,
+        index: usize,
+        mut value: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let (class, initializers) = {
+            let state = decorations
+                .downcast_ref::<Self>()
+                .expect("must be class decorations");
+            (
+                state.class.clone(),
+                state.elements[index].initializers.clone(),
+            )
+        };
+
+        for initializer in initializers {
+            value = initializer.call(&class.clone().into(), &[value], context)?;
+        }
+        Ok(value)
+    }
+
+    /// Runs the initializers added by the decorators of a static field after it was defined.
+    pub(crate) fn run_field_extra_initializers(
+        decorations: &JsObject,
+        index: usize,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let (class, initializers) = {
+            let state = decorations
+                .downcast_ref::<Self>()
+                .expect("must be class decorations");
+            (
+                state.class.clone(),
+                state.elements[index].extra_initializers.clone(),
+            )
+        };
+
+        for initializer in initializers {
+            initializer.call(&class.clone().into(), &[], context)?;
+        }
+        Ok(())
+    }
+
+    /// Runs the initializers added by the class decorators, once the class is fully defined.
+    pub(crate) fn run_class_initializers(
+        decorations: &JsObject,
+        class: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let initializers = decorations
+            .downcast_ref::<Self>()
+            .expect("must be class decorations")
+            .class_initializers
+            .clone();
+
+        for initializer in initializers {
+            initializer.call(class, &[], context)?;
+        }
+        Ok(())
+    }
+}
+
+/// Applies the decorators of a class element, replacing its definition with the decorated one.
+///
+/// The initializers added by the decorators of methods and accessors are pushed to
+/// `method_initializers`.
+fn apply_element_decorators(
+    element: &mut DecoratedElement,
+    class: &JsObject,
+    home: &JsObject,
+    metadata: &JsObject,
+    method_initializers: &mut ThinVec<JsFunction>,
+    context: &mut Context,
+) -> JsResult<()> {
+    let kind = element.kind;
+    let (mut method, mut setter) = get_element_functions(element, class, home, context)?;
+
+    for decorator in element.decorators.iter().rev() {
+        let state = Gc::new(GcRefCell::new(DecorationState::default()));
+        let context_object = create_context_object(
+            kind.name(),
+            element.key.to_value(),
+            Some((kind, element.is_static, &element.key)),
+            &state,
+            metadata,
+            context,
+        );
+
+        let value = match kind {
+            ClassElementKind::Method | ClassElementKind::Getter => {
+                method.clone().map_or_else(JsValue::undefined, Into::into)
+            }
+            ClassElementKind::Setter => setter.clone().map_or_else(JsValue::undefined, Into::into),
+            ClassElementKind::Field => JsValue::undefined(),
+            ClassElementKind::Accessor => {
+                let value = JsObject::with_object_proto(context.intrinsics());
+                value.create_data_property_or_throw(
+                    js_string!("get"),
+                    method.clone().map_or_else(JsValue::undefined, Into::into),
+                    context,
+                )?;
+                value.create_data_property_or_throw(
+                    js_string!("set"),
+                    setter.clone().map_or_else(JsValue::undefined, Into::into),
+                    context,
+                )?;
+                value.into()
+            }
+        };
+
+        let extra_initializers = if kind == ClassElementKind::Field {
+            &mut element.extra_initializers
+        } else {
+            &mut *method_initializers
+        };
+        let result = call_decorator(
+            decorator,
+            value,
+            context_object,
+            &state,
+            extra_initializers,
+            context,
+        )?;
+
+        match kind {
+            ClassElementKind::Method | ClassElementKind::Getter | ClassElementKind::Setter => {
+                if result.is_undefined() {
+                    continue;
+                }
+                let function = result.as_callable().cloned().ok_or_else(|| {
+                    JsNativeError::typ()
+                        .with_message("method decorators must return a function or undefined")
+                })?;
+                if kind == ClassElementKind::Setter {
+                    setter = Some(function);
+                } else {
+                    method = Some(function);
+                }
+            }
+            ClassElementKind::Field => {
+                if result.is_undefined() {
+                    continue;
+                }
+                let initializer = result.as_callable().cloned().ok_or_else(|| {
+                    JsNativeError::typ()
+                        .with_message("field decorators must return a function or undefined")
+                })?;
+                element
+                    .initializers
+                    .push(JsFunction::from_object_unchecked(initializer));
+            }
+            ClassElementKind::Accessor => {
+                if result.is_undefined() {
+                    continue;
+                }
+                let Some(result) = result.as_object() else {
+                    return Err(JsNativeError::typ()
+                        .with_message("accessor decorators must return an object or undefined")
+                        .into());
+                };
+                if let Some(get) = get_optional_function(result, js_string!("get"), context)? {
+                    method = Some(get);
+                }
+                if let Some(set) = get_optional_function(result, js_string!("set"), context)? {
+                    setter = Some(set);
+                }
+                if let Some(init) = get_optional_function(result, js_string!("init"), context)? {
+                    element
+                        .initializers
+                        .push(JsFunction::from_object_unchecked(init));
+                }
+            }
+        }
+    }
+
+    if kind != ClassElementKind::Field {
+        set_element_functions(element, class, home, method, setter, context)?;
+    }
+
+    Ok(())
+}
+
+/// Calls a decorator with the decorated value and its context object.
+///
+/// The initializers added by the decorator are pushed to `extra_initializers`.
+fn call_decorator(
+    decorator: &JsValue,
+    value: JsValue,
+    context_object: JsObject,
+    state: &Gc<GcRefCell<DecorationState>>,
+    extra_initializers: &mut ThinVec<JsFunction>,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let decorator = decorator
+        .as_callable()
+        .ok_or_else(|| JsNativeError::typ().with_message("decorators must be functions"))?;
+    let result = decorator.call(
+        &JsValue::undefined(),
+        &[value, context_object.into()],
+        context,
+    );
+
+    let mut state = state.borrow_mut();
+    state.finished = true;
+    extra_initializers.extend(state.initializers.drain(..));
+
+    result
+}
+
+/// Gets the optional function property `key` of the object returned by an accessor decorator.
+fn get_optional_function(
+    object: &JsObject,
+    key: JsString,
+    context: &mut Context,
+) -> JsResult<Option<JsObject>> {
+    let value = object.get(key, context)?;
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    value.as_callable().cloned().map(Some).ok_or_else(|| {
+        JsNativeError::typ()
+            .with_message("accessor decorators must return functions or undefined")
+            .into()
+    })
+}
+
+/// Creates the context object passed as the second argument to decorators.
+///
+/// `element` is `None` for class decorators.
+fn create_context_object(
+    kind: JsString,
+    name: JsValue,
+    element: Option<(ClassElementKind, bool, &ElementKey)>,
+    state: &Gc<GcRefCell<DecorationState>>,
+    metadata: &JsObject,
+    context: &mut Context,
+) -> JsObject {
+    let object = JsObject::with_object_proto(context.intrinsics());
+    object
+        .create_data_property_or_throw(js_string!("kind"), kind, context)
+        .expect("cannot fail for a new object");
+
+    if let Some((kind, is_static, key)) = element {
+        let access = JsObject::with_object_proto(context.intrinsics());
+        if kind != ClassElementKind::Setter {
+            let get = access_function(AccessFunction::Get, key, context);
+            access
+                .create_data_property_or_throw(js_string!("get"), get, context)
+                .expect("cannot fail for a new object");
+        }
+        if matches!(
+            kind,
+            ClassElementKind::Setter | ClassElementKind::Field | ClassElementKind::Accessor
+        ) {
+            let set = access_function(AccessFunction::Set, key, context);
+            access
+                .create_data_property_or_throw(js_string!("set"), set, context)
+                .expect("cannot fail for a new object");
+        }
+        let has = access_function(AccessFunction::Has, key, context);
+        access
+            .create_data_property_or_throw(js_string!("has"), has, context)
+            .expect("cannot fail for a new object");
+
+        object
+            .create_data_property_or_throw(js_string!("access"), access, context)
+            .expect("cannot fail for a new object");
+        object
+            .create_data_property_or_throw(js_string!("static"), is_static, context)
+            .expect("cannot fail for a new object");
+        object
+            .create_data_property_or_throw(
+                js_string!("private"),
+                matches!(key, ElementKey::Private(_)),
+                context,
+            )
+            .expect("cannot fail for a new object");
+    }
+
+    object
+        .create_data_property_or_throw(js_string!("name"), name, context)
+        .expect("cannot fail for a new object");
+
+    let add_initializer = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, args, state: &Gc<GcRefCell<DecorationState>>, _| {
+                let mut state = state.borrow_mut();
+                if state.finished {
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot add initializers after decoration has finished")
+                        .into());
+                }
+                let initializer = args.get_or_undefined(0).as_callable().ok_or_else(|| {
+                    JsNativeError::typ().with_message("initializers must be functions")
+                })?;
+                state
+                    .initializers
+                    .push(JsFunction::from_object_unchecked(initializer.clone()));
+                Ok(JsValue::undefined())
+            },
+            state.clone(),
+        ),
+    )
+    .name(js_string!("addInitializer"))
+    .length(1)
+    .build();
+    object
+        .create_data_property_or_throw(js_string!("addInitializer"), add_initializer, context)
+        .expect("cannot fail for a new object");
+
+    object
+        .create_data_property_or_throw(js_string!("metadata"), metadata.clone(), context)
+        .expect("cannot fail for a new object");
+
+    object
+}
+
+/// The functions of the `access` object of decorator context objects.
+#[derive(Debug, Clone, Copy, Trace, Finalize)]
+#[boa_gc(empty_trace)]
+enum AccessFunction {
+    Get,
+    Set,
+    Has,
+}
+
+/// The captures of the functions of the `access` object of decorator context objects.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct AccessCaptures {
+    function: AccessFunction,
+    key: ElementKey,
+}
+
+/// Creates a function of the `access` object of a decorator context object.
+fn access_function(
+    function: AccessFunction,
+    key: &ElementKey,
+    context: &mut Context,
+) -> JsFunction {
+    let (name, length) = match function {
+        AccessFunction::Get => (js_string!("get"), 1),
+        AccessFunction::Set => (js_string!("set"), 2),
+        AccessFunction::Has => (js_string!("has"), 1),
+    };
+
+    FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, args, captures: &AccessCaptures, context| {
+                let object = args.get_or_undefined(0).as_object().ok_or_else(|| {
+                    JsNativeError::typ().with_message("decorator access target must be an object")
+                })?;
+
+                match (captures.function, &captures.key) {
+                    (AccessFunction::Get, ElementKey::Public(key)) => {
+                        object.get(key.clone(), context)
+                    }
+                    (AccessFunction::Get, ElementKey::Private(name)) => {
+                        object.private_get(name, context)
+                    }
+                    (AccessFunction::Set, ElementKey::Public(key)) => {
+                        object.set(key.clone(), args.get_or_undefined(1).clone(), true, context)?;
+                        Ok(JsValue::undefined())
+                    }
+                    (AccessFunction::Set, ElementKey::Private(name)) => {
+                        object.private_set(name, args.get_or_undefined(1).clone(), context)?;
+                        Ok(JsValue::undefined())
+                    }
+                    (AccessFunction::Has, ElementKey::Public(key)) => {
+                        Ok(object.has_property(key.clone(), context)?.into())
+                    }
+                    (AccessFunction::Has, ElementKey::Private(name)) => Ok(object
+                        .private_element_find(name, true, true)
+                        .is_some()
+                        .into()),
+                }
+            },
+            AccessCaptures {
+                function,
+                key: key.clone(),
+            },
+        ),
+    )
+    .name(name)
+    .length(length)
+    .build()
+}
+
+/// Gets the current functions of a decorated method, getter, setter or accessor.
+///
+/// Returns the method or getter, and the setter.
+fn get_element_functions(
+    element: &DecoratedElement,
+    class: &JsObject,
+    home: &JsObject,
+    context: &mut Context,
+) -> JsResult<(Option<JsObject>, Option<JsObject>)> {
+    if element.kind == ClassElementKind::Field {
+        return Ok((None, None));
+    }
+
+    let name = match &element.key {
+        ElementKey::Public(key) => {
+            let Some(desc) =
+                home.__get_own_property__(key, &mut InternalMethodContext::new(context))?
+            else {
+                return Ok((None, None));
+            };
+            let method = match element.kind {
+                ClassElementKind::Method => desc.value().and_then(JsValue::as_object).cloned(),
+                _ => desc.get().and_then(JsValue::as_object).cloned(),
+            };
+            return Ok((method, desc.set().and_then(JsValue::as_object).cloned()));
+        }
+        ElementKey::Private(name) => name,
+    };
+
+    let functions = if element.is_static {
+        find_private_element(
+            class.borrow_mut().private_elements_mut(),
+            name,
+            element.kind,
+        )
+    } else {
+        find_private_element(
+            class
+                .downcast_mut::<OrdinaryFunction>()
+                .expect("class must be function object")
+                .private_methods_mut(),
+            name,
+            element.kind,
+        )
+    };
+
+    Ok(functions)
+}
+
+/// Replaces the functions of a decorated method, getter, setter or accessor.
+fn set_element_functions(
+    element: &DecoratedElement,
+    class: &JsObject,
+    home: &JsObject,
+    method: Option<JsObject>,
+    setter: Option<JsObject>,
+    context: &mut Context,
+) -> JsResult<()> {
+    let name = match &element.key {
+        ElementKey::Public(key) => {
+            let desc = match element.kind {
+                ClassElementKind::Method => PropertyDescriptor::builder().maybe_value(method),
+                ClassElementKind::Getter => PropertyDescriptor::builder().maybe_get(method),
+                ClassElementKind::Setter => PropertyDescriptor::builder().maybe_set(setter),
+                ClassElementKind::Accessor => PropertyDescriptor::builder()
+                    .maybe_get(method)
+                    .maybe_set(setter),
+                ClassElementKind::Field => return Ok(()),
+            };
+            home.define_property_or_throw(key.clone(), desc, context)?;
+            return Ok(());
+        }
+        ElementKey::Private(name) => name,
+    };
+
+    if element.is_static {
+        replace_private_element(
+            class.borrow_mut().private_elements_mut(),
+            name,
+            element.kind,
+            method,
+            setter,
+        );
+    } else {
+        replace_private_element(
+            class
+                .downcast_mut::<OrdinaryFunction>()
+                .expect("class must be function object")
+                .private_methods_mut(),
+            name,
+            element.kind,
+            method,
+            setter,
+        );
+    }
+
+    Ok(())
+}
+
+/// Returns `true` if `element` is the private method or accessor of the given kind.
+fn is_private_element_of_kind(element: &PrivateElement, kind: ClassElementKind) -> bool {
+    match (element, kind) {
+        (PrivateElement::Method(_), ClassElementKind::Method)
+        | (PrivateElement::Accessor { .. }, ClassElementKind::Accessor) => true,
+        (PrivateElement::Accessor { getter, .. }, ClassElementKind::Getter) => getter.is_some(),
+        (PrivateElement::Accessor { setter, .. }, ClassElementKind::Setter) => setter.is_some(),
+        _ => false,
+    }
+}
+
+/// Finds the private method or accessor `name` of the given kind in a list of private elements.
+///
+/// Returns the method or getter, and the setter.
+fn find_private_element(
+    elements: &[(PrivateName, PrivateElement)],
+    name: &PrivateName,
+    kind: ClassElementKind,
+) -> (Option<JsObject>, Option<JsObject>) {
+    elements
+        .iter()
+        .find(|(key, element)| key == name && is_private_element_of_kind(element, kind))
+        .map_or((None, None), |(_, element)| match element {
+            PrivateElement::Method(method) => (Some(method.clone()), None),
+            PrivateElement::Accessor { getter, setter } => (getter.clone(), setter.clone()),
+            PrivateElement::Field(_) => (None, None),
+        })
+}
+
+/// Replaces the private method or accessor `name` of the given kind in a list of private elements.
+#[allow(clippy::similar_names)]
+fn replace_private_element(
+    elements: &mut [(PrivateName, PrivateElement)],
+    name: &PrivateName,
+    kind: ClassElementKind,
+    method: Option<JsObject>,
+    setter: Option<JsObject>,
+) {
+    let Some((_, element)) = elements
+        .iter_mut()
+        .find(|(key, element)| key == name && is_private_element_of_kind(element, kind))
+    else {
+        return;
+    };
+
+    match element {
+        PrivateElement::Method(current) => {
+            if let Some(method) = method {
+                *current = method;
+            }
+        }
+        PrivateElement::Accessor {
+            getter: current_getter,
+            setter: current_setter,
+        } => {
+            if kind != ClassElementKind::Setter && method.is_some() {
+                *current_getter = method;
+            }
+            if kind != ClassElementKind::Getter && setter.is_some() {
+                *current_setter = setter;
+            }
+        }
+        PrivateElement::Field(_) => {}
+    }
+}
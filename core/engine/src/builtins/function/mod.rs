@@ -55,6 +55,7 @@ use super::Proxy;
 
 pub(crate) mod arguments;
 mod bound;
+pub(crate) mod decorators;
 
 pub use bound::BoundFunction;
 
@@ -142,6 +143,22 @@ pub enum ClassFieldDefinition {
 
     /// A class field definition with a private name.
     Private(PrivateName, JsFunction),
+
+    /// A decorated class field definition.
+    ///
+    /// The value produced by the field initializer is passed through the `initializers` returned by
+    /// its decorators before being defined, and the `extra_initializers` added by the decorators
+    /// are called after the field is defined.
+    Decorated {
+        /// The undecorated field definition.
+        field: Box<ClassFieldDefinition>,
+
+        /// The initializers returned by the decorators of the field.
+        initializers: ThinVec<JsFunction>,
+
+        /// The initializers added with `context.addInitializer` by the decorators of the field.
+        extra_initializers: ThinVec<JsFunction>,
+    },
 }
 
 unsafe impl Trace for ClassFieldDefinition {
@@ -153,6 +170,15 @@ unsafe impl Trace for ClassFieldDefinition {
             Self::Private(_, func) => {
                 mark(func);
             }
+            Self::Decorated {
+                field,
+                initializers,
+                extra_initializers,
+            } => {
+                mark(field);
+                mark(initializers);
+                mark(extra_initializers);
+            }
         }
     }}
 }
@@ -185,6 +211,9 @@ pub struct OrdinaryFunction {
 
     /// The `[[PrivateMethods]]` internal slot.
     private_methods: ThinVec<(PrivateName, PrivateElement)>,
+
+    /// The `[[Initializers]]` internal slot.
+    initializers: ThinVec<JsFunction>,
 }
 
 impl JsData for OrdinaryFunction {
@@ -223,6 +252,7 @@ impl OrdinaryFunction {
             realm,
             fields: ThinVec::default(),
             private_methods: ThinVec::default(),
+            initializers: ThinVec::default(),
         }
     }
 
@@ -262,6 +292,11 @@ impl OrdinaryFunction {
         &self.fields
     }
 
+    /// Returns a mutable reference to the values of the `[[Fields]]` internal slot.
+    pub(crate) fn fields_mut(&mut self) -> &mut [ClassFieldDefinition] {
+        &mut self.fields
+    }
+
     /// Pushes a value to the `[[Fields]]` internal slot if present.
     pub(crate) fn push_field(
         &mut self,
@@ -288,6 +323,21 @@ impl OrdinaryFunction {
         self.private_methods.push((name, method));
     }
 
+    /// Returns a mutable reference to the values of the `[[PrivateMethods]]` internal slot.
+    pub(crate) fn private_methods_mut(&mut self) -> &mut [(PrivateName, PrivateElement)] {
+        &mut self.private_methods
+    }
+
+    /// Returns the values of the `[[Initializers]]` internal slot.
+    pub(crate) fn get_initializers(&self) -> &[JsFunction] {
+        &self.initializers
+    }
+
+    /// Pushes an initializer to the `[[Initializers]]` internal slot.
+    pub(crate) fn push_initializer(&mut self, initializer: JsFunction) {
+        self.initializers.push(initializer);
+    }
+
     /// Gets the `Realm` from where this function originates.
     #[must_use]
     pub const fn realm(&self) -> &Realm {
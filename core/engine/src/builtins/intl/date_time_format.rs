@@ -9,8 +9,13 @@
 
 use crate::{
     builtins::{
-        options::OptionType, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
-        OrdinaryObject,
+        intl::options::coerce_options_to_object,
+        options::OptionType,
+        time_zone::{
+            format_offset_time_zone_identifier, get_available_named_time_zone_identifier,
+            parse_utc_offset, system_time_zone_identifier,
+        },
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
     },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
@@ -18,7 +23,7 @@ use crate::{
     object::{internal_methods::get_prototype_from_constructor, JsObject},
     realm::Realm,
     string::StaticJsStrings,
-    Context, JsData, JsResult, JsString, JsValue,
+    Context, JsArgs, JsData, JsResult, JsString, JsValue,
 };
 
 use boa_gc::{Finalize, Trace};
@@ -81,7 +86,7 @@ impl BuiltInConstructor for DateTimeFormat {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat
     fn constructor(
         new_target: &JsValue,
-        _args: &[JsValue],
+        args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
@@ -104,6 +109,8 @@ impl BuiltInConstructor for DateTimeFormat {
             StandardConstructors::date_time_format,
             context,
         )?;
+        // Only the time zone steps of `InitializeDateTimeFormat` are implemented for now.
+        let time_zone = get_time_zone_option(args.get_or_undefined(1), context)?;
         // 2. Let dateTimeFormat be ? OrdinaryCreateFromConstructor(newTarget, "%DateTimeFormat.prototype%",
         // « [[InitializedDateTimeFormat]], [[Locale]], [[Calendar]], [[NumberingSystem]], [[TimeZone]], [[Weekday]],
         // [[Era]], [[Year]], [[Month]], [[Day]], [[DayPeriod]], [[Hour]], [[Minute]], [[Second]],
@@ -116,7 +123,7 @@ impl BuiltInConstructor for DateTimeFormat {
                 locale: js_string!("en-US"),
                 calendar: js_string!("gregory"),
                 numbering_system: js_string!("arab"),
-                time_zone: js_string!("UTC"),
+                time_zone,
                 weekday: js_string!("narrow"),
                 era: js_string!("narrow"),
                 year: js_string!("numeric"),
@@ -144,6 +151,66 @@ impl BuiltInConstructor for DateTimeFormat {
     }
 }
 
+/// The time zone steps of [`InitializeDateTimeFormat ( dateTimeFormat, locales, options )`][spec].
+///
+/// Named time zones keep the identifier provided by the user, only normalizing its case, so
+/// aliases like `Asia/Calcutta` are not replaced by their primary identifier.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-createdatetimeformat
+fn get_time_zone_option(options: &JsValue, context: &mut Context) -> JsResult<JsString> {
+    // Set options to ? CoerceOptionsToObject(options).
+    let options = coerce_options_to_object(options, context)?;
+
+    // Let timeZone be ? Get(options, "timeZone").
+    let time_zone = options.get(js_string!("timeZone"), context)?;
+
+    // If timeZone is undefined, then
+    if time_zone.is_undefined() {
+        // a. Set timeZone to SystemTimeZoneIdentifier().
+        return Ok(JsString::from(
+            system_time_zone_identifier(context).as_str(),
+        ));
+    }
+
+    // Else, set timeZone to ? ToString(timeZone).
+    let time_zone = time_zone.to_string(context)?.to_std_string_escaped();
+
+    // If IsTimeZoneOffsetString(timeZone) is true, then
+    if let Some((offset_minutes, sub_minute)) = parse_utc_offset(&time_zone) {
+        // a. Let parseResult be ParseText(StringToCodePoints(timeZone), UTCOffset).
+        // b. Assert: parseResult is a Parse Node.
+        // c. If parseResult contains more than one MinuteSecond Parse Node, throw a RangeError exception.
+        if sub_minute {
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "Intl.DateTimeFormat: `{time_zone}` must not have seconds"
+                ))
+                .into());
+        }
+
+        // d. Let offsetNanoseconds be ParseTimeZoneOffsetString(timeZone).
+        // e. Let offsetMinutes be offsetNanoseconds / (6 × 10^10).
+        // f. Assert: offsetMinutes is an integer.
+        // g. Set timeZone to FormatOffsetTimeZoneIdentifier(offsetMinutes).
+        return Ok(JsString::from(
+            format_offset_time_zone_identifier(offset_minutes).as_str(),
+        ));
+    }
+
+    // Else,
+    //     a. Let timeZoneIdentifierRecord be GetAvailableNamedTimeZoneIdentifier(timeZone).
+    //     b. If timeZoneIdentifierRecord is empty, throw a RangeError exception.
+    //     c. Set timeZone to timeZoneIdentifierRecord.[[Identifier]].
+    let record =
+        get_available_named_time_zone_identifier(&time_zone, context).ok_or_else(|| {
+            JsNativeError::range().with_message(format!(
+                "Intl.DateTimeFormat: `{time_zone}` is not a supported time zone"
+            ))
+        })?;
+
+    Ok(JsString::from(record.identifier.as_str()))
+}
+
 /// Represents the `required` and `defaults` arguments in the abstract operation
 /// `toDateTimeOptions`.
 ///
@@ -310,3 +377,52 @@ impl OptionType for HourCycle {
         }
     }
 }
+
+#[cfg(all(test, feature = "intl_bundled"))]
+mod tests {
+    use super::DateTimeFormat;
+    use crate::{run_test_actions, Context, JsNativeErrorKind, JsValue, TestAction};
+
+    fn time_zone(value: &JsValue) -> String {
+        value
+            .as_object()
+            .and_then(|o| {
+                o.downcast_ref::<DateTimeFormat>()
+                    .map(|dtf| dtf.time_zone.clone())
+            })
+            .expect("must be an `Intl.DateTimeFormat` object")
+            .to_std_string_escaped()
+    }
+
+    #[test]
+    fn time_zone_option() {
+        run_test_actions([
+            TestAction::assert_with_op(
+                "new Intl.DateTimeFormat('en', { timeZone: 'asia/calcutta' })",
+                |value, _: &mut Context| time_zone(&value) == "Asia/Calcutta",
+            ),
+            TestAction::assert_with_op(
+                "new Intl.DateTimeFormat('en', { timeZone: 'etc/gmt' })",
+                |value, _: &mut Context| time_zone(&value) == "Etc/GMT",
+            ),
+            TestAction::assert_with_op(
+                "new Intl.DateTimeFormat('en', { timeZone: '+0530' })",
+                |value, _: &mut Context| time_zone(&value) == "+05:30",
+            ),
+            TestAction::assert_with_op(
+                "new Intl.DateTimeFormat('en', { timeZone: '-01' })",
+                |value, _: &mut Context| time_zone(&value) == "-01:00",
+            ),
+            TestAction::assert_native_error(
+                "new Intl.DateTimeFormat('en', { timeZone: '+05:30:15' })",
+                JsNativeErrorKind::Range,
+                "Intl.DateTimeFormat: `+05:30:15` must not have seconds",
+            ),
+            TestAction::assert_native_error(
+                "new Intl.DateTimeFormat('en', { timeZone: 'Asia/Atlantis' })",
+                JsNativeErrorKind::Range,
+                "Intl.DateTimeFormat: `Asia/Atlantis` is not a supported time zone",
+            ),
+        ]);
+    }
+}
@@ -0,0 +1,568 @@
+use boa_gc::{Finalize, Trace};
+use cow_utils::CowUtils;
+use icu_experimental::{
+    dimension::provider::currency_displayname::CurrencyDisplaynameV1,
+    displaynames::{
+        provider::RegionDisplayNamesV1, DisplayNamesOptions, DisplayNamesPreferences,
+        LocaleDisplayNamesFormatter, RegionDisplayNames, ScriptDisplayNames,
+    },
+};
+use icu_locale::{
+    subtags::{Region, Script},
+    LanguageIdentifier, Locale,
+};
+use icu_provider::{
+    DataIdentifierBorrowed, DataLocale, DataMarkerAttributes, DataProvider, DataRequest,
+    DataRequestMetadata,
+};
+
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{
+    locale::{canonicalize_locale_list, filter_locales, resolve_locale},
+    options::IntlOptions,
+    Service,
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(all(test, feature = "intl_bundled"))]
+mod tests;
+
+/// The ICU4X formatter used to get the display names of an `Intl.DisplayNames` object.
+enum DisplayNamesFormatter {
+    Language(Box<LocaleDisplayNamesFormatter>),
+    Region(RegionDisplayNames),
+    Script(ScriptDisplayNames),
+    /// Currency display names are loaded on demand, since ICU4X stores one entry per currency.
+    Currency(DataLocale),
+    // TODO: Missing support from ICU4X for calendar and date time field display names.
+    Unsupported,
+}
+
+impl std::fmt::Debug for DisplayNamesFormatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The ICU4X display names formatters don't implement `Debug`.
+        f.write_str(match self {
+            Self::Language(_) => "Language",
+            Self::Region(_) => "Region",
+            Self::Script(_) => "Script",
+            Self::Currency(_) => "Currency",
+            Self::Unsupported => "Unsupported",
+        })
+    }
+}
+
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `DisplayNames` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct DisplayNames {
+    locale: Locale,
+    style: DisplayNamesStyle,
+    typ: DisplayNamesType,
+    fallback: DisplayNamesFallback,
+    language_display: Option<DisplayNamesLanguageDisplay>,
+    native: DisplayNamesFormatter,
+}
+
+impl Service for DisplayNames {
+    type LangMarker = RegionDisplayNamesV1;
+
+    type LocaleOptions = ();
+}
+
+impl IntrinsicObject for DisplayNames {
+    fn init(realm: &Realm) {
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DisplayNames"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::of, js_string!("of"), 1)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DisplayNames {
+    const NAME: JsString = StaticJsStrings::DISPLAY_NAMES;
+}
+
+impl BuiltInConstructor for DisplayNames {
+    const LENGTH: usize = 2;
+    const P: usize = 2;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::display_names;
+
+    /// Constructor [`Intl.DisplayNames ( locales, options )`][spec].
+    ///
+    /// Constructor for `DisplayNames` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/DisplayNames
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.DisplayNames` constructor without `new`")
+                .into());
+        }
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 2. Let displayNames be ? OrdinaryCreateFromConstructor(NewTarget, "%DisplayNames.prototype%", « [[InitializedDisplayNames]], [[Locale]], [[Style]], [[Type]], [[Fallback]], [[LanguageDisplay]], [[Fields]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::display_names,
+            context,
+        )?;
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. If options is undefined, throw a TypeError exception.
+        if options.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("`Intl.DisplayNames` requires an options object")
+                .into());
+        }
+
+        // 5. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(options)?;
+
+        // 6. Let opt be a new Record.
+        // 7. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        let matcher =
+            get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 8. Set opt.[[localeMatcher]] to matcher.
+        // 9. Let r be ResolveLocale(%DisplayNames%.[[AvailableLocales]], requestedLocales, opt, %DisplayNames%.[[RelevantExtensionKeys]], %DisplayNames%.[[LocaleData]]).
+        let locale = resolve_locale::<Self>(
+            requested_locales,
+            &mut IntlOptions {
+                matcher,
+                ..Default::default()
+            },
+            context.intl_provider(),
+        )?;
+
+        // 10. Let style be ? GetOption(options, "style", string, « "narrow", "short", "long" », "long").
+        // 11. Set displayNames.[[Style]] to style.
+        let style: DisplayNamesStyle =
+            get_option(&options, js_string!("style"), context)?.unwrap_or_default();
+
+        // 12. Let type be ? GetOption(options, "type", string, « "language", "region", "script", "currency", "calendar", "dateTimeField" », undefined).
+        // 13. If type is undefined, throw a TypeError exception.
+        // 14. Set displayNames.[[Type]] to type.
+        let typ: DisplayNamesType =
+            get_option(&options, js_string!("type"), context)?.ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`type` option of `Intl.DisplayNames` is required")
+            })?;
+
+        // 15. Let fallback be ? GetOption(options, "fallback", string, « "code", "none" », "code").
+        // 16. Set displayNames.[[Fallback]] to fallback.
+        let fallback = get_option(&options, js_string!("fallback"), context)?.unwrap_or_default();
+
+        // 17. Set displayNames.[[Locale]] to r.[[Locale]].
+        // 18. Let resolvedLocaleData be r.[[LocaleData]].
+        // 19. Let types be resolvedLocaleData.[[types]].
+        // 20. Assert: types is a Record (see 12.2.3).
+        // 21. Let languageDisplay be ? GetOption(options, "languageDisplay", string, « "dialect", "standard" », "dialect").
+        let language_display: DisplayNamesLanguageDisplay =
+            get_option(&options, js_string!("languageDisplay"), context)?.unwrap_or_default();
+
+        // 22. Let typeFields be types.[[<type>]].
+        // 23. Assert: typeFields is a Record (see 12.2.3).
+        // 24. If type is "language", then
+        //     a. Set displayNames.[[LanguageDisplay]] to languageDisplay.
+        //     b. Let typeFields be typeFields.[[<languageDisplay>]].
+        //     c. Assert: typeFields is a Record (see 12.2.3).
+        // 25. Let styleFields be typeFields.[[<style>]].
+        // 26. Assert: styleFields is a Record (see 12.2.3).
+        // 27. Set displayNames.[[Fields]] to styleFields.
+        let prefs = DisplayNamesPreferences::from(&locale);
+        let mut icu_options = DisplayNamesOptions::default();
+        icu_options.style = Some(style.to_icu());
+        icu_options.language_display = language_display.to_icu();
+        let provider = context.intl_provider().erased_provider();
+        let native = match typ {
+            DisplayNamesType::Language => {
+                LocaleDisplayNamesFormatter::try_new_with_buffer_provider(
+                    provider,
+                    prefs,
+                    icu_options,
+                )
+                .map(|formatter| DisplayNamesFormatter::Language(Box::new(formatter)))
+            }
+            DisplayNamesType::Region => {
+                RegionDisplayNames::try_new_with_buffer_provider(provider, prefs, icu_options)
+                    .map(DisplayNamesFormatter::Region)
+            }
+            DisplayNamesType::Script => {
+                ScriptDisplayNames::try_new_with_buffer_provider(provider, prefs, icu_options)
+                    .map(DisplayNamesFormatter::Script)
+            }
+            DisplayNamesType::Currency => {
+                Ok(DisplayNamesFormatter::Currency(DataLocale::from(&locale)))
+            }
+            DisplayNamesType::Calendar | DisplayNamesType::DateTimeField => {
+                Ok(DisplayNamesFormatter::Unsupported)
+            }
+        }
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        let display_names = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                locale,
+                style,
+                typ,
+                fallback,
+                language_display: (typ == DisplayNamesType::Language).then_some(language_display),
+                native,
+            },
+        );
+
+        // 28. Return displayNames.
+        Ok(display_names.into())
+    }
+}
+
+impl DisplayNames {
+    /// [`Intl.DisplayNames.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in display
+    /// names without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %DisplayNames%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<Self>(requested_locales, options, context).map(JsValue::from)
+    }
+
+    /// [`Intl.DisplayNames.prototype.of ( code )`][spec].
+    ///
+    /// Returns the localized display name of the provided code.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/of
+    fn of(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let display_names = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`of` can only be called on an `Intl.DisplayNames` object")
+        })?;
+        let display_names = display_names.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`of` can only be called on an `Intl.DisplayNames` object")
+        })?;
+
+        // 3. Let code be ? ToString(code).
+        let code = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+
+        // 4. Set code to ? CanonicalCodeForDisplayNames(displayNames.[[Type]], code).
+        let code = canonical_code_for_display_names(display_names.typ, &code, context)?;
+
+        // 5. Let fields be displayNames.[[Fields]].
+        // 6. If fields has a field [[<code>]], return fields.[[<code>]].
+        let name = match &display_names.native {
+            DisplayNamesFormatter::Language(formatter) => code
+                .parse::<Locale>()
+                .ok()
+                .map(|locale| formatter.of(&locale).into_owned())
+                // ICU4X falls back to the code itself if there's no display name for it.
+                .filter(|name| *name != code),
+            DisplayNamesFormatter::Region(names) => code
+                .parse::<Region>()
+                .ok()
+                .and_then(|region| names.of(region))
+                .map(str::to_owned),
+            DisplayNamesFormatter::Script(names) => code
+                .parse::<Script>()
+                .ok()
+                .and_then(|script| names.of(script))
+                .map(str::to_owned),
+            DisplayNamesFormatter::Currency(locale) => DataMarkerAttributes::try_from_str(&code)
+                .ok()
+                .and_then(|attributes| {
+                    DataProvider::<CurrencyDisplaynameV1>::load(
+                        context.intl_provider(),
+                        DataRequest {
+                            id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                                attributes, locale,
+                            ),
+                            metadata: {
+                                let mut metadata = DataRequestMetadata::default();
+                                metadata.silent = true;
+                                metadata
+                            },
+                        },
+                    )
+                    .ok()
+                })
+                .map(|data| data.payload.get().display_name.to_string()),
+            DisplayNamesFormatter::Unsupported => None,
+        };
+        if let Some(name) = name {
+            return Ok(js_string!(name).into());
+        }
+
+        match display_names.fallback {
+            // 7. If displayNames.[[Fallback]] is "code", return code.
+            DisplayNamesFallback::Code => Ok(js_string!(code).into()),
+            // 8. Return undefined.
+            DisplayNamesFallback::None => Ok(JsValue::undefined()),
+        }
+    }
+
+    /// [`Intl.DisplayNames.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and options computed during the
+    /// construction of the current `Intl.DisplayNames` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let display_names = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.DisplayNames` object",
+            )
+        })?;
+        let display_names = display_names.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.DisplayNames` object",
+            )
+        })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. For each row of Table 18, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of displayNames's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options
+            .create_data_property_or_throw(
+                js_string!("locale"),
+                js_string!(display_names.locale.to_string()),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(
+                js_string!("style"),
+                display_names.style.to_js_string(),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(
+                js_string!("type"),
+                display_names.typ.to_js_string(),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(
+                js_string!("fallback"),
+                display_names.fallback.to_js_string(),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        if let Some(language_display) = display_names.language_display {
+            options
+                .create_data_property_or_throw(
+                    js_string!("languageDisplay"),
+                    language_display.to_js_string(),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+}
+
+/// Abstract operation [`CanonicalCodeForDisplayNames ( type, code )`][spec].
+///
+/// Verifies that `code` is a well-formed code for the given `type`, returning its canonical form.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalcodefordisplaynames
+fn canonical_code_for_display_names(
+    typ: DisplayNamesType,
+    code: &str,
+    context: &Context,
+) -> JsResult<String> {
+    match typ {
+        // 1. If type is "language", then
+        DisplayNamesType::Language => {
+            // a. If code cannot be matched by the unicode_language_id Unicode locale nonterminal, throw a RangeError exception.
+            // b. If IsStructurallyValidLanguageTag(code) is false, throw a RangeError exception.
+            let id = (!code.contains('_'))
+                .then(|| LanguageIdentifier::try_from_str(code).ok())
+                .flatten()
+                .ok_or_else(|| {
+                    JsNativeError::range()
+                        .with_message(format!("`{code}` is not a valid language code"))
+                })?;
+
+            // c. Return CanonicalizeUnicodeLocaleId(code).
+            let mut locale = Locale::from(id);
+            context
+                .intl_provider()
+                .locale_canonicalizer()?
+                .canonicalize(&mut locale);
+            Ok(locale.to_string())
+        }
+        // 2. If type is "region", then
+        DisplayNamesType::Region => {
+            // a. If code cannot be matched by the unicode_region_subtag Unicode locale nonterminal, throw a RangeError exception.
+            // b. Return the ASCII-uppercase of code.
+            Region::try_from_str(code)
+                .map(|region| region.as_str().to_owned())
+                .map_err(|_| {
+                    JsNativeError::range()
+                        .with_message(format!("`{code}` is not a valid region code"))
+                        .into()
+                })
+        }
+        // 3. If type is "script", then
+        DisplayNamesType::Script => {
+            // a. If code cannot be matched by the unicode_script_subtag Unicode locale nonterminal, throw a RangeError exception.
+            // b. Assert: The length of code is 4, and every code unit of code represents an ASCII letter (0x0041 through 0x005A and 0x0061 through 0x007A, both inclusive).
+            // c. Let first be the ASCII-uppercase of the substring of code from 0 to 1.
+            // d. Let rest be the ASCII-lowercase of the substring of code from 1.
+            // e. Return the string-concatenation of first and rest.
+            Script::try_from_str(code)
+                .map(|script| script.as_str().to_owned())
+                .map_err(|_| {
+                    JsNativeError::range()
+                        .with_message(format!("`{code}` is not a valid script code"))
+                        .into()
+                })
+        }
+        // 4. If type is "calendar", then
+        DisplayNamesType::Calendar => {
+            // a. If code cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+            // b. If code uses any of the backwards compatibility syntax described in Unicode Technical Standard #35 Part 1 Core, Section 3.3 BCP 47 Conformance, throw a RangeError exception.
+            let is_type = code.split('-').all(|subtag| {
+                (3..=8).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+            });
+            if !is_type {
+                return Err(JsNativeError::range()
+                    .with_message(format!("`{code}` is not a valid calendar code"))
+                    .into());
+            }
+
+            // c. Return the ASCII-lowercase of code.
+            Ok(code.cow_to_ascii_lowercase().into_owned())
+        }
+        // 5. If type is "dateTimeField", then
+        DisplayNamesType::DateTimeField => {
+            // a. If the result of IsValidDateTimeFieldCode(code) is false, throw a RangeError exception.
+            if !matches!(
+                code,
+                "era"
+                    | "year"
+                    | "quarter"
+                    | "month"
+                    | "weekOfYear"
+                    | "weekday"
+                    | "day"
+                    | "dayPeriod"
+                    | "hour"
+                    | "minute"
+                    | "second"
+                    | "timeZoneName"
+            ) {
+                return Err(JsNativeError::range()
+                    .with_message(format!("`{code}` is not a valid date time field code"))
+                    .into());
+            }
+
+            // b. Return code.
+            Ok(code.to_owned())
+        }
+        // 6. Assert: type is "currency".
+        DisplayNamesType::Currency => {
+            // 7. If IsWellFormedCurrencyCode(code) is false, throw a RangeError exception.
+            if code.len() != 3 || !code.bytes().all(|b| b.is_ascii_alphabetic()) {
+                return Err(JsNativeError::range()
+                    .with_message(format!("`{code}` is not a valid currency code"))
+                    .into());
+            }
+
+            // 8. Return the ASCII-uppercase of code.
+            Ok(code.cow_to_ascii_uppercase().into_owned())
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use std::str::FromStr;
+
+use icu_experimental::displaynames::{LanguageDisplay, Style};
+
+use crate::{builtins::options::ParsableOptionType, js_string, JsString};
+
+/// The `type` option of an `Intl.DisplayNames` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisplayNamesType {
+    Language,
+    Region,
+    Script,
+    Currency,
+    Calendar,
+    DateTimeField,
+}
+
+impl DisplayNamesType {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Language => js_string!("language"),
+            Self::Region => js_string!("region"),
+            Self::Script => js_string!("script"),
+            Self::Currency => js_string!("currency"),
+            Self::Calendar => js_string!("calendar"),
+            Self::DateTimeField => js_string!("dateTimeField"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDisplayNamesTypeError;
+
+impl std::fmt::Display for ParseDisplayNamesTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            "provided string was not `language`, `region`, `script`, `currency`, \
+            `calendar` or `dateTimeField`",
+        )
+    }
+}
+
+impl FromStr for DisplayNamesType {
+    type Err = ParseDisplayNamesTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "language" => Ok(Self::Language),
+            "region" => Ok(Self::Region),
+            "script" => Ok(Self::Script),
+            "currency" => Ok(Self::Currency),
+            "calendar" => Ok(Self::Calendar),
+            "dateTimeField" => Ok(Self::DateTimeField),
+            _ => Err(ParseDisplayNamesTypeError),
+        }
+    }
+}
+
+impl ParsableOptionType for DisplayNamesType {}
+
+/// The `style` option of an `Intl.DisplayNames` object.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum DisplayNamesStyle {
+    #[default]
+    Long,
+    Short,
+    Narrow,
+}
+
+impl DisplayNamesStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Long => js_string!("long"),
+            Self::Short => js_string!("short"),
+            Self::Narrow => js_string!("narrow"),
+        }
+    }
+
+    pub(crate) const fn to_icu(self) -> Style {
+        match self {
+            Self::Long => Style::Long,
+            Self::Short => Style::Short,
+            Self::Narrow => Style::Narrow,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDisplayNamesStyleError;
+
+impl std::fmt::Display for ParseDisplayNamesStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `long`, `short` or `narrow`")
+    }
+}
+
+impl FromStr for DisplayNamesStyle {
+    type Err = ParseDisplayNamesStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            _ => Err(ParseDisplayNamesStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for DisplayNamesStyle {}
+
+/// The `fallback` option of an `Intl.DisplayNames` object.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum DisplayNamesFallback {
+    #[default]
+    Code,
+    None,
+}
+
+impl DisplayNamesFallback {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Code => js_string!("code"),
+            Self::None => js_string!("none"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDisplayNamesFallbackError;
+
+impl std::fmt::Display for ParseDisplayNamesFallbackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `code` or `none`")
+    }
+}
+
+impl FromStr for DisplayNamesFallback {
+    type Err = ParseDisplayNamesFallbackError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "code" => Ok(Self::Code),
+            "none" => Ok(Self::None),
+            _ => Err(ParseDisplayNamesFallbackError),
+        }
+    }
+}
+
+impl ParsableOptionType for DisplayNamesFallback {}
+
+/// The `languageDisplay` option of an `Intl.DisplayNames` object.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum DisplayNamesLanguageDisplay {
+    #[default]
+    Dialect,
+    Standard,
+}
+
+impl DisplayNamesLanguageDisplay {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Dialect => js_string!("dialect"),
+            Self::Standard => js_string!("standard"),
+        }
+    }
+
+    pub(crate) const fn to_icu(self) -> LanguageDisplay {
+        match self {
+            Self::Dialect => LanguageDisplay::Dialect,
+            Self::Standard => LanguageDisplay::Standard,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDisplayNamesLanguageDisplayError;
+
+impl std::fmt::Display for ParseDisplayNamesLanguageDisplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `dialect` or `standard`")
+    }
+}
+
+impl FromStr for DisplayNamesLanguageDisplay {
+    type Err = ParseDisplayNamesLanguageDisplayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dialect" => Ok(Self::Dialect),
+            "standard" => Ok(Self::Standard),
+            _ => Err(ParseDisplayNamesLanguageDisplayError),
+        }
+    }
+}
+
+impl ParsableOptionType for DisplayNamesLanguageDisplay {}
@@ -0,0 +1,122 @@
+use indoc::indoc;
+
+use crate::{js_string, run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+
+#[test]
+fn of() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var languages = new Intl.DisplayNames("en", { type: "language" });
+            var standard = new Intl.DisplayNames("en", {
+                type: "language",
+                languageDisplay: "standard",
+            });
+            var regions = new Intl.DisplayNames("en", { type: "region" });
+            var scripts = new Intl.DisplayNames("en", { type: "script" });
+            var germanRegions = new Intl.DisplayNames("de", { type: "region" });
+            var currencies = new Intl.DisplayNames("en", { type: "currency" });
+            var germanCurrencies = new Intl.DisplayNames("de", { type: "currency" });
+        "#}),
+        TestAction::assert_eq("languages.of('fr')", js_string!("French")),
+        TestAction::assert_eq("languages.of('en-GB')", js_string!("British English")),
+        TestAction::assert_eq(
+            "standard.of('en-GB')",
+            js_string!("English (United Kingdom)"),
+        ),
+        TestAction::assert_eq("regions.of('US')", js_string!("United States")),
+        TestAction::assert_eq("regions.of('419')", js_string!("Latin America")),
+        TestAction::assert_eq("germanRegions.of('DE')", js_string!("Deutschland")),
+        TestAction::assert_eq("scripts.of('Latn')", js_string!("Latin")),
+        TestAction::assert_eq("scripts.of('arab')", js_string!("Arabic")),
+        TestAction::assert_eq("currencies.of('USD')", js_string!("US Dollar")),
+        TestAction::assert_eq("currencies.of('eur')", js_string!("Euro")),
+        TestAction::assert_eq("germanCurrencies.of('JPY')", js_string!("Japanischer Yen")),
+    ]);
+}
+
+#[test]
+fn fallback() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var code = new Intl.DisplayNames("en", { type: "region" });
+            var none = new Intl.DisplayNames("en", { type: "region", fallback: "none" });
+        "#}),
+        TestAction::assert_eq("code.of('AA')", js_string!("AA")),
+        TestAction::assert_eq("none.of('AA')", JsValue::undefined()),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'currency', fallback: 'none' }).of('XYZ')",
+            JsValue::undefined(),
+        ),
+        TestAction::assert_eq("code.of('aa')", js_string!("AA")),
+        TestAction::assert_native_error(
+            "code.of('12')",
+            JsNativeErrorKind::Range,
+            "`12` is not a valid region code",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en')",
+            JsNativeErrorKind::Type,
+            "`Intl.DisplayNames` requires an options object",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en', {})",
+            JsNativeErrorKind::Type,
+            "`type` option of `Intl.DisplayNames` is required",
+        ),
+    ]);
+}
+
+#[test]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var options = new Intl.DisplayNames("en-US", { type: "language" }).resolvedOptions();
+            var region = new Intl.DisplayNames("de", {
+                type: "region",
+                style: "short",
+                fallback: "none",
+            }).resolvedOptions();
+        "#}),
+        TestAction::assert_eq(
+            "Object.keys(options).join()",
+            js_string!("locale,style,type,fallback,languageDisplay"),
+        ),
+        TestAction::assert_eq("options.locale", js_string!("en-US")),
+        TestAction::assert_eq("options.style", js_string!("long")),
+        TestAction::assert_eq("options.type", js_string!("language")),
+        TestAction::assert_eq("options.fallback", js_string!("code")),
+        TestAction::assert_eq("options.languageDisplay", js_string!("dialect")),
+        TestAction::assert_eq("region.locale", js_string!("de")),
+        TestAction::assert_eq("region.style", js_string!("short")),
+        TestAction::assert_eq("region.fallback", js_string!("none")),
+        TestAction::assert("!('languageDisplay' in region)"),
+    ]);
+}
+
+#[test]
+fn prototype_before_locales() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            var newTarget = function () {}.bind(null);
+            Object.defineProperty(newTarget, "prototype", {
+                get() {
+                    log.push("prototype");
+                    throw new EvalError();
+                },
+            });
+            var locales = {
+                get length() {
+                    log.push("locales");
+                    return 0;
+                },
+            };
+        "#}),
+        TestAction::assert_native_error(
+            "Reflect.construct(Intl.DisplayNames, [locales, { type: 'region' }], newTarget)",
+            JsNativeErrorKind::Eval,
+            "",
+        ),
+        TestAction::assert_eq("log.join()", js_string!("prototype")),
+    ]);
+}
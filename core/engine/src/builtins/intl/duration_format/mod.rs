@@ -0,0 +1,1534 @@
+use boa_gc::{Finalize, Trace};
+use fixed_decimal::{Decimal, Sign};
+use icu_datetime::{fieldsets::T, input::Time, DateTimeFormatterPreferences, NoCalendarFormatter};
+use icu_decimal::{preferences::NumberingSystem, provider::DecimalSymbolsV1};
+use icu_experimental::dimension::units::{
+    formatter::{UnitsFormatter, UnitsFormatterPreferences},
+    options::{UnitsFormatterOptions, Width},
+};
+use icu_list::{
+    options::{ListFormatterOptions, ListLength},
+    ListFormatter, ListFormatterPreferences,
+};
+use icu_locale::{extensions::unicode::Value, Locale};
+use writeable::{PartsWrite, Writeable};
+
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object},
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{
+    list_format::{create_parts_from_list, ListPart},
+    locale::{canonicalize_locale_list, filter_locales, resolve_locale},
+    number_format::{NumberFormat, NumberFormatLocaleOptions, NumberPartsCollector},
+    options::{get_number_option, IntlOptions},
+    Service,
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(all(test, feature = "intl_bundled"))]
+mod tests;
+
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `DurationFormat` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct DurationFormat {
+    locale: Locale,
+    numbering_system: Option<Value>,
+    style: DurationFormatStyle,
+    unit_options: [DurationUnitOptions; DurationUnit::COUNT],
+    hour_minute_separator: String,
+    minute_second_separator: String,
+    fractional_digits: Option<u8>,
+}
+
+impl Service for DurationFormat {
+    type LangMarker = DecimalSymbolsV1;
+
+    type LocaleOptions = NumberFormatLocaleOptions;
+
+    fn resolve(
+        locale: &mut Locale,
+        options: &mut Self::LocaleOptions,
+        provider: &crate::context::icu::IntlProvider,
+    ) {
+        // `Intl.DurationFormat` has the same relevant extension keys as `Intl.NumberFormat`.
+        <NumberFormat as Service>::resolve(locale, options, provider);
+    }
+}
+
+impl IntrinsicObject for DurationFormat {
+    fn init(realm: &Realm) {
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DurationFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format, js_string!("format"), 1)
+            .method(Self::format_to_parts, js_string!("formatToParts"), 1)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DurationFormat {
+    const NAME: JsString = StaticJsStrings::DURATION_FORMAT;
+}
+
+impl BuiltInConstructor for DurationFormat {
+    const LENGTH: usize = 0;
+    const P: usize = 4;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::duration_format;
+
+    /// Constructor [`Intl.DurationFormat ( [ locales [ , options ] ] )`][spec].
+    ///
+    /// Constructor for `DurationFormat` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/DurationFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.DurationFormat` constructor without `new`")
+                .into());
+        }
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        let duration_format = Self::new(locales, options, context)?;
+
+        // 2. Let durationFormat be ? OrdinaryCreateFromConstructor(NewTarget, "%Intl.DurationFormatPrototype%", « [[InitializedDurationFormat]], [[Locale]], [[NumberingSystem]], [[Style]], [[YearsOptions]], [[MonthsOptions]], [[WeeksOptions]], [[DaysOptions]], [[HoursOptions]], [[MinutesOptions]], [[SecondsOptions]], [[MillisecondsOptions]], [[MicrosecondsOptions]], [[NanosecondsOptions]], [[HourMinuteSeparator]], [[MinuteSecondSeparator]], [[FractionalDigits]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::duration_format,
+            context,
+        )?;
+        let duration_format = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            duration_format,
+        );
+
+        // 29. Return durationFormat.
+        Ok(duration_format.into())
+    }
+}
+
+impl DurationFormat {
+    /// Creates a new instance of `DurationFormat`.
+    pub(crate) fn new(
+        locales: &JsValue,
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. Let options be ? GetOptionsObject(options).
+        let options = get_options_object(options)?;
+
+        // 5. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        let matcher =
+            get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 6. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 7. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        let numbering_system =
+            get_option::<NumberingSystem>(&options, js_string!("numberingSystem"), context)?;
+
+        // 8. Let opt be the Record { [[localeMatcher]]: matcher, [[nu]]: numberingSystem }.
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: NumberFormatLocaleOptions {
+                numbering_system: numbering_system.map(Value::from),
+            },
+        };
+
+        // 9. Let r be ResolveLocale(%Intl.DurationFormat%.[[AvailableLocales]], requestedLocales, opt, %Intl.DurationFormat%.[[RelevantExtensionKeys]], %Intl.DurationFormat%.[[LocaleData]]).
+        // 10. Let locale be r.[[Locale]].
+        // 11. Set durationFormat.[[Locale]] to locale.
+        let locale = resolve_locale::<Self>(
+            requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        )?;
+
+        // 12. Set durationFormat.[[NumberingSystem]] to r.[[nu]].
+        // 13. Let style be ? GetOption(options, "style", string, « "long", "short", "narrow", "digital" », "short").
+        // 14. Set durationFormat.[[Style]] to style.
+        let style = get_option(&options, js_string!("style"), context)?.unwrap_or_default();
+
+        // 15. Let resolvedLocaleData be r.[[LocaleData]].
+        // 16. Let digitalFormat be resolvedLocaleData.[[DigitalFormat]].
+        // 17. Let twoDigitHours be digitalFormat.[[TwoDigitHours]].
+        // 18. Set durationFormat.[[HourMinuteSeparator]] to digitalFormat.[[HourMinuteSeparator]].
+        // 19. Set durationFormat.[[MinuteSecondSeparator]] to digitalFormat.[[MinuteSecondSeparator]].
+        let (hour_minute_separator, minute_second_separator) =
+            digital_separators(&locale, context)?;
+
+        // 20. Let prevStyle be the empty String.
+        let mut prev_style = None;
+        let mut unit_options = [DurationUnitOptions {
+            style: DurationUnitStyle::Short,
+            display: DurationUnitDisplay::Auto,
+        }; DurationUnit::COUNT];
+
+        // 21. For each row of Table 20, except the header row, in table order, do
+        for unit in DurationUnit::ALL {
+            // a. Let slot be the Internal Slot value of the current row.
+            // b. Let unit be the Unit value of the current row.
+            // c. Let styles be the Styles value of the current row.
+            // d. Let digitalBase be the Digital Default value of the current row.
+            // e. Let unitOptions be ? GetDurationUnitOptions(unit, options, style, styles, digitalBase, prevStyle, twoDigitHours).
+            let options = get_duration_unit_options(unit, &options, style, prev_style, context)?;
+
+            // f. Set the value of durationFormat's internal slot whose name is slot to unitOptions.
+            unit_options[unit as usize] = options;
+
+            // g. If unit is one of "hours", "minutes", "seconds", "milliseconds", or "microseconds", then
+            if unit >= DurationUnit::Hours {
+                // i. Set prevStyle to unitOptions.[[Style]].
+                prev_style = Some(options.style);
+            }
+        }
+
+        // 22. Set durationFormat.[[FractionalDigits]] to ? GetNumberOption(options, "fractionalDigits", 0, 9, undefined).
+        let fractional_digits =
+            get_number_option(&options, js_string!("fractionalDigits"), 0, 9, context)?;
+
+        Ok(Self {
+            locale,
+            numbering_system: intl_options.service_options.numbering_system,
+            style,
+            unit_options,
+            hour_minute_separator,
+            minute_second_separator,
+            fractional_digits,
+        })
+    }
+
+    /// [`Intl.DurationFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in duration
+    /// formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.DurationFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<Self>(requested_locales, options, context).map(JsValue::from)
+    }
+
+    /// [`Intl.DurationFormat.prototype.format ( duration )`][spec].
+    ///
+    /// Returns a language-sensitive formatted string representing the provided duration.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/format
+    fn format(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let df be this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = this
+            .as_object()
+            .filter(|o| o.borrow().is::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`format` can only be called on an `Intl.DurationFormat` object")
+            })?;
+
+        // 3. Let record be ? ToDurationRecord(duration).
+        let record = to_duration_record(args.get_or_undefined(0), context)?;
+
+        // 4-7.
+        df.downcast_ref::<Self>()
+            .expect("checked above")
+            .format_to_string(&record, context)
+            .map(JsValue::from)
+    }
+
+    /// [`Intl.DurationFormat.prototype.formatToParts ( duration )`][spec].
+    ///
+    /// Returns an array of objects representing the parts of the formatted duration.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let df be this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = this
+            .as_object()
+            .filter(|o| o.borrow().is::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`formatToParts` can only be called on an `Intl.DurationFormat` object",
+                )
+            })?;
+
+        // 3. Let record be ? ToDurationRecord(duration).
+        let record = to_duration_record(args.get_or_undefined(0), context)?;
+
+        // 4. Let parts be PartitionDurationFormatPattern(df, record).
+        let parts = df
+            .downcast_ref::<Self>()
+            .expect("checked above")
+            .partition_duration_format_pattern(&record, context)?;
+
+        // 5. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .expect("creating an empty array with default proto must not fail");
+
+        // 6. Let n be 0.
+        // 7. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        for (n, part) in parts.into_iter().enumerate() {
+            // a. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+            let obj = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(obj, "type", part.[[Type]]).
+            obj.create_data_property_or_throw(js_string!("type"), js_string!(part.typ), context)
+                .expect("operation must not fail per the spec");
+
+            // c. Perform ! CreateDataPropertyOrThrow(obj, "value", part.[[Value]]).
+            obj.create_data_property_or_throw(js_string!("value"), js_string!(part.value), context)
+                .expect("operation must not fail per the spec");
+
+            // d. If part.[[Unit]] is not empty, perform ! CreateDataPropertyOrThrow(obj, "unit", part.[[Unit]]).
+            if let Some(unit) = part.unit {
+                obj.create_data_property_or_throw(js_string!("unit"), js_string!(unit), context)
+                    .expect("operation must not fail per the spec");
+            }
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), obj).
+            result
+                .create_data_property_or_throw(n, obj, context)
+                .expect("operation must not fail per the spec");
+
+            // f. Set n to n + 1.
+        }
+
+        // 8. Return result.
+        Ok(result.into())
+    }
+
+    /// [`Intl.DurationFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and options computed during the
+    /// construction of the current `Intl.DurationFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let df be the this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.DurationFormat` object",
+            )
+        })?;
+        let df = df.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.DurationFormat` object",
+            )
+        })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. For each row of Table 21, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of df's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If p is "fractionalDigits", then
+        //         i. If v is not undefined, set v to 𝔽(v).
+        //     d. Else,
+        //         i. Assert: v is not undefined.
+        //     e. If v is "fractional", then
+        //         i. Assert: The Internal Slot value of the current row is [[MillisecondsOptions]], [[MicrosecondsOptions]], or [[NanosecondsOptions]].
+        //         ii. Set v to "numeric".
+        //     f. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options
+            .create_data_property_or_throw(
+                js_string!("locale"),
+                js_string!(df.locale.to_string()),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        if let Some(nu) = &df.numbering_system {
+            options
+                .create_data_property_or_throw(
+                    js_string!("numberingSystem"),
+                    js_string!(nu.to_string()),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+        options
+            .create_data_property_or_throw(js_string!("style"), df.style.to_js_string(), context)
+            .expect("operation must not fail per the spec");
+        for unit in DurationUnit::ALL {
+            let DurationUnitOptions { style, display } = df.unit_options[unit as usize];
+            let style = if style == DurationUnitStyle::Fractional {
+                DurationUnitStyle::Numeric
+            } else {
+                style
+            };
+            options
+                .create_data_property_or_throw(
+                    js_string!(unit.name()),
+                    style.to_js_string(),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+            options
+                .create_data_property_or_throw(
+                    js_string!(unit.display_name()),
+                    display.to_js_string(),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+        if let Some(fractional_digits) = df.fractional_digits {
+            options
+                .create_data_property_or_throw(
+                    js_string!("fractionalDigits"),
+                    i32::from(fractional_digits),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+
+    /// Formats `duration` into a string, as done by `Intl.DurationFormat.prototype.format`.
+    pub(crate) fn format_to_string(
+        &self,
+        duration: &DurationRecord,
+        context: &mut Context,
+    ) -> JsResult<JsString> {
+        // 4. Let parts be PartitionDurationFormatPattern(df, record).
+        let parts = self.partition_duration_format_pattern(duration, context)?;
+
+        // 5. Let result be the empty String.
+        // 6. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        let result = parts.into_iter().map(|part| part.value).collect::<String>();
+
+        // 7. Return result.
+        Ok(js_string!(result))
+    }
+
+    /// Abstract operation [`PartitionDurationFormatPattern ( durationFormat, duration )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondurationformatpattern
+    fn partition_duration_format_pattern(
+        &self,
+        duration: &DurationRecord,
+        context: &mut Context,
+    ) -> JsResult<Vec<DurationPart>> {
+        // 1. Let result be a new empty List.
+        let mut result = Vec::new();
+
+        // 2. Let signDisplayed be true.
+        let mut sign_displayed = true;
+
+        // 3. Let numericUnitFound be false.
+        // 4. While numericUnitFound is false, repeat for each row in Table 22 in table order, except the header row:
+        for unit in DurationUnit::ALL {
+            // a. Let value be the value of duration's field whose name is the Value Field value of the current row.
+            // b. Let unitOptions be the value of durationFormat's internal slot whose name is the Internal Slot value of the current row.
+            // c. Let style be unitOptions.[[Style]].
+            // d. Let display be unitOptions.[[Display]].
+            // e. Let unit be the Unit value of the current row.
+            // f. Let numberFormatUnit be the NumberFormat Unit value of the current row.
+            let DurationUnitOptions { style, display } = self.unit_options[unit as usize];
+
+            // g. If style is "numeric" or "2-digit", then
+            if matches!(
+                style,
+                DurationUnitStyle::Numeric | DurationUnitStyle::TwoDigit
+            ) {
+                // i. Let numericPartsList be FormatNumericUnits(durationFormat, duration, unit, signDisplayed).
+                let numeric_parts =
+                    self.format_numeric_units(duration, unit, sign_displayed, context)?;
+
+                // ii. If numericPartsList is not empty, append numericPartsList to result.
+                if !numeric_parts.is_empty() {
+                    result.push(numeric_parts);
+                }
+
+                // iii. Set numericUnitFound to true.
+                break;
+            }
+
+            // h. Else,
+            //     i. Let nfOpts be OrdinaryObjectCreate(null).
+            let nf_opts = JsObject::with_null_proto();
+            let mut value = (duration.get(unit) as i128, 0);
+            let mut numeric_unit_found = false;
+
+            //     ii. If unit is "seconds", "milliseconds", or "microseconds", then
+            if matches!(
+                unit,
+                DurationUnit::Seconds | DurationUnit::Milliseconds | DurationUnit::Microseconds
+            ) {
+                // 1. If unit is "seconds", then
+                //     a. Let nextStyle be durationFormat.[[MillisecondsOptions]].[[Style]].
+                // 2. Else if unit is "milliseconds", then
+                //     a. Let nextStyle be durationFormat.[[MicrosecondsOptions]].[[Style]].
+                // 3. Else,
+                //     a. Let nextStyle be durationFormat.[[NanosecondsOptions]].[[Style]].
+                let next_style = self.unit_options[unit as usize + 1].style;
+
+                // 4. If nextStyle is "fractional", then
+                if next_style == DurationUnitStyle::Fractional {
+                    // a. Set value to value + AddFractionalDigits(durationFormat, duration).
+                    value = self.add_fractional_digits(duration, unit);
+
+                    // b. If durationFormat.[[FractionalDigits]] is undefined, then
+                    //     i. Let maximumFractionDigits be 9𝔽.
+                    //     ii. Let minimumFractionDigits be +0𝔽.
+                    // c. Else,
+                    //     i. Let maximumFractionDigits be durationFormat.[[FractionalDigits]].
+                    //     ii. Let minimumFractionDigits be durationFormat.[[FractionalDigits]].
+                    // d. Perform ! CreateDataPropertyOrThrow(nfOpts, "maximumFractionDigits", maximumFractionDigits).
+                    // e. Perform ! CreateDataPropertyOrThrow(nfOpts, "minimumFractionDigits", minimumFractionDigits).
+                    // f. Perform ! CreateDataPropertyOrThrow(nfOpts, "roundingMode", "trunc").
+                    self.set_fractional_digit_options(&nf_opts, context);
+
+                    // g. Set numericUnitFound to true.
+                    numeric_unit_found = true;
+                }
+            }
+
+            //     iii. If display is "always" or value is not 0, then
+            if display == DurationUnitDisplay::Always || value.0 != 0 {
+                let mut number = to_decimal(value);
+
+                // 1. Perform ! CreateDataPropertyOrThrow(nfOpts, "numberingSystem", durationFormat.[[NumberingSystem]]).
+                self.set_numbering_system_option(&nf_opts, context);
+
+                // 2. If signDisplayed is true, then
+                if sign_displayed {
+                    // a. Set signDisplayed to false.
+                    sign_displayed = false;
+
+                    // b. If value is 0 and DurationSign(duration) is -1, then
+                    if value.0 == 0 && duration.sign() == -1 {
+                        // i. Set value to negative-zero.
+                        number.set_sign(Sign::Negative);
+                    }
+                } else {
+                    // 3. Else,
+                    //     a. Perform ! CreateDataPropertyOrThrow(nfOpts, "signDisplay", "never").
+                    nf_opts
+                        .create_data_property_or_throw(
+                            js_string!("signDisplay"),
+                            js_string!("never"),
+                            context,
+                        )
+                        .expect("operation must not fail per the spec");
+                }
+
+                // 4. Perform ! CreateDataPropertyOrThrow(nfOpts, "style", "unit").
+                // 5. Perform ! CreateDataPropertyOrThrow(nfOpts, "unit", numberFormatUnit).
+                // 6. Perform ! CreateDataPropertyOrThrow(nfOpts, "unitDisplay", style).
+                for (key, value) in [
+                    (js_string!("style"), js_string!("unit")),
+                    (js_string!("unit"), js_string!(unit.number_format_unit())),
+                    (js_string!("unitDisplay"), style.to_js_string()),
+                ] {
+                    nf_opts
+                        .create_data_property_or_throw(key, value, context)
+                        .expect("operation must not fail per the spec");
+                }
+
+                // 7. Let nf be ! Construct(%Intl.NumberFormat%, « durationFormat.[[Locale]], nfOpts »).
+                // 8. Let parts be PartitionNumberPattern(nf, value).
+                // 9. Let list be a new empty List.
+                // 10. For each Record { [[Type]], [[Value]] } part in parts, do
+                //     a. Append the Record { [[Type]]: part.[[Type]], [[Value]]: part.[[Value]], [[Unit]]: numberFormatUnit } to list.
+                // 11. Append list to result.
+                result.push(self.format_unit(number, &nf_opts, unit, style, context)?);
+            }
+
+            if numeric_unit_found {
+                break;
+            }
+        }
+
+        // 5. Return ListFormatParts(durationFormat, result).
+        self.list_format_parts(result, context)
+    }
+
+    /// Abstract operation [`FormatNumericUnits ( durationFormat, duration, firstNumericUnit, signDisplayed )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumericunits
+    fn format_numeric_units(
+        &self,
+        duration: &DurationRecord,
+        first_numeric_unit: DurationUnit,
+        mut sign_displayed: bool,
+        context: &mut Context,
+    ) -> JsResult<Vec<DurationPart>> {
+        // 1. Assert: firstNumericUnit is "hours", "minutes", or "seconds".
+        debug_assert!(matches!(
+            first_numeric_unit,
+            DurationUnit::Hours | DurationUnit::Minutes | DurationUnit::Seconds
+        ));
+
+        // 2. Let numericPartsList be a new empty List.
+        let mut numeric_parts = Vec::new();
+
+        // 3. Let hoursValue be duration.[[Hours]].
+        // 4. Let hoursDisplay be durationFormat.[[HoursOptions]].[[Display]].
+        // 5. Let minutesValue be duration.[[Minutes]].
+        // 6. Let minutesDisplay be durationFormat.[[MinutesOptions]].[[Display]].
+        // 7. Let secondsValue be duration.[[Seconds]].
+        let hours_value = (duration.get(DurationUnit::Hours) as i128, 0);
+        let hours_display = self.unit_options[DurationUnit::Hours as usize].display;
+        let minutes_value = (duration.get(DurationUnit::Minutes) as i128, 0);
+        let minutes_display = self.unit_options[DurationUnit::Minutes as usize].display;
+        let mut seconds_value = (duration.get(DurationUnit::Seconds) as i128, 0);
+
+        // 8. If durationFormat.[[MillisecondsOptions]].[[Style]] is "fractional", then
+        if self.unit_options[DurationUnit::Milliseconds as usize].style
+            == DurationUnitStyle::Fractional
+        {
+            // a. Set secondsValue to secondsValue + AddFractionalDigits(durationFormat, duration).
+            seconds_value = self.add_fractional_digits(duration, DurationUnit::Seconds);
+        }
+
+        // 9. Let secondsDisplay be durationFormat.[[SecondsOptions]].[[Display]].
+        let seconds_display = self.unit_options[DurationUnit::Seconds as usize].display;
+
+        // 10. Let hoursFormatted be false.
+        // 11. If firstNumericUnit is "hours", then
+        //     a. If hoursValue is not 0 or hoursDisplay is "always", then
+        //         i. Set hoursFormatted to true.
+        let hours_formatted = first_numeric_unit == DurationUnit::Hours
+            && (hours_value.0 != 0 || hours_display == DurationUnitDisplay::Always);
+
+        // 12. If secondsValue is not 0 or secondsDisplay is "always", then
+        //     a. Let secondsFormatted be true.
+        // 13. Else,
+        //     a. Let secondsFormatted be false.
+        let seconds_formatted =
+            seconds_value.0 != 0 || seconds_display == DurationUnitDisplay::Always;
+
+        // 14. Let minutesFormatted be false.
+        // 15. If firstNumericUnit is "hours" or firstNumericUnit is "minutes", then
+        //     a. If hoursFormatted is true and secondsFormatted is true, then
+        //         i. Set minutesFormatted to true.
+        //     b. Else if minutesValue is not 0 or minutesDisplay is "always", then
+        //         i. Set minutesFormatted to true.
+        let minutes_formatted = first_numeric_unit != DurationUnit::Seconds
+            && ((hours_formatted && seconds_formatted)
+                || minutes_value.0 != 0
+                || minutes_display == DurationUnitDisplay::Always);
+
+        // 16. If hoursFormatted is true, then
+        //     a. If signDisplayed is true, then
+        //         i. If hoursValue is 0 and DurationSign(duration) is -1, then
+        //             1. Set hoursValue to negative-zero.
+        //     b. Let hoursParts be FormatNumericHours(durationFormat, hoursValue, signDisplayed).
+        //     c. Set numericPartsList to the list-concatenation of numericPartsList and hoursParts.
+        //     d. Set signDisplayed to false.
+        // 17. If minutesFormatted is true, then
+        //     a. If signDisplayed is true, then
+        //         i. If minutesValue is 0 and DurationSign(duration) is -1, then
+        //             1. Set minutesValue to negative-zero.
+        //     b. Let minutesParts be FormatNumericMinutes(durationFormat, minutesValue, hoursFormatted, signDisplayed).
+        //     c. Set numericPartsList to the list-concatenation of numericPartsList and minutesParts.
+        //     d. Set signDisplayed to false.
+        // 18. If secondsFormatted is true, then
+        //     a. Let secondsParts be FormatNumericSeconds(durationFormat, secondsValue, minutesFormatted, signDisplayed).
+        //     b. Set numericPartsList to the list-concatenation of numericPartsList and secondsParts.
+        for (unit, value, formatted, separated) in [
+            (DurationUnit::Hours, hours_value, hours_formatted, false),
+            (
+                DurationUnit::Minutes,
+                minutes_value,
+                minutes_formatted,
+                hours_formatted,
+            ),
+            (
+                DurationUnit::Seconds,
+                seconds_value,
+                seconds_formatted,
+                minutes_formatted,
+            ),
+        ] {
+            if !formatted {
+                continue;
+            }
+
+            let mut number = to_decimal(value);
+            if sign_displayed && value.0 == 0 && duration.sign() == -1 {
+                number.set_sign(Sign::Negative);
+            }
+            numeric_parts.extend(self.format_numeric_unit(
+                number,
+                unit,
+                separated,
+                sign_displayed,
+                context,
+            )?);
+            sign_displayed = false;
+        }
+
+        // 19. Return numericPartsList.
+        Ok(numeric_parts)
+    }
+
+    /// Abstract operations [`FormatNumericHours ( durationFormat, hoursValue, signDisplayed )`][hours],
+    /// [`FormatNumericMinutes ( durationFormat, minutesValue, hoursDisplayed, signDisplayed )`][minutes]
+    /// and [`FormatNumericSeconds ( durationFormat, secondsValue, minutesDisplayed, signDisplayed )`][seconds].
+    ///
+    /// [hours]: https://tc39.es/ecma402/#sec-formatnumerichours
+    /// [minutes]: https://tc39.es/ecma402/#sec-formatnumericminutes
+    /// [seconds]: https://tc39.es/ecma402/#sec-formatnumericseconds
+    fn format_numeric_unit(
+        &self,
+        mut value: Decimal,
+        unit: DurationUnit,
+        separated: bool,
+        sign_displayed: bool,
+        context: &mut Context,
+    ) -> JsResult<Vec<DurationPart>> {
+        // 1. Let result be a new empty List.
+        let mut result = Vec::new();
+
+        // 2. If minutesDisplayed (or hoursDisplayed) is true, then
+        if separated {
+            // a. Let separator be durationFormat.[[MinuteSecondSeparator]] (or [[HourMinuteSeparator]]).
+            // b. Append the Record { [[Type]]: "literal", [[Value]]: separator, [[Unit]]: empty } to result.
+            let separator = if unit == DurationUnit::Seconds {
+                &self.minute_second_separator
+            } else {
+                &self.hour_minute_separator
+            };
+            result.push(DurationPart {
+                typ: "literal",
+                value: separator.clone(),
+                unit: None,
+            });
+        }
+
+        // 3. Let secondsStyle be durationFormat.[[SecondsOptions]].[[Style]].
+        // 4. Let nfOpts be OrdinaryObjectCreate(null).
+        let style = self.unit_options[unit as usize].style;
+        let nf_opts = JsObject::with_null_proto();
+
+        // 5. Let numberingSystem be durationFormat.[[NumberingSystem]].
+        // 6. Perform ! CreateDataPropertyOrThrow(nfOpts, "numberingSystem", numberingSystem).
+        self.set_numbering_system_option(&nf_opts, context);
+
+        // 7. If secondsStyle is "2-digit", then
+        if style == DurationUnitStyle::TwoDigit {
+            // a. Perform ! CreateDataPropertyOrThrow(nfOpts, "minimumIntegerDigits", 2𝔽).
+            nf_opts
+                .create_data_property_or_throw(js_string!("minimumIntegerDigits"), 2, context)
+                .expect("operation must not fail per the spec");
+        }
+
+        // 8. If signDisplayed is false, then
+        if !sign_displayed {
+            // a. Perform ! CreateDataPropertyOrThrow(nfOpts, "signDisplay", "never").
+            nf_opts
+                .create_data_property_or_throw(
+                    js_string!("signDisplay"),
+                    js_string!("never"),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+
+        // 9. Perform ! CreateDataPropertyOrThrow(nfOpts, "useGrouping", false).
+        nf_opts
+            .create_data_property_or_throw(js_string!("useGrouping"), false, context)
+            .expect("operation must not fail per the spec");
+
+        // 10. If durationFormat.[[FractionalDigits]] is undefined, then
+        //     a. Let maximumFractionDigits be 9𝔽.
+        //     b. Let minimumFractionDigits be +0𝔽.
+        // 11. Else,
+        //     a. Let maximumFractionDigits be durationFormat.[[FractionalDigits]].
+        //     b. Let minimumFractionDigits be durationFormat.[[FractionalDigits]].
+        // 12. Perform ! CreateDataPropertyOrThrow(nfOpts, "maximumFractionDigits", maximumFractionDigits).
+        // 13. Perform ! CreateDataPropertyOrThrow(nfOpts, "minimumFractionDigits", minimumFractionDigits).
+        // 14. Perform ! CreateDataPropertyOrThrow(nfOpts, "roundingMode", "trunc").
+        if unit == DurationUnit::Seconds {
+            self.set_fractional_digit_options(&nf_opts, context);
+        }
+
+        // 15. Let nf be ! Construct(%Intl.NumberFormat%, « durationFormat.[[Locale]], nfOpts »).
+        // 16. Let secondsParts be PartitionNumberPattern(nf, secondsValue).
+        // 17. For each Record { [[Type]], [[Value]] } part of secondsParts, do
+        //     a. Append the Record { [[Type]]: part.[[Type]], [[Value]]: part.[[Value]], [[Unit]]: "second" } to result.
+        let unit = unit.number_format_unit();
+        result.extend(
+            self.format_number(&mut value, &nf_opts, context)?
+                .into_iter()
+                .map(|(typ, value)| DurationPart {
+                    typ,
+                    value,
+                    unit: Some(unit),
+                }),
+        );
+
+        // 18. Return result.
+        Ok(result)
+    }
+
+    /// Formats `value` using the unit pattern of `unit` in the given style.
+    fn format_unit(
+        &self,
+        mut value: Decimal,
+        nf_opts: &JsObject,
+        unit: DurationUnit,
+        style: DurationUnitStyle,
+        context: &mut Context,
+    ) -> JsResult<Vec<DurationPart>> {
+        let number_parts = self.format_number(&mut value, nf_opts, context)?;
+        let unit = unit.number_format_unit();
+
+        // TODO: `Intl.NumberFormat` cannot format units yet, so the number is wrapped in the
+        // unit pattern of ICU4X's units formatter. The number parts of the units formatter are
+        // replaced by the parts of the number formatter, keeping only the literal text around them.
+        let mut options = UnitsFormatterOptions::default();
+        options.width = match style {
+            DurationUnitStyle::Long => Width::Long,
+            DurationUnitStyle::Narrow => Width::Narrow,
+            _ => Width::Short,
+        };
+        let formatter = UnitsFormatter::try_new_with_buffer_provider(
+            context.intl_provider().erased_provider(),
+            UnitsFormatterPreferences::from(&self.locale),
+            unit,
+            options,
+        )
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+        let mut unit_parts = NumberPartsCollector::default();
+        formatter
+            .format_fixed_decimal(&value)
+            .write_to_parts(&mut unit_parts)
+            .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+        let unit_parts = unit_parts.finish();
+
+        let (Some(first), Some(last)) = (
+            unit_parts.iter().position(|(typ, _)| *typ != "literal"),
+            unit_parts.iter().rposition(|(typ, _)| *typ != "literal"),
+        ) else {
+            return Err(JsNativeError::typ()
+                .with_message(format!("the unit pattern of `{unit}` has no number"))
+                .into());
+        };
+        let before = unit_parts[..first]
+            .iter()
+            .map(|(_, value)| value.as_str())
+            .collect::<String>();
+        let after = unit_parts[last + 1..]
+            .iter()
+            .map(|(_, value)| value.as_str())
+            .collect::<String>();
+
+        let mut result = Vec::with_capacity(number_parts.len() + 4);
+        push_unit_affix(&mut result, &before, unit);
+        result.extend(number_parts.into_iter().map(|(typ, value)| DurationPart {
+            typ,
+            value,
+            unit: Some(unit),
+        }));
+        push_unit_affix(&mut result, &after, unit);
+
+        Ok(result)
+    }
+
+    /// Formats `value` with a new `Intl.NumberFormat` for the locale of this `DurationFormat`,
+    /// returning the `(type, value)` pairs of the formatted parts.
+    fn format_number(
+        &self,
+        value: &mut Decimal,
+        nf_opts: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<Vec<(&'static str, String)>> {
+        let nf = NumberFormat::new(
+            &js_string!(self.locale.to_string()).into(),
+            &nf_opts.clone().into(),
+            context,
+        )?;
+
+        let mut parts = NumberPartsCollector::default();
+        nf.format(value)
+            .write_to_parts(&mut parts)
+            .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        Ok(parts.finish())
+    }
+
+    /// Sets the `numberingSystem` option of a number format options object.
+    fn set_numbering_system_option(&self, nf_opts: &JsObject, context: &mut Context) {
+        if let Some(nu) = &self.numbering_system {
+            nf_opts
+                .create_data_property_or_throw(
+                    js_string!("numberingSystem"),
+                    js_string!(nu.to_string()),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+    }
+
+    /// Sets the fraction digits options used to format the fractional part of a unit.
+    fn set_fractional_digit_options(&self, nf_opts: &JsObject, context: &mut Context) {
+        let (minimum, maximum) = self
+            .fractional_digits
+            .map_or((0, 9), |digits| (digits, digits));
+
+        for (key, value) in [
+            (js_string!("maximumFractionDigits"), JsValue::from(maximum)),
+            (js_string!("minimumFractionDigits"), JsValue::from(minimum)),
+            (js_string!("roundingMode"), js_string!("trunc").into()),
+        ] {
+            nf_opts
+                .create_data_property_or_throw(key, value, context)
+                .expect("operation must not fail per the spec");
+        }
+    }
+
+    /// Abstract operation [`AddFractionalDigits ( durationFormat, duration )`][spec].
+    ///
+    /// Instead of returning only the fractional digits, this returns the exact value of `unit`
+    /// plus the fractional digits of the smaller units, as a `(mantissa, exponent)` pair.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-addfractionaldigits
+    fn add_fractional_digits(&self, duration: &DurationRecord, unit: DurationUnit) -> (i128, i16) {
+        // 1. Let result be 0.
+        let mut mantissa = duration.get(unit) as i128;
+
+        // 2. Let exponent be 3.
+        let mut exponent = 0;
+
+        // 3. For each row of Table 22, except the header row, in table order, do
+        for unit in &DurationUnit::ALL[unit as usize + 1..] {
+            // a. Let style be the value of durationFormat's internal slot whose name is the Style Slot value of the current row.
+            // b. If style is "fractional", then
+            if self.unit_options[*unit as usize].style == DurationUnitStyle::Fractional {
+                // i. Assert: The Unit value of the current row is "milliseconds", "microseconds", or "nanoseconds".
+                // ii. Let value be the value of duration's field whose name is the Value Field value of the current row.
+                // iii. Set value to value / 10**exponent.
+                // iv. Set result to result + value.
+                // v. Set exponent to exponent + 3.
+                mantissa = mantissa * 1000 + duration.get(*unit) as i128;
+                exponent -= 3;
+            }
+        }
+
+        // 4. Return result.
+        (mantissa, exponent)
+    }
+
+    /// Abstract operation [`ListFormatParts ( durationFormat, partitionedPartsList )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-listformatparts
+    fn list_format_parts(
+        &self,
+        partitioned_parts_list: Vec<Vec<DurationPart>>,
+        context: &Context,
+    ) -> JsResult<Vec<DurationPart>> {
+        // 1. Let lfOpts be OrdinaryObjectCreate(null).
+        // 2. Perform ! CreateDataPropertyOrThrow(lfOpts, "type", "unit").
+        // 3. Let listStyle be durationFormat.[[Style]].
+        // 4. If listStyle is "digital", then
+        //     a. Set listStyle to "short".
+        // 5. Perform ! CreateDataPropertyOrThrow(lfOpts, "style", listStyle).
+        let length = match self.style {
+            DurationFormatStyle::Long => ListLength::Wide,
+            DurationFormatStyle::Short | DurationFormatStyle::Digital => ListLength::Short,
+            DurationFormatStyle::Narrow => ListLength::Narrow,
+        };
+
+        // 6. Let lf be ! Construct(%Intl.ListFormat%, « durationFormat.[[Locale]], lfOpts »).
+        let lf = ListFormatter::try_new_unit_with_buffer_provider(
+            context.intl_provider().erased_provider(),
+            ListFormatterPreferences::from(&self.locale),
+            ListFormatterOptions::default().with_length(length),
+        )
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        // 7. Let strings be a new empty List.
+        // 8. For each element parts of partitionedPartsList, do
+        //     a. Let string be the empty String.
+        //     b. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //         i. Set string to the string-concatenation of string and part.[[Value]].
+        //     c. Append string to strings.
+        let strings = partitioned_parts_list
+            .iter()
+            .map(|parts| {
+                parts
+                    .iter()
+                    .map(|part| part.value.as_str())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        // 9. Let formattedPartsList be CreatePartsFromList(lf, strings).
+        let formatted_parts_list = create_parts_from_list(&lf, strings.into_iter())?;
+
+        // 10. Let partitionedPartsIndex be 0.
+        // 11. Let partitionedLength be the number of elements in partitionedPartsList.
+        let mut partitioned_parts = partitioned_parts_list.into_iter();
+
+        // 12. Let flattenedPartsList be a new empty List.
+        let mut flattened_parts_list = Vec::new();
+
+        // 13. For each Record { [[Type]], [[Value]] } listPart in formattedPartsList, do
+        for list_part in formatted_parts_list {
+            match list_part {
+                // a. If listPart.[[Type]] is "element", then
+                ListPart::Element(_) => {
+                    // i. Assert: partitionedPartsIndex < partitionedLength.
+                    // ii. Let parts be partitionedPartsList[partitionedPartsIndex].
+                    // iii. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+                    //     1. Append part to flattenedPartsList.
+                    // iv. Set partitionedPartsIndex to partitionedPartsIndex + 1.
+                    flattened_parts_list.extend(
+                        partitioned_parts
+                            .next()
+                            .expect("there must be a partitioned part for each element"),
+                    );
+                }
+                // b. Else,
+                ListPart::Literal(value) => {
+                    // i. Assert: listPart.[[Type]] is "literal".
+                    // ii. Append the Record { [[Type]]: "literal", [[Value]]: listPart.[[Value]], [[Unit]]: empty } to flattenedPartsList.
+                    flattened_parts_list.push(DurationPart {
+                        typ: "literal",
+                        value,
+                        unit: None,
+                    });
+                }
+            }
+        }
+
+        // 14. Return flattenedPartsList.
+        Ok(flattened_parts_list)
+    }
+}
+
+/// A record returned by [`DurationFormat::partition_duration_format_pattern`].
+#[derive(Debug)]
+struct DurationPart {
+    typ: &'static str,
+    value: String,
+    unit: Option<&'static str>,
+}
+
+/// The [Duration Record][spec] formatted by an `Intl.DurationFormat` object.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-duration-records
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct DurationRecord([f64; DurationUnit::COUNT]);
+
+impl DurationRecord {
+    /// Gets the value of the field of `unit`.
+    pub(crate) const fn get(&self, unit: DurationUnit) -> f64 {
+        self.0[unit as usize]
+    }
+
+    /// Abstract operation [`DurationSign ( duration )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-durationsign
+    pub(crate) fn sign(&self) -> i8 {
+        // 1. For each value v of « years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds », do
+        for v in self.0 {
+            // a. If v < 0, return -1.
+            if v < 0.0 {
+                return -1;
+            }
+            // b. If v > 0, return 1.
+            if v > 0.0 {
+                return 1;
+            }
+        }
+
+        // 2. Return 0.
+        0
+    }
+
+    /// Abstract operation [`IsValidDuration ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-isvalidduration
+    #[cfg(not(feature = "temporal"))]
+    fn is_valid(&self) -> bool {
+        /// The limit of the normalized seconds of a duration, in nanoseconds.
+        const LIMIT: i128 = (1 << 53) * 1_000_000_000;
+
+        // 1. Let sign be DurationSign(years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+        let sign = self.sign();
+
+        // 2. For each value v of « years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds », do
+        for v in self.0 {
+            // a. If 𝔽(v) is not finite, return false.
+            // b. If v < 0 and sign > 0, return false.
+            // c. If v > 0 and sign < 0, return false.
+            if !v.is_finite() || (v < 0.0 && sign > 0) || (v > 0.0 && sign < 0) {
+                return false;
+            }
+        }
+
+        // 3. If abs(years) ≥ 2**32, return false.
+        // 4. If abs(months) ≥ 2**32, return false.
+        // 5. If abs(weeks) ≥ 2**32, return false.
+        if [
+            DurationUnit::Years,
+            DurationUnit::Months,
+            DurationUnit::Weeks,
+        ]
+        .into_iter()
+        .any(|unit| self.get(unit).abs() >= 2f64.powi(32))
+        {
+            return false;
+        }
+
+        // 6. Let normalizedSeconds be days × 86,400 + hours × 3600 + minutes × 60 + seconds + ℝ(𝔽(milliseconds)) × 10**-3 + ℝ(𝔽(microseconds)) × 10**-6 + ℝ(𝔽(nanoseconds)) × 10**-9.
+        // 7. NOTE: The above step cannot be implemented directly using floating-point arithmetic. [...]
+        // 8. If abs(normalizedSeconds) ≥ 2**53, return false.
+        // All the values have the same sign, so any value bigger than the limit on its own makes
+        // the duration invalid.
+        let normalized_nanoseconds = [
+            (DurationUnit::Days, 86_400_000_000_000),
+            (DurationUnit::Hours, 3_600_000_000_000),
+            (DurationUnit::Minutes, 60_000_000_000),
+            (DurationUnit::Seconds, 1_000_000_000),
+            (DurationUnit::Milliseconds, 1_000_000),
+            (DurationUnit::Microseconds, 1_000),
+            (DurationUnit::Nanoseconds, 1),
+        ]
+        .into_iter()
+        .try_fold(0i128, |total, (unit, factor)| {
+            let value = self.get(unit).abs();
+            if value >= LIMIT as f64 {
+                return None;
+            }
+            (value as i128)
+                .checked_mul(factor)
+                .and_then(|value| total.checked_add(value))
+        });
+
+        // 9. Return true.
+        normalized_nanoseconds.is_some_and(|total| total < LIMIT)
+    }
+}
+
+#[cfg(feature = "temporal")]
+impl From<&temporal_rs::Duration> for DurationRecord {
+    fn from(duration: &temporal_rs::Duration) -> Self {
+        Self([
+            duration.years() as f64,
+            duration.months() as f64,
+            duration.weeks() as f64,
+            duration.days() as f64,
+            duration.hours() as f64,
+            duration.minutes() as f64,
+            duration.seconds() as f64,
+            duration.milliseconds() as f64,
+            duration.microseconds() as f64,
+            duration.nanoseconds() as f64,
+        ])
+    }
+}
+
+/// Abstract operation [`ToDurationRecord ( input )`][spec].
+///
+/// When `Temporal` is enabled, this accepts any value accepted by `ToTemporalDuration`
+/// instead, including `Temporal.Duration` objects and ISO 8601 duration strings.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-todurationrecord
+#[cfg(feature = "temporal")]
+fn to_duration_record(input: &JsValue, context: &mut Context) -> JsResult<DurationRecord> {
+    let duration = crate::builtins::temporal::to_temporal_duration(input, context)?;
+    Ok(DurationRecord::from(&duration))
+}
+
+/// Abstract operation [`ToDurationRecord ( input )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-todurationrecord
+#[cfg(not(feature = "temporal"))]
+fn to_duration_record(input: &JsValue, context: &mut Context) -> JsResult<DurationRecord> {
+    // 1. If input is not an Object, then
+    let Some(input) = input.as_object() else {
+        // a. If input is a String, throw a RangeError exception.
+        if input.is_string() {
+            return Err(JsNativeError::range()
+                .with_message("cannot format a string as a duration")
+                .into());
+        }
+
+        // b. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("duration must be an object")
+            .into());
+    };
+
+    // 2. Let result be a new Duration Record with each field set to 0.
+    let mut result = DurationRecord::default();
+    let mut any_defined = false;
+
+    // 3-22. For each field in alphabetical order, get its value and, if it is not undefined,
+    //       set the field of the result to ? ToIntegerIfIntegral(value).
+    for unit in [
+        DurationUnit::Days,
+        DurationUnit::Hours,
+        DurationUnit::Microseconds,
+        DurationUnit::Milliseconds,
+        DurationUnit::Minutes,
+        DurationUnit::Months,
+        DurationUnit::Nanoseconds,
+        DurationUnit::Seconds,
+        DurationUnit::Weeks,
+        DurationUnit::Years,
+    ] {
+        let value = input.get(js_string!(unit.name()), context)?;
+        if value.is_undefined() {
+            continue;
+        }
+        any_defined = true;
+
+        // `ToIntegerIfIntegral ( argument )`
+        // https://tc39.es/ecma402/#sec-tointegerifintegral
+
+        // 1. Let number be ? ToNumber(argument).
+        let number = value.to_number(context)?;
+
+        // 2. If number is not an integral Number, throw a RangeError exception.
+        if !number.is_finite() || number.fract() != 0.0 {
+            return Err(JsNativeError::range()
+                .with_message(format!("`{}` must be an integral number", unit.name()))
+                .into());
+        }
+
+        // 3. Return ℝ(number).
+        result.0[unit as usize] = number + 0.0;
+    }
+
+    // 23. If years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, and nanoseconds are all undefined, throw a TypeError exception.
+    if !any_defined {
+        return Err(JsNativeError::typ()
+            .with_message("duration object must have at least one duration property")
+            .into());
+    }
+
+    // 24. If IsValidDuration( result.[[Years]], result.[[Months]], result.[[Weeks]], result.[[Days]], result.[[Hours]], result.[[Minutes]], result.[[Seconds]], result.[[Milliseconds]], result.[[Microseconds]], result.[[Nanoseconds]]) is false, then
+    if !result.is_valid() {
+        // a. Throw a RangeError exception.
+        return Err(JsNativeError::range()
+            .with_message("duration is out of range")
+            .into());
+    }
+
+    // 25. Return result.
+    Ok(result)
+}
+
+/// Abstract operation [`GetDurationUnitOptions ( unit, options, baseStyle, stylesList, digitalBase, prevStyle, twoDigitHours )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getdurationunitoptions
+fn get_duration_unit_options(
+    unit: DurationUnit,
+    options: &JsObject,
+    base_style: DurationFormatStyle,
+    prev_style: Option<DurationUnitStyle>,
+    context: &mut Context,
+) -> JsResult<DurationUnitOptions> {
+    // 1. Let style be ? GetOption(options, unit, string, stylesList, undefined).
+    let style = get_option::<DurationUnitStyle>(options, js_string!(unit.name()), context)?;
+    if style.is_some_and(|style| !unit.allows(style)) {
+        return Err(JsNativeError::range()
+            .with_message(format!("invalid style for the `{}` option", unit.name()))
+            .into());
+    }
+
+    // 2. Let displayDefault be "always".
+    let mut display_default = DurationUnitDisplay::Always;
+
+    // 3. If style is undefined, then
+    let mut style = style.unwrap_or_else(|| {
+        // a. If baseStyle is "digital", then
+        if base_style == DurationFormatStyle::Digital {
+            // i. If unit is not one of "hours", "minutes", or "seconds", then
+            if !matches!(
+                unit,
+                DurationUnit::Hours | DurationUnit::Minutes | DurationUnit::Seconds
+            ) {
+                // 1. Set displayDefault to "auto".
+                display_default = DurationUnitDisplay::Auto;
+            }
+
+            // ii. Set style to digitalBase.
+            unit.digital_base()
+        }
+        // b. Else,
+        //     i. If prevStyle is "fractional", "numeric" or "2-digit", then
+        else if prev_style.is_some_and(DurationUnitStyle::is_numeric) {
+            // 1. If unit is not one of "minutes" or "seconds", then
+            if !matches!(unit, DurationUnit::Minutes | DurationUnit::Seconds) {
+                // a. Set displayDefault to "auto".
+                display_default = DurationUnitDisplay::Auto;
+            }
+
+            // 2. Set style to "numeric".
+            DurationUnitStyle::Numeric
+        }
+        //     ii. Else,
+        else {
+            // 1. Set displayDefault to "auto".
+            display_default = DurationUnitDisplay::Auto;
+
+            // 2. Set style to baseStyle.
+            base_style.into()
+        }
+    });
+
+    // 4. If style is "numeric" and IsFractionalSecondUnitName(unit) is true, then
+    if style == DurationUnitStyle::Numeric && unit.is_subsecond() {
+        // a. Set style to "fractional".
+        style = DurationUnitStyle::Fractional;
+
+        // b. Set displayDefault to "auto".
+        display_default = DurationUnitDisplay::Auto;
+    }
+
+    // 5. Let displayField be the string-concatenation of unit and "Display".
+    // 6. Let display be ? GetOption(options, displayField, string, « "auto", "always" », displayDefault).
+    let display =
+        get_option(options, js_string!(unit.display_name()), context)?.unwrap_or(display_default);
+
+    // 7. If display is "always" and style is "fractional", then
+    if display == DurationUnitDisplay::Always && style == DurationUnitStyle::Fractional {
+        // a. Throw a RangeError exception.
+        return Err(JsNativeError::range()
+            .with_message(format!(
+                "`{}` cannot be always displayed as a fraction",
+                unit.name()
+            ))
+            .into());
+    }
+
+    match prev_style {
+        // 8. If prevStyle is "fractional", then
+        //     a. If style is not "fractional", then
+        //         i. Throw a RangeError exception.
+        Some(DurationUnitStyle::Fractional) if style != DurationUnitStyle::Fractional => {
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "`{}` must be fractional after a fractional unit",
+                    unit.name()
+                ))
+                .into());
+        }
+        // 9. If prevStyle is "numeric" or "2-digit", then
+        Some(DurationUnitStyle::Numeric | DurationUnitStyle::TwoDigit) => {
+            // a. If style is not "fractional", "numeric" or "2-digit", then
+            if !style.is_numeric() {
+                // i. Throw a RangeError exception.
+                return Err(JsNativeError::range()
+                    .with_message(format!(
+                        "`{}` must be numeric after a numeric unit",
+                        unit.name()
+                    ))
+                    .into());
+            }
+
+            // b. If unit is "minutes" or "seconds", then
+            if matches!(unit, DurationUnit::Minutes | DurationUnit::Seconds) {
+                // i. Set style to "2-digit".
+                style = DurationUnitStyle::TwoDigit;
+            }
+        }
+        _ => {}
+    }
+
+    // 10. If unit is "hours" and twoDigitHours is true, then
+    //     a. Set style to "2-digit".
+    // TODO: Missing support from ICU4X for the digital format data of a locale.
+
+    // 11. Return the Duration Unit Options Record { [[Style]]: style, [[Display]]: display  }.
+    Ok(DurationUnitOptions { style, display })
+}
+
+/// Converts a `(mantissa, exponent)` pair into a [`Decimal`].
+fn to_decimal((mantissa, exponent): (i128, i16)) -> Decimal {
+    let mut decimal = Decimal::from(mantissa);
+    decimal.multiply_pow10(exponent);
+    decimal
+}
+
+/// Returns the hour-minute and minute-second separators of the digital format of `locale`.
+///
+/// The separators are taken from the literals between the fields of the locale's time pattern.
+fn digital_separators(locale: &Locale, context: &Context) -> JsResult<(String, String)> {
+    let formatter = NoCalendarFormatter::try_new_with_buffer_provider(
+        context.intl_provider().erased_provider(),
+        DateTimeFormatterPreferences::from(locale),
+        T::hms(),
+    )
+    .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+    let time = Time::try_new(1, 2, 3, 0).expect("01:02:03 must be a valid time");
+    let mut parts = FieldPartsCollector::default();
+    formatter
+        .format(&time)
+        .write_to_parts(&mut parts)
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+    let parts = parts.finish();
+
+    let separator = |from: &str, to: &str| {
+        parts
+            .windows(3)
+            .find(|window| window[0].0 == from && window[1].0 == "literal" && window[2].0 == to)
+            .map(|window| window[1].1.clone())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(format!(
+                    "the locale `{locale}` has no separator between {from}s and {to}s"
+                ))
+            })
+    };
+
+    Ok((separator("hour", "minute")?, separator("minute", "second")?))
+}
+
+/// A [`PartsWrite`] that collects the outermost parts of a formatted value, merging any nested
+/// parts (such as the digits of a date time field) into their enclosing part.
+#[derive(Debug, Default)]
+struct FieldPartsCollector {
+    parts: Vec<(&'static str, String)>,
+    text: String,
+}
+
+impl FieldPartsCollector {
+    fn flush_text(&mut self) {
+        if !self.text.is_empty() {
+            self.parts.push(("literal", std::mem::take(&mut self.text)));
+        }
+    }
+
+    fn finish(mut self) -> Vec<(&'static str, String)> {
+        self.flush_text();
+        self.parts
+    }
+}
+
+impl std::fmt::Write for FieldPartsCollector {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.text.push_str(s);
+        Ok(())
+    }
+}
+
+impl PartsWrite for FieldPartsCollector {
+    type SubPartsWrite = Self;
+
+    fn with_part(
+        &mut self,
+        part: writeable::Part,
+        mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
+    ) -> std::fmt::Result {
+        self.flush_text();
+        let mut nested = Self::default();
+        f(&mut nested)?;
+        let text = nested.finish().into_iter().map(|(_, text)| text).collect();
+        self.parts.push((part.value, text));
+        Ok(())
+    }
+}
+
+/// Pushes the parts of the text surrounding the number of a formatted unit, splitting the unit
+/// label from the whitespace around it.
+fn push_unit_affix(parts: &mut Vec<DurationPart>, affix: &str, unit: &'static str) {
+    let trimmed = affix.trim_start();
+    let leading = &affix[..affix.len() - trimmed.len()];
+    let label = trimmed.trim_end();
+    let trailing = &trimmed[label.len()..];
+
+    for (typ, value) in [("literal", leading), ("unit", label), ("literal", trailing)] {
+        if !value.is_empty() {
+            parts.push(DurationPart {
+                typ,
+                value: value.to_owned(),
+                unit: Some(unit),
+            });
+        }
+    }
+}
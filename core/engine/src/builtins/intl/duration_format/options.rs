@@ -0,0 +1,280 @@
+use std::str::FromStr;
+
+use crate::{builtins::options::ParsableOptionType, js_string, JsString};
+
+/// The `style` option of an `Intl.DurationFormat` object.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum DurationFormatStyle {
+    Long,
+    #[default]
+    Short,
+    Narrow,
+    Digital,
+}
+
+impl DurationFormatStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Long => js_string!("long"),
+            Self::Short => js_string!("short"),
+            Self::Narrow => js_string!("narrow"),
+            Self::Digital => js_string!("digital"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDurationFormatStyleError;
+
+impl std::fmt::Display for ParseDurationFormatStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `long`, `short`, `narrow` or `digital`")
+    }
+}
+
+impl FromStr for DurationFormatStyle {
+    type Err = ParseDurationFormatStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            "digital" => Ok(Self::Digital),
+            _ => Err(ParseDurationFormatStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for DurationFormatStyle {}
+
+/// The style used to format a single unit of an `Intl.DurationFormat` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DurationUnitStyle {
+    Long,
+    Short,
+    Narrow,
+    Numeric,
+    TwoDigit,
+    /// Only set internally for the units smaller than a second that are formatted as the
+    /// fractional part of the previous unit.
+    Fractional,
+}
+
+impl DurationUnitStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Long => js_string!("long"),
+            Self::Short => js_string!("short"),
+            Self::Narrow => js_string!("narrow"),
+            Self::Numeric => js_string!("numeric"),
+            Self::TwoDigit => js_string!("2-digit"),
+            Self::Fractional => js_string!("fractional"),
+        }
+    }
+
+    /// Returns `true` if this style formats the unit as a number without a unit label.
+    pub(crate) const fn is_numeric(self) -> bool {
+        matches!(self, Self::Numeric | Self::TwoDigit | Self::Fractional)
+    }
+}
+
+impl From<DurationFormatStyle> for DurationUnitStyle {
+    fn from(style: DurationFormatStyle) -> Self {
+        match style {
+            DurationFormatStyle::Long => Self::Long,
+            DurationFormatStyle::Short => Self::Short,
+            DurationFormatStyle::Narrow => Self::Narrow,
+            DurationFormatStyle::Digital => Self::Numeric,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDurationUnitStyleError;
+
+impl std::fmt::Display for ParseDurationUnitStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `long`, `short`, `narrow`, `numeric` or `2-digit`")
+    }
+}
+
+impl FromStr for DurationUnitStyle {
+    type Err = ParseDurationUnitStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            _ => Err(ParseDurationUnitStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for DurationUnitStyle {}
+
+/// The display option of a single unit of an `Intl.DurationFormat` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DurationUnitDisplay {
+    Auto,
+    Always,
+}
+
+impl DurationUnitDisplay {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Auto => js_string!("auto"),
+            Self::Always => js_string!("always"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDurationUnitDisplayError;
+
+impl std::fmt::Display for ParseDurationUnitDisplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `auto` or `always`")
+    }
+}
+
+impl FromStr for DurationUnitDisplay {
+    type Err = ParseDurationUnitDisplayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            _ => Err(ParseDurationUnitDisplayError),
+        }
+    }
+}
+
+impl ParsableOptionType for DurationUnitDisplay {}
+
+/// The resolved style and display options of a single duration unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DurationUnitOptions {
+    pub(crate) style: DurationUnitStyle,
+    pub(crate) display: DurationUnitDisplay,
+}
+
+/// The units of a duration, in the order of the [Duration Instances Components table][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#table-durationformat
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum DurationUnit {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl DurationUnit {
+    /// The total number of units.
+    pub(crate) const COUNT: usize = 10;
+
+    /// All the units, from the largest to the smallest.
+    pub(crate) const ALL: [Self; Self::COUNT] = [
+        Self::Years,
+        Self::Months,
+        Self::Weeks,
+        Self::Days,
+        Self::Hours,
+        Self::Minutes,
+        Self::Seconds,
+        Self::Milliseconds,
+        Self::Microseconds,
+        Self::Nanoseconds,
+    ];
+
+    /// The name of the unit, which is also the name of its style option.
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Years => "years",
+            Self::Months => "months",
+            Self::Weeks => "weeks",
+            Self::Days => "days",
+            Self::Hours => "hours",
+            Self::Minutes => "minutes",
+            Self::Seconds => "seconds",
+            Self::Milliseconds => "milliseconds",
+            Self::Microseconds => "microseconds",
+            Self::Nanoseconds => "nanoseconds",
+        }
+    }
+
+    /// The name of the display option of the unit.
+    pub(crate) const fn display_name(self) -> &'static str {
+        match self {
+            Self::Years => "yearsDisplay",
+            Self::Months => "monthsDisplay",
+            Self::Weeks => "weeksDisplay",
+            Self::Days => "daysDisplay",
+            Self::Hours => "hoursDisplay",
+            Self::Minutes => "minutesDisplay",
+            Self::Seconds => "secondsDisplay",
+            Self::Milliseconds => "millisecondsDisplay",
+            Self::Microseconds => "microsecondsDisplay",
+            Self::Nanoseconds => "nanosecondsDisplay",
+        }
+    }
+
+    /// The `Intl.NumberFormat` unit identifier of the unit.
+    pub(crate) const fn number_format_unit(self) -> &'static str {
+        match self {
+            Self::Years => "year",
+            Self::Months => "month",
+            Self::Weeks => "week",
+            Self::Days => "day",
+            Self::Hours => "hour",
+            Self::Minutes => "minute",
+            Self::Seconds => "second",
+            Self::Milliseconds => "millisecond",
+            Self::Microseconds => "microsecond",
+            Self::Nanoseconds => "nanosecond",
+        }
+    }
+
+    /// Checks if `style` is in the list of styles allowed for this unit.
+    pub(crate) const fn allows(self, style: DurationUnitStyle) -> bool {
+        match self {
+            Self::Years | Self::Months | Self::Weeks | Self::Days => matches!(
+                style,
+                DurationUnitStyle::Long | DurationUnitStyle::Short | DurationUnitStyle::Narrow
+            ),
+            Self::Hours | Self::Minutes | Self::Seconds => {
+                !matches!(style, DurationUnitStyle::Fractional)
+            }
+            Self::Milliseconds | Self::Microseconds | Self::Nanoseconds => !matches!(
+                style,
+                DurationUnitStyle::TwoDigit | DurationUnitStyle::Fractional
+            ),
+        }
+    }
+
+    /// The style used for this unit when the base style is `"digital"`.
+    pub(crate) const fn digital_base(self) -> DurationUnitStyle {
+        match self {
+            Self::Years | Self::Months | Self::Weeks | Self::Days => DurationUnitStyle::Short,
+            _ => DurationUnitStyle::Numeric,
+        }
+    }
+
+    /// Returns `true` if this is one of the units smaller than a second.
+    pub(crate) const fn is_subsecond(self) -> bool {
+        matches!(
+            self,
+            Self::Milliseconds | Self::Microseconds | Self::Nanoseconds
+        )
+    }
+}
@@ -0,0 +1,190 @@
+use indoc::indoc;
+
+use crate::{js_string, run_test_actions, TestAction};
+
+const DURATION: &str = indoc! {r#"
+    var duration = { hours: 1, minutes: 2, seconds: 3, milliseconds: 400 };
+    var parts = (style) =>
+        new Intl.DurationFormat("en", { style })
+            .formatToParts(duration)
+            .map((p) => p.unit ? `${p.type}:${p.value}:${p.unit}` : `${p.type}:${p.value}`)
+            .join("|");
+"#};
+
+#[test]
+fn long() {
+    run_test_actions([
+        TestAction::run(DURATION),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'long' }).format(duration)",
+            js_string!("1 hour, 2 minutes, 3 seconds, 400 milliseconds"),
+        ),
+        TestAction::assert_eq(
+            "parts('long')",
+            js_string!(
+                "integer:1:hour|literal: :hour|unit:hour:hour|literal:, |\
+                 integer:2:minute|literal: :minute|unit:minutes:minute|literal:, |\
+                 integer:3:second|literal: :second|unit:seconds:second|literal:, |\
+                 integer:400:millisecond|literal: :millisecond|unit:milliseconds:millisecond"
+            ),
+        ),
+    ]);
+}
+
+#[test]
+fn short() {
+    run_test_actions([
+        TestAction::run(DURATION),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en').format(duration)",
+            js_string!("1 hr, 2 min, 3 sec, 400 ms"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'short' }).format({ years: 2, days: 1 })",
+            js_string!("2 yrs, 1 day"),
+        ),
+        TestAction::assert_eq(
+            "parts('short')",
+            js_string!(
+                "integer:1:hour|literal: :hour|unit:hr:hour|literal:, |\
+                 integer:2:minute|literal: :minute|unit:min:minute|literal:, |\
+                 integer:3:second|literal: :second|unit:sec:second|literal:, |\
+                 integer:400:millisecond|literal: :millisecond|unit:ms:millisecond"
+            ),
+        ),
+    ]);
+}
+
+#[test]
+fn narrow() {
+    run_test_actions([
+        TestAction::run(DURATION),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'narrow' }).format(duration)",
+            js_string!("1h 2m 3s 400ms"),
+        ),
+        TestAction::assert_eq(
+            "parts('narrow')",
+            js_string!(
+                "integer:1:hour|unit:h:hour|literal: |\
+                 integer:2:minute|unit:m:minute|literal: |\
+                 integer:3:second|unit:s:second|literal: |\
+                 integer:400:millisecond|unit:ms:millisecond"
+            ),
+        ),
+    ]);
+}
+
+#[test]
+fn digital() {
+    run_test_actions([
+        TestAction::run(DURATION),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'digital' }).format(duration)",
+            js_string!("1:02:03.4"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'digital', fractionalDigits: 3 })
+                .format({ days: 1, minutes: 5 })",
+            js_string!("1 day, 0:05:00.000"),
+        ),
+        TestAction::assert_eq(
+            "parts('digital')",
+            js_string!(
+                "integer:1:hour|literal::|integer:02:minute|literal::|\
+                 integer:03:second|decimal:.:second|fraction:4:second"
+            ),
+        ),
+    ]);
+}
+
+#[test]
+fn digital_separators() {
+    run_test_actions([TestAction::assert_eq(
+        "new Intl.DurationFormat('fi', { style: 'digital' }).format({ hours: 1, minutes: 2, seconds: 3 })",
+        js_string!("1.02.03"),
+    )]);
+}
+
+#[test]
+fn invalid_durations() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var df = new Intl.DurationFormat("en");
+            var throws = (duration, error) => {
+                try {
+                    df.format(duration);
+                } catch (e) {
+                    return e instanceof error;
+                }
+                return false;
+            };
+        "#}),
+        TestAction::assert("throws({}, TypeError)"),
+        TestAction::assert("throws(undefined, TypeError)"),
+        TestAction::assert("throws({ hours: 1.5 }, RangeError)"),
+        TestAction::assert("throws({ hours: 1, minutes: -1 }, RangeError)"),
+        TestAction::assert("throws({ years: 2 ** 32 }, RangeError)"),
+    ]);
+}
+
+#[test]
+#[cfg(not(feature = "temporal"))]
+fn invalid_duration_messages() {
+    use crate::JsNativeErrorKind;
+
+    run_test_actions([
+        TestAction::assert_native_error(
+            "new Intl.DurationFormat('en').format({})",
+            JsNativeErrorKind::Type,
+            "duration object must have at least one duration property",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DurationFormat('en').format({ hours: 1.5 })",
+            JsNativeErrorKind::Range,
+            "`hours` must be an integral number",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DurationFormat('en').format({ hours: 1, minutes: -1 })",
+            JsNativeErrorKind::Range,
+            "duration is out of range",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DurationFormat('en').format('PT1H')",
+            JsNativeErrorKind::Range,
+            "cannot format a string as a duration",
+        ),
+    ]);
+}
+
+#[test]
+#[cfg(feature = "temporal")]
+fn temporal_durations() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var df = new Intl.DurationFormat("en", { style: "long" });
+            var duration = Temporal.Duration.from({ hours: 1, minutes: 2, seconds: 3 });
+        "#}),
+        TestAction::assert_eq(
+            "df.format(duration)",
+            js_string!("1 hour, 2 minutes, 3 seconds"),
+        ),
+        TestAction::assert_eq(
+            "df.format('P1DT12H')",
+            js_string!("1 day, 12 hours"),
+        ),
+        TestAction::assert_eq(
+            "df.format(duration.negated())",
+            js_string!("-1 hour, 2 minutes, 3 seconds"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'digital' }).format(duration)",
+            js_string!("1:02:03"),
+        ),
+        TestAction::assert_eq(
+            "df.formatToParts(Temporal.Duration.from('PT5M')).map((p) => `${p.type}:${p.value}`).join('|')",
+            js_string!("integer:5|literal: |unit:minutes"),
+        ),
+        TestAction::assert("df.format('PT1H') === df.format({ hours: 1 })"),
+    ]);
+}
@@ -0,0 +1,418 @@
+//! Enumeration of the values supported by the `Intl` services, used by `Intl.supportedValuesOf`
+//! and the locale info methods of `Intl.Locale`.
+
+use icu_calendar::{
+    preferences::{CalendarAlgorithm, CalendarPreferences},
+    types::Weekday,
+    week::{WeekInformation, WeekPreferences},
+    AnyCalendarKind,
+};
+use icu_collator::provider::CollationMetadataV1;
+use icu_datetime::{
+    fieldsets::{T, YMD},
+    input::Time,
+    DateTimeFormatter, DateTimeFormatterPreferences, NoCalendarFormatter,
+};
+use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV1};
+use icu_experimental::dimension::provider::currency_displayname::CurrencyDisplaynameV1;
+use icu_locale::{
+    extensions::unicode::Value, extensions_unicode_key as key, langid, subtags::Region, Direction,
+    LanguageIdentifier, Locale,
+};
+use icu_provider::{
+    DataIdentifierBorrowed, DataLocale, DataMarkerAttributes, DataProvider, DataRequest,
+    DataRequestMetadata,
+};
+
+use crate::{
+    builtins::{
+        intl::number_format::SANCTIONED_UNITS,
+        time_zone::{
+            available_primary_time_zone_identifiers, primary_time_zone_identifiers_of_region,
+        },
+    },
+    context::icu::IntlProvider,
+    JsNativeError, JsResult,
+};
+
+use super::validate_extension;
+
+/// The calendars supported by ICU4X's `AnyCalendar`, sorted alphabetically.
+///
+/// Only the calendars for which the provider has date formatting data are available.
+const CALENDARS: [&str; 16] = [
+    "buddhist",
+    "chinese",
+    "coptic",
+    "dangi",
+    "ethioaa",
+    "ethiopic",
+    "gregory",
+    "hebrew",
+    "indian",
+    "islamic-civil",
+    "islamic-tbla",
+    "islamic-umalqura",
+    "iso8601",
+    "japanese",
+    "persian",
+    "roc",
+];
+
+/// The tailored collation types, sorted alphabetically, with a language that has data for
+/// each one.
+///
+/// `"standard"` and `"search"` are not included, since they cannot be selected with the
+/// `collation` option of `Intl.Collator`.
+const COLLATIONS: [(&str, LanguageIdentifier); 12] = [
+    ("compat", langid!("ar")),
+    ("dict", langid!("si")),
+    ("emoji", langid!("und")),
+    ("eor", langid!("und")),
+    ("phonebk", langid!("de")),
+    ("phonetic", langid!("ln")),
+    ("pinyin", langid!("zh")),
+    ("searchjl", langid!("ko")),
+    ("stroke", langid!("zh")),
+    ("trad", langid!("es")),
+    ("unihan", langid!("zh")),
+    ("zhuyin", langid!("zh")),
+];
+
+/// The [numbering systems with simple digit mappings][spec], sorted alphabetically.
+///
+/// [spec]: https://tc39.es/ecma402/#table-numbering-system-digits
+const NUMBERING_SYSTEMS: [&str; 77] = [
+    "adlm", "ahom", "arab", "arabext", "bali", "beng", "bhks", "brah", "cakm", "cham", "deva",
+    "diak", "fullwide", "gara", "gong", "gonm", "gujr", "gukh", "guru", "hanidec", "hmng", "hmnp",
+    "java", "kali", "kawi", "khmr", "knda", "krai", "lana", "lanatham", "laoo", "latn", "lepc",
+    "limb", "mathbold", "mathdbl", "mathmono", "mathsanb", "mathsans", "mlym", "modi", "mong",
+    "mroo", "mtei", "mymr", "mymrepka", "mymrpao", "mymrshan", "mymrtlng", "nagm", "newa", "nkoo",
+    "olck", "onao", "orya", "osma", "outlined", "rohg", "saur", "segment", "shrd", "sind", "sinh",
+    "sora", "sund", "sunu", "takr", "talu", "tamldec", "telu", "thai", "tibt", "tirh", "tnsa",
+    "vaii", "wara", "wcho",
+];
+
+/// Returns the [`AnyCalendarKind`] of the calendar type `ca`.
+fn calendar_kind(ca: &str) -> Option<AnyCalendarKind> {
+    let value = Value::try_from_str(ca).ok()?;
+    let algorithm = CalendarAlgorithm::try_from(&value).ok()?;
+    AnyCalendarKind::try_from(algorithm).ok()
+}
+
+/// Checks if the provider has the data to format dates of `locale` in the calendar `ca`.
+fn validate_calendar(locale: &Locale, ca: &str, provider: &IntlProvider) -> bool {
+    let (Some(kind), Ok(value)) = (calendar_kind(ca), Value::try_from_str(ca)) else {
+        return false;
+    };
+    let mut locale = locale.clone();
+    locale.extensions.unicode.keywords.set(key!("ca"), value);
+
+    // ICU4X falls back to the default calendar of the locale if the requested calendar is
+    // not supported, so the calendar of the formatter must be checked as well.
+    DateTimeFormatter::try_new_with_buffer_provider(
+        provider.erased_provider(),
+        DateTimeFormatterPreferences::from(&locale),
+        YMD::medium(),
+    )
+    .is_ok_and(|formatter| formatter.calendar().kind() == kind)
+}
+
+/// Abstract operation [`AvailableCanonicalCalendars ( )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availablecanonicalcalendars
+pub(crate) fn available_canonical_calendars(provider: &IntlProvider) -> Vec<String> {
+    let root = Locale::UNKNOWN;
+    CALENDARS
+        .into_iter()
+        .filter(|ca| validate_calendar(&root, ca, provider))
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Abstract operation [`AvailableCanonicalCollations ( )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availablecanonicalcollations
+pub(crate) fn available_canonical_collations(provider: &IntlProvider) -> Vec<String> {
+    COLLATIONS
+        .into_iter()
+        .filter(|(co, language)| {
+            validate_extension::<CollationMetadataV1>(
+                language.clone(),
+                DataMarkerAttributes::from_str_or_panic(co),
+                provider,
+            )
+        })
+        .map(|(co, _)| co)
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Abstract operation [`AvailableCanonicalCurrencies ( )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availablecanonicalcurrencies
+pub(crate) fn available_canonical_currencies(provider: &IntlProvider) -> Vec<String> {
+    // The currencies are the well-formed currency codes with display names in the provider,
+    // which are stored with the currency code as the marker attributes.
+    let mut list = Vec::new();
+    for first in b'A'..=b'Z' {
+        for second in b'A'..=b'Z' {
+            for third in b'A'..=b'Z' {
+                let code = [first, second, third];
+                let code = std::str::from_utf8(&code).expect("currency codes must be ASCII");
+                if validate_extension::<CurrencyDisplaynameV1>(
+                    langid!("en"),
+                    DataMarkerAttributes::from_str_or_panic(code),
+                    provider,
+                ) {
+                    list.push(code.to_owned());
+                }
+            }
+        }
+    }
+    list
+}
+
+/// Abstract operation [`AvailableCanonicalNumberingSystems ( )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availablecanonicalnumberingsystems
+pub(crate) fn available_canonical_numbering_systems(provider: &IntlProvider) -> Vec<String> {
+    NUMBERING_SYSTEMS
+        .into_iter()
+        .filter(|nu| {
+            validate_extension::<DecimalDigitsV1>(
+                langid!("und"),
+                DataMarkerAttributes::from_str_or_panic(nu),
+                provider,
+            )
+        })
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Abstract operation [`AvailableCanonicalTimeZones ( )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availablecanonicaltimezones
+pub(crate) fn available_canonical_time_zones(provider: &IntlProvider) -> Vec<String> {
+    available_primary_time_zone_identifiers(provider)
+}
+
+/// Abstract operation [`AvailableCanonicalUnits ( )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availablecanonicalunits
+pub(crate) fn available_canonical_units() -> Vec<String> {
+    SANCTIONED_UNITS
+        .iter()
+        .map(|&unit| unit.to_owned())
+        .collect()
+}
+
+/// Gets the region of `locale`, adding the likely subtags if it doesn't have one.
+fn locale_region(locale: &Locale, provider: &IntlProvider) -> JsResult<Option<Region>> {
+    if let Some(region) = locale.id.region {
+        return Ok(Some(region));
+    }
+
+    let mut id = locale.id.clone();
+    provider.locale_expander()?.maximize(&mut id);
+    Ok(id.region)
+}
+
+/// Abstract operation [`CalendarsOfLocale ( loc )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-calendars-of-locale
+pub(crate) fn calendars_of_locale(
+    locale: &Locale,
+    provider: &IntlProvider,
+) -> JsResult<Vec<String>> {
+    // 1. If loc.[[Calendar]] is not undefined, then
+    if let Some(ca) = locale.extensions.unicode.keywords.get(&key!("ca")) {
+        // a. Return CreateArrayFromList(« loc.[[Calendar]] »).
+        return Ok(vec![ca.to_string()]);
+    }
+
+    // 2. Let list be a List of unique calendar types, sorted by the preference of loc.
+    // ICU4X doesn't include the calendar preferences of CLDR, so the list contains the calendar
+    // that `Intl.DateTimeFormat` uses by default for the region of the locale, followed by the
+    // Gregorian calendar, as long as the provider has their data.
+    let mut maximized = locale.clone();
+    maximized.id.region = locale_region(locale, provider)?;
+    let default = AnyCalendarKind::new(CalendarPreferences::from(&maximized));
+    let mut list = CALENDARS
+        .into_iter()
+        .filter(|&ca| ca == "gregory" || calendar_kind(ca) == Some(default))
+        .filter(|ca| validate_calendar(locale, ca, provider))
+        .map(str::to_owned)
+        .collect::<Vec<_>>();
+    list.sort_by_key(|ca| calendar_kind(ca) != Some(default));
+
+    // 3. Return CreateArrayFromList(list).
+    Ok(list)
+}
+
+/// Abstract operation [`CollationsOfLocale ( loc )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-collations-of-locale
+pub(crate) fn collations_of_locale(locale: &Locale, provider: &IntlProvider) -> Vec<String> {
+    // 1. If loc.[[Collation]] is not undefined, then
+    if let Some(co) = locale.extensions.unicode.keywords.get(&key!("co")) {
+        // a. Return CreateArrayFromList(« loc.[[Collation]] »).
+        return vec![co.to_string()];
+    }
+
+    // 2. Let list be a List of unique collation types for loc, excluding "standard" and
+    //    "search", sorted in ascending code unit order.
+    let list = COLLATIONS
+        .iter()
+        .map(|(co, _)| *co)
+        .filter(|co| {
+            validate_extension::<CollationMetadataV1>(
+                locale.id.clone(),
+                DataMarkerAttributes::from_str_or_panic(co),
+                provider,
+            )
+        })
+        .map(str::to_owned)
+        .collect::<Vec<_>>();
+
+    // 3. Return CreateArrayFromList(list).
+    list
+}
+
+/// Abstract operation [`HourCyclesOfLocale ( loc )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-hour-cycles-of-locale
+pub(crate) fn hour_cycles_of_locale(
+    locale: &Locale,
+    provider: &IntlProvider,
+) -> JsResult<Vec<String>> {
+    // 1. If loc.[[HourCycle]] is not undefined, then
+    if let Some(hc) = locale.extensions.unicode.keywords.get(&key!("hc")) {
+        // a. Return CreateArrayFromList(« loc.[[HourCycle]] »).
+        return Ok(vec![hc.to_string()]);
+    }
+
+    // 2. Let list be a List of the hour cycle types preferred by loc.
+    // The preferred hour cycle is the one used in the time pattern of the locale.
+    let formatter = NoCalendarFormatter::try_new_with_buffer_provider(
+        provider.erased_provider(),
+        DateTimeFormatterPreferences::from(locale),
+        T::hm(),
+    )
+    .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+    let time = Time::try_new(0, 0, 0, 0).expect("midnight must be a valid time");
+    let pattern = formatter.format(&time).pattern().to_string();
+
+    let mut quoted = false;
+    let hc = pattern
+        .chars()
+        .find_map(|c| {
+            if c == '\'' {
+                quoted = !quoted;
+                return None;
+            }
+            match c {
+                _ if quoted => None,
+                'K' => Some("h11"),
+                'h' => Some("h12"),
+                'H' => Some("h23"),
+                'k' => Some("h24"),
+                _ => None,
+            }
+        })
+        .unwrap_or("h23");
+
+    // 3. Return CreateArrayFromList(list).
+    Ok(vec![hc.to_owned()])
+}
+
+/// Abstract operation [`NumberingSystemsOfLocale ( loc )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-numbering-systems-of-locale
+pub(crate) fn numbering_systems_of_locale(
+    locale: &Locale,
+    provider: &IntlProvider,
+) -> JsResult<Vec<String>> {
+    // 1. If loc.[[NumberingSystem]] is not undefined, then
+    if let Some(nu) = locale.extensions.unicode.keywords.get(&key!("nu")) {
+        // a. Return CreateArrayFromList(« loc.[[NumberingSystem]] »).
+        return Ok(vec![nu.to_string()]);
+    }
+
+    // 2. Let list be a List of unique numbering systems, sorted by the preference of loc.
+    let locale = DataLocale::from(locale);
+    let req = DataRequest {
+        id: DataIdentifierBorrowed::for_locale(&locale),
+        metadata: DataRequestMetadata::default(),
+    };
+    let data = DataProvider::<DecimalSymbolsV1>::load(provider, req)
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+    // 3. Return CreateArrayFromList(list).
+    Ok(vec![data.payload.get().numsys().to_owned()])
+}
+
+/// Abstract operation [`TextDirectionOfLocale ( loc )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-text-direction-of-locale
+pub(crate) fn text_direction_of_locale(
+    locale: &Locale,
+    provider: &IntlProvider,
+) -> JsResult<Option<&'static str>> {
+    // 1. Let locale be loc.[[Locale]].
+    // 2. If the default general ordering of characters within a line in locale is right-to-left, return "rtl".
+    // 3. If the default general ordering of characters within a line in locale is left-to-right, return "ltr".
+    // 4. Return undefined.
+    Ok(match provider.locale_directionality()?.get(&locale.id) {
+        Some(Direction::RightToLeft) => Some("rtl"),
+        Some(Direction::LeftToRight) => Some("ltr"),
+        _ => None,
+    })
+}
+
+/// Abstract operation [`TimeZonesOfLocale ( loc )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-time-zones-of-locale
+pub(crate) fn time_zones_of_locale(region: Region, provider: &IntlProvider) -> Vec<String> {
+    // 1. Let region be GetLocaleRegion(loc.[[Locale]]).
+    // 2. Assert: region is not undefined.
+    // 3. Let list be a List of the unique canonical time zone identifiers which are in common use in region.
+    // 4. Sort list according to lexicographic code unit order.
+    // 5. Return CreateArrayFromList(list).
+    primary_time_zone_identifiers_of_region(region.as_str(), provider)
+}
+
+/// Abstract operation [`WeekInfoOfLocale ( loc )`][spec].
+///
+/// Returns the first day of the week and the weekend days of `locale`, numbered from 1 for
+/// Monday to 7 for Sunday.
+///
+/// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-week-info-of-locale
+pub(crate) fn week_info_of_locale(
+    locale: &Locale,
+    provider: &IntlProvider,
+) -> JsResult<(u8, Vec<u8>)> {
+    const fn weekday_number(weekday: Weekday) -> u8 {
+        match weekday {
+            Weekday::Monday => 1,
+            Weekday::Tuesday => 2,
+            Weekday::Wednesday => 3,
+            Weekday::Thursday => 4,
+            Weekday::Friday => 5,
+            Weekday::Saturday => 6,
+            Weekday::Sunday => 7,
+        }
+    }
+
+    let info = WeekInformation::try_new_with_buffer_provider(
+        provider.erased_provider(),
+        WeekPreferences::from(locale),
+    )
+    .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+    let first_day = weekday_number(info.first_weekday);
+    let mut weekend = info.weekend().map(weekday_number).collect::<Vec<_>>();
+    weekend.sort_unstable();
+
+    Ok((first_day, weekend))
+}
@@ -8,13 +8,17 @@ use icu_locale::{
 #[cfg(all(test, feature = "intl_bundled"))]
 mod tests;
 
+mod info;
 mod utils;
+pub(crate) use info::*;
 pub(crate) use utils::*;
 
 mod options;
 
 use crate::{
-    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    builtins::{
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     js_string,
     object::{internal_methods::get_prototype_from_constructor, JsObject},
@@ -83,6 +87,17 @@ impl IntrinsicObject for Locale {
             .method(Self::maximize, js_string!("maximize"), 0)
             .method(Self::minimize, js_string!("minimize"), 0)
             .method(Self::to_string, js_string!("toString"), 0)
+            .method(Self::get_calendars, js_string!("getCalendars"), 0)
+            .method(Self::get_collations, js_string!("getCollations"), 0)
+            .method(Self::get_hour_cycles, js_string!("getHourCycles"), 0)
+            .method(
+                Self::get_numbering_systems,
+                js_string!("getNumberingSystems"),
+                0,
+            )
+            .method(Self::get_time_zones, js_string!("getTimeZones"), 0)
+            .method(Self::get_text_info, js_string!("getTextInfo"), 0)
+            .method(Self::get_week_info, js_string!("getWeekInfo"), 0)
             .accessor(
                 js_string!("baseName"),
                 Some(base_name),
@@ -163,7 +178,7 @@ impl BuiltInObject for Locale {
 
 impl BuiltInConstructor for Locale {
     const LENGTH: usize = 1;
-    const P: usize = 14;
+    const P: usize = 21;
     const SP: usize = 0;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
@@ -758,4 +773,281 @@ impl Locale {
         // 4. Return the substring of variants from 1.
         Ok(js_string!(loc.id.variants.to_string()).into())
     }
+
+    /// [`Intl.Locale.prototype.getCalendars ( )`][spec].
+    ///
+    /// Returns a list of the calendar types preferred by this locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getCalendars
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getCalendars
+    pub(crate) fn get_calendars(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this
+            .as_object()
+            .and_then(|o| o.downcast_ref::<icu_locale::Locale>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`Locale.prototype.getCalendars` can only be called on a `Locale` object",
+                )
+            })?
+            .clone();
+
+        // 3. Return CalendarsOfLocale(loc).
+        let list = calendars_of_locale(&loc, context.intl_provider())?;
+        Ok(Array::create_array_from_list(
+            list.into_iter().map(|value| js_string!(value).into()),
+            context,
+        )
+        .into())
+    }
+
+    /// [`Intl.Locale.prototype.getCollations ( )`][spec].
+    ///
+    /// Returns a list of the collation types preferred by this locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getCollations
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getCollations
+    pub(crate) fn get_collations(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this
+            .as_object()
+            .and_then(|o| o.downcast_ref::<icu_locale::Locale>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`Locale.prototype.getCollations` can only be called on a `Locale` object",
+                )
+            })?
+            .clone();
+
+        // 3. Return CollationsOfLocale(loc).
+        let list = collations_of_locale(&loc, context.intl_provider());
+        Ok(Array::create_array_from_list(
+            list.into_iter().map(|value| js_string!(value).into()),
+            context,
+        )
+        .into())
+    }
+
+    /// [`Intl.Locale.prototype.getHourCycles ( )`][spec].
+    ///
+    /// Returns a list of the hour cycle types preferred by this locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getHourCycles
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getHourCycles
+    pub(crate) fn get_hour_cycles(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this
+            .as_object()
+            .and_then(|o| o.downcast_ref::<icu_locale::Locale>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`Locale.prototype.getHourCycles` can only be called on a `Locale` object",
+                )
+            })?
+            .clone();
+
+        // 3. Return HourCyclesOfLocale(loc).
+        let list = hour_cycles_of_locale(&loc, context.intl_provider())?;
+        Ok(Array::create_array_from_list(
+            list.into_iter().map(|value| js_string!(value).into()),
+            context,
+        )
+        .into())
+    }
+
+    /// [`Intl.Locale.prototype.getNumberingSystems ( )`][spec].
+    ///
+    /// Returns a list of the numbering systems preferred by this locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getNumberingSystems
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getNumberingSystems
+    pub(crate) fn get_numbering_systems(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this
+            .as_object()
+            .and_then(|o| o.downcast_ref::<icu_locale::Locale>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`Locale.prototype.getNumberingSystems` can only be called on a `Locale` object",
+                )
+            })?
+            .clone();
+
+        // 3. Return NumberingSystemsOfLocale(loc).
+        let list = numbering_systems_of_locale(&loc, context.intl_provider())?;
+        Ok(Array::create_array_from_list(
+            list.into_iter().map(|value| js_string!(value).into()),
+            context,
+        )
+        .into())
+    }
+
+    /// [`Intl.Locale.prototype.getTimeZones ( )`][spec].
+    ///
+    /// Returns a list of the time zones used in the region of this locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getTimeZones
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getTimeZones
+    pub(crate) fn get_time_zones(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this
+            .as_object()
+            .and_then(|o| o.downcast_ref::<icu_locale::Locale>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`Locale.prototype.getTimeZones` can only be called on a `Locale` object",
+                )
+            })?;
+
+        // 3. Let region be GetLocaleRegion(loc.[[Locale]]).
+        // 4. If region is undefined, return undefined.
+        let Some(region) = loc.id.region else {
+            return Ok(JsValue::undefined());
+        };
+
+        // 5. Return TimeZonesOfLocale(loc).
+        let list = time_zones_of_locale(region, context.intl_provider());
+        Ok(Array::create_array_from_list(
+            list.into_iter().map(|value| JsString::from(value).into()),
+            context,
+        )
+        .into())
+    }
+
+    /// [`Intl.Locale.prototype.getTextInfo ( )`][spec].
+    ///
+    /// Returns the text layout information of this locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getTextInfo
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getTextInfo
+    pub(crate) fn get_text_info(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this
+            .as_object()
+            .and_then(|o| o.downcast_ref::<icu_locale::Locale>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`Locale.prototype.getTextInfo` can only be called on a `Locale` object",
+                )
+            })?
+            .clone();
+
+        // 3. Let info be OrdinaryObjectCreate(%Object.prototype%).
+        let info = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. Let dir be TextDirectionOfLocale(loc).
+        let dir = text_direction_of_locale(&loc, context.intl_provider())?
+            .map(|dir| JsValue::from(js_string!(dir)))
+            .unwrap_or_default();
+
+        // 5. Perform ! CreateDataPropertyOrThrow(info, "direction", dir).
+        info.create_data_property_or_throw(js_string!("direction"), dir, context)
+            .expect("operation must not fail per the spec");
+
+        // 6. Return info.
+        Ok(info.into())
+    }
+
+    /// [`Intl.Locale.prototype.getWeekInfo ( )`][spec].
+    ///
+    /// Returns the week information of this locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getWeekInfo
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getWeekInfo
+    pub(crate) fn get_week_info(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this
+            .as_object()
+            .and_then(|o| o.downcast_ref::<icu_locale::Locale>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`Locale.prototype.getWeekInfo` can only be called on a `Locale` object",
+                )
+            })?
+            .clone();
+
+        // 3. Let info be OrdinaryObjectCreate(%Object.prototype%).
+        let info = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. Let wi be WeekInfoOfLocale(loc).
+        let (first_day, weekend) = week_info_of_locale(&loc, context.intl_provider())?;
+
+        // 5. Let we be CreateArrayFromList(wi.[[Weekend]]).
+        let we = Array::create_array_from_list(weekend.into_iter().map(JsValue::from), context);
+
+        // 6. Perform ! CreateDataPropertyOrThrow(info, "firstDay", wi.[[FirstDay]]).
+        info.create_data_property_or_throw(js_string!("firstDay"), first_day, context)
+            .expect("operation must not fail per the spec");
+
+        // 7. Perform ! CreateDataPropertyOrThrow(info, "weekend", we).
+        info.create_data_property_or_throw(js_string!("weekend"), we, context)
+            .expect("operation must not fail per the spec");
+
+        // 8. Return info.
+        Ok(info.into())
+    }
 }
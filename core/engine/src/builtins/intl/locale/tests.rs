@@ -8,13 +8,20 @@ use icu_provider::{
     DataIdentifierBorrowed, DataLocale, DataProvider, DataRequest, DataRequestMetadata,
 };
 
+use indoc::indoc;
+
 use crate::{
     builtins::intl::{
-        locale::{default_locale, resolve_locale},
+        locale::{
+            available_canonical_calendars, available_canonical_collations,
+            available_canonical_currencies, available_canonical_numbering_systems,
+            available_canonical_time_zones, default_locale, resolve_locale,
+        },
         options::{IntlOptions, LocaleMatcher},
         Service,
     },
     context::icu::IntlProvider,
+    js_string, run_test_actions, JsValue, TestAction,
 };
 
 #[derive(Debug)]
@@ -96,3 +103,84 @@ fn locale_resolution() {
         resolve_locale::<TestService>([locale!("bn-Arab")], &mut options, &provider).unwrap();
     assert_eq!(locale, "bn-u-nu-beng".parse().unwrap());
 }
+
+#[test]
+fn supported_values() {
+    let provider = IntlProvider::try_new_buffer(boa_icu_provider::buffer());
+
+    let numbering_systems = available_canonical_numbering_systems(&provider);
+    assert!(numbering_systems.iter().any(|nu| nu == "latn"));
+    assert!(numbering_systems.iter().any(|nu| nu == "arab"));
+    assert!(numbering_systems.is_sorted());
+
+    let collations = available_canonical_collations(&provider);
+    assert!(collations.iter().any(|co| co == "phonebk"));
+    assert!(!collations.iter().any(|co| co == "standard"));
+    assert!(!collations.iter().any(|co| co == "search"));
+    assert!(collations.is_sorted());
+
+    let calendars = available_canonical_calendars(&provider);
+    assert!(calendars.iter().any(|ca| ca == "gregory"));
+    assert!(calendars.iter().any(|ca| ca == "japanese"));
+    assert!(calendars.iter().any(|ca| ca == "islamic-umalqura"));
+    assert!(calendars.is_sorted());
+
+    let currencies = available_canonical_currencies(&provider);
+    assert!(currencies.iter().any(|cu| cu == "USD"));
+    assert!(currencies.iter().any(|cu| cu == "EUR"));
+    assert!(!currencies.iter().any(|cu| cu == "AAA"));
+    assert!(currencies.is_sorted());
+
+    let time_zones = available_canonical_time_zones(&provider);
+    assert!(time_zones.iter().any(|tz| tz == "UTC"));
+    assert!(time_zones.iter().any(|tz| tz == "America/New_York"));
+    assert!(!time_zones.iter().any(|tz| tz == "Asia/Calcutta"));
+    assert!(time_zones.is_sorted());
+}
+
+#[test]
+fn locale_info() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var enUS = new Intl.Locale("en-US");
+            var ar = new Intl.Locale("ar");
+            var ja = new Intl.Locale("ja-JP");
+        "#}),
+        TestAction::assert_eq("enUS.getCalendars().join()", js_string!("gregory")),
+        TestAction::assert_eq("ja.getCalendars().join()", js_string!("gregory")),
+        TestAction::assert_eq(
+            "new Intl.Locale('th').getCalendars().join()",
+            js_string!("buddhist,gregory"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('fa').getCalendars().join()",
+            js_string!("persian,gregory"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('th-u-ca-gregory').getCalendars().join()",
+            js_string!("gregory"),
+        ),
+        TestAction::assert_eq("enUS.getHourCycles().join()", js_string!("h12")),
+        TestAction::assert_eq("ja.getHourCycles().join()", js_string!("h23")),
+        TestAction::assert_eq("enUS.getNumberingSystems().join()", js_string!("latn")),
+        TestAction::assert_eq(
+            "new Intl.Locale('en-u-nu-arab').getNumberingSystems().join()",
+            js_string!("arab"),
+        ),
+        TestAction::assert("new Intl.Locale('de').getCollations().includes('phonebk')"),
+        TestAction::assert_eq("enUS.getTextInfo().direction", js_string!("ltr")),
+        TestAction::assert_eq("ar.getTextInfo().direction", js_string!("rtl")),
+        TestAction::assert_eq("enUS.getWeekInfo().firstDay", 7),
+        TestAction::assert_eq("enUS.getWeekInfo().weekend.join()", js_string!("6,7")),
+        TestAction::assert_eq("new Intl.Locale('de').getWeekInfo().firstDay", 1),
+        TestAction::assert("enUS.getTimeZones().includes('America/New_York')"),
+        TestAction::assert_eq(
+            "new Intl.Locale('en-IN').getTimeZones().join()",
+            js_string!("Asia/Kolkata"),
+        ),
+        TestAction::assert_eq("new Intl.Locale('en').getTimeZones()", JsValue::undefined()),
+        TestAction::assert("Intl.supportedValuesOf('timeZone').includes('Europe/Berlin')"),
+        TestAction::assert("Intl.supportedValuesOf('currency').includes('JPY')"),
+        TestAction::assert("Intl.supportedValuesOf('calendar').includes('hebrew')"),
+    ]);
+}
@@ -22,7 +22,7 @@ use crate::{
     realm::Realm,
     string::StaticJsStrings,
     symbol::JsSymbol,
-    Context, JsArgs, JsData, JsResult, JsString, JsValue,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
 };
 
 use boa_gc::{Finalize, Trace};
@@ -31,15 +31,20 @@ use static_assertions::const_assert;
 
 pub(crate) mod collator;
 pub(crate) mod date_time_format;
+pub(crate) mod display_names;
+pub(crate) mod duration_format;
 pub(crate) mod list_format;
 pub(crate) mod locale;
 pub(crate) mod number_format;
 pub(crate) mod plural_rules;
+pub(crate) mod relative_time_format;
 pub(crate) mod segmenter;
 
 pub(crate) use self::{
-    collator::Collator, date_time_format::DateTimeFormat, list_format::ListFormat, locale::Locale,
-    number_format::NumberFormat, plural_rules::PluralRules, segmenter::Segmenter,
+    collator::Collator, date_time_format::DateTimeFormat, display_names::DisplayNames,
+    duration_format::DurationFormat, list_format::ListFormat, locale::Locale,
+    number_format::NumberFormat, plural_rules::PluralRules,
+    relative_time_format::RelativeTimeFormat, segmenter::Segmenter,
 };
 
 mod options;
@@ -48,9 +53,12 @@ mod options;
 // Hopefully, we'll be able to migrate this to the definition of `Service` in the future
 // (https://github.com/rust-lang/rust/issues/76560)
 const_assert! {!<Collator as Service>::LangMarker::INFO.is_singleton}
+const_assert! {!<DisplayNames as Service>::LangMarker::INFO.is_singleton}
+const_assert! {!<DurationFormat as Service>::LangMarker::INFO.is_singleton}
 const_assert! {!<ListFormat as Service>::LangMarker::INFO.is_singleton}
 const_assert! {!<NumberFormat as Service>::LangMarker::INFO.is_singleton}
 const_assert! {!<PluralRules as Service>::LangMarker::INFO.is_singleton}
+const_assert! {!<RelativeTimeFormat as Service>::LangMarker::INFO.is_singleton}
 const_assert! {!<Segmenter as Service>::LangMarker::INFO.is_singleton}
 
 /// JavaScript `Intl` object.
@@ -86,6 +94,24 @@ impl IntrinsicObject for Intl {
                 realm.intrinsics().constructors().collator().constructor(),
                 Collator::ATTRIBUTE,
             )
+            .static_property(
+                DisplayNames::NAME,
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .display_names()
+                    .constructor(),
+                DisplayNames::ATTRIBUTE,
+            )
+            .static_property(
+                DurationFormat::NAME,
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .duration_format()
+                    .constructor(),
+                DurationFormat::ATTRIBUTE,
+            )
             .static_property(
                 ListFormat::NAME,
                 realm
@@ -114,6 +140,15 @@ impl IntrinsicObject for Intl {
                     .constructor(),
                 PluralRules::ATTRIBUTE,
             )
+            .static_property(
+                RelativeTimeFormat::NAME,
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .relative_time_format()
+                    .constructor(),
+                RelativeTimeFormat::ATTRIBUTE,
+            )
             .static_property(
                 DateTimeFormat::NAME,
                 realm
@@ -137,6 +172,11 @@ impl IntrinsicObject for Intl {
                 js_string!("getCanonicalLocales"),
                 1,
             )
+            .static_method(
+                Self::supported_values_of,
+                js_string!("supportedValuesOf"),
+                1,
+            )
             .build();
     }
 
@@ -176,6 +216,73 @@ impl Intl {
             context,
         )))
     }
+
+    /// `Intl.supportedValuesOf ( key )`
+    ///
+    /// Returns an array containing the supported calendar, collation, currency, numbering
+    /// system, time zone or unit identifiers.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN docs][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.supportedvaluesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/supportedValuesOf
+    pub(crate) fn supported_values_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let key be ? ToString(key).
+        let key = args.get_or_undefined(0).to_string(context)?;
+
+        let list = match key.to_std_string_escaped().as_str() {
+            // 2. If key is "calendar", then
+            //     a. Let list be a new empty List.
+            //     b. For each element identifier of AvailableCalendars(), do
+            //         i. Let canonical be CanonicalizeUValue("ca", identifier).
+            //         ii. If identifier is canonical, then
+            //             1. Append identifier to list.
+            "calendar" => locale::available_canonical_calendars(context.intl_provider()),
+            // 3. Else if key is "collation", then
+            //     a. Let list be AvailableCanonicalCollations( ).
+            "collation" => locale::available_canonical_collations(context.intl_provider()),
+            // 4. Else if key is "currency", then
+            //     a. Let list be AvailableCanonicalCurrencies( ).
+            "currency" => locale::available_canonical_currencies(context.intl_provider()),
+            // 5. Else if key is "numberingSystem", then
+            //     a. Let list be AvailableCanonicalNumberingSystems( ).
+            "numberingSystem" => {
+                locale::available_canonical_numbering_systems(context.intl_provider())
+            }
+            // 6. Else if key is "timeZone", then
+            //     a. Let list be a new empty List.
+            //     b. For each element timeZone of AvailableNamedTimeZoneIdentifiers(), do
+            //         i. If timeZone.[[Identifier]] is timeZone.[[PrimaryIdentifier]], then
+            //             1. Append timeZone.[[Identifier]] to list.
+            "timeZone" => locale::available_canonical_time_zones(context.intl_provider()),
+            // 7. Else if key is "unit", then
+            //     a. Let list be AvailableCanonicalUnits( ).
+            "unit" => locale::available_canonical_units(),
+            // 8. Else,
+            //     a. Throw a RangeError exception.
+            _ => {
+                return Err(JsNativeError::range()
+                    .with_message(format!(
+                        "`{}` is not a supported key",
+                        key.to_std_string_escaped()
+                    ))
+                    .into())
+            }
+        };
+
+        // 9. Return CreateArrayFromList( list ).
+        Ok(Array::create_array_from_list(
+            list.into_iter().map(|value| js_string!(value).into()),
+            context,
+        )
+        .into())
+    }
 }
 
 /// A service component that is part of the `Intl` API.
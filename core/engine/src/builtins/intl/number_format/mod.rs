@@ -1,4 +1,4 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, fmt::Write};
 
 use boa_gc::{Finalize, Trace};
 use fixed_decimal::{Decimal, FloatPrecision, SignDisplay};
@@ -18,6 +18,7 @@ use icu_provider::DataMarkerAttributes;
 use num_bigint::BigInt;
 use num_traits::Num;
 pub(crate) use options::*;
+use writeable::PartsWrite;
 
 use super::{
     locale::{canonicalize_locale_list, filter_locales, resolve_locale, validate_extension},
@@ -83,7 +84,7 @@ impl NumberFormat {
 
 #[derive(Debug, Clone)]
 pub(super) struct NumberFormatLocaleOptions {
-    numbering_system: Option<Value>,
+    pub(super) numbering_system: Option<Value>,
 }
 
 impl Service for NumberFormat {
@@ -864,3 +865,65 @@ pub(crate) fn js_string_to_fixed_decimal(string: &JsString) -> Option<Decimal> {
 
     Decimal::try_from_str(&s).ok()
 }
+
+/// Collects the parts of a formatted number as pairs of `(type, value)`.
+///
+/// Text written outside of any part has the type of the enclosing part, which allows splitting
+/// nested parts such as the group separators inside the integer part.
+#[derive(Debug)]
+pub(crate) struct NumberPartsCollector {
+    parts: Vec<(&'static str, String)>,
+    typ: &'static str,
+    text: String,
+}
+
+impl Default for NumberPartsCollector {
+    fn default() -> Self {
+        Self::with_type("literal")
+    }
+}
+
+impl NumberPartsCollector {
+    fn with_type(typ: &'static str) -> Self {
+        Self {
+            parts: Vec::new(),
+            typ,
+            text: String::new(),
+        }
+    }
+
+    fn flush_text(&mut self) {
+        if !self.text.is_empty() {
+            self.parts.push((self.typ, std::mem::take(&mut self.text)));
+        }
+    }
+
+    pub(crate) fn finish(mut self) -> Vec<(&'static str, String)> {
+        self.flush_text();
+        self.parts
+    }
+}
+
+impl Write for NumberPartsCollector {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.text.write_str(s)
+    }
+}
+
+impl PartsWrite for NumberPartsCollector {
+    type SubPartsWrite = Self;
+
+    fn with_part(
+        &mut self,
+        part: writeable::Part,
+        mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
+    ) -> std::fmt::Result {
+        self.flush_text();
+        // The values of the parts of `icu_decimal` are the same as the types of
+        // the parts of `Intl.NumberFormat`.
+        let mut nested = Self::with_type(part.value);
+        f(&mut nested)?;
+        self.parts.extend(nested.finish());
+        Ok(())
+    }
+}
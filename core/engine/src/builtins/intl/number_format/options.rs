@@ -302,6 +302,57 @@ impl Unit {
     }
 }
 
+/// The [sanctioned single unit identifiers][spec], sorted alphabetically.
+///
+/// [spec]: https://tc39.es/ecma402/#table-sanctioned-single-unit-identifiers
+pub(crate) const SANCTIONED_UNITS: [&str; 45] = [
+    "acre",
+    "bit",
+    "byte",
+    "celsius",
+    "centimeter",
+    "day",
+    "degree",
+    "fahrenheit",
+    "fluid-ounce",
+    "foot",
+    "gallon",
+    "gigabit",
+    "gigabyte",
+    "gram",
+    "hectare",
+    "hour",
+    "inch",
+    "kilobit",
+    "kilobyte",
+    "kilogram",
+    "kilometer",
+    "liter",
+    "megabit",
+    "megabyte",
+    "meter",
+    "microsecond",
+    "mile",
+    "mile-scandinavian",
+    "milliliter",
+    "millimeter",
+    "millisecond",
+    "minute",
+    "month",
+    "nanosecond",
+    "ounce",
+    "percent",
+    "petabyte",
+    "pound",
+    "second",
+    "stone",
+    "terabit",
+    "terabyte",
+    "week",
+    "yard",
+    "year",
+];
+
 #[derive(Debug)]
 pub(crate) struct ParseUnitError;
 
@@ -318,54 +369,6 @@ impl std::str::FromStr for Unit {
     ///
     /// [spec]: https://tc39.es/ecma402/#sec-iswellformedunitidentifier
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        const SANCTIONED_UNITS: [&str; 45] = [
-            "acre",
-            "bit",
-            "byte",
-            "celsius",
-            "centimeter",
-            "day",
-            "degree",
-            "fahrenheit",
-            "fluid-ounce",
-            "foot",
-            "gallon",
-            "gigabit",
-            "gigabyte",
-            "gram",
-            "hectare",
-            "hour",
-            "inch",
-            "kilobit",
-            "kilobyte",
-            "kilogram",
-            "kilometer",
-            "liter",
-            "megabit",
-            "megabyte",
-            "meter",
-            "microsecond",
-            "mile",
-            "mile-scandinavian",
-            "milliliter",
-            "millimeter",
-            "millisecond",
-            "minute",
-            "month",
-            "nanosecond",
-            "ounce",
-            "percent",
-            "petabyte",
-            "pound",
-            "second",
-            "stone",
-            "terabit",
-            "terabyte",
-            "week",
-            "yard",
-            "year",
-        ];
-
         let (num, den) = s
             .split_once("-per-")
             .filter(|(_, den)| !den.is_empty())
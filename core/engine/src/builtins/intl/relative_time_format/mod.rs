@@ -0,0 +1,635 @@
+use std::cell::OnceCell;
+
+use boa_gc::{Finalize, Trace};
+use fixed_decimal::{Decimal, FloatPrecision, Sign};
+use icu_decimal::preferences::NumberingSystem;
+use icu_experimental::relativetime::{
+    options::Numeric, provider::LongDayRelativeV1, RelativeTimeFormatter,
+    RelativeTimeFormatterOptions, RelativeTimeFormatterPreferences,
+};
+use icu_locale::{extensions::unicode::Value, Locale};
+use icu_provider::{buf::BufferMarker, DataError, DynamicDryDataProvider};
+use writeable::Writeable;
+
+use crate::{
+    builtins::{
+        options::get_option, Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
+        IntrinsicObject, OrdinaryObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{
+    locale::{
+        canonicalize_locale_list, filter_locales, numbering_systems_of_locale, resolve_locale,
+    },
+    number_format::{NumberFormat, NumberFormatLocaleOptions, NumberPartsCollector},
+    options::{coerce_options_to_object, IntlOptions},
+    Service,
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(all(test, feature = "intl_bundled"))]
+mod tests;
+
+#[derive(Trace, Finalize, JsData)]
+// Safety: `RelativeTimeFormat` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct RelativeTimeFormat {
+    locale: Locale,
+    numbering_system: JsString,
+    style: RelativeTimeStyle,
+    numeric: Numeric,
+    number_format: NumberFormat,
+    formatters: [OnceCell<RelativeTimeFormatter>; RelativeTimeUnit::COUNT],
+}
+
+impl std::fmt::Debug for RelativeTimeFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // `RelativeTimeFormatter` doesn't implement `Debug`.
+        f.debug_struct("RelativeTimeFormat")
+            .field("locale", &self.locale)
+            .field("numbering_system", &self.numbering_system)
+            .field("style", &self.style)
+            .field("numeric", &self.numeric)
+            .field("number_format", &self.number_format)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Service for RelativeTimeFormat {
+    type LangMarker = LongDayRelativeV1;
+
+    type LocaleOptions = NumberFormatLocaleOptions;
+
+    fn resolve(
+        locale: &mut Locale,
+        options: &mut Self::LocaleOptions,
+        provider: &crate::context::icu::IntlProvider,
+    ) {
+        // `Intl.RelativeTimeFormat` has the same relevant extension keys as `Intl.NumberFormat`.
+        <NumberFormat as Service>::resolve(locale, options, provider);
+    }
+}
+
+impl IntrinsicObject for RelativeTimeFormat {
+    fn init(realm: &Realm) {
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.RelativeTimeFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format, js_string!("format"), 2)
+            .method(Self::format_to_parts, js_string!("formatToParts"), 2)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for RelativeTimeFormat {
+    const NAME: JsString = StaticJsStrings::RELATIVE_TIME_FORMAT;
+}
+
+impl BuiltInConstructor for RelativeTimeFormat {
+    const LENGTH: usize = 0;
+    const P: usize = 4;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::relative_time_format;
+
+    /// Constructor [`Intl.RelativeTimeFormat ( [ locales [ , options ] ] )`][spec].
+    ///
+    /// Constructor for `RelativeTimeFormat` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl-relativetimeformat-constructor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/RelativeTimeFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.RelativeTimeFormat` constructor without `new`")
+                .into());
+        }
+
+        // 2. Let relativeTimeFormat be ? OrdinaryCreateFromConstructor(NewTarget, "%Intl.RelativeTimeFormat.prototype%", « [[InitializedRelativeTimeFormat]], [[Locale]], [[DataLocale]], [[Style]], [[Numeric]], [[NumberFormat]], [[NumberingSystem]], [[PluralRules]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::relative_time_format,
+            context,
+        )?;
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 5. Let opt be a new Record.
+        // 6. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 7. Set opt.[[LocaleMatcher]] to matcher.
+        let matcher =
+            get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 8. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 9. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 10. Set opt.[[nu]] to numberingSystem.
+        let numbering_system =
+            get_option::<NumberingSystem>(&options, js_string!("numberingSystem"), context)?;
+
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: NumberFormatLocaleOptions {
+                numbering_system: numbering_system.map(Value::from),
+            },
+        };
+
+        // 11. Let r be ResolveLocale(%Intl.RelativeTimeFormat%.[[AvailableLocales]], requestedLocales, opt, %Intl.RelativeTimeFormat%.[[RelevantExtensionKeys]], %Intl.RelativeTimeFormat%.[[LocaleData]]).
+        // 12. Let locale be r.[[Locale]].
+        // 13. Set relativeTimeFormat.[[Locale]] to locale.
+        // 14. Set relativeTimeFormat.[[DataLocale]] to r.[[DataLocale]].
+        // 15. Set relativeTimeFormat.[[NumberingSystem]] to r.[[nu]].
+        let locale = resolve_locale::<Self>(
+            requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        )?;
+
+        // The resolved locale only contains the `nu` extension if it was supported, so the
+        // first numbering system of the locale is the resolved numbering system.
+        let numbering_system = numbering_systems_of_locale(&locale, context.intl_provider())?
+            .into_iter()
+            .next()
+            .map_or_else(|| js_string!("latn"), JsString::from);
+
+        // 16. Let style be ? GetOption(options, "style", string, « "long", "short", "narrow" », "long").
+        // 17. Set relativeTimeFormat.[[Style]] to style.
+        let style = get_option(&options, js_string!("style"), context)?.unwrap_or_default();
+
+        // 18. Let numeric be ? GetOption(options, "numeric", string, « "always", "auto" », "always").
+        // 19. Set relativeTimeFormat.[[Numeric]] to numeric.
+        let numeric =
+            get_option(&options, js_string!("numeric"), context)?.unwrap_or(Numeric::Always);
+
+        // 20. Let relativeTimeFormat.[[NumberFormat]] be ! Construct(%Intl.NumberFormat%, « locale »).
+        // The resolved locale already contains the `nu` extension, if any.
+        let number_format = NumberFormat::new(
+            &js_string!(locale.to_string()).into(),
+            &JsValue::undefined(),
+            context,
+        )?;
+
+        // 21. Let relativeTimeFormat.[[PluralRules]] be ! Construct(%Intl.PluralRules%, « locale »).
+        // The plural rules are handled by the ICU4X formatters.
+
+        let relative_time_format = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                locale,
+                numbering_system,
+                style,
+                numeric,
+                number_format,
+                formatters: Default::default(),
+            },
+        );
+
+        // 22. Return relativeTimeFormat.
+        Ok(relative_time_format.into())
+    }
+}
+
+impl RelativeTimeFormat {
+    /// [`Intl.RelativeTimeFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in relative
+    /// time formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.RelativeTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<Self>(requested_locales, options, context).map(JsValue::from)
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.format ( value, unit )`][spec].
+    ///
+    /// Formats a value and unit according to the locale and formatting options of this
+    /// `Intl.RelativeTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/format
+    fn format(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let rtf = this
+            .as_object()
+            .filter(|o| o.borrow().is::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`format` can only be called on an `Intl.RelativeTimeFormat` object",
+                )
+            })?;
+
+        // 3. Let value be ? ToNumber(value).
+        let value = args.get_or_undefined(0).to_number(context)?;
+
+        // 4. Let unit be ? ToString(unit).
+        let unit = args.get_or_undefined(1).to_string(context)?;
+
+        // 5. Return ? FormatRelativeTime(relativeTimeFormat, value, unit).
+        // `FormatRelativeTime ( relativeTimeFormat, value, unit )`
+        // https://tc39.es/ecma402/#sec-FormatRelativeTime
+
+        // 1. Let parts be ? PartitionRelativeTimePattern(relativeTimeFormat, value, unit).
+        let parts = rtf
+            .downcast_ref::<Self>()
+            .expect("checked above")
+            .partition_pattern(value, &unit.to_std_string_escaped(), context)?;
+
+        // 2. Let result be the empty String.
+        // 3. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        let result = parts.into_iter().map(|part| part.value).collect::<String>();
+
+        // 4. Return result.
+        Ok(js_string!(result).into())
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.formatToParts ( value, unit )`][spec].
+    ///
+    /// Returns an array of objects representing the relative time format in parts that can be
+    /// used for custom locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let rtf = this
+            .as_object()
+            .filter(|o| o.borrow().is::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`formatToParts` can only be called on an `Intl.RelativeTimeFormat` object",
+                )
+            })?;
+
+        // 3. Let value be ? ToNumber(value).
+        let value = args.get_or_undefined(0).to_number(context)?;
+
+        // 4. Let unit be ? ToString(unit).
+        let unit = args.get_or_undefined(1).to_string(context)?;
+
+        // 5. Return ? FormatRelativeTimeToParts(relativeTimeFormat, value, unit).
+        // `FormatRelativeTimeToParts ( relativeTimeFormat, value, unit )`
+        // https://tc39.es/ecma402/#sec-FormatRelativeTimeToParts
+
+        // 1. Let parts be ? PartitionRelativeTimePattern(relativeTimeFormat, value, unit).
+        let parts = rtf
+            .downcast_ref::<Self>()
+            .expect("checked above")
+            .partition_pattern(value, &unit.to_std_string_escaped(), context)?;
+
+        // 2. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .expect("creating an empty array with default proto must not fail");
+
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        for (n, part) in parts.into_iter().enumerate() {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            let o = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            o.create_data_property_or_throw(js_string!("type"), js_string!(part.typ), context)
+                .expect("operation must not fail per the spec");
+
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            o.create_data_property_or_throw(js_string!("value"), js_string!(part.value), context)
+                .expect("operation must not fail per the spec");
+
+            // d. If part.[[Unit]] is not empty, then
+            if let Some(unit) = part.unit {
+                // i. Perform ! CreateDataPropertyOrThrow(O, "unit", part.[[Unit]]).
+                o.create_data_property_or_throw(js_string!("unit"), unit.to_js_string(), context)
+                    .expect("operation must not fail per the spec");
+            }
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(𝔽(n)), O).
+            result
+                .create_data_property_or_throw(n, o, context)
+                .expect("operation must not fail per the spec");
+
+            // f. Increment n by 1.
+        }
+
+        // 5. Return result.
+        Ok(result.into())
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and formatting options
+    /// computed during the construction of the current `Intl.RelativeTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let rtf = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.RelativeTimeFormat` object",
+            )
+        })?;
+        let rtf = rtf.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.RelativeTimeFormat` object",
+            )
+        })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. For each row of Table 21, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of relativeTimeFormat's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Assert: v is not undefined.
+        //     d. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options
+            .create_data_property_or_throw(
+                js_string!("locale"),
+                js_string!(rtf.locale.to_string()),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(js_string!("style"), rtf.style.to_js_string(), context)
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(
+                js_string!("numeric"),
+                if matches!(rtf.numeric, Numeric::Auto) {
+                    js_string!("auto")
+                } else {
+                    js_string!("always")
+                },
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(
+                js_string!("numberingSystem"),
+                rtf.numbering_system.clone(),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+
+    /// Gets the ICU4X formatter for `unit`, loading its data on first use.
+    fn formatter(
+        &self,
+        unit: RelativeTimeUnit,
+        context: &Context,
+    ) -> JsResult<&RelativeTimeFormatter> {
+        let cell = &self.formatters[unit as usize];
+        if let Some(formatter) = cell.get() {
+            return Ok(formatter);
+        }
+
+        let options = RelativeTimeFormatterOptions {
+            numeric: self.numeric,
+        };
+
+        let formatter = new_formatter(
+            context.intl_provider().erased_provider(),
+            RelativeTimeFormatterPreferences::from(&self.locale),
+            options,
+            self.style,
+            unit,
+        )
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        Ok(cell.get_or_init(|| formatter))
+    }
+
+    /// Abstract operation [`PartitionRelativeTimePattern ( relativeTimeFormat, value, unit )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-PartitionRelativeTimePattern
+    fn partition_pattern(
+        &self,
+        value: f64,
+        unit: &str,
+        context: &Context,
+    ) -> JsResult<Vec<RelativeTimePart>> {
+        // 1. If value is NaN, +∞𝔽, or -∞𝔽, throw a RangeError exception.
+        if !value.is_finite() {
+            return Err(JsNativeError::range()
+                .with_message("relative time value must be finite")
+                .into());
+        }
+
+        // 2. Let unit be ? SingularRelativeTimeUnit(unit).
+        let unit = RelativeTimeUnit::from_singular_or_plural(unit)?;
+
+        // 3-10. Select the patterns for the locale, style and unit.
+        let formatter = self.formatter(unit, context)?;
+
+        // 11. If value is -0𝔽 or if value is less than -0𝔽, then
+        //     a. Let tl be "past".
+        //     b. NOTE: The "past" pattern formats the absolute value of `value`.
+        // 12. Else,
+        //     a. Let tl be "future".
+        // 13. Let fv be PartitionNumberPattern(relativeTimeFormat.[[NumberFormat]], value).
+        let mut number = Decimal::try_from_f64(value.abs(), FloatPrecision::RoundTrip)
+            .expect("`value` must be finite");
+        let mut number_parts = NumberPartsCollector::default();
+        self.number_format
+            .format(&mut number)
+            .write_to_parts(&mut number_parts)
+            .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+        let number_parts = number_parts.finish();
+
+        // `number` was rounded by the number formatter, so the relative time formatter will
+        // produce the same digits as the ones in `number_parts`.
+        if value.is_sign_negative() {
+            number.set_sign(Sign::Negative);
+        }
+
+        // 14. Let patterns be the patterns of the unit for the data locale and style.
+        // 15. Let pattern be patterns.[[<tl>]], which ICU4X selects using the sign of `number`.
+        // 16. Return MakePartsList(pattern, unit, fv).
+        let output = formatter.format(number);
+        let text = output.write_to_string();
+        let number_string = number_parts
+            .iter()
+            .map(|(_, value)| value.as_str())
+            .collect::<String>();
+
+        // Numeric "auto" patterns such as "yesterday" do not contain a number.
+        let Some(index) = text
+            .find(&number_string)
+            .filter(|_| !number_string.is_empty())
+        else {
+            return Ok(vec![RelativeTimePart {
+                typ: "literal",
+                value: text.into_owned(),
+                unit: None,
+            }]);
+        };
+
+        // `MakePartsList ( pattern, unit, parts )`
+        // https://tc39.es/ecma402/#sec-makepartslist
+        let mut result = Vec::with_capacity(number_parts.len() + 2);
+        let before = &text[..index];
+        if !before.is_empty() {
+            result.push(RelativeTimePart {
+                typ: "literal",
+                value: before.to_string(),
+                unit: None,
+            });
+        }
+        result.extend(
+            number_parts
+                .into_iter()
+                .map(|(typ, value)| RelativeTimePart {
+                    typ,
+                    value,
+                    unit: Some(unit),
+                }),
+        );
+        let after = &text[index + number_string.len()..];
+        if !after.is_empty() {
+            result.push(RelativeTimePart {
+                typ: "literal",
+                value: after.to_string(),
+                unit: None,
+            });
+        }
+
+        Ok(result)
+    }
+}
+
+/// Creates the ICU4X relative time formatter for a combination of style and unit.
+fn new_formatter(
+    provider: &dyn DynamicDryDataProvider<BufferMarker>,
+    prefs: RelativeTimeFormatterPreferences,
+    options: RelativeTimeFormatterOptions,
+    style: RelativeTimeStyle,
+    unit: RelativeTimeUnit,
+) -> Result<RelativeTimeFormatter, DataError> {
+    use RelativeTimeStyle as S;
+    use RelativeTimeUnit as U;
+
+    macro_rules! try_new {
+        ($constructor:ident) => {
+            RelativeTimeFormatter::$constructor(provider, prefs, options)
+        };
+    }
+
+    match (style, unit) {
+        (S::Long, U::Second) => try_new!(try_new_long_second_with_buffer_provider),
+        (S::Long, U::Minute) => try_new!(try_new_long_minute_with_buffer_provider),
+        (S::Long, U::Hour) => try_new!(try_new_long_hour_with_buffer_provider),
+        (S::Long, U::Day) => try_new!(try_new_long_day_with_buffer_provider),
+        (S::Long, U::Week) => try_new!(try_new_long_week_with_buffer_provider),
+        (S::Long, U::Month) => try_new!(try_new_long_month_with_buffer_provider),
+        (S::Long, U::Quarter) => try_new!(try_new_long_quarter_with_buffer_provider),
+        (S::Long, U::Year) => try_new!(try_new_long_year_with_buffer_provider),
+        (S::Short, U::Second) => try_new!(try_new_short_second_with_buffer_provider),
+        (S::Short, U::Minute) => try_new!(try_new_short_minute_with_buffer_provider),
+        (S::Short, U::Hour) => try_new!(try_new_short_hour_with_buffer_provider),
+        (S::Short, U::Day) => try_new!(try_new_short_day_with_buffer_provider),
+        (S::Short, U::Week) => try_new!(try_new_short_week_with_buffer_provider),
+        (S::Short, U::Month) => try_new!(try_new_short_month_with_buffer_provider),
+        (S::Short, U::Quarter) => try_new!(try_new_short_quarter_with_buffer_provider),
+        (S::Short, U::Year) => try_new!(try_new_short_year_with_buffer_provider),
+        (S::Narrow, U::Second) => try_new!(try_new_narrow_second_with_buffer_provider),
+        (S::Narrow, U::Minute) => try_new!(try_new_narrow_minute_with_buffer_provider),
+        (S::Narrow, U::Hour) => try_new!(try_new_narrow_hour_with_buffer_provider),
+        (S::Narrow, U::Day) => try_new!(try_new_narrow_day_with_buffer_provider),
+        (S::Narrow, U::Week) => try_new!(try_new_narrow_week_with_buffer_provider),
+        (S::Narrow, U::Month) => try_new!(try_new_narrow_month_with_buffer_provider),
+        (S::Narrow, U::Quarter) => try_new!(try_new_narrow_quarter_with_buffer_provider),
+        (S::Narrow, U::Year) => try_new!(try_new_narrow_year_with_buffer_provider),
+    }
+}
+
+/// A record returned by [`RelativeTimeFormat::partition_pattern`].
+#[derive(Debug)]
+struct RelativeTimePart {
+    typ: &'static str,
+    value: String,
+    unit: Option<RelativeTimeUnit>,
+}
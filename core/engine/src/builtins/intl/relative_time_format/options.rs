@@ -0,0 +1,124 @@
+use std::str::FromStr;
+
+use icu_experimental::relativetime::options::Numeric;
+
+use crate::{
+    builtins::options::{OptionType, ParsableOptionType},
+    js_string, Context, JsNativeError, JsResult, JsString, JsValue,
+};
+
+/// The `style` option of an `Intl.RelativeTimeFormat` object.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum RelativeTimeStyle {
+    #[default]
+    Long,
+    Short,
+    Narrow,
+}
+
+impl RelativeTimeStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Long => js_string!("long"),
+            Self::Short => js_string!("short"),
+            Self::Narrow => js_string!("narrow"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseRelativeTimeStyleError;
+
+impl std::fmt::Display for ParseRelativeTimeStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `long`, `short` or `narrow`")
+    }
+}
+
+impl FromStr for RelativeTimeStyle {
+    type Err = ParseRelativeTimeStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            _ => Err(ParseRelativeTimeStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for RelativeTimeStyle {}
+
+impl OptionType for Numeric {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "always" => Ok(Self::Always),
+            "auto" => Ok(Self::Auto),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `always` or `auto`")
+                .into()),
+        }
+    }
+}
+
+/// A time unit that can be formatted by an `Intl.RelativeTimeFormat` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RelativeTimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl RelativeTimeUnit {
+    /// The total number of units.
+    pub(crate) const COUNT: usize = 8;
+
+    /// Abstract operation [`SingularRelativeTimeUnit ( unit )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-singularrelativetimeunit
+    pub(crate) fn from_singular_or_plural(unit: &str) -> JsResult<Self> {
+        // 1. If unit is "seconds", return "second".
+        // 2. If unit is "minutes", return "minute".
+        // 3. If unit is "hours", return "hour".
+        // 4. If unit is "days", return "day".
+        // 5. If unit is "weeks", return "week".
+        // 6. If unit is "months", return "month".
+        // 7. If unit is "quarters", return "quarter".
+        // 8. If unit is "years", return "year".
+        // 9. If unit is not one of "second", "minute", "hour", "day", "week", "month", "quarter",
+        //    or "year", throw a RangeError exception.
+        // 10. Return unit.
+        match unit {
+            "second" | "seconds" => Ok(Self::Second),
+            "minute" | "minutes" => Ok(Self::Minute),
+            "hour" | "hours" => Ok(Self::Hour),
+            "day" | "days" => Ok(Self::Day),
+            "week" | "weeks" => Ok(Self::Week),
+            "month" | "months" => Ok(Self::Month),
+            "quarter" | "quarters" => Ok(Self::Quarter),
+            "year" | "years" => Ok(Self::Year),
+            _ => Err(JsNativeError::range()
+                .with_message(format!("`{unit}` is not a valid relative time unit"))
+                .into()),
+        }
+    }
+
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Second => js_string!("second"),
+            Self::Minute => js_string!("minute"),
+            Self::Hour => js_string!("hour"),
+            Self::Day => js_string!("day"),
+            Self::Week => js_string!("week"),
+            Self::Month => js_string!("month"),
+            Self::Quarter => js_string!("quarter"),
+            Self::Year => js_string!("year"),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use indoc::indoc;
+
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+
+#[test]
+fn format() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var rtf = new Intl.RelativeTimeFormat("en");
+            var auto = new Intl.RelativeTimeFormat("en", { numeric: "auto" });
+            var short = new Intl.RelativeTimeFormat("en", { style: "short" });
+        "#}),
+        TestAction::assert_eq("rtf.format(3, 'day')", js_string!("in 3 days")),
+        TestAction::assert_eq("rtf.format(-1, 'days')", js_string!("1 day ago")),
+        TestAction::assert_eq("rtf.format(1.5, 'hour')", js_string!("in 1.5 hours")),
+        TestAction::assert_eq("rtf.format(-1000, 'year')", js_string!("1,000 years ago")),
+        TestAction::assert_eq("auto.format(-1, 'day')", js_string!("yesterday")),
+        TestAction::assert_eq("auto.format(0, 'day')", js_string!("today")),
+        TestAction::assert_eq("auto.format(2, 'day')", js_string!("in 2 days")),
+        TestAction::assert_eq("short.format(5, 'minute')", js_string!("in 5 min.")),
+        TestAction::assert_native_error(
+            "rtf.format(1, 'century')",
+            JsNativeErrorKind::Range,
+            "`century` is not a valid relative time unit",
+        ),
+        TestAction::assert_native_error(
+            "rtf.format(Infinity, 'day')",
+            JsNativeErrorKind::Range,
+            "relative time value must be finite",
+        ),
+    ]);
+}
+
+#[test]
+fn format_to_parts() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var rtf = new Intl.RelativeTimeFormat("en");
+            var parts = rtf.formatToParts(1234.5, "second");
+            var auto = new Intl.RelativeTimeFormat("en", { numeric: "auto" }).formatToParts(1, "day");
+        "#}),
+        TestAction::assert_eq(
+            "parts.map(p => `${p.type}:${p.value}:${p.unit}`).join('|')",
+            js_string!(
+                "literal:in :undefined|integer:1:second|group:,:second|integer:234:second|\
+                 decimal:.:second|fraction:5:second|literal: seconds:undefined"
+            ),
+        ),
+        TestAction::assert_eq("auto.length", 1),
+        TestAction::assert_eq("auto[0].type", js_string!("literal")),
+        TestAction::assert_eq("auto[0].value", js_string!("tomorrow")),
+        TestAction::assert("!('unit' in auto[0])"),
+    ]);
+}
+
+#[test]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var options = new Intl.RelativeTimeFormat("en-US").resolvedOptions();
+            var custom = new Intl.RelativeTimeFormat("hi", {
+                numberingSystem: "deva",
+                style: "narrow",
+                numeric: "auto",
+            }).resolvedOptions();
+        "#}),
+        TestAction::assert_eq("options.locale", js_string!("en-US")),
+        TestAction::assert_eq("options.style", js_string!("long")),
+        TestAction::assert_eq("options.numeric", js_string!("always")),
+        TestAction::assert_eq("options.numberingSystem", js_string!("latn")),
+        TestAction::assert_eq(
+            "Object.keys(options).join()",
+            js_string!("locale,style,numeric,numberingSystem"),
+        ),
+        TestAction::assert_eq("custom.locale", js_string!("hi-u-nu-deva")),
+        TestAction::assert_eq("custom.style", js_string!("narrow")),
+        TestAction::assert_eq("custom.numeric", js_string!("auto")),
+        TestAction::assert_eq("custom.numberingSystem", js_string!("deva")),
+        TestAction::assert_eq(
+            "Object.keys(custom).join()",
+            js_string!("locale,style,numeric,numberingSystem"),
+        ),
+    ]);
+}
@@ -0,0 +1,614 @@
+//! This module implements the `%IteratorHelperPrototype%` object and the iterator helper objects
+//! returned by the lazy methods of `%Iterator.prototype%` and by `Iterator.concat`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [Iterator Sequencing proposal][proposal]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%-object
+//! [proposal]: https://tc39.es/proposal-iterator-sequencing/
+
+use crate::{
+    builtins::{
+        iterable::{
+            create_iter_result_object, get_iterator_direct, get_iterator_flattenable,
+            PrimitiveHandling,
+        },
+        BuiltInBuilder, IntrinsicObject,
+    },
+    context::intrinsics::Intrinsics,
+    error::JsNativeError,
+    js_string,
+    object::JsObject,
+    property::Attribute,
+    realm::Realm,
+    symbol::JsSymbol,
+    Context, JsData, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Gc, Trace};
+
+use super::IteratorRecord;
+
+/// The `[[GeneratorState]]` of an iterator helper object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HelperState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+/// The abstract closure that drives an iterator helper object.
+///
+/// Each variant stores the values captured by the closure of the corresponding
+/// `Iterator.prototype` method.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) enum HelperClosure {
+    /// Closure of [`Iterator.prototype.map ( mapper )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.map
+    Map { mapper: JsObject, counter: u64 },
+
+    /// Closure of [`Iterator.prototype.filter ( predicate )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.filter
+    Filter { predicate: JsObject, counter: u64 },
+
+    /// Closure of [`Iterator.prototype.take ( limit )`][spec].
+    ///
+    /// A `remaining` value of `None` represents `+∞`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.take
+    Take { remaining: Option<u64> },
+
+    /// Closure of [`Iterator.prototype.drop ( limit )`][spec].
+    ///
+    /// A `remaining` value of `None` represents `+∞`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.drop
+    Drop { remaining: Option<u64> },
+
+    /// Closure of [`Iterator.prototype.flatMap ( mapper )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.flatmap
+    FlatMap {
+        mapper: JsObject,
+        counter: u64,
+        inner: Option<IteratorRecord>,
+    },
+}
+
+impl HelperClosure {
+    /// Runs the closure until the next `Yield`, returning `None` if the closure completed.
+    fn resume(
+        &mut self,
+        iterated: &mut IteratorRecord,
+        context: &mut Context,
+    ) -> JsResult<Option<JsValue>> {
+        match self {
+            Self::Map { mapper, counter } => {
+                // a. Let counter be 0.
+                // b. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return ReturnCompletion(undefined).
+                let Some(value) = iterated.step_value(context)? else {
+                    return Ok(None);
+                };
+
+                //     iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                let mapped =
+                    mapper.call(&JsValue::undefined(), &[value, (*counter).into()], context);
+
+                //     iv. IfAbruptCloseIterator(mapped, iterated).
+                let mapped = match mapped {
+                    Ok(mapped) => mapped,
+                    Err(err) => return iterated.close(Err(err), context).map(|_| None),
+                };
+
+                //     vi. Set counter to counter + 1.
+                *counter += 1;
+
+                //     v. Let completion be Completion(Yield(mapped)).
+                Ok(Some(mapped))
+            }
+            Self::Filter { predicate, counter } => loop {
+                // b. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return ReturnCompletion(undefined).
+                let Some(value) = iterated.step_value(context)? else {
+                    return Ok(None);
+                };
+
+                //     iii. Let selected be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+                let selected = predicate.call(
+                    &JsValue::undefined(),
+                    &[value.clone(), (*counter).into()],
+                    context,
+                );
+
+                //     iv. IfAbruptCloseIterator(selected, iterated).
+                let selected = match selected {
+                    Ok(selected) => selected,
+                    Err(err) => return iterated.close(Err(err), context).map(|_| None),
+                };
+
+                //     vi. Set counter to counter + 1.
+                *counter += 1;
+
+                //     v. If ToBoolean(selected) is true, then
+                if selected.to_boolean() {
+                    // 1. Let completion be Completion(Yield(value)).
+                    return Ok(Some(value));
+                }
+            },
+            Self::Take { remaining } => {
+                // b. Repeat,
+                //     i. If remaining = 0, then
+                if *remaining == Some(0) {
+                    // 1. Return ? IteratorClose(iterated, ReturnCompletion(undefined)).
+                    return iterated
+                        .close(Ok(JsValue::undefined()), context)
+                        .map(|_| None);
+                }
+
+                //     ii. If remaining ≠ +∞, then
+                if let Some(remaining) = remaining {
+                    // 1. Set remaining to remaining - 1.
+                    *remaining -= 1;
+                }
+
+                //     iii. Let value be ? IteratorStepValue(iterated).
+                //     iv. If value is done, return ReturnCompletion(undefined).
+                //     v. Let completion be Completion(Yield(value)).
+                iterated.step_value(context)
+            }
+            Self::Drop { remaining } => {
+                // b. Repeat, while remaining > 0,
+                while *remaining != Some(0) {
+                    // i. If remaining ≠ +∞, then
+                    if let Some(remaining) = remaining {
+                        // 1. Set remaining to remaining - 1.
+                        *remaining -= 1;
+                    }
+
+                    // ii. Let next be ? IteratorStep(iterated).
+                    // iii. If next is done, return ReturnCompletion(undefined).
+                    if iterated.step(context)? {
+                        return Ok(None);
+                    }
+                }
+
+                // c. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return ReturnCompletion(undefined).
+                //     iii. Let completion be Completion(Yield(value)).
+                iterated.step_value(context)
+            }
+            Self::FlatMap {
+                mapper,
+                counter,
+                inner,
+            } => loop {
+                // viii. Repeat, while innerAlive is true,
+                if let Some(inner_iterator) = inner {
+                    // 1. Let innerValue be Completion(IteratorStepValue(innerIterator)).
+                    let inner_value = inner_iterator.step_value(context);
+
+                    // 2. IfAbruptCloseIterator(innerValue, iterated).
+                    let inner_value = match inner_value {
+                        Ok(inner_value) => inner_value,
+                        Err(err) => return iterated.close(Err(err), context).map(|_| None),
+                    };
+
+                    // 4. Else,
+                    //     a. Let completion be Completion(Yield(innerValue)).
+                    if let Some(value) = inner_value {
+                        return Ok(Some(value));
+                    }
+
+                    // 3. If innerValue is done, then
+                    //     a. Set innerAlive to false.
+                    *inner = None;
+
+                    // ix. Set counter to counter + 1.
+                    *counter += 1;
+                }
+
+                // b. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return ReturnCompletion(undefined).
+                let Some(value) = iterated.step_value(context)? else {
+                    return Ok(None);
+                };
+
+                //     iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                let mapped =
+                    mapper.call(&JsValue::undefined(), &[value, (*counter).into()], context);
+
+                //     iv. IfAbruptCloseIterator(mapped, iterated).
+                let mapped = match mapped {
+                    Ok(mapped) => mapped,
+                    Err(err) => return iterated.close(Err(err), context).map(|_| None),
+                };
+
+                //     v. Let innerIterator be Completion(GetIteratorFlattenable(mapped, reject-primitives)).
+                let inner_iterator =
+                    get_iterator_flattenable(&mapped, PrimitiveHandling::RejectPrimitives, context);
+
+                //     vi. IfAbruptCloseIterator(innerIterator, iterated).
+                //     vii. Let innerAlive be true.
+                match inner_iterator {
+                    Ok(inner_iterator) => *inner = Some(inner_iterator),
+                    Err(err) => return iterated.close(Err(err), context).map(|_| None),
+                }
+            },
+        }
+    }
+
+    /// Resumes the closure with a return completion after a `Yield`.
+    fn resume_return(&self, iterated: &IteratorRecord, context: &mut Context) -> JsResult<()> {
+        // If completion is an abrupt completion, then
+        if let Self::FlatMap {
+            inner: Some(inner_iterator),
+            ..
+        } = self
+        {
+            // i. Let backupCompletion be Completion(IteratorClose(innerIterator, completion)).
+            let backup_completion = inner_iterator.close(Ok(JsValue::undefined()), context);
+
+            // ii. IfAbruptCloseIterator(backupCompletion, iterated).
+            if let Err(err) = backup_completion {
+                return iterated.close(Err(err), context).map(|_| ());
+            }
+        }
+
+        // IfAbruptCloseIterator(completion, iterated).
+        // iii. Return ? IteratorClose(iterated, completion).
+        iterated
+            .close(Ok(JsValue::undefined()), context)
+            .map(|_| ())
+    }
+}
+
+/// An iterable captured by the closure of `Iterator.concat`.
+#[derive(Debug, Trace, Finalize)]
+pub(crate) struct ConcatIterable {
+    /// The `[[OpenMethod]]` of the iterable.
+    pub(crate) open_method: JsObject,
+
+    /// The `[[Iterable]]` itself.
+    pub(crate) iterable: JsObject,
+}
+
+/// The abstract closure of [`Iterator.concat ( ...items )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-iterator-sequencing/#sec-iterator.concat
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ConcatClosure {
+    /// The captured `iterables` list.
+    iterables: Gc<Box<[ConcatIterable]>>,
+
+    /// The index of the next iterable to open.
+    next: usize,
+
+    /// The iterator record of the iterable that is currently being iterated, if any.
+    inner: Option<IteratorRecord>,
+}
+
+impl ConcatClosure {
+    /// Runs the closure until the next `Yield`, returning `None` if the closure completed.
+    fn resume(&mut self, context: &mut Context) -> JsResult<Option<JsValue>> {
+        // a. For each Record iterable of iterables, do
+        loop {
+            if let Some(iterator_record) = &mut self.inner {
+                // v. Repeat, while innerAlive is true,
+                //     1. Let innerValue be ? IteratorStepValue(iteratorRecord).
+                //     3. Else,
+                //         a. Let completion be Completion(Yield(innerValue)).
+                if let Some(value) = iterator_record.step_value(context)? {
+                    return Ok(Some(value));
+                }
+
+                //     2. If innerValue is done, then
+                //         a. Set innerAlive to false.
+                self.inner = None;
+            }
+
+            let Some(iterable) = self.iterables.get(self.next) else {
+                // b. Return ReturnCompletion(undefined).
+                return Ok(None);
+            };
+            self.next += 1;
+
+            // i. Let iter be ? Call(iterable.[[OpenMethod]], iterable.[[Iterable]]).
+            let iter =
+                iterable
+                    .open_method
+                    .call(&iterable.iterable.clone().into(), &[], context)?;
+
+            // ii. If iter is not an Object, throw a TypeError exception.
+            let iter = iter.as_object().ok_or_else(|| {
+                JsNativeError::typ().with_message("Iterator.concat: iterator is not an object")
+            })?;
+
+            // iii. Let iteratorRecord be ? GetIteratorDirect(iter).
+            // iv. Let innerAlive be true.
+            self.inner = Some(get_iterator_direct(iter, context)?);
+        }
+    }
+
+    /// Resumes the closure with a return completion after a `Yield`.
+    fn resume_return(&self, context: &mut Context) -> JsResult<()> {
+        // b. If completion is an abrupt completion, then
+        //     i. Return ? IteratorClose(iteratorRecord, completion).
+        match &self.inner {
+            Some(iterator_record) => iterator_record
+                .close(Ok(JsValue::undefined()), context)
+                .map(|_| ()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The iterators and closure driving an iterator helper object.
+#[derive(Debug, Clone, Trace, Finalize)]
+enum HelperKind {
+    /// A helper created by a lazy method of `%Iterator.prototype%`.
+    Lazy {
+        /// The `[[UnderlyingIterator]]` of the helper.
+        underlying: IteratorRecord,
+
+        /// The closure driving the helper.
+        closure: HelperClosure,
+    },
+
+    /// A helper created by `Iterator.concat`, whose `[[UnderlyingIterators]]` list is empty.
+    Concat(ConcatClosure),
+}
+
+impl HelperKind {
+    /// Runs the closure until the next `Yield`, returning `None` if the closure completed.
+    fn resume(&mut self, context: &mut Context) -> JsResult<Option<JsValue>> {
+        match self {
+            Self::Lazy {
+                underlying,
+                closure,
+            } => closure.resume(underlying, context),
+            Self::Concat(closure) => closure.resume(context),
+        }
+    }
+
+    /// Resumes the closure with a return completion after a `Yield`.
+    fn resume_return(&self, context: &mut Context) -> JsResult<()> {
+        match self {
+            Self::Lazy {
+                underlying,
+                closure,
+            } => closure.resume_return(underlying, context),
+            Self::Concat(closure) => closure.resume_return(context),
+        }
+    }
+
+    /// Closes the underlying iterators of a helper that was never resumed.
+    fn close_underlying(&self, context: &mut Context) -> JsResult<()> {
+        match self {
+            Self::Lazy { underlying, .. } => underlying
+                .close(Ok(JsValue::undefined()), context)
+                .map(|_| ()),
+            Self::Concat(_) => Ok(()),
+        }
+    }
+}
+
+/// An iterator helper object, created by the lazy methods of `%Iterator.prototype%` and by
+/// `Iterator.concat`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-iterator-helper-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct IteratorHelper {
+    /// The underlying iterators and the closure driving the helper.
+    kind: HelperKind,
+
+    /// The `[[GeneratorState]]` of the helper.
+    #[unsafe_ignore_trace]
+    state: HelperState,
+}
+
+impl IntrinsicObject for IteratorHelper {
+    fn init(realm: &Realm) {
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .prototype(realm.intrinsics().constructors().iterator().prototype())
+            .static_method(Self::next, js_string!("next"), 0)
+            .static_method(Self::r#return, js_string!("return"), 0)
+            .static_property(
+                JsSymbol::to_string_tag(),
+                js_string!("Iterator Helper"),
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics.objects().iterator_prototypes().iterator_helper()
+    }
+}
+
+impl IteratorHelper {
+    /// Abstract operation `CreateIteratorFromClosure ( closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] » )`.
+    ///
+    /// Creates a new iterator helper object that runs `closure` over `underlying`.
+    pub(crate) fn create(
+        underlying: IteratorRecord,
+        closure: HelperClosure,
+        context: &Context,
+    ) -> JsObject {
+        Self::from_kind(
+            HelperKind::Lazy {
+                underlying,
+                closure,
+            },
+            context,
+        )
+    }
+
+    /// Abstract operation `CreateIteratorFromClosure ( closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterators]] » )`.
+    ///
+    /// Creates a new iterator helper object that iterates each of the `iterables` in order.
+    pub(crate) fn create_concat(iterables: Box<[ConcatIterable]>, context: &Context) -> JsObject {
+        Self::from_kind(
+            HelperKind::Concat(ConcatClosure {
+                iterables: Gc::new(iterables),
+                next: 0,
+                inner: None,
+            }),
+            context,
+        )
+    }
+
+    /// Creates a new iterator helper object in the suspended-start state.
+    fn from_kind(kind: HelperKind, context: &Context) -> JsObject {
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .iterator_helper(),
+            Self {
+                kind,
+                state: HelperState::SuspendedStart,
+            },
+        )
+    }
+
+    /// `%IteratorHelperPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.next
+    fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? GeneratorResume(this value, undefined, "Iterator Helper").
+
+        // `GeneratorValidate ( generator, generatorBrand )`
+        let object = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "Iterator Helper.prototype.next: `this` is not an Iterator Helper",
+                )
+            })?;
+
+        let mut kind = {
+            let mut helper = object.borrow_mut();
+            let helper = &mut helper.data;
+            match helper.state {
+                HelperState::Executing => {
+                    return Err(JsNativeError::typ()
+                        .with_message(
+                            "Iterator Helper.prototype.next: generator is already running",
+                        )
+                        .into());
+                }
+                HelperState::Completed => {
+                    return Ok(create_iter_result_object(
+                        JsValue::undefined(),
+                        true,
+                        context,
+                    ));
+                }
+                HelperState::SuspendedStart | HelperState::SuspendedYield => {}
+            }
+            helper.state = HelperState::Executing;
+            helper.kind.clone()
+        };
+
+        let result = kind.resume(context);
+
+        let mut helper = object.borrow_mut();
+        let helper = &mut helper.data;
+        helper.kind = kind;
+
+        match result {
+            Ok(Some(value)) => {
+                helper.state = HelperState::SuspendedYield;
+                Ok(create_iter_result_object(value, false, context))
+            }
+            Ok(None) => {
+                helper.state = HelperState::Completed;
+                Ok(create_iter_result_object(
+                    JsValue::undefined(),
+                    true,
+                    context,
+                ))
+            }
+            Err(err) => {
+                helper.state = HelperState::Completed;
+                Err(err)
+            }
+        }
+    }
+
+    /// `%IteratorHelperPrototype%.return ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.return
+    fn r#return(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[UnderlyingIterator]]).
+        let object = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "Iterator Helper.prototype.return: `this` is not an Iterator Helper",
+                )
+            })?;
+
+        // 3. Assert: O has a [[GeneratorState]] slot.
+        let (state, kind) = {
+            let mut helper = object.borrow_mut();
+            let helper = &mut helper.data;
+            let state = helper.state;
+            helper.state = match state {
+                HelperState::SuspendedStart => HelperState::Completed,
+                HelperState::SuspendedYield => HelperState::Executing,
+                state => state,
+            };
+            (state, helper.kind.clone())
+        };
+
+        let result = match state {
+            // 4. If O.[[GeneratorState]] is suspended-start, then
+            HelperState::SuspendedStart => {
+                // a. Set O.[[GeneratorState]] to completed.
+                // b. NOTE: Once a generator enters the completed state it never leaves it and its associated execution context is never resumed. Any execution state associated with O can be discarded at this point.
+                // c. If O has an [[UnderlyingIterators]] slot, then
+                //     i. Perform ? IteratorCloseAll(O.[[UnderlyingIterators]], NormalCompletion(unused)).
+                // d. Else,
+                //     i. Perform ? IteratorClose(O.[[UnderlyingIterator]], NormalCompletion(unused)).
+                // e. Return CreateIterResultObject(undefined, true).
+                kind.close_underlying(context)
+            }
+            // 5. Let C be ReturnCompletion(undefined).
+            // 6. Return ? GeneratorResumeAbrupt(O, C, "Iterator Helper").
+            HelperState::SuspendedYield => kind.resume_return(context),
+            HelperState::Executing => {
+                return Err(JsNativeError::typ()
+                    .with_message("Iterator Helper.prototype.return: generator is already running")
+                    .into());
+            }
+            HelperState::Completed => Ok(()),
+        };
+
+        object.borrow_mut().data.state = HelperState::Completed;
+
+        result.map(|()| create_iter_result_object(JsValue::undefined(), true, context))
+    }
+}
@@ -1,19 +1,35 @@
 //! Boa's implementation of ECMAScript's `IteratorRecord` and iterator prototype objects.
 
 use crate::{
-    builtins::{BuiltInBuilder, IntrinsicObject},
-    context::intrinsics::Intrinsics,
+    builtins::{Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     js_string,
-    object::JsObject,
+    object::{
+        internal_methods::{get_prototype_from_constructor, InternalMethodContext},
+        JsObject, CONSTRUCTOR,
+    },
+    property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
+    string::StaticJsStrings,
     symbol::JsSymbol,
-    Context, JsResult, JsValue,
+    value::IntegerOrInfinity,
+    Context, JsArgs, JsError, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 
 mod async_from_sync_iterator;
+mod iterator_helper;
+mod wrap_for_valid_iterator;
+
+#[cfg(test)]
+mod tests;
+
 pub(crate) use async_from_sync_iterator::AsyncFromSyncIterator;
+pub(crate) use iterator_helper::IteratorHelper;
+pub(crate) use wrap_for_valid_iterator::WrapForValidIterator;
+
+use iterator_helper::{ConcatIterable, HelperClosure};
 
 /// `IfAbruptCloseIterator ( value, iteratorRecord )`
 ///
@@ -70,12 +86,26 @@ pub struct IteratorPrototypes {
     /// The `ForInIteratorPrototype` prototype object.
     for_in: JsObject,
 
+    /// The `%IteratorHelperPrototype%` prototype object.
+    iterator_helper: JsObject,
+
+    /// The `%WrapForValidIteratorPrototype%` prototype object.
+    wrap_for_valid_iterator: JsObject,
+
     /// The `%SegmentIteratorPrototype%` prototype object.
     #[cfg(feature = "intl")]
     segment: JsObject,
 }
 
 impl IteratorPrototypes {
+    /// Creates a new set of iterator prototypes that uses `iterator` as the `IteratorPrototype`
+    /// object, which is shared with the prototype of the `Iterator` constructor.
+    pub(crate) fn with_iterator_prototype(iterator: JsObject) -> Self {
+        let mut prototypes = Self::default();
+        prototypes.iterator = iterator;
+        prototypes
+    }
+
     /// Returns the `ArrayIteratorPrototype` object.
     #[inline]
     #[must_use]
@@ -139,6 +169,20 @@ impl IteratorPrototypes {
         self.for_in.clone()
     }
 
+    /// Returns the `%IteratorHelperPrototype%` object.
+    #[inline]
+    #[must_use]
+    pub fn iterator_helper(&self) -> JsObject {
+        self.iterator_helper.clone()
+    }
+
+    /// Returns the `%WrapForValidIteratorPrototype%` object.
+    #[inline]
+    #[must_use]
+    pub fn wrap_for_valid_iterator(&self) -> JsObject {
+        self.wrap_for_valid_iterator.clone()
+    }
+
     /// Returns the `%SegmentIteratorPrototype%` object.
     #[inline]
     #[must_use]
@@ -148,26 +192,813 @@ impl IteratorPrototypes {
     }
 }
 
-/// `%IteratorPrototype%` object
+/// The `Iterator` constructor and the `%Iterator.prototype%` object.
 ///
 /// More information:
 ///  - [ECMA reference][spec]
 ///
-/// [spec]: https://tc39.es/ecma262/#sec-%iteratorprototype%-object
+/// [spec]: https://tc39.es/ecma262/#sec-iterator-objects
 pub(crate) struct Iterator;
 
 impl IntrinsicObject for Iterator {
     fn init(realm: &Realm) {
-        BuiltInBuilder::with_intrinsic::<Self>(realm)
-            .static_method(|v, _, _| Ok(v.clone()), JsSymbol::iterator(), 0)
+        let get_to_string_tag = BuiltInBuilder::callable(realm, Self::get_to_string_tag)
+            .name(js_string!("get [Symbol.toStringTag]"))
+            .build();
+        let set_to_string_tag = BuiltInBuilder::callable(realm, Self::set_to_string_tag)
+            .name(js_string!("set [Symbol.toStringTag]"))
+            .length(1)
+            .build();
+        let get_constructor = BuiltInBuilder::callable(realm, Self::get_constructor)
+            .name(js_string!("get constructor"))
+            .build();
+        let set_constructor = BuiltInBuilder::callable(realm, Self::set_constructor)
+            .name(js_string!("set constructor"))
+            .length(1)
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::from, js_string!("from"), 1)
+            .static_method(Self::concat, js_string!("concat"), 0)
+            .method(|v, _, _| Ok(v.clone()), JsSymbol::iterator(), 0)
+            .method(Self::map, js_string!("map"), 1)
+            .method(Self::filter, js_string!("filter"), 1)
+            .method(Self::take, js_string!("take"), 1)
+            .method(Self::drop, js_string!("drop"), 1)
+            .method(Self::flat_map, js_string!("flatMap"), 1)
+            .method(Self::reduce, js_string!("reduce"), 1)
+            .method(Self::to_array, js_string!("toArray"), 0)
+            .method(Self::for_each, js_string!("forEach"), 1)
+            .method(Self::some, js_string!("some"), 1)
+            .method(Self::every, js_string!("every"), 1)
+            .method(Self::find, js_string!("find"), 1)
+            .accessor(
+                JsSymbol::to_string_tag(),
+                Some(get_to_string_tag),
+                Some(set_to_string_tag),
+                Attribute::CONFIGURABLE,
+            )
             .build();
+
+        // The `constructor` property of `%Iterator.prototype%` is an accessor property,
+        // unlike the data property defined by the builder.
+        realm
+            .intrinsics()
+            .constructors()
+            .iterator()
+            .prototype()
+            .insert(
+                CONSTRUCTOR,
+                PropertyDescriptor::builder()
+                    .get(get_constructor)
+                    .set(set_constructor)
+                    .enumerable(false)
+                    .configurable(true),
+            );
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
-        intrinsics.objects().iterator_prototypes().iterator()
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
     }
 }
 
+impl BuiltInObject for Iterator {
+    const NAME: JsString = StaticJsStrings::ITERATOR;
+}
+
+impl BuiltInConstructor for Iterator {
+    const LENGTH: usize = 0;
+    const P: usize = 14;
+    const SP: usize = 2;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::iterator;
+
+    /// Constructor [`Iterator ( )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator-constructor
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is either undefined or the active function object, throw a TypeError exception.
+        let active_function = context
+            .active_function_object()
+            .unwrap_or_else(|| context.intrinsics().constructors().iterator().constructor());
+        if new_target.is_undefined()
+            || new_target
+                .as_object()
+                .is_some_and(|new_target| JsObject::equals(new_target, &active_function))
+        {
+            return Err(JsNativeError::typ()
+                .with_message("Iterator: cannot construct abstract class `Iterator` directly")
+                .into());
+        }
+
+        // 2. Return ? OrdinaryCreateFromConstructor(NewTarget, "%Iterator.prototype%").
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::iterator, context)?;
+
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            OrdinaryObject,
+        )
+        .into())
+    }
+}
+
+impl Iterator {
+    /// `Iterator.from ( O )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let iteratorRecord be ? GetIteratorFlattenable(O, iterate-string-primitives).
+        let iterator_record = get_iterator_flattenable(
+            args.get_or_undefined(0),
+            PrimitiveHandling::IterateStrings,
+            context,
+        )?;
+
+        // 2. Let hasInstance be ? OrdinaryHasInstance(%Iterator%, iteratorRecord.[[Iterator]]).
+        let has_instance = JsValue::ordinary_has_instance(
+            &context
+                .intrinsics()
+                .constructors()
+                .iterator()
+                .constructor()
+                .into(),
+            &iterator_record.iterator().clone().into(),
+            context,
+        )?;
+
+        // 3. If hasInstance is true, then
+        if has_instance {
+            // a. Return iteratorRecord.[[Iterator]].
+            return Ok(iterator_record.iterator().clone().into());
+        }
+
+        // 4. Let wrapper be OrdinaryObjectCreate(%WrapForValidIteratorPrototype%, « [[Iterated]] »).
+        // 5. Set wrapper.[[Iterated]] to iteratorRecord.
+        // 6. Return wrapper.
+        Ok(WrapForValidIterator::create(iterator_record, context).into())
+    }
+
+    /// `Iterator.concat ( ...items )`
+    ///
+    /// More information:
+    ///  - [Iterator Sequencing proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-sequencing/#sec-iterator.concat
+    fn concat(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let iterables be a new empty List.
+        // 2. For each element item of items, do
+        let iterables = args
+            .iter()
+            .map(|item| {
+                // a. If item is not an Object, throw a TypeError exception.
+                let iterable = item.as_object().ok_or_else(|| {
+                    JsNativeError::typ().with_message(format!(
+                        "Iterator.concat: value with type `{}` is not an iterable object",
+                        item.type_of()
+                    ))
+                })?;
+
+                // b. Let method be ? GetMethod(item, %Symbol.iterator%).
+                // c. If method is undefined, throw a TypeError exception.
+                let open_method =
+                    item.get_method(JsSymbol::iterator(), context)?
+                        .ok_or_else(|| {
+                            JsNativeError::typ()
+                                .with_message("Iterator.concat: value is not iterable")
+                        })?;
+
+                // d. Append the Record { [[OpenMethod]]: method, [[Iterable]]: item } to iterables.
+                Ok(ConcatIterable {
+                    open_method,
+                    iterable: iterable.clone(),
+                })
+            })
+            .collect::<JsResult<Box<[_]>>>()?;
+
+        // 3. Let closure be a new Abstract Closure with no parameters that captures iterables and performs the following steps when called:
+        // 4. Let gen be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterators]] »).
+        // 5. Set gen.[[UnderlyingIterators]] to a new empty List.
+        // 6. Return gen.
+        Ok(IteratorHelper::create_concat(iterables, context).into())
+    }
+
+    /// `get Iterator.prototype [ %Symbol.toStringTag% ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-iterator.prototype-%symbol.tostringtag%
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_to_string_tag(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return "Iterator".
+        Ok(StaticJsStrings::ITERATOR.into())
+    }
+
+    /// `set Iterator.prototype [ %Symbol.toStringTag% ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-iterator.prototype-%symbol.tostringtag%
+    fn set_to_string_tag(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? SetterThatIgnoresPrototypeProperties(this value, %Iterator.prototype%, %Symbol.toStringTag%, v).
+        setter_that_ignores_prototype_properties(
+            this,
+            JsSymbol::to_string_tag().into(),
+            args.get_or_undefined(0).clone(),
+            context,
+        )?;
+
+        // 2. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `get Iterator.prototype.constructor`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-iterator.prototype.constructor
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_constructor(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return %Iterator%.
+        Ok(context
+            .intrinsics()
+            .constructors()
+            .iterator()
+            .constructor()
+            .into())
+    }
+
+    /// `set Iterator.prototype.constructor`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-iterator.prototype.constructor
+    fn set_constructor(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? SetterThatIgnoresPrototypeProperties(this value, %Iterator.prototype%, "constructor", v).
+        setter_that_ignores_prototype_properties(
+            this,
+            CONSTRUCTOR.into(),
+            args.get_or_undefined(0).clone(),
+            context,
+        )?;
+
+        // 2. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Validates the `this` value of the `Iterator.prototype` methods, returning an iterator
+    /// record that can be used to close it.
+    fn this_iterator(this: &JsValue, method: &str) -> JsResult<IteratorRecord> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message(format!(
+                "Iterator.prototype.{method}: `this` is not an object"
+            ))
+        })?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        Ok(IteratorRecord::new(o.clone(), JsValue::undefined()))
+    }
+
+    /// Closes `iterated` with a throw completion, returning the resulting error.
+    fn close_with_error(
+        iterated: &IteratorRecord,
+        error: JsError,
+        context: &mut Context,
+    ) -> JsError {
+        iterated
+            .close(Err(error), context)
+            .expect_err("IteratorClose must return a throw completion")
+    }
+
+    /// Validates that `func` is callable, closing `iterated` if it isn't.
+    fn callable_or_close(
+        iterated: &IteratorRecord,
+        func: &JsValue,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 4. If IsCallable(func) is false, then
+        if let Some(func) = func.as_callable() {
+            return Ok(func.clone());
+        }
+
+        // a. Let error be ThrowCompletion(a newly created TypeError object).
+        let error = JsNativeError::typ()
+            .with_message(format!(
+                "Iterator.prototype.{method}: argument is not callable"
+            ))
+            .into();
+
+        // b. Return ? IteratorClose(iterated, error).
+        Err(Self::close_with_error(iterated, error, context))
+    }
+
+    /// Validates the `limit` argument of `take` and `drop`, closing `iterated` if it is invalid.
+    ///
+    /// Returns `None` if the limit is `+∞`.
+    fn limit_or_close(
+        iterated: &IteratorRecord,
+        limit: &JsValue,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<Option<u64>> {
+        // 4. Let numLimit be Completion(ToNumber(limit)).
+        // 5. IfAbruptCloseIterator(numLimit, iterated).
+        let num_limit = match limit.to_number(context) {
+            Ok(num_limit) => num_limit,
+            Err(err) => return Err(Self::close_with_error(iterated, err, context)),
+        };
+
+        // 6. If numLimit is NaN, then
+        //     a. Let error be ThrowCompletion(a newly created RangeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        // 7. Let integerLimit be ! ToIntegerOrInfinity(numLimit).
+        // 8. If integerLimit < 0, then
+        //     a. Let error be ThrowCompletion(a newly created RangeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let message = match IntegerOrInfinity::from(num_limit) {
+            _ if num_limit.is_nan() => "limit cannot be NaN",
+            IntegerOrInfinity::PositiveInfinity => return Ok(None),
+            IntegerOrInfinity::Integer(limit) => match u64::try_from(limit) {
+                Ok(limit) => return Ok(Some(limit)),
+                Err(_) => "limit cannot be negative",
+            },
+            IntegerOrInfinity::NegativeInfinity => "limit cannot be negative",
+        };
+
+        let error = JsNativeError::range()
+            .with_message(format!("Iterator.prototype.{method}: {message}"))
+            .into();
+        Err(Self::close_with_error(iterated, error, context))
+    }
+
+    /// `Iterator.prototype.map ( mapper )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.map
+    fn map(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let iterated = Self::this_iterator(this, "map")?;
+
+        // 4. If IsCallable(mapper) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let mapper = Self::callable_or_close(&iterated, args.get_or_undefined(0), "map", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(iterated.iterator(), context)?;
+
+        // 6. Let closure be a new Abstract Closure with no parameters that captures iterated and mapper and performs the following steps when called:
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        // 9. Return result.
+        Ok(
+            IteratorHelper::create(iterated, HelperClosure::Map { mapper, counter: 0 }, context)
+                .into(),
+        )
+    }
+
+    /// `Iterator.prototype.filter ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.filter
+    fn filter(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let iterated = Self::this_iterator(this, "filter")?;
+
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let predicate =
+            Self::callable_or_close(&iterated, args.get_or_undefined(0), "filter", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(iterated.iterator(), context)?;
+
+        // 6. Let closure be a new Abstract Closure with no parameters that captures iterated and predicate and performs the following steps when called:
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        // 9. Return result.
+        Ok(IteratorHelper::create(
+            iterated,
+            HelperClosure::Filter {
+                predicate,
+                counter: 0,
+            },
+            context,
+        )
+        .into())
+    }
+
+    /// `Iterator.prototype.take ( limit )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.take
+    fn take(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let iterated = Self::this_iterator(this, "take")?;
+
+        // 4-8.
+        let remaining = Self::limit_or_close(&iterated, args.get_or_undefined(0), "take", context)?;
+
+        // 9. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(iterated.iterator(), context)?;
+
+        // 10. Let closure be a new Abstract Closure with no parameters that captures iterated and integerLimit and performs the following steps when called:
+        // 11. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 12. Set result.[[UnderlyingIterator]] to iterated.
+        // 13. Return result.
+        Ok(IteratorHelper::create(iterated, HelperClosure::Take { remaining }, context).into())
+    }
+
+    /// `Iterator.prototype.drop ( limit )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.drop
+    fn drop(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let iterated = Self::this_iterator(this, "drop")?;
+
+        // 4-8.
+        let remaining = Self::limit_or_close(&iterated, args.get_or_undefined(0), "drop", context)?;
+
+        // 9. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(iterated.iterator(), context)?;
+
+        // 10. Let closure be a new Abstract Closure with no parameters that captures iterated and integerLimit and performs the following steps when called:
+        // 11. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 12. Set result.[[UnderlyingIterator]] to iterated.
+        // 13. Return result.
+        Ok(IteratorHelper::create(iterated, HelperClosure::Drop { remaining }, context).into())
+    }
+
+    /// `Iterator.prototype.flatMap ( mapper )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.flatmap
+    fn flat_map(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let iterated = Self::this_iterator(this, "flatMap")?;
+
+        // 4. If IsCallable(mapper) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let mapper =
+            Self::callable_or_close(&iterated, args.get_or_undefined(0), "flatMap", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(iterated.iterator(), context)?;
+
+        // 6. Let closure be a new Abstract Closure with no parameters that captures iterated and mapper and performs the following steps when called:
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        // 9. Return result.
+        Ok(IteratorHelper::create(
+            iterated,
+            HelperClosure::FlatMap {
+                mapper,
+                counter: 0,
+                inner: None,
+            },
+            context,
+        )
+        .into())
+    }
+
+    /// `Iterator.prototype.reduce ( reducer [ , initialValue ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.reduce
+    fn reduce(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let iterated = Self::this_iterator(this, "reduce")?;
+
+        // 4. If IsCallable(reducer) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let reducer =
+            Self::callable_or_close(&iterated, args.get_or_undefined(0), "reduce", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(iterated.iterator(), context)?;
+
+        let (mut accumulator, mut counter) = if let Some(initial_value) = args.get(1) {
+            // 7. Else,
+            //     a. Let accumulator be initialValue.
+            //     b. Let counter be 0.
+            (initial_value.clone(), 0u64)
+        } else {
+            // 6. If initialValue is not present, then
+            //     a. Let accumulator be ? IteratorStepValue(iterated).
+            //     b. If accumulator is done, throw a TypeError exception.
+            //     c. Let counter be 1.
+            let accumulator = iterated.step_value(context)?.ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "Iterator.prototype.reduce: cannot reduce an empty iterator without an initial value",
+                )
+            })?;
+            (accumulator, 1)
+        };
+
+        // 8. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return accumulator.
+        while let Some(value) = iterated.step_value(context)? {
+            // c. Let result be Completion(Call(reducer, undefined, « accumulator, value, 𝔽(counter) »)).
+            let result = reducer.call(
+                &JsValue::undefined(),
+                &[accumulator, value, counter.into()],
+                context,
+            );
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            // e. Set accumulator to result.
+            accumulator = if_abrupt_close_iterator!(result, iterated, context);
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        Ok(accumulator)
+    }
+
+    /// `Iterator.prototype.toArray ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.toarray
+    fn to_array(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let iterated = Self::this_iterator(this, "toArray")?;
+
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        // 4. Let items be a new empty List.
+        // 5. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return CreateArrayFromList(items).
+        //     c. Append value to items.
+        let items = get_iterator_direct(iterated.iterator(), context)?.into_list(context)?;
+        Ok(Array::create_array_from_list(items, context).into())
+    }
+
+    /// `Iterator.prototype.forEach ( procedure )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.foreach
+    fn for_each(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let iterated = Self::this_iterator(this, "forEach")?;
+
+        // 4. If IsCallable(procedure) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let procedure =
+            Self::callable_or_close(&iterated, args.get_or_undefined(0), "forEach", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(iterated.iterator(), context)?;
+
+        // 6. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 7. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return undefined.
+        while let Some(value) = iterated.step_value(context)? {
+            // c. Let result be Completion(Call(procedure, undefined, « value, 𝔽(counter) »)).
+            let result = procedure.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `Iterator.prototype.some ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.some
+    fn some(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let iterated = Self::this_iterator(this, "some")?;
+
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let predicate =
+            Self::callable_or_close(&iterated, args.get_or_undefined(0), "some", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(iterated.iterator(), context)?;
+
+        // 6. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 7. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return false.
+        while let Some(value) = iterated.step_value(context)? {
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is true, return ? IteratorClose(iterated, NormalCompletion(true)).
+            if result.to_boolean() {
+                return iterated.close(Ok(true.into()), context);
+            }
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        Ok(false.into())
+    }
+
+    /// `Iterator.prototype.every ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.every
+    fn every(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let iterated = Self::this_iterator(this, "every")?;
+
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let predicate =
+            Self::callable_or_close(&iterated, args.get_or_undefined(0), "every", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(iterated.iterator(), context)?;
+
+        // 6. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 7. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return true.
+        while let Some(value) = iterated.step_value(context)? {
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is false, return ? IteratorClose(iterated, NormalCompletion(false)).
+            if !result.to_boolean() {
+                return iterated.close(Ok(false.into()), context);
+            }
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        Ok(true.into())
+    }
+
+    /// `Iterator.prototype.find ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.find
+    fn find(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let iterated = Self::this_iterator(this, "find")?;
+
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let predicate =
+            Self::callable_or_close(&iterated, args.get_or_undefined(0), "find", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(iterated.iterator(), context)?;
+
+        // 6. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 7. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return undefined.
+        while let Some(value) = iterated.step_value(context)? {
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(
+                &JsValue::undefined(),
+                &[value.clone(), counter.into()],
+                context,
+            );
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is true, return ? IteratorClose(iterated, NormalCompletion(value)).
+            if result.to_boolean() {
+                return iterated.close(Ok(value), context);
+            }
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        Ok(JsValue::undefined())
+    }
+}
+
+/// `SetterThatIgnoresPrototypeProperties ( thisValue, home, p, v )`, with `home` set to
+/// `%Iterator.prototype%`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-SetterThatIgnoresPrototypeProperties
+fn setter_that_ignores_prototype_properties(
+    this: &JsValue,
+    p: PropertyKey,
+    v: JsValue,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 1. If thisValue is not an Object, throw a TypeError exception.
+    let this = this.as_object().ok_or_else(|| {
+        JsNativeError::typ().with_message("cannot set property of a non-object value")
+    })?;
+
+    // 2. If SameValue(thisValue, home) is true, then
+    let home = context.intrinsics().constructors().iterator().prototype();
+    if JsObject::equals(this, &home) {
+        // a. NOTE: Throwing here emulates assignment to a non-writable data property on the home object in strict mode code.
+        // b. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("cannot set property of `Iterator.prototype`")
+            .into());
+    }
+
+    // 3. Let desc be ? thisValue.[[GetOwnProperty]](p).
+    let desc = this.__get_own_property__(&p, &mut InternalMethodContext::new(context))?;
+
+    if desc.is_none() {
+        // 4. If desc is undefined, then
+        //     a. Perform ? CreateDataPropertyOrThrow(thisValue, p, v).
+        this.create_data_property_or_throw(p, v, context)?;
+    } else {
+        // 5. Else,
+        //     a. Perform ? Set(thisValue, p, v, true).
+        this.set(p, v, true, context)?;
+    }
+
+    // 6. Return unused.
+    Ok(())
+}
+
 /// `%AsyncIteratorPrototype%` object
 ///
 /// More information:
@@ -297,6 +1128,79 @@ impl JsValue {
     }
 }
 
+/// `primitiveHandling` argument of [`get_iterator_flattenable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PrimitiveHandling {
+    /// Strings are iterated, other primitives are rejected.
+    IterateStrings,
+
+    /// All primitives are rejected.
+    RejectPrimitives,
+}
+
+/// `GetIteratorDirect ( obj )`
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiteratordirect
+pub(crate) fn get_iterator_direct(
+    obj: &JsObject,
+    context: &mut Context,
+) -> JsResult<IteratorRecord> {
+    // 1. Let nextMethod be ? Get(obj, "next").
+    let next_method = obj.get(js_string!("next"), context)?;
+
+    // 2. Let iteratorRecord be the Iterator Record { [[Iterator]]: obj, [[NextMethod]]: nextMethod, [[Done]]: false }.
+    // 3. Return iteratorRecord.
+    Ok(IteratorRecord::new(obj.clone(), next_method))
+}
+
+/// `GetIteratorFlattenable ( obj, primitiveHandling )`
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiteratorflattenable
+pub(crate) fn get_iterator_flattenable(
+    obj: &JsValue,
+    primitive_handling: PrimitiveHandling,
+    context: &mut Context,
+) -> JsResult<IteratorRecord> {
+    // 1. If obj is not an Object, then
+    //     a. If primitiveHandling is reject-primitives, throw a TypeError exception.
+    //     b. Assert: primitiveHandling is iterate-string-primitives.
+    //     c. If obj is not a String, throw a TypeError exception.
+    if !obj.is_object()
+        && (primitive_handling == PrimitiveHandling::RejectPrimitives || !obj.is_string())
+    {
+        return Err(JsNativeError::typ()
+            .with_message(format!(
+                "value with type `{}` is not an iterable object",
+                obj.type_of()
+            ))
+            .into());
+    }
+
+    // 2. Let method be ? GetMethod(obj, %Symbol.iterator%).
+    let iterator = match obj.get_method(JsSymbol::iterator(), context)? {
+        // 3. If method is undefined, then
+        //     a. Let iterator be obj.
+        None => obj.clone(),
+        // 4. Else,
+        //     a. Let iterator be ? Call(method, obj).
+        Some(method) => method.call(obj, &[], context)?,
+    };
+
+    // 5. If iterator is not an Object, throw a TypeError exception.
+    let iterator = iterator
+        .as_object()
+        .ok_or_else(|| JsNativeError::typ().with_message("returned iterator is not an object"))?;
+
+    // 6. Return ? GetIteratorDirect(iterator).
+    get_iterator_direct(iterator, context)
+}
+
 /// The result of the iteration process.
 #[derive(Debug, Clone, Trace, Finalize)]
 pub struct IteratorResult {
@@ -539,7 +1443,7 @@ impl IteratorRecord {
     ///  - [ECMA reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-iteratorstepvalue
-    pub(crate) fn step_value(&mut self, context: &mut Context) -> JsResult<Option<JsValue>> {
+    pub fn step_value(&mut self, context: &mut Context) -> JsResult<Option<JsValue>> {
         // 1. Let result be ? IteratorStep(iteratorRecord).
         if self.step(context)? {
             // 2. If result is done, then
@@ -565,11 +1469,7 @@ impl IteratorRecord {
     ///  - [ECMA reference][spec]
     ///
     ///  [spec]: https://tc39.es/ecma262/#sec-iteratorclose
-    pub(crate) fn close(
-        &self,
-        completion: JsResult<JsValue>,
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub fn close(&self, completion: JsResult<JsValue>, context: &mut Context) -> JsResult<JsValue> {
         // 1. Assert: Type(iteratorRecord.[[Iterator]]) is Object.
 
         // 2. Let iterator be iteratorRecord.[[Iterator]].
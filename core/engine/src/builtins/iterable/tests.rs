@@ -0,0 +1,197 @@
+use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn iterator_constructor() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "new Iterator()",
+            JsNativeErrorKind::Type,
+            "Iterator: cannot construct abstract class `Iterator` directly",
+        ),
+        TestAction::run("class MyIterator extends Iterator {}"),
+        TestAction::assert("new MyIterator() instanceof Iterator"),
+        TestAction::assert("Iterator.prototype === Object.getPrototypeOf([].values()).__proto__"),
+        TestAction::assert_eq(
+            "Iterator.prototype[Symbol.toStringTag]",
+            js_str!("Iterator"),
+        ),
+        TestAction::assert("[].values().constructor === Iterator"),
+    ]);
+}
+
+#[test]
+fn lazy_helpers() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                function* naturals() {
+                    let i = 0;
+                    while (true) {
+                        yield i++;
+                    }
+                }
+            "#}),
+        TestAction::assert(indoc! {r#"
+                arrayEquals(
+                    naturals()
+                        .drop(1)
+                        .filter((x) => x % 2 === 0)
+                        .map((x) => x * 10)
+                        .take(3)
+                        .toArray(),
+                    [20, 40, 60]
+                )
+            "#}),
+        TestAction::assert(indoc! {r#"
+                arrayEquals(
+                    [1, 2, 3].values().flatMap((x) => [x, x]).toArray(),
+                    [1, 1, 2, 2, 3, 3]
+                )
+            "#}),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call([].values().map(x => x))",
+            js_str!("[object Iterator Helper]"),
+        ),
+    ]);
+}
+
+#[test]
+fn helper_closes_underlying() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                let closed = 0;
+                const underlying = {
+                    __proto__: Iterator.prototype,
+                    next() { return { value: 1, done: false }; },
+                    return() { closed++; return {}; },
+                };
+                const helper = underlying.map((x) => x);
+                helper.next();
+                helper.return();
+            "#}),
+        TestAction::assert_eq("closed", 1),
+        TestAction::assert("helper.next().done"),
+        TestAction::assert_native_error(
+            "underlying.take(-1)",
+            JsNativeErrorKind::Range,
+            "Iterator.prototype.take: limit cannot be negative",
+        ),
+        TestAction::assert_eq("closed", 2),
+        TestAction::assert_native_error(
+            "underlying.flatMap(() => 1).next()",
+            JsNativeErrorKind::Type,
+            "value with type `number` is not an iterable object",
+        ),
+        TestAction::assert_eq("closed", 3),
+    ]);
+}
+
+#[test]
+fn eager_methods() {
+    run_test_actions([
+        TestAction::assert_eq("[1, 2, 3].values().reduce((a, b) => a + b)", 6),
+        TestAction::assert_eq("[1, 2, 3].values().reduce((a, b) => a + b, 10)", 16),
+        TestAction::assert_native_error(
+            "[].values().reduce((a, b) => a + b)",
+            JsNativeErrorKind::Type,
+            "Iterator.prototype.reduce: cannot reduce an empty iterator without an initial value",
+        ),
+        TestAction::assert("[1, 2, 3].values().some((x) => x === 2)"),
+        TestAction::assert("![1, 2, 3].values().every((x) => x < 3)"),
+        TestAction::assert_eq("[1, 2, 3].values().find((x) => x > 1)", 2),
+        TestAction::assert_eq(
+            "let sum = 0; [1, 2, 3].values().forEach((x) => { sum += x; }); sum",
+            6,
+        ),
+    ]);
+}
+
+#[test]
+fn iterator_from() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::assert(indoc! {r#"
+                const iter = [1, 2].values();
+                Iterator.from(iter) === iter
+            "#}),
+        TestAction::assert("arrayEquals(Iterator.from('ab').toArray(), ['a', 'b'])"),
+        TestAction::run(indoc! {r#"
+                let count = 0;
+                const wrapped = Iterator.from({
+                    next() { return { value: count++, done: count > 2 }; },
+                });
+            "#}),
+        TestAction::assert("wrapped instanceof Iterator"),
+        TestAction::assert("arrayEquals(wrapped.toArray(), [0, 1])"),
+        TestAction::assert("wrapped.return().done"),
+        TestAction::assert_native_error(
+            "Iterator.from(1)",
+            JsNativeErrorKind::Type,
+            "value with type `number` is not an iterable object",
+        ),
+    ]);
+}
+
+#[test]
+fn iterator_concat() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                let opened = 0;
+                function* gen(...values) {
+                    opened++;
+                    yield* values;
+                }
+                const concat = Iterator.concat(gen(1, 2), [], gen(3));
+            "#}),
+        TestAction::assert_eq("opened", 0),
+        TestAction::assert_eq("concat.next().value", 1),
+        TestAction::assert_eq("opened", 1),
+        TestAction::assert("arrayEquals(concat.toArray(), [2, 3])"),
+        TestAction::assert_eq("opened", 2),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(Iterator.concat())",
+            js_str!("[object Iterator Helper]"),
+        ),
+        TestAction::assert_native_error(
+            "Iterator.concat('ab')",
+            JsNativeErrorKind::Type,
+            "Iterator.concat: value with type `string` is not an iterable object",
+        ),
+        TestAction::assert_native_error(
+            "Iterator.concat({})",
+            JsNativeErrorKind::Type,
+            "Iterator.concat: value is not iterable",
+        ),
+    ]);
+}
+
+#[test]
+fn iterator_concat_return() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                let closed = 0;
+                const iterable = {
+                    [Symbol.iterator]() {
+                        return {
+                            next() { return { value: 1, done: false }; },
+                            return() { closed++; return {}; },
+                        };
+                    },
+                };
+                const concat = Iterator.concat(iterable, iterable);
+                concat.return();
+            "#}),
+        TestAction::assert_eq("closed", 0),
+        TestAction::assert("concat.next().done"),
+        TestAction::run(indoc! {r#"
+                const started = Iterator.concat(iterable, iterable);
+                started.next();
+                started.return();
+            "#}),
+        TestAction::assert_eq("closed", 1),
+        TestAction::assert("started.next().done"),
+    ]);
+}
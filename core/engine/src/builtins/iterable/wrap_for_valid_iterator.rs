@@ -0,0 +1,129 @@
+//! This module implements the `%WrapForValidIteratorPrototype%` object, used by `Iterator.from`
+//! to wrap iterators that don't inherit from `%Iterator.prototype%`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%-object
+
+use crate::{
+    builtins::{iterable::create_iter_result_object, BuiltInBuilder, IntrinsicObject},
+    context::intrinsics::Intrinsics,
+    error::JsNativeError,
+    js_string,
+    object::JsObject,
+    realm::Realm,
+    Context, JsData, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+
+use super::IteratorRecord;
+
+/// An object created by `Iterator.from` to wrap an iterator record.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%-object
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct WrapForValidIterator {
+    /// The `[[Iterated]]` internal slot.
+    iterated: IteratorRecord,
+}
+
+impl IntrinsicObject for WrapForValidIterator {
+    fn init(realm: &Realm) {
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .prototype(realm.intrinsics().constructors().iterator().prototype())
+            .static_method(Self::next, js_string!("next"), 0)
+            .static_method(Self::r#return, js_string!("return"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics
+            .objects()
+            .iterator_prototypes()
+            .wrap_for_valid_iterator()
+    }
+}
+
+impl WrapForValidIterator {
+    /// Creates a new wrapper object for `iterated`, as in steps 4 and 5 of `Iterator.from`.
+    pub(crate) fn create(iterated: IteratorRecord, context: &Context) -> JsObject {
+        // 4. Let wrapper be OrdinaryObjectCreate(%WrapForValidIteratorPrototype%, « [[Iterated]] »).
+        // 5. Set wrapper.[[Iterated]] to iteratorRecord.
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .wrap_for_valid_iterator(),
+            Self { iterated },
+        )
+    }
+
+    /// `%WrapForValidIteratorPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%.next
+    fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[Iterated]]).
+        // 3. Let iteratorRecord be O.[[Iterated]].
+        let iterated = this
+            .as_object()
+            .and_then(|o| o.downcast_ref::<Self>().map(|w| w.iterated.clone()))
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "WrapForValidIteratorPrototype.next: `this` is not a wrapped iterator",
+                )
+            })?;
+
+        // 4. Return ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+        iterated
+            .next_method()
+            .call(&iterated.iterator().clone().into(), &[], context)
+    }
+
+    /// `%WrapForValidIteratorPrototype%.return ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%.return
+    fn r#return(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[Iterated]]).
+        // 3. Let iterator be O.[[Iterated]].[[Iterator]].
+        // 4. Assert: iterator is an Object.
+        let iterator = this
+            .as_object()
+            .and_then(|o| {
+                o.downcast_ref::<Self>()
+                    .map(|w| w.iterated.iterator().clone())
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "WrapForValidIteratorPrototype.return: `this` is not a wrapped iterator",
+                )
+            })?;
+
+        // 5. Let returnMethod be ? GetMethod(iterator, "return").
+        let Some(return_method) = iterator.get_method(js_string!("return"), context)? else {
+            // 6. If returnMethod is undefined, then
+            //     a. Return CreateIterResultObject(undefined, true).
+            return Ok(create_iter_result_object(
+                JsValue::undefined(),
+                true,
+                context,
+            ));
+        };
+
+        // 7. Return ? Call(returnMethod, iterator).
+        return_method.call(&iterator.into(), &[], context)
+    }
+}
@@ -37,11 +37,26 @@ use crate::{
 use boa_gc::Gc;
 use boa_parser::{Parser, Source};
 
+#[cfg(feature = "experimental")]
+use crate::{object::IntegrityLevel, JsData};
+#[cfg(feature = "experimental")]
+use boa_gc::{Finalize, Trace};
+
 use super::{BuiltInBuilder, IntrinsicObject};
 
+use self::parse_record::JsonParseRecord;
+
+mod parse_record;
+
 #[cfg(test)]
 mod tests;
 
+/// The `[[IsRawJSON]]` internal slot of the objects created by `JSON.rawJSON`.
+#[cfg(feature = "experimental")]
+#[derive(Debug, Clone, Copy, Trace, Finalize, JsData)]
+#[boa_gc(empty_trace)]
+pub(crate) struct RawJson;
+
 /// JavaScript `JSON` global object.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub(crate) struct Json;
@@ -51,11 +66,43 @@ impl IntrinsicObject for Json {
         let to_string_tag = JsSymbol::to_string_tag();
         let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
 
-        BuiltInBuilder::with_intrinsic::<Self>(realm)
-            .static_method(Self::parse, js_string!("parse"), 2)
-            .static_method(Self::stringify, js_string!("stringify"), 3)
-            .static_property(to_string_tag, Self::NAME, attribute)
-            .build();
+        let parse_function = BuiltInBuilder::callable_with_object(
+            realm,
+            realm.intrinsics().objects().json_parse().into(),
+            Self::parse,
+        )
+        .name(js_string!("parse"))
+        .length(2)
+        .build();
+
+        let stringify_function = BuiltInBuilder::callable_with_object(
+            realm,
+            realm.intrinsics().objects().json_stringify().into(),
+            Self::stringify,
+        )
+        .name(js_string!("stringify"))
+        .length(3)
+        .build();
+
+        let builder = BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .static_property(
+                js_string!("parse"),
+                parse_function,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .static_property(
+                js_string!("stringify"),
+                stringify_function,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .static_property(to_string_tag, Self::NAME, attribute);
+
+        #[cfg(feature = "experimental")]
+        let builder = builder
+            .static_method(Self::raw_json, js_string!("rawJSON"), 1)
+            .static_method(Self::is_raw_json, js_string!("isRawJSON"), 1);
+
+        builder.build();
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
@@ -156,11 +203,17 @@ impl Json {
 
             // b. Let rootName be the empty String.
             // c. Perform ! CreateDataPropertyOrThrow(root, rootName, unfiltered).
-            root.create_data_property_or_throw(js_string!(), unfiltered, context)
+            root.create_data_property_or_throw(js_string!(), unfiltered.clone(), context)
                 .expect("CreateDataPropertyOrThrow should never throw here");
 
-            // d. Return ? InternalizeJSONProperty(root, rootName, reviver).
-            Self::internalize_json_property(&root, js_string!(), obj, context)
+            // d. Let snapshot be CreateJSONParseRecord(script, rootName, unfiltered).
+            // The source text is only exposed to the reviver with the `experimental` feature.
+            let snapshot = cfg!(feature = "experimental")
+                .then(|| JsonParseRecord::new(&json_string, unfiltered, context))
+                .transpose()?;
+
+            // e. Return ? InternalizeJSONProperty(root, rootName, reviver, snapshot).
+            Self::internalize_json_property(&root, js_string!(), obj, snapshot.as_ref(), context)
         } else {
             // 12. Else,
             // a. Return unfiltered.
@@ -168,22 +221,49 @@ impl Json {
         }
     }
 
-    /// `25.5.1.1 InternalizeJSONProperty ( holder, name, reviver )`
+    /// `InternalizeJSONProperty ( holder, name, reviver, parseRecord )`
     ///
     /// More information:
-    ///  - [ECMAScript reference][spec]
+    ///  - [JSON.parse source text access proposal][spec]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-internalizejsonproperty
+    /// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-internalizejsonproperty
     fn internalize_json_property(
         holder: &JsObject,
         name: JsString,
         reviver: &JsObject,
+        parse_record: Option<&JsonParseRecord>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let val be ? Get(holder, name).
         let val = holder.get(name.clone(), context)?;
 
-        // 2. If Type(val) is Object, then
+        // 2. Let context be OrdinaryObjectCreate(%Object.prototype%).
+        // Without the `experimental` feature, the reviver doesn't receive a context.
+        let source_context = cfg!(feature = "experimental")
+            .then(|| JsObject::with_object_proto(context.intrinsics()));
+
+        // 3. If parseRecord is a JSON Parse Record and SameValue(parseRecord.[[Value]], val) is true, then
+        //     b. Let elementRecords be parseRecord.[[Elements]].
+        //     c. Let entryRecords be parseRecord.[[Entries]].
+        // 4. Else,
+        //     a. Let elementRecords be a new empty List.
+        //     b. Let entryRecords be a new empty List.
+        let parse_record = parse_record.filter(|record| JsValue::same_value(record.value(), &val));
+
+        // 3.a. If val is not an Object, then
+        if let (Some(source_context), Some(source)) = (
+            &source_context,
+            parse_record.and_then(JsonParseRecord::source),
+        ) {
+            // i. Let parseNode be parseRecord.[[ParseNode]].
+            // ii. Let sourceText be the source text matched by parseNode.
+            // iii. Perform ! CreateDataPropertyOrThrow(context, "source", CodePointsToString(sourceText)).
+            source_context
+                .create_data_property_or_throw(js_string!("source"), source.clone(), context)
+                .expect("CreateDataPropertyOrThrow should never throw here");
+        }
+
+        // 5. If Type(val) is Object, then
         if let Some(obj) = val.as_object() {
             // a. Let isArray be ? IsArray(val).
             // b. If isArray is true, then
@@ -194,16 +274,24 @@ impl Json {
                 let len = obj.length_of_array_like(context)? as i64;
                 for i in 0..len {
                     // 1. Let prop be ! ToString(𝔽(I)).
-                    // 2. Let newElement be ? InternalizeJSONProperty(val, prop, reviver).
-                    let new_element =
-                        Self::internalize_json_property(obj, i.into(), reviver, context)?;
-
-                    // 3. If newElement is undefined, then
+                    // 2. If I < the number of elements in elementRecords, let elementRecord be elementRecords[I]. Otherwise, let elementRecord be empty.
+                    let element_record = parse_record.and_then(|record| record.element(i as usize));
+
+                    // 3. Let newElement be ? InternalizeJSONProperty(val, prop, reviver, elementRecord).
+                    let new_element = Self::internalize_json_property(
+                        obj,
+                        i.into(),
+                        reviver,
+                        element_record,
+                        context,
+                    )?;
+
+                    // 4. If newElement is undefined, then
                     if new_element.is_undefined() {
                         // a. Perform ? val.[[Delete]](prop).
                         obj.__delete__(&i.into(), &mut InternalMethodContext::new(context))?;
                     }
-                    // 4. Else,
+                    // 5. Else,
                     else {
                         // a. Perform ? CreateDataProperty(val, prop, newElement).
                         obj.create_data_property(i, new_element, context)?;
@@ -223,16 +311,24 @@ impl Json {
                         .expect("EnumerableOwnPropertyNames only returns strings")
                         .clone();
 
-                    // 1. Let newElement be ? InternalizeJSONProperty(val, P, reviver).
-                    let new_element =
-                        Self::internalize_json_property(obj, p.clone(), reviver, context)?;
+                    // 1. If entryRecords contains a Record whose [[Key]] is P, let entryRecord be that Record's [[Value]]. Otherwise, let entryRecord be empty.
+                    let entry_record = parse_record.and_then(|record| record.entry(&p));
+
+                    // 2. Let newElement be ? InternalizeJSONProperty(val, P, reviver, entryRecord).
+                    let new_element = Self::internalize_json_property(
+                        obj,
+                        p.clone(),
+                        reviver,
+                        entry_record,
+                        context,
+                    )?;
 
-                    // 2. If newElement is undefined, then
+                    // 3. If newElement is undefined, then
                     if new_element.is_undefined() {
                         // a. Perform ? val.[[Delete]](P).
                         obj.__delete__(&p.into(), &mut InternalMethodContext::new(context))?;
                     }
-                    // 3. Else,
+                    // 4. Else,
                     else {
                         // a. Perform ? CreateDataProperty(val, P, newElement).
                         obj.create_data_property(p, new_element, context)?;
@@ -241,8 +337,97 @@ impl Json {
             }
         }
 
-        // 3. Return ? Call(reviver, holder, « name, val »).
-        reviver.call(&holder.clone().into(), &[name.into(), val], context)
+        // 6. Return ? Call(reviver, holder, « name, val, context »).
+        match source_context {
+            Some(source_context) => reviver.call(
+                &holder.clone().into(),
+                &[name.into(), val, source_context.into()],
+                context,
+            ),
+            None => reviver.call(&holder.clone().into(), &[name.into(), val], context),
+        }
+    }
+
+    /// `JSON.rawJSON ( text )`
+    ///
+    /// Creates a frozen object whose JSON serialization is the given primitive JSON text.
+    ///
+    /// More information:
+    ///  - [JSON.parse source text access proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-json.rawjson
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/rawJSON
+    #[cfg(feature = "experimental")]
+    pub(crate) fn raw_json(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let jsonString be ? ToString(text).
+        let json_string = args.get_or_undefined(0).to_string(context)?;
+
+        // 2. Throw a SyntaxError exception if jsonString is the empty String, or if either the first or last code unit of jsonString is any of 0x0009 (CHARACTER TABULATION), 0x000A (LINE FEED), 0x000D (CARRIAGE RETURN), or 0x0020 (SPACE).
+        let is_whitespace =
+            |unit: Option<u16>| matches!(unit, None | Some(0x0009 | 0x000A | 0x000D | 0x0020));
+        if is_whitespace(json_string.get(0))
+            || is_whitespace(json_string.get(json_string.len().wrapping_sub(1)))
+        {
+            return Err(JsNativeError::syntax()
+                .with_message("invalid raw JSON text")
+                .into());
+        }
+
+        // 3. Parse StringToCodePoints(jsonString) as a JSON text as specified in ECMA-404. Throw a SyntaxError exception if it is not a valid JSON text as defined in that specification, or if its outermost value is an object or array as defined in that specification.
+        let text = json_string
+            .to_std_string()
+            .map_err(|e| JsNativeError::syntax().with_message(e.to_string()))?;
+        match serde_json::from_str::<serde_json::Value>(&text) {
+            Err(e) => return Err(JsNativeError::syntax().with_message(e.to_string()).into()),
+            Ok(serde_json::Value::Array(_) | serde_json::Value::Object(_)) => {
+                return Err(JsNativeError::syntax()
+                    .with_message("raw JSON text cannot be an object or an array")
+                    .into());
+            }
+            Ok(_) => {}
+        }
+
+        // 4. Let internalSlotsList be « [[IsRawJSON]] ».
+        // 5. Let obj be OrdinaryObjectCreate(null, internalSlotsList).
+        let obj = JsObject::from_proto_and_data(None, RawJson);
+
+        // 6. Perform ! CreateDataPropertyOrThrow(obj, "rawJSON", jsonString).
+        obj.create_data_property_or_throw(js_string!("rawJSON"), json_string, context)
+            .expect("CreateDataPropertyOrThrow should never throw here");
+
+        // 7. Perform ! SetIntegrityLevel(obj, frozen).
+        obj.set_integrity_level(IntegrityLevel::Frozen, context)
+            .expect("SetIntegrityLevel should never throw here");
+
+        // 8. Return obj.
+        Ok(obj.into())
+    }
+
+    /// `JSON.isRawJSON ( O )`
+    ///
+    /// Returns `true` if the given value was created by `JSON.rawJSON`.
+    ///
+    /// More information:
+    ///  - [JSON.parse source text access proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-json.israwjson
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/isRawJSON
+    #[cfg(feature = "experimental")]
+    #[allow(clippy::unnecessary_wraps)]
+    pub(crate) fn is_raw_json(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. If Type(O) is Object and O has an [[IsRawJSON]] internal slot, return true.
+        // 2. Return false.
+        Ok(args
+            .get_or_undefined(0)
+            .as_object()
+            .is_some_and(JsObject::is::<RawJson>)
+            .into())
     }
 
     /// `JSON.stringify( value[, replacer[, space]] )`
@@ -463,13 +648,23 @@ impl Json {
             }
         }
 
-        // 5. If value is null, return "null".
+        // 5. If value is an Object and value has an [[IsRawJSON]] internal slot, then
+        #[cfg(feature = "experimental")]
+        if let Some(obj) = value.as_object().filter(|obj| obj.is::<RawJson>()) {
+            // a. Return ! Get(value, "rawJSON").
+            let raw_json = obj
+                .get(js_string!("rawJSON"), context)
+                .expect("Get should never throw here");
+            return Ok(raw_json.as_string().cloned());
+        }
+
+        // 6. If value is null, return "null".
         if value.is_null() {
             return Ok(Some(js_string!("null")));
         }
 
-        // 6. If value is true, return "true".
-        // 7. If value is false, return "false".
+        // 7. If value is true, return "true".
+        // 8. If value is false, return "false".
         if value.is_boolean() {
             return Ok(Some(js_string!(if value.to_boolean() {
                 "true"
@@ -478,12 +673,12 @@ impl Json {
             })));
         }
 
-        // 8. If Type(value) is String, return QuoteJSONString(value).
+        // 9. If Type(value) is String, return QuoteJSONString(value).
         if let Some(s) = value.as_string() {
             return Ok(Some(Self::quote_json_string(s)));
         }
 
-        // 9. If Type(value) is Number, then
+        // 10. If Type(value) is Number, then
         if let Some(n) = value.as_number() {
             // a. If value is finite, return ! ToString(value).
             if n.is_finite() {
@@ -498,14 +693,14 @@ impl Json {
             return Ok(Some(js_string!("null")));
         }
 
-        // 10. If Type(value) is BigInt, throw a TypeError exception.
+        // 11. If Type(value) is BigInt, throw a TypeError exception.
         if value.is_bigint() {
             return Err(JsNativeError::typ()
                 .with_message("cannot serialize bigint to JSON")
                 .into());
         }
 
-        // 11. If Type(value) is Object and IsCallable(value) is false, then
+        // 12. If Type(value) is Object and IsCallable(value) is false, then
         if let Some(obj) = value.as_object() {
             if !obj.is_callable() {
                 // a. Let isArray be ? IsArray(value).
@@ -519,7 +714,7 @@ impl Json {
             }
         }
 
-        // 12. Return undefined.
+        // 13. Return undefined.
         Ok(None)
     }
 
@@ -0,0 +1,205 @@
+//! Implementation of the JSON Parse Records used by `JSON.parse` to expose the source text of
+//! primitive values to revivers.
+//!
+//! More information:
+//!  - [JSON.parse source text access proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-json-parse-with-source/
+
+use rustc_hash::FxHashMap;
+
+use crate::{Context, JsResult, JsString, JsValue};
+
+/// A [JSON Parse Record][spec].
+///
+/// Associates a value created by `JSON.parse` with the JSON text it was parsed from.
+///
+/// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-json-parse-record
+#[derive(Debug)]
+pub(super) struct JsonParseRecord {
+    /// The `[[Value]]` field.
+    value: JsValue,
+    kind: JsonParseRecordKind,
+}
+
+#[derive(Debug)]
+enum JsonParseRecordKind {
+    /// A primitive value, with its `[[Source]]` text.
+    Primitive(JsString),
+    /// An array, with its `[[Elements]]` records.
+    Array(Vec<JsonParseRecord>),
+    /// An object, with its `[[Entries]]` records.
+    Object(FxHashMap<JsString, JsonParseRecord>),
+}
+
+impl JsonParseRecord {
+    /// Abstract operation [`CreateJSONParseRecord ( parseNode, key, val )`][spec].
+    ///
+    /// Creates the parse record of `value`, which must be the result of parsing the valid JSON
+    /// text `text`.
+    ///
+    /// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-createjsonparserecord
+    pub(super) fn new(text: &str, value: JsValue, context: &mut Context) -> JsResult<Self> {
+        let mut scanner = Scanner { text, pos: 0 };
+        scanner.record(value, context)
+    }
+
+    /// Gets the `[[Value]]` of the record.
+    pub(super) const fn value(&self) -> &JsValue {
+        &self.value
+    }
+
+    /// Gets the source text of the record, if it represents a primitive value.
+    pub(super) const fn source(&self) -> Option<&JsString> {
+        match &self.kind {
+            JsonParseRecordKind::Primitive(source) => Some(source),
+            _ => None,
+        }
+    }
+
+    /// Gets the record of the element at `index`, if it represents an array.
+    pub(super) fn element(&self, index: usize) -> Option<&Self> {
+        match &self.kind {
+            JsonParseRecordKind::Array(elements) => elements.get(index),
+            _ => None,
+        }
+    }
+
+    /// Gets the record of the entry with key `key`, if it represents an object.
+    pub(super) fn entry(&self, key: &JsString) -> Option<&Self> {
+        match &self.kind {
+            JsonParseRecordKind::Object(entries) => entries.get(key),
+            _ => None,
+        }
+    }
+}
+
+/// Walks through a valid JSON text, alongside the value produced from it.
+struct Scanner<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Scanner<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    /// Skips `byte` and any whitespace after it, returning `true` if it was found.
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            self.skip_whitespace();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Scans a string literal, including its quotes.
+    fn string(&mut self) -> &str {
+        let start = self.pos;
+        self.pos += 1;
+        while let Some(byte) = self.peek() {
+            self.pos += 1;
+            match byte {
+                b'\\' => self.pos += 1,
+                b'"' => break,
+                _ => {}
+            }
+        }
+        &self.text[start..self.pos]
+    }
+
+    /// Scans a number, boolean or null literal.
+    fn literal(&mut self) -> &str {
+        let start = self.pos;
+        while let Some(byte) = self.peek() {
+            if matches!(byte, b',' | b']' | b'}' | b' ' | b'\t' | b'\n' | b'\r') {
+                break;
+            }
+            self.pos += 1;
+        }
+        &self.text[start..self.pos]
+    }
+
+    fn record(&mut self, value: JsValue, context: &mut Context) -> JsResult<JsonParseRecord> {
+        self.skip_whitespace();
+
+        let kind = match self.peek() {
+            Some(b'[') => {
+                let object = value.as_object().expect("arrays must parse to objects");
+                let mut elements = Vec::new();
+                self.eat(b'[');
+                while !self.eat(b']') {
+                    let element = object.get(elements.len(), context)?;
+                    elements.push(self.record(element, context)?);
+                    self.eat(b',');
+                }
+                JsonParseRecordKind::Array(elements)
+            }
+            Some(b'{') => {
+                let object = value.as_object().expect("objects must parse to objects");
+                let mut entries = FxHashMap::default();
+                self.eat(b'{');
+                while !self.eat(b'}') {
+                    let key = unescape(self.string());
+                    self.skip_whitespace();
+                    self.eat(b':');
+                    // Duplicate keys keep the last value, so the last record must win as well.
+                    let entry = object.get(key.clone(), context)?;
+                    let record = self.record(entry, context)?;
+                    entries.insert(key, record);
+                    self.eat(b',');
+                }
+                JsonParseRecordKind::Object(entries)
+            }
+            Some(b'"') => JsonParseRecordKind::Primitive(self.string().into()),
+            _ => JsonParseRecordKind::Primitive(self.literal().into()),
+        };
+        self.skip_whitespace();
+
+        Ok(JsonParseRecord { value, kind })
+    }
+}
+
+/// Gets the string value of a quoted JSON string literal.
+fn unescape(literal: &str) -> JsString {
+    let contents = &literal[1..literal.len() - 1];
+    let mut result = Vec::with_capacity(contents.len());
+    let mut chars = contents.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.extend_from_slice(c.encode_utf16(&mut [0; 2]));
+            continue;
+        }
+
+        let unit = match chars.next() {
+            Some('b') => 0x0008,
+            Some('t') => 0x0009,
+            Some('n') => 0x000A,
+            Some('f') => 0x000C,
+            Some('r') => 0x000D,
+            Some('u') => {
+                let digits = chars.as_str().get(..4).unwrap_or_default();
+                let unit = u16::from_str_radix(digits, 16).unwrap_or_default();
+                chars = chars.as_str()[digits.len()..].chars();
+                unit
+            }
+            // `\"`, `\\` and `\/` escape themselves.
+            Some(c) => {
+                result.extend_from_slice(c.encode_utf16(&mut [0; 2]));
+                continue;
+            }
+            None => break,
+        };
+        result.push(unit);
+    }
+    JsString::from(&result[..])
+}
@@ -315,3 +315,59 @@ fn json_parse_with_no_args_throws_syntax_error() {
         "expected value at line 1 column 1",
     )]);
 }
+
+#[cfg(feature = "experimental")]
+#[test]
+fn json_parse_reviver_source_text() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const sources = [];
+                const parsed = JSON.parse(
+                    '{ "id": 12345678901234567890, "tags": ["a\\u0062", true], "x": null }',
+                    (key, value, context) => {
+                        sources.push(context.source);
+                        return key === "id" ? BigInt(context.source) : value;
+                    },
+                );
+            "#}),
+        TestAction::assert("parsed.id === 12345678901234567890n"),
+        TestAction::assert_eq(
+            "sources.join()",
+            js_string!(r#"12345678901234567890,"a\u0062",true,,null,"#),
+        ),
+        TestAction::assert(
+            "JSON.parse('[1]', function (k, v, c) { return k === '' ? 'source' in c : v; }) === false",
+        ),
+        TestAction::assert_eq(
+            r#"JSON.parse('[1, 2]', function (k, v, c) { if (k === "0") this[1] = 3; return k === "" ? v : c.source; })[1]"#,
+            JsValue::undefined(),
+        ),
+    ]);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn json_raw_json() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "JSON.stringify({ id: JSON.rawJSON('12345678901234567890') })",
+            js_string!(r#"{"id":12345678901234567890}"#),
+        ),
+        TestAction::assert_eq(r#"JSON.rawJSON('"x"').rawJSON"#, js_str!(r#""x""#)),
+        TestAction::assert("Object.isFrozen(JSON.rawJSON('null'))"),
+        TestAction::assert("Object.getPrototypeOf(JSON.rawJSON('1')) === null"),
+        TestAction::assert("JSON.isRawJSON(JSON.rawJSON('1'))"),
+        TestAction::assert("!JSON.isRawJSON({ rawJSON: '1' })"),
+        TestAction::assert("!JSON.isRawJSON(1)"),
+        TestAction::assert_native_error(
+            "JSON.rawJSON(' 1')",
+            JsNativeErrorKind::Syntax,
+            "invalid raw JSON text",
+        ),
+        TestAction::assert_native_error(
+            "JSON.rawJSON('{}')",
+            JsNativeErrorKind::Syntax,
+            "raw JSON text cannot be an object or an array",
+        ),
+    ]);
+}
@@ -55,7 +55,7 @@ impl IntrinsicObject for Map {
             .name(js_string!("entries"))
             .build();
 
-        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .static_method(Self::group_by, js_string!("groupBy"), 2)
             .static_accessor(
                 JsSymbol::species(),
@@ -91,8 +91,18 @@ impl IntrinsicObject for Map {
                 Some(get_size),
                 None,
                 Attribute::CONFIGURABLE,
-            )
-            .build();
+            );
+
+        #[cfg(feature = "experimental")]
+        let builder = builder
+            .method(Self::get_or_insert, js_string!("getOrInsert"), 2)
+            .method(
+                Self::get_or_insert_computed,
+                js_string!("getOrInsertComputed"),
+                2,
+            );
+
+        builder.build();
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
@@ -106,7 +116,7 @@ impl BuiltInObject for Map {
 
 impl BuiltInConstructor for Map {
     const LENGTH: usize = 0;
-    const P: usize = 11;
+    const P: usize = 13;
     const SP: usize = 2;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
@@ -363,6 +373,118 @@ impl Map {
             .into())
     }
 
+    /// `Map.prototype.getOrInsert( key, value )`
+    ///
+    /// Returns the value associated with the key, inserting `value` if the key is not present.
+    ///
+    /// More information:
+    ///  - [Upsert proposal reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-upsert/#sec-map.prototype.getOrInsert
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/getOrInsert
+    #[cfg(feature = "experimental")]
+    pub(crate) fn get_or_insert(
+        this: &JsValue,
+        args: &[JsValue],
+        _: &mut Context,
+    ) -> JsResult<JsValue> {
+        let key = args.get_or_undefined(0);
+        let value = args.get_or_undefined(1);
+
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[MapData]]).
+        let Some(mut map) = this
+            .as_object()
+            .and_then(JsObject::downcast_mut::<OrderedMap<JsValue>>)
+        else {
+            return Err(JsNativeError::typ()
+                .with_message("'this' is not a Map")
+                .into());
+        };
+
+        // 3. Set key to CanonicalizeKeyedCollectionKey(key).
+        let key = match key.as_number() {
+            Some(n) if n.is_zero() => JsValue::new(0),
+            _ => key.clone(),
+        };
+
+        // 4. For each Record { [[Key]], [[Value]] } p of M.[[MapData]], do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return p.[[Value]].
+        // 5. Let p be the Record { [[Key]]: key, [[Value]]: value }.
+        // 6. Append p to M.[[MapData]].
+        // 7. Return value.
+        Ok(map.get_or_insert(key, value.clone()).clone())
+    }
+
+    /// `Map.prototype.getOrInsertComputed( key, callbackfn )`
+    ///
+    /// Returns the value associated with the key, inserting the result of calling `callbackfn`
+    /// with the key if the key is not present.
+    ///
+    /// More information:
+    ///  - [Upsert proposal reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-upsert/#sec-map.prototype.getOrInsertComputed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/getOrInsertComputed
+    #[cfg(feature = "experimental")]
+    pub(crate) fn get_or_insert_computed(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[MapData]]).
+        let Some(object) = this
+            .as_object()
+            .filter(|object| object.is::<OrderedMap<JsValue>>())
+        else {
+            return Err(JsNativeError::typ()
+                .with_message("'this' is not a Map")
+                .into());
+        };
+
+        // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
+        let Some(callback) = args.get_or_undefined(1).as_callable() else {
+            return Err(JsNativeError::typ()
+                .with_message("Map.prototype.getOrInsertComputed: callback is not callable")
+                .into());
+        };
+
+        // 4. Set key to CanonicalizeKeyedCollectionKey(key).
+        let key = args.get_or_undefined(0);
+        let key = match key.as_number() {
+            Some(n) if n.is_zero() => JsValue::new(0),
+            _ => key.clone(),
+        };
+
+        // 5. For each Record { [[Key]], [[Value]] } p of M.[[MapData]], do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return p.[[Value]].
+        if let Some(value) = object
+            .downcast_ref::<OrderedMap<JsValue>>()
+            .and_then(|map| map.get(&key).cloned())
+        {
+            return Ok(value);
+        }
+
+        // 6. Let value be ? Call(callbackfn, undefined, « key »).
+        let value = callback.call(&JsValue::undefined(), std::slice::from_ref(&key), context)?;
+
+        // 7. For each Record { [[Key]], [[Value]] } p of M.[[MapData]], do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, then
+        //         i. Set p.[[Value]] to value.
+        //         ii. Return value.
+        // 8. Let p be the Record { [[Key]]: key, [[Value]]: value }.
+        // 9. Append p to M.[[MapData]].
+        if let Some(mut map) = object.downcast_mut::<OrderedMap<JsValue>>() {
+            map.insert(key, value.clone());
+        }
+
+        // 10. Return value.
+        Ok(value)
+    }
+
     /// `Map.prototype.clear( )`
     ///
     /// Removes all entries from the map.
@@ -123,6 +123,17 @@ impl<V> OrderedMap<V> {
         self.map.insert(MapKey::Key(key), Some(value)).flatten()
     }
 
+    /// Return a reference to the value stored for `key`, inserting `value`
+    /// last in order if no equivalent key existed in the map.
+    ///
+    /// Computes in **O(1)** time (amortized average).
+    pub fn get_or_insert(&mut self, key: JsValue, value: V) -> &V {
+        self.map
+            .entry(MapKey::Key(key))
+            .or_insert(None)
+            .get_or_insert(value)
+    }
+
     /// Remove the key-value pair equivalent to `key` and return
     /// its value.
     ///
@@ -105,6 +105,53 @@ fn set() {
     ]);
 }
 
+#[cfg(feature = "experimental")]
+#[test]
+fn get_or_insert() {
+    run_test_actions([
+        TestAction::run("let map = new Map([['1', 'one']]);"),
+        TestAction::assert_eq("map.getOrInsert('1', 'uno')", js_str!("one")),
+        TestAction::assert_eq("map.getOrInsert('2', 'two')", js_str!("two")),
+        TestAction::assert_eq("map.get('2')", js_str!("two")),
+        TestAction::assert_eq("map.getOrInsert(-0, 'zero'); map.get(0)", js_str!("zero")),
+        TestAction::assert_eq("map.size", 3),
+    ]);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn get_or_insert_computed() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                let map = new Map([["1", "one"]]);
+                let calls = 0;
+                function compute(key) {
+                    calls++;
+                    return key + "!";
+                }
+            "#}),
+        TestAction::assert_eq("map.getOrInsertComputed('1', compute)", js_str!("one")),
+        TestAction::assert_eq("calls", 0),
+        TestAction::assert_eq("map.getOrInsertComputed('2', compute)", js_str!("2!")),
+        TestAction::assert_eq("calls", 1),
+        TestAction::assert_eq("map.get('2')", js_str!("2!")),
+        TestAction::assert_eq("map.getOrInsertComputed(-0, key => 1 / key)", f64::INFINITY),
+        // The callback may insert the key itself, in which case its result overwrites the entry.
+        TestAction::assert_eq(
+            "map.getOrInsertComputed('3', key => { map.set(key, 'inner'); return 'outer'; })",
+            js_str!("outer"),
+        ),
+        TestAction::assert_eq("map.get('3')", js_str!("outer")),
+        TestAction::assert_eq("map.size", 4),
+        TestAction::assert_native_error(
+            "map.getOrInsertComputed('4', 'not callable')",
+            JsNativeErrorKind::Type,
+            "Map.prototype.getOrInsertComputed: callback is not callable",
+        ),
+        TestAction::assert("!map.has('4')"),
+    ]);
+}
+
 #[test]
 fn clear() {
     run_test_actions([
@@ -160,6 +160,20 @@ fn fround() {
     ]);
 }
 
+#[test]
+fn f16round() {
+    run_test_actions([
+        TestAction::assert_eq("Math.f16round(NaN)", f64::NAN),
+        TestAction::assert_eq("Math.f16round(Infinity)", f64::INFINITY),
+        TestAction::assert_eq("Math.f16round(5)", 5.0),
+        TestAction::assert_eq("Math.f16round(5.05)", 5.050_781_25),
+        TestAction::assert_eq("Math.f16round(1.337)", 1.336_914_062_5),
+        TestAction::assert_eq("Math.f16round(65519)", 65504.0),
+        TestAction::assert_eq("Math.f16round(65520)", f64::INFINITY),
+        TestAction::assert_eq("Math.f16round()", f64::NAN),
+    ]);
+}
+
 #[test]
 fn hypot() {
     run_test_actions([
@@ -2,6 +2,7 @@
 
 pub mod array;
 pub mod array_buffer;
+pub mod async_disposable_stack;
 pub mod async_function;
 pub mod async_generator;
 pub mod async_generator_function;
@@ -10,6 +11,7 @@ pub mod bigint;
 pub mod boolean;
 pub mod dataview;
 pub mod date;
+pub mod disposable_stack;
 pub mod error;
 pub mod eval;
 pub mod function;
@@ -26,6 +28,8 @@ pub mod proxy;
 pub mod reflect;
 pub mod regexp;
 pub mod set;
+#[cfg(feature = "experimental")]
+pub mod shadow_realm;
 pub mod string;
 pub mod symbol;
 pub mod typed_array;
@@ -44,13 +48,16 @@ pub mod escape;
 #[cfg(feature = "intl")]
 pub mod intl;
 
-// TODO: remove `cfg` when `Temporal` gets to stage 4.
-#[cfg(any(feature = "intl", feature = "temporal"))]
+// TODO: remove `cfg_attr` when `Temporal` gets to stage 4.
+#[cfg_attr(not(any(feature = "intl", feature = "temporal")), allow(dead_code))]
 pub(crate) mod options;
 
 #[cfg(feature = "temporal")]
 pub mod temporal;
 
+#[cfg(any(feature = "intl", feature = "temporal"))]
+pub(crate) mod time_zone;
+
 pub(crate) use self::{
     array::Array,
     async_function::AsyncFunction,
@@ -59,8 +66,8 @@ pub(crate) use self::{
     dataview::DataView,
     date::Date,
     error::{
-        AggregateError, Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError,
-        UriError,
+        AggregateError, Error, EvalError, RangeError, ReferenceError, SuppressedError, SyntaxError,
+        TypeError, UriError,
     },
     eval::Eval,
     function::BuiltInFunctionObject,
@@ -77,8 +84,8 @@ pub(crate) use self::{
     string::String,
     symbol::Symbol,
     typed_array::{
-        BigInt64Array, BigUint64Array, Float32Array, Float64Array, Int16Array, Int32Array,
-        Int8Array, Uint16Array, Uint32Array, Uint8Array, Uint8ClampedArray,
+        BigInt64Array, BigUint64Array, Float16Array, Float32Array, Float64Array, Int16Array,
+        Int32Array, Int8Array, Uint16Array, Uint32Array, Uint8Array, Uint8ClampedArray,
     },
 };
 
@@ -86,13 +93,17 @@ use crate::{
     builtins::{
         array::ArrayIterator,
         array_buffer::{ArrayBuffer, SharedArrayBuffer},
+        async_disposable_stack::AsyncDisposableStack,
         async_generator::AsyncGenerator,
         async_generator_function::AsyncGeneratorFunction,
         atomics::Atomics,
+        disposable_stack::DisposableStack,
         error::r#type::ThrowTypeError,
         generator::Generator,
         generator_function::GeneratorFunction,
-        iterable::{AsyncFromSyncIterator, AsyncIterator, Iterator},
+        iterable::{
+            AsyncFromSyncIterator, AsyncIterator, Iterator, IteratorHelper, WrapForValidIterator,
+        },
         map::MapIterator,
         object::for_in_iterator::ForInIterator,
         regexp::RegExpStringIterator,
@@ -100,7 +111,7 @@ use crate::{
         string::StringIterator,
         typed_array::BuiltinTypedArray,
         uri::{DecodeUri, DecodeUriComponent, EncodeUri, EncodeUriComponent},
-        weak::WeakRef,
+        weak::{FinalizationRegistry, WeakRef},
         weak_map::WeakMap,
         weak_set::WeakSet,
     },
@@ -202,6 +213,8 @@ impl Realm {
         BuiltInFunctionObject::init(self);
         OrdinaryObject::init(self);
         Iterator::init(self);
+        IteratorHelper::init(self);
+        WrapForValidIterator::init(self);
         AsyncIterator::init(self);
         AsyncFromSyncIterator::init(self);
         ForInIterator::init(self);
@@ -240,6 +253,7 @@ impl Realm {
         Uint32Array::init(self);
         BigInt64Array::init(self);
         BigUint64Array::init(self);
+        Float16Array::init(self);
         Float32Array::init(self);
         Float64Array::init(self);
         Symbol::init(self);
@@ -252,6 +266,7 @@ impl Realm {
         EvalError::init(self);
         UriError::init(self);
         AggregateError::init(self);
+        SuppressedError::init(self);
         Reflect::init(self);
         Generator::init(self);
         GeneratorFunction::init(self);
@@ -264,6 +279,11 @@ impl Realm {
         DecodeUri::init(self);
         DecodeUriComponent::init(self);
         WeakRef::init(self);
+        FinalizationRegistry::init(self);
+        DisposableStack::init(self);
+        AsyncDisposableStack::init(self);
+        #[cfg(feature = "experimental")]
+        shadow_realm::ShadowRealm::init(self);
         WeakMap::init(self);
         WeakSet::init(self);
         Atomics::init(self);
@@ -285,6 +305,9 @@ impl Realm {
             intl::segmenter::Segments::init(self);
             intl::segmenter::SegmentIterator::init(self);
             intl::PluralRules::init(self);
+            intl::RelativeTimeFormat::init(self);
+            intl::DisplayNames::init(self);
+            intl::DurationFormat::init(self);
             intl::NumberFormat::init(self);
         }
 
@@ -371,6 +394,7 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<Uint32Array>(context)?;
     global_binding::<BigInt64Array>(context)?;
     global_binding::<BigUint64Array>(context)?;
+    global_binding::<Float16Array>(context)?;
     global_binding::<Float32Array>(context)?;
     global_binding::<Float64Array>(context)?;
     global_binding::<Symbol>(context)?;
@@ -382,6 +406,7 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<EvalError>(context)?;
     global_binding::<UriError>(context)?;
     global_binding::<AggregateError>(context)?;
+    global_binding::<SuppressedError>(context)?;
     global_binding::<Reflect>(context)?;
     global_binding::<Promise>(context)?;
     global_binding::<EncodeUri>(context)?;
@@ -389,6 +414,12 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<DecodeUri>(context)?;
     global_binding::<DecodeUriComponent>(context)?;
     global_binding::<WeakRef>(context)?;
+    global_binding::<FinalizationRegistry>(context)?;
+    global_binding::<DisposableStack>(context)?;
+    global_binding::<AsyncDisposableStack>(context)?;
+    #[cfg(feature = "experimental")]
+    global_binding::<shadow_realm::ShadowRealm>(context)?;
+    global_binding::<Iterator>(context)?;
     global_binding::<WeakMap>(context)?;
     global_binding::<WeakSet>(context)?;
     global_binding::<Atomics>(context)?;
@@ -24,12 +24,15 @@ use crate::{
 };
 use boa_gc::{Finalize, Trace};
 use boa_macros::{js_str, utf16};
-use boa_parser::lexer::regex::RegExpFlags;
+use boa_parser::lexer::regex::{rename_duplicate_named_groups, RegExpFlags};
 use regress::{Flags, Range, Regex};
 use std::str::FromStr;
 
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
+#[cfg(feature = "annex-b")]
+use crate::native_function::NativeFunctionPointer;
+
 mod regexp_string_iterator;
 pub(crate) use regexp_string_iterator::RegExpStringIterator;
 #[cfg(test)]
@@ -37,14 +40,126 @@ mod tests;
 
 /// The internal representation of a `RegExp` object.
 #[derive(Debug, Clone, Trace, Finalize, JsData)]
-// Safety: `RegExp` does not contain any objects which needs to be traced, so this is safe.
-#[boa_gc(unsafe_empty_trace)]
 pub struct RegExp {
     /// Regex matcher.
+    #[unsafe_ignore_trace]
     matcher: Regex,
+    #[unsafe_ignore_trace]
     flags: RegExpFlags,
+    /// The `(new name, original name)` pairs of the duplicate named groups that were renamed
+    /// before compiling the matcher.
+    #[unsafe_ignore_trace]
+    renamed_groups: Box<[(String, String)]>,
     original_source: JsString,
     original_flags: JsString,
+
+    /// The realm in which this `RegExp` was allocated.
+    #[cfg(feature = "annex-b")]
+    realm: Realm,
+
+    /// Whether this `RegExp` updates the legacy static properties of `%RegExp%`.
+    #[cfg(feature = "annex-b")]
+    legacy_features_enabled: bool,
+}
+
+/// The legacy static properties of `%RegExp%`, as defined in the [legacy RegExp features proposal][spec].
+///
+/// A value of `None` represents an `empty` internal slot, which throws a `TypeError` when accessed.
+///
+/// [spec]: https://github.com/tc39/proposal-regexp-legacy-features
+#[cfg(feature = "annex-b")]
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct LegacyRegExpStatics {
+    /// `[[RegExpInput]]`
+    input: Option<JsString>,
+
+    /// The rest of the internal slots, which are always updated and invalidated together.
+    last_match: Option<LegacyRegExpMatch>,
+}
+
+#[cfg(feature = "annex-b")]
+impl Default for LegacyRegExpStatics {
+    fn default() -> Self {
+        // The initial value of all these internal slots is the empty String.
+        Self {
+            input: Some(JsString::default()),
+            last_match: Some(LegacyRegExpMatch::default()),
+        }
+    }
+}
+
+#[cfg(feature = "annex-b")]
+#[derive(Debug, Clone, Default, Trace, Finalize)]
+struct LegacyRegExpMatch {
+    /// `[[RegExpLastMatch]]`
+    last_match: JsString,
+    /// `[[RegExpLastParen]]`
+    last_paren: JsString,
+    /// `[[RegExpLeftContext]]`
+    left_context: JsString,
+    /// `[[RegExpRightContext]]`
+    right_context: JsString,
+    /// `[[RegExpParen1]]` to `[[RegExpParen9]]`
+    parens: [JsString; 9],
+}
+
+#[cfg(feature = "annex-b")]
+impl LegacyRegExpStatics {
+    /// `UpdateLegacyRegExpStaticProperties ( C, S, startIndex, endIndex, capturedValues )`
+    ///
+    /// More information:
+    ///  - [proposal-regexp-legacy-features][spec]
+    ///
+    /// [spec]: https://github.com/tc39/proposal-regexp-legacy-features#updatelegacyregexpstaticproperties--c-s-startindex-endindex-capturedvalues-
+    fn update(&mut self, s: &JsString, match_value: &regress::Match) {
+        let range = match_value.start()..match_value.end();
+        let captured_values = match_value
+            .captures
+            .iter()
+            .map(|capture| {
+                capture
+                    .clone()
+                    .map_or_else(JsString::default, |range| js_string!(s.get_expect(range)))
+            })
+            .collect::<Vec<_>>();
+
+        // 6. Let n be the number of elements in capturedValues.
+        // 7. Set the value of C's [[RegExpInput]] internal slot to S.
+        self.input = Some(s.clone());
+
+        // 13. For each integer i such that 1 ≤ i ≤ 9
+        //     a. If i ≤ n, set the value of C's [[RegExpPareni]] internal slot to the ith element of capturedValues.
+        //     b. Else, set the value of C's [[RegExpPareni]] internal slot to the empty String.
+        let mut parens: [JsString; 9] = Default::default();
+        for (paren, value) in parens.iter_mut().zip(&captured_values) {
+            *paren = value.clone();
+        }
+
+        self.last_match = Some(LegacyRegExpMatch {
+            // 8. Set the value of C's [[RegExpLastMatch]] internal slot to a String whose length is endIndex - startIndex and containing the code units from S with indices startIndex through endIndex - 1, in ascending order.
+            last_match: js_string!(s.get_expect(range.clone())),
+            // 9. If n > 0, set the value of C's [[RegExpLastParen]] internal slot to the last element of capturedValues.
+            // 10. Else, set the value of C's [[RegExpLastParen]] internal slot to the empty String.
+            last_paren: captured_values.last().cloned().unwrap_or_default(),
+            // 11. Set the value of C's [[RegExpLeftContext]] internal slot to a String whose length is startIndex and containing the code units from S with indices 0 through startIndex - 1, in ascending order.
+            left_context: js_string!(s.get_expect(..range.start)),
+            // 12. Set the value of C's [[RegExpRightContext]] internal slot to a String whose length is len - endIndex and containing the code units from S with indices endIndex through len - 1, in ascending order.
+            right_context: js_string!(s.get_expect(range.end..)),
+            parens,
+        });
+    }
+
+    /// `InvalidateLegacyRegExpStaticProperties ( C )`
+    ///
+    /// More information:
+    ///  - [proposal-regexp-legacy-features][spec]
+    ///
+    /// [spec]: https://github.com/tc39/proposal-regexp-legacy-features#invalidatelegacyregexpstaticproperties--c
+    fn invalidate(&mut self) {
+        // 2. Set the value of the following internal slots of C to empty:
+        self.input = None;
+        self.last_match = None;
+    }
 }
 
 impl IntrinsicObject for RegExp {
@@ -92,6 +207,7 @@ impl IntrinsicObject for RegExp {
                 None,
                 Attribute::CONFIGURABLE,
             )
+            .static_method(Self::escape, js_string!("escape"), 1)
             .property(js_string!("lastIndex"), 0, Attribute::all())
             .method(Self::test, js_string!("test"), 1)
             .method(Self::exec, js_string!("exec"), 1)
@@ -158,7 +274,74 @@ impl IntrinsicObject for RegExp {
             );
 
         #[cfg(feature = "annex-b")]
-        let regexp = regexp.method(Self::compile, js_string!("compile"), 2);
+        let regexp = {
+            let mut regexp = regexp.method(Self::compile, js_string!("compile"), 2);
+
+            let get_input = BuiltInBuilder::callable(realm, Self::get_legacy_input)
+                .name(js_string!("get input"))
+                .build();
+            let set_input = BuiltInBuilder::callable(realm, Self::set_legacy_input)
+                .name(js_string!("set input"))
+                .length(1)
+                .build();
+            for name in [js_string!("input"), js_string!("$_")] {
+                regexp = regexp.static_accessor(
+                    name,
+                    Some(get_input.clone()),
+                    Some(set_input.clone()),
+                    Attribute::CONFIGURABLE,
+                );
+            }
+
+            let legacy_getters: [(_, _, NativeFunctionPointer); 13] = [
+                (
+                    js_string!("lastMatch"),
+                    Some(js_string!("$&")),
+                    Self::get_legacy_last_match,
+                ),
+                (
+                    js_string!("lastParen"),
+                    Some(js_string!("$+")),
+                    Self::get_legacy_last_paren,
+                ),
+                (
+                    js_string!("leftContext"),
+                    Some(js_string!("$`")),
+                    Self::get_legacy_left_context,
+                ),
+                (
+                    js_string!("rightContext"),
+                    Some(js_string!("$'")),
+                    Self::get_legacy_right_context,
+                ),
+                (js_string!("$1"), None, Self::get_legacy_paren::<1>),
+                (js_string!("$2"), None, Self::get_legacy_paren::<2>),
+                (js_string!("$3"), None, Self::get_legacy_paren::<3>),
+                (js_string!("$4"), None, Self::get_legacy_paren::<4>),
+                (js_string!("$5"), None, Self::get_legacy_paren::<5>),
+                (js_string!("$6"), None, Self::get_legacy_paren::<6>),
+                (js_string!("$7"), None, Self::get_legacy_paren::<7>),
+                (js_string!("$8"), None, Self::get_legacy_paren::<8>),
+                (js_string!("$9"), None, Self::get_legacy_paren::<9>),
+            ];
+            for (name, alias, getter) in legacy_getters {
+                let getter = BuiltInBuilder::callable(realm, getter)
+                    .name(js_string!(js_str!("get "), &name))
+                    .build();
+                regexp = regexp.static_accessor(
+                    name,
+                    Some(getter.clone()),
+                    None,
+                    Attribute::CONFIGURABLE,
+                );
+                if let Some(alias) = alias {
+                    regexp =
+                        regexp.static_accessor(alias, Some(getter), None, Attribute::CONFIGURABLE);
+                }
+            }
+
+            regexp
+        };
 
         regexp.build();
     }
@@ -175,7 +358,7 @@ impl BuiltInObject for RegExp {
 impl BuiltInConstructor for RegExp {
     const LENGTH: usize = 2;
     const P: usize = 19;
-    const SP: usize = 1;
+    const SP: usize = 2;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
         StandardConstructors::regexp;
@@ -263,7 +446,28 @@ impl BuiltInConstructor for RegExp {
             get_prototype_from_constructor(new_target, StandardConstructors::regexp, context)?;
 
         // 8.Return ? RegExpInitialize(O, P, F).
-        Self::initialize(Some(proto), &p, &f, context)
+        let regexp = Self::initialize(Some(proto), &p, &f, context)?;
+
+        // Legacy RegExp features, RegExpAlloc ( newTarget )
+        // 5. If SameValue(newTarget, thisRealm.[[Intrinsics]].[[%RegExp%]]) is true, then
+        //     a. Set obj.[[LegacyFeaturesEnabled]] to true.
+        // 6. Else,
+        //     a. Set obj.[[LegacyFeaturesEnabled]] to false.
+        #[cfg(feature = "annex-b")]
+        if let Some(new_target) = new_target.as_object() {
+            let legacy_features_enabled = JsObject::equals(
+                new_target,
+                &context.intrinsics().constructors().regexp().constructor(),
+            );
+            if let Some(mut rx) = regexp
+                .as_object()
+                .and_then(JsObject::downcast_mut::<RegExp>)
+            {
+                rx.legacy_features_enabled = legacy_features_enabled;
+            }
+        }
+
+        Ok(regexp)
     }
 }
 
@@ -338,12 +542,18 @@ impl RegExp {
 
         // 13. Let parseResult be ParsePattern(patternText, u, v).
         // 14. If parseResult is a non-empty List of SyntaxError objects, throw a SyntaxError exception.
-        let matcher =
-            Regex::from_unicode(p.code_points().map(CodePoint::as_u32), Flags::from(flags))
+        let mut pattern = p.code_points().map(CodePoint::as_u32).collect();
+        let renamed_groups =
+            rename_duplicate_named_groups(&mut pattern, flags.contains(RegExpFlags::UNICODE_SETS))
                 .map_err(|error| {
                     JsNativeError::syntax()
-                        .with_message(format!("failed to create matcher: {}", error.text))
+                        .with_message(format!("failed to create matcher: {error}"))
                 })?;
+        let matcher =
+            Regex::from_unicode(pattern.into_iter(), Flags::from(flags)).map_err(|error| {
+                JsNativeError::syntax()
+                    .with_message(format!("failed to create matcher: {}", error.text))
+            })?;
 
         // 15. Assert: parseResult is a Pattern Parse Node.
         // 16. Set obj.[[OriginalSource]] to P.
@@ -355,11 +565,35 @@ impl RegExp {
         Ok(RegExp {
             matcher,
             flags,
+            renamed_groups: renamed_groups.into_boxed_slice(),
             original_source: p,
             original_flags: f,
+            #[cfg(feature = "annex-b")]
+            realm: context.realm().clone(),
+            #[cfg(feature = "annex-b")]
+            legacy_features_enabled: true,
         })
     }
 
+    /// Gets the `[[OriginalSource]]` internal slot of the `RegExp`.
+    pub(crate) const fn original_source(&self) -> &JsString {
+        &self.original_source
+    }
+
+    /// Gets the `[[OriginalFlags]]` internal slot of the `RegExp`.
+    pub(crate) const fn original_flags(&self) -> &JsString {
+        &self.original_flags
+    }
+
+    /// Gets the name of the named group `name` of the matcher as written in the original
+    /// pattern, undoing the renaming of duplicate named groups.
+    fn original_group_name<'a>(&'a self, name: &'a str) -> &'a str {
+        self.renamed_groups
+            .iter()
+            .find(|(new_name, _)| new_name == name)
+            .map_or(name, |(_, original)| original)
+    }
+
     /// `RegExpInitialize ( obj, pattern, flags )`
     ///
     /// If prototype is `None`, initializes the prototype to `%RegExp%.prototype`.
@@ -426,6 +660,48 @@ impl RegExp {
         Ok(this.clone())
     }
 
+    /// `RegExp.escape ( S )`
+    ///
+    /// Escapes any characters that could be interpreted as syntax in a regular expression pattern.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexp.escape
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/escape
+    fn escape(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. If S is not a String, throw a TypeError exception.
+        let s = args.get_or_undefined(0).as_string().ok_or_else(|| {
+            JsNativeError::typ().with_message("RegExp.escape requires a string argument")
+        })?;
+
+        // 2. Let escaped be the empty String.
+        let mut escaped = Vec::with_capacity(s.len());
+
+        // 3. Let cpList be StringToCodePoints(S).
+        // 4. For each code point c of cpList, do
+        for c in s.code_points() {
+            match c {
+                // a. If escaped is the empty String and c is matched by either DecimalDigit or AsciiLetter, then
+                CodePoint::Unicode(c) if escaped.is_empty() && c.is_ascii_alphanumeric() => {
+                    // i. NOTE: Escaping a leading digit ensures that output corresponds with pattern text which may be used after a \0 character escape or a DecimalEscape such as \1 and still match S rather than be interpreted as an extension of the preceding escape sequence. Escaping a leading ASCII letter does the same for the context after \c.
+                    // ii. Let numericValue be the numeric value of c.
+                    // iii. Let hex be Number::toString(𝔽(numericValue), 16).
+                    // iv. Assert: The length of hex is 2.
+                    // v. Set escaped to the string-concatenation of the code unit 0x005C (REVERSE SOLIDUS), "x", and hex.
+                    escaped.extend(format!("\\x{:x}", u32::from(c)).encode_utf16());
+                }
+                // b. Else,
+                //     i. Set escaped to the string-concatenation of escaped and EncodeForRegExpEscape(c).
+                c => encode_for_regexp_escape(&mut escaped, c),
+            }
+        }
+
+        // 5. Return escaped.
+        Ok(js_string!(&escaped[..]).into())
+    }
+
     fn regexp_has_flag(this: &JsValue, flag: u8, context: &mut Context) -> JsResult<JsValue> {
         if let Some(object) = this.as_object() {
             if let Some(regexp) = object.downcast_ref::<RegExp>() {
@@ -1071,10 +1347,28 @@ impl RegExp {
 
         let mut named_groups = match_value
             .named_groups()
+            .map(|(name, range)| (rx.original_group_name(name), range))
             .collect::<Vec<(&str, Option<Range>)>>();
         // Strict mode requires groups to be created in a sorted order
         named_groups.sort_by(|(name_x, _), (name_y, _)| name_x.cmp(name_y));
 
+        // Duplicate group names can only appear in different alternatives, which means at most
+        // one of the groups with the same name participated in the match. Keep a single entry
+        // for each name, using the range of the group that matched, if any.
+        //
+        // 33.e.ii. If matchedGroupNames contains s, then
+        //     1. Assert: capturedValue is undefined.
+        //     2. Append undefined to groupNames.
+        named_groups.dedup_by(|(name, range), (prev_name, prev_range)| {
+            if name != prev_name {
+                return false;
+            }
+            if prev_range.is_none() {
+                *prev_range = range.take();
+            }
+            true
+        });
+
         // Combines:
         // 26. Let groupNames be a new empty List.
         // 30. If R contains any GroupName, then
@@ -1187,6 +1481,25 @@ impl RegExp {
                 .expect("this CreateDataPropertyOrThrow call must not fail");
         }
 
+        // Legacy RegExp features, RegExpBuiltinExec ( R, S )
+        // 1. Let thisRealm be the current Realm Record.
+        // 2. Let rRealm be the value of R's [[Realm]] internal slot.
+        // 3. If SameValue(thisRealm, rRealm) is true, then
+        #[cfg(feature = "annex-b")]
+        if rx.realm == *context.realm() {
+            let mut statics = context.realm().regexp_legacy_statics().borrow_mut();
+
+            // a. If the value of R's [[LegacyFeaturesEnabled]] internal slot is true, then
+            if rx.legacy_features_enabled {
+                // i. Perform UpdateLegacyRegExpStaticProperties(%RegExp%, S, lastIndex, e, capturedValues).
+                statics.update(input, &match_value);
+            // b. Else,
+            } else {
+                // i. Perform InvalidateLegacyRegExpStaticProperties(%RegExp%).
+                statics.invalidate();
+            }
+        }
+
         // 35. Return A.
         Ok(Some(a))
     }
@@ -1891,6 +2204,33 @@ impl RegExp {
                 JsNativeError::typ()
                     .with_message("`RegExp.prototype.compile` cannot be called for a non-object")
             })?;
+
+        {
+            let rx = this
+                .downcast_ref::<RegExp>()
+                .expect("already checked that the object was a RegExp");
+
+            // Legacy RegExp features
+            // 3. Let thisRealm be the current Realm Record.
+            // 4. Let oRealm be the value of O's [[Realm]] internal slot.
+            // 5. If SameValue(thisRealm, oRealm) is false, throw a TypeError exception.
+            if rx.realm != *context.realm() {
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "`RegExp.prototype.compile` cannot be called on a cross-realm RegExp",
+                    )
+                    .into());
+            }
+
+            // 6. If the value of R's [[LegacyFeaturesEnabled]] internal slot is false, throw a TypeError exception.
+            if !rx.legacy_features_enabled {
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "`RegExp.prototype.compile` cannot be called on a RegExp subclass instance",
+                    )
+                    .into());
+            }
+        }
         let pattern = args.get_or_undefined(0);
         let flags = args.get_or_undefined(1);
         // 3. If pattern is an Object and pattern has a [[RegExpMatcher]] internal slot, then
@@ -1930,6 +2270,210 @@ impl RegExp {
 
         Ok(this.into())
     }
+
+    /// `GetLegacyRegExpStaticProperty ( C, thisValue, internalSlotName )`
+    ///
+    /// More information:
+    ///  - [proposal-regexp-legacy-features][spec]
+    ///
+    /// [spec]: https://github.com/tc39/proposal-regexp-legacy-features#getlegacyregexpstaticproperty-c-thisvalue-internalslotname-
+    #[cfg(feature = "annex-b")]
+    fn get_legacy_static_property(
+        this: &JsValue,
+        context: &Context,
+        internal_slot: fn(&LegacyRegExpStatics) -> Option<&JsString>,
+    ) -> JsResult<JsValue> {
+        // 1. Assert C is an object that has an internal slot named internalSlotName.
+        // 2. If SameValue(C, thisValue) is false, throw a TypeError exception.
+        let c = context.intrinsics().constructors().regexp().constructor();
+        if !this
+            .as_object()
+            .is_some_and(|this| JsObject::equals(this, &c))
+        {
+            return Err(JsNativeError::typ()
+                .with_message("legacy RegExp static properties can only be accessed on %RegExp%")
+                .into());
+        }
+
+        // 3. Let value be the value of the internal slot of C named internalSlotName.
+        let statics = context.realm().regexp_legacy_statics().borrow();
+
+        // 4. If value is empty, throw a TypeError exception.
+        // 5. Return value.
+        internal_slot(&statics)
+            .map(|value| value.clone().into())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("legacy RegExp static properties have been invalidated")
+                    .into()
+            })
+    }
+
+    /// `get RegExp.input` and `get RegExp.$_`
+    #[cfg(feature = "annex-b")]
+    fn get_legacy_input(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value, [[RegExpInput]]).
+        Self::get_legacy_static_property(this, context, |statics| statics.input.as_ref())
+    }
+
+    /// `set RegExp.input` and `set RegExp.$_`
+    ///
+    /// Includes the steps of `SetLegacyRegExpStaticProperty ( C, thisValue, internalSlotName, val )`.
+    #[cfg(feature = "annex-b")]
+    fn set_legacy_input(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Assert C is an object that has an internal slot named internalSlotName.
+        // 2. If SameValue(C, thisValue) is false, throw a TypeError exception.
+        let c = context.intrinsics().constructors().regexp().constructor();
+        if !this
+            .as_object()
+            .is_some_and(|this| JsObject::equals(this, &c))
+        {
+            return Err(JsNativeError::typ()
+                .with_message("legacy RegExp static properties can only be accessed on %RegExp%")
+                .into());
+        }
+
+        // 3. Let strVal be ? ToString(val).
+        let str_val = args.get_or_undefined(0).to_string(context)?;
+
+        // 4. Set the value of the internal slot of C named internalSlotName to strVal.
+        context.realm().regexp_legacy_statics().borrow_mut().input = Some(str_val);
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `get RegExp.lastMatch` and `get RegExp.$&`
+    #[cfg(feature = "annex-b")]
+    fn get_legacy_last_match(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value, [[RegExpLastMatch]]).
+        Self::get_legacy_static_property(this, context, |statics| {
+            statics.last_match.as_ref().map(|m| &m.last_match)
+        })
+    }
+
+    /// `get RegExp.lastParen` and `get RegExp.$+`
+    #[cfg(feature = "annex-b")]
+    fn get_legacy_last_paren(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value, [[RegExpLastParen]]).
+        Self::get_legacy_static_property(this, context, |statics| {
+            statics.last_match.as_ref().map(|m| &m.last_paren)
+        })
+    }
+
+    /// `get RegExp.leftContext` and ``get RegExp.$` ``
+    #[cfg(feature = "annex-b")]
+    fn get_legacy_left_context(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value, [[RegExpLeftContext]]).
+        Self::get_legacy_static_property(this, context, |statics| {
+            statics.last_match.as_ref().map(|m| &m.left_context)
+        })
+    }
+
+    /// `get RegExp.rightContext` and `get RegExp.$'`
+    #[cfg(feature = "annex-b")]
+    fn get_legacy_right_context(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value, [[RegExpRightContext]]).
+        Self::get_legacy_static_property(this, context, |statics| {
+            statics.last_match.as_ref().map(|m| &m.right_context)
+        })
+    }
+
+    /// `get RegExp.$1` to `get RegExp.$9`
+    #[cfg(feature = "annex-b")]
+    fn get_legacy_paren<const N: usize>(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value, [[RegExpParenN]]).
+        Self::get_legacy_static_property(this, context, |statics| {
+            statics.last_match.as_ref().map(|m| &m.parens[N - 1])
+        })
+    }
+}
+
+/// `EncodeForRegExpEscape ( c )`
+///
+/// Appends the escaped form of `c` to `escaped`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-encodeforregexpescape
+fn encode_for_regexp_escape(escaped: &mut Vec<u16>, c: CodePoint) {
+    // 3. Let otherPunctuators be the string-concatenation of ",-=<>#&!%:;@~'`" and the code unit 0x0022 (QUOTATION MARK).
+    // 4. Let toEscape be StringToCodePoints(otherPunctuators).
+    const OTHER_PUNCTUATORS: &str = ",-=<>#&!%:;@~'`\"";
+
+    let mut buf = [0; 2];
+    match c {
+        // 1. If c is matched by SyntaxCharacter or c is U+002F (SOLIDUS), then
+        CodePoint::Unicode(
+            c @ ('^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|'
+            | '/'),
+        ) => {
+            // a. Return the string-concatenation of 0x005C (REVERSE SOLIDUS) and UTF16EncodeCodePoint(c).
+            escaped.push(u16::from(b'\\'));
+            escaped.extend_from_slice(c.encode_utf16(&mut buf));
+        }
+        // 2. Else if c is the code point listed in some cell of the "Code Point" column of Table 67, then
+        //     a. Return the string-concatenation of 0x005C (REVERSE SOLIDUS) and the string in the "ControlEscape" column of the row whose "Code Point" column contains c.
+        CodePoint::Unicode('\t') => escaped.extend_from_slice(utf16!(r"\t")),
+        CodePoint::Unicode('\n') => escaped.extend_from_slice(utf16!(r"\n")),
+        CodePoint::Unicode('\u{000B}') => escaped.extend_from_slice(utf16!(r"\v")),
+        CodePoint::Unicode('\u{000C}') => escaped.extend_from_slice(utf16!(r"\f")),
+        CodePoint::Unicode('\r') => escaped.extend_from_slice(utf16!(r"\r")),
+        // 5. If toEscape contains c, c is matched by either WhiteSpace or LineTerminator, or c has the same numeric value as a leading surrogate or trailing surrogate, then
+        CodePoint::Unicode(c)
+            if OTHER_PUNCTUATORS.contains(c) || string::is_trimmable_whitespace(c) =>
+        {
+            escape_numeric_value(escaped, u32::from(c));
+        }
+        CodePoint::UnpairedSurrogate(surr) => escape_numeric_value(escaped, u32::from(surr)),
+        // 6. Return UTF16EncodeCodePoint(c).
+        CodePoint::Unicode(c) => escaped.extend_from_slice(c.encode_utf16(&mut buf)),
+    }
+}
+
+/// Steps 5.a to 5.f of [`EncodeForRegExpEscape ( c )`][spec].
+///
+/// [spec]: https://tc39.es/ecma262/#sec-encodeforregexpescape
+fn escape_numeric_value(escaped: &mut Vec<u16>, c: u32) {
+    // a. Let cNum be the numeric value of c.
+    // b. If cNum ≤ 0xFF, then
+    if c <= 0xFF {
+        // i. Let hex be Number::toString(𝔽(cNum), 16).
+        // ii. Return the string-concatenation of the code unit 0x005C (REVERSE SOLIDUS), "x", and StringPad(hex, 2, "0", start).
+        escaped.extend(format!("\\x{c:02x}").encode_utf16());
+    } else {
+        // c. Let escaped be the empty String.
+        // d. Let codeUnits be UTF16EncodeCodePoint(c).
+        // e. For each code unit cu of codeUnits, do
+        //     i. Set escaped to the string-concatenation of escaped and UnicodeEscape(cu).
+        // f. Return escaped.
+        // NOTE: All the code points escaped here are in the BMP, so `c` is always a single code unit.
+        escaped.extend(format!("\\u{c:04x}").encode_utf16());
+    }
 }
 
 /// `22.2.5.2.3 AdvanceStringIndex ( S, index, unicode )`
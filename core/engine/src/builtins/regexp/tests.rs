@@ -225,3 +225,81 @@ fn regular_expression_construction_independant_of_global_reg_exp() {
         TestAction::run(regex),
     ]);
 }
+
+#[test]
+fn duplicate_named_groups() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var re = /(?<y>\d{4})-\d{2}|\d{2}-(?<y>\d{4})/d;
+            var first = re.exec("2024-10");
+            var second = re.exec("10-2024");
+        "#}),
+        TestAction::assert_eq("first.groups.y", js_str!("2024")),
+        TestAction::assert_eq("second.groups.y", js_str!("2024")),
+        TestAction::assert_eq("Object.keys(second.groups).length", 1),
+        TestAction::assert_eq("first.indices.groups.y[0]", 0),
+        TestAction::assert_eq("second.indices.groups.y[0]", 3),
+        TestAction::assert_eq("'10-2024'.replace(re, '$<y>')", js_str!("2024")),
+        TestAction::assert("/(?<a>x)|(?<a>y)/.exec('y').groups.a === 'y'"),
+        TestAction::assert_eq(r"/(?:(?<a>x)|(?<a>y))\k<a>/.exec('yy')[0]", js_str!("yy")),
+        TestAction::assert_eq(r"/(?:(?<a>x)|(?<a>y))\k<a>/.exec('xy')", JsValue::null()),
+    ]);
+}
+
+#[test]
+fn escape() {
+    run_test_actions([
+        TestAction::assert_eq("RegExp.escape('foo')", js_str!("\\x66oo")),
+        TestAction::assert_eq("RegExp.escape('1.5')", js_str!("\\x31\\.5")),
+        TestAction::assert_eq("RegExp.escape('(a|b)')", js_str!("\\(a\\|b\\)")),
+        TestAction::assert_eq("RegExp.escape('a-b, c')", js_str!("\\x61\\x2db\\x2c\\x20c")),
+        TestAction::assert_eq("RegExp.escape('\\n\\u2028')", js_str!("\\n\\u2028")),
+        TestAction::assert_eq("RegExp.escape('\\ud800')", js_str!("\\ud800")),
+        TestAction::assert("new RegExp(RegExp.escape('a.b*')).test('a.b*')"),
+        TestAction::assert_native_error(
+            "RegExp.escape(1)",
+            JsNativeErrorKind::Type,
+            "RegExp.escape requires a string argument",
+        ),
+    ]);
+}
+
+#[cfg(feature = "annex-b")]
+#[test]
+fn legacy_static_properties() {
+    run_test_actions([
+        TestAction::assert_eq("RegExp.lastMatch", js_str!("")),
+        TestAction::run("/(\\d+)-(\\d+)/.exec('from 10-20 to')"),
+        TestAction::assert_eq("RegExp.$1", js_str!("10")),
+        TestAction::assert_eq("RegExp.$2", js_str!("20")),
+        TestAction::assert_eq("RegExp.$3", js_str!("")),
+        TestAction::assert_eq("RegExp.input", js_str!("from 10-20 to")),
+        TestAction::assert_eq("RegExp['$_']", js_str!("from 10-20 to")),
+        TestAction::assert_eq("RegExp.lastMatch", js_str!("10-20")),
+        TestAction::assert_eq("RegExp['$&']", js_str!("10-20")),
+        TestAction::assert_eq("RegExp.lastParen", js_str!("20")),
+        TestAction::assert_eq("RegExp.leftContext", js_str!("from ")),
+        TestAction::assert_eq("RegExp.rightContext", js_str!(" to")),
+        TestAction::run("RegExp.input = 'changed'"),
+        TestAction::assert_eq("RegExp.$_", js_str!("changed")),
+        TestAction::assert_native_error(
+            "Object.getOwnPropertyDescriptor(RegExp, '$1').get.call({})",
+            JsNativeErrorKind::Type,
+            "legacy RegExp static properties can only be accessed on %RegExp%",
+        ),
+        TestAction::run(indoc! {r#"
+            class MyRegExp extends RegExp {}
+            new MyRegExp("a").exec("a");
+        "#}),
+        TestAction::assert_native_error(
+            "RegExp.lastMatch",
+            JsNativeErrorKind::Type,
+            "legacy RegExp static properties have been invalidated",
+        ),
+        TestAction::assert_native_error(
+            "new MyRegExp('a').compile('b')",
+            JsNativeErrorKind::Type,
+            "`RegExp.prototype.compile` cannot be called on a RegExp subclass instance",
+        ),
+    ]);
+}
@@ -0,0 +1,417 @@
+//! Boa's implementation of ECMAScript's `ShadowRealm` object.
+//!
+//! A `ShadowRealm` is a distinct global environment, with its own global object containing its
+//! own intrinsics and built-ins, that can only exchange primitives and wrapped callables with the
+//! realm that created it.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-shadowrealm/
+
+use boa_ast::operations::{contains, ContainsSymbol};
+use boa_gc::{Finalize, Trace};
+use boa_parser::{Parser, Source};
+
+use crate::{
+    builtins::{
+        eval::Eval,
+        promise::{Promise, PromiseCapability},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    module::{ModuleRequest, Referrer},
+    native_function::NativeFunction,
+    object::{internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    vm::opcode::ImportCall,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+mod wrapped_function;
+
+pub(crate) use wrapped_function::WrappedFunction;
+
+#[cfg(test)]
+mod tests;
+
+/// The internal representation of a `ShadowRealm` object.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct ShadowRealm {
+    realm: Realm,
+}
+
+impl IntrinsicObject for ShadowRealm {
+    fn init(realm: &Realm) {
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::evaluate, js_string!("evaluate"), 1)
+            .method(Self::import_value, js_string!("importValue"), 2)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for ShadowRealm {
+    const NAME: JsString = StaticJsStrings::SHADOW_REALM;
+}
+
+impl BuiltInConstructor for ShadowRealm {
+    const LENGTH: usize = 0;
+    const P: usize = 3;
+    const SP: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::shadow_realm;
+
+    /// `ShadowRealm ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%ShadowRealm.prototype%", « [[ShadowRealm]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::shadow_realm,
+            context,
+        )?;
+
+        // 3. Let callerContext be the running execution context.
+        // 4. Perform ? InitializeHostDefinedRealm().
+        // 5. Let innerContext be the running execution context.
+        // 6. Remove innerContext from the execution context stack and restore callerContext as the running execution context.
+        // 7. Let realmRec be the Realm of innerContext.
+        // 8. Set O.[[ShadowRealm]] to realmRec.
+        // 9. Perform ? HostInitializeShadowRealm(realmRec, innerContext, O).
+        // NOTE: The global object of the new realm is customized by the host through
+        //       `HostHooks::create_global_object` and `HostHooks::create_global_this`.
+        let realm = context.create_realm()?;
+
+        let shadow_realm = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self { realm },
+        );
+
+        // 10. Return O.
+        Ok(shadow_realm.into())
+    }
+}
+
+impl ShadowRealm {
+    /// Abstract operation `ValidateShadowRealmObject ( O )`, returning the `[[ShadowRealm]]` of `O`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-validateshadowrealmobject
+    fn validate(o: &JsValue, method: &str) -> JsResult<Realm> {
+        // 1. Perform ? RequireInternalSlot(O, [[ShadowRealm]]).
+        o.as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .map(|shadow_realm| shadow_realm.realm.clone())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "ShadowRealm.prototype.{method}: `this` is not a ShadowRealm object"
+                    ))
+                    .into()
+            })
+    }
+
+    /// `ShadowRealm.prototype.evaluate ( sourceText )`
+    ///
+    /// Synchronously evaluates `sourceText` as a script inside the shadow realm.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.evaluate
+    fn evaluate(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::validate(this, "evaluate")?;
+
+        // 3. If sourceText is not a String, throw a TypeError exception.
+        let source_text = args.get_or_undefined(0).as_string().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("ShadowRealm.prototype.evaluate: source text must be a string")
+        })?;
+
+        // 4. Let callerRealm be the current Realm Record.
+        let caller_realm = context.realm().clone();
+
+        // 5. Let evalRealm be O.[[ShadowRealm]].
+        // 6. Return ? PerformShadowRealmEval(sourceText, callerRealm, evalRealm).
+        Self::perform_shadow_realm_eval(source_text, &caller_realm, eval_realm, context)
+    }
+
+    /// Abstract operation `PerformShadowRealmEval ( sourceText, callerRealm, evalRealm )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-performshadowrealmeval
+    fn perform_shadow_realm_eval(
+        source_text: &JsString,
+        caller_realm: &Realm,
+        eval_realm: Realm,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? HostEnsureCanCompileStrings(evalRealm, « », sourceText, false).
+        context.host_hooks().ensure_can_compile_strings(
+            eval_realm.clone(),
+            &[],
+            source_text,
+            false,
+            context,
+        )?;
+
+        // 2. Perform the following substeps in an implementation-defined order, possibly interleaving parsing and error detection:
+        //     a. Let script be ParseText(StringToCodePoints(sourceText), Script).
+        //     b. If script is a List of errors, throw a SyntaxError exception.
+        //     c. If script Contains ScriptBody is false, return undefined.
+        //     d. Let body be the ScriptBody of script.
+        let source_text = source_text.to_vec();
+        let mut parser = Parser::new(Source::from_utf16(&source_text));
+        parser.set_identifier(context.next_parser_identifier());
+        let (body, source) = parser.parse_eval(false, context.interner_mut())?;
+
+        //     e. If body Contains NewTarget is true, throw a SyntaxError exception.
+        if contains(&body, ContainsSymbol::NewTarget) {
+            return Err(JsNativeError::syntax()
+                .with_message("invalid `new.target` expression inside ShadowRealm evaluation")
+                .into());
+        }
+
+        //     f. If body Contains SuperProperty is true, throw a SyntaxError exception.
+        if contains(&body, ContainsSymbol::SuperProperty) {
+            return Err(JsNativeError::syntax()
+                .with_message("invalid `super` reference inside ShadowRealm evaluation")
+                .into());
+        }
+
+        //     g. If body Contains SuperCall is true, throw a SyntaxError exception.
+        if contains(&body, ContainsSymbol::SuperCall) {
+            return Err(JsNativeError::syntax()
+                .with_message("invalid `super` call inside ShadowRealm evaluation")
+                .into());
+        }
+
+        // 3. Let strictEval be IsStrict of script.
+        // 4. Let runningContext be the running execution context.
+        // 5. If runningContext is not already suspended, suspend runningContext.
+        // 6. Let evalContext be GetShadowRealmContext(evalRealm, strictEval).
+        // 7. Let lexEnv be evalContext's LexicalEnvironment.
+        // 8. Let varEnv be evalContext's VariableEnvironment.
+        // 9. Push evalContext onto the execution context stack; evalContext is now the running execution context.
+        let caller_context_realm = context.enter_realm(eval_realm);
+
+        // 10. Let result be Completion(EvalDeclarationInstantiation(body, varEnv, lexEnv, null, strictEval)).
+        // 11. If result.[[Type]] is normal, then
+        //     a. Set result to Completion(Evaluation of body).
+        // 12. If result.[[Type]] is normal and result.[[Value]] is empty, then
+        //     a. Set result to NormalCompletion(undefined).
+        let result = Eval::evaluate_body(body, source, false, None, false, context);
+
+        // 13. Suspend evalContext and remove it from the execution context stack.
+        // 14. Resume the context that is now on the top of the execution context stack as the running execution context.
+        context.enter_realm(caller_context_realm);
+
+        match result {
+            // 16. Return ? GetWrappedValue(callerRealm, result.[[Value]]).
+            Ok(value) => get_wrapped_value(caller_realm, value, context),
+            Err(err) if !err.is_catchable() => Err(err),
+            // 15. If result.[[Type]] is not normal, throw a TypeError exception.
+            Err(err) => Err(JsNativeError::typ()
+                .with_message(match err.as_native() {
+                    Some(native) => format!(
+                        "ShadowRealm evaluation threw an error: {}",
+                        native.message()
+                    ),
+                    None => "ShadowRealm evaluation threw an error".to_owned(),
+                })
+                .into()),
+        }
+    }
+
+    /// `ShadowRealm.prototype.importValue ( specifier, exportName )`
+    ///
+    /// Imports the module `specifier` inside the shadow realm, and resolves with the wrapped value
+    /// of its `exportName` export.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.importvalue
+    fn import_value(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::validate(this, "importValue")?;
+
+        // 3. Let specifierString be ? ToString(specifier).
+        let specifier = args.get_or_undefined(0).to_string(context)?;
+
+        // 4. If exportName is not a String, throw a TypeError exception.
+        let export_name = args
+            .get_or_undefined(1)
+            .as_string()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("ShadowRealm.prototype.importValue: export name must be a string")
+            })?;
+
+        // 5. Let callerRealm be the current Realm Record.
+        // 6. Let evalRealm be O.[[ShadowRealm]].
+        // 7. Return ShadowRealmImportValue(specifierString, exportName, callerRealm, evalRealm).
+        Ok(Self::shadow_realm_import_value(specifier, export_name, eval_realm, context).into())
+    }
+
+    /// Abstract operation `ShadowRealmImportValue ( specifierString, exportNameString, callerRealm, evalRealm )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealmimportvalue
+    fn shadow_realm_import_value(
+        specifier: JsString,
+        export_name: JsString,
+        eval_realm: Realm,
+        context: &mut Context,
+    ) -> JsObject {
+        // 1. Let evalContext be GetShadowRealmContext(evalRealm, true).
+        // 3. Push evalContext onto the execution context stack; evalContext is now the running execution context.
+        let caller_realm = context.enter_realm(eval_realm.clone());
+
+        // 2. Let innerCapability be ! NewPromiseCapability(%Promise%).
+        let inner_capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("operation cannot fail for the %Promise% intrinsic");
+
+        // 4. Let referrer be the Realm component of evalContext.
+        // 5. Perform HostLoadImportedModule(referrer, specifierString, empty, innerCapability).
+        ImportCall::load_imported_module(
+            Referrer::Realm(eval_realm),
+            ModuleRequest::from_specifier(specifier),
+            inner_capability.clone(),
+            context,
+        );
+
+        // 6. Pop evalContext from the execution context stack.
+        context.enter_realm(caller_realm);
+
+        // 7. Let steps be the steps of an ExportGetter function as described below.
+        // 8. Let onFulfilled be CreateBuiltinFunction(steps, 1, "", « [[ExportNameString]] », callerRealm).
+        // 9. Set onFulfilled.[[ExportNameString]] to exportNameString.
+        let on_fulfilled = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, export_name, context| {
+                    // 1. Assert: exports is a module namespace exotic object.
+                    let exports = args
+                        .get_or_undefined(0)
+                        .as_object()
+                        .expect("exports must be a module namespace object");
+
+                    // 2. Let f be the active function object.
+                    // 3. Let string be f.[[ExportNameString]].
+                    // 4. Assert: Type(string) is String.
+                    // 5. Let hasOwn be ? HasOwnProperty(exports, string).
+                    // 6. If hasOwn is false, throw a TypeError exception.
+                    if !exports.has_own_property(export_name.clone(), context)? {
+                        return Err(JsNativeError::typ()
+                            .with_message(format!(
+                                "ShadowRealm.prototype.importValue: module has no export named `{}`",
+                                export_name.to_std_string_escaped()
+                            ))
+                            .into());
+                    }
+
+                    // 7. Let value be ? Get(exports, string).
+                    let value = exports.get(export_name.clone(), context)?;
+
+                    // 8. Let realm be f.[[Realm]].
+                    // 9. Return ? GetWrappedValue(realm, value).
+                    let realm = context.realm().clone();
+                    get_wrapped_value(&realm, value, context)
+                },
+                export_name,
+            ),
+        )
+        .length(1)
+        .build();
+
+        // 10. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("operation cannot fail for the %Promise% intrinsic");
+
+        // 11. Return PerformPromiseThen(innerCapability.[[Promise]], onFulfilled, callerRealm.[[Intrinsics]].[[%ThrowTypeError%]], promiseCapability).
+        Promise::perform_promise_then(
+            inner_capability.promise(),
+            Some(on_fulfilled),
+            Some(context.intrinsics().objects().throw_type_error()),
+            Some(promise_capability.clone()),
+            context,
+        );
+
+        promise_capability.promise().clone()
+    }
+}
+
+/// Abstract operation `GetWrappedValue ( callerRealm, value )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-getwrappedvalue
+pub(crate) fn get_wrapped_value(
+    caller_realm: &Realm,
+    value: JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. If Type(value) is Object, then
+    if let Some(object) = value.as_object() {
+        // a. If IsCallable(value) is false, throw a TypeError exception.
+        if !object.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message("only primitives and callables can cross the ShadowRealm boundary")
+                .into());
+        }
+
+        // b. Return ? WrappedFunctionCreate(callerRealm, value).
+        return WrappedFunction::create(caller_realm, object, context).map(Into::into);
+    }
+
+    // 2. Return value.
+    Ok(value)
+}
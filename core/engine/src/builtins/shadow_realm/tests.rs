@@ -0,0 +1,88 @@
+use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn constructor() {
+    run_test_actions([
+        TestAction::assert_eq("typeof ShadowRealm", js_str!("function")),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(new ShadowRealm())",
+            js_str!("[object ShadowRealm]"),
+        ),
+        TestAction::assert_native_error(
+            "ShadowRealm()",
+            JsNativeErrorKind::Type,
+            "ShadowRealm: cannot call constructor without `new`",
+        ),
+    ]);
+}
+
+#[test]
+fn evaluate() {
+    run_test_actions([
+        TestAction::run("var realm = new ShadowRealm();"),
+        TestAction::assert_eq("realm.evaluate('1 + 1')", 2),
+        TestAction::assert_eq("realm.evaluate('typeof realm')", js_str!("undefined")),
+        TestAction::run("realm.evaluate('globalThis.answer = 42; var other = 1;')"),
+        TestAction::assert_eq("realm.evaluate('answer + other')", 43),
+        TestAction::assert_eq("typeof answer", js_str!("undefined")),
+        TestAction::assert("realm.evaluate('Array') !== Array"),
+        TestAction::assert_native_error(
+            "realm.evaluate('({})')",
+            JsNativeErrorKind::Type,
+            "only primitives and callables can cross the ShadowRealm boundary",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('throw new RangeError()')",
+            JsNativeErrorKind::Type,
+            "ShadowRealm evaluation threw an error",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate(1)",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.evaluate: source text must be a string",
+        ),
+        TestAction::assert(indoc! {r#"
+            try {
+                realm.evaluate('new.target');
+                false
+            } catch (e) {
+                e instanceof SyntaxError
+            }
+        "#}),
+    ]);
+}
+
+#[test]
+fn wrapped_functions() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var realm = new ShadowRealm();
+            var add = realm.evaluate("function add(a, b) { return a + b; }; add");
+            var apply = realm.evaluate("(f, x) => f(x) * 2");
+        "#}),
+        TestAction::assert_eq("typeof add", js_str!("function")),
+        TestAction::assert_eq("add(1, 2)", 3),
+        TestAction::assert_eq("add.name", js_str!("add")),
+        TestAction::assert_eq("add.length", 2),
+        TestAction::assert("Object.getPrototypeOf(add) === Function.prototype"),
+        TestAction::assert("!('prototype' in add)"),
+        TestAction::assert_eq("apply(x => x + 1, 20)", 42),
+        TestAction::assert_native_error(
+            "add({}, 1)",
+            JsNativeErrorKind::Type,
+            "only primitives and callables can cross the ShadowRealm boundary",
+        ),
+        TestAction::assert_native_error(
+            "apply(() => { throw new Error('inner'); }, 1)",
+            JsNativeErrorKind::Type,
+            "an error was thrown by a function across the ShadowRealm boundary",
+        ),
+        TestAction::assert_native_error(
+            "new add(1, 2)",
+            JsNativeErrorKind::Type,
+            "not a constructor",
+        ),
+    ]);
+}
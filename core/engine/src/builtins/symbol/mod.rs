@@ -94,12 +94,15 @@ pub struct Symbol;
 
 impl IntrinsicObject for Symbol {
     fn init(realm: &Realm) {
+        let symbol_async_dispose = JsSymbol::async_dispose();
         let symbol_async_iterator = JsSymbol::async_iterator();
+        let symbol_dispose = JsSymbol::dispose();
         let symbol_has_instance = JsSymbol::has_instance();
         let symbol_is_concat_spreadable = JsSymbol::is_concat_spreadable();
         let symbol_iterator = JsSymbol::iterator();
         let symbol_match = JsSymbol::r#match();
         let symbol_match_all = JsSymbol::match_all();
+        let symbol_metadata = JsSymbol::metadata();
         let symbol_replace = JsSymbol::replace();
         let symbol_search = JsSymbol::search();
         let symbol_species = JsSymbol::species();
@@ -122,11 +125,13 @@ impl IntrinsicObject for Symbol {
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .static_method(Self::for_, js_string!("for"), 1)
             .static_method(Self::key_for, js_string!("keyFor"), 1)
+            .static_property(js_string!("asyncDispose"), symbol_async_dispose, attribute)
             .static_property(
                 js_string!("asyncIterator"),
                 symbol_async_iterator,
                 attribute,
             )
+            .static_property(js_string!("dispose"), symbol_dispose, attribute)
             .static_property(js_string!("hasInstance"), symbol_has_instance, attribute)
             .static_property(
                 js_string!("isConcatSpreadable"),
@@ -136,6 +141,7 @@ impl IntrinsicObject for Symbol {
             .static_property(js_string!("iterator"), symbol_iterator, attribute)
             .static_property(js_string!("match"), symbol_match, attribute)
             .static_property(js_string!("matchAll"), symbol_match_all, attribute)
+            .static_property(js_string!("metadata"), symbol_metadata, attribute)
             .static_property(js_string!("replace"), symbol_replace, attribute)
             .static_property(js_string!("search"), symbol_search, attribute)
             .static_property(js_string!("species"), symbol_species, attribute)
@@ -184,7 +190,7 @@ impl BuiltInObject for Symbol {
 impl BuiltInConstructor for Symbol {
     const LENGTH: usize = 0;
     const P: usize = 5;
-    const SP: usize = 15;
+    const SP: usize = 18;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
         StandardConstructors::symbol;
@@ -346,15 +352,8 @@ impl Symbol {
             JsNativeError::typ().with_message("Symbol.keyFor: sym is not a symbol")
         })?;
 
-        // 2. For each element e of the GlobalSymbolRegistry List (see 20.4.2.2), do
-        //     a. If SameValue(e.[[Symbol]], sym) is true, return e.[[Key]].
-        // 3. Assert: GlobalSymbolRegistry does not currently contain an entry for sym.
-        // 4. Return undefined.
-
-        Ok(GLOBAL_SYMBOL_REGISTRY
-            .get_key(&sym)
-            .map(JsValue::from)
-            .unwrap_or_default())
+        // 2. Return KeyForSymbol(sym).
+        Ok(key_for_symbol(&sym).map(JsValue::from).unwrap_or_default())
     }
 
     /// `Symbol.prototype [ @@toPrimitive ]`
@@ -378,3 +377,16 @@ impl Symbol {
         Ok(sym.into())
     }
 }
+
+/// Abstract operation [`KeyForSymbol ( sym )`][spec].
+///
+/// Returns the key of `sym` in the global symbol registry, or `None` if it is not registered.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-keyforsymbol
+pub(crate) fn key_for_symbol(sym: &JsSymbol) -> Option<JsString> {
+    // 1. For each element e of the GlobalSymbolRegistry List, do
+    //     a. If SameValue(e.[[Symbol]], sym) is true, return e.[[Key]].
+    // 2. Assert: The GlobalSymbolRegistry List does not currently contain an entry for sym.
+    // 3. Return undefined.
+    GLOBAL_SYMBOL_REGISTRY.get_key(sym)
+}
@@ -865,26 +865,40 @@ impl Duration {
         Ok(JsString::from(result).into())
     }
 
-    // TODO: Potentially update docs if localeString is inverted.
-    /// 7.3.24 `Temporal.Duration.prototype.toLocaleString ( )`
+    /// 7.3.24 `Temporal.Duration.prototype.toLocaleString ( [ locales [ , options ] ] )`
     pub(crate) fn to_locale_string(
         this: &JsValue,
-        _: &[JsValue],
-        _: &mut Context,
+        #[cfg_attr(not(feature = "intl"), allow(unused_variables))] args: &[JsValue],
+        #[cfg_attr(not(feature = "intl"), allow(unused_variables))] context: &mut Context,
     ) -> JsResult<JsValue> {
-        // TODO: Update for ECMA-402 compliance
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
         let duration = this
             .as_object()
             .and_then(JsObject::downcast_ref::<Self>)
             .ok_or_else(|| {
                 JsNativeError::typ().with_message("this value must be a Duration object.")
-            })?;
+            })?
+            .inner;
 
-        let result = duration
-            .inner
-            .as_temporal_string(ToStringRoundingOptions::default())?;
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::duration_format::{DurationFormat, DurationRecord};
 
-        Ok(JsString::from(result).into())
+            // 3. Let formatter be ? Construct(%Intl.DurationFormat%, « locales, options »).
+            let formatter =
+                DurationFormat::new(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+            // 4. Return FormatDurationToString(formatter, duration).
+            let result = formatter.format_to_string(&DurationRecord::from(&duration), context)?;
+            Ok(result.into())
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let result = duration.as_temporal_string(ToStringRoundingOptions::default())?;
+            Ok(JsString::from(result).into())
+        }
     }
 
     /// 7.3.25 `Temporal.Duration.prototype.valueOf ( )`
@@ -1,13 +1,15 @@
 //! Boa's implementation of `Temporal.Now` ECMAScript Builtin object.
 
 use crate::{
-    builtins::{BuiltInBuilder, BuiltInObject, IntrinsicObject},
+    builtins::{
+        time_zone::system_time_zone_identifier, BuiltInBuilder, BuiltInObject, IntrinsicObject,
+    },
     context::intrinsics::Intrinsics,
     js_string,
     property::Attribute,
     realm::Realm,
     string::StaticJsStrings,
-    Context, JsArgs, JsNativeError, JsObject, JsResult, JsString, JsSymbol, JsValue,
+    Context, JsArgs, JsObject, JsResult, JsString, JsSymbol, JsValue,
 };
 use temporal_rs::{
     now::{Now as NowInner, NowBuilder},
@@ -63,10 +65,11 @@ impl Now {
     ///  - [ECMAScript specification][spec]
     ///
     /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.now.timezone
-    fn time_zone_id(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    #[allow(clippy::unnecessary_wraps)]
+    fn time_zone_id(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // TODO: this should be optimized once system time zone is in context
         // 1. Return ! SystemTimeZone().
-        Ok(JsString::from(system_time_zone_id()?).into())
+        Ok(JsString::from(system_time_zone_identifier(context)).into())
     }
 
     /// 2.2.2 `Temporal.Now.instant()`
@@ -137,7 +140,7 @@ impl Now {
 
 fn build_now(context: &mut Context) -> JsResult<NowInner> {
     Ok(NowBuilder::default()
-        .with_system_zone(system_time_zone()?)
+        .with_system_zone(system_time_zone(context)?)
         .with_system_nanoseconds(system_nanoseconds(context)?)
         .build())
 }
@@ -148,12 +151,7 @@ fn system_nanoseconds(context: &mut Context) -> JsResult<EpochNanoseconds> {
     )?)
 }
 
-fn system_time_zone_id() -> JsResult<String> {
-    iana_time_zone::get_timezone()
-        .map_err(|e| JsNativeError::range().with_message(e.to_string()).into())
-}
-
 // TODO: Move system time zone fetching to context similiar to `Clock` and `TimeZoneProvider`
-fn system_time_zone() -> JsResult<TimeZone> {
-    system_time_zone_id().and_then(|s| TimeZone::try_from_identifier_str(&s).map_err(Into::into))
+fn system_time_zone(context: &Context) -> JsResult<TimeZone> {
+    TimeZone::try_from_identifier_str(&system_time_zone_identifier(context)).map_err(Into::into)
 }
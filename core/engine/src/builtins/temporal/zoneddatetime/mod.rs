@@ -4,6 +4,7 @@ use crate::{
     builtins::{
         options::{get_option, get_options_object},
         temporal::options::get_digits_option,
+        time_zone::{get_available_named_time_zone_identifier, time_zone_equals},
         BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
     },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
@@ -25,7 +26,7 @@ use temporal_rs::{
         ToStringRoundingOptions, Unit,
     },
     partial::{PartialDate, PartialTime, PartialZonedDateTime},
-    provider::{TimeZoneProvider, TransitionDirection},
+    provider::TransitionDirection,
     Calendar, MonthCode, TimeZone, TinyAsciiStr, UtcOffset, ZonedDateTime as ZonedDateTimeInner,
 };
 
@@ -37,6 +38,9 @@ use super::{
     to_temporal_duration, to_temporal_time,
 };
 
+#[cfg(test)]
+mod tests;
+
 /// The `Temporal.ZonedDateTime` object.
 #[derive(Debug, Clone, Trace, Finalize, JsData)]
 #[boa_gc(unsafe_empty_trace)]
@@ -415,15 +419,7 @@ impl BuiltInConstructor for ZonedDateTime {
         //  7. Else,
         // a. Set timeZone to FormatOffsetTimeZoneIdentifier(timeZoneParse.[[OffsetMinutes]]).
         let timezone = TimeZone::try_from_identifier_str(&timezone_str.to_std_string_escaped())?;
-        if matches!(timezone, TimeZone::IanaIdentifier(_))
-            && !context
-                .tz_provider()
-                .check_identifier(&timezone.identifier()?)
-        {
-            return Err(JsNativeError::range()
-                .with_message("TimeZone string is not a supported by IANA identifier.")
-                .into());
-        }
+        let timezone = to_available_timezone(timezone, context)?;
 
         //  8. If calendar is undefined, set calendar to "iso8601".
         //  9. If calendar is not a String, throw a TypeError exception.
@@ -1023,6 +1019,7 @@ impl ZonedDateTime {
             })?;
 
         let other = to_temporal_zoneddatetime(args.get_or_undefined(0), None, context)?;
+        let other = with_equal_timezone(other, &zdt.inner, context)?;
 
         let options = get_options_object(args.get_or_undefined(1))?;
         let settings = get_difference_settings(&options, context)?;
@@ -1042,6 +1039,7 @@ impl ZonedDateTime {
             })?;
 
         let other = to_temporal_zoneddatetime(args.get_or_undefined(0), None, context)?;
+        let other = with_equal_timezone(other, &zdt.inner, context)?;
 
         let options = get_options_object(args.get_or_undefined(1))?;
         let settings = get_difference_settings(&options, context)?;
@@ -1122,6 +1120,8 @@ impl ZonedDateTime {
 
     /// 6.3.40 `Temporal.ZonedDateTime.prototype.equals ( other )`
     fn equals(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
         let zdt = this
             .as_object()
             .and_then(JsObject::downcast_ref::<Self>)
@@ -1129,8 +1129,25 @@ impl ZonedDateTime {
                 JsNativeError::typ().with_message("the this object must be a ZonedDateTime object.")
             })?;
 
+        // 3. Set other to ? ToTemporalZonedDateTime(other).
         let other = to_temporal_zoneddatetime(args.get_or_undefined(0), None, context)?;
-        Ok((zdt.inner == other).into())
+
+        // 4. If zonedDateTime.[[EpochNanoseconds]] ≠ other.[[EpochNanoseconds]], return false.
+        if zdt.inner.epoch_nanoseconds() != other.epoch_nanoseconds() {
+            return Ok(false.into());
+        }
+
+        // 5. If TimeZoneEquals(zonedDateTime.[[TimeZone]], other.[[TimeZone]]) is false, return false.
+        if !time_zone_equals(
+            &zdt.inner.timezone().identifier()?,
+            &other.timezone().identifier()?,
+            context,
+        ) {
+            return Ok(false.into());
+        }
+
+        // 6. Return CalendarEquals(zonedDateTime.[[Calendar]], other.[[Calendar]]).
+        Ok((zdt.inner.calendar() == other.calendar()).into())
     }
 
     fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
@@ -1483,12 +1500,17 @@ pub(crate) fn to_temporal_zoneddatetime(
             // 7. If offsetBehaviour is option, then
             //        a. Set offsetNanoseconds to ! ParseDateTimeUTCOffset(offsetString).
             // 8. Let epochNanoseconds be ? InterpretISODateTimeOffset(isoDate, time, offsetBehaviour, offsetNanoseconds, timeZone, disambiguation, offsetOption, matchBehaviour).
-            Ok(ZonedDateTimeInner::from_str_with_provider(
+            let zdt = ZonedDateTimeInner::from_str_with_provider(
                 &zdt_source.to_std_string_escaped(),
                 disambiguation,
                 offset_option,
                 context.tz_provider(),
-            )?)
+            )?;
+            let timezone = to_available_timezone(zdt.timezone().clone(), context)?;
+            if timezone == *zdt.timezone() {
+                return Ok(zdt);
+            }
+            Ok(zdt.with_timezone(timezone)?)
         }
         // 5. Else,
         // a. If item is not a String, throw a TypeError exception.
@@ -1527,16 +1549,45 @@ pub(crate) fn to_temporal_timezone_identifier(
     // 8. If timeZoneIdentifierRecord is empty, throw a RangeError exception.
     // 9. Return timeZoneIdentifierRecord.[[Identifier]].
     let timezone = TimeZone::try_from_str(&tz_string.to_std_string_escaped())?;
-    if matches!(timezone, TimeZone::IanaIdentifier(_))
-        && !context
-            .tz_provider()
-            .check_identifier(&timezone.identifier()?)
+    to_available_timezone(timezone, context)
+}
+
+/// Validates that a parsed named time zone is available, replacing its identifier with the
+/// case-normalized identifier of the time zone. Aliases are preserved, not replaced by their
+/// primary identifier.
+///
+/// Equivalent to the `GetAvailableNamedTimeZoneIdentifier(name).[[Identifier]]` steps of
+/// `ToTemporalTimeZoneIdentifier` and the `Temporal.ZonedDateTime` constructor.
+fn to_available_timezone(timezone: TimeZone, context: &Context) -> JsResult<TimeZone> {
+    let TimeZone::IanaIdentifier(name) = &timezone else {
+        return Ok(timezone);
+    };
+
+    let record = get_available_named_time_zone_identifier(name, context).ok_or_else(|| {
+        JsNativeError::range().with_message("TimeZone string is not a supported IANA identifier.")
+    })?;
+
+    Ok(TimeZone::IanaIdentifier(record.identifier))
+}
+
+/// Replaces the time zone of `other` with the time zone of `zdt` if both are equal according to
+/// `TimeZoneEquals`, so that differences between zoned date-times in aliased time zones can be
+/// computed using date units.
+fn with_equal_timezone(
+    other: ZonedDateTimeInner,
+    zdt: &ZonedDateTimeInner,
+    context: &Context,
+) -> JsResult<ZonedDateTimeInner> {
+    if other.timezone() == zdt.timezone()
+        || !time_zone_equals(
+            &other.timezone().identifier()?,
+            &zdt.timezone().identifier()?,
+            context,
+        )
     {
-        return Err(JsNativeError::range()
-            .with_message("TimeZone string is not a supported IANA identifier.")
-            .into());
+        return Ok(other);
     }
-    Ok(timezone)
+    Ok(other.with_timezone(zdt.timezone().clone())?)
 }
 
 fn to_offset_string(value: &JsValue, context: &mut Context) -> JsResult<UtcOffset> {
@@ -0,0 +1,57 @@
+use boa_macros::js_str;
+use indoc::indoc;
+
+use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+
+#[test]
+fn time_zone_identifiers_are_case_normalized() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Temporal.ZonedDateTime(0n, 'asia/calcutta').timeZoneId",
+            js_str!("Asia/Calcutta"),
+        ),
+        TestAction::assert_eq(
+            "Temporal.Instant.fromEpochMilliseconds(0).toZonedDateTimeISO('asia/calcutta').timeZoneId",
+            js_str!("Asia/Calcutta"),
+        ),
+        TestAction::assert_eq(
+            "new Temporal.ZonedDateTime(0n, 'UTC').withTimeZone('etc/utc').timeZoneId",
+            js_str!("Etc/UTC"),
+        ),
+        TestAction::assert_native_error(
+            "new Temporal.ZonedDateTime(0n, 'Asia/Atlantis')",
+            JsNativeErrorKind::Range,
+            "TimeZone string is not a supported IANA identifier.",
+        ),
+    ]);
+}
+
+#[test]
+fn equals_treats_aliases_as_equal() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const calcutta = new Temporal.ZonedDateTime(0n, "Asia/Calcutta");
+                const kolkata = new Temporal.ZonedDateTime(0n, "Asia/Kolkata");
+            "#}),
+        TestAction::assert("calcutta.equals(kolkata)"),
+        TestAction::assert(
+            "new Temporal.ZonedDateTime(0n, 'UTC').equals(calcutta.withTimeZone('Etc/GMT'))",
+        ),
+        TestAction::assert("!calcutta.equals(kolkata.withTimeZone('Asia/Dhaka'))"),
+        TestAction::assert("!calcutta.equals(new Temporal.ZonedDateTime(1n, 'Asia/Kolkata'))"),
+        TestAction::assert("!calcutta.equals(kolkata.withTimeZone('+05:30'))"),
+        TestAction::assert(
+            "new Temporal.ZonedDateTime(0n, '+05:30').equals(new Temporal.ZonedDateTime(0n, '+0530'))",
+        ),
+        TestAction::assert("!calcutta.equals(new Temporal.ZonedDateTime(0n, '+05:31'))"),
+        TestAction::assert_eq(
+            "calcutta.until(kolkata, { largestUnit: 'days' }).toString()",
+            js_str!("PT0S"),
+        ),
+        TestAction::assert_native_error(
+            "calcutta.until(kolkata.withTimeZone('Asia/Dhaka'), { largestUnit: 'days' })",
+            JsNativeErrorKind::Range,
+            "Time zones cannot be different if unit is a date unit.",
+        ),
+    ]);
+}
@@ -0,0 +1,319 @@
+//! Utilities to resolve and compare time zone identifiers, shared by `Temporal` and `Intl`.
+//!
+//! The available named time zones are the ones supported by the time zone provider of the
+//! context, and their case-normalized and primary identifiers come from the IANA time zone
+//! data of the ICU4X provider of the context, so they don't depend on the time zone database of
+//! the host. When `Temporal` is disabled, the time zones of the ICU4X data are available, and
+//! when `Intl` is disabled, every available time zone is its own primary time zone.
+//!
+//! More information:
+//!  - [Time Zone Canonicalization proposal][proposal]
+//!
+//! [proposal]: https://tc39.es/proposal-canonical-tz/
+
+#[cfg(feature = "intl")]
+use icu_time::{
+    zone::iana::{IanaParser, IanaParserExtended},
+    TimeZone,
+};
+#[cfg(feature = "temporal")]
+use temporal_rs::provider::TimeZoneProvider;
+
+#[cfg(feature = "intl")]
+use crate::context::icu::IntlProvider;
+use crate::Context;
+
+/// A [`Time Zone Identifier Record`][spec].
+///
+/// [spec]: https://tc39.es/ecma262/#sec-time-zone-identifier-record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TimeZoneIdentifierRecord {
+    /// The case-normalized identifier of the time zone, which may be an alias.
+    pub(crate) identifier: String,
+
+    /// The primary identifier that `identifier` resolves to.
+    pub(crate) primary_identifier: String,
+}
+
+/// Abstract operation [`GetAvailableNamedTimeZoneIdentifier ( timeZoneIdentifier )`][spec].
+///
+/// Returns `None` if `time_zone_identifier` is not the identifier of an available named time
+/// zone, ignoring ASCII case.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getavailablenamedtimezoneidentifier
+pub(crate) fn get_available_named_time_zone_identifier(
+    time_zone_identifier: &str,
+    context: &Context,
+) -> Option<TimeZoneIdentifierRecord> {
+    #[cfg(feature = "intl")]
+    let record = iana_parser(context.intl_provider()).and_then(|parser| {
+        let parsed = parser.as_borrowed().parse(time_zone_identifier);
+        (parsed.time_zone != TimeZone::UNKNOWN).then(|| TimeZoneIdentifierRecord {
+            identifier: parsed.normalized.to_owned(),
+            primary_identifier: primary_identifier(parsed.canonical).to_owned(),
+        })
+    });
+    #[cfg(not(feature = "intl"))]
+    let record = None;
+
+    // 1. For each element record of AvailableNamedTimeZoneIdentifiers(), do
+    //     a. If record.[[Identifier]] is an ASCII-case-insensitive match for timeZoneIdentifier, return record.
+    // 2. Return empty.
+    #[cfg(feature = "temporal")]
+    let available = context.tz_provider().check_identifier(time_zone_identifier);
+    #[cfg(not(feature = "temporal"))]
+    let available = record.is_some();
+    if !available {
+        return None;
+    }
+
+    // Zones of the provider which are missing from the ICU4X data are their own primary zone.
+    record.or_else(|| {
+        Some(TimeZoneIdentifierRecord {
+            identifier: time_zone_identifier.to_owned(),
+            primary_identifier: time_zone_identifier.to_owned(),
+        })
+    })
+}
+
+/// Loads the parser of IANA time zone identifiers from the data of `provider`.
+#[cfg(feature = "intl")]
+fn iana_parser(provider: &IntlProvider) -> Option<IanaParserExtended<IanaParser>> {
+    IanaParserExtended::try_new_with_buffer_provider(provider.erased_provider()).ok()
+}
+
+/// Returns the primary identifier of the time zone with the canonical IANA identifier
+/// `canonical`, which is always `UTC` for `UTC` and its aliases.
+#[cfg(feature = "intl")]
+fn primary_identifier(canonical: &str) -> &str {
+    match canonical {
+        "Etc/UTC" | "Etc/GMT" | "GMT" => "UTC",
+        canonical => canonical,
+    }
+}
+
+/// Returns the sorted primary identifiers of the time zones that match `filter`, which receives
+/// the BCP-47 identifier of each time zone.
+#[cfg(feature = "intl")]
+fn primary_time_zone_identifiers(
+    provider: &IntlProvider,
+    filter: impl Fn(&str) -> bool,
+) -> Vec<String> {
+    let Some(parser) = iana_parser(provider) else {
+        return Vec::new();
+    };
+    let mut primary = parser
+        .as_borrowed()
+        .iter()
+        .filter(|zone| zone.time_zone != TimeZone::UNKNOWN && filter(zone.time_zone.0.as_str()))
+        .map(|zone| primary_identifier(zone.canonical).to_owned())
+        .collect::<Vec<_>>();
+    primary.sort_unstable();
+    primary.dedup();
+    primary
+}
+
+/// Abstract operation [`AvailableNamedTimeZoneIdentifiers ( )`][spec], returning only the
+/// primary identifiers, sorted and without duplicates.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-availablenamedtimezoneidentifiers
+#[cfg(feature = "intl")]
+pub(crate) fn available_primary_time_zone_identifiers(provider: &IntlProvider) -> Vec<String> {
+    primary_time_zone_identifiers(provider, |_| true)
+}
+
+/// Returns the sorted primary identifiers of the time zones used in `region`, which must be an
+/// alphabetic region code.
+///
+/// The BCP-47 identifiers of the time zones of a region are the lowercase region code followed
+/// by a three letter city code.
+#[cfg(feature = "intl")]
+pub(crate) fn primary_time_zone_identifiers_of_region(
+    region: &str,
+    provider: &IntlProvider,
+) -> Vec<String> {
+    primary_time_zone_identifiers(provider, |bcp47| {
+        bcp47.len() == 5 && bcp47[..2].eq_ignore_ascii_case(region)
+    })
+}
+
+/// Abstract operation [`SystemTimeZoneIdentifier ( )`][spec].
+///
+/// Returns the primary identifier of the time zone of the host, or `UTC` if it cannot be
+/// determined or isn't available. Without `Temporal`, the time zone of the host cannot be
+/// determined, so this returns the offset time zone of its current local time instead.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-systemtimezoneidentifier
+pub(crate) fn system_time_zone_identifier(context: &Context) -> String {
+    #[cfg(feature = "temporal")]
+    {
+        iana_time_zone::get_timezone()
+            .ok()
+            .and_then(|id| get_available_named_time_zone_identifier(&id, context))
+            .map_or_else(|| "UTC".to_owned(), |record| record.primary_identifier)
+    }
+
+    #[cfg(not(feature = "temporal"))]
+    {
+        let now_seconds = context.clock().now().millis_since_epoch() / 1000;
+        let offset_seconds = context
+            .host_hooks()
+            .local_timezone_offset_seconds(i64::try_from(now_seconds).unwrap_or(i64::MAX));
+        i16::try_from(offset_seconds / 60)
+            .map_or_else(|_| "UTC".to_owned(), format_offset_time_zone_identifier)
+    }
+}
+
+/// Parses `offset` as a [`UTCOffset`][spec] string, returning the offset in minutes and
+/// whether the offset has seconds or fractional seconds, or `None` if `offset` is not an
+/// offset string.
+///
+/// The offset time zone identifiers are the offset strings with a minute precision.
+///
+/// [spec]: https://tc39.es/proposal-temporal/#prod-UTCOffset
+pub(crate) fn parse_utc_offset(offset: &str) -> Option<(i16, bool)> {
+    /// Parses a two digit number from the start of `bytes`, returning it if it's at most `max`.
+    fn two_digits(bytes: &[u8], max: u8) -> Option<u8> {
+        match bytes {
+            [tens @ b'0'..=b'9', units @ b'0'..=b'9', ..] => {
+                Some((tens - b'0') * 10 + (units - b'0')).filter(|&value| value <= max)
+            }
+            _ => None,
+        }
+    }
+
+    let (sign, rest) = match offset.as_bytes() {
+        [b'+', rest @ ..] => (1, rest),
+        [b'-', rest @ ..] => (-1, rest),
+        _ => return None,
+    };
+
+    // TemporalSign Hour
+    let hour = two_digits(rest, 23)?;
+    let rest = &rest[2..];
+    if rest.is_empty() {
+        return Some((sign * i16::from(hour) * 60, false));
+    }
+
+    // TemporalSign Hour : MinuteSecond, or TemporalSign Hour MinuteSecond
+    let extended = rest[0] == b':';
+    let rest = if extended { &rest[1..] } else { rest };
+    let minute = two_digits(rest, 59)?;
+    let rest = &rest[2..];
+    let minutes = sign * (i16::from(hour) * 60 + i16::from(minute));
+    if rest.is_empty() {
+        return Some((minutes, false));
+    }
+
+    // ... : MinuteSecond TemporalDecimalFraction?, using the same separator as the minutes.
+    let ((true, [b':', rest @ ..]) | (false, rest)) = (extended, rest) else {
+        return None;
+    };
+    two_digits(rest, 59)?;
+    match &rest[2..] {
+        [] => Some((minutes, true)),
+        [b'.' | b',', fraction @ ..]
+            if (1..=9).contains(&fraction.len()) && fraction.iter().all(u8::is_ascii_digit) =>
+        {
+            Some((minutes, true))
+        }
+        _ => None,
+    }
+}
+
+/// Abstract operation [`FormatOffsetTimeZoneIdentifier ( offsetMinutes [ , style ] )`][spec],
+/// with the separated style.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-formatoffsettimezoneidentifier
+#[cfg_attr(not(feature = "intl"), allow(dead_code))]
+pub(crate) fn format_offset_time_zone_identifier(offset_minutes: i16) -> String {
+    // 1. If offsetMinutes ≥ 0, let sign be the code unit 0x002B (PLUS SIGN); otherwise, let sign be the code unit 0x002D (HYPHEN-MINUS).
+    let sign = if offset_minutes >= 0 { '+' } else { '-' };
+
+    // 2. Let absoluteMinutes be abs(offsetMinutes).
+    // 3. Let hours be floor(absoluteMinutes / 60).
+    // 4. Let minutes be absoluteMinutes modulo 60.
+    let absolute_minutes = offset_minutes.unsigned_abs();
+    let (hours, minutes) = (absolute_minutes / 60, absolute_minutes % 60);
+
+    // 5. Let timeString be FormatTimeString(hours, minutes, 0, 0, style).
+    // 6. Return the string-concatenation of sign and timeString.
+    format!("{sign}{hours:02}:{minutes:02}")
+}
+
+/// Abstract operation [`TimeZoneEquals ( one, two )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-timezoneequals
+#[cfg_attr(not(feature = "temporal"), allow(dead_code))]
+pub(crate) fn time_zone_equals(one: &str, two: &str, context: &Context) -> bool {
+    // 1. If one is two, return true.
+    if one == two {
+        return true;
+    }
+
+    // 2. Let offsetMinutesOne be ! ParseTimeZoneIdentifier(one).[[OffsetMinutes]].
+    // 3. Let offsetMinutesTwo be ! ParseTimeZoneIdentifier(two).[[OffsetMinutes]].
+    let offset_one = parse_utc_offset(one).map(|(minutes, _)| minutes);
+    let offset_two = parse_utc_offset(two).map(|(minutes, _)| minutes);
+
+    match (offset_one, offset_two) {
+        // 4. If offsetMinutesOne is empty and offsetMinutesTwo is empty, then
+        (None, None) => {
+            // a. Let recordOne be GetAvailableNamedTimeZoneIdentifier(one).
+            // b. Let recordTwo be GetAvailableNamedTimeZoneIdentifier(two).
+            // c. If recordOne is not empty and recordTwo is not empty and recordOne.[[PrimaryIdentifier]] is recordTwo.[[PrimaryIdentifier]], return true.
+            match (
+                get_available_named_time_zone_identifier(one, context),
+                get_available_named_time_zone_identifier(two, context),
+            ) {
+                (Some(one), Some(two)) => one.primary_identifier == two.primary_identifier,
+                _ => false,
+            }
+        }
+        // 5. Else,
+        //     a. If offsetMinutesOne is not empty and offsetMinutesTwo is not empty and offsetMinutesOne = offsetMinutesTwo, return true.
+        (Some(one), Some(two)) => one == two,
+        // 6. Return false.
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_offset_time_zone_identifier, parse_utc_offset};
+
+    #[test]
+    fn utc_offsets() {
+        assert_eq!(parse_utc_offset("+05"), Some((300, false)));
+        assert_eq!(parse_utc_offset("+05:30"), Some((330, false)));
+        assert_eq!(parse_utc_offset("-0530"), Some((-330, false)));
+        assert_eq!(parse_utc_offset("-00:00"), Some((0, false)));
+        assert_eq!(parse_utc_offset("+05:30:15"), Some((330, true)));
+        assert_eq!(parse_utc_offset("+053015.5"), Some((330, true)));
+        assert_eq!(parse_utc_offset("+05:30:00,123456789"), Some((330, true)));
+
+        for invalid in [
+            "",
+            "05:30",
+            "+5",
+            "+24",
+            "+05:60",
+            "+05:3",
+            "+0530:15",
+            "+05:3015",
+            "+05:30:15.",
+            "+05:30:15.1234567890",
+            "UTC",
+        ] {
+            assert_eq!(parse_utc_offset(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn format_offsets() {
+        assert_eq!(format_offset_time_zone_identifier(330), "+05:30");
+        assert_eq!(format_offset_time_zone_identifier(-60), "-01:00");
+        assert_eq!(format_offset_time_zone_identifier(0), "+00:00");
+        assert_eq!(format_offset_time_zone_identifier(-1439), "-23:59");
+    }
+}
@@ -0,0 +1,1054 @@
+//! Boa's implementation of the base64 and hex methods of `Uint8Array`.
+//!
+//! More information:
+//!  - [Uint8Array to/from base64 proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8Array/fromBase64
+
+use std::sync::atomic::Ordering;
+
+use crate::{
+    builtins::{
+        array_buffer::utils::{SliceRef, SliceRefMut},
+        options::get_options_object,
+    },
+    js_string, Context, JsArgs, JsNativeError, JsObject, JsResult, JsString, JsValue,
+};
+
+use super::{BuiltinTypedArray, TypedArray, TypedArrayKind, Uint8Array};
+
+/// The alphabet used to encode and decode base64 strings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Base64Alphabet {
+    /// The standard base64 alphabet, using `+` and `/`.
+    #[default]
+    Base64,
+    /// The URL and filename safe base64 alphabet, using `-` and `_`.
+    Base64Url,
+}
+
+impl Base64Alphabet {
+    /// Gets the `alphabet` option of an options object.
+    fn from_options(options: &JsObject, context: &mut Context) -> JsResult<Self> {
+        // 1. Let alphabet be ? Get(opts, "alphabet").
+        let alphabet = options.get(js_string!("alphabet"), context)?;
+
+        // 2. If alphabet is undefined, set alphabet to "base64".
+        if alphabet.is_undefined() {
+            return Ok(Self::Base64);
+        }
+
+        // 3. If alphabet is neither "base64" nor "base64url", throw a TypeError exception.
+        match alphabet.as_string() {
+            Some(alphabet) if *alphabet == "base64" => Ok(Self::Base64),
+            Some(alphabet) if *alphabet == "base64url" => Ok(Self::Base64Url),
+            _ => Err(JsNativeError::typ()
+                .with_message("alphabet must be either \"base64\" or \"base64url\"")
+                .into()),
+        }
+    }
+
+    const fn table(self) -> &'static [u8; 64] {
+        match self {
+            Self::Base64 => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+            Self::Base64Url => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+        }
+    }
+}
+
+/// How to handle the last chunk of a base64 string when decoding it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LastChunkHandling {
+    /// Decodes a last chunk of 2 or 3 characters, with or without padding, ignoring any
+    /// non-zero padding bits.
+    #[default]
+    Loose,
+    /// Requires the last chunk to be padded and its padding bits to be zero.
+    Strict,
+    /// Stops decoding before a last chunk of less than 4 characters.
+    StopBeforePartial,
+}
+
+impl LastChunkHandling {
+    /// Gets the `lastChunkHandling` option of an options object.
+    fn from_options(options: &JsObject, context: &mut Context) -> JsResult<Self> {
+        // 1. Let lastChunkHandling be ? Get(opts, "lastChunkHandling").
+        let last_chunk_handling = options.get(js_string!("lastChunkHandling"), context)?;
+
+        // 2. If lastChunkHandling is undefined, set lastChunkHandling to "loose".
+        if last_chunk_handling.is_undefined() {
+            return Ok(Self::Loose);
+        }
+
+        // 3. If lastChunkHandling is not one of "loose", "strict", or "stop-before-partial", throw a TypeError exception.
+        match last_chunk_handling.as_string() {
+            Some(handling) if *handling == "loose" => Ok(Self::Loose),
+            Some(handling) if *handling == "strict" => Ok(Self::Strict),
+            Some(handling) if *handling == "stop-before-partial" => Ok(Self::StopBeforePartial),
+            _ => Err(JsNativeError::typ()
+                .with_message(
+                    "lastChunkHandling must be one of \"loose\", \"strict\" or \"stop-before-partial\"",
+                )
+                .into()),
+        }
+    }
+}
+
+/// The result of decoding a base64 or hex string.
+struct DecodeResult {
+    /// The number of code units read from the string.
+    read: usize,
+    /// The decoded bytes.
+    bytes: Vec<u8>,
+    /// The error found while decoding, if any.
+    error: Option<JsNativeError>,
+}
+
+impl DecodeResult {
+    const fn ok(read: usize, bytes: Vec<u8>) -> Self {
+        Self {
+            read,
+            bytes,
+            error: None,
+        }
+    }
+
+    fn error(read: usize, bytes: Vec<u8>, message: &'static str) -> Self {
+        Self {
+            read,
+            bytes,
+            error: Some(JsNativeError::syntax().with_message(message)),
+        }
+    }
+}
+
+/// Abstract operation [`SkipAsciiWhitespace ( string, index )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-skipasciiwhitespace
+fn skip_ascii_whitespace(string: &[u16], mut index: usize) -> usize {
+    while let Some(0x0009 | 0x000A | 0x000C | 0x000D | 0x0020) = string.get(index) {
+        index += 1;
+    }
+    index
+}
+
+/// Abstract operation [`DecodeBase64Chunk ( chunk [ , throwOnExtraBits ] )`][spec].
+///
+/// `chunk` contains the 6-bit values of 2 to 4 base64 characters. Returns `false` without
+/// decoding anything if `throw_on_extra_bits` is `true` and the padding bits are not zero.
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-decodebase64chunk
+fn decode_base64_chunk(chunk: &[u8], throw_on_extra_bits: bool, bytes: &mut Vec<u8>) -> bool {
+    // 1. Let chunkLength be the length of chunk.
+    // 2. If chunkLength = 2, then
+    //     a. Set chunk to the string-concatenation of chunk and "AA".
+    // 3. Else if chunkLength = 3, then
+    //     a. Set chunk to the string-concatenation of chunk and "A".
+    // 4. Else,
+    //     a. Assert: chunkLength is 4.
+    // 5. Let byteSequence be the unique sequence of 3 bytes resulting from decoding chunk as base64.
+    let triple = chunk
+        .iter()
+        .chain(std::iter::repeat(&0))
+        .take(4)
+        .fold(0u32, |acc, &value| acc << 6 | u32::from(value));
+    let byte_sequence = [(triple >> 16) as u8, (triple >> 8) as u8, triple as u8];
+
+    // 6. If chunkLength = 2, then
+    //     a. Assert: byteSequence[0] is the only significant byte.
+    //     b. If throwOnExtraBits is true and byteSequence[1] ≠ 0, then
+    //         i. Throw a SyntaxError exception.
+    //     c. Return « byteSequence[0] ».
+    // 7. Else if chunkLength = 3, then
+    //     a. Assert: byteSequence[0] and byteSequence[1] are the only significant bytes.
+    //     b. If throwOnExtraBits is true and byteSequence[2] ≠ 0, then
+    //         i. Throw a SyntaxError exception.
+    //     c. Return « byteSequence[0], byteSequence[1] ».
+    // 8. Else,
+    //     a. Return byteSequence.
+    let significant = chunk.len() - 1;
+    if throw_on_extra_bits
+        && byte_sequence
+            .get(significant)
+            .is_some_and(|&byte| byte != 0)
+    {
+        return false;
+    }
+    bytes.extend_from_slice(&byte_sequence[..significant]);
+    true
+}
+
+/// Abstract operation [`FromBase64 ( string, alphabet, lastChunkHandling [ , maxLength ] )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-frombase64
+fn from_base64(
+    string: &[u16],
+    alphabet: Base64Alphabet,
+    last_chunk_handling: LastChunkHandling,
+    max_length: usize,
+) -> DecodeResult {
+    const INVALID: &str = "invalid base64 string";
+
+    // 1. If maxLength is not present, then
+    //     a. Let maxLength be 2**53 - 1.
+    //     b. NOTE: Because the input is a string, the length of strings is limited to 2**53 - 1 characters, and the output requires no more bytes than the input has characters, this limit can never be reached. However, it is editorially convenient to use a finite value here.
+    // 2. NOTE: The order of validation and decoding in the algorithm below is not observable. Implementations are encouraged to perform them in whatever order is most efficient, possibly interleaving validation with decoding, as long as the behaviour is observably equivalent.
+
+    // 3. If maxLength = 0, then
+    if max_length == 0 {
+        // a. Return the Record { [[Read]]: 0, [[Bytes]]: « », [[Error]]: none }.
+        return DecodeResult::ok(0, Vec::new());
+    }
+
+    // 4. Let read be 0.
+    let mut read = 0;
+    // 5. Let bytes be « ».
+    let mut bytes = Vec::new();
+    // 6. Let chunk be the empty String.
+    // 7. Let chunkLength be 0.
+    let mut chunk = [0u8; 4];
+    let mut chunk_length = 0;
+    // 8. Let index be 0.
+    let mut index = 0;
+    // 9. Let length be the length of string.
+    let length = string.len();
+
+    // 10. Repeat,
+    loop {
+        // a. Set index to SkipAsciiWhitespace(string, index).
+        index = skip_ascii_whitespace(string, index);
+
+        // b. If index = length, then
+        if index == length {
+            // i. If chunkLength > 0, then
+            if chunk_length > 0 {
+                match last_chunk_handling {
+                    // 1. If lastChunkHandling is "stop-before-partial", then
+                    //     a. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                    LastChunkHandling::StopBeforePartial => return DecodeResult::ok(read, bytes),
+                    // 2. Else if lastChunkHandling is "loose", then
+                    LastChunkHandling::Loose => {
+                        // a. If chunkLength = 1, then
+                        if chunk_length == 1 {
+                            // i. Let error be a new SyntaxError exception.
+                            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                            return DecodeResult::error(read, bytes, INVALID);
+                        }
+                        // b. Set bytes to the list-concatenation of bytes and ! DecodeBase64Chunk(chunk, false).
+                        decode_base64_chunk(&chunk[..chunk_length], false, &mut bytes);
+                    }
+                    // 3. Else,
+                    //     a. Assert: lastChunkHandling is "strict".
+                    //     b. Let error be a new SyntaxError exception.
+                    //     c. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                    LastChunkHandling::Strict => {
+                        return DecodeResult::error(read, bytes, "missing base64 padding");
+                    }
+                }
+            }
+
+            // ii. Return the Record { [[Read]]: length, [[Bytes]]: bytes, [[Error]]: none }.
+            return DecodeResult::ok(length, bytes);
+        }
+
+        // c. Let char be the substring of string from index to index + 1.
+        let mut char = string[index];
+
+        // d. Set index to index + 1.
+        index += 1;
+
+        // e. If char is "=", then
+        if char == u16::from(b'=') {
+            // i. If chunkLength < 2, then
+            if chunk_length < 2 {
+                // 1. Let error be a new SyntaxError exception.
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                return DecodeResult::error(read, bytes, INVALID);
+            }
+
+            // ii. Set index to SkipAsciiWhitespace(string, index).
+            index = skip_ascii_whitespace(string, index);
+
+            // iii. If chunkLength = 2, then
+            if chunk_length == 2 {
+                // 1. If index = length, then
+                if index == length {
+                    // a. If lastChunkHandling is "stop-before-partial", then
+                    if last_chunk_handling == LastChunkHandling::StopBeforePartial {
+                        // i. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                        return DecodeResult::ok(read, bytes);
+                    }
+
+                    // b. Let error be a new SyntaxError exception.
+                    // c. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                    return DecodeResult::error(read, bytes, "missing base64 padding");
+                }
+
+                // 2. Set char to the substring of string from index to index + 1.
+                // 3. If char is "=", then
+                if string[index] == u16::from(b'=') {
+                    // a. Set index to SkipAsciiWhitespace(string, index + 1).
+                    index = skip_ascii_whitespace(string, index + 1);
+                }
+            }
+
+            // iv. If index < length, then
+            if index < length {
+                // 1. Let error be a new SyntaxError exception.
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                return DecodeResult::error(read, bytes, "unexpected data after base64 padding");
+            }
+
+            // v. If lastChunkHandling is "strict", let throwOnExtraBits be true.
+            // vi. Else, let throwOnExtraBits be false.
+            let throw_on_extra_bits = last_chunk_handling == LastChunkHandling::Strict;
+
+            // vii. Let decodeResult be Completion(DecodeBase64Chunk(chunk, throwOnExtraBits)).
+            // viii. If decodeResult is an abrupt completion, then
+            if !decode_base64_chunk(&chunk[..chunk_length], throw_on_extra_bits, &mut bytes) {
+                // 1. Let error be decodeResult.[[Value]].
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                return DecodeResult::error(read, bytes, "non-zero padding bits in base64 string");
+            }
+
+            // ix. Set bytes to the list-concatenation of bytes and ! decodeResult.
+            // x. Return the Record { [[Read]]: length, [[Bytes]]: bytes, [[Error]]: none }.
+            return DecodeResult::ok(length, bytes);
+        }
+
+        // f. If alphabet is "base64url", then
+        if alphabet == Base64Alphabet::Base64Url {
+            match u8::try_from(char) {
+                // i. If char is either "+" or "/", then
+                Ok(b'+' | b'/') => {
+                    // 1. Let error be a new SyntaxError exception.
+                    // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                    return DecodeResult::error(read, bytes, INVALID);
+                }
+                // ii. Else if char is "-", then
+                //     1. Set char to "+".
+                Ok(b'-') => char = u16::from(b'+'),
+                // iii. Else if char is "_", then
+                //     1. Set char to "/".
+                Ok(b'_') => char = u16::from(b'/'),
+                _ => {}
+            }
+        }
+
+        // g. If the sole code unit of char is not an element of the standard base64 alphabet, then
+        let Some(value) = Base64Alphabet::Base64
+            .table()
+            .iter()
+            .position(|&c| u16::from(c) == char)
+        else {
+            // i. Let error be a new SyntaxError exception.
+            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+            return DecodeResult::error(read, bytes, INVALID);
+        };
+
+        // h. Let remaining be maxLength - the length of bytes.
+        let remaining = max_length - bytes.len();
+
+        // i. If remaining = 1 and chunkLength = 2, or if remaining = 2 and chunkLength = 3, then
+        if (remaining == 1 && chunk_length == 2) || (remaining == 2 && chunk_length == 3) {
+            // i. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+            return DecodeResult::ok(read, bytes);
+        }
+
+        // j. Set chunk to the string-concatenation of chunk and char.
+        // k. Set chunkLength to the length of chunk.
+        chunk[chunk_length] = value as u8;
+        chunk_length += 1;
+
+        // l. If chunkLength = 4, then
+        if chunk_length == 4 {
+            // i. Set bytes to the list-concatenation of bytes and ! DecodeBase64Chunk(chunk).
+            decode_base64_chunk(&chunk, false, &mut bytes);
+
+            // ii. Set chunk to the empty String.
+            // iii. Set chunkLength to 0.
+            chunk_length = 0;
+
+            // iv. Set read to index.
+            read = index;
+
+            // v. If the length of bytes = maxLength, then
+            if bytes.len() == max_length {
+                // 1. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                return DecodeResult::ok(read, bytes);
+            }
+        }
+    }
+}
+
+/// Abstract operation [`FromHex ( string [ , maxLength ] )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-fromhex
+fn from_hex(string: &[u16], max_length: usize) -> DecodeResult {
+    fn hex_value(unit: u16) -> Option<u8> {
+        char::from_u32(u32::from(unit))?
+            .to_digit(16)
+            .map(|digit| digit as u8)
+    }
+
+    // 1. If maxLength is not present, let maxLength be 2**53 - 1.
+    // 2. Let length be the length of string.
+    let length = string.len();
+
+    // 3. Let bytes be « ».
+    let mut bytes = Vec::new();
+
+    // 4. Let read be 0.
+    let mut read = 0;
+
+    // 5. If length modulo 2 is not 0, then
+    if length % 2 != 0 {
+        // a. Let error be a new SyntaxError exception.
+        // b. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+        return DecodeResult::error(read, bytes, "hex string must have an even length");
+    }
+
+    // 6. Repeat, while read < length and the length of bytes < maxLength,
+    while read < length && bytes.len() < max_length {
+        // a. Let hexits be the substring of string from read to read + 2.
+        // b. If hexits contains any code units which are not in "0123456789abcdefABCDEF", then
+        let (Some(high), Some(low)) = (hex_value(string[read]), hex_value(string[read + 1])) else {
+            // i. Let error be a new SyntaxError exception.
+            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+            return DecodeResult::error(read, bytes, "invalid hex string");
+        };
+
+        // c. Set read to read + 2.
+        read += 2;
+
+        // d. Let byte be the integer value represented by hexits in base-16 notation, using the letters A-F and a-f for digits with values 10 through 15.
+        // e. Append byte to bytes.
+        bytes.push(high << 4 | low);
+    }
+
+    // 7. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+    DecodeResult::ok(read, bytes)
+}
+
+impl Uint8Array {
+    /// `Uint8Array.fromBase64 ( string [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Uint8Array to/from base64 proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.frombase64
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8Array/fromBase64
+    pub(crate) fn from_base64(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If string is not a String, throw a TypeError exception.
+        let string = string_argument(args)?;
+
+        // 2. Let opts be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 3. Let alphabet be ? Get(opts, "alphabet").
+        // 4. If alphabet is undefined, set alphabet to "base64".
+        // 5. If alphabet is neither "base64" nor "base64url", throw a TypeError exception.
+        let alphabet = Base64Alphabet::from_options(&options, context)?;
+
+        // 6. Let lastChunkHandling be ? Get(opts, "lastChunkHandling").
+        // 7. If lastChunkHandling is undefined, set lastChunkHandling to "loose".
+        // 8. If lastChunkHandling is not one of "loose", "strict", or "stop-before-partial", throw a TypeError exception.
+        let last_chunk_handling = LastChunkHandling::from_options(&options, context)?;
+
+        // 9. Let result be FromBase64(string, alphabet, lastChunkHandling).
+        // 10. If result.[[Error]] is not none, then
+        //     a. Throw result.[[Error]].
+        // 11. Let resultLength be the length of result.[[Bytes]].
+        // 12. Let ta be ? AllocateTypedArray("Uint8Array", %Uint8Array%, %Uint8Array.prototype%, resultLength).
+        // 13. Set the value at each index of ta.[[ViewedArrayBuffer]].[[ArrayBufferData]] to the value at the corresponding index of result.[[Bytes]].
+        // 14. Return ta.
+        Self::create_from_base64(string, alphabet, last_chunk_handling, context).map(Into::into)
+    }
+
+    /// `Uint8Array.fromHex ( string )`
+    ///
+    /// More information:
+    ///  - [Uint8Array to/from base64 proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.fromhex
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8Array/fromHex
+    pub(crate) fn from_hex(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If string is not a String, throw a TypeError exception.
+        let string = string_argument(args)?;
+
+        // 2. Let result be FromHex(string).
+        // 3. If result.[[Error]] is not none, then
+        //     a. Throw result.[[Error]].
+        // 4. Let resultLength be the length of result.[[Bytes]].
+        // 5. Let ta be ? AllocateTypedArray("Uint8Array", %Uint8Array%, %Uint8Array.prototype%, resultLength).
+        // 6. Set the value at each index of ta.[[ViewedArrayBuffer]].[[ArrayBufferData]] to the value at the corresponding index of result.[[Bytes]].
+        // 7. Return ta.
+        Self::create_from_hex(string, context).map(Into::into)
+    }
+
+    /// `Uint8Array.prototype.toBase64 ( [ options ] )`
+    ///
+    /// More information:
+    ///  - [Uint8Array to/from base64 proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.tobase64
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8Array/toBase64
+    pub(crate) fn to_base64(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateUint8Array(O).
+        let o = Self::validate(this)?;
+
+        // 3. Let opts be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0))?;
+
+        // 4. Let alphabet be ? Get(opts, "alphabet").
+        // 5. If alphabet is undefined, set alphabet to "base64".
+        // 6. If alphabet is neither "base64" nor "base64url", throw a TypeError exception.
+        let alphabet = Base64Alphabet::from_options(&options, context)?;
+
+        // 7. Let omitPadding be ToBoolean(? Get(opts, "omitPadding")).
+        let omit_padding = options
+            .get(js_string!("omitPadding"), context)?
+            .to_boolean();
+
+        // 8. Let toEncode be ? GetUint8ArrayBytes(O).
+        // 9. If alphabet is "base64", then
+        //     a. Let outAscii be the sequence of code points which results from encoding toEncode according to the base64 encoding specified in section 4 of RFC 4648. Padding is included if and only if omitPadding is false.
+        // 10. Else,
+        //     a. Assert: alphabet is "base64url".
+        //     b. Let outAscii be the sequence of code points which results from encoding toEncode according to the base64url encoding specified in section 5 of RFC 4648. Padding is included if and only if omitPadding is false.
+        // 11. Return CodePointsToString(outAscii).
+        let out = Self::encode_to_base64(&o.borrow().data, alphabet, omit_padding)?;
+        Ok(out.into())
+    }
+
+    /// `Uint8Array.prototype.toHex ( )`
+    ///
+    /// More information:
+    ///  - [Uint8Array to/from base64 proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.tohex
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8Array/toHex
+    pub(crate) fn to_hex(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateUint8Array(O).
+        let o = Self::validate(this)?;
+
+        // 3. Let toEncode be ? GetUint8ArrayBytes(O).
+        // 4. Let out be the empty String.
+        // 5. For each byte byte of toEncode, do
+        //     a. Let hex be Number::toString(𝔽(byte), 16).
+        //     b. Set hex to StringPad(hex, 2, "0", start).
+        //     c. Set out to the string-concatenation of out and hex.
+        // 6. Return out.
+        let out = Self::encode_to_hex(&o.borrow().data)?;
+        Ok(out.into())
+    }
+
+    /// `Uint8Array.prototype.setFromBase64 ( string [ , options ] )`
+    ///
+    /// More information:
+    ///  - [Uint8Array to/from base64 proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.setfrombase64
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8Array/setFromBase64
+    pub(crate) fn set_from_base64(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let into be the this value.
+        // 2. Perform ? ValidateUint8Array(into).
+        let into = Self::validate(this)?;
+
+        // 3. If string is not a String, throw a TypeError exception.
+        let string = string_argument(args)?;
+
+        // 4. Let opts be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 5. Let alphabet be ? Get(opts, "alphabet").
+        // 6. If alphabet is undefined, set alphabet to "base64".
+        // 7. If alphabet is neither "base64" nor "base64url", throw a TypeError exception.
+        let alphabet = Base64Alphabet::from_options(&options, context)?;
+
+        // 8. Let lastChunkHandling be ? Get(opts, "lastChunkHandling").
+        // 9. If lastChunkHandling is undefined, set lastChunkHandling to "loose".
+        // 10. If lastChunkHandling is not one of "loose", "strict", or "stop-before-partial", throw a TypeError exception.
+        let last_chunk_handling = LastChunkHandling::from_options(&options, context)?;
+
+        // 11. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(into, seq-cst).
+        // 12. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        // 13. Let byteLength be TypedArrayLength(taRecord).
+        // 14. Let result be FromBase64(string, alphabet, lastChunkHandling, byteLength).
+        // 15. Let bytes be result.[[Bytes]].
+        // 16. Let written be the length of bytes.
+        // 17. NOTE: FromBase64 does not invoke any user code, so the ArrayBuffer backing into cannot have been detached or shrunk.
+        // 18. Assert: written ≤ byteLength.
+        // 19. Perform SetUint8ArrayBytes(into, bytes).
+        // 20. If result.[[Error]] is not none, then
+        //     a. Throw result.[[Error]].
+        let (read, written) =
+            Self::decode_base64_into(&into.borrow().data, string, alphabet, last_chunk_handling)?;
+
+        // 21. Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+        // 22. Perform ! CreateDataPropertyOrThrow(resultObject, "read", 𝔽(result.[[Read]])).
+        // 23. Perform ! CreateDataPropertyOrThrow(resultObject, "written", 𝔽(written)).
+        // 24. Return resultObject.
+        Ok(read_written_object(read, written, context).into())
+    }
+
+    /// `Uint8Array.prototype.setFromHex ( string )`
+    ///
+    /// More information:
+    ///  - [Uint8Array to/from base64 proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.setfromhex
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8Array/setFromHex
+    pub(crate) fn set_from_hex(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let into be the this value.
+        // 2. Perform ? ValidateUint8Array(into).
+        let into = Self::validate(this)?;
+
+        // 3. If string is not a String, throw a TypeError exception.
+        let string = string_argument(args)?;
+
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(into, seq-cst).
+        // 5. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        // 6. Let byteLength be TypedArrayLength(taRecord).
+        // 7. Let result be FromHex(string, byteLength).
+        // 8. Let bytes be result.[[Bytes]].
+        // 9. Let written be the length of bytes.
+        // 10. NOTE: FromHex does not invoke any user code, so the ArrayBuffer backing into cannot have been detached or shrunk.
+        // 11. Assert: written ≤ byteLength.
+        // 12. Perform SetUint8ArrayBytes(into, bytes).
+        // 13. If result.[[Error]] is not none, then
+        //     a. Throw result.[[Error]].
+        let (read, written) = Self::decode_hex_into(&into.borrow().data, string)?;
+
+        // 14. Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+        // 15. Perform ! CreateDataPropertyOrThrow(resultObject, "read", 𝔽(result.[[Read]])).
+        // 16. Perform ! CreateDataPropertyOrThrow(resultObject, "written", 𝔽(written)).
+        // 17. Return resultObject.
+        Ok(read_written_object(read, written, context).into())
+    }
+
+    /// Creates a new `Uint8Array` from the bytes encoded in the base64 string `string`.
+    pub(crate) fn create_from_base64(
+        string: &JsString,
+        alphabet: Base64Alphabet,
+        last_chunk_handling: LastChunkHandling,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        let result = from_base64(&string.to_vec(), alphabet, last_chunk_handling, usize::MAX);
+        if let Some(error) = result.error {
+            return Err(error.into());
+        }
+        Self::create_from_bytes(&result.bytes, context)
+    }
+
+    /// Creates a new `Uint8Array` from the bytes encoded in the hex string `string`.
+    pub(crate) fn create_from_hex(string: &JsString, context: &mut Context) -> JsResult<JsObject> {
+        let result = from_hex(&string.to_vec(), usize::MAX);
+        if let Some(error) = result.error {
+            return Err(error.into());
+        }
+        Self::create_from_bytes(&result.bytes, context)
+    }
+
+    /// Encodes the bytes of the `Uint8Array` `ta` as a base64 string.
+    pub(crate) fn encode_to_base64(
+        ta: &TypedArray,
+        alphabet: Base64Alphabet,
+        omit_padding: bool,
+    ) -> JsResult<JsString> {
+        let table = alphabet.table();
+        let bytes = Self::get_bytes(ta)?;
+
+        let mut out = Vec::with_capacity(bytes.len().div_ceil(3) * 4);
+        for chunk in bytes.chunks(3) {
+            let triple = chunk
+                .iter()
+                .chain(std::iter::repeat(&0))
+                .take(3)
+                .fold(0u32, |acc, &byte| acc << 8 | u32::from(byte));
+            for i in 0..=chunk.len() {
+                let value = (triple >> (18 - 6 * i)) & 0x3F;
+                out.push(u16::from(table[value as usize]));
+            }
+            if !omit_padding {
+                out.extend(std::iter::repeat_n(u16::from(b'='), 3 - chunk.len()));
+            }
+        }
+
+        Ok(JsString::from(&out[..]))
+    }
+
+    /// Encodes the bytes of the `Uint8Array` `ta` as a hex string.
+    pub(crate) fn encode_to_hex(ta: &TypedArray) -> JsResult<JsString> {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+        let bytes = Self::get_bytes(ta)?;
+        let out = bytes
+            .iter()
+            .flat_map(|&byte| {
+                [
+                    DIGITS[usize::from(byte >> 4)],
+                    DIGITS[usize::from(byte & 0xF)],
+                ]
+            })
+            .map(u16::from)
+            .collect::<Vec<_>>();
+
+        Ok(JsString::from(&out[..]))
+    }
+
+    /// Decodes the base64 string `string` into the `Uint8Array` `ta`, returning the number of
+    /// code units read from `string` and the number of bytes written into `ta`.
+    pub(crate) fn decode_base64_into(
+        ta: &TypedArray,
+        string: &JsString,
+        alphabet: Base64Alphabet,
+        last_chunk_handling: LastChunkHandling,
+    ) -> JsResult<(usize, usize)> {
+        let byte_length = Self::length(ta)?;
+        let result = from_base64(&string.to_vec(), alphabet, last_chunk_handling, byte_length);
+        Self::set_bytes(ta, &result.bytes);
+        if let Some(error) = result.error {
+            return Err(error.into());
+        }
+        Ok((result.read, result.bytes.len()))
+    }
+
+    /// Decodes the hex string `string` into the `Uint8Array` `ta`, returning the number of
+    /// code units read from `string` and the number of bytes written into `ta`.
+    pub(crate) fn decode_hex_into(ta: &TypedArray, string: &JsString) -> JsResult<(usize, usize)> {
+        let byte_length = Self::length(ta)?;
+        let result = from_hex(&string.to_vec(), byte_length);
+        Self::set_bytes(ta, &result.bytes);
+        if let Some(error) = result.error {
+            return Err(error.into());
+        }
+        Ok((result.read, result.bytes.len()))
+    }
+
+    /// Abstract operation [`ValidateUint8Array ( ta )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-validateuint8array
+    fn validate(ta: &JsValue) -> JsResult<JsObject<TypedArray>> {
+        // 1. Perform ? RequireInternalSlot(ta, [[TypedArrayName]]).
+        // 2. If ta.[[TypedArrayName]] is not "Uint8Array", throw a TypeError exception.
+        ta.as_object()
+            .and_then(|o| o.clone().downcast::<TypedArray>().ok())
+            .filter(|o| o.borrow().data.kind() == TypedArrayKind::Uint8)
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`this` is not a Uint8Array")
+                    .into()
+            })
+    }
+
+    /// Creates a new `Uint8Array` containing `bytes`.
+    fn create_from_bytes(bytes: &[u8], context: &mut Context) -> JsResult<JsObject> {
+        let constructor = context
+            .intrinsics()
+            .constructors()
+            .typed_uint8_array()
+            .constructor();
+        let ta =
+            BuiltinTypedArray::allocate::<Self>(&constructor.into(), bytes.len() as u64, context)?;
+        Self::set_bytes(
+            &ta.downcast_ref::<TypedArray>()
+                .expect("must be a typed array"),
+            bytes,
+        );
+        Ok(ta)
+    }
+
+    /// Gets `TypedArrayLength(MakeTypedArrayWithBufferWitnessRecord(ta, seq-cst))`, throwing if
+    /// `ta` is out of bounds.
+    fn length(ta: &TypedArray) -> JsResult<usize> {
+        let buffer = ta.viewed_array_buffer().as_buffer();
+        let Some(buf) = buffer
+            .bytes(Ordering::SeqCst)
+            .filter(|buf| !ta.is_out_of_bounds(buf.len()))
+        else {
+            return Err(JsNativeError::typ()
+                .with_message("typed array is outside the bounds of its inner buffer")
+                .into());
+        };
+        Ok(ta.array_length(buf.len()) as usize)
+    }
+
+    /// Abstract operation [`GetUint8ArrayBytes ( ta )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-getuint8arraybytes
+    fn get_bytes(ta: &TypedArray) -> JsResult<Vec<u8>> {
+        // 1. Let buffer be ta.[[ViewedArrayBuffer]].
+        // 2. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(ta, seq-cst).
+        // 3. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        // 4. Let len be TypedArrayLength(taRecord).
+        let len = Self::length(ta)?;
+
+        // 5. Let byteOffset be ta.[[ByteOffset]].
+        let byte_offset = ta.byte_offset() as usize;
+
+        // 6. Let bytes be a new empty List.
+        // 7. Let index be 0.
+        // 8. Repeat, while index < len,
+        //     a. Let byteIndex be byteOffset + index.
+        //     b. Let byte be ℝ(GetValueFromBuffer(buffer, byteIndex, uint8, true, unordered)).
+        //     c. Append byte to bytes.
+        //     d. Set index to index + 1.
+        let buffer = ta.viewed_array_buffer().as_buffer();
+        let buf = buffer
+            .bytes(Ordering::SeqCst)
+            .expect("buffer was checked to be in bounds");
+        let bytes = match buf.subslice(byte_offset..byte_offset + len) {
+            SliceRef::Slice(bytes) => bytes.to_vec(),
+            SliceRef::AtomicSlice(bytes) => bytes
+                .iter()
+                .map(|byte| byte.load(Ordering::Relaxed))
+                .collect(),
+        };
+
+        // 9. Return bytes.
+        Ok(bytes)
+    }
+
+    /// Abstract operation [`SetUint8ArrayBytes ( into, bytes )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-writeuint8arraybytes
+    fn set_bytes(into: &TypedArray, bytes: &[u8]) {
+        // 1. Let offset be into.[[ByteOffset]].
+        let offset = into.byte_offset() as usize;
+
+        // 2. Let len be the length of bytes.
+        // 3. Let index be 0.
+        // 4. Repeat, while index < len,
+        //     a. Let byte be bytes[index].
+        //     b. Let byteIndexInBuffer be index + offset.
+        //     c. Perform SetValueInBuffer(into.[[ViewedArrayBuffer]], byteIndexInBuffer, uint8, 𝔽(byte), true, unordered).
+        //     d. Set index to index + 1.
+        let mut buffer = into.viewed_array_buffer().as_buffer_mut();
+        let mut buf = buffer
+            .bytes(Ordering::SeqCst)
+            .expect("buffer cannot be detached while decoding");
+        match buf.subslice_mut(offset..offset + bytes.len()) {
+            SliceRefMut::Slice(dest) => dest.copy_from_slice(bytes),
+            SliceRefMut::AtomicSlice(dest) => {
+                for (dest, &byte) in dest.iter().zip(bytes) {
+                    dest.store(byte, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+}
+
+/// Gets the first argument, throwing if it is not a string.
+fn string_argument(args: &[JsValue]) -> JsResult<&JsString> {
+    args.get_or_undefined(0).as_string().ok_or_else(|| {
+        JsNativeError::typ()
+            .with_message("expected a string argument")
+            .into()
+    })
+}
+
+/// Creates the `{ read, written }` result object of the `setFrom*` methods.
+fn read_written_object(read: usize, written: usize, context: &mut Context) -> JsObject {
+    let result = JsObject::with_object_proto(context.intrinsics());
+    result
+        .create_data_property_or_throw(js_string!("read"), read, context)
+        .expect("CreateDataPropertyOrThrow should never throw here");
+    result
+        .create_data_property_or_throw(js_string!("written"), written, context)
+        .expect("CreateDataPropertyOrThrow should never throw here");
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::{from_base64, Base64Alphabet, LastChunkHandling};
+    use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+
+    /// Decodes `string` with the standard alphabet, returning the number of code units read,
+    /// the decoded bytes and the error message, if any.
+    fn decode(
+        string: &str,
+        last_chunk_handling: LastChunkHandling,
+        max_length: usize,
+    ) -> (usize, Vec<u8>, Option<String>) {
+        let string = string.encode_utf16().collect::<Vec<_>>();
+        let result = from_base64(
+            &string,
+            Base64Alphabet::Base64,
+            last_chunk_handling,
+            max_length,
+        );
+        let error = result.error.map(|error| error.message().to_owned());
+        (result.read, result.bytes, error)
+    }
+
+    #[test]
+    fn loose() {
+        let decode = |string| decode(string, LastChunkHandling::Loose, usize::MAX);
+
+        assert_eq!(decode("Zm9vYmFy"), (8, b"foobar".to_vec(), None));
+        assert_eq!(decode("Zm9vYg"), (6, b"foob".to_vec(), None));
+        assert_eq!(decode("Zm9vYh=="), (8, b"foob".to_vec(), None));
+        assert_eq!(
+            decode("Zm9vY"),
+            (4, b"foo".to_vec(), Some("invalid base64 string".to_owned()))
+        );
+    }
+
+    #[test]
+    fn strict() {
+        let decode = |string| decode(string, LastChunkHandling::Strict, usize::MAX);
+
+        assert_eq!(decode("Zm9vYmFy"), (8, b"foobar".to_vec(), None));
+        assert_eq!(decode("Zm9vYg=="), (8, b"foob".to_vec(), None));
+        assert_eq!(decode("Zm9vYmE="), (8, b"fooba".to_vec(), None));
+        assert_eq!(
+            decode("Zm9vYg"),
+            (
+                4,
+                b"foo".to_vec(),
+                Some("missing base64 padding".to_owned())
+            )
+        );
+        assert_eq!(
+            decode("Zm9vYh=="),
+            (
+                4,
+                b"foo".to_vec(),
+                Some("non-zero padding bits in base64 string".to_owned())
+            )
+        );
+    }
+
+    #[test]
+    fn stop_before_partial() {
+        let decode = |string| decode(string, LastChunkHandling::StopBeforePartial, usize::MAX);
+
+        assert_eq!(decode("Zm9vYmFy"), (8, b"foobar".to_vec(), None));
+        assert_eq!(decode("Zm9vYg"), (4, b"foo".to_vec(), None));
+        assert_eq!(decode("Zm9vYg="), (4, b"foo".to_vec(), None));
+        assert_eq!(decode("Zm9vYg=="), (8, b"foob".to_vec(), None));
+        assert_eq!(decode("Zm9vY"), (4, b"foo".to_vec(), None));
+    }
+
+    #[test]
+    fn bad_padding() {
+        let decode = |string| decode(string, LastChunkHandling::Loose, usize::MAX);
+
+        assert_eq!(
+            decode("Zm9v="),
+            (4, b"foo".to_vec(), Some("invalid base64 string".to_owned()))
+        );
+        assert_eq!(
+            decode("Zm9vY==="),
+            (4, b"foo".to_vec(), Some("invalid base64 string".to_owned()))
+        );
+        assert_eq!(
+            decode("Zm9vYg="),
+            (
+                4,
+                b"foo".to_vec(),
+                Some("missing base64 padding".to_owned())
+            )
+        );
+        assert_eq!(
+            decode("Zm9vYg==Zm9v"),
+            (
+                4,
+                b"foo".to_vec(),
+                Some("unexpected data after base64 padding".to_owned())
+            )
+        );
+    }
+
+    #[test]
+    fn whitespace() {
+        let decode = |string| decode(string, LastChunkHandling::Strict, usize::MAX);
+
+        assert_eq!(decode(" Zm 9v\tYg\n=\r= "), (14, b"foob".to_vec(), None));
+        assert_eq!(decode("\u{c}Zm9v\u{c}"), (6, b"foo".to_vec(), None));
+        assert_eq!(
+            decode("Zm9v\u{a0}"),
+            (4, b"foo".to_vec(), Some("invalid base64 string".to_owned()))
+        );
+    }
+
+    #[test]
+    fn max_length() {
+        let decode = |string, max_length| decode(string, LastChunkHandling::Loose, max_length);
+
+        assert_eq!(decode("Zm9vYmFy", 0), (0, Vec::new(), None));
+        assert_eq!(decode("Zm9vYmFy", 3), (4, b"foo".to_vec(), None));
+        assert_eq!(decode("Zm9vYmFy", 4), (4, b"foo".to_vec(), None));
+        assert_eq!(decode("Zm9vYmFy", 5), (4, b"foo".to_vec(), None));
+        assert_eq!(decode("Zm9vYmE=", 5), (8, b"fooba".to_vec(), None));
+    }
+
+    #[test]
+    fn set_from_base64_partial_write() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var target = new Uint8Array(4);
+                var result = target.setFromBase64("Zm9vYmFy");
+            "#}),
+            TestAction::assert_eq("result.read", 4),
+            TestAction::assert_eq("result.written", 3),
+            TestAction::assert_eq("target.join()", js_string!("102,111,111,0")),
+            TestAction::run(indoc! {r#"
+                var target = new Uint8Array(5);
+                var result = target.setFromBase64("Zm9vYmFy");
+            "#}),
+            TestAction::assert_eq("result.read", 4),
+            TestAction::assert_eq("result.written", 3),
+            TestAction::run(indoc! {r#"
+                var target = new Uint8Array(8);
+                var error;
+                try {
+                    target.setFromBase64("Zm9vYmFy$");
+                } catch (e) {
+                    error = e;
+                }
+            "#}),
+            TestAction::assert("error instanceof SyntaxError"),
+            TestAction::assert_eq("target.join()", js_string!("102,111,111,98,97,114,0,0")),
+            TestAction::assert_native_error(
+                "new Uint8Array(8).setFromBase64('Zm9vYg', { lastChunkHandling: 'strict' })",
+                JsNativeErrorKind::Syntax,
+                "missing base64 padding",
+            ),
+        ]);
+    }
+}
@@ -0,0 +1,495 @@
+use boa_gc::{Finalize, Gc, Trace, WeakGc};
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    job::GenericJob,
+    js_string,
+    object::{
+        internal_methods::get_prototype_from_constructor, ErasedVTableObject, JsFunction, JsObject,
+    },
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{can_be_held_weakly, existing_weak_target, weak_target};
+
+/// A record that stores the information of a value registered in a [`FinalizationRegistry`].
+///
+/// More Information:
+///  - [ECMAScript Reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-finalization-registry-instances
+#[derive(Trace, Finalize)]
+struct RegistryCell {
+    /// The `[[WeakRefTarget]]` of the cell.
+    target: WeakGc<ErasedVTableObject>,
+
+    /// The `[[HeldValue]]` of the cell, which must be kept alive by the registry.
+    held_value: JsValue,
+
+    /// The `[[UnregisterToken]]` of the cell, or `None` if the cell cannot be unregistered.
+    unregister_token: Option<WeakGc<ErasedVTableObject>>,
+}
+
+// We cannot derive `Debug` because `WeakGc<ErasedVTableObject>` doesn't implement it.
+impl std::fmt::Debug for RegistryCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegistryCell")
+            .field("is_empty", &self.is_empty())
+            .field("held_value", &self.held_value)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RegistryCell {
+    /// Returns `true` if the target of this cell was already collected.
+    fn is_empty(&self) -> bool {
+        !self.target.is_upgradable()
+    }
+
+    /// Returns `true` if the unregister token of this cell is the same as `token`.
+    fn has_token(&self, token: &Gc<ErasedVTableObject>) -> bool {
+        self.unregister_token
+            .as_ref()
+            .and_then(WeakGc::upgrade)
+            .is_some_and(|t| Gc::ptr_eq(&t, token))
+    }
+}
+
+/// Boa's implementation of ECMAScript's `FinalizationRegistry` builtin object.
+///
+/// A `FinalizationRegistry` allows the user to request a callback to be called when an object
+/// registered in the registry gets collected by the garbage collector.
+///
+/// More Information:
+///  - [ECMAScript Reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct FinalizationRegistry {
+    /// The `[[Realm]]` where the cleanup jobs of the registry will run.
+    realm: Realm,
+
+    /// The `[[CleanupCallback]]` of the registry.
+    cleanup_callback: JsFunction,
+
+    /// The `[[Cells]]` of the registry.
+    cells: Vec<RegistryCell>,
+
+    /// Whether a cleanup job for this registry is currently enqueued.
+    cleanup_pending: bool,
+}
+
+impl IntrinsicObject for FinalizationRegistry {
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+
+    fn init(realm: &Realm) {
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("FinalizationRegistry"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::register, js_string!("register"), 2)
+            .method(Self::unregister, js_string!("unregister"), 1)
+            .method(Self::cleanup_some, js_string!("cleanupSome"), 0)
+            .build();
+    }
+}
+
+impl BuiltInObject for FinalizationRegistry {
+    const NAME: JsString = StaticJsStrings::FINALIZATION_REGISTRY;
+}
+
+impl BuiltInConstructor for FinalizationRegistry {
+    const LENGTH: usize = 1;
+    const P: usize = 4;
+    const SP: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::finalization_registry;
+
+    /// Constructor [`FinalizationRegistry ( cleanupCallback )`][cons]
+    ///
+    /// [cons]: https://tc39.es/ecma262/#sec-finalization-registry-cleanup-callback
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("FinalizationRegistry: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. If IsCallable(cleanupCallback) is false, throw a TypeError exception.
+        let cleanup_callback = args
+            .get_or_undefined(0)
+            .as_callable()
+            .cloned()
+            .and_then(JsFunction::from_object)
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("FinalizationRegistry: cleanup callback must be callable")
+            })?;
+
+        // 3. Let finalizationRegistry be ? OrdinaryCreateFromConstructor(NewTarget,
+        //    "%FinalizationRegistry.prototype%", « [[Realm]], [[CleanupCallback]], [[Cells]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::finalization_registry,
+            context,
+        )?;
+
+        // 4. Let fn be the active function object.
+        // 5. Set finalizationRegistry.[[Realm]] to fn.[[Realm]].
+        // 6. Set finalizationRegistry.[[CleanupCallback]] to HostMakeJobCallback(cleanupCallback).
+        // 7. Set finalizationRegistry.[[Cells]] to a new empty List.
+        let registry = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                realm: context.realm().clone(),
+                cleanup_callback,
+                cells: Vec::new(),
+                cleanup_pending: false,
+            },
+        );
+
+        context
+            .finalization_registries
+            .push(WeakGc::new(registry.inner()));
+
+        // 8. Return finalizationRegistry.
+        Ok(registry.into())
+    }
+}
+
+impl FinalizationRegistry {
+    /// Method [`FinalizationRegistry.prototype.register ( target, heldValue [ , unregisterToken ] )`][spec].
+    ///
+    /// Registers `target` in the registry, calling the cleanup callback with `heldValue` after
+    /// `target` gets collected.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.register
+    fn register(this: &JsValue, args: &[JsValue], _context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "FinalizationRegistry.prototype.register: expected `this` to be a `FinalizationRegistry` object",
+                )
+            })?;
+
+        let target = args.get_or_undefined(0);
+        let held_value = args.get_or_undefined(1);
+        let unregister_token = args.get_or_undefined(2);
+
+        // 3. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        let target_object = weak_target(target).ok_or_else(|| {
+            JsNativeError::typ().with_message(format!(
+                "FinalizationRegistry.prototype.register: expected target argument of type `object` or non-registered `symbol`, got target of type `{}`",
+                target.type_of()
+            ))
+        })?;
+
+        // 4. If SameValue(target, heldValue) is true, throw a TypeError exception.
+        if JsValue::same_value(target, held_value) {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "FinalizationRegistry.prototype.register: target and held value must not be the same",
+                )
+                .into());
+        }
+
+        // 5. If CanBeHeldWeakly(unregisterToken) is false, then
+        let unregister_token = if let Some(token) = weak_target(unregister_token) {
+            Some(WeakGc::new(token.inner()))
+        } else if unregister_token.is_undefined() {
+            // b. Set unregisterToken to empty.
+            None
+        } else {
+            // a. If unregisterToken is not undefined, throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "FinalizationRegistry.prototype.register: expected unregister token of type `object`, non-registered `symbol` or `undefined`, got `{}`",
+                    unregister_token.type_of()
+                ))
+                .into());
+        };
+
+        // 6. Let cell be the Record { [[WeakRefTarget]]: target, [[HeldValue]]: heldValue, [[UnregisterToken]]: unregisterToken }.
+        // 7. Append cell to finalizationRegistry.[[Cells]].
+        registry.borrow_mut().data.cells.push(RegistryCell {
+            target: WeakGc::new(target_object.inner()),
+            held_value: held_value.clone(),
+            unregister_token,
+        });
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Method [`FinalizationRegistry.prototype.unregister ( unregisterToken )`][spec].
+    ///
+    /// Removes all cells registered with `unregisterToken` from the registry, returning `true`
+    /// if any cell was removed.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister
+    fn unregister(this: &JsValue, args: &[JsValue], _context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "FinalizationRegistry.prototype.unregister: expected `this` to be a `FinalizationRegistry` object",
+                )
+            })?;
+
+        // 3. If CanBeHeldWeakly(unregisterToken) is false, throw a TypeError exception.
+        let unregister_token = args.get_or_undefined(0);
+        if !can_be_held_weakly(unregister_token) {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "FinalizationRegistry.prototype.unregister: expected unregister token of type `object` or non-registered `symbol`, got `{}`",
+                    unregister_token.type_of()
+                ))
+                .into());
+        }
+
+        // A symbol that was never held weakly cannot be the token of any cell.
+        let Some(token) = existing_weak_target(unregister_token) else {
+            return Ok(false.into());
+        };
+
+        // 4. Let removed be false.
+        // 5. For each Record { [[WeakRefTarget]], [[HeldValue]], [[UnregisterToken]] } cell of finalizationRegistry.[[Cells]], do
+        //     a. If cell.[[UnregisterToken]] is not empty and SameValue(cell.[[UnregisterToken]], unregisterToken) is true, then
+        //         i. Remove cell from finalizationRegistry.[[Cells]].
+        //         ii. Set removed to true.
+        let mut registry = registry.borrow_mut();
+        let cells = &mut registry.data.cells;
+        let len = cells.len();
+        cells.retain(|cell| !cell.has_token(token.inner()));
+
+        // 6. Return removed.
+        Ok((cells.len() != len).into())
+    }
+
+    /// Method [`FinalizationRegistry.prototype.cleanupSome ( [ callback ] )`][spec].
+    ///
+    /// Synchronously runs the cleanup logic of the registry for all the targets that were
+    /// collected, using `callback` instead of the registry's cleanup callback if provided.
+    ///
+    /// [spec]: https://tc39.es/proposal-cleanup-some/#sec-finalization-registry.prototype.cleanupSome
+    fn cleanup_some(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "FinalizationRegistry.prototype.cleanupSome: expected `this` to be a `FinalizationRegistry` object",
+                )
+            })?;
+
+        // 3. If callback is present and IsCallable(callback) is false, throw a TypeError exception.
+        let callback = args.get_or_undefined(0);
+        let callback = if callback.is_undefined() {
+            None
+        } else {
+            Some(
+                callback
+                    .as_callable()
+                    .cloned()
+                    .and_then(JsFunction::from_object)
+                    .ok_or_else(|| {
+                        JsNativeError::typ().with_message(
+                            "FinalizationRegistry.prototype.cleanupSome: callback must be callable",
+                        )
+                    })?,
+            )
+        };
+
+        // 4. Perform ? CleanupFinalizationRegistry(finalizationRegistry, callback).
+        Self::cleanup(&registry, callback, context)?;
+
+        // 5. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Abstract operation [`CleanupFinalizationRegistry ( finalizationRegistry )`][spec].
+    ///
+    /// Calls the cleanup callback of the registry (or `callback` if provided) with the held value
+    /// of every cell whose target was collected.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-cleanup-finalization-registry
+    fn cleanup(
+        registry: &JsObject<Self>,
+        callback: Option<JsFunction>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Assert: finalizationRegistry has [[Cells]] and [[CleanupCallback]] internal slots.
+        // 2. Let callback be finalizationRegistry.[[CleanupCallback]].
+        let callback = callback.unwrap_or_else(|| registry.borrow().data.cleanup_callback.clone());
+
+        // 3. While finalizationRegistry.[[Cells]] contains a Record cell such that cell.[[WeakRefTarget]] is empty, an implementation may perform the following steps:
+        loop {
+            // a. Choose any such cell.
+            // b. Remove cell from finalizationRegistry.[[Cells]].
+            let held_value = {
+                let mut registry = registry.borrow_mut();
+                let cells = &mut registry.data.cells;
+                let Some(index) = cells.iter().position(RegistryCell::is_empty) else {
+                    break;
+                };
+                cells.swap_remove(index).held_value.clone()
+            };
+
+            // c. Perform ? HostCallJobCallback(callback, undefined, « cell.[[HeldValue]] »).
+            callback.call(&JsValue::undefined(), &[held_value], context)?;
+        }
+
+        // 4. Return unused.
+        Ok(())
+    }
+
+    /// Abstract operation [`HostEnqueueFinalizationRegistryCleanupJob ( finalizationRegistry )`][spec].
+    ///
+    /// Enqueues a cleanup job for every live registry that has at least one cell whose target
+    /// was collected. Since targets can only be collected by a garbage collection, this is a
+    /// no-op if no collections were done since the last call.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry
+    pub(crate) fn enqueue_cleanup_jobs(context: &mut Context) {
+        let collections = boa_gc::collections();
+        if collections == context.last_gc_collection {
+            return;
+        }
+        context.last_gc_collection = collections;
+
+        // Registries that were already collected cannot call their cleanup callbacks anymore.
+        context
+            .finalization_registries
+            .retain(WeakGc::is_upgradable);
+
+        let registries = context
+            .finalization_registries
+            .iter()
+            .filter_map(WeakGc::upgrade)
+            .filter_map(|registry| JsObject::from(registry).downcast::<Self>().ok())
+            .collect::<Vec<_>>();
+
+        for registry in registries {
+            let realm = {
+                let mut registry = registry.borrow_mut();
+                let registry = &mut registry.data;
+                if registry.cleanup_pending || !registry.cells.iter().any(RegistryCell::is_empty) {
+                    continue;
+                }
+                registry.cleanup_pending = true;
+                registry.realm.clone()
+            };
+
+            // 1. Let cleanupJob be a new Job Abstract Closure with no parameters that captures finalizationRegistry and performs the following steps when called:
+            let job = GenericJob::new(
+                move |context| {
+                    registry.borrow_mut().data.cleanup_pending = false;
+
+                    // a. Let cleanupResult be Completion(CleanupFinalizationRegistry(finalizationRegistry)).
+                    // b. If cleanupResult is an abrupt completion, perform any host-defined steps for reporting the error.
+                    // c. Return unused.
+                    Self::cleanup(&registry, None, context)?;
+                    Ok(JsValue::undefined())
+                },
+                realm,
+                context,
+            );
+
+            // 2. Perform HostEnqueueGenericJob(cleanupJob, finalizationRegistry.[[Realm]]).
+            context.enqueue_job(job.into());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::{js_string, run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+
+    #[test]
+    fn finalization_registry_cleanup() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var held = [];
+                var registry = new FinalizationRegistry(value => held.push(value));
+                {
+                    let obj = {};
+                    registry.register(obj, "collected");
+                }
+            "#}),
+            TestAction::inspect_context(|context| {
+                context.clear_kept_objects();
+                boa_gc::force_collect();
+                context.clear_kept_objects();
+                context.run_jobs().expect("cleanup jobs should not fail");
+            }),
+            TestAction::assert_eq("held.length", 1),
+            TestAction::assert_eq("held[0]", js_string!("collected")),
+        ]);
+    }
+
+    #[test]
+    fn finalization_registry_unregister() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var registry = new FinalizationRegistry(() => {});
+                var token = {};
+                registry.register({}, 1, token);
+                registry.register({}, 2, token);
+            "#}),
+            TestAction::assert("registry.unregister(token)"),
+            TestAction::assert("!registry.unregister(token)"),
+            TestAction::assert_native_error(
+                "registry.unregister(1)",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry.prototype.unregister: expected unregister token of type `object` or non-registered `symbol`, got `number`",
+            ),
+            TestAction::assert_native_error(
+                "let o = {}; registry.register(o, o)",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry.prototype.register: target and held value must not be the same",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn finalization_registry_cleanup_some() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var held = [];
+                var registry = new FinalizationRegistry(() => {});
+                registry.register({}, "a");
+            "#}),
+            TestAction::inspect_context(|_| boa_gc::force_collect()),
+            TestAction::run("registry.cleanupSome(value => held.push(value))"),
+            TestAction::assert_eq("held.length", 1),
+            TestAction::assert_eq("held[0]", JsValue::from(js_string!("a"))),
+        ]);
+    }
+}
@@ -1,5 +1,63 @@
-//! Boa's implementation of ECMAScript's `WeakRef` object.
+//! Boa's implementation of ECMAScript's `WeakRef` and `FinalizationRegistry` objects.
 
+mod finalization_registry;
+mod symbol_anchor;
 mod weak_ref;
 
+pub(crate) use finalization_registry::FinalizationRegistry;
+pub(crate) use symbol_anchor::{SymbolAnchor, SymbolAnchors};
 pub(crate) use weak_ref::WeakRef;
+
+use crate::{builtins::symbol::key_for_symbol, value::JsVariant, JsObject, JsValue};
+
+/// Abstract operation [`CanBeHeldWeakly ( v )`][spec].
+///
+/// [spec]: https://tc39.es/ecma262/#sec-canbeheldweakly
+pub(crate) fn can_be_held_weakly(v: &JsValue) -> bool {
+    match v.variant() {
+        // 1. If v is an Object, return true.
+        JsVariant::Object(_) => true,
+        // 2. If v is a Symbol and KeyForSymbol(v) is undefined, return true.
+        JsVariant::Symbol(symbol) => key_for_symbol(symbol).is_none(),
+        // 3. Return false.
+        _ => false,
+    }
+}
+
+/// Gets the object that weak references to `v` must hold, or `None` if `v` cannot be held
+/// weakly.
+///
+/// Objects are held directly, while non-registered symbols are held through their
+/// [`SymbolAnchor`], which is created if it doesn't exist yet.
+pub(crate) fn weak_target(v: &JsValue) -> Option<JsObject> {
+    if !can_be_held_weakly(v) {
+        return None;
+    }
+    match v.variant() {
+        JsVariant::Object(object) => Some(object.clone()),
+        JsVariant::Symbol(symbol) => Some(SymbolAnchors::get_or_insert(symbol)),
+        _ => None,
+    }
+}
+
+/// Gets the object that weak references to `v` hold, without creating an anchor for `v` if it
+/// is a symbol that was never held weakly.
+///
+/// Returns `None` if `v` cannot be held weakly, or if it cannot be the target of any existing
+/// weak reference.
+pub(crate) fn existing_weak_target(v: &JsValue) -> Option<JsObject> {
+    match v.variant() {
+        JsVariant::Object(object) => Some(object.clone()),
+        JsVariant::Symbol(symbol) => SymbolAnchors::get(symbol),
+        _ => None,
+    }
+}
+
+/// Gets the value represented by the target `object` of a weak reference, or `None` if it is
+/// the anchor of a symbol that was already dropped.
+pub(crate) fn weak_target_value(object: JsObject) -> Option<JsValue> {
+    if let Some(anchor) = object.downcast_ref::<SymbolAnchor>() {
+        return anchor.symbol().map(JsValue::from);
+    }
+    Some(object.into())
+}
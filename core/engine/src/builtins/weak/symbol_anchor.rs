@@ -0,0 +1,334 @@
+//! Anchors that allow symbols to be used as the targets of weak references.
+//!
+//! Symbols are reference counted instead of being managed by the garbage collector, so they
+//! cannot be used directly as the keys of ephemerons. Instead, every symbol that is held weakly
+//! gets an anchor object, and weak collections hold the anchor weakly in place of the symbol.
+//!
+//! The anchor must stay alive for as long as the symbol is reachable. Symbols stored in the
+//! garbage collected heap are traced as edges to their anchor, while the anchors of symbols
+//! referenced from outside the heap are roots. This mirrors how the garbage collector finds the
+//! roots of `Gc` pointers: the references to a symbol found while tracing the heap are counted,
+//! and the anchor stops being a root once all the strong references to the symbol come from
+//! the heap. This way, cycles such as `wm.set(s, { s })` can be reclaimed.
+
+use std::{cell::Cell, cell::RefCell, mem::ManuallyDrop};
+
+use boa_gc::{Finalize, Trace, Tracer, WeakGc};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    object::ErasedVTableObject,
+    symbol::{JsSymbol, WeakJsSymbol},
+    JsData, JsObject,
+};
+
+thread_local! {
+    /// The anchors of all the symbols that are held weakly within the current thread, which
+    /// share the same garbage collector.
+    static SYMBOL_ANCHORS: RefCell<SymbolAnchors> = RefCell::default();
+}
+
+/// The object data of the anchor of a symbol.
+#[derive(Debug, Finalize, JsData)]
+pub(crate) struct SymbolAnchor {
+    symbol: WeakJsSymbol,
+    hash: u64,
+}
+
+// SAFETY: `SymbolAnchor` does not contain any objects which need to be traced. Tracing the
+// anchor only resets the count of heap references of its symbol for the next collection.
+unsafe impl Trace for SymbolAnchor {
+    unsafe fn trace(&self, _tracer: &mut Tracer) {
+        // Every live anchor is marked exactly once per collection, after all the references to
+        // its symbol were counted by `trace_non_roots`.
+        SYMBOL_ANCHORS.with(|anchors| {
+            if let Some(entry) = anchors.borrow().anchors.get(&self.hash) {
+                entry.heap_refs.set(0);
+            }
+        });
+    }
+
+    unsafe fn trace_non_roots(&self) {}
+
+    fn run_finalizer(&self) {
+        Finalize::finalize(self);
+    }
+}
+
+impl SymbolAnchor {
+    /// Gets the anchored symbol, or `None` if it has already been dropped.
+    pub(crate) fn symbol(&self) -> Option<JsSymbol> {
+        self.symbol.upgrade()
+    }
+}
+
+/// The anchor of a symbol that is held weakly.
+struct AnchorEntry {
+    symbol: WeakJsSymbol,
+
+    /// Weak reference to the anchor, which must be checked before using `root`.
+    anchor: WeakGc<ErasedVTableObject>,
+
+    /// Strong reference to the anchor, which is a root only while the symbol is referenced from
+    /// outside the heap. If the anchor gets collected this reference is dangling, so it must
+    /// never be used or dropped after `anchor` can no longer be upgraded.
+    root: ManuallyDrop<JsObject>,
+
+    /// The number of references to the symbol found in the heap during the current collection.
+    heap_refs: Cell<usize>,
+}
+
+impl std::fmt::Debug for AnchorEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnchorEntry")
+            .field("symbol", &self.symbol)
+            .field("heap_refs", &self.heap_refs)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AnchorEntry {
+    /// Releases the strong reference to the anchor, if it wasn't collected.
+    fn release(mut self) {
+        if self.anchor.is_upgradable() {
+            // SAFETY: the anchor wasn't collected, so `root` is still valid, and `self` is
+            // consumed so `root` cannot be used again.
+            unsafe { ManuallyDrop::drop(&mut self.root) }
+        }
+    }
+}
+
+/// The anchors of all the symbols that are held weakly.
+#[derive(Debug, Default)]
+pub(crate) struct SymbolAnchors {
+    anchors: FxHashMap<u64, AnchorEntry>,
+}
+
+impl Drop for SymbolAnchors {
+    fn drop(&mut self) {
+        // This only happens when the thread exits, where the garbage collector may have already
+        // freed the anchors. The anchors are freed together with the rest of the heap.
+        for (_, entry) in self.anchors.drain() {
+            std::mem::forget(entry);
+        }
+    }
+}
+
+impl SymbolAnchors {
+    /// Gets the anchor of `symbol`, or `None` if it was never held weakly.
+    pub(crate) fn get(symbol: &JsSymbol) -> Option<JsObject> {
+        SYMBOL_ANCHORS.with(|anchors| {
+            anchors
+                .borrow()
+                .anchors
+                .get(&symbol.hash())
+                .and_then(|entry| entry.anchor.upgrade())
+                .map(JsObject::from)
+        })
+    }
+
+    /// Gets the anchor of `symbol`, creating it if it doesn't exist.
+    pub(crate) fn get_or_insert(symbol: &JsSymbol) -> JsObject {
+        if let Some(anchor) = Self::get(symbol) {
+            return anchor;
+        }
+
+        // The anchors must not be borrowed while allocating, since this can trigger a collection.
+        let anchor = JsObject::from_proto_and_data(
+            None,
+            SymbolAnchor {
+                symbol: symbol.downgrade(),
+                hash: symbol.hash(),
+            },
+        );
+        let entry = AnchorEntry {
+            symbol: symbol.downgrade(),
+            anchor: WeakGc::new(anchor.inner()),
+            root: ManuallyDrop::new(anchor.clone()),
+            heap_refs: Cell::new(0),
+        };
+
+        let previous = SYMBOL_ANCHORS
+            .with(|anchors| anchors.borrow_mut().anchors.insert(symbol.hash(), entry));
+        if let Some(previous) = previous {
+            previous.release();
+        }
+
+        anchor
+    }
+
+    /// Releases the anchors of all the symbols that have been dropped, allowing the garbage
+    /// collector to reclaim the entries keyed by them.
+    pub(crate) fn release_dropped() {
+        let released = SYMBOL_ANCHORS.with(|anchors| {
+            let mut anchors = anchors.borrow_mut();
+            let dropped = anchors
+                .anchors
+                .iter()
+                .filter(|(_, entry)| !entry.symbol.is_upgradable() || !entry.anchor.is_upgradable())
+                .map(|(hash, _)| *hash)
+                .collect::<Vec<_>>();
+            dropped
+                .into_iter()
+                .filter_map(|hash| anchors.anchors.remove(&hash))
+                .collect::<Vec<_>>()
+        });
+
+        for entry in released {
+            entry.release();
+        }
+    }
+
+    /// Marks the anchor of `symbol`, which was found in a reachable part of the heap.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called while tracing the heap during the mark phase of a collection.
+    pub(crate) unsafe fn trace(symbol: &JsSymbol, tracer: &mut Tracer) {
+        SYMBOL_ANCHORS.with(|anchors| {
+            let anchors = anchors.borrow();
+            if anchors.anchors.is_empty() {
+                return;
+            }
+            if let Some(entry) = anchors.anchors.get(&symbol.hash()) {
+                if entry.anchor.is_upgradable() {
+                    // SAFETY: the anchor is still alive, and the caller ensures this is called
+                    // during the mark phase.
+                    unsafe { entry.root.trace(tracer) }
+                }
+            }
+        });
+    }
+
+    /// Counts a reference to `symbol` found in the heap. Once all the strong references to the
+    /// symbol were found in the heap, the reference of the anchors to its anchor is counted as
+    /// a reference from the heap, so it stops being a root.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called once for every symbol stored in the heap, while counting the
+    /// references from the heap during a collection.
+    pub(crate) unsafe fn trace_non_roots(symbol: &JsSymbol) {
+        SYMBOL_ANCHORS.with(|anchors| {
+            let anchors = anchors.borrow();
+            if anchors.anchors.is_empty() {
+                return;
+            }
+            let Some(entry) = anchors.anchors.get(&symbol.hash()) else {
+                return;
+            };
+
+            // Well-known symbols are never dropped, so their anchors always stay as roots.
+            let Some(strong_count) = symbol.strong_count() else {
+                return;
+            };
+            let heap_refs = entry.heap_refs.get() + 1;
+            entry.heap_refs.set(heap_refs);
+            if heap_refs == strong_count && entry.anchor.is_upgradable() {
+                // SAFETY: the anchor is still alive, and the caller ensures this is called
+                // while counting the references from the heap.
+                unsafe { entry.root.trace_non_roots() }
+            }
+        });
+    }
+
+    /// Gets the number of anchors that are still registered.
+    #[cfg(test)]
+    pub(crate) fn len() -> usize {
+        SYMBOL_ANCHORS.with(|anchors| anchors.borrow().anchors.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::SymbolAnchors;
+    use crate::{run_test_actions, Context, JsSymbol, JsValue, Source, TestAction};
+
+    #[test]
+    fn symbol_cycles_are_reclaimed() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var wm = new WeakMap();
+                var ws = new WeakSet();
+                var ref;
+                {
+                    let s = Symbol("cycle");
+                    wm.set(s, { s });
+                    ws.add(s);
+                    ref = new WeakRef(s);
+                }
+            "#}),
+            TestAction::inspect_context(|context| {
+                assert_eq!(SymbolAnchors::len(), 1);
+                context.clear_kept_objects();
+                boa_gc::force_collect();
+                context.clear_kept_objects();
+                assert_eq!(SymbolAnchors::len(), 0);
+            }),
+            TestAction::assert_eq("ref.deref()", JsValue::undefined()),
+        ]);
+    }
+
+    #[test]
+    fn symbols_reachable_from_the_heap_keep_their_entries() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var wm = new WeakMap();
+                var holder = {};
+                {
+                    let s = Symbol("held");
+                    wm.set(s, { s });
+                    holder.s = s;
+                }
+            "#}),
+            TestAction::inspect_context(|context| {
+                context.clear_kept_objects();
+                boa_gc::force_collect();
+                context.clear_kept_objects();
+                assert_eq!(SymbolAnchors::len(), 1);
+            }),
+            TestAction::assert("wm.get(holder.s).s === holder.s"),
+        ]);
+    }
+
+    #[test]
+    fn symbols_held_outside_the_heap_keep_their_entries() {
+        let context = &mut Context::default();
+        let symbol = JsValue::from(JsSymbol::new(None).expect("must create a symbol"));
+
+        let set = context
+            .eval(Source::from_bytes(
+                "var wm = new WeakMap(); (s) => { wm.set(s, { s }); }",
+            ))
+            .expect("must evaluate the script");
+        set.as_callable()
+            .expect("must be a function")
+            .call(
+                &JsValue::undefined(),
+                std::slice::from_ref(&symbol),
+                context,
+            )
+            .expect("must set the entry");
+
+        context.clear_kept_objects();
+        boa_gc::force_collect();
+        context.clear_kept_objects();
+        assert_eq!(SymbolAnchors::len(), 1);
+
+        let get = context
+            .eval(Source::from_bytes("(s) => wm.get(s)?.s === s"))
+            .expect("must evaluate the script");
+        let found = get
+            .as_callable()
+            .expect("must be a function")
+            .call(
+                &JsValue::undefined(),
+                std::slice::from_ref(&symbol),
+                context,
+            )
+            .expect("must get the entry");
+        assert_eq!(found, JsValue::from(true));
+    }
+}
@@ -72,11 +72,12 @@ impl BuiltInConstructor for WeakRef {
                 .into());
         }
 
-        // 2. If target is not an Object, throw a TypeError exception.
-        let target = args.first().and_then(JsValue::as_object).ok_or_else(|| {
+        // 2. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        let target = args.get_or_undefined(0);
+        let weak_target = super::weak_target(target).ok_or_else(|| {
             JsNativeError::typ().with_message(format!(
-                "WeakRef: expected target argument of type `object`, got target of type `{}`",
-                args.get_or_undefined(0).type_of()
+                "WeakRef: expected target argument of type `object` or non-registered `symbol`, got target of type `{}`",
+                target.type_of()
             ))
         })?;
 
@@ -87,7 +88,7 @@ impl BuiltInConstructor for WeakRef {
         let weak_ref = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
-            WeakGc::new(target.inner()),
+            WeakGc::new(weak_target.inner()),
         );
 
         // 4. Perform AddToKeptObjects(target).
@@ -123,14 +124,15 @@ impl WeakRef {
         // https://tc39.es/ecma262/multipage/managing-memory.html#sec-weakrefderef
         // 1. Let target be weakRef.[[WeakRefTarget]].
         // 2. If target is not empty, then
-        if let Some(object) = weak_ref.upgrade() {
-            let object = JsObject::from(object);
-
+        if let Some(target) = weak_ref
+            .upgrade()
+            .and_then(|object| super::weak_target_value(object.into()))
+        {
             // a. Perform AddToKeptObjects(target).
-            context.kept_alive.push(object.clone());
+            context.kept_alive.push(target.clone());
 
             // b. Return target.
-            Ok(object.into())
+            Ok(target)
         } else {
             // 3. Return undefined.
             Ok(JsValue::undefined())
@@ -142,7 +144,7 @@ impl WeakRef {
 mod tests {
     use indoc::indoc;
 
-    use crate::{run_test_actions, JsValue, TestAction};
+    use crate::{run_test_actions, JsNativeErrorKind, JsValue, TestAction};
 
     #[test]
     fn weak_ref_collected() {
@@ -165,4 +167,38 @@ mod tests {
             TestAction::assert_eq("ptr.deref()", JsValue::undefined()),
         ]);
     }
+
+    #[test]
+    fn weak_ref_symbol_collected() {
+        run_test_actions([
+            TestAction::assert_with_op(
+                indoc! {r#"
+                    var ptr;
+                    {
+                        let sym = Symbol("a");
+                        ptr = new WeakRef(sym);
+                    }
+                    ptr.deref()
+                "#},
+                |v, _| v.is_symbol(),
+            ),
+            TestAction::inspect_context(|context| {
+                context.clear_kept_objects();
+                boa_gc::force_collect();
+            }),
+            TestAction::assert_eq("ptr.deref()", JsValue::undefined()),
+        ]);
+    }
+
+    #[test]
+    fn weak_ref_symbol_targets() {
+        run_test_actions([
+            TestAction::assert("new WeakRef(Symbol.iterator).deref() === Symbol.iterator"),
+            TestAction::assert_native_error(
+                "new WeakRef(Symbol.for('registered'))",
+                JsNativeErrorKind::Type,
+                "WeakRef: expected target argument of type `object` or non-registered `symbol`, got target of type `symbol`",
+            ),
+        ]);
+    }
 }
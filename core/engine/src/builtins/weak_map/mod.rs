@@ -9,8 +9,9 @@
 
 use crate::{
     builtins::{
-        map::add_entries_from_iterable, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
-        IntrinsicObject,
+        map::add_entries_from_iterable,
+        weak::{existing_weak_target, weak_target},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
     },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     js_string,
@@ -34,7 +35,7 @@ impl IntrinsicObject for WeakMap {
     }
 
     fn init(realm: &Realm) {
-        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .property(
                 JsSymbol::to_string_tag(),
                 Self::NAME,
@@ -43,8 +44,18 @@ impl IntrinsicObject for WeakMap {
             .method(Self::delete, js_string!("delete"), 1)
             .method(Self::get, js_string!("get"), 1)
             .method(Self::has, js_string!("has"), 1)
-            .method(Self::set, js_string!("set"), 2)
-            .build();
+            .method(Self::set, js_string!("set"), 2);
+
+        #[cfg(feature = "experimental")]
+        let builder = builder
+            .method(Self::get_or_insert, js_string!("getOrInsert"), 2)
+            .method(
+                Self::get_or_insert_computed,
+                js_string!("getOrInsertComputed"),
+                2,
+            );
+
+        builder.build();
     }
 }
 
@@ -57,7 +68,7 @@ impl BuiltInObject for WeakMap {
 impl BuiltInConstructor for WeakMap {
     /// The amount of arguments the `WeakMap` constructor takes.
     const LENGTH: usize = 0;
-    const P: usize = 5;
+    const P: usize = 7;
     const SP: usize = 0;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
@@ -134,18 +145,17 @@ impl WeakMap {
                 JsNativeError::typ().with_message("WeakMap.delete: called with non-object value")
             })?;
 
-        // 3. Let entries be M.[[WeakMapData]].
-        // 4. If key is not an Object, return false.
-        let Some(key) = args.get_or_undefined(0).as_object() else {
+        // 3. If CanBeHeldWeakly(key) is false, return false.
+        let Some(key) = existing_weak_target(args.get_or_undefined(0)) else {
             return Ok(false.into());
         };
 
-        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        // 4. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
         // a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, then
         // i. Set p.[[Key]] to empty.
         // ii. Set p.[[Value]] to empty.
         // iii. Return true.
-        // 6. Return false.
+        // 5. Return false.
         Ok(map.remove(key.inner()).is_some().into())
     }
 
@@ -171,15 +181,14 @@ impl WeakMap {
                 JsNativeError::typ().with_message("WeakMap.get: called with non-object value")
             })?;
 
-        // 3. Let entries be M.[[WeakMapData]].
-        // 4. If key is not an Object, return undefined.
-        let Some(key) = args.get_or_undefined(0).as_object() else {
+        // 3. If CanBeHeldWeakly(key) is false, return undefined.
+        let Some(key) = existing_weak_target(args.get_or_undefined(0)) else {
             return Ok(JsValue::undefined());
         };
 
-        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        // 4. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
         // a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return p.[[Value]].
-        // 6. Return undefined.
+        // 5. Return undefined.
         Ok(map.get(key.inner()).unwrap_or_default())
     }
 
@@ -205,15 +214,14 @@ impl WeakMap {
                 JsNativeError::typ().with_message("WeakMap.has: called with non-object value")
             })?;
 
-        // 3. Let entries be M.[[WeakMapData]].
-        // 4. If key is not an Object, return false.
-        let Some(key) = args.get_or_undefined(0).as_object() else {
+        // 3. If CanBeHeldWeakly(key) is false, return false.
+        let Some(key) = existing_weak_target(args.get_or_undefined(0)) else {
             return Ok(false.into());
         };
 
-        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        // 4. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
         // a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return true.
-        // 6. Return false.
+        // 5. Return false.
         Ok(map.contains_key(key.inner()).into())
     }
 
@@ -239,26 +247,141 @@ impl WeakMap {
                 JsNativeError::typ().with_message("WeakMap.set: called with non-object value")
             })?;
 
-        // 3. Let entries be M.[[WeakMapData]].
-        // 4. If key is not an Object, throw a TypeError exception.
+        // 3. If CanBeHeldWeakly(key) is false, throw a TypeError exception.
         let key = args.get_or_undefined(0);
-        let Some(key) = key.as_object() else {
+        let Some(key) = weak_target(key) else {
             return Err(JsNativeError::typ()
                 .with_message(format!(
-                    "WeakMap.set: expected target argument of type `object`, got target of type `{}`",
+                    "WeakMap.set: expected target argument of type `object` or non-registered `symbol`, got target of type `{}`",
                     key.type_of()
                 )).into());
         };
 
-        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        // 4. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
         // a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, then
         // i. Set p.[[Value]] to value.
         // ii. Return M.
-        // 6. Let p be the Record { [[Key]]: key, [[Value]]: value }.
-        // 7. Append p to entries.
+        // 5. Let p be the Record { [[Key]]: key, [[Value]]: value }.
+        // 6. Append p to M.[[WeakMapData]].
         map.insert(key.inner(), args.get_or_undefined(1).clone());
 
-        // 8. Return M.
+        // 7. Return M.
         Ok(this.clone())
     }
+
+    /// `WeakMap.prototype.getOrInsert ( key, value )`
+    ///
+    /// Returns the value associated with the key, inserting `value` if the key is not present.
+    ///
+    /// More information:
+    ///  - [Upsert proposal reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-upsert/#sec-weakmap.prototype.getOrInsert
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/getOrInsert
+    #[cfg(feature = "experimental")]
+    pub(crate) fn get_or_insert(
+        this: &JsValue,
+        args: &[JsValue],
+        _context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let mut map = this
+            .as_object()
+            .and_then(JsObject::downcast_mut::<NativeWeakMap>)
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("WeakMap.getOrInsert: called with non-object value")
+            })?;
+
+        // 3. If CanBeHeldWeakly(key) is false, throw a TypeError exception.
+        let key = args.get_or_undefined(0);
+        let Some(key) = weak_target(key) else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "WeakMap.getOrInsert: expected target argument of type `object` or non-registered `symbol`, got target of type `{}`",
+                    key.type_of()
+                )).into());
+        };
+
+        // 4. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return p.[[Value]].
+        // 5. Let p be the Record { [[Key]]: key, [[Value]]: value }.
+        // 6. Append p to M.[[WeakMapData]].
+        // 7. Return value.
+        Ok(map.get_or_insert(key.inner(), args.get_or_undefined(1).clone()))
+    }
+
+    /// `WeakMap.prototype.getOrInsertComputed ( key, callbackfn )`
+    ///
+    /// Returns the value associated with the key, inserting the result of calling `callbackfn`
+    /// with the key if the key is not present.
+    ///
+    /// More information:
+    ///  - [Upsert proposal reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-upsert/#sec-weakmap.prototype.getOrInsertComputed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/getOrInsertComputed
+    #[cfg(feature = "experimental")]
+    pub(crate) fn get_or_insert_computed(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let Some(object) = this.as_object().filter(|o| o.is::<NativeWeakMap>()) else {
+            return Err(JsNativeError::typ()
+                .with_message("WeakMap.getOrInsertComputed: called with non-object value")
+                .into());
+        };
+
+        // 3. If CanBeHeldWeakly(key) is false, throw a TypeError exception.
+        let key_value = args.get_or_undefined(0);
+        let Some(key) = weak_target(key_value) else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "WeakMap.getOrInsertComputed: expected target argument of type `object` or non-registered `symbol`, got target of type `{}`",
+                    key_value.type_of()
+                )).into());
+        };
+
+        // 4. If IsCallable(callbackfn) is false, throw a TypeError exception.
+        let Some(callback) = args.get_or_undefined(1).as_callable() else {
+            return Err(JsNativeError::typ()
+                .with_message("WeakMap.getOrInsertComputed: callback is not callable")
+                .into());
+        };
+
+        // 5. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return p.[[Value]].
+        if let Some(value) = object
+            .downcast_ref::<NativeWeakMap>()
+            .and_then(|map| map.get(key.inner()))
+        {
+            return Ok(value);
+        }
+
+        // 6. Let value be ? Call(callbackfn, undefined, « key »).
+        let value = callback.call(
+            &JsValue::undefined(),
+            std::slice::from_ref(key_value),
+            context,
+        )?;
+
+        // 7. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, then
+        //         i. Set p.[[Value]] to value.
+        //         ii. Return value.
+        // 8. Let p be the Record { [[Key]]: key, [[Value]]: value }.
+        // 9. Append p to M.[[WeakMapData]].
+        if let Some(mut map) = object.downcast_mut::<NativeWeakMap>() {
+            map.insert(key.inner(), value.clone());
+        }
+
+        // 10. Return value.
+        Ok(value)
+    }
 }
@@ -1,13 +1,14 @@
 use super::{BindingAccessOpcode, ByteCompiler, Literal, Register, ToJsString};
 use crate::{
+    builtins::function::decorators::ClassElementKind,
     js_string,
     vm::{opcode::BindingOpcode, CodeBlock, CodeBlockFlags},
 };
 use boa_ast::{
     expression::Identifier,
     function::{
-        ClassDeclaration, ClassElement, ClassElementName, ClassExpression, FormalParameterList,
-        FunctionExpression,
+        ClassDeclaration, ClassElement, ClassElementName, ClassExpression, Decorator,
+        FormalParameterList, FunctionExpression,
     },
     property::{MethodDefinitionKind, PropertyName},
     scope::Scope,
@@ -23,10 +24,12 @@ enum StaticElement {
     StaticBlock(Gc<CodeBlock>),
 
     // A static class field with it's function code, an optional name index and the information if the function is an anonymous function.
+    // Decorated fields and auto-accessors also store the index of their decorators in the class decorations.
     StaticField {
         code: Gc<CodeBlock>,
         name_index: StaticFieldName,
         is_anonymous_function: bool,
+        decorated_index: Option<u32>,
     },
 }
 
@@ -36,6 +39,14 @@ enum StaticFieldName {
     Register(Register),
 }
 
+// The name of a decorated class element.
+#[derive(Clone, Copy)]
+enum DecoratedElementName<'a> {
+    Literal(Sym),
+    Register(&'a Register),
+    PrivateName(u32),
+}
+
 /// Describes the complete specification of a class.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) struct ClassSpec<'a> {
@@ -43,6 +54,7 @@ pub(crate) struct ClassSpec<'a> {
     super_ref: Option<&'a Expression>,
     constructor: Option<&'a FunctionExpression>,
     elements: &'a [ClassElement],
+    decorators: &'a [Decorator],
     has_binding_identifier: bool,
     name_scope: Option<&'a Scope>,
 }
@@ -54,6 +66,7 @@ impl<'a> From<&'a ClassDeclaration> for ClassSpec<'a> {
             super_ref: class.super_ref(),
             constructor: class.constructor(),
             elements: class.elements(),
+            decorators: class.decorators(),
             has_binding_identifier: true,
             name_scope: Some(class.name_scope()),
         }
@@ -67,6 +80,7 @@ impl<'a> From<&'a ClassExpression> for ClassSpec<'a> {
             super_ref: class.super_ref(),
             constructor: class.constructor(),
             elements: class.elements(),
+            decorators: class.decorators(),
             has_binding_identifier: class.name().is_some(),
             name_scope: class.name_scope(),
         }
@@ -90,6 +104,23 @@ impl ByteCompiler<'_> {
             .map_or(Sym::EMPTY_STRING, Identifier::sym)
             .to_js_string(self.interner());
 
+        // Decorators are only supported with the `experimental` feature.
+        let has_decorators = cfg!(feature = "experimental")
+            && (!class.decorators.is_empty()
+                || class
+                    .elements
+                    .iter()
+                    .any(|element| !element.decorators().is_empty()));
+
+        // Class decorators are evaluated in the scope surrounding the class.
+        let class_decorators = if has_decorators {
+            let decorators = self.register_allocator.alloc();
+            self.compile_decorators(class.decorators, &decorators);
+            Some(decorators)
+        } else {
+            None
+        };
+
         let outer_scope = self.push_declarative_scope(class.name_scope);
 
         // The new span is not the same as the parent `ByteCompiler` have.
@@ -160,8 +191,20 @@ impl ByteCompiler<'_> {
 
         let prototype_register = self.register_allocator.alloc();
 
+        let superclass = if has_decorators {
+            let superclass = self.register_allocator.alloc();
+            self.bytecode.emit_push_undefined(superclass.variable());
+            Some(superclass)
+        } else {
+            None
+        };
+
         if let Some(node) = class.super_ref {
             self.compile_expr(node, &prototype_register);
+            if let Some(superclass) = &superclass {
+                self.bytecode
+                    .emit_move(superclass.variable(), prototype_register.variable());
+            }
             self.bytecode.emit_push_class_prototype(
                 prototype_register.variable(),
                 class_register.variable(),
@@ -181,6 +224,21 @@ impl ByteCompiler<'_> {
         );
         self.register_allocator.dealloc(prototype_register);
 
+        let decorations = if let Some(superclass) = superclass {
+            let decorations = self.register_allocator.alloc();
+            self.bytecode.emit_create_class_decorations(
+                decorations.variable(),
+                class_register.variable(),
+                superclass.variable(),
+            );
+            self.register_allocator.dealloc(superclass);
+            Some(decorations)
+        } else {
+            None
+        };
+        let mut decorated_elements = 0;
+        let mut auto_accessors = 0;
+
         let mut name_indices = ThinVec::new();
         for element in class.elements {
             match element {
@@ -215,176 +273,209 @@ impl ByteCompiler<'_> {
 
         for element in class.elements {
             match element {
-                ClassElement::MethodDefinition(m) => match m.name() {
-                    ClassElementName::PropertyName(PropertyName::Literal(name)) => {
-                        let index = self.get_or_insert_name(name.sym());
-                        let method = self.method(m.into());
-
-                        let object_register = if m.is_static() {
-                            &class_register
-                        } else {
-                            &proto_register
-                        };
-
-                        match (m.is_static(), m.kind()) {
-                            (true, MethodDefinitionKind::Get) => {
-                                self.bytecode.emit_define_class_static_getter_by_name(
-                                    method.variable(),
-                                    object_register.variable(),
-                                    index.into(),
-                                );
-                            }
-                            (true, MethodDefinitionKind::Set) => {
-                                self.bytecode.emit_define_class_static_setter_by_name(
-                                    method.variable(),
-                                    object_register.variable(),
-                                    index.into(),
-                                );
-                            }
-                            (true, _) => {
-                                self.bytecode.emit_define_class_static_method_by_name(
-                                    method.variable(),
-                                    object_register.variable(),
-                                    index.into(),
-                                );
-                            }
-                            (false, MethodDefinitionKind::Get) => {
-                                self.bytecode.emit_define_class_getter_by_name(
-                                    method.variable(),
-                                    object_register.variable(),
-                                    index.into(),
-                                );
-                            }
-                            (false, MethodDefinitionKind::Set) => {
-                                self.bytecode.emit_define_class_setter_by_name(
-                                    method.variable(),
-                                    object_register.variable(),
-                                    index.into(),
-                                );
-                            }
-                            (false, _) => {
-                                self.bytecode.emit_define_class_method_by_name(
-                                    method.variable(),
-                                    object_register.variable(),
-                                    index.into(),
-                                );
-                            }
-                        }
+                ClassElement::MethodDefinition(m) => {
+                    let decorators = self.compile_element_decorators(m.decorators());
+                    let kind = match m.kind() {
+                        MethodDefinitionKind::Get => ClassElementKind::Getter,
+                        MethodDefinitionKind::Set => ClassElementKind::Setter,
+                        _ => ClassElementKind::Method,
+                    };
+                    match m.name() {
+                        ClassElementName::PropertyName(PropertyName::Literal(name)) => {
+                            let index = self.get_or_insert_name(name.sym());
+                            let method = self.method(m.into());
 
-                        self.register_allocator.dealloc(method);
-                    }
-                    ClassElementName::PropertyName(PropertyName::Computed(name)) => {
-                        let key = self.register_allocator.alloc();
-                        self.compile_expr(name, &key);
-                        self.bytecode
-                            .emit_to_property_key(key.variable(), key.variable());
-                        let method = self.method(m.into());
-                        let object_register = if m.is_static() {
-                            &class_register
-                        } else {
-                            &proto_register
-                        };
-
-                        match (m.is_static(), m.kind()) {
-                            (true, MethodDefinitionKind::Get) => {
-                                self.bytecode.emit_define_class_static_getter_by_value(
-                                    method.variable(),
-                                    key.variable(),
-                                    object_register.variable(),
-                                );
-                            }
-                            (true, MethodDefinitionKind::Set) => {
-                                self.bytecode.emit_define_class_static_setter_by_value(
-                                    method.variable(),
-                                    key.variable(),
-                                    object_register.variable(),
-                                );
-                            }
-                            (true, _) => {
-                                self.bytecode.emit_define_class_static_method_by_value(
-                                    method.variable(),
-                                    key.variable(),
-                                    object_register.variable(),
-                                );
-                            }
-                            (false, MethodDefinitionKind::Get) => {
-                                self.bytecode.emit_define_class_getter_by_value(
-                                    method.variable(),
-                                    key.variable(),
-                                    object_register.variable(),
-                                );
-                            }
-                            (false, MethodDefinitionKind::Set) => {
-                                self.bytecode.emit_define_class_setter_by_value(
-                                    method.variable(),
-                                    key.variable(),
-                                    object_register.variable(),
-                                );
-                            }
-                            (false, _) => {
-                                self.bytecode.emit_define_class_method_by_value(
-                                    method.variable(),
-                                    key.variable(),
-                                    object_register.variable(),
-                                );
+                            let object_register = if m.is_static() {
+                                &class_register
+                            } else {
+                                &proto_register
+                            };
+
+                            match (m.is_static(), m.kind()) {
+                                (true, MethodDefinitionKind::Get) => {
+                                    self.bytecode.emit_define_class_static_getter_by_name(
+                                        method.variable(),
+                                        object_register.variable(),
+                                        index.into(),
+                                    );
+                                }
+                                (true, MethodDefinitionKind::Set) => {
+                                    self.bytecode.emit_define_class_static_setter_by_name(
+                                        method.variable(),
+                                        object_register.variable(),
+                                        index.into(),
+                                    );
+                                }
+                                (true, _) => {
+                                    self.bytecode.emit_define_class_static_method_by_name(
+                                        method.variable(),
+                                        object_register.variable(),
+                                        index.into(),
+                                    );
+                                }
+                                (false, MethodDefinitionKind::Get) => {
+                                    self.bytecode.emit_define_class_getter_by_name(
+                                        method.variable(),
+                                        object_register.variable(),
+                                        index.into(),
+                                    );
+                                }
+                                (false, MethodDefinitionKind::Set) => {
+                                    self.bytecode.emit_define_class_setter_by_name(
+                                        method.variable(),
+                                        object_register.variable(),
+                                        index.into(),
+                                    );
+                                }
+                                (false, _) => {
+                                    self.bytecode.emit_define_class_method_by_name(
+                                        method.variable(),
+                                        object_register.variable(),
+                                        index.into(),
+                                    );
+                                }
                             }
+
+                            self.register_allocator.dealloc(method);
+                            self.push_element_decorators(
+                                decorations.as_ref(),
+                                decorators,
+                                DecoratedElementName::Literal(name.sym()),
+                                kind,
+                                m.is_static(),
+                                &mut decorated_elements,
+                            );
                         }
+                        ClassElementName::PropertyName(PropertyName::Computed(name)) => {
+                            let key = self.register_allocator.alloc();
+                            self.compile_expr(name, &key);
+                            self.bytecode
+                                .emit_to_property_key(key.variable(), key.variable());
+                            let method = self.method(m.into());
+                            let object_register = if m.is_static() {
+                                &class_register
+                            } else {
+                                &proto_register
+                            };
 
-                        self.register_allocator.dealloc(method);
-                        self.register_allocator.dealloc(key);
-                    }
-                    ClassElementName::PrivateName(name) => {
-                        let index = self.get_or_insert_private_name(*name);
-                        let method = self.method(m.into());
-                        match (m.is_static(), m.kind()) {
-                            (true, MethodDefinitionKind::Get) => {
-                                self.bytecode.emit_set_private_getter(
-                                    class_register.variable(),
-                                    method.variable(),
-                                    index.into(),
-                                );
-                            }
-                            (true, MethodDefinitionKind::Set) => {
-                                self.bytecode.emit_set_private_setter(
-                                    class_register.variable(),
-                                    method.variable(),
-                                    index.into(),
-                                );
-                            }
-                            (true, _) => {
-                                self.bytecode.emit_set_private_method(
-                                    class_register.variable(),
-                                    method.variable(),
-                                    index.into(),
-                                );
+                            match (m.is_static(), m.kind()) {
+                                (true, MethodDefinitionKind::Get) => {
+                                    self.bytecode.emit_define_class_static_getter_by_value(
+                                        method.variable(),
+                                        key.variable(),
+                                        object_register.variable(),
+                                    );
+                                }
+                                (true, MethodDefinitionKind::Set) => {
+                                    self.bytecode.emit_define_class_static_setter_by_value(
+                                        method.variable(),
+                                        key.variable(),
+                                        object_register.variable(),
+                                    );
+                                }
+                                (true, _) => {
+                                    self.bytecode.emit_define_class_static_method_by_value(
+                                        method.variable(),
+                                        key.variable(),
+                                        object_register.variable(),
+                                    );
+                                }
+                                (false, MethodDefinitionKind::Get) => {
+                                    self.bytecode.emit_define_class_getter_by_value(
+                                        method.variable(),
+                                        key.variable(),
+                                        object_register.variable(),
+                                    );
+                                }
+                                (false, MethodDefinitionKind::Set) => {
+                                    self.bytecode.emit_define_class_setter_by_value(
+                                        method.variable(),
+                                        key.variable(),
+                                        object_register.variable(),
+                                    );
+                                }
+                                (false, _) => {
+                                    self.bytecode.emit_define_class_method_by_value(
+                                        method.variable(),
+                                        key.variable(),
+                                        object_register.variable(),
+                                    );
+                                }
                             }
-                            (false, MethodDefinitionKind::Get) => {
-                                self.bytecode.emit_push_class_private_getter(
-                                    class_register.variable(),
-                                    method.variable(),
-                                    index.into(),
-                                );
-                            }
-                            (false, MethodDefinitionKind::Set) => {
-                                self.bytecode.emit_push_class_private_setter(
-                                    class_register.variable(),
-                                    method.variable(),
-                                    index.into(),
-                                );
-                            }
-                            (false, _) => {
-                                self.bytecode.emit_push_class_private_method(
-                                    class_register.variable(),
-                                    proto_register.variable(),
-                                    method.variable(),
-                                    index.into(),
-                                );
+
+                            self.register_allocator.dealloc(method);
+                            self.push_element_decorators(
+                                decorations.as_ref(),
+                                decorators,
+                                DecoratedElementName::Register(&key),
+                                kind,
+                                m.is_static(),
+                                &mut decorated_elements,
+                            );
+                            self.register_allocator.dealloc(key);
+                        }
+                        ClassElementName::PrivateName(name) => {
+                            let index = self.get_or_insert_private_name(*name);
+                            let method = self.method(m.into());
+                            match (m.is_static(), m.kind()) {
+                                (true, MethodDefinitionKind::Get) => {
+                                    self.bytecode.emit_set_private_getter(
+                                        class_register.variable(),
+                                        method.variable(),
+                                        index.into(),
+                                    );
+                                }
+                                (true, MethodDefinitionKind::Set) => {
+                                    self.bytecode.emit_set_private_setter(
+                                        class_register.variable(),
+                                        method.variable(),
+                                        index.into(),
+                                    );
+                                }
+                                (true, _) => {
+                                    self.bytecode.emit_set_private_method(
+                                        class_register.variable(),
+                                        method.variable(),
+                                        index.into(),
+                                    );
+                                }
+                                (false, MethodDefinitionKind::Get) => {
+                                    self.bytecode.emit_push_class_private_getter(
+                                        class_register.variable(),
+                                        method.variable(),
+                                        index.into(),
+                                    );
+                                }
+                                (false, MethodDefinitionKind::Set) => {
+                                    self.bytecode.emit_push_class_private_setter(
+                                        class_register.variable(),
+                                        method.variable(),
+                                        index.into(),
+                                    );
+                                }
+                                (false, _) => {
+                                    self.bytecode.emit_push_class_private_method(
+                                        class_register.variable(),
+                                        proto_register.variable(),
+                                        method.variable(),
+                                        index.into(),
+                                    );
+                                }
                             }
+                            self.register_allocator.dealloc(method);
+                            self.push_element_decorators(
+                                decorations.as_ref(),
+                                decorators,
+                                DecoratedElementName::PrivateName(index),
+                                kind,
+                                m.is_static(),
+                                &mut decorated_elements,
+                            );
                         }
-                        self.register_allocator.dealloc(method);
                     }
-                },
+                }
                 ClassElement::FieldDefinition(field) => {
+                    let decorators = self.compile_element_decorators(field.decorators());
                     let name = self.register_allocator.alloc();
                     match field.name() {
                         PropertyName::Literal(ident) => {
@@ -401,6 +492,10 @@ impl ByteCompiler<'_> {
                             self.compile_expr(expr, &name);
                         }
                     }
+                    if decorators.is_some() || field.is_accessor() {
+                        self.bytecode
+                            .emit_to_property_key(name.variable(), name.variable());
+                    }
                     let mut field_compiler = ByteCompiler::new(
                         js_string!(),
                         true,
@@ -440,16 +535,42 @@ impl ByteCompiler<'_> {
 
                     let dst = self.register_allocator.alloc();
                     self.emit_get_function(&dst, index);
-                    self.bytecode.emit_push_class_field(
-                        class_register.variable(),
-                        name.variable(),
-                        dst.variable(),
-                        is_anonymous_function.into(),
+                    let kind = if field.is_accessor() {
+                        let storage = self.auto_accessor_storage_name(&mut auto_accessors);
+                        self.bytecode.emit_push_class_field_private(
+                            class_register.variable(),
+                            dst.variable(),
+                            storage.into(),
+                        );
+                        self.bytecode.emit_define_auto_accessor(
+                            class_register.variable(),
+                            proto_register.variable(),
+                            name.variable(),
+                            storage.into(),
+                        );
+                        ClassElementKind::Accessor
+                    } else {
+                        self.bytecode.emit_push_class_field(
+                            class_register.variable(),
+                            name.variable(),
+                            dst.variable(),
+                            is_anonymous_function.into(),
+                        );
+                        ClassElementKind::Field
+                    };
+                    self.push_element_decorators(
+                        decorations.as_ref(),
+                        decorators,
+                        DecoratedElementName::Register(&name),
+                        kind,
+                        false,
+                        &mut decorated_elements,
                     );
                     self.register_allocator.dealloc(name);
                     self.register_allocator.dealloc(dst);
                 }
                 ClassElement::PrivateFieldDefinition(field) => {
+                    let decorators = self.compile_element_decorators(field.decorators());
                     let name_index = self.get_or_insert_private_name(*field.name());
                     let mut field_compiler = ByteCompiler::new(
                         class_name.clone(),
@@ -484,22 +605,109 @@ impl ByteCompiler<'_> {
                     let index = self.push_function_to_constants(code);
                     let dst = self.register_allocator.alloc();
                     self.emit_get_function(&dst, index);
-                    self.bytecode.emit_push_class_field_private(
-                        class_register.variable(),
-                        dst.variable(),
-                        name_index.into(),
+                    let kind = if field.is_accessor() {
+                        let storage = self.auto_accessor_storage_name(&mut auto_accessors);
+                        self.bytecode.emit_push_class_field_private(
+                            class_register.variable(),
+                            dst.variable(),
+                            storage.into(),
+                        );
+                        self.bytecode.emit_define_private_auto_accessor(
+                            class_register.variable(),
+                            name_index.into(),
+                            storage.into(),
+                            false.into(),
+                        );
+                        ClassElementKind::Accessor
+                    } else {
+                        self.bytecode.emit_push_class_field_private(
+                            class_register.variable(),
+                            dst.variable(),
+                            name_index.into(),
+                        );
+                        ClassElementKind::Field
+                    };
+                    self.push_element_decorators(
+                        decorations.as_ref(),
+                        decorators,
+                        DecoratedElementName::PrivateName(name_index),
+                        kind,
+                        false,
+                        &mut decorated_elements,
                     );
                     self.register_allocator.dealloc(dst);
                 }
                 ClassElement::StaticFieldDefinition(field) => {
-                    let name_index = match field.name() {
-                        PropertyName::Literal(name) => {
-                            StaticFieldName::Index(self.get_or_insert_name(name.sym()))
+                    let decorators = self.compile_element_decorators(field.decorators());
+                    let (name_index, decorated_index) = if field.is_accessor() {
+                        let key = self.register_allocator.alloc();
+                        match field.name() {
+                            PropertyName::Literal(name) => {
+                                self.emit_push_literal(
+                                    Literal::String(
+                                        self.interner()
+                                            .resolve_expect(name.sym())
+                                            .into_common(false),
+                                    ),
+                                    &key,
+                                );
+                            }
+                            PropertyName::Computed(name) => {
+                                self.compile_expr(name, &key);
+                            }
                         }
-                        PropertyName::Computed(name) => {
-                            let name_register = self.register_allocator.alloc();
-                            self.compile_expr(name, &name_register);
-                            StaticFieldName::Register(name_register)
+                        self.bytecode
+                            .emit_to_property_key(key.variable(), key.variable());
+                        let storage = self.auto_accessor_storage_name(&mut auto_accessors);
+                        self.bytecode.emit_define_auto_accessor(
+                            class_register.variable(),
+                            class_register.variable(),
+                            key.variable(),
+                            storage.into(),
+                        );
+                        let decorated_index = self.push_element_decorators(
+                            decorations.as_ref(),
+                            decorators,
+                            DecoratedElementName::Register(&key),
+                            ClassElementKind::Accessor,
+                            true,
+                            &mut decorated_elements,
+                        );
+                        self.register_allocator.dealloc(key);
+                        (StaticFieldName::PrivateName(storage), decorated_index)
+                    } else {
+                        match field.name() {
+                            PropertyName::Literal(name) => {
+                                let decorated_index = self.push_element_decorators(
+                                    decorations.as_ref(),
+                                    decorators,
+                                    DecoratedElementName::Literal(name.sym()),
+                                    ClassElementKind::Field,
+                                    true,
+                                    &mut decorated_elements,
+                                );
+                                let index = self.get_or_insert_name(name.sym());
+                                (StaticFieldName::Index(index), decorated_index)
+                            }
+                            PropertyName::Computed(name) => {
+                                let name_register = self.register_allocator.alloc();
+                                self.compile_expr(name, &name_register);
+                                if decorators.is_some() {
+                                    self.bytecode.emit_to_property_key(
+                                        name_register.variable(),
+                                        name_register.variable(),
+                                    );
+                                }
+                                let decorated_index = self.push_element_decorators(
+                                    decorations.as_ref(),
+                                    decorators,
+                                    DecoratedElementName::Register(&name_register),
+                                    ClassElementKind::Field,
+                                    true,
+                                    &mut decorated_elements,
+                                );
+                                (StaticFieldName::Register(name_register), decorated_index)
+                            }
                         }
                     };
                     let mut field_compiler = ByteCompiler::new(
@@ -541,10 +749,32 @@ impl ByteCompiler<'_> {
                         code,
                         name_index,
                         is_anonymous_function,
+                        decorated_index,
                     });
                 }
                 ClassElement::PrivateStaticFieldDefinition(field) => {
+                    let decorators = self.compile_element_decorators(field.decorators());
                     let name_index = self.get_or_insert_private_name(*field.name());
+                    let (storage, kind) = if field.is_accessor() {
+                        let storage = self.auto_accessor_storage_name(&mut auto_accessors);
+                        self.bytecode.emit_define_private_auto_accessor(
+                            class_register.variable(),
+                            name_index.into(),
+                            storage.into(),
+                            true.into(),
+                        );
+                        (storage, ClassElementKind::Accessor)
+                    } else {
+                        (name_index, ClassElementKind::Field)
+                    };
+                    let decorated_index = self.push_element_decorators(
+                        decorations.as_ref(),
+                        decorators,
+                        DecoratedElementName::PrivateName(name_index),
+                        kind,
+                        true,
+                        &mut decorated_elements,
+                    );
                     let mut field_compiler = ByteCompiler::new(
                         class_name.clone(),
                         true,
@@ -582,8 +812,9 @@ impl ByteCompiler<'_> {
 
                     static_elements.push(StaticElement::StaticField {
                         code,
-                        name_index: StaticFieldName::PrivateName(name_index),
+                        name_index: StaticFieldName::PrivateName(storage),
                         is_anonymous_function,
+                        decorated_index,
                     });
                 }
                 ClassElement::StaticBlock(block) => {
@@ -623,6 +854,32 @@ impl ByteCompiler<'_> {
             }
         }
 
+        let decorated_class =
+            if let (Some(decorations), Some(decorators)) = (&decorations, class_decorators) {
+                let decorated_class = self.register_allocator.alloc();
+                let name_index = self.get_or_insert_string(class_name.clone());
+                self.bytecode.emit_apply_class_decorators(
+                    decorated_class.variable(),
+                    decorations.variable(),
+                    decorators.variable(),
+                    name_index.into(),
+                );
+                self.register_allocator.dealloc(decorators);
+
+                if let Some(scope) = class.name_scope {
+                    let binding = scope.get_identifier_reference(class_name.clone());
+                    let index = self.insert_binding(binding);
+                    self.emit_binding_access(
+                        BindingAccessOpcode::PutLexicalValue,
+                        &index,
+                        &decorated_class,
+                    );
+                }
+                Some(decorated_class)
+            } else {
+                None
+            };
+
         for element in static_elements {
             match element {
                 StaticElement::StaticBlock(code) => {
@@ -641,6 +898,7 @@ impl ByteCompiler<'_> {
                     code,
                     name_index,
                     is_anonymous_function,
+                    decorated_index,
                 } => {
                     let index = self.push_function_to_constants(code);
                     let function = self.register_allocator.alloc();
@@ -653,6 +911,25 @@ impl ByteCompiler<'_> {
                     self.bytecode.emit_call(0u32.into());
                     let value = self.register_allocator.alloc();
                     self.pop_into_register(&value);
+                    if let StaticFieldName::Register(key) = &name_index {
+                        if is_anonymous_function {
+                            self.bytecode
+                                .emit_to_property_key(key.variable(), key.variable());
+                            self.bytecode.emit_set_function_name(
+                                value.variable(),
+                                key.variable(),
+                                0u32.into(),
+                            );
+                        }
+                    }
+                    let decorated = decorations.as_ref().zip(decorated_index);
+                    if let Some((decorations, index)) = decorated {
+                        self.bytecode.emit_apply_field_initializers(
+                            decorations.variable(),
+                            index.into(),
+                            value.variable(),
+                        );
+                    }
                     match name_index {
                         StaticFieldName::PrivateName(name) => {
                             self.bytecode.emit_define_private_field(
@@ -669,15 +946,6 @@ impl ByteCompiler<'_> {
                             );
                         }
                         StaticFieldName::Register(key) => {
-                            if is_anonymous_function {
-                                self.bytecode
-                                    .emit_to_property_key(key.variable(), key.variable());
-                                self.bytecode.emit_set_function_name(
-                                    value.variable(),
-                                    key.variable(),
-                                    0u32.into(),
-                                );
-                            }
                             self.bytecode.emit_define_own_property_by_value(
                                 value.variable(),
                                 key.variable(),
@@ -686,12 +954,27 @@ impl ByteCompiler<'_> {
                             self.register_allocator.dealloc(key);
                         }
                     }
+                    if let Some((decorations, index)) = decorated {
+                        self.bytecode.emit_run_field_extra_initializers(
+                            decorations.variable(),
+                            index.into(),
+                        );
+                    }
 
                     self.register_allocator.dealloc(value);
                 }
             }
         }
 
+        if let (Some(decorations), Some(decorated_class)) = (decorations, decorated_class) {
+            self.bytecode
+                .emit_run_class_initializers(decorations.variable(), decorated_class.variable());
+            self.bytecode
+                .emit_move(class_register.variable(), decorated_class.variable());
+            self.register_allocator.dealloc(decorations);
+            self.register_allocator.dealloc(decorated_class);
+        }
+
         self.register_allocator.dealloc(proto_register);
 
         self.pop_declarative_scope(outer_scope);
@@ -709,4 +992,88 @@ impl ByteCompiler<'_> {
         // NOTE: Reset strict mode to before class declaration/expression evalutation.
         self.code_block_flags.set(CodeBlockFlags::STRICT, strict);
     }
+
+    /// Evaluates a list of decorators into an array in the `dst` register.
+    fn compile_decorators(&mut self, decorators: &[Decorator], dst: &Register) {
+        self.bytecode.emit_push_new_array(dst.variable());
+        let value = self.register_allocator.alloc();
+        for decorator in decorators {
+            self.compile_expr(decorator.expression(), &value);
+            self.bytecode
+                .emit_push_value_to_array(value.variable(), dst.variable());
+        }
+        self.register_allocator.dealloc(value);
+    }
+
+    /// Evaluates the decorators of a class element, if it has any.
+    fn compile_element_decorators(&mut self, decorators: &[Decorator]) -> Option<Register> {
+        if cfg!(not(feature = "experimental")) || decorators.is_empty() {
+            return None;
+        }
+        let dst = self.register_allocator.alloc();
+        self.compile_decorators(decorators, &dst);
+        Some(dst)
+    }
+
+    /// Records the evaluated decorators of a class element in the class decorations.
+    ///
+    /// Returns the index of the element in the class decorations, if it has decorators.
+    fn push_element_decorators(
+        &mut self,
+        decorations: Option<&Register>,
+        decorators: Option<Register>,
+        name: DecoratedElementName<'_>,
+        kind: ClassElementKind,
+        is_static: bool,
+        decorated_elements: &mut u32,
+    ) -> Option<u32> {
+        let decorators = decorators?;
+        let decorations = decorations.expect("classes with decorators must have decorations");
+        let kind = kind.encode(is_static);
+
+        match name {
+            DecoratedElementName::Literal(name) => {
+                let key = self.register_allocator.alloc();
+                self.emit_push_literal(
+                    Literal::String(self.interner().resolve_expect(name).into_common(false)),
+                    &key,
+                );
+                self.bytecode.emit_push_class_element_decorators(
+                    decorations.variable(),
+                    key.variable(),
+                    decorators.variable(),
+                    kind.into(),
+                );
+                self.register_allocator.dealloc(key);
+            }
+            DecoratedElementName::Register(key) => {
+                self.bytecode.emit_push_class_element_decorators(
+                    decorations.variable(),
+                    key.variable(),
+                    decorators.variable(),
+                    kind.into(),
+                );
+            }
+            DecoratedElementName::PrivateName(index) => {
+                self.bytecode.emit_push_class_private_element_decorators(
+                    decorations.variable(),
+                    decorators.variable(),
+                    kind.into(),
+                    index.into(),
+                );
+            }
+        }
+        self.register_allocator.dealloc(decorators);
+
+        let index = *decorated_elements;
+        *decorated_elements += 1;
+        Some(index)
+    }
+
+    /// Returns the name index of the private storage of the next auto-accessor of a class.
+    fn auto_accessor_storage_name(&mut self, auto_accessors: &mut u32) -> u32 {
+        let description = js_string!(format!("accessor storage {auto_accessors}"));
+        *auto_accessors += 1;
+        self.get_or_insert_string(description)
+    }
 }
@@ -0,0 +1,148 @@
+use super::{jump_control::JumpControlInfoFlags, ByteCompiler};
+use boa_ast::{declaration::LexicalDeclaration, Declaration, StatementListItem};
+
+/// The kind of `using` declarations that a statement list directly contains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UsingDeclarations {
+    /// Only `using` declarations.
+    Sync,
+
+    /// At least one `await using` declaration.
+    Async,
+}
+
+impl UsingDeclarations {
+    /// Finds the kind of `using` declarations directly contained in the given statement list items.
+    pub(crate) fn of<'a, I>(items: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a StatementListItem>,
+    {
+        let mut result = None;
+        for item in items {
+            if let StatementListItem::Declaration(decl) = item {
+                if let Declaration::Lexical(lexical) = decl.as_ref() {
+                    match Self::of_declaration(lexical) {
+                        Some(Self::Async) => return Some(Self::Async),
+                        Some(Self::Sync) => result = Some(Self::Sync),
+                        None => {}
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Gets the kind of `using` declaration of a lexical declaration.
+    pub(crate) const fn of_declaration(decl: &LexicalDeclaration) -> Option<Self> {
+        match decl {
+            LexicalDeclaration::Using(_) => Some(Self::Sync),
+            LexicalDeclaration::AwaitUsing(_) => Some(Self::Async),
+            LexicalDeclaration::Let(_) | LexicalDeclaration::Const(_) => None,
+        }
+    }
+}
+
+impl ByteCompiler<'_> {
+    /// Compiles the statements emitted by `f` inside a new dispose scope.
+    ///
+    /// All the resources added by `using` and `await using` declarations compiled by `f` are
+    /// disposed when the scope is exited, regardless of whether it is exited normally, through
+    /// a `break`, `continue` or `return`, or by a thrown exception. Errors thrown while disposing
+    /// are chained to the completion of the scope using `SuppressedError` objects.
+    ///
+    /// This is equivalent to the [`DisposeResources`][spec] calls done by the evaluation of
+    /// blocks, function bodies, modules and `for` statements.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn compile_dispose_scope<F>(&mut self, kind: UsingDeclarations, use_expr: bool, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let capability = self.register_allocator.alloc();
+        self.bytecode
+            .emit_create_dispose_capability(capability.variable());
+        let outer_capability = self.dispose_capability.replace(capability.index());
+
+        let has_error = self.register_allocator.alloc();
+        self.bytecode.emit_push_false(has_error.variable());
+
+        let finally_re_throw = self.register_allocator.alloc();
+        let finally_jump_index = self.register_allocator.alloc();
+        self.bytecode.emit_push_true(finally_re_throw.variable());
+        self.push_try_with_finally_control_info(&finally_re_throw, &finally_jump_index, use_expr);
+
+        let try_handler = self.push_handler();
+        f(self);
+        self.bytecode.emit_push_false(finally_re_throw.variable());
+        let finally = self.jump();
+        self.patch_handler(try_handler);
+
+        let catch_handler = self.push_handler();
+        let error = self.register_allocator.alloc();
+        self.bytecode.emit_exception(error.variable());
+        self.bytecode.emit_push_true(has_error.variable());
+        self.bytecode.emit_push_true(finally_re_throw.variable());
+
+        // If the `Exception` opcode rethrows, this is a generator `return()` empty exception,
+        // which is disposed like a normal completion and rethrown afterwards.
+        let no_throw = self.jump();
+        self.patch_handler(catch_handler);
+
+        self.patch_jump(no_throw);
+        self.patch_jump(finally);
+
+        self.dispose_capability = outer_capability;
+
+        let finally_start = self.next_opcode_location();
+        self.jump_info
+            .last_mut()
+            .expect("there should be a try block")
+            .flags |= JumpControlInfoFlags::IN_FINALLY;
+
+        let value = self.register_allocator.alloc();
+        self.bytecode
+            .emit_set_register_from_accumulator(value.variable());
+
+        match kind {
+            UsingDeclarations::Sync => {
+                self.bytecode.emit_dispose_resources(
+                    capability.variable(),
+                    has_error.variable(),
+                    error.variable(),
+                );
+            }
+            UsingDeclarations::Async => {
+                let promise = self.register_allocator.alloc();
+                self.bytecode.emit_dispose_resources_async(
+                    promise.variable(),
+                    capability.variable(),
+                    has_error.variable(),
+                    error.variable(),
+                );
+                self.bytecode.emit_await(promise.variable());
+                let resume_kind = self.register_allocator.alloc();
+                self.pop_into_register(&resume_kind);
+                self.pop_into_register(&promise);
+                self.bytecode
+                    .emit_generator_next(resume_kind.variable(), promise.variable());
+                self.register_allocator.dealloc(resume_kind);
+                self.register_allocator.dealloc(promise);
+            }
+        }
+
+        self.bytecode.emit_set_accumulator(value.variable());
+        self.register_allocator.dealloc(value);
+        self.register_allocator.dealloc(error);
+        self.register_allocator.dealloc(has_error);
+        self.register_allocator.dealloc(capability);
+
+        // Disposing with an error completion always throws, so reaching this point with
+        // `finally_re_throw` set means that this is a generator `return()`.
+        let do_not_throw_exit = self.jump_if_false(&finally_re_throw);
+        self.bytecode.emit_re_throw();
+        self.patch_jump(do_not_throw_exit);
+        self.pop_try_with_finally_control_info(finally_start);
+        self.register_allocator.dealloc(finally_re_throw);
+        self.register_allocator.dealloc(finally_jump_index);
+    }
+}
@@ -36,7 +36,7 @@ pub(crate) enum JumpRecordAction {
     /// Handles finally, this needs to be done if we are in the try or catch section of a try statement that
     /// has a finally block.
     ///
-    /// It places push integer value [`crate::vm::opcode::Opcode`] as well as [`crate::vm::opcode::Opcode::PushFalse`], which means don't [`ReThrow`](crate::vm::opcode::Opcode::ReThrow).
+    /// It stores an integer value in the jump index register of the try statement, as well as placing [`crate::vm::opcode::Opcode::PushFalse`], which means don't [`ReThrow`](crate::vm::opcode::Opcode::ReThrow).
     ///
     /// The integer is an index used to jump. See [`crate::vm::opcode::Opcode::JumpTable`]. This is needed because the following code:
     ///
@@ -62,6 +62,8 @@ pub(crate) enum JumpRecordAction {
         index: u32,
         /// Register for the flag that indicated if the finally block needs to re throw.
         finally_throw: u32,
+        /// Register for the index used by the jump table at the end of the finally block.
+        jump_index: u32,
     },
 }
 
@@ -109,14 +111,12 @@ impl JumpRecord {
                 JumpRecordAction::HandleFinally {
                     index: value,
                     finally_throw,
+                    jump_index,
                 } => {
                     // Note: +1 because 0 is reserved for default entry in jump table (for fallthrough).
                     let index = value as i32 + 1;
-                    let value = compiler.register_allocator.alloc();
-                    compiler.emit_push_integer(index, &value);
-                    compiler.push_from_register(&value);
+                    compiler.emit_push_integer_to(index, jump_index.into());
                     compiler.bytecode.emit_push_false(finally_throw.into());
-                    compiler.register_allocator.dealloc(value);
                 }
                 JumpRecordAction::CloseIterator { r#async } => {
                     compiler.iterator_close(r#async);
@@ -173,6 +173,7 @@ pub(crate) struct JumpControlInfo {
     pub(crate) jumps: Vec<JumpRecord>,
     current_open_environments_count: u32,
     pub(crate) finally_throw: Option<u32>,
+    pub(crate) finally_jump_index: Option<u32>,
 }
 
 bitflags! {
@@ -217,6 +218,7 @@ impl JumpControlInfo {
             jumps: Vec::new(),
             current_open_environments_count,
             finally_throw: None,
+            finally_jump_index: None,
         }
     }
 
@@ -240,8 +242,13 @@ impl JumpControlInfo {
         self
     }
 
-    pub(crate) fn with_try_with_finally_flag(mut self, dst: &Register) -> Self {
-        self.finally_throw = Some(dst.index());
+    pub(crate) fn with_try_with_finally_flag(
+        mut self,
+        finally_throw: &Register,
+        jump_index: &Register,
+    ) -> Self {
+        self.finally_throw = Some(finally_throw.index());
+        self.finally_jump_index = Some(jump_index.index());
         self
     }
 
@@ -529,13 +536,18 @@ impl ByteCompiler<'_> {
     // ---- `TryStatement`'s `JumpControlInfo` methods ---- //
 
     /// Pushes a `TryStatement`'s `JumpControlInfo` onto the `jump_info` stack.
+    ///
+    /// Sets `jump_index` to zero, which makes the jump table at the end of the finally block
+    /// fall through when the block is not entered by a `break`, `continue` or `return`.
     pub(crate) fn push_try_with_finally_control_info(
         &mut self,
         finally_throw: &Register,
+        jump_index: &Register,
         use_expr: bool,
     ) {
+        self.bytecode.emit_push_zero(jump_index.variable());
         let new_info = JumpControlInfo::new(self.current_open_environments_count)
-            .with_try_with_finally_flag(finally_throw);
+            .with_try_with_finally_flag(finally_throw, jump_index);
 
         self.push_contol_info(new_info, use_expr);
     }
@@ -558,6 +570,11 @@ impl ByteCompiler<'_> {
             self.patch_jump_with_target(*label, finally_start);
         }
 
+        let jump_index = info
+            .finally_jump_index
+            .expect("try statements with a finally block must have a jump index");
+        self.bytecode.emit_push_from_register(jump_index.into());
+
         let jump_table_index = self.next_opcode_location();
         self.bytecode.emit_jump_table(
             Self::DUMMY_ADDRESS,
@@ -3,6 +3,7 @@
 mod class;
 mod declaration;
 mod declarations;
+mod dispose;
 mod env;
 mod expression;
 mod function;
@@ -18,7 +19,7 @@ use crate::{
     builtins::function::{arguments::MappedArguments, ThisMode},
     js_string,
     vm::{
-        opcode::{BindingOpcode, ByteCodeEmitter},
+        opcode::{BindingOpcode, ByteCodeEmitter, VaryingOperand},
         CallFrame, CodeBlock, CodeBlockFlags, Constant, GeneratorResumeKind, Handler, InlineCache,
     },
     JsBigInt, JsStr, JsString, SourceText, SpannedSourceText,
@@ -52,6 +53,7 @@ use thin_vec::ThinVec;
 pub(crate) use declarations::{
     eval_declaration_instantiation_context, global_declaration_instantiation_context,
 };
+use dispose::UsingDeclarations;
 pub(crate) use function::FunctionCompiler;
 pub(crate) use jump_control::JumpControlInfo;
 pub(crate) use register::*;
@@ -456,6 +458,10 @@ pub struct ByteCompiler<'ctx> {
     ///
     /// Async functions and async generator functions, need to be closed and resolved.
     pub(crate) async_handler: Option<u32>,
+
+    /// The register holding the dispose capability of the innermost statement list
+    /// that contains `using` or `await using` declarations.
+    pub(crate) dispose_capability: Option<u32>,
     json_parse: bool,
 
     /// Whether the function is in a `with` statement.
@@ -554,6 +560,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             bindings_map: FxHashMap::default(),
             jump_info: Vec::new(),
             async_handler: None,
+            dispose_capability: None,
             json_parse,
             variable_scope,
             lexical_scope,
@@ -904,14 +911,18 @@ impl<'ctx> ByteCompiler<'ctx> {
     }
 
     fn emit_push_integer(&mut self, value: i32, dst: &Register) {
+        self.emit_push_integer_to(value, dst.variable());
+    }
+
+    fn emit_push_integer_to(&mut self, value: i32, dst: VaryingOperand) {
         match value {
-            0 => self.bytecode.emit_push_zero(dst.variable()),
-            1 => self.bytecode.emit_push_one(dst.variable()),
-            x if i32::from(x as i8) == x => self.bytecode.emit_push_int8(dst.variable(), x as i8),
+            0 => self.bytecode.emit_push_zero(dst),
+            1 => self.bytecode.emit_push_one(dst),
+            x if i32::from(x as i8) == x => self.bytecode.emit_push_int8(dst, x as i8),
             x if i32::from(x as i16) == x => {
-                self.bytecode.emit_push_int16(dst.variable(), x as i16);
+                self.bytecode.emit_push_int16(dst, x as i16);
             }
-            x => self.bytecode.emit_push_int32(dst.variable(), x),
+            x => self.bytecode.emit_push_int32(dst, x),
         }
     }
 
@@ -1261,6 +1272,17 @@ impl<'ctx> ByteCompiler<'ctx> {
 
     /// Compile a [`StatementList`].
     pub fn compile_statement_list(&mut self, list: &StatementList, use_expr: bool, block: bool) {
+        if let Some(kind) = UsingDeclarations::of(list.statements()) {
+            self.compile_dispose_scope(kind, use_expr, |compiler| {
+                compiler.compile_statement_list_items(list, use_expr, block);
+            });
+        } else {
+            self.compile_statement_list_items(list, use_expr, block);
+        }
+    }
+
+    /// Compile the items of a [`StatementList`].
+    fn compile_statement_list_items(&mut self, list: &StatementList, use_expr: bool, block: bool) {
         if use_expr || self.jump_control_info_has_use_expr() {
             let mut use_expr_index = 0;
             for (i, statement) in list.statements().iter().enumerate() {
@@ -1617,6 +1639,30 @@ impl<'ctx> ByteCompiler<'ctx> {
                     }
                 }
             }
+            LexicalDeclaration::Using(decls) | LexicalDeclaration::AwaitUsing(decls) => {
+                let capability = self
+                    .dispose_capability
+                    .expect("using declarations must be in a dispose scope");
+                let hint = u32::from(decl.is_await_using());
+                for variable in decls.as_ref() {
+                    let Binding::Identifier(ident) = variable.binding() else {
+                        unreachable!("using declarations cannot contain binding patterns");
+                    };
+                    let ident = ident.to_js_string(self.interner());
+                    let init = variable
+                        .init()
+                        .expect("using declaration must have initializer");
+                    let value = self.register_allocator.alloc();
+                    self.compile_expr(init, &value);
+                    self.bytecode.emit_add_disposable_resource(
+                        capability.into(),
+                        value.variable(),
+                        hint.into(),
+                    );
+                    self.emit_binding(BindingOpcode::InitLexical, ident, &value);
+                    self.register_allocator.dealloc(value);
+                }
+            }
         }
     }
 
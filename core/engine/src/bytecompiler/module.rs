@@ -1,4 +1,4 @@
-use super::{ByteCompiler, Literal, ToJsString};
+use super::{dispose::UsingDeclarations, ByteCompiler, Literal, ToJsString};
 use crate::vm::opcode::BindingOpcode;
 use boa_ast::{declaration::ExportDeclaration, ModuleItem, ModuleItemList};
 use boa_interner::Sym;
@@ -7,8 +7,21 @@ impl ByteCompiler<'_> {
     /// Compiles a [`ModuleItemList`].
     #[inline]
     pub fn compile_module_item_list(&mut self, list: &ModuleItemList) {
-        for node in list.items() {
-            self.compile_module_item(node);
+        let using = UsingDeclarations::of(list.items().iter().filter_map(|item| match item {
+            ModuleItem::StatementListItem(item) => Some(item),
+            _ => None,
+        }));
+
+        if let Some(kind) = using {
+            self.compile_dispose_scope(kind, false, |compiler| {
+                for node in list.items() {
+                    compiler.compile_module_item(node);
+                }
+            });
+        } else {
+            for node in list.items() {
+                self.compile_module_item(node);
+            }
         }
     }
 
@@ -10,7 +10,9 @@ use boa_ast::{
 use boa_interner::Sym;
 
 use crate::{
-    bytecompiler::{Access, BindingAccessOpcode, ByteCompiler, ToJsString},
+    bytecompiler::{
+        dispose::UsingDeclarations, Access, BindingAccessOpcode, ByteCompiler, Register, ToJsString,
+    },
     vm::opcode::BindingOpcode,
 };
 
@@ -20,6 +22,29 @@ impl ByteCompiler<'_> {
         for_loop: &ForLoop,
         label: Option<Sym>,
         use_expr: bool,
+    ) {
+        let using = match for_loop.init() {
+            Some(ForLoopInitializer::Lexical(decl)) => {
+                UsingDeclarations::of_declaration(decl.declaration())
+            }
+            _ => None,
+        };
+
+        if let Some(kind) = using {
+            // Resources of `using` bindings are disposed once the whole loop is exited.
+            self.compile_dispose_scope(kind, use_expr, |compiler| {
+                compiler.compile_for_loop_statement(for_loop, label, use_expr);
+            });
+        } else {
+            self.compile_for_loop_statement(for_loop, label, use_expr);
+        }
+    }
+
+    fn compile_for_loop_statement(
+        &mut self,
+        for_loop: &ForLoop,
+        label: Option<Sym>,
+        use_expr: bool,
     ) {
         let mut let_binding_indices = None;
         let mut outer_scope_local = None;
@@ -221,6 +246,9 @@ impl ByteCompiler<'_> {
             IterableLoopInitializer::Pattern(pattern) => {
                 self.compile_declaration_pattern(pattern, BindingOpcode::SetName, &value);
             }
+            IterableLoopInitializer::Using(_) | IterableLoopInitializer::AwaitUsing(_) => {
+                unreachable!("using declarations are not allowed in the head of a for-in loop")
+            }
         }
 
         self.register_allocator.dealloc(value);
@@ -295,6 +323,53 @@ impl ByteCompiler<'_> {
         let outer_scope = self.push_declarative_scope(for_of_loop.scope());
         let handler_index = self.push_handler();
 
+        let using = match for_of_loop.initializer() {
+            IterableLoopInitializer::Using(_) => Some(UsingDeclarations::Sync),
+            IterableLoopInitializer::AwaitUsing(_) => Some(UsingDeclarations::Async),
+            _ => None,
+        };
+        if let Some(kind) = using {
+            // Resources of `using` bindings are disposed at the end of each iteration.
+            self.compile_dispose_scope(kind, use_expr, |compiler| {
+                compiler.compile_for_of_iteration(for_of_loop, value, use_expr);
+            });
+        } else {
+            self.compile_for_of_iteration(for_of_loop, value, use_expr);
+        }
+
+        {
+            let exit = self.jump();
+            self.patch_handler(handler_index);
+
+            let error = self.register_allocator.alloc();
+            self.bytecode.emit_exception(error.variable());
+
+            // NOTE: Capture throw of the iterator close and ignore it.
+            let handler_index = self.push_handler();
+            self.iterator_close(for_of_loop.r#await());
+            self.patch_handler(handler_index);
+
+            self.bytecode.emit_throw(error.variable());
+            self.register_allocator.dealloc(error);
+            self.patch_jump(exit);
+        }
+
+        self.pop_declarative_scope(outer_scope);
+        self.bytecode.emit_jump(start_address);
+
+        self.patch_jump(exit);
+        self.pop_loop_control_info();
+
+        self.iterator_close(for_of_loop.r#await());
+    }
+
+    /// Binds the current value of a `for...of` loop and compiles the body of the loop.
+    fn compile_for_of_iteration(
+        &mut self,
+        for_of_loop: &ForOfLoop,
+        value: Register,
+        use_expr: bool,
+    ) {
         match for_of_loop.initializer() {
             IterableLoopInitializer::Identifier(ref ident) => {
                 let ident = ident.to_js_string(self.interner());
@@ -339,36 +414,31 @@ impl ByteCompiler<'_> {
             IterableLoopInitializer::Pattern(pattern) => {
                 self.compile_declaration_pattern(pattern, BindingOpcode::SetName, &value);
             }
+            IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => {
+                let Binding::Identifier(ident) = declaration else {
+                    unreachable!("using declarations cannot contain binding patterns");
+                };
+                let capability = self
+                    .dispose_capability
+                    .expect("using declarations must be in a dispose scope");
+                let hint = u32::from(matches!(
+                    for_of_loop.initializer(),
+                    IterableLoopInitializer::AwaitUsing(_)
+                ));
+                self.bytecode.emit_add_disposable_resource(
+                    capability.into(),
+                    value.variable(),
+                    hint.into(),
+                );
+                let ident = ident.to_js_string(self.interner());
+                self.emit_binding(BindingOpcode::InitLexical, ident, &value);
+            }
         }
 
         self.register_allocator.dealloc(value);
 
         self.compile_stmt(for_of_loop.body(), use_expr, true);
-
-        {
-            let exit = self.jump();
-            self.patch_handler(handler_index);
-
-            let error = self.register_allocator.alloc();
-            self.bytecode.emit_exception(error.variable());
-
-            // NOTE: Capture throw of the iterator close and ignore it.
-            let handler_index = self.push_handler();
-            self.iterator_close(for_of_loop.r#await());
-            self.patch_handler(handler_index);
-
-            self.bytecode.emit_throw(error.variable());
-            self.register_allocator.dealloc(error);
-            self.patch_jump(exit);
-        }
-
-        self.pop_declarative_scope(outer_scope);
-        self.bytecode.emit_jump(start_address);
-
-        self.patch_jump(exit);
-        self.pop_loop_control_info();
-
-        self.iterator_close(for_of_loop.r#await());
     }
 
     pub(crate) fn compile_while_loop(
@@ -121,10 +121,13 @@ impl ByteCompiler<'_> {
             actions.push(JumpRecordAction::PopEnvironments { count });
 
             if !info.in_finally() {
-                if let Some(finally_throw) = info.finally_throw {
+                if let (Some(finally_throw), Some(jump_index)) =
+                    (info.finally_throw, info.finally_jump_index)
+                {
                     actions.push(JumpRecordAction::HandleFinally {
                         index: info.jumps.len() as u32,
                         finally_throw,
+                        jump_index,
                     });
                     actions.push(JumpRecordAction::Transfer { index: i as u32 });
                 }
@@ -158,7 +158,7 @@ pub trait HostHooks {
         Ok(())
     }
 
-    /// Creates the global object of a new [`Context`] from the initial intrinsics.
+    /// Creates the global object of a new [`Context`] or `ShadowRealm` from the initial intrinsics.
     ///
     /// Equivalent to the step 7 of [`InitializeHostDefinedRealm ( )`][ihdr].
     ///
@@ -1,7 +1,7 @@
 use std::{cell::OnceCell, fmt::Debug};
 
 use icu_casemap::CaseMapper;
-use icu_locale::{LocaleCanonicalizer, LocaleExpander};
+use icu_locale::{LocaleCanonicalizer, LocaleDirectionality, LocaleExpander};
 use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer};
 use icu_provider::prelude::*;
 use serde::Deserialize;
@@ -36,6 +36,7 @@ pub(crate) struct IntlProvider {
     inner_provider: Box<dyn DynamicDryDataProvider<BufferMarker>>,
     locale_canonicalizer: OnceCell<LocaleCanonicalizer>,
     locale_expander: OnceCell<LocaleExpander>,
+    locale_directionality: OnceCell<LocaleDirectionality>,
     string_normalizers: OnceCell<StringNormalizers>,
     case_mapper: OnceCell<CaseMapper>,
 }
@@ -69,6 +70,7 @@ impl Debug for IntlProvider {
         f.debug_struct("Icu")
             .field("locale_canonicalizer", &self.locale_canonicalizer)
             .field("locale_expander", &self.locale_expander)
+            .field("locale_directionality", &self.locale_directionality)
             .field("string_normalizers", &self.string_normalizers)
             .field("string_normalizercase_mapper", &self.case_mapper)
             .finish_non_exhaustive()
@@ -87,6 +89,7 @@ impl IntlProvider {
         Self {
             locale_canonicalizer: OnceCell::new(),
             locale_expander: OnceCell::new(),
+            locale_directionality: OnceCell::new(),
             string_normalizers: OnceCell::new(),
             case_mapper: OnceCell::new(),
             inner_provider: Box::new(provider),
@@ -116,6 +119,19 @@ impl IntlProvider {
         Ok(self.locale_expander.get_or_init(|| le))
     }
 
+    /// Gets the [`LocaleDirectionality`] tool.
+    pub(crate) fn locale_directionality(&self) -> Result<&LocaleDirectionality, IcuError> {
+        if let Some(ld) = self.locale_directionality.get() {
+            return Ok(ld);
+        }
+        let expander = self.locale_expander()?.clone();
+        let ld = LocaleDirectionality::try_new_with_expander_unstable(
+            &self.inner_provider.as_deserializing(),
+            expander,
+        )?;
+        Ok(self.locale_directionality.get_or_init(|| ld))
+    }
+
     /// Gets the [`StringNormalizers`] tools.
     pub(crate) fn string_normalizers(&self) -> Result<&StringNormalizers, IcuError> {
         if let Some(sn) = self.string_normalizers.get() {
@@ -4,6 +4,7 @@ use std::cell::RefCell;
 use std::{cell::Cell, path::Path, rc::Rc};
 
 use boa_ast::StatementList;
+use boa_gc::WeakGc;
 use boa_interner::Interner;
 use boa_parser::source::ReadChar;
 pub use hooks::{DefaultHooks, HostHooks};
@@ -16,13 +17,16 @@ use temporal_rs::tzdb::FsTzdbProvider;
 use crate::job::Job;
 use crate::vm::RuntimeLimits;
 use crate::{
-    builtins,
+    builtins::{
+        self,
+        weak::{FinalizationRegistry, SymbolAnchors},
+    },
     class::{Class, ClassBuilder},
     job::{JobExecutor, SimpleJobExecutor},
     js_string,
     module::{IdleModuleLoader, ModuleLoader, SimpleModuleLoader},
     native_function::NativeFunction,
-    object::{shape::RootShape, FunctionObjectBuilder, JsObject},
+    object::{shape::RootShape, ErasedVTableObject, FunctionObjectBuilder, JsObject},
     optimizer::{Optimizer, OptimizerOptions, OptimizerStatistics},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
@@ -103,7 +107,14 @@ pub struct Context {
 
     pub(crate) vm: Vm,
 
-    pub(crate) kept_alive: Vec<JsObject>,
+    pub(crate) kept_alive: Vec<JsValue>,
+
+    /// Weak references to all the `FinalizationRegistry` objects created by this context.
+    pub(crate) finalization_registries: Vec<WeakGc<ErasedVTableObject>>,
+
+    /// Number of garbage collections that had run the last time the finalization registries
+    /// were checked for collected targets.
+    pub(crate) last_gc_collection: usize,
 
     can_block: bool,
 
@@ -476,6 +487,7 @@ impl Context {
     /// Runs all the jobs with the provided job executor.
     #[inline]
     pub fn run_jobs(&mut self) -> JsResult<()> {
+        FinalizationRegistry::enqueue_cleanup_jobs(self);
         let result = self.job_executor().run_jobs(self);
         self.clear_kept_objects();
         result
@@ -490,6 +502,7 @@ impl Context {
     /// provide a custom implementatin of `JobExecutor` to the context.
     #[allow(clippy::future_not_send)]
     pub async fn run_jobs_async(&mut self) -> JsResult<()> {
+        FinalizationRegistry::enqueue_cleanup_jobs(self);
         let result = self
             .job_executor()
             .run_jobs_async(&RefCell::new(self))
@@ -503,12 +516,19 @@ impl Context {
     /// Clears all objects maintained alive by calls to the [`AddToKeptObjects`][add] abstract
     /// operation, used within the [`WeakRef`][weak] constructor.
     ///
+    /// This also releases the anchors of the weakly held symbols that were dropped, and enqueues
+    /// the cleanup jobs of all [`FinalizationRegistry`][registry] objects that have targets
+    /// collected by the garbage collector.
+    ///
     /// [clear]: https://tc39.es/ecma262/multipage/executable-code-and-execution-contexts.html#sec-clear-kept-objects
     /// [add]: https://tc39.es/ecma262/multipage/executable-code-and-execution-contexts.html#sec-addtokeptobjects
     /// [weak]: https://tc39.es/ecma262/multipage/managing-memory.html#sec-weak-ref-objects
+    /// [registry]: https://tc39.es/ecma262/multipage/managing-memory.html#sec-finalization-registry-objects
     #[inline]
     pub fn clear_kept_objects(&mut self) {
         self.kept_alive.clear();
+        SymbolAnchors::release_dropped();
+        FinalizationRegistry::enqueue_cleanup_jobs(self);
     }
 
     /// Retrieves the current stack trace of the context.
@@ -1122,6 +1142,8 @@ impl ContextBuilder {
             #[cfg(feature = "fuzz")]
             instructions_remaining: self.instructions_remaining,
             kept_alive: Vec::new(),
+            finalization_registries: Vec::new(),
+            last_gc_collection: boa_gc::collections(),
             host_hooks,
             clock,
             job_executor,
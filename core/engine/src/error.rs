@@ -459,6 +459,18 @@ impl JsError {
 
                         JsNativeErrorKind::Aggregate(error_list)
                     }
+                    Error::Suppressed => {
+                        let error = try_get_property(js_string!("error"), "error", context)?
+                            .unwrap_or_default();
+                        let suppressed =
+                            try_get_property(js_string!("suppressed"), "suppressed", context)?
+                                .unwrap_or_default();
+
+                        JsNativeErrorKind::Suppressed {
+                            error: Box::new(Self::from_opaque(error)),
+                            suppressed: Box::new(Self::from_opaque(suppressed)),
+                        }
+                    }
                 };
 
                 let realm = try_get_property(js_string!("constructor"), "constructor", context)?
@@ -579,6 +591,12 @@ impl JsError {
                     .map(|err| err.into_erased(context))
                     .collect(),
             ),
+            JsNativeErrorKind::Suppressed { error, suppressed } => {
+                JsErasedNativeErrorKind::Suppressed {
+                    error: Box::new(error.into_erased(context)),
+                    suppressed: Box::new(suppressed.into_erased(context)),
+                }
+            }
             JsNativeErrorKind::Error => JsErasedNativeErrorKind::Error,
             JsNativeErrorKind::Eval => JsErasedNativeErrorKind::Eval,
             JsNativeErrorKind::Range => JsErasedNativeErrorKind::Range,
@@ -781,6 +799,40 @@ impl JsNativeError {
         matches!(self.kind, JsNativeErrorKind::Aggregate(_))
     }
 
+    /// Creates a new `JsNativeError` of kind `SuppressedError` from the `error` that was thrown
+    /// and the `suppressed` error it replaced, with empty `message` and undefined `cause`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{JsNativeError, JsNativeErrorKind};
+    /// let error = JsNativeError::suppressed(
+    ///     JsNativeError::typ().into(),
+    ///     JsNativeError::syntax().into(),
+    /// );
+    ///
+    /// assert!(matches!(error.kind, JsNativeErrorKind::Suppressed { .. }));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn suppressed(error: JsError, suppressed: JsError) -> Self {
+        Self::new(
+            JsNativeErrorKind::Suppressed {
+                error: Box::new(error),
+                suppressed: Box::new(suppressed),
+            },
+            Cow::Borrowed(""),
+            None,
+        )
+    }
+
+    /// Check if it's a [`JsNativeErrorKind::Suppressed`].
+    #[must_use]
+    #[inline]
+    pub const fn is_suppressed(&self) -> bool {
+        matches!(self.kind, JsNativeErrorKind::Suppressed { .. })
+    }
+
     /// Creates a new `JsNativeError` of kind `Error`, with empty `message` and undefined `cause`.
     ///
     /// # Examples
@@ -1098,6 +1150,10 @@ impl JsNativeError {
             JsNativeErrorKind::Aggregate(_) => {
                 (constructors.aggregate_error().prototype(), Error::Aggregate)
             }
+            JsNativeErrorKind::Suppressed { .. } => (
+                constructors.suppressed_error().prototype(),
+                Error::Suppressed,
+            ),
             JsNativeErrorKind::Error => (constructors.error().prototype(), Error::Error),
             JsNativeErrorKind::Eval => (constructors.eval_error().prototype(), Error::Eval),
             JsNativeErrorKind::Range => (constructors.range_error().prototype(), Error::Range),
@@ -1152,6 +1208,17 @@ impl JsNativeError {
             )
             .expect("The spec guarantees this succeeds for a newly created object ");
         }
+
+        if let JsNativeErrorKind::Suppressed { error, suppressed } = kind {
+            let error = error.to_opaque(context);
+            let suppressed = suppressed.to_opaque(context);
+            o.create_non_enumerable_data_property_or_throw(js_string!("error"), error, context);
+            o.create_non_enumerable_data_property_or_throw(
+                js_string!("suppressed"),
+                suppressed,
+                context,
+            );
+        }
         o
     }
 
@@ -1194,6 +1261,18 @@ pub enum JsNativeErrorKind {
     /// [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
     Aggregate(Vec<JsError>),
+    /// An error thrown while another error was being handled, replacing (suppressing) it.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    Suppressed {
+        /// The error that was thrown.
+        error: Box<JsError>,
+        /// The error that was suppressed by `error`.
+        suppressed: Box<JsError>,
+    },
     /// A generic error. Commonly used as the base for custom exceptions.
     ///
     /// More information:
@@ -1278,6 +1357,10 @@ unsafe impl Trace for JsNativeErrorKind {
         mark,
         match &this {
             Self::Aggregate(errors) => mark(errors),
+            Self::Suppressed { error, suppressed } => {
+                mark(error);
+                mark(suppressed);
+            }
             Self::Error
             | Self::Eval
             | Self::Range
@@ -1298,6 +1381,7 @@ impl JsNativeErrorKind {
     pub(crate) fn is_catchable(&self) -> bool {
         match self {
             Self::Aggregate(_)
+            | Self::Suppressed { .. }
             | Self::Error
             | Self::Eval
             | Self::Range
@@ -1317,6 +1401,7 @@ impl PartialEq<Error> for JsNativeErrorKind {
         matches!(
             (self, other),
             (Self::Aggregate(_), Error::Aggregate)
+                | (Self::Suppressed { .. }, Error::Suppressed)
                 | (Self::Error, Error::Error)
                 | (Self::Eval, Error::Eval)
                 | (Self::Range, Error::Range)
@@ -1332,6 +1417,7 @@ impl fmt::Display for JsNativeErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Aggregate(_) => "AggregateError",
+            Self::Suppressed { .. } => "SuppressedError",
             Self::Error => "Error",
             Self::Eval => "EvalError",
             Self::Range => "RangeError",
@@ -1443,6 +1529,18 @@ pub enum JsErasedNativeErrorKind {
     /// [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
     Aggregate(Vec<JsErasedError>),
+    /// An error thrown while another error was being handled, replacing (suppressing) it.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    Suppressed {
+        /// The error that was thrown.
+        error: Box<JsErasedError>,
+        /// The error that was suppressed by `error`.
+        suppressed: Box<JsErasedError>,
+    },
     /// A generic error. Commonly used as the base for custom exceptions.
     ///
     /// More information:
@@ -1521,6 +1619,7 @@ impl fmt::Display for JsErasedNativeErrorKind {
             Self::Aggregate(errors) => {
                 return write!(f, "AggregateError(error count: {})", errors.len());
             }
+            Self::Suppressed { .. } => "SuppressedError",
             Self::Error => "Error",
             Self::Eval => "EvalError",
             Self::Range => "RangeError",
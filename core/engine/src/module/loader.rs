@@ -2,13 +2,14 @@ use std::path::{Component, Path, PathBuf};
 
 use rustc_hash::FxHashMap;
 
-use boa_gc::GcRefCell;
+use boa_gc::{Finalize, GcRefCell, Trace};
+use boa_interner::Interner;
 use boa_parser::Source;
 
 use crate::script::Script;
 use crate::{
-    js_string, object::JsObject, realm::Realm, vm::ActiveRunnable, Context, JsError, JsNativeError,
-    JsResult, JsString,
+    bytecompiler::ToJsString, js_string, object::JsObject, realm::Realm, vm::ActiveRunnable,
+    Context, JsError, JsNativeError, JsResult, JsString,
 };
 
 use super::Module;
@@ -109,6 +110,151 @@ pub fn resolve_module_specifier(
     }
 }
 
+/// The import attribute keys supported by the engine, as returned by the host hook
+/// [`HostGetSupportedImportAttributes ( )`][spec].
+///
+/// [spec]: https://tc39.es/ecma262/#sec-hostgetsupportedimportattributes
+pub(crate) const SUPPORTED_IMPORT_ATTRIBUTES: &[&str] = &["type"];
+
+/// An import attribute of a [`ModuleRequest`], e.g. the `type: "json"` in
+/// `import data from "./data.json" with { type: "json" }`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Trace, Finalize)]
+// SAFETY: `ImportAttribute` only contains strings, which don't need to be traced.
+#[boa_gc(unsafe_empty_trace)]
+pub struct ImportAttribute {
+    key: JsString,
+    value: JsString,
+}
+
+impl ImportAttribute {
+    /// Creates a new import attribute from its key and its value.
+    #[must_use]
+    pub const fn new(key: JsString, value: JsString) -> Self {
+        Self { key, value }
+    }
+
+    /// Gets the key of the attribute.
+    #[must_use]
+    pub const fn key(&self) -> &JsString {
+        &self.key
+    }
+
+    /// Gets the value of the attribute.
+    #[must_use]
+    pub const fn value(&self) -> &JsString {
+        &self.value
+    }
+}
+
+/// A [**`ModuleRequest` Record**][spec], composed of the specifier of the requested module and the
+/// import attributes of the request.
+///
+/// [spec]: https://tc39.es/ecma262/#modulerequest-record
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Trace, Finalize)]
+// SAFETY: `ModuleRequest` only contains strings, which don't need to be traced.
+#[boa_gc(unsafe_empty_trace)]
+pub struct ModuleRequest {
+    specifier: JsString,
+    attributes: Box<[ImportAttribute]>,
+}
+
+impl ModuleRequest {
+    /// Creates a new module request from a specifier and a list of import attributes.
+    ///
+    /// The attributes are sorted by key, which makes the equality of two requests follow the
+    /// [`ModuleRequestsEqual`][spec] operation.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ModuleRequestsEqual
+    #[must_use]
+    pub fn new(specifier: JsString, mut attributes: Vec<ImportAttribute>) -> Self {
+        attributes.sort_unstable_by(|a, b| a.key.cmp(&b.key));
+        Self {
+            specifier,
+            attributes: attributes.into_boxed_slice(),
+        }
+    }
+
+    /// Creates a new module request from a specifier, without any import attributes.
+    #[must_use]
+    pub fn from_specifier(specifier: JsString) -> Self {
+        Self {
+            specifier,
+            attributes: Box::default(),
+        }
+    }
+
+    /// Creates a module request from its AST representation.
+    pub(crate) fn from_ast(
+        request: &boa_ast::declaration::ModuleRequest,
+        interner: &Interner,
+    ) -> Self {
+        Self::new(
+            request.specifier().to_js_string(interner),
+            request
+                .attributes()
+                .iter()
+                .map(|attribute| {
+                    ImportAttribute::new(
+                        attribute.key().to_js_string(interner),
+                        attribute.value().to_js_string(interner),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// Gets the specifier of the requested module.
+    #[must_use]
+    pub const fn specifier(&self) -> &JsString {
+        &self.specifier
+    }
+
+    /// Gets the import attributes of the request, sorted by key.
+    #[must_use]
+    pub fn attributes(&self) -> &[ImportAttribute] {
+        &self.attributes
+    }
+
+    /// Gets the value of the import attribute with the provided key, if the request has one.
+    #[must_use]
+    pub fn get_attribute(&self, key: &str) -> Option<&JsString> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(ImportAttribute::value)
+    }
+}
+
+/// The type of a requested module, as defined by its `type` import attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ModuleType {
+    /// A JavaScript module, requested without a `type` attribute.
+    JavaScript,
+    /// A JSON module, requested with a `type: "json"` attribute.
+    Json,
+}
+
+impl ModuleType {
+    /// Gets the type of the module requested by `request`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the `type` attribute of the request has an unsupported value.
+    pub fn from_request(request: &ModuleRequest) -> JsResult<Self> {
+        match request.get_attribute("type") {
+            None => Ok(Self::JavaScript),
+            Some(ty) if ty == "json" => Ok(Self::Json),
+            Some(ty) => Err(JsNativeError::typ()
+                .with_message(format!(
+                    "unsupported module type `{}`",
+                    ty.to_std_string_escaped()
+                ))
+                .into()),
+        }
+    }
+}
+
 /// The referrer from which a load request of a module originates.
 #[derive(Debug, Clone)]
 pub enum Referrer {
@@ -146,12 +292,16 @@ impl From<ActiveRunnable> for Referrer {
 /// This trait allows to customize the behaviour of the engine on module load requests and
 /// `import.meta` requests.
 pub trait ModuleLoader {
-    /// Host hook [`HostLoadImportedModule ( referrer, specifier, hostDefined, payload )`][spec].
+    /// Host hook [`HostLoadImportedModule ( referrer, moduleRequest, hostDefined, payload )`][spec].
     ///
     /// This hook allows to customize the module loading functionality of the engine. Technically,
     /// this should call the [`FinishLoadingImportedModule`][finish] operation, but this simpler API just provides
     /// a closure that replaces `FinishLoadingImportedModule`.
     ///
+    /// The `request` contains both the specifier of the module and its import attributes. Loaders
+    /// must take the attributes into account, e.g. by loading a JSON module when the request has a
+    /// `type: "json"` attribute (see [`ModuleType::from_request`] and [`Module::parse_json`]).
+    ///
     /// # Requirements
     ///
     /// - The host environment must perform `FinishLoadingImportedModule(referrer, specifier, payload, result)`,
@@ -172,7 +322,7 @@ pub trait ModuleLoader {
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     );
@@ -222,7 +372,7 @@ impl ModuleLoader for IdleModuleLoader {
     fn load_imported_module(
         &self,
         _referrer: Referrer,
-        _specifier: JsString,
+        _request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
@@ -246,6 +396,7 @@ impl ModuleLoader for IdleModuleLoader {
 pub struct SimpleModuleLoader {
     root: PathBuf,
     module_map: GcRefCell<FxHashMap<PathBuf, Module>>,
+    json_module_map: GcRefCell<FxHashMap<PathBuf, Module>>,
 }
 
 impl SimpleModuleLoader {
@@ -265,6 +416,7 @@ impl SimpleModuleLoader {
         Ok(Self {
             root: absolute,
             module_map: GcRefCell::default(),
+            json_module_map: GcRefCell::default(),
         })
     }
 
@@ -285,14 +437,37 @@ impl ModuleLoader for SimpleModuleLoader {
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
         let result = (|| {
+            let specifier = request.specifier();
             let short_path = specifier.to_std_string_escaped();
             let path =
-                resolve_module_specifier(Some(&self.root), &specifier, referrer.path(), context)?;
+                resolve_module_specifier(Some(&self.root), specifier, referrer.path(), context)?;
+
+            if ModuleType::from_request(&request)? == ModuleType::Json {
+                if let Some(module) = self.json_module_map.borrow().get(&path) {
+                    return Ok(module.clone());
+                }
+
+                let json = std::fs::read_to_string(&path).map_err(|err| {
+                    JsNativeError::typ()
+                        .with_message(format!("could not open file `{short_path}`"))
+                        .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
+                })?;
+                let module = Module::parse_json(js_string!(json), context).map_err(|err| {
+                    JsNativeError::syntax()
+                        .with_message(format!("could not parse JSON module `{short_path}`"))
+                        .with_cause(err)
+                })?;
+                self.json_module_map
+                    .borrow_mut()
+                    .insert(path, module.clone());
+                return Ok(module);
+            }
+
             if let Some(module) = self.get(&path) {
                 return Ok(module);
             }
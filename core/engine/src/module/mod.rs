@@ -41,6 +41,7 @@ pub use namespace::ModuleNamespace;
 use source::SourceTextModule;
 pub use synthetic::{SyntheticModule, SyntheticModuleInitializer};
 
+use crate::bytecompiler::ToJsString;
 use crate::object::TypedJsFunction;
 use crate::spanned_source_text::SourceText;
 use crate::{
@@ -70,6 +71,7 @@ impl std::fmt::Debug for Module {
         f.debug_struct("Module")
             .field("realm", &self.inner.realm.addr())
             .field("namespace", &self.inner.namespace)
+            .field("deferred_namespace", &self.inner.deferred_namespace)
             .field("kind", &self.inner.kind)
             .finish()
     }
@@ -79,6 +81,7 @@ impl std::fmt::Debug for Module {
 struct ModuleRepr {
     realm: Realm,
     namespace: GcRefCell<Option<JsObject>>,
+    deferred_namespace: GcRefCell<Option<JsObject>>,
     kind: ModuleKind,
     host_defined: HostDefined,
     path: Option<PathBuf>,
@@ -169,6 +172,22 @@ impl Module {
         let (module, source) =
             parser.parse_module_with_source(realm.scope(), context.interner_mut())?;
 
+        // It is a Syntax Error if WithClauseToAttributes of WithClause has an entry entry such
+        // that HostGetSupportedImportAttributes() does not contain entry.[[Key]].
+        for request in module.items().requests() {
+            for attribute in request.attributes() {
+                let key = attribute.key().to_js_string(context.interner());
+                if !SUPPORTED_IMPORT_ATTRIBUTES.iter().any(|k| key == *k) {
+                    return Err(JsNativeError::syntax()
+                        .with_message(format!(
+                            "unsupported import attribute `{}`",
+                            key.to_std_string_escaped()
+                        ))
+                        .into());
+                }
+            }
+        }
+
         let source_text = SourceText::new(source);
         let src = SourceTextModule::new(module, context.interner(), source_text);
 
@@ -176,6 +195,7 @@ impl Module {
             inner: Gc::new(ModuleRepr {
                 realm,
                 namespace: GcRefCell::default(),
+                deferred_namespace: GcRefCell::default(),
                 kind: ModuleKind::SourceText(Box::new(src)),
                 host_defined: HostDefined::default(),
                 path,
@@ -205,6 +225,7 @@ impl Module {
             inner: Gc::new(ModuleRepr {
                 realm,
                 namespace: GcRefCell::default(),
+                deferred_namespace: GcRefCell::default(),
                 kind: ModuleKind::Synthetic(Box::new(synth)),
                 host_defined: HostDefined::default(),
                 path,
@@ -502,6 +523,79 @@ impl Module {
         }
     }
 
+    /// Abstract operation [`GatherAsynchronousTransitiveDependencies ( module [ , seen ] )`][spec].
+    ///
+    /// Gets the modules with top-level await that must be evaluated eagerly for a deferred import
+    /// of this module.
+    ///
+    /// [spec]: https://tc39.es/proposal-defer-import-eval/#sec-GatherAsynchronousTransitiveDependencies
+    #[allow(clippy::mutable_key_type)]
+    fn gather_async_transitive_dependencies(&self, seen: &mut FxHashSet<Module>) -> Vec<Module> {
+        // 1. If seen is not present, set seen to a new empty List.
+        // 3. If seen contains module, return result.
+        // 4. Append module to seen.
+        if !seen.insert(self.clone()) {
+            return Vec::new();
+        }
+
+        match self.kind() {
+            ModuleKind::SourceText(src) => src.gather_async_transitive_dependencies(self, seen),
+            // 5. If module is not a Cyclic Module Record, return result.
+            ModuleKind::Synthetic(_) => Vec::new(),
+        }
+    }
+
+    /// Abstract operation [`ReadyForSyncExecution ( module [ , seen ] )`][spec].
+    ///
+    /// Checks if this module and its dependencies can be evaluated synchronously.
+    ///
+    /// [spec]: https://tc39.es/proposal-defer-import-eval/#sec-ReadyForSyncExecution
+    #[allow(clippy::mutable_key_type)]
+    fn ready_for_sync_execution(&self, seen: &mut FxHashSet<Module>) -> bool {
+        // 1. If seen is not present, set seen to a new empty List.
+        // 2. If seen contains module, return true.
+        // 3. Append module to seen.
+        if !seen.insert(self.clone()) {
+            return true;
+        }
+
+        match self.kind() {
+            ModuleKind::SourceText(src) => src.ready_for_sync_execution(seen),
+            // 4. If module is not a Cyclic Module Record, return true.
+            ModuleKind::Synthetic(_) => true,
+        }
+    }
+
+    /// Abstract operation [`EnsureDeferredNamespaceEvaluation ( O )`][spec], starting from step 3.
+    ///
+    /// Synchronously evaluates this module for an access to its deferred namespace.
+    ///
+    /// [spec]: https://tc39.es/proposal-defer-import-eval/#sec-EnsureDeferredNamespaceEvaluation
+    pub(crate) fn ensure_deferred_evaluation(&self, context: &mut Context) -> JsResult<()> {
+        // 3. If m is a Cyclic Module Record, m.[[Status]] is not evaluated, and
+        //    ReadyForSyncExecution(m) is false, throw a TypeError exception.
+        if !self.ready_for_sync_execution(&mut FxHashSet::default()) {
+            return Err(JsNativeError::typ()
+                .with_message("cannot synchronously evaluate a deferred module")
+                .into());
+        }
+
+        // 4. Let promise be ! m.Evaluate().
+        let promise = self.evaluate(context);
+
+        match promise.state() {
+            // 5. Assert: promise.[[PromiseState]] is either fulfilled or rejected.
+            PromiseState::Pending => Err(JsNativeError::typ()
+                .with_message("cannot synchronously evaluate a deferred module")
+                .into()),
+            // 7. Return unused.
+            PromiseState::Fulfilled(_) => Ok(()),
+            // 6. If promise.[[PromiseState]] is rejected, then
+            //    a. Return ThrowCompletion(promise.[[PromiseResult]]).
+            PromiseState::Rejected(err) => Err(JsError::from_opaque(err)),
+        }
+    }
+
     /// Loads, links and evaluates this module, returning a promise that will resolve after the module
     /// finishes its lifecycle.
     ///
@@ -577,35 +671,55 @@ impl Module {
         // 2. Let namespace be module.[[Namespace]].
         // 3. If namespace is empty, then
         // 4. Return namespace.
-        self.inner
-            .namespace
-            .borrow_mut()
-            .get_or_insert_with(|| {
-                // a. Let exportedNames be module.GetExportedNames().
-                let exported_names =
-                    self.get_exported_names(&mut Vec::default(), context.interner());
-
-                // b. Let unambiguousNames be a new empty List.
-                let unambiguous_names = exported_names
-                    .into_iter()
-                    // c. For each element name of exportedNames, do
-                    .filter_map(|name| {
-                        // i. Let resolution be module.ResolveExport(name).
-                        // ii. If resolution is a ResolvedBinding Record, append name to unambiguousNames.
-                        self.resolve_export(
-                            name.clone(),
-                            &mut HashSet::default(),
-                            context.interner(),
-                        )
-                        .ok()
-                        .map(|_| name)
-                    })
-                    .collect();
-
-                //     d. Set namespace to ModuleNamespaceCreate(module, unambiguousNames).
-                ModuleNamespace::create(self.clone(), unambiguous_names, context)
+        if let Some(namespace) = &*self.inner.namespace.borrow() {
+            return namespace.clone();
+        }
+        let namespace = self.create_namespace(false, context);
+        *self.inner.namespace.borrow_mut() = Some(namespace.clone());
+        namespace
+    }
+
+    /// Abstract operation [`GetModuleNamespace ( module, phase )`][spec], with a `defer` phase.
+    ///
+    /// Gets the deferred [**Module Namespace Object**][ns] of this module, which evaluates the
+    /// module the first time one of its exports is accessed.
+    ///
+    /// [spec]: https://tc39.es/proposal-defer-import-eval/#sec-getmodulenamespace
+    /// [ns]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects
+    #[cfg(feature = "experimental")]
+    pub fn deferred_namespace(&self, context: &mut Context) -> JsObject {
+        // 3. If phase is defer, let namespace be module.[[DeferredNamespace]].
+        if let Some(namespace) = &*self.inner.deferred_namespace.borrow() {
+            return namespace.clone();
+        }
+        let namespace = self.create_namespace(true, context);
+        *self.inner.deferred_namespace.borrow_mut() = Some(namespace.clone());
+        namespace
+    }
+
+    /// Creates a new namespace object for this module, as done by the step 3 of
+    /// [`GetModuleNamespace`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getmodulenamespace
+    fn create_namespace(&self, deferred: bool, context: &mut Context) -> JsObject {
+        // a. Let exportedNames be module.GetExportedNames().
+        let exported_names = self.get_exported_names(&mut Vec::default(), context.interner());
+
+        // b. Let unambiguousNames be a new empty List.
+        let unambiguous_names = exported_names
+            .into_iter()
+            // c. For each element name of exportedNames, do
+            .filter_map(|name| {
+                // i. Let resolution be module.ResolveExport(name).
+                // ii. If resolution is a ResolvedBinding Record, append name to unambiguousNames.
+                self.resolve_export(name.clone(), &mut HashSet::default(), context.interner())
+                    .ok()
+                    .map(|_| name)
             })
-            .clone()
+            .collect();
+
+        // d. Set namespace to ModuleNamespaceCreate(module, unambiguousNames, phase).
+        ModuleNamespace::create(self.clone(), unambiguous_names, deferred, context)
     }
 
     /// Get an exported value from the module.
@@ -26,6 +26,7 @@ pub struct ModuleNamespace {
     module: Module,
     #[unsafe_ignore_trace]
     exports: IndexSet<JsString, BuildHasherDefault<FxHasher>>,
+    deferred: bool,
 }
 
 impl JsData for ModuleNamespace {
@@ -51,28 +52,49 @@ impl JsData for ModuleNamespace {
 }
 
 impl ModuleNamespace {
-    /// Abstract operation [`ModuleNamespaceCreate ( module, exports )`][spec].
+    /// Abstract operation [`ModuleNamespaceCreate ( module, exports, phase )`][spec].
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-modulenamespacecreate
-    pub(crate) fn create(module: Module, names: Vec<JsString>, context: &mut Context) -> JsObject {
+    /// A deferred namespace evaluates its module the first time one of its exports is accessed.
+    ///
+    /// [spec]: https://tc39.es/proposal-defer-import-eval/#sec-modulenamespacecreate
+    pub(crate) fn create(
+        module: Module,
+        names: Vec<JsString>,
+        deferred: bool,
+        context: &mut Context,
+    ) -> JsObject {
         // 1. Assert: module.[[Namespace]] is empty.
         // ignored since this is ensured by `Module::namespace`.
 
         // 6. Let sortedExports be a List whose elements are the elements of exports ordered as if an Array of the same values had been sorted using %Array.prototype.sort% using undefined as comparefn.
-        let mut exports = names.into_iter().collect::<IndexSet<_, _>>();
+        let mut exports = names
+            .into_iter()
+            // `then` is never exposed by deferred namespaces, to avoid evaluating the module
+            // when the namespace is awaited.
+            .filter(|name| !deferred || *name != "then")
+            .collect::<IndexSet<_, _>>();
         exports.sort();
 
+        let tag = if deferred {
+            js_string!("Deferred Module")
+        } else {
+            js_string!("Module")
+        };
+
         // 2. Let internalSlotsList be the internal slots listed in Table 32.
         // 3. Let M be MakeBasicObject(internalSlotsList).
         // 4. Set M's essential internal methods to the definitions specified in 10.4.6.
         // 5. Set M.[[Module]] to module.
         // 7. Set M.[[Exports]] to sortedExports.
         // 8. Create own properties of M corresponding to the definitions in 28.3.
-        let namespace = context
-            .intrinsics()
-            .templates()
-            .namespace()
-            .create(Self { module, exports }, vec![js_string!("Module").into()]);
+        let namespace = context.intrinsics().templates().namespace().create(
+            Self {
+                module,
+                exports,
+                deferred,
+            },
+            vec![tag.into()],
+        );
 
         // 9. Set module.[[Namespace]] to M.
         // Ignored because this is done by `Module::namespace`
@@ -90,6 +112,39 @@ impl ModuleNamespace {
     pub(crate) const fn module(&self) -> &Module {
         &self.module
     }
+
+    /// Returns `true` if this is a deferred Module Namespace object.
+    pub(crate) const fn is_deferred(&self) -> bool {
+        self.deferred
+    }
+}
+
+/// Abstract operation [`GetModuleExportsList ( O )`][spec], up to the evaluation of the module.
+///
+/// Evaluates the module of a deferred namespace before an access to the export `key`, or to all
+/// the exports if `key` is `None`. Does nothing for non-deferred namespaces.
+///
+/// [spec]: https://tc39.es/proposal-defer-import-eval/#sec-GetModuleExportsList
+fn ensure_deferred_evaluation(
+    obj: &JsObject,
+    key: Option<&JsString>,
+    context: &mut Context,
+) -> JsResult<()> {
+    let module = {
+        let obj = obj
+            .downcast_ref::<ModuleNamespace>()
+            .expect("internal method can only be called on module namespace objects");
+
+        // 1. If O.[[Deferred]] is true, perform ? EnsureDeferredNamespaceEvaluation(O).
+        // `IsSymbolLikeNamespaceKey ( P, O )`: `then` never triggers the evaluation of a deferred
+        // namespace.
+        if !obj.is_deferred() || key.is_some_and(|key| *key == "then") {
+            return Ok(());
+        }
+        obj.module().clone()
+    };
+
+    module.ensure_deferred_evaluation(context)
 }
 
 /// [`[[GetPrototypeOf]] ( )`][spec].
@@ -152,6 +207,8 @@ fn module_namespace_exotic_get_own_property(
         PropertyKey::String(s) => s.clone(),
     };
 
+    ensure_deferred_evaluation(obj, Some(&key), context)?;
+
     {
         let obj = obj
             .downcast_ref::<ModuleNamespace>()
@@ -231,6 +288,8 @@ fn module_namespace_exotic_has_property(
         PropertyKey::String(s) => s.clone(),
     };
 
+    ensure_deferred_evaluation(obj, Some(&key), context)?;
+
     let obj = obj
         .downcast_ref::<ModuleNamespace>()
         .expect("internal method can only be called on module namespace objects");
@@ -267,6 +326,8 @@ fn module_namespace_exotic_try_get(
         PropertyKey::String(s) => s.clone(),
     };
 
+    ensure_deferred_evaluation(obj, Some(&key), context)?;
+
     let obj = obj
         .downcast_ref::<ModuleNamespace>()
         .expect("internal method can only be called on module namespace objects");
@@ -348,6 +409,8 @@ fn module_namespace_exotic_get(
         PropertyKey::String(s) => s.clone(),
     };
 
+    ensure_deferred_evaluation(obj, Some(&key), context)?;
+
     let obj = obj
         .downcast_ref::<ModuleNamespace>()
         .expect("internal method can only be called on module namespace objects");
@@ -442,6 +505,8 @@ fn module_namespace_exotic_delete(
         PropertyKey::String(s) => s.clone(),
     };
 
+    ensure_deferred_evaluation(obj, Some(&key), context)?;
+
     let obj = obj
         .downcast_ref::<ModuleNamespace>()
         .expect("internal method can only be called on module namespace objects");
@@ -461,6 +526,9 @@ fn module_namespace_exotic_own_property_keys(
     obj: &JsObject,
     context: &mut Context,
 ) -> JsResult<Vec<PropertyKey>> {
+    // 1. Let exports be ? GetModuleExportsList(O).
+    ensure_deferred_evaluation(obj, None, context)?;
+
     // 2. Let symbolKeys be OrdinaryOwnPropertyKeys(O).
     let symbol_keys = ordinary_own_property_keys(obj, context)?;
 
@@ -468,7 +536,6 @@ fn module_namespace_exotic_own_property_keys(
         .downcast_ref::<ModuleNamespace>()
         .expect("internal method can only be called on module namespace objects");
 
-    // 1. Let exports be O.[[Exports]].
     let exports = obj.exports();
 
     // 3. Return the list-concatenation of exports and symbolKeys.
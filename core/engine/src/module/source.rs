@@ -2,7 +2,7 @@ use std::{cell::Cell, collections::HashSet, hash::BuildHasherDefault, rc::Rc};
 
 use boa_ast::{
     declaration::{
-        ExportEntry, ImportEntry, ImportName, IndirectExportEntry, LocalExportEntry,
+        ExportEntry, ImportEntry, ImportName, ImportPhase, IndirectExportEntry, LocalExportEntry,
         ReExportImportName,
     },
     operations::{
@@ -14,7 +14,7 @@ use boa_ast::{
 use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 use boa_interner::Interner;
 use boa_macros::js_str;
-use indexmap::IndexSet;
+use indexmap::IndexMap;
 use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 
 use crate::{
@@ -34,8 +34,8 @@ use crate::{
 };
 
 use super::{
-    BindingName, GraphLoadingState, Module, Referrer, ResolveExportError, ResolvedBinding,
-    SourceText,
+    BindingName, GraphLoadingState, Module, ModuleRequest, Referrer, ResolveExportError,
+    ResolvedBinding, SourceText,
 };
 
 /// Information for the [**Depth-first search**] algorithm used in the
@@ -211,7 +211,7 @@ impl std::fmt::Debug for SourceTextContext {
 #[derive(Trace, Finalize)]
 pub(crate) struct SourceTextModule {
     status: GcRefCell<ModuleStatus>,
-    loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
+    loaded_modules: GcRefCell<FxHashMap<ModuleRequest, Module>>,
     async_parent_modules: GcRefCell<Vec<Module>>,
     import_meta: GcRefCell<Option<JsObject>>,
     #[unsafe_ignore_trace]
@@ -232,13 +232,13 @@ impl std::fmt::Debug for SourceTextModule {
 #[derive(Debug)]
 struct ModuleCode {
     has_tla: bool,
-    requested_modules: IndexSet<JsString, BuildHasherDefault<FxHasher>>,
+    requested_modules: IndexMap<ModuleRequest, ImportPhase, BuildHasherDefault<FxHasher>>,
     source: boa_ast::Module,
     source_text: SourceText,
     import_entries: Vec<ImportEntry>,
     local_export_entries: Vec<LocalExportEntry>,
     indirect_export_entries: Vec<IndirectExportEntry>,
-    star_export_entries: Vec<JsString>,
+    star_export_entries: Vec<ModuleRequest>,
 }
 
 impl SourceTextModule {
@@ -249,12 +249,19 @@ impl SourceTextModule {
     /// [parse]: https://tc39.es/ecma262/#sec-parsemodule
     pub(super) fn new(code: boa_ast::Module, interner: &Interner, source_text: SourceText) -> Self {
         // 3. Let requestedModules be the ModuleRequests of body.
-        let requested_modules = code
-            .items()
-            .requests()
-            .iter()
-            .map(|name| name.to_js_string(interner))
-            .collect();
+        let mut requested_modules = IndexMap::<_, _, BuildHasherDefault<FxHasher>>::default();
+        for request in code.items().requests() {
+            let phase = request.phase();
+            requested_modules
+                .entry(ModuleRequest::from_ast(&request, interner))
+                // A module that is both imported eagerly and deferred is evaluated eagerly.
+                .and_modify(|current| {
+                    if phase == ImportPhase::Evaluation {
+                        *current = phase;
+                    }
+                })
+                .or_insert(phase);
+        }
         // 4. Let importEntries be ImportEntries of body.
         let import_entries = code.items().import_entries();
 
@@ -278,7 +285,7 @@ impl SourceTextModule {
                     if let Some((module, import)) =
                         import_entries.iter().find_map(|ie| match ie.import_name() {
                             ImportName::Name(name) if ie.local_name() == entry.local_name() => {
-                                Some((ie.module_request(), name))
+                                Some((ie.module_request().clone(), name))
                             }
                             _ => None,
                         })
@@ -307,7 +314,7 @@ impl SourceTextModule {
                 ExportEntry::StarReExport { module_request } => {
                     // i. Assert: ee.[[ExportName]] is null.
                     // ii. Append ee to starExportEntries.
-                    star_export_entries.push(module_request.to_js_string(interner));
+                    star_export_entries.push(ModuleRequest::from_ast(&module_request, interner));
                 }
                 // c. Else,
                 //    i. Append ee to indirectExportEntries.
@@ -371,7 +378,7 @@ impl SourceTextModule {
                 .pending_modules
                 .set(state.pending_modules.get() + requested.len());
             // d. For each String required of module.[[RequestedModules]], do
-            for required in requested.iter().cloned() {
+            for required in requested.keys().cloned() {
                 // i. If module.[[LoadedModules]] contains a Record whose [[Specifier]] is required, then
                 let loaded = self.loaded_modules.borrow().get(&required).cloned();
                 if let Some(loaded) = loaded {
@@ -564,7 +571,7 @@ impl SourceTextModule {
             // a. If SameValue(exportName, e.[[ExportName]]) is true, then
             if export_name == &e.export_name().to_js_string(interner) {
                 // i. Let importedModule be GetImportedModule(module, e.[[ModuleRequest]]).
-                let module_request = e.module_request().to_js_string(interner);
+                let module_request = ModuleRequest::from_ast(e.module_request(), interner);
                 let imported_module = self.loaded_modules.borrow()[&module_request].clone();
                 return match e.import_name() {
                     // ii. If e.[[ImportName]] is all, then
@@ -739,7 +746,7 @@ impl SourceTextModule {
 
         // 9. For each String required of module.[[RequestedModules]], do
 
-        for required in &self.code.requested_modules {
+        for required in self.code.requested_modules.keys() {
             // a. Let requiredModule be GetImportedModule(module, required).
             let required_module = self.loaded_modules.borrow()[required].clone();
 
@@ -1067,14 +1074,38 @@ impl SourceTextModule {
         // 10. Append module to stack.
         stack.push(module_self.clone());
 
-        // 11. For each String required of module.[[RequestedModules]], do
-        for required in &self.code.requested_modules {
+        // 11. Let requiredModules be a new empty List.
+        let mut required_modules = Vec::new();
+        // 12. For each ModuleRequest Record required of module.[[RequestedModules]], do
+        for (required, phase) in &self.code.requested_modules {
             // a. Let requiredModule be GetImportedModule(module, required).
             let required_module = self.loaded_modules.borrow()[required].clone();
-            // b. Set index to ? InnerModuleEvaluation(requiredModule, stack, index).
+            // b. If required.[[Phase]] is defer, then
+            if cfg!(feature = "experimental") && *phase == ImportPhase::Defer {
+                // i. Let additionalModules be GatherAsynchronousTransitiveDependencies(requiredModule).
+                let additional_modules =
+                    required_module.gather_async_transitive_dependencies(&mut FxHashSet::default());
+                // ii. For each Module Record additionalModule of additionalModules, do
+                for additional_module in additional_modules {
+                    // 1. If requiredModules does not contain additionalModule, then
+                    //    a. Append additionalModule to requiredModules.
+                    if !required_modules.contains(&additional_module) {
+                        required_modules.push(additional_module);
+                    }
+                }
+            // c. Else if requiredModules does not contain requiredModule, then
+            } else if !required_modules.contains(&required_module) {
+                // i. Append requiredModule to requiredModules.
+                required_modules.push(required_module);
+            }
+        }
+
+        // 13. For each Module Record requiredModule of requiredModules, do
+        for required_module in required_modules {
+            // a. Set index to ? InnerModuleEvaluation(requiredModule, stack, index).
             index = required_module.inner_evaluate(stack, index, context)?;
 
-            // c. If requiredModule is a Cyclic Module Record, then
+            // b. If requiredModule is a Cyclic Module Record, then
             if let ModuleKind::SourceText(required_module_src) = required_module.kind() {
                 // i. Assert: requiredModule.[[Status]] is one of evaluating, evaluating-async, or evaluated.
                 // ii. Assert: requiredModule.[[Status]] is evaluating if and only if stack contains requiredModule.
@@ -1140,7 +1171,7 @@ impl SourceTextModule {
             }
         }
 
-        // 12. If module.[[PendingAsyncDependencies]] > 0 or module.[[HasTLA]] is true, then
+        // 14. If module.[[PendingAsyncDependencies]] > 0 or module.[[HasTLA]] is true, then
         if pending_async_dependencies > 0 || self.code.has_tla {
             // a. Assert: module.[[AsyncEvaluation]] is false and was never previously set to true.
             {
@@ -1161,7 +1192,7 @@ impl SourceTextModule {
                 self.execute_async(module_self, context);
             }
         } else {
-            // 13. Else,
+            // 15. Else,
             //    a. Perform ? module.ExecuteModule().
             self.execute(module_self, None, context)?;
         }
@@ -1170,12 +1201,12 @@ impl SourceTextModule {
             "haven't transitioned from the `Evaluating` state, so it should have its dfs info",
         );
 
-        // 14. Assert: module occurs exactly once in stack.
+        // 16. Assert: module occurs exactly once in stack.
         debug_assert_eq!(stack.iter().filter(|m| *m == module_self).count(), 1);
-        // 15. Assert: module.[[DFSAncestorIndex]] ≤ module.[[DFSIndex]].
+        // 17. Assert: module.[[DFSAncestorIndex]] ≤ module.[[DFSIndex]].
         assert!(dfs_info.dfs_ancestor_index <= dfs_info.dfs_index);
 
-        // 16. If module.[[DFSAncestorIndex]] = module.[[DFSIndex]], then
+        // 18. If module.[[DFSAncestorIndex]] = module.[[DFSIndex]], then
         if dfs_info.dfs_ancestor_index == dfs_info.dfs_index {
             // a. Let done be false.
             // b. Repeat, while done is false,
@@ -1239,10 +1270,98 @@ impl SourceTextModule {
             }
         }
 
-        // 17. Return index.
+        // 19. Return index.
         Ok(index)
     }
 
+    /// Abstract operation [`GatherAsynchronousTransitiveDependencies ( module [ , seen ] )`][spec],
+    /// starting from step 6.
+    ///
+    /// [spec]: https://tc39.es/proposal-defer-import-eval/#sec-GatherAsynchronousTransitiveDependencies
+    #[allow(clippy::mutable_key_type)]
+    pub(super) fn gather_async_transitive_dependencies(
+        &self,
+        module_self: &Module,
+        seen: &mut FxHashSet<Module>,
+    ) -> Vec<Module> {
+        // 2. Let result be a new empty List.
+        let mut result = Vec::new();
+
+        match &*self.status.borrow() {
+            // 6. If module.[[Status]] is either evaluating or evaluated, return result.
+            ModuleStatus::Evaluating { .. } | ModuleStatus::Evaluated { .. } => return result,
+            // Modules that are already being evaluated asynchronously must also be waited on.
+            ModuleStatus::EvaluatingAsync { .. } => {
+                result.push(module_self.clone());
+                return result;
+            }
+            ModuleStatus::Linked { .. } => {}
+            _ => unreachable!("deferred modules must be linked before being evaluated"),
+        }
+
+        // 7. If module.[[HasTLA]] is true, then
+        if self.code.has_tla {
+            // a. Append module to result.
+            // b. Return result.
+            result.push(module_self.clone());
+            return result;
+        }
+
+        // 8. For each ModuleRequest Record required of module.[[RequestedModules]], do
+        for required in self.code.requested_modules.keys() {
+            // a. Let requiredModule be GetImportedModule(module, required).
+            let required_module = self.loaded_modules.borrow()[required].clone();
+            // b. Let additionalModules be GatherAsynchronousTransitiveDependencies(requiredModule, seen).
+            let additional_modules = required_module.gather_async_transitive_dependencies(seen);
+            // c. For each Module Record m of additionalModules, do
+            for m in additional_modules {
+                // i. If result does not contain m, append m to result.
+                if !result.contains(&m) {
+                    result.push(m);
+                }
+            }
+        }
+
+        // 9. Return result.
+        result
+    }
+
+    /// Abstract operation [`ReadyForSyncExecution ( module [ , seen ] )`][spec], starting from
+    /// step 5.
+    ///
+    /// [spec]: https://tc39.es/proposal-defer-import-eval/#sec-ReadyForSyncExecution
+    #[allow(clippy::mutable_key_type)]
+    pub(super) fn ready_for_sync_execution(&self, seen: &mut FxHashSet<Module>) -> bool {
+        match &*self.status.borrow() {
+            // 5. If module.[[Status]] is evaluated, return true.
+            ModuleStatus::Evaluated { .. } => return true,
+            // 6. If module.[[Status]] is either evaluating or evaluating-async, return false.
+            ModuleStatus::Evaluating { .. } | ModuleStatus::EvaluatingAsync { .. } => return false,
+            // 7. Assert: module.[[Status]] is linked.
+            ModuleStatus::Linked { .. } => {}
+            _ => unreachable!("7. Assert: module.[[Status]] is linked."),
+        }
+
+        // 8. If module.[[HasTLA]] is true, return false.
+        if self.code.has_tla {
+            return false;
+        }
+
+        // 9. For each ModuleRequest Record required of module.[[RequestedModules]], do
+        for required in self.code.requested_modules.keys() {
+            // a. Let requiredModule be GetImportedModule(module, required).
+            let required_module = self.loaded_modules.borrow()[required].clone();
+            // b. If ReadyForSyncExecution(requiredModule, seen) is false, then
+            if !required_module.ready_for_sync_execution(seen) {
+                // i. Return false.
+                return false;
+            }
+        }
+
+        // 10. Return true.
+        true
+    }
+
     /// Abstract operation [`ExecuteAsyncModule ( module )`][spec].
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-execute-async-module
@@ -1382,6 +1501,7 @@ impl SourceTextModule {
             Namespace {
                 locator: BindingLocator,
                 module: Module,
+                deferred: bool,
             },
             Single {
                 locator: BindingLocator,
@@ -1450,7 +1570,8 @@ impl SourceTextModule {
             // 7. For each ImportEntry Record in of module.[[ImportEntries]], do
             for entry in &self.code.import_entries {
                 // a. Let importedModule be GetImportedModule(module, in.[[ModuleRequest]]).
-                let module_request = entry.module_request().to_js_string(compiler.interner());
+                let module_request =
+                    ModuleRequest::from_ast(entry.module_request(), compiler.interner());
                 let imported_module = self.loaded_modules.borrow()[&module_request].clone();
 
                 if let ImportName::Name(name) = entry.import_name() {
@@ -1491,6 +1612,7 @@ impl SourceTextModule {
                         imports.push(ImportBinding::Namespace {
                             locator,
                             module: resolution.module,
+                            deferred: false,
                         });
                     }
                 } else {
@@ -1500,11 +1622,12 @@ impl SourceTextModule {
                     let name = entry.local_name().to_js_string(compiler.interner());
                     let locator = env.get_binding(&name).expect("binding must exist");
 
-                    //    i. Let namespace be GetModuleNamespace(importedModule).
+                    //    i. Let namespace be GetModuleNamespace(importedModule, in.[[ModuleRequest]].[[Phase]]).
                     //       deferred to initialization below
                     imports.push(ImportBinding::Namespace {
                         locator,
                         module: imported_module.clone(),
+                        deferred: entry.module_request().phase() == ImportPhase::Defer,
                     });
                 }
             }
@@ -1632,9 +1755,17 @@ impl SourceTextModule {
         // deferred initialization of import bindings
         for import in imports {
             match import {
-                ImportBinding::Namespace { locator, module } => {
-                    // i. Let namespace be GetModuleNamespace(importedModule).
-                    let namespace = module.namespace(context);
+                ImportBinding::Namespace {
+                    locator,
+                    module,
+                    deferred,
+                } => {
+                    // i. Let namespace be GetModuleNamespace(importedModule, phase).
+                    let namespace = match deferred {
+                        #[cfg(feature = "experimental")]
+                        true => module.deferred_namespace(context),
+                        _ => module.namespace(context),
+                    };
                     context.vm.environments.put_lexical_value(
                         locator.scope(),
                         locator.binding_index(),
@@ -1781,7 +1912,7 @@ impl SourceTextModule {
     }
 
     /// Gets the loaded modules of this module.
-    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<JsString, Module>> {
+    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<ModuleRequest, Module>> {
         &self.loaded_modules
     }
 
@@ -7,6 +7,7 @@ use crate::{
 };
 
 use boa_gc::{Finalize, Trace};
+use half::f16;
 use std::ops::Deref;
 
 /// `JsDataView` provides a wrapper for Boa's implementation of the ECMAScript `DataView` object
@@ -214,6 +215,22 @@ impl JsDataView {
         .map(|v| v.as_number().expect("value must be a number") as u64)
     }
 
+    /// Returns a signed 16-bit float integer at the specified offset from the start of the [`JsDataView`]
+    #[inline]
+    pub fn get_float16(
+        &self,
+        byte_offset: usize,
+        is_little_endian: bool,
+        context: &mut Context,
+    ) -> JsResult<f16> {
+        DataView::get_float16(
+            &self.inner.clone().upcast().into(),
+            &[byte_offset.into(), is_little_endian.into()],
+            context,
+        )
+        .map(|v| f16::from_f64(v.as_number().expect("value must be a number")))
+    }
+
     /// Returns a signed 32-bit float integer at the specified offset from the start of the [`JsDataView`]
     #[inline]
     pub fn get_float32(
@@ -374,6 +391,26 @@ impl JsDataView {
         )
     }
 
+    /// Sets a signed 16-bit float at the specified offset from the start of the [`JsDataView`]
+    #[inline]
+    pub fn set_float16(
+        &self,
+        byte_offset: usize,
+        value: f16,
+        is_little_endian: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        DataView::set_float16(
+            &self.inner.clone().upcast().into(),
+            &[
+                byte_offset.into(),
+                f64::from(value).into(),
+                is_little_endian.into(),
+            ],
+            context,
+        )
+    }
+
     /// Sets a signed 32-bit integer at the specified offset from the start of the [`JsDataView`]
     #[inline]
     pub fn set_float32(
@@ -334,6 +334,85 @@ impl JsMap {
         Map::get(&self.inner.clone().into(), &[key.into()], context)
     }
 
+    /// Gets the value associated with the specified key within the [`JsMap`], inserting `value`
+    /// if the key does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let js_map = JsMap::new(context);
+    /// js_map.set(js_string!("foo"), js_string!("bar"), context)?;
+    ///
+    /// let existing = js_map.get_or_insert(js_string!("foo"), js_string!("baz"), context)?;
+    /// let inserted = js_map.get_or_insert(js_string!("hello"), js_string!("world"), context)?;
+    ///
+    /// assert_eq!(existing, js_string!("bar").into());
+    /// assert_eq!(inserted, js_string!("world").into());
+    /// assert_eq!(js_map.get_size(context)?, 2.into());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "experimental")]
+    pub fn get_or_insert<K, V>(&self, key: K, value: V, context: &mut Context) -> JsResult<JsValue>
+    where
+        K: Into<JsValue>,
+        V: Into<JsValue>,
+    {
+        Map::get_or_insert(
+            &self.inner.clone().into(),
+            &[key.into(), value.into()],
+            context,
+        )
+    }
+
+    /// Gets the value associated with the specified key within the [`JsMap`], inserting the
+    /// result of calling `callback` with the key if the key does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, NativeFunction, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let js_map = JsMap::new(context);
+    /// let callback = NativeFunction::from_fn_ptr(|_, args, context| {
+    ///     args[0].to_number(context).map(|n| JsValue::new(n * 2.0))
+    /// })
+    /// .to_js_function(context.realm());
+    ///
+    /// let computed = js_map.get_or_insert_computed(21, callback, context)?;
+    ///
+    /// assert_eq!(computed, 42.into());
+    /// assert_eq!(js_map.get(21, context)?, 42.into());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "experimental")]
+    pub fn get_or_insert_computed<T>(
+        &self,
+        key: T,
+        callback: JsFunction,
+        context: &mut Context,
+    ) -> JsResult<JsValue>
+    where
+        T: Into<JsValue>,
+    {
+        Map::get_or_insert_computed(
+            &self.inner.clone().into(),
+            &[key.into(), callback.into()],
+            context,
+        )
+    }
+
     /// Removes all entries from the [`JsMap`].
     ///
     /// # Example
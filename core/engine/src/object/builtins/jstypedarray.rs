@@ -1,13 +1,17 @@
 //! Rust API wrappers for the `TypedArray` Builtin ECMAScript Objects
 use crate::{
     builtins::typed_array::BuiltinTypedArray,
-    builtins::{typed_array::TypedArray, BuiltInConstructor},
+    builtins::{
+        typed_array::{Base64Alphabet, LastChunkHandling, TypedArray, Uint8Array},
+        BuiltInConstructor,
+    },
     error::JsNativeError,
     object::{JsArrayBuffer, JsFunction, JsObject},
     value::{IntoOrUndefined, TryFromJs},
     Context, JsResult, JsString, JsValue,
 };
-use boa_gc::{Finalize, Trace};
+use boa_gc::{Finalize, GcRef, Trace};
+use half::f16;
 use std::ops::Deref;
 
 /// `JsTypedArray` provides a wrapper for Boa's implementation of the ECMAScript `TypedArray`
@@ -1087,6 +1091,74 @@ JsTypedArrayType!(
     to_uint8,
     u8
 );
+impl JsUint8Array {
+    /// Creates a `JsUint8Array` from the bytes encoded in a base64 string.
+    ///
+    /// Same as `Uint8Array.fromBase64(string, { alphabet, lastChunkHandling })` in JavaScript.
+    pub fn from_base64(
+        string: &JsString,
+        alphabet: Base64Alphabet,
+        last_chunk_handling: LastChunkHandling,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        Uint8Array::create_from_base64(string, alphabet, last_chunk_handling, context)
+            .and_then(Self::from_object)
+    }
+
+    /// Creates a `JsUint8Array` from the bytes encoded in a hex string.
+    ///
+    /// Same as `Uint8Array.fromHex(string)` in JavaScript.
+    pub fn from_hex(string: &JsString, context: &mut Context) -> JsResult<Self> {
+        Uint8Array::create_from_hex(string, context).and_then(Self::from_object)
+    }
+
+    /// Encodes the bytes of the array as a base64 string.
+    ///
+    /// Same as `array.toBase64({ alphabet, omitPadding })` in JavaScript.
+    pub fn to_base64(&self, alphabet: Base64Alphabet, omit_padding: bool) -> JsResult<JsString> {
+        let array = self.typed_array();
+        Uint8Array::encode_to_base64(&array, alphabet, omit_padding)
+    }
+
+    /// Encodes the bytes of the array as a hex string.
+    ///
+    /// Same as `array.toHex()` in JavaScript.
+    pub fn to_hex(&self) -> JsResult<JsString> {
+        let array = self.typed_array();
+        Uint8Array::encode_to_hex(&array)
+    }
+
+    /// Decodes a base64 string into the array, returning the number of code units read from
+    /// `string` and the number of bytes written into the array.
+    ///
+    /// Same as `array.setFromBase64(string, { alphabet, lastChunkHandling })` in JavaScript.
+    pub fn set_from_base64(
+        &self,
+        string: &JsString,
+        alphabet: Base64Alphabet,
+        last_chunk_handling: LastChunkHandling,
+    ) -> JsResult<(usize, usize)> {
+        let array = self.typed_array();
+        Uint8Array::decode_base64_into(&array, string, alphabet, last_chunk_handling)
+    }
+
+    /// Decodes a hex string into the array, returning the number of code units read from
+    /// `string` and the number of bytes written into the array.
+    ///
+    /// Same as `array.setFromHex(string)` in JavaScript.
+    pub fn set_from_hex(&self, string: &JsString) -> JsResult<(usize, usize)> {
+        let array = self.typed_array();
+        Uint8Array::decode_hex_into(&array, string)
+    }
+
+    fn typed_array(&self) -> GcRef<'_, TypedArray> {
+        self.inner
+            .inner
+            .downcast_ref::<TypedArray>()
+            .expect("a JsUint8Array must be a typed array")
+    }
+}
+
 JsTypedArrayType!(
     JsInt8Array,
     Int8Array,
@@ -1127,6 +1199,14 @@ JsTypedArrayType!(
     to_i32,
     i32
 );
+JsTypedArrayType!(
+    JsFloat16Array,
+    Float16Array,
+    is_typed_float16_array,
+    typed_float16_array,
+    to_f16,
+    f16
+);
 JsTypedArrayType!(
     JsFloat32Array,
     Float32Array,
@@ -1154,6 +1234,40 @@ fn typed_iterators_uint8() {
     assert_eq!(vec, vec2);
 }
 
+#[test]
+fn uint8_array_base64_and_hex() {
+    let context = &mut Context::default();
+
+    let array = JsUint8Array::from_base64(
+        &JsString::from("SGVsbG8"),
+        Base64Alphabet::Base64,
+        LastChunkHandling::Loose,
+        context,
+    )
+    .unwrap();
+    assert_eq!(array.iter(context).collect::<Vec<_>>(), b"Hello");
+    assert_eq!(
+        array.to_base64(Base64Alphabet::Base64, false).unwrap(),
+        JsString::from("SGVsbG8=")
+    );
+    assert_eq!(array.to_hex().unwrap(), JsString::from("48656c6c6f"));
+
+    assert_eq!(array.set_from_hex(&JsString::from("cafe")).unwrap(), (4, 2));
+    assert_eq!(
+        array
+            .set_from_base64(
+                &JsString::from("__8"),
+                Base64Alphabet::Base64Url,
+                LastChunkHandling::Loose,
+            )
+            .unwrap(),
+        (3, 2)
+    );
+    assert_eq!(array.to_hex().unwrap(), JsString::from("ffff6c6c6f"));
+
+    assert!(JsUint8Array::from_hex(&JsString::from("abc"), context).is_err());
+}
+
 #[test]
 fn typed_iterators_uint32() {
     let context = &mut Context::default();
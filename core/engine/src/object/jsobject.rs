@@ -11,6 +11,8 @@ use super::{
     shape::RootShape,
     JsPrototype, NativeObject, Object, PrivateName, PropertyMap,
 };
+#[cfg(feature = "annex-b")]
+use crate::native_function::{native_function_call, NativeFunction, NativeFunctionObject};
 use crate::{
     builtins::{
         array::ARRAY_EXOTIC_INTERNAL_METHODS,
@@ -75,6 +77,42 @@ pub(crate) struct VTableObject<T: NativeObject + ?Sized> {
     object: GcRefCell<Object<T>>,
 }
 
+/// The internal methods of objects with an `[[IsHTMLDDA]]` internal slot.
+#[cfg(feature = "annex-b")]
+static HTML_DDA_INTERNAL_METHODS: InternalObjectMethods = InternalObjectMethods {
+    __call__: native_function_call,
+    ..ORDINARY_INTERNAL_METHODS
+};
+
+#[cfg(feature = "annex-b")]
+impl JsObject {
+    /// Creates a new callable object with an [`[[IsHTMLDDA]]`][spec] internal slot, which calls
+    /// `function` when called.
+    ///
+    /// Objects with this slot emulate the legacy behaviour of `document.all`: `typeof` returns
+    /// `"undefined"` for them, `ToBoolean` converts them to `false`, and they are loosely equal
+    /// to `null` and `undefined`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-IsHTMLDDA-internal-slot
+    #[must_use]
+    pub fn new_html_dda(function: NativeFunction, context: &Context) -> Self {
+        let prototype = context.intrinsics().constructors().function().prototype();
+        Self::from_object_and_vtable(
+            Object {
+                properties: PropertyMap::from_prototype_unique_shape(Some(prototype)),
+                extensible: true,
+                private_elements: ThinVec::new(),
+                data: NativeFunctionObject {
+                    f: function,
+                    constructor: None,
+                    realm: Some(context.realm().clone()),
+                },
+            },
+            &HTML_DDA_INTERNAL_METHODS,
+        )
+    }
+}
+
 impl Default for JsObject {
     fn default() -> Self {
         Self::from_proto_and_data(None, OrdinaryObject)
@@ -288,6 +326,18 @@ impl JsObject {
         std::ptr::eq(self.vtable(), &raw const ARRAY_EXOTIC_INTERNAL_METHODS)
     }
 
+    /// Checks if the object has an [`[[IsHTMLDDA]]`][spec] internal slot.
+    ///
+    /// See [`JsObject::new_html_dda`] for more information.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-IsHTMLDDA-internal-slot
+    #[cfg(feature = "annex-b")]
+    #[inline]
+    #[must_use]
+    pub fn is_html_dda(&self) -> bool {
+        std::ptr::eq(self.vtable(), &raw const HTML_DDA_INTERNAL_METHODS)
+    }
+
     /// The inner implementation of `deep_strict_equals`, which keeps a list of values we've
     /// seen to avoid recursive objects.
     pub(crate) fn deep_strict_equals_inner(
@@ -209,6 +209,11 @@ impl PrivateName {
     pub(crate) const fn new(description: JsString, id: usize) -> Self {
         Self { description, id }
     }
+
+    /// Returns the `[[Description]]` of the private name.
+    pub(crate) const fn description(&self) -> &JsString {
+        &self.description
+    }
 }
 
 /// The representation of private object elements.
@@ -308,6 +313,11 @@ impl<T: ?Sized> Object<T> {
         self.properties.remove(key)
     }
 
+    /// Returns a mutable reference to the private elements of the object.
+    pub(crate) fn private_elements_mut(&mut self) -> &mut [(PrivateName, PrivateElement)] {
+        &mut self.private_elements
+    }
+
     /// Append a private element to an object.
     pub(crate) fn append_private_element(&mut self, name: PrivateName, element: PrivateElement) {
         if let PrivateElement::Accessor { getter, setter } = &element {
@@ -425,6 +435,17 @@ impl Object<dyn NativeObject> {
         }
     }
 
+    /// Checks if it a `Float16Array` object.
+    #[inline]
+    #[must_use]
+    pub fn is_typed_float16_array(&self) -> bool {
+        if let Some(int) = self.downcast_ref::<TypedArray>() {
+            matches!(int.kind(), TypedArrayKind::Float16)
+        } else {
+            false
+        }
+    }
+
     /// Checks if it a `Float32Array` object.
     #[inline]
     #[must_use]
@@ -1,4 +1,6 @@
 use super::internal_methods::InternalMethodContext;
+#[cfg(feature = "experimental")]
+use crate::builtins::shadow_realm::WrappedFunction;
 use crate::value::JsVariant;
 use crate::{
     builtins::{
@@ -830,6 +832,11 @@ impl JsObject {
             return fun.get_function_realm(context);
         }
 
+        #[cfg(feature = "experimental")]
+        if let Some(wrapped) = constructor.downcast_ref::<WrappedFunction>() {
+            return Ok(wrapped.realm().clone());
+        }
+
         Ok(context.realm().clone())
     }
 
@@ -1076,42 +1083,69 @@ impl JsObject {
         field_record: &ClassFieldDefinition,
         context: &mut Context,
     ) -> JsResult<()> {
+        let (field_record, initializers, extra_initializers) = match field_record {
+            ClassFieldDefinition::Decorated {
+                field,
+                initializers,
+                extra_initializers,
+            } => (&**field, &initializers[..], &extra_initializers[..]),
+            _ => (field_record, &[][..], &[][..]),
+        };
+
         // 2. Let initializer be fieldRecord.[[Initializer]].
         let initializer = match field_record {
             ClassFieldDefinition::Public(_, function, _)
             | ClassFieldDefinition::Private(_, function) => function,
+            ClassFieldDefinition::Decorated { .. } => {
+                unreachable!("decorated field definitions cannot be nested")
+            }
         };
 
         // 3. If initializer is not empty, then
-        // a. Let initValue be ? Call(initializer, receiver).
+        // a. Let initValue be ? Call(initializer, receiver).
         // 4. Else, let initValue be undefined.
-        let init_value = initializer.call(&self.clone().into(), &[], context)?;
+        let mut init_value = initializer.call(&self.clone().into(), &[], context)?;
+
+        if let ClassFieldDefinition::Public(_, _, Some(function_name)) = field_record {
+            set_function_name(
+                init_value
+                    .as_object()
+                    .expect("init value must be a function object"),
+                function_name,
+                None,
+                context,
+            );
+        }
+
+        // Decorators proposal: for each element initializer of fieldRecord.[[Initializers]], do
+        //   a. Set initValue to ? Call(initializer, receiver, « initValue »).
+        for initializer in initializers {
+            init_value = initializer.call(&self.clone().into(), &[init_value], context)?;
+        }
 
         match field_record {
             // 1. Let fieldName be fieldRecord.[[Name]].
             // 5. If fieldName is a Private Name, then
             ClassFieldDefinition::Private(field_name, _) => {
-                // a. Perform ? PrivateFieldAdd(receiver, fieldName, initValue).
+                // a. Perform ? PrivateFieldAdd(receiver, fieldName, initValue).
                 self.private_field_add(field_name, init_value, context)?;
             }
             // 1. Let fieldName be fieldRecord.[[Name]].
             // 6. Else,
-            ClassFieldDefinition::Public(field_name, _, function_name) => {
-                if let Some(function_name) = function_name {
-                    set_function_name(
-                        init_value
-                            .as_object()
-                            .expect("init value must be a function object"),
-                        function_name,
-                        None,
-                        context,
-                    );
-                }
-
+            ClassFieldDefinition::Public(field_name, _, _) => {
                 // a. Assert: IsPropertyKey(fieldName) is true.
-                // b. Perform ? CreateDataPropertyOrThrow(receiver, fieldName, initValue).
+                // b. Perform ? CreateDataPropertyOrThrow(receiver, fieldName, initValue).
                 self.create_data_property_or_throw(field_name.clone(), init_value, context)?;
             }
+            ClassFieldDefinition::Decorated { .. } => {
+                unreachable!("decorated field definitions cannot be nested")
+            }
+        }
+
+        // Decorators proposal: for each element initializer of fieldRecord.[[ExtraInitializers]], do
+        //   a. Perform ? Call(initializer, receiver).
+        for initializer in extra_initializers {
+            initializer.call(&self.clone().into(), &[], context)?;
         }
 
         // 7. Return unused.
@@ -1142,6 +1176,12 @@ impl JsObject {
             self.private_method_or_accessor_add(name, method, context)?;
         }
 
+        // Decorators proposal: for each element initializer of constructor.[[Initializers]], do
+        //   a. Perform ? Call(initializer, O).
+        for initializer in constructor_function.get_initializers() {
+            initializer.call(&self.clone().into(), &[], context)?;
+        }
+
         // 3. Let fields be the value of constructor.[[Fields]].
         // 4. For each element fieldRecord of fields, do
         for field_record in constructor_function.get_fields() {
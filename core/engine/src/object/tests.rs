@@ -38,3 +38,31 @@ fn object_properties_return_order() {
         ),
     ]);
 }
+
+#[cfg(feature = "annex-b")]
+#[test]
+fn html_dda_object() {
+    use crate::{js_string, native_function::NativeFunction, property::Attribute, JsObject};
+    use boa_macros::js_str;
+
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            let all = JsObject::new_html_dda(
+                NativeFunction::from_fn_ptr(|_, args, _| Ok(args.len().into())),
+                context,
+            );
+            assert!(all.is_html_dda());
+            context
+                .register_global_property(js_string!("all"), all, Attribute::all())
+                .unwrap();
+        }),
+        TestAction::assert_eq("typeof all", js_str!("undefined")),
+        TestAction::assert("!all"),
+        TestAction::assert_eq("all ? 1 : 2", 2),
+        TestAction::assert("all == null && undefined == all && all != 0"),
+        TestAction::assert("all !== undefined && all !== null"),
+        TestAction::assert_eq("all(1, 2)", 2),
+        TestAction::assert("(all ?? 1) === all"),
+        TestAction::assert("all instanceof Function"),
+    ]);
+}
@@ -11,6 +11,8 @@ use std::any::TypeId;
 use boa_ast::scope::Scope;
 use rustc_hash::FxHashMap;
 
+#[cfg(feature = "annex-b")]
+use crate::builtins::regexp::LegacyRegExpStatics;
 use crate::{
     class::Class,
     context::{
@@ -18,9 +20,9 @@ use crate::{
         HostHooks,
     },
     environments::DeclarativeEnvironment,
-    module::Module,
+    module::{Module, ModuleRequest},
     object::shape::RootShape,
-    HostDefined, JsNativeError, JsObject, JsResult, JsString,
+    HostDefined, JsNativeError, JsObject, JsResult,
 };
 use boa_gc::{Finalize, Gc, GcRef, GcRefCell, GcRefMut, Trace};
 
@@ -67,10 +69,14 @@ struct Inner {
     global_object: JsObject,
     global_this: JsObject,
     template_map: GcRefCell<FxHashMap<u64, JsObject>>,
-    loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
+    loaded_modules: GcRefCell<FxHashMap<ModuleRequest, Module>>,
     host_classes: GcRefCell<FxHashMap<TypeId, StandardConstructor>>,
 
     host_defined: GcRefCell<HostDefined>,
+
+    /// The legacy static properties of `%RegExp%`.
+    #[cfg(feature = "annex-b")]
+    regexp_legacy_statics: GcRefCell<LegacyRegExpStatics>,
 }
 
 impl Realm {
@@ -99,6 +105,8 @@ impl Realm {
                 loaded_modules: GcRefCell::default(),
                 host_classes: GcRefCell::default(),
                 host_defined: GcRefCell::default(),
+                #[cfg(feature = "annex-b")]
+                regexp_legacy_statics: GcRefCell::default(),
             }),
         };
 
@@ -179,7 +187,7 @@ impl Realm {
         &self.inner.global_this
     }
 
-    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<JsString, Module>> {
+    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<ModuleRequest, Module>> {
         &self.inner.loaded_modules
     }
 
@@ -199,6 +207,12 @@ impl Realm {
         }
     }
 
+    /// Returns the legacy static properties of `%RegExp%`.
+    #[cfg(feature = "annex-b")]
+    pub(crate) fn regexp_legacy_statics(&self) -> &GcRefCell<LegacyRegExpStatics> {
+        &self.inner.regexp_legacy_statics
+    }
+
     pub(crate) fn push_template(&self, site: u64, template: JsObject) {
         self.inner.template_map.borrow_mut().insert(site, template);
     }
@@ -18,10 +18,11 @@ use boa_parser::{source::ReadChar, Parser, Source};
 use crate::{
     bytecompiler::{global_declaration_instantiation_context, ByteCompiler},
     js_string,
+    module::ModuleRequest,
     realm::Realm,
     spanned_source_text::SourceText,
     vm::{ActiveRunnable, CallFrame, CallFrameFlags, CodeBlock},
-    Context, HostDefined, JsResult, JsString, JsValue, Module, SpannedSourceText,
+    Context, HostDefined, JsResult, JsValue, Module, SpannedSourceText,
 };
 
 /// ECMAScript's [**Script Record**][spec].
@@ -49,7 +50,7 @@ struct Inner {
     source: boa_ast::Script,
     source_text: SourceText,
     codeblock: GcRefCell<Option<Gc<CodeBlock>>>,
-    loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
+    loaded_modules: GcRefCell<FxHashMap<ModuleRequest, Module>>,
     host_defined: HostDefined,
     path: Option<PathBuf>,
 }
@@ -70,7 +71,7 @@ impl Script {
     }
 
     /// Gets the loaded modules of this script.
-    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<JsString, Module>> {
+    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<ModuleRequest, Module>> {
         &self.inner.loaded_modules
     }
 
@@ -22,10 +22,11 @@
 )]
 
 use crate::{
+    builtins::weak::SymbolAnchors,
     js_string,
     string::{JsString, StaticJsStrings},
 };
-use boa_gc::{Finalize, Trace};
+use boa_gc::{Finalize, Trace, Tracer};
 use tag_ptr::{Tagged, UnwrappedTagged};
 
 use boa_macros::{js_str, JsData};
@@ -33,7 +34,7 @@ use num_enum::{IntoPrimitive, TryFromPrimitive};
 
 use std::{
     hash::{Hash, Hasher},
-    sync::{atomic::Ordering, Arc},
+    sync::{atomic::Ordering, Arc, Weak},
 };
 
 use portable_atomic::AtomicU64;
@@ -61,12 +62,15 @@ fn get_id() -> Option<u64> {
 #[derive(Debug, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
 #[repr(u8)]
 enum WellKnown {
+    AsyncDispose,
     AsyncIterator,
+    Dispose,
     HasInstance,
     IsConcatSpreadable,
     Iterator,
     Match,
     MatchAll,
+    Metadata,
     Replace,
     Search,
     Species,
@@ -79,12 +83,15 @@ enum WellKnown {
 impl WellKnown {
     const fn description(self) -> JsString {
         match self {
+            Self::AsyncDispose => StaticJsStrings::SYMBOL_ASYNC_DISPOSE,
             Self::AsyncIterator => StaticJsStrings::SYMBOL_ASYNC_ITERATOR,
+            Self::Dispose => StaticJsStrings::SYMBOL_DISPOSE,
             Self::HasInstance => StaticJsStrings::SYMBOL_HAS_INSTANCE,
             Self::IsConcatSpreadable => StaticJsStrings::SYMBOL_IS_CONCAT_SPREADABLE,
             Self::Iterator => StaticJsStrings::SYMBOL_ITERATOR,
             Self::Match => StaticJsStrings::SYMBOL_MATCH,
             Self::MatchAll => StaticJsStrings::SYMBOL_MATCH_ALL,
+            Self::Metadata => StaticJsStrings::SYMBOL_METADATA,
             Self::Replace => StaticJsStrings::SYMBOL_REPLACE,
             Self::Search => StaticJsStrings::SYMBOL_SEARCH,
             Self::Species => StaticJsStrings::SYMBOL_SPECIES,
@@ -97,12 +104,15 @@ impl WellKnown {
 
     const fn fn_name(self) -> JsString {
         match self {
+            Self::AsyncDispose => StaticJsStrings::FN_SYMBOL_ASYNC_DISPOSE,
             Self::AsyncIterator => StaticJsStrings::FN_SYMBOL_ASYNC_ITERATOR,
+            Self::Dispose => StaticJsStrings::FN_SYMBOL_DISPOSE,
             Self::HasInstance => StaticJsStrings::FN_SYMBOL_HAS_INSTANCE,
             Self::IsConcatSpreadable => StaticJsStrings::FN_SYMBOL_IS_CONCAT_SPREADABLE,
             Self::Iterator => StaticJsStrings::FN_SYMBOL_ITERATOR,
             Self::Match => StaticJsStrings::FN_SYMBOL_MATCH,
             Self::MatchAll => StaticJsStrings::FN_SYMBOL_MATCH_ALL,
+            Self::Metadata => StaticJsStrings::FN_SYMBOL_METADATA,
             Self::Replace => StaticJsStrings::FN_SYMBOL_REPLACE,
             Self::Search => StaticJsStrings::FN_SYMBOL_SEARCH,
             Self::Species => StaticJsStrings::FN_SYMBOL_SPECIES,
@@ -131,15 +141,31 @@ struct Inner {
 }
 
 /// This represents a JavaScript symbol primitive.
-#[derive(Trace, Finalize, JsData)]
-// Safety: JsSymbol does not contain any objects which needs to be traced,
-// so this is safe.
-#[boa_gc(unsafe_empty_trace)]
+#[derive(Finalize, JsData)]
 #[allow(clippy::module_name_repetitions)]
 pub struct JsSymbol {
     repr: Tagged<Inner>,
 }
 
+// SAFETY: `JsSymbol` does not contain any objects which need to be traced. Symbols that are
+// held weakly are the only ones with an anchor in the garbage collected heap, which is traced
+// through `SymbolAnchors` using the symbol as the key.
+unsafe impl Trace for JsSymbol {
+    unsafe fn trace(&self, tracer: &mut Tracer) {
+        // SAFETY: the caller must ensure this is called during the mark phase.
+        unsafe { SymbolAnchors::trace(self, tracer) }
+    }
+
+    unsafe fn trace_non_roots(&self) {
+        // SAFETY: the caller must ensure this is called for a symbol stored in the heap.
+        unsafe { SymbolAnchors::trace_non_roots(self) }
+    }
+
+    fn run_finalizer(&self) {
+        Finalize::finalize(self);
+    }
+}
+
 // SAFETY: `JsSymbol` uses `Arc` to do the reference counting, making this type thread-safe.
 unsafe impl Send for JsSymbol {}
 // SAFETY: `JsSymbol` uses `Arc` to do the reference counting, making this type thread-safe.
@@ -248,9 +274,50 @@ impl JsSymbol {
         )
     }
 
+    /// Creates a weak reference to this symbol, which doesn't keep it alive.
+    pub(crate) fn downgrade(&self) -> WeakJsSymbol {
+        match self.repr.unwrap() {
+            UnwrappedTagged::Ptr(ptr) => {
+                // SAFETY: the pointer returned by `self.repr` must be a valid pointer
+                // that came from an `Arc::into_raw` call, and `ManuallyDrop` ensures we don't
+                // decrement the reference count owned by `self`.
+                let arc = unsafe {
+                    std::mem::ManuallyDrop::new(Arc::from_raw(ptr.as_ptr().cast_const()))
+                };
+                WeakJsSymbol {
+                    repr: WeakRepr::Unique(Arc::downgrade(&arc)),
+                }
+            }
+            UnwrappedTagged::Tag(tag) => WeakJsSymbol {
+                repr: WeakRepr::WellKnown(tag),
+            },
+        }
+    }
+
+    /// Gets the number of strong references to this symbol, or `None` if this is a well-known
+    /// symbol, which is never dropped.
+    pub(crate) fn strong_count(&self) -> Option<usize> {
+        match self.repr.unwrap() {
+            UnwrappedTagged::Ptr(ptr) => {
+                // SAFETY: the pointer returned by `self.repr` must be a valid pointer
+                // that came from an `Arc::into_raw` call, and `ManuallyDrop` ensures we don't
+                // decrement the reference count owned by `self`.
+                let arc = unsafe {
+                    std::mem::ManuallyDrop::new(Arc::from_raw(ptr.as_ptr().cast_const()))
+                };
+                Some(Arc::strong_count(&arc))
+            }
+            UnwrappedTagged::Tag(_) => None,
+        }
+    }
+
     well_known_symbols! {
+        /// Gets the static `JsSymbol` for `"Symbol.asyncDispose"`.
+        (async_dispose, WellKnown::AsyncDispose),
         /// Gets the static `JsSymbol` for `"Symbol.asyncIterator"`.
         (async_iterator, WellKnown::AsyncIterator),
+        /// Gets the static `JsSymbol` for `"Symbol.dispose"`.
+        (dispose, WellKnown::Dispose),
         /// Gets the static `JsSymbol` for `"Symbol.hasInstance"`.
         (has_instance, WellKnown::HasInstance),
         /// Gets the static `JsSymbol` for `"Symbol.isConcatSpreadable"`.
@@ -261,6 +328,8 @@ impl JsSymbol {
         (r#match, WellKnown::Match),
         /// Gets the static `JsSymbol` for `"Symbol.matchAll"`.
         (match_all, WellKnown::MatchAll),
+        /// Gets the static `JsSymbol` for `"Symbol.metadata"`.
+        (metadata, WellKnown::Metadata),
         /// Gets the static `JsSymbol` for `"Symbol.replace"`.
         (replace, WellKnown::Replace),
         /// Gets the static `JsSymbol` for `"Symbol.search"`.
@@ -305,6 +374,46 @@ impl Drop for JsSymbol {
     }
 }
 
+/// A weak reference to a [`JsSymbol`].
+///
+/// Well-known symbols are never collected, so references to them can always be upgraded.
+#[derive(Debug, Clone)]
+pub(crate) struct WeakJsSymbol {
+    repr: WeakRepr,
+}
+
+#[derive(Debug, Clone)]
+enum WeakRepr {
+    WellKnown(usize),
+    Unique(Weak<Inner>),
+}
+
+impl WeakJsSymbol {
+    /// Gets the referenced symbol, or `None` if it has already been dropped.
+    pub(crate) fn upgrade(&self) -> Option<JsSymbol> {
+        match &self.repr {
+            WeakRepr::WellKnown(tag) => Some(JsSymbol {
+                repr: Tagged::from_tag(*tag),
+            }),
+            WeakRepr::Unique(weak) => {
+                let arc = weak.upgrade()?;
+                Some(JsSymbol {
+                    // SAFETY: Pointers returned by `Arc::into_raw` must be non-null.
+                    repr: unsafe { Tagged::from_ptr(Arc::into_raw(arc).cast_mut()) },
+                })
+            }
+        }
+    }
+
+    /// Returns `true` if the referenced symbol is still alive.
+    pub(crate) fn is_upgradable(&self) -> bool {
+        match &self.repr {
+            WeakRepr::WellKnown(_) => true,
+            WeakRepr::Unique(weak) => weak.strong_count() > 0,
+        }
+    }
+}
+
 impl std::fmt::Debug for JsSymbol {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("JsSymbol")
@@ -72,3 +72,214 @@ fn class_can_access_super_from_static_initializer() {
         TestAction::assert_eq("c.field", js_str!("super field")),
     ]);
 }
+
+#[cfg(feature = "experimental")]
+#[test]
+fn class_decorators() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let log = [];
+            function logged(value, context) {
+                log.push(context.kind + " " + String(context.name));
+                if (context.kind === "class") {
+                    return class extends value {
+                        decorated = true;
+                    };
+                }
+            }
+
+            @logged
+            class C {
+                @logged static m() {}
+                @logged a = 1;
+                @logged static b = 2;
+                @logged get c() { return 3; }
+                @logged #d() { return 4; }
+                d() { return this.#d(); }
+            }
+
+            let c = new C();
+        "#}),
+        TestAction::assert_eq(
+            "log.join()",
+            js_str!("method m,getter c,method #d,field b,field a,class C"),
+        ),
+        TestAction::assert("c.decorated"),
+        TestAction::assert_eq("c.a", 1),
+        TestAction::assert_eq("C.b", 2),
+        TestAction::assert_eq("c.c", 3),
+        TestAction::assert_eq("c.d()", 4),
+    ]);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn class_decorators_replace_elements() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function double(value, { kind }) {
+                if (kind === "method") {
+                    return function (...args) { return value.apply(this, args) * 2; };
+                }
+                if (kind === "field") {
+                    return (initial) => initial * 2;
+                }
+            }
+
+            class C {
+                @double m() { return 2; }
+                @double a = 3;
+                @double static #b = 4;
+                static b() { return C.#b; }
+            }
+        "#}),
+        TestAction::assert_eq("new C().m()", 4),
+        TestAction::assert_eq("new C().a", 6),
+        TestAction::assert_eq("C.b()", 8),
+    ]);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn class_auto_accessors() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function tracked(target, context) {
+                return {
+                    get() { return target.get.call(this); },
+                    set(value) { target.set.call(this, value + 1); },
+                    init(value) { return value * 10; },
+                };
+            }
+
+            class C {
+                accessor a = 1;
+                @tracked accessor b = 2;
+                static accessor #c = 3;
+                static c() { return C.#c; }
+            }
+
+            let c = new C();
+            c.b = 5;
+            let descriptor = Object.getOwnPropertyDescriptor(C.prototype, "a");
+        "#}),
+        TestAction::assert_eq("c.a", 1),
+        TestAction::assert_eq("c.b", 6),
+        TestAction::assert_eq("C.c()", 3),
+        TestAction::assert("!Object.hasOwn(c, 'a')"),
+        TestAction::assert_eq("typeof descriptor.get", js_str!("function")),
+        TestAction::assert_eq("typeof descriptor.set", js_str!("function")),
+        TestAction::assert_eq("descriptor.get.name", js_str!("get a")),
+        TestAction::assert("!descriptor.enumerable && descriptor.configurable"),
+    ]);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn class_decorators_add_initializer() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let log = [];
+            function init(name) {
+                return (value, context) => {
+                    context.addInitializer(function () {
+                        log.push(name + ":" + typeof this);
+                    });
+                };
+            }
+
+            @init("class")
+            class C {
+                @init("method") m() {}
+                @init("static") static s() {}
+                @init("field") f;
+            }
+            log.push("defined");
+            new C();
+        "#}),
+        TestAction::assert_eq(
+            "log.join()",
+            js_str!("static:function,class:function,defined,method:object,field:object"),
+        ),
+    ]);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn class_decorators_access() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let access;
+            function capture(value, context) {
+                access = context.access;
+            }
+
+            class C {
+                @capture #x = 1;
+                static read(obj) { return access.get(obj); }
+            }
+
+            let c = new C();
+            access.set(c, 2);
+        "#}),
+        TestAction::assert_eq("C.read(c)", 2),
+        TestAction::assert("access.has(c)"),
+        TestAction::assert("!access.has({})"),
+    ]);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn class_decorators_metadata() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function meta(key) {
+                return (value, context) => {
+                    context.metadata[key] = context.name;
+                };
+            }
+
+            @meta("class")
+            class A {
+                @meta("field") a;
+            }
+
+            @meta("class")
+            class B extends A {}
+
+            let metadata = A[Symbol.metadata];
+        "#}),
+        TestAction::assert_eq("metadata.class", js_str!("A")),
+        TestAction::assert_eq("metadata.field", js_str!("a")),
+        TestAction::assert_eq("B[Symbol.metadata].class", js_str!("B")),
+        TestAction::assert_eq("B[Symbol.metadata].field", js_str!("a")),
+        TestAction::assert("Object.getPrototypeOf(B[Symbol.metadata]) === metadata"),
+        TestAction::assert("Object.getPrototypeOf(metadata) === null"),
+    ]);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn class_decorators_errors() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "@(1) class A {}",
+            crate::JsNativeErrorKind::Type,
+            "decorators must be functions",
+        ),
+        TestAction::assert_native_error(
+            "class B { @(() => 1) m() {} }",
+            crate::JsNativeErrorKind::Type,
+            "method decorators must return a function or undefined",
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                let add;
+                class C { @((v, c) => { add = c.addInitializer; }) m() {} }
+                add(() => {});
+            "#},
+            crate::JsNativeErrorKind::Type,
+            "cannot add initializers after decoration has finished",
+        ),
+    ]);
+}
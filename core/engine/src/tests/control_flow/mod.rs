@@ -213,6 +213,26 @@ fn finally_with_loop_break() {
     )]);
 }
 
+#[test]
+fn finally_in_for_of_loop_break() {
+    run_test_actions([TestAction::assert_eq(
+        indoc! {r#"
+            let log = "";
+            for (const i of [1, 2, 3]) {
+              try {
+                if (i === 2) {
+                  break;
+                }
+              } finally {
+                log += i;
+              }
+            }
+            log;
+        "#},
+        js_str!("12"),
+    )]);
+}
+
 #[test]
 fn single_case_switch() {
     run_test_actions([TestAction::assert_eq(
@@ -75,6 +75,18 @@ impl JsValue {
                 true
             }
 
+            // B.3.6.2 Changes to IsLooselyEqual
+            // 4. Perform the following steps:
+            //     a. If x is an Object, x has an [[IsHTMLDDA]] internal slot, and y is either undefined or null, return true.
+            //     b. If x is either undefined or null, y is an Object, and y has an [[IsHTMLDDA]] internal slot, return true.
+            #[cfg(feature = "annex-b")]
+            (JsVariant::Object(object), JsVariant::Null | JsVariant::Undefined)
+            | (JsVariant::Null | JsVariant::Undefined, JsVariant::Object(object))
+                if object.is_html_dda() =>
+            {
+                true
+            }
+
             // 3. If Type(x) is Number and Type(y) is String, return the result of the comparison x == ! ToNumber(y).
             // 4. If Type(x) is String and Type(y) is Number, return the result of the comparison ! ToNumber(x) == y.
             //
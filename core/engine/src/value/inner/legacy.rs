@@ -33,6 +33,8 @@ unsafe impl Trace for EnumBasedValue {
     custom_trace! {this, mark, {
         if let Some(o) = this.as_object() {
             mark(o);
+        } else if let Some(s) = this.as_symbol() {
+            mark(s);
         }
     }}
 }
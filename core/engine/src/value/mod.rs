@@ -9,6 +9,7 @@ use std::{
     sync::LazyLock,
 };
 
+use half::f16;
 use num_bigint::BigInt;
 use num_integer::Integer;
 use num_traits::{ToPrimitive, Zero};
@@ -25,7 +26,7 @@ pub(crate) use self::conversions::IntoOrUndefined;
 pub use self::{
     conversions::try_from_js::TryFromJs, conversions::try_into_js::TryIntoJs,
     display::ValueDisplay, integer::IntegerOrInfinity, operations::*, r#type::Type,
-    variant::JsVariant,
+    structured_clone::StructuredValue, variant::JsVariant,
 };
 use crate::builtins::RegExp;
 use crate::object::{ErasedObject, JsFunction, JsPromise, JsRegExp};
@@ -49,6 +50,7 @@ mod hash;
 mod inner;
 mod integer;
 mod operations;
+mod structured_clone;
 mod r#type;
 mod variant;
 
@@ -440,6 +442,10 @@ impl JsValue {
     #[must_use]
     pub fn to_boolean(&self) -> bool {
         match self.variant() {
+            // B.3.6.1 Changes to ToBoolean
+            // 1. If argument is an Object and argument has an [[IsHTMLDDA]] internal slot, return false.
+            #[cfg(feature = "annex-b")]
+            JsVariant::Object(object) if object.is_html_dda() => false,
             JsVariant::Symbol(_) | JsVariant::Object(_) => true,
             JsVariant::String(s) if !s.is_empty() => true,
             JsVariant::Float64(n) if n != 0.0 && !n.is_nan() => true,
@@ -990,6 +996,11 @@ impl JsValue {
         }
     }
 
+    /// Converts a value to a 16 bit floating point.
+    pub fn to_f16(&self, context: &mut Context) -> JsResult<f16> {
+        self.to_number(context).map(f16::from_f64)
+    }
+
     /// Converts a value to a 32 bit floating point.
     pub fn to_f32(&self, context: &mut Context) -> JsResult<f32> {
         self.to_number(context).map(|n| n as f32)
@@ -0,0 +1,767 @@
+//! This module implements the [structured clone algorithm][spec], which copies values
+//! between contexts.
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#safe-passing-of-structured-data
+
+use std::sync::atomic::Ordering;
+
+use rustc_hash::FxHashMap;
+
+use super::{JsValue, JsVariant};
+use crate::{
+    bigint::RawBigInt,
+    builtins::{
+        array_buffer::{ArrayBuffer, SharedArrayBuffer},
+        dataview::DataView,
+        date::Date,
+        error::Error,
+        map::ordered_map::OrderedMap,
+        regexp::RegExp,
+        set::ordered_set::OrderedSet,
+        typed_array::{TypedArray, TypedArrayKind},
+        Array,
+    },
+    error::JsNativeError,
+    js_string,
+    object::{
+        builtins::{JsArrayBuffer, JsMap, JsRegExp, JsSet, JsSharedArrayBuffer},
+        internal_methods::InternalMethodContext,
+        JsObject,
+    },
+    property::{PropertyKey, PropertyNameKind},
+    Context, JsBigInt, JsResult, JsString,
+};
+
+/// A value serialized with the structured clone algorithm.
+///
+/// A `StructuredValue` doesn't hold any garbage collected value and doesn't depend on the
+/// [`Context`] it was serialized from, so it can be deserialized into any other context,
+/// including one running on another thread.
+///
+/// Primitives, `Boolean`, `Number`, `BigInt` and `String` objects, `Date`s, `RegExp`s,
+/// `ArrayBuffer`s, `SharedArrayBuffer`s, typed arrays, `DataView`s, `Map`s, `Set`s,
+/// errors, arrays and ordinary objects are supported. Objects referenced multiple times
+/// (including cycles) are deserialized to a single object.
+///
+/// # Example
+///
+/// ```
+/// use boa_engine::{js_string, value::StructuredValue, Context, Source};
+///
+/// let context = &mut Context::default();
+/// let value = context
+///     .eval(Source::from_bytes("new Map([[1, new Set([1n, 2n])]])"))
+///     .unwrap();
+/// let serialized = StructuredValue::serialize(&value, context).unwrap();
+///
+/// let other = &mut Context::default();
+/// let map = serialized.deserialize(other).unwrap();
+/// other
+///     .global_object()
+///     .set(js_string!("map"), map, false, other)
+///     .unwrap();
+/// let size = other.eval(Source::from_bytes("map.get(1).size")).unwrap();
+/// assert_eq!(size, 2.into());
+/// ```
+#[derive(Debug, Clone)]
+pub struct StructuredValue {
+    root: Value,
+    objects: Vec<Record>,
+}
+
+/// A serialized primitive, or a reference to a serialized object.
+#[derive(Debug, Clone)]
+enum Value {
+    Undefined,
+    Null,
+    Boolean(bool),
+    Number(f64),
+    BigInt(RawBigInt),
+    String(Vec<u16>),
+    Object(usize),
+}
+
+/// The native errors kept by the serialization of an error, see [`Record::Error`].
+#[derive(Debug, Clone, Copy)]
+enum ErrorName {
+    Error,
+    Eval,
+    Range,
+    Reference,
+    Syntax,
+    Type,
+    Uri,
+}
+
+/// A serialized object.
+#[derive(Debug, Clone)]
+enum Record {
+    Boolean(bool),
+    Number(f64),
+    BigInt(RawBigInt),
+    String(Vec<u16>),
+    Date(f64),
+    RegExp {
+        source: Vec<u16>,
+        flags: Vec<u16>,
+    },
+    ArrayBuffer {
+        data: Vec<u8>,
+        max_byte_length: Option<u64>,
+    },
+    SharedArrayBuffer(SharedArrayBuffer),
+    TypedArray {
+        kind: TypedArrayKind,
+        buffer: usize,
+        byte_offset: u64,
+        length: Option<u64>,
+    },
+    DataView {
+        buffer: usize,
+        byte_offset: u64,
+        byte_length: Option<u64>,
+    },
+    Map(Vec<(Value, Value)>),
+    Set(Vec<Value>),
+    Error {
+        name: ErrorName,
+        message: Option<Vec<u16>>,
+    },
+    Array {
+        length: u64,
+        properties: Vec<(Vec<u16>, Value)>,
+    },
+    Object(Vec<(Vec<u16>, Value)>),
+}
+
+impl StructuredValue {
+    /// Serializes `value` with the [`StructuredSerialize`][spec] abstract operation.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `TypeError` if `value` contains a value that cannot be cloned, such as a
+    /// symbol, a function or a detached `ArrayBuffer`, or any error thrown while reading
+    /// the properties of an object.
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#structuredserialize
+    pub fn serialize(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        Self::serialize_with_transfer(value, &[], context)
+    }
+
+    /// Serializes `value` with the [`StructuredSerializeWithTransfer`][spec] abstract
+    /// operation.
+    ///
+    /// The `ArrayBuffer`s in `transfer` are moved to the serialized value instead of being
+    /// copied, and are detached once `value` is serialized.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `TypeError` if `value` cannot be serialized, or if `transfer` contains
+    /// duplicates or objects other than non-detached `ArrayBuffer`s.
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#structuredserializewithtransfer
+    pub fn serialize_with_transfer(
+        value: &JsValue,
+        transfer: &[JsObject],
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let mut serializer = Serializer::default();
+
+        let mut transferred = Vec::with_capacity(transfer.len());
+        for object in transfer {
+            let buffer = JsArrayBuffer::from_object(object.clone())
+                .map_err(|_| data_clone_error("only ArrayBuffer objects can be transferred"))?;
+            if serializer.memory.contains_key(object) {
+                return Err(data_clone_error(
+                    "an ArrayBuffer is transferred more than once",
+                ));
+            }
+            if buffer.borrow().data().is_detached() {
+                return Err(data_clone_error("cannot transfer a detached ArrayBuffer"));
+            }
+
+            let index = serializer.objects.len();
+            serializer.memory.insert(object.clone(), index);
+            serializer.objects.push(Record::ArrayBuffer {
+                data: Vec::new(),
+                max_byte_length: None,
+            });
+            transferred.push((index, buffer));
+        }
+
+        let root = serializer.serialize(value, context)?;
+
+        for (index, buffer) in transferred {
+            let max_byte_length = buffer.borrow().data().max_byte_len();
+            let data = buffer.detach(&JsValue::undefined())?;
+            serializer.objects[index] = Record::ArrayBuffer {
+                data,
+                max_byte_length,
+            };
+        }
+
+        Ok(Self {
+            root,
+            objects: serializer.objects,
+        })
+    }
+
+    /// Deserializes the value into `context` with the [`StructuredDeserialize`][spec]
+    /// abstract operation.
+    ///
+    /// The same `StructuredValue` can be deserialized multiple times, creating new objects
+    /// each time.
+    ///
+    /// # Errors
+    ///
+    /// Any error thrown while creating the objects, such as a `RangeError` if an
+    /// `ArrayBuffer` cannot be allocated.
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#structureddeserialize
+    pub fn deserialize(&self, context: &mut Context) -> JsResult<JsValue> {
+        // Create every object but the views first, as views need their buffer to exist.
+        let mut objects = Vec::with_capacity(self.objects.len());
+        for record in &self.objects {
+            let object = match record {
+                Record::Boolean(value) => Some(JsValue::new(*value).to_object(context)?),
+                Record::Number(value) => Some(JsValue::new(*value).to_object(context)?),
+                Record::BigInt(value) => {
+                    Some(JsValue::new(JsBigInt::from(value.clone())).to_object(context)?)
+                }
+                Record::String(value) => {
+                    Some(JsValue::new(JsString::from(&value[..])).to_object(context)?)
+                }
+                Record::Date(time_value) => {
+                    let prototype = context.intrinsics().constructors().date().prototype();
+                    Some(JsObject::from_proto_and_data_with_shared_shape(
+                        context.root_shape(),
+                        prototype,
+                        Date::new(*time_value),
+                    ))
+                }
+                Record::RegExp { source, flags } => Some(
+                    JsRegExp::new(
+                        JsValue::new(JsString::from(&source[..])),
+                        JsValue::new(JsString::from(&flags[..])),
+                        context,
+                    )?
+                    .into(),
+                ),
+                Record::ArrayBuffer {
+                    data,
+                    max_byte_length,
+                } => {
+                    let mut buffer = JsArrayBuffer::from_byte_block(data.clone(), context)?;
+                    if let Some(max_byte_length) = max_byte_length {
+                        buffer = buffer.with_max_byte_length(*max_byte_length);
+                    }
+                    Some(buffer.into())
+                }
+                Record::SharedArrayBuffer(buffer) => {
+                    Some(JsSharedArrayBuffer::from_buffer(buffer.clone(), context).into())
+                }
+                Record::Map(_) => Some(JsMap::new(context).into()),
+                Record::Set(_) => Some(JsSet::new(context).into()),
+                Record::Error { name, message } => {
+                    let constructors = context.intrinsics().constructors();
+                    let constructor = match name {
+                        ErrorName::Error => constructors.error(),
+                        ErrorName::Eval => constructors.eval_error(),
+                        ErrorName::Range => constructors.range_error(),
+                        ErrorName::Reference => constructors.reference_error(),
+                        ErrorName::Syntax => constructors.syntax_error(),
+                        ErrorName::Type => constructors.type_error(),
+                        ErrorName::Uri => constructors.uri_error(),
+                    }
+                    .constructor();
+                    let args = message
+                        .as_ref()
+                        .map(|message| JsValue::new(JsString::from(&message[..])));
+                    Some(constructor.construct(args.as_slice(), None, context)?)
+                }
+                Record::Array { length, .. } => Some(Array::array_create(*length, None, context)?),
+                Record::Object(_) => Some(JsObject::with_object_proto(context.intrinsics())),
+                Record::TypedArray { .. } | Record::DataView { .. } => None,
+            };
+            objects.push(object);
+        }
+
+        for (index, record) in self.objects.iter().enumerate() {
+            let (constructor, byte_offset, length, buffer) = match record {
+                Record::TypedArray {
+                    kind,
+                    buffer,
+                    byte_offset,
+                    length,
+                } => (
+                    kind.standard_constructor()(context.intrinsics().constructors()).constructor(),
+                    *byte_offset,
+                    *length,
+                    *buffer,
+                ),
+                Record::DataView {
+                    buffer,
+                    byte_offset,
+                    byte_length,
+                } => (
+                    context
+                        .intrinsics()
+                        .constructors()
+                        .data_view()
+                        .constructor(),
+                    *byte_offset,
+                    *byte_length,
+                    *buffer,
+                ),
+                _ => continue,
+            };
+            let buffer = objects[buffer]
+                .clone()
+                .expect("the buffer of a view must be created before the view");
+            let args = [
+                buffer.into(),
+                byte_offset.into(),
+                length.map_or_else(JsValue::undefined, JsValue::from),
+            ];
+            objects[index] = Some(constructor.construct(&args, None, context)?);
+        }
+
+        let objects = objects
+            .into_iter()
+            .map(|object| object.expect("all objects must be created"))
+            .collect::<Vec<_>>();
+
+        for (record, object) in self.objects.iter().zip(&objects) {
+            match record {
+                Record::Map(entries) => {
+                    let map = JsMap::from_object(object.clone())?;
+                    for (key, value) in entries {
+                        map.set(key.to_js(&objects), value.to_js(&objects), context)?;
+                    }
+                }
+                Record::Set(values) => {
+                    let set = JsSet::from_object(object.clone())?;
+                    for value in values {
+                        set.add(value.to_js(&objects), context)?;
+                    }
+                }
+                Record::Array { properties, .. } | Record::Object(properties) => {
+                    for (key, value) in properties {
+                        object.create_data_property_or_throw(
+                            JsString::from(&key[..]),
+                            value.to_js(&objects),
+                            context,
+                        )?;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(self.root.to_js(&objects))
+    }
+}
+
+impl Value {
+    /// Gets the deserialized value, given the deserialized `objects`.
+    fn to_js(&self, objects: &[JsObject]) -> JsValue {
+        match self {
+            Self::Undefined => JsValue::undefined(),
+            Self::Null => JsValue::null(),
+            Self::Boolean(value) => JsValue::new(*value),
+            Self::Number(value) => JsValue::new(*value),
+            Self::BigInt(value) => JsValue::new(JsBigInt::from(value.clone())),
+            Self::String(value) => JsValue::new(JsString::from(&value[..])),
+            Self::Object(index) => objects[*index].clone().into(),
+        }
+    }
+}
+
+/// The state of a [`StructuredValue`] being serialized.
+#[derive(Default)]
+struct Serializer {
+    /// The index of the record of every object already serialized.
+    memory: FxHashMap<JsObject, usize>,
+    objects: Vec<Record>,
+}
+
+impl Serializer {
+    /// Serializes `value`, adding the records of the objects it references.
+    fn serialize(&mut self, value: &JsValue, context: &mut Context) -> JsResult<Value> {
+        match value.variant() {
+            JsVariant::Undefined => Ok(Value::Undefined),
+            JsVariant::Null => Ok(Value::Null),
+            JsVariant::Boolean(value) => Ok(Value::Boolean(value)),
+            JsVariant::Integer32(value) => Ok(Value::Number(f64::from(value))),
+            JsVariant::Float64(value) => Ok(Value::Number(value)),
+            JsVariant::BigInt(value) => Ok(Value::BigInt(value.as_inner().clone())),
+            JsVariant::String(value) => Ok(Value::String(value.to_vec())),
+            JsVariant::Symbol(_) => Err(data_clone_error("symbols cannot be cloned")),
+            JsVariant::Object(object) => Ok(Value::Object(self.serialize_object(object, context)?)),
+        }
+    }
+
+    /// Serializes `object`, returning the index of its record.
+    fn serialize_object(&mut self, object: &JsObject, context: &mut Context) -> JsResult<usize> {
+        if let Some(&index) = self.memory.get(object) {
+            return Ok(index);
+        }
+
+        // Reserve the record before serializing the values referenced by the object, so
+        // cycles point back to it.
+        let index = self.objects.len();
+        self.memory.insert(object.clone(), index);
+        self.objects.push(Record::Object(Vec::new()));
+
+        let record = self.record(object, context)?;
+        self.objects[index] = record;
+        Ok(index)
+    }
+
+    /// Creates the record of `object`.
+    fn record(&mut self, object: &JsObject, context: &mut Context) -> JsResult<Record> {
+        if let Some(value) = object.downcast_ref::<bool>() {
+            return Ok(Record::Boolean(*value));
+        }
+        if let Some(value) = object.downcast_ref::<f64>() {
+            return Ok(Record::Number(*value));
+        }
+        if let Some(value) = object.downcast_ref::<JsBigInt>() {
+            return Ok(Record::BigInt(value.as_inner().clone()));
+        }
+        if let Some(value) = object.downcast_ref::<JsString>() {
+            return Ok(Record::String(value.to_vec()));
+        }
+        if let Some(date) = object.downcast_ref::<Date>() {
+            return Ok(Record::Date(date.time_value()));
+        }
+        if let Some(regexp) = object.downcast_ref::<RegExp>() {
+            return Ok(Record::RegExp {
+                source: regexp.original_source().to_vec(),
+                flags: regexp.original_flags().to_vec(),
+            });
+        }
+        if let Some(buffer) = object.downcast_ref::<ArrayBuffer>() {
+            let data = buffer
+                .bytes()
+                .ok_or_else(|| data_clone_error("cannot clone a detached ArrayBuffer"))?;
+            return Ok(Record::ArrayBuffer {
+                data: data.to_vec(),
+                max_byte_length: buffer.max_byte_len(),
+            });
+        }
+        if let Some(buffer) = object.downcast_ref::<SharedArrayBuffer>() {
+            return Ok(Record::SharedArrayBuffer(buffer.clone()));
+        }
+
+        let view = if let Some(array) = object.downcast_ref::<TypedArray>() {
+            let buffer = array.viewed_array_buffer().clone();
+            let length = buffer
+                .as_buffer()
+                .bytes(Ordering::SeqCst)
+                .filter(|bytes| !array.is_out_of_bounds(bytes.len()))
+                .map(|bytes| (!array.is_auto_length()).then(|| array.array_length(bytes.len())));
+            Some((buffer, Some(array.kind()), array.byte_offset(), length))
+        } else if let Some(view) = object.downcast_ref::<DataView>() {
+            let buffer = view.viewed_array_buffer.clone();
+            let length = buffer
+                .as_buffer()
+                .bytes(Ordering::SeqCst)
+                .filter(|bytes| !view.is_out_of_bounds(bytes.len()))
+                .map(|_| view.byte_length);
+            Some((buffer, None, view.byte_offset, length))
+        } else {
+            None
+        };
+        if let Some((buffer, kind, byte_offset, length)) = view {
+            let length = length.ok_or_else(|| {
+                data_clone_error("cannot clone a view on a detached or out of bounds buffer")
+            })?;
+            let buffer = self.serialize_object(&buffer.into(), context)?;
+            return Ok(match kind {
+                Some(kind) => Record::TypedArray {
+                    kind,
+                    buffer,
+                    byte_offset,
+                    length,
+                },
+                None => Record::DataView {
+                    buffer,
+                    byte_offset,
+                    byte_length: length,
+                },
+            });
+        }
+
+        let entries = object.downcast_ref::<OrderedMap<JsValue>>().map(|map| {
+            map.iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect::<Vec<_>>()
+        });
+        if let Some(entries) = entries {
+            let entries = entries
+                .iter()
+                .map(|(key, value)| {
+                    Ok((
+                        self.serialize(key, context)?,
+                        self.serialize(value, context)?,
+                    ))
+                })
+                .collect::<JsResult<_>>()?;
+            return Ok(Record::Map(entries));
+        }
+
+        let values = object
+            .downcast_ref::<OrderedSet>()
+            .map(|set| set.iter().cloned().collect::<Vec<_>>());
+        if let Some(values) = values {
+            let values = values
+                .iter()
+                .map(|value| self.serialize(value, context))
+                .collect::<JsResult<_>>()?;
+            return Ok(Record::Set(values));
+        }
+
+        if object.is::<Error>() {
+            let name = match object.get(js_string!("name"), context)?.as_string() {
+                Some(name) if name == &js_string!("EvalError") => ErrorName::Eval,
+                Some(name) if name == &js_string!("RangeError") => ErrorName::Range,
+                Some(name) if name == &js_string!("ReferenceError") => ErrorName::Reference,
+                Some(name) if name == &js_string!("SyntaxError") => ErrorName::Syntax,
+                Some(name) if name == &js_string!("TypeError") => ErrorName::Type,
+                Some(name) if name == &js_string!("URIError") => ErrorName::Uri,
+                _ => ErrorName::Error,
+            };
+            let message = object.__get_own_property__(
+                &js_string!("message").into(),
+                &mut InternalMethodContext::new(context),
+            )?;
+            let message = match message.as_ref().and_then(|desc| desc.value()) {
+                Some(message) => Some(message.to_string(context)?.to_vec()),
+                None => None,
+            };
+            return Ok(Record::Error { name, message });
+        }
+
+        if object.is_array() {
+            let length = object.length_of_array_like(context)?;
+            let properties = self.properties(object, context)?;
+            return Ok(Record::Array { length, properties });
+        }
+
+        if object.is_callable() {
+            return Err(data_clone_error("functions cannot be cloned"));
+        }
+        if !object.is_ordinary() {
+            return Err(data_clone_error("this kind of object cannot be cloned"));
+        }
+
+        Ok(Record::Object(self.properties(object, context)?))
+    }
+
+    /// Serializes the enumerable own string-keyed properties of `object`.
+    fn properties(
+        &mut self,
+        object: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<Vec<(Vec<u16>, Value)>> {
+        let keys = object.enumerable_own_property_names(PropertyNameKind::Key, context)?;
+        let mut properties = Vec::with_capacity(keys.len());
+        for key in keys {
+            let key = key
+                .as_string()
+                .expect("enumerable own property names must be strings")
+                .clone();
+            let property_key = PropertyKey::from(key.clone());
+
+            // A getter of a previous property may have deleted this one.
+            if object.has_own_property(property_key.clone(), context)? {
+                let value = object.get(property_key, context)?;
+                properties.push((key.to_vec(), self.serialize(&value, context)?));
+            }
+        }
+        Ok(properties)
+    }
+}
+
+/// Creates the error thrown when a value cannot be cloned.
+///
+/// The HTML specification throws a `DataCloneError` `DOMException` here, but the engine
+/// has no `DOMException`, so a `TypeError` is thrown instead.
+fn data_clone_error(message: &'static str) -> crate::JsError {
+    JsNativeError::typ().with_message(message).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use boa_macros::js_str;
+    use indoc::indoc;
+
+    use super::StructuredValue;
+    use crate::{
+        object::builtins::JsArrayBuffer, run_test_actions, run_test_actions_with, Context,
+        JsNativeErrorKind, Source, TestAction,
+    };
+
+    #[test]
+    fn structured_value_is_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<StructuredValue>();
+    }
+
+    #[test]
+    fn clone_between_contexts() {
+        let from = &mut Context::default();
+        let value = from
+            .eval(Source::from_bytes(indoc! {r#"
+                const shared = { x: 1 };
+                const value = {
+                    primitives: [undefined, null, true, -0, 1.5, 10n, "text"],
+                    wrappers: [Object(false), Object(2), Object(3n), Object("str")],
+                    date: new Date(1000),
+                    regexp: /a+b/gu,
+                    map: new Map([[shared, "shared"], ["k", [1, 2]]]),
+                    set: new Set([shared, 1]),
+                    error: new RangeError("out of range"),
+                    sparse: [1, , 3],
+                    shared,
+                };
+                value.self = value;
+                value
+            "#}))
+            .unwrap();
+
+        let serialized = StructuredValue::serialize(&value, from).unwrap();
+
+        let into = &mut Context::default();
+        let value = serialized.deserialize(into).unwrap();
+        into.global_object()
+            .set(js_str!("value"), value, true, into)
+            .unwrap();
+
+        run_test_actions_with(
+            [
+                TestAction::assert(indoc! {r#"
+                value.self === value &&
+                value.shared === value.map.keys().next().value &&
+                value.set.has(value.shared) &&
+                value.primitives.length === 7 &&
+                value.primitives[0] === undefined &&
+                value.primitives[1] === null &&
+                Object.is(value.primitives[3], -0) &&
+                value.primitives[5] === 10n &&
+                value.primitives[6] === "text"
+            "#}),
+                TestAction::assert(indoc! {r#"
+                value.wrappers[0] instanceof Boolean && value.wrappers[0].valueOf() === false &&
+                value.wrappers[1] instanceof Number && value.wrappers[1].valueOf() === 2 &&
+                typeof value.wrappers[2] === "object" && value.wrappers[2].valueOf() === 3n &&
+                value.wrappers[3] instanceof String && value.wrappers[3].length === 3
+            "#}),
+                TestAction::assert(indoc! {r#"
+                value.date instanceof Date && value.date.getTime() === 1000 &&
+                value.regexp instanceof RegExp && value.regexp.source === "a+b" &&
+                value.regexp.flags === "gu" && value.regexp.lastIndex === 0 &&
+                value.map.get("k")[1] === 2 &&
+                value.error instanceof RangeError && value.error.message === "out of range" &&
+                value.sparse.length === 3 && !(1 in value.sparse)
+            "#}),
+            ],
+            into,
+        );
+    }
+
+    #[test]
+    fn clone_buffers_and_views() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var buffer = new ArrayBuffer(8, { maxByteLength: 16 });
+                new Uint8Array(buffer).set([1, 2, 3, 4, 5, 6, 7, 8]);
+                var value = {
+                    buffer,
+                    bytes: new Uint8Array(buffer, 2, 4),
+                    tracking: new Uint16Array(buffer),
+                    view: new DataView(buffer, 1, 2),
+                };
+            "#}),
+            TestAction::inspect_context(|ctx| {
+                let value = ctx.global_object().get(js_str!("value"), ctx).unwrap();
+                let serialized = StructuredValue::serialize(&value, ctx).unwrap();
+                let value = serialized.deserialize(ctx).unwrap();
+                ctx.global_object()
+                    .set(js_str!("copy"), value, true, ctx)
+                    .unwrap();
+            }),
+            TestAction::assert(indoc! {r#"
+                copy.buffer !== buffer &&
+                copy.buffer.resizable && copy.buffer.maxByteLength === 16 &&
+                copy.bytes.buffer === copy.buffer &&
+                copy.bytes.join() === "3,4,5,6" &&
+                copy.view.getUint16(0) === 0x0203 && copy.view.byteLength === 2
+            "#}),
+            TestAction::run("copy.buffer.resize(12)"),
+            TestAction::assert_eq("copy.tracking.length", 6),
+            TestAction::assert_eq("buffer.byteLength", 8),
+        ]);
+    }
+
+    #[test]
+    fn transfer() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var buffer = new Uint8Array([1, 2, 3]).buffer;
+                var value = { buffer, view: new Uint8Array(buffer, 1) };
+            "#}),
+            TestAction::inspect_context(|ctx| {
+                let global = ctx.global_object();
+                let value = global.get(js_str!("value"), ctx).unwrap();
+                let buffer = global.get(js_str!("buffer"), ctx).unwrap();
+                let buffer = buffer.as_object().unwrap().clone();
+
+                let serialized = StructuredValue::serialize_with_transfer(
+                    &value,
+                    std::slice::from_ref(&buffer),
+                    ctx,
+                )
+                .unwrap();
+                assert!(JsArrayBuffer::from_object(buffer.clone())
+                    .unwrap()
+                    .data()
+                    .is_none());
+
+                let err = StructuredValue::serialize_with_transfer(&value, &[buffer], ctx)
+                    .unwrap_err()
+                    .try_native(ctx)
+                    .unwrap();
+                assert_eq!(err.kind, JsNativeErrorKind::Type);
+
+                let value = serialized.deserialize(ctx).unwrap();
+                global.set(js_str!("copy"), value, true, ctx).unwrap();
+            }),
+            TestAction::assert_eq("buffer.byteLength", 0),
+            TestAction::assert_eq("copy.buffer.byteLength", 3),
+            TestAction::assert("copy.view.buffer === copy.buffer"),
+            TestAction::assert_eq("copy.view.join()", js_str!("2,3")),
+        ]);
+    }
+
+    #[test]
+    fn uncloneable_values() {
+        run_test_actions([TestAction::inspect_context(|ctx| {
+            for source in [
+                "Symbol()",
+                "({ f() {} })",
+                "[new WeakMap()]",
+                "new Proxy({}, {})",
+                "new Set([Symbol.iterator])",
+            ] {
+                let value = ctx.eval(Source::from_bytes(source)).unwrap();
+                let err = StructuredValue::serialize(&value, ctx)
+                    .unwrap_err()
+                    .try_native(ctx)
+                    .unwrap();
+                assert_eq!(err.kind, JsNativeErrorKind::Type, "{source}");
+            }
+        })]);
+    }
+}
@@ -70,6 +70,9 @@ impl JsVariant<'_> {
             JsVariant::Null => "object",
             JsVariant::Undefined => "undefined",
             JsVariant::BigInt(_) => "bigint",
+            // B.3.6.3 Changes to the typeof Operator
+            #[cfg(feature = "annex-b")]
+            JsVariant::Object(object) if object.is_html_dda() => "undefined",
             JsVariant::Object(object) => {
                 if object.is_callable() {
                     "function"
@@ -91,6 +94,9 @@ impl JsVariant<'_> {
             JsVariant::Null => js_string!("object"),
             JsVariant::Undefined => js_string!("undefined"),
             JsVariant::BigInt(_) => js_string!("bigint"),
+            // B.3.6.3 Changes to the typeof Operator
+            #[cfg(feature = "annex-b")]
+            JsVariant::Object(object) if object.is_html_dda() => js_string!("undefined"),
             JsVariant::Object(object) => {
                 if object.is_callable() {
                     js_string!("function")
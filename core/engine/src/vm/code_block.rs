@@ -347,7 +347,8 @@ impl CodeBlock {
             | Instruction::CreateMappedArgumentsObject { dst }
             | Instruction::CreateUnmappedArgumentsObject { dst }
             | Instruction::RestParameterInit { dst }
-            | Instruction::PushNewArray { dst } => format!("dst:{dst}"),
+            | Instruction::PushNewArray { dst }
+            | Instruction::CreateDisposeCapability { dst } => format!("dst:{dst}"),
             Instruction::Add { lhs, rhs, dst }
             | Instruction::Sub { lhs, rhs, dst }
             | Instruction::Div { lhs, rhs, dst }
@@ -700,6 +701,9 @@ impl CodeBlock {
             Instruction::SetPrototype { object, prototype } => {
                 format!("object:{object}, prototype:{prototype}")
             }
+            Instruction::ImportCall { value, options } => {
+                format!("value:{value}, options:{options}")
+            }
             Instruction::PushValueToArray { value, array } => {
                 format!("value:{value}, array:{array}")
             }
@@ -712,7 +716,6 @@ impl CodeBlock {
             | Instruction::Pos { value }
             | Instruction::Neg { value }
             | Instruction::IsObject { value }
-            | Instruction::ImportCall { value }
             | Instruction::BindThisValue { value }
             | Instruction::BitNot { value } => {
                 format!("value:{value}")
@@ -776,12 +779,102 @@ impl CodeBlock {
             } => {
                 format!("src:{src}, configurable:{configurable}, name_index:{name_index}")
             }
+            Instruction::AddDisposableResource {
+                capability,
+                value,
+                hint,
+            } => {
+                format!("capability:{capability}, value:{value}, hint:{hint}")
+            }
+            Instruction::DisposeResources {
+                capability,
+                has_error,
+                error,
+            } => {
+                format!("capability:{capability}, has_error:{has_error}, error:{error}")
+            }
+            Instruction::DisposeResourcesAsync {
+                dst,
+                capability,
+                has_error,
+                error,
+            } => {
+                format!("dst:{dst}, capability:{capability}, has_error:{has_error}, error:{error}")
+            }
             Instruction::CreateGlobalVarBinding {
                 configurable,
                 name_index,
             } => {
                 format!("configurable:{configurable}, name_index:{name_index}")
             }
+            Instruction::CreateClassDecorations {
+                dst,
+                class,
+                superclass,
+            } => {
+                format!("dst:{dst}, class:{class}, superclass:{superclass}")
+            }
+            Instruction::PushClassElementDecorators {
+                decorations,
+                key,
+                decorators,
+                kind,
+            } => {
+                format!(
+                    "decorations:{decorations}, key:{key}, decorators:{decorators}, kind:{kind}"
+                )
+            }
+            Instruction::PushClassPrivateElementDecorators {
+                decorations,
+                decorators,
+                kind,
+                name_index,
+            } => {
+                format!(
+                    "decorations:{decorations}, decorators:{decorators}, kind:{kind}, name_index:{name_index}"
+                )
+            }
+            Instruction::ApplyClassDecorators {
+                dst,
+                decorations,
+                decorators,
+                name_index,
+            } => {
+                format!(
+                    "dst:{dst}, decorations:{decorations}, decorators:{decorators}, name_index:{name_index}"
+                )
+            }
+            Instruction::RunClassInitializers { decorations, class } => {
+                format!("decorations:{decorations}, class:{class}")
+            }
+            Instruction::ApplyFieldInitializers {
+                decorations,
+                index,
+                value,
+            } => {
+                format!("decorations:{decorations}, index:{index}, value:{value}")
+            }
+            Instruction::RunFieldExtraInitializers { decorations, index } => {
+                format!("decorations:{decorations}, index:{index}")
+            }
+            Instruction::DefineAutoAccessor {
+                class,
+                object,
+                key,
+                storage_index,
+            } => {
+                format!("class:{class}, object:{object}, key:{key}, storage_index:{storage_index}")
+            }
+            Instruction::DefinePrivateAutoAccessor {
+                class,
+                name_index,
+                storage_index,
+                is_static,
+            } => {
+                format!(
+                    "class:{class}, name_index:{name_index}, storage_index:{storage_index}, is_static:{is_static}"
+                )
+            }
             Instruction::PushPrivateEnvironment {
                 class,
                 name_indices,
@@ -827,20 +920,7 @@ impl CodeBlock {
             | Instruction::NewSpread
             | Instruction::SuperCallSpread
             | Instruction::PopPrivateEnvironment => String::new(),
-            Instruction::Reserved1
-            | Instruction::Reserved2
-            | Instruction::Reserved3
-            | Instruction::Reserved4
-            | Instruction::Reserved5
-            | Instruction::Reserved6
-            | Instruction::Reserved7
-            | Instruction::Reserved8
-            | Instruction::Reserved9
-            | Instruction::Reserved10
-            | Instruction::Reserved11
-            | Instruction::Reserved12
-            | Instruction::Reserved13
-            | Instruction::Reserved14
+            Instruction::Reserved14
             | Instruction::Reserved15
             | Instruction::Reserved16
             | Instruction::Reserved17
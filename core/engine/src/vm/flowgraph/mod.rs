@@ -442,27 +442,27 @@ impl CodeBlock {
                 | Instruction::CreateMappedArgumentsObject { .. }
                 | Instruction::CreateUnmappedArgumentsObject { .. }
                 | Instruction::CreateGlobalFunctionBinding { .. }
-                | Instruction::CreateGlobalVarBinding { .. } => {
+                | Instruction::CreateGlobalVarBinding { .. }
+                | Instruction::CreateDisposeCapability { .. }
+                | Instruction::AddDisposableResource { .. }
+                | Instruction::DisposeResources { .. }
+                | Instruction::DisposeResourcesAsync { .. }
+                | Instruction::CreateClassDecorations { .. }
+                | Instruction::PushClassElementDecorators { .. }
+                | Instruction::PushClassPrivateElementDecorators { .. }
+                | Instruction::ApplyClassDecorators { .. }
+                | Instruction::RunClassInitializers { .. }
+                | Instruction::ApplyFieldInitializers { .. }
+                | Instruction::RunFieldExtraInitializers { .. }
+                | Instruction::DefineAutoAccessor { .. }
+                | Instruction::DefinePrivateAutoAccessor { .. } => {
                     graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
                     graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
                 }
                 Instruction::Return => {
                     graph.add_node(previous_pc, NodeShape::Diamond, label.into(), Color::Red);
                 }
-                Instruction::Reserved1
-                | Instruction::Reserved2
-                | Instruction::Reserved3
-                | Instruction::Reserved4
-                | Instruction::Reserved5
-                | Instruction::Reserved6
-                | Instruction::Reserved7
-                | Instruction::Reserved8
-                | Instruction::Reserved9
-                | Instruction::Reserved10
-                | Instruction::Reserved11
-                | Instruction::Reserved12
-                | Instruction::Reserved13
-                | Instruction::Reserved14
+                Instruction::Reserved14
                 | Instruction::Reserved15
                 | Instruction::Reserved16
                 | Instruction::Reserved17
@@ -0,0 +1,444 @@
+use crate::{
+    builtins::function::{
+        decorators::{ClassDecorations, ClassElementKind, ElementKey},
+        set_function_name, OrdinaryFunction,
+    },
+    error::JsNativeError,
+    js_string,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsFunction, PrivateElement, PrivateName},
+    property::{PropertyDescriptor, PropertyKey},
+    vm::opcode::{Operation, VaryingOperand},
+    Context, JsArgs, JsObject, JsResult, JsValue,
+};
+use boa_macros::js_str;
+
+/// Gets the class decorations stored in the given register.
+fn class_decorations(decorations: VaryingOperand, context: &Context) -> JsObject {
+    context
+        .vm
+        .get_register(decorations.into())
+        .as_object()
+        .expect("class decorations register must contain an object")
+        .clone()
+}
+
+/// Gets the list of decorators stored as an array object in the given register.
+fn decorator_list(decorators: VaryingOperand, context: &Context) -> Vec<JsValue> {
+    context
+        .vm
+        .get_register(decorators.into())
+        .as_object()
+        .expect("decorators must be stored in an array object")
+        .borrow()
+        .properties()
+        .to_dense_indexed_properties()
+        .expect("decorators array must be dense")
+        .into_iter()
+        .collect()
+}
+
+/// `CreateClassDecorations` implements the Opcode Operation for `Opcode::CreateClassDecorations`
+///
+/// Operation:
+///  - Creates the record that collects the decorators of a class and of its elements.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CreateClassDecorations;
+
+impl CreateClassDecorations {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (dst, class, superclass): (VaryingOperand, VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let class = context
+            .vm
+            .get_register(class.into())
+            .as_object()
+            .expect("class must be function object")
+            .clone();
+        let superclass = context.vm.get_register(superclass.into()).clone();
+
+        let decorations = ClassDecorations::new(class, &superclass, context)?;
+        let decorations = JsObject::from_proto_and_data(None, decorations);
+        context.vm.set_register(dst.into(), decorations.into());
+        Ok(())
+    }
+}
+
+impl Operation for CreateClassDecorations {
+    const NAME: &'static str = "CreateClassDecorations";
+    const INSTRUCTION: &'static str = "INST - CreateClassDecorations";
+    const COST: u8 = 4;
+}
+
+/// `PushClassElementDecorators` implements the Opcode Operation for `Opcode::PushClassElementDecorators`
+///
+/// Operation:
+///  - Records the decorators of a class element with a public name.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushClassElementDecorators;
+
+impl PushClassElementDecorators {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (decorations, key, decorators, kind): (
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+        ),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let decorations = class_decorations(decorations, context);
+        let key = context
+            .vm
+            .get_register(key.into())
+            .clone()
+            .to_property_key(context)?;
+        let decorators = decorator_list(decorators, context);
+        let (kind, is_static) = ClassElementKind::decode(kind.into());
+
+        decorations
+            .downcast_mut::<ClassDecorations>()
+            .expect("must be class decorations")
+            .push_element(kind, is_static, ElementKey::Public(key), decorators);
+        Ok(())
+    }
+}
+
+impl Operation for PushClassElementDecorators {
+    const NAME: &'static str = "PushClassElementDecorators";
+    const INSTRUCTION: &'static str = "INST - PushClassElementDecorators";
+    const COST: u8 = 4;
+}
+
+/// `PushClassPrivateElementDecorators` implements the Opcode Operation for `Opcode::PushClassPrivateElementDecorators`
+///
+/// Operation:
+///  - Records the decorators of a class element with a private name.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushClassPrivateElementDecorators;
+
+impl PushClassPrivateElementDecorators {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (decorations, decorators, kind, index): (
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+        ),
+        context: &mut Context,
+    ) {
+        let decorations = class_decorations(decorations, context);
+        let name = context
+            .vm
+            .frame()
+            .code_block()
+            .constant_string(index.into());
+        let decorators = decorator_list(decorators, context);
+        let (kind, is_static) = ClassElementKind::decode(kind.into());
+
+        let mut decorations = decorations
+            .downcast_mut::<ClassDecorations>()
+            .expect("must be class decorations");
+        let name = decorations.class().private_name(name);
+        decorations.push_element(kind, is_static, ElementKey::Private(name), decorators);
+    }
+}
+
+impl Operation for PushClassPrivateElementDecorators {
+    const NAME: &'static str = "PushClassPrivateElementDecorators";
+    const INSTRUCTION: &'static str = "INST - PushClassPrivateElementDecorators";
+    const COST: u8 = 4;
+}
+
+/// `ApplyClassDecorators` implements the Opcode Operation for `Opcode::ApplyClassDecorators`
+///
+/// Operation:
+///  - Applies the decorators of the class elements and of the class.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ApplyClassDecorators;
+
+impl ApplyClassDecorators {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (dst, decorations, decorators, index): (
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+        ),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let decorations = class_decorations(decorations, context);
+        let decorators = decorator_list(decorators, context);
+        let class_name = context
+            .vm
+            .frame()
+            .code_block()
+            .constant_string(index.into());
+
+        let class = ClassDecorations::apply(&decorations, &decorators, &class_name, context)?;
+        context.vm.set_register(dst.into(), class.into());
+        Ok(())
+    }
+}
+
+impl Operation for ApplyClassDecorators {
+    const NAME: &'static str = "ApplyClassDecorators";
+    const INSTRUCTION: &'static str = "INST - ApplyClassDecorators";
+    const COST: u8 = 8;
+}
+
+/// `RunClassInitializers` implements the Opcode Operation for `Opcode::RunClassInitializers`
+///
+/// Operation:
+///  - Runs the initializers added by the class decorators.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RunClassInitializers;
+
+impl RunClassInitializers {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (decorations, class): (VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let decorations = class_decorations(decorations, context);
+        let class = context.vm.get_register(class.into()).clone();
+        ClassDecorations::run_class_initializers(&decorations, &class, context)
+    }
+}
+
+impl Operation for RunClassInitializers {
+    const NAME: &'static str = "RunClassInitializers";
+    const INSTRUCTION: &'static str = "INST - RunClassInitializers";
+    const COST: u8 = 4;
+}
+
+/// `ApplyFieldInitializers` implements the Opcode Operation for `Opcode::ApplyFieldInitializers`
+///
+/// Operation:
+///  - Passes the value of a decorated static field through the initializers of its decorators.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ApplyFieldInitializers;
+
+impl ApplyFieldInitializers {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (decorations, index, value): (VaryingOperand, VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let decorations = class_decorations(decorations, context);
+        let init_value = context.vm.get_register(value.into()).clone();
+        let init_value = ClassDecorations::apply_field_initializers(
+            &decorations,
+            index.into(),
+            init_value,
+            context,
+        )?;
+        context.vm.set_register(value.into(), init_value);
+        Ok(())
+    }
+}
+
+impl Operation for ApplyFieldInitializers {
+    const NAME: &'static str = "ApplyFieldInitializers";
+    const INSTRUCTION: &'static str = "INST - ApplyFieldInitializers";
+    const COST: u8 = 4;
+}
+
+/// `RunFieldExtraInitializers` implements the Opcode Operation for `Opcode::RunFieldExtraInitializers`
+///
+/// Operation:
+///  - Runs the initializers added by the decorators of a static field after it was defined.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RunFieldExtraInitializers;
+
+impl RunFieldExtraInitializers {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (decorations, index): (VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let decorations = class_decorations(decorations, context);
+        ClassDecorations::run_field_extra_initializers(&decorations, index.into(), context)
+    }
+}
+
+impl Operation for RunFieldExtraInitializers {
+    const NAME: &'static str = "RunFieldExtraInitializers";
+    const INSTRUCTION: &'static str = "INST - RunFieldExtraInitializers";
+    const COST: u8 = 4;
+}
+
+/// Creates the getter and setter of an auto-accessor, that read and write its private storage.
+fn auto_accessor_functions(
+    storage: &PrivateName,
+    context: &mut Context,
+) -> (JsFunction, JsFunction) {
+    let getter = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |this, _, storage: &PrivateName, context| {
+                let object = this.as_object().ok_or_else(|| {
+                    JsNativeError::typ().with_message("auto-accessor receiver must be an object")
+                })?;
+                object.private_get(storage, context)
+            },
+            storage.clone(),
+        ),
+    )
+    .build();
+
+    let setter = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |this, args, storage: &PrivateName, context| {
+                let object = this.as_object().ok_or_else(|| {
+                    JsNativeError::typ().with_message("auto-accessor receiver must be an object")
+                })?;
+                object.private_set(storage, args.get_or_undefined(0).clone(), context)?;
+                Ok(JsValue::undefined())
+            },
+            storage.clone(),
+        ),
+    )
+    .length(1)
+    .build();
+
+    (getter, setter)
+}
+
+/// `DefineAutoAccessor` implements the Opcode Operation for `Opcode::DefineAutoAccessor`
+///
+/// Operation:
+///  - Defines the getter and setter of an auto-accessor with a public name.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DefineAutoAccessor;
+
+impl DefineAutoAccessor {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (class, object, key, index): (
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+        ),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let class = context
+            .vm
+            .get_register(class.into())
+            .as_object()
+            .expect("class must be function object")
+            .clone();
+        let object = context
+            .vm
+            .get_register(object.into())
+            .as_object()
+            .expect("home object must be an object")
+            .clone();
+        let key: PropertyKey = context
+            .vm
+            .get_register(key.into())
+            .clone()
+            .to_property_key(context)?;
+        let storage = context
+            .vm
+            .frame()
+            .code_block()
+            .constant_string(index.into());
+        let storage = class.private_name(storage);
+
+        let (getter, setter) = auto_accessor_functions(&storage, context);
+        set_function_name(&getter, &key, Some(js_str!("get")), context);
+        set_function_name(&setter, &key, Some(js_str!("set")), context);
+
+        object.define_property_or_throw(
+            key,
+            PropertyDescriptor::builder()
+                .get(getter)
+                .set(setter)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+        Ok(())
+    }
+}
+
+impl Operation for DefineAutoAccessor {
+    const NAME: &'static str = "DefineAutoAccessor";
+    const INSTRUCTION: &'static str = "INST - DefineAutoAccessor";
+    const COST: u8 = 4;
+}
+
+/// `DefinePrivateAutoAccessor` implements the Opcode Operation for `Opcode::DefinePrivateAutoAccessor`
+///
+/// Operation:
+///  - Defines the getter and setter of an auto-accessor with a private name.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DefinePrivateAutoAccessor;
+
+impl DefinePrivateAutoAccessor {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (class, name_index, storage_index, is_static): (
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+        ),
+        context: &mut Context,
+    ) {
+        let class = context
+            .vm
+            .get_register(class.into())
+            .as_object()
+            .expect("class must be function object")
+            .clone();
+        let code_block = context.vm.frame().code_block();
+        let name = code_block.constant_string(name_index.into());
+        let storage = code_block.constant_string(storage_index.into());
+        let storage = class.private_name(storage);
+
+        let (getter, setter) = auto_accessor_functions(&storage, context);
+        for (function, prefix) in [(&getter, js_str!("get #")), (&setter, js_str!("set #"))] {
+            let name = js_string!(prefix, &name);
+            function
+                .define_property_or_throw(
+                    js_string!("name"),
+                    PropertyDescriptor::builder()
+                        .value(name)
+                        .writable(false)
+                        .enumerable(false)
+                        .configurable(true),
+                    context,
+                )
+                .expect("failed to set name property on private accessor");
+        }
+
+        let name = class.private_name(name);
+        let element = PrivateElement::Accessor {
+            getter: Some(getter.into()),
+            setter: Some(setter.into()),
+        };
+        if u32::from(is_static) != 0 {
+            class.borrow_mut().append_private_element(name, element);
+        } else {
+            class
+                .downcast_mut::<OrdinaryFunction>()
+                .expect("class must be function object")
+                .push_private_method(name, element);
+        }
+    }
+}
+
+impl Operation for DefinePrivateAutoAccessor {
+    const NAME: &'static str = "DefinePrivateAutoAccessor";
+    const INSTRUCTION: &'static str = "INST - DefinePrivateAutoAccessor";
+    const COST: u8 = 6;
+}
@@ -0,0 +1,152 @@
+use crate::{
+    builtins::disposable_stack::capability::{
+        create_disposable_resource, DisposeCapability, DisposeHint,
+    },
+    vm::opcode::{Operation, VaryingOperand},
+    Context, JsError, JsObject, JsResult,
+};
+
+/// Takes the dispose capability stored in the given register, leaving an empty one in its place.
+fn take_dispose_capability(capability: VaryingOperand, context: &Context) -> DisposeCapability {
+    let object = context
+        .vm
+        .get_register(capability.into())
+        .as_object()
+        .expect("dispose capability register must contain an object");
+    let mut capability = object
+        .downcast_mut::<DisposeCapability>()
+        .expect("dispose capability register must contain a dispose capability");
+    std::mem::take(&mut *capability)
+}
+
+/// `CreateDisposeCapability` implements the Opcode Operation for `Opcode::CreateDisposeCapability`
+///
+/// Operation:
+///  - Creates a new, empty dispose capability.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CreateDisposeCapability;
+
+impl CreateDisposeCapability {
+    #[inline(always)]
+    pub(crate) fn operation(dst: VaryingOperand, context: &mut Context) {
+        let capability = JsObject::from_proto_and_data(None, DisposeCapability::new());
+        context.vm.set_register(dst.into(), capability.into());
+    }
+}
+
+impl Operation for CreateDisposeCapability {
+    const NAME: &'static str = "CreateDisposeCapability";
+    const INSTRUCTION: &'static str = "INST - CreateDisposeCapability";
+    const COST: u8 = 2;
+}
+
+/// `AddDisposableResource` implements the Opcode Operation for `Opcode::AddDisposableResource`
+///
+/// Operation:
+///  - Adds the value of a `using` or `await using` binding to a dispose capability.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AddDisposableResource;
+
+impl AddDisposableResource {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (capability, value, hint): (VaryingOperand, VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let value = context.vm.get_register(value.into()).clone();
+        let hint = if u32::from(hint) != 0 {
+            DisposeHint::Async
+        } else {
+            DisposeHint::Sync
+        };
+
+        let Some(resource) = create_disposable_resource(&value, hint, None, context)? else {
+            return Ok(());
+        };
+
+        let object = context
+            .vm
+            .get_register(capability.into())
+            .as_object()
+            .expect("dispose capability register must contain an object")
+            .clone();
+        object
+            .downcast_mut::<DisposeCapability>()
+            .expect("dispose capability register must contain a dispose capability")
+            .push(resource);
+
+        Ok(())
+    }
+}
+
+impl Operation for AddDisposableResource {
+    const NAME: &'static str = "AddDisposableResource";
+    const INSTRUCTION: &'static str = "INST - AddDisposableResource";
+    const COST: u8 = 4;
+}
+
+/// `DisposeResources` implements the Opcode Operation for `Opcode::DisposeResources`
+///
+/// Operation:
+///  - Disposes all the sync-dispose resources of a dispose capability, throwing the resulting
+///    error if any.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DisposeResources;
+
+impl DisposeResources {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (capability, has_error, error): (VaryingOperand, VaryingOperand, VaryingOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let completion = completion(has_error, error, context);
+        take_dispose_capability(capability, context).dispose_resources(completion, context)
+    }
+}
+
+impl Operation for DisposeResources {
+    const NAME: &'static str = "DisposeResources";
+    const INSTRUCTION: &'static str = "INST - DisposeResources";
+    const COST: u8 = 6;
+}
+
+/// `DisposeResourcesAsync` implements the Opcode Operation for `Opcode::DisposeResourcesAsync`
+///
+/// Operation:
+///  - Disposes all the resources of a dispose capability, returning a promise that settles
+///    once every resource was disposed.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DisposeResourcesAsync;
+
+impl DisposeResourcesAsync {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (dst, capability, has_error, error): (
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+            VaryingOperand,
+        ),
+        context: &mut Context,
+    ) {
+        let completion = completion(has_error, error, context);
+        let promise = take_dispose_capability(capability, context)
+            .dispose_resources_async(completion, context);
+        context.vm.set_register(dst.into(), promise.into());
+    }
+}
+
+impl Operation for DisposeResourcesAsync {
+    const NAME: &'static str = "DisposeResourcesAsync";
+    const INSTRUCTION: &'static str = "INST - DisposeResourcesAsync";
+    const COST: u8 = 6;
+}
+
+/// Builds the completion of a dispose scope from its `has_error` and `error` registers.
+fn completion(has_error: VaryingOperand, error: VaryingOperand, context: &Context) -> JsResult<()> {
+    if context.vm.get_register(has_error.into()).to_boolean() {
+        let error = context.vm.get_register(error.into()).clone();
+        return Err(JsError::from_opaque(error));
+    }
+    Ok(())
+}
@@ -18,8 +18,10 @@ mod call;
 mod concat;
 mod control_flow;
 mod copy;
+mod decorator;
 mod define;
 mod delete;
+mod dispose;
 mod environment;
 mod generator;
 mod get;
@@ -52,10 +54,14 @@ pub(crate) use control_flow::*;
 #[doc(inline)]
 pub(crate) use copy::*;
 #[doc(inline)]
+pub(crate) use decorator::*;
+#[doc(inline)]
 pub(crate) use define::*;
 #[doc(inline)]
 pub(crate) use delete::*;
 #[doc(inline)]
+pub(crate) use dispose::*;
+#[doc(inline)]
 pub(crate) use environment::*;
 #[doc(inline)]
 pub(crate) use generator::*;
@@ -1658,9 +1664,12 @@ generate_opcodes! {
     /// Dynamically import a module.
     ///
     /// - Registers:
-    ///   - Input: value
+    ///   - Input: value, options
     ///   - Output: value
-    ImportCall { value: VaryingOperand },
+    ImportCall {
+        value: VaryingOperand,
+        options: VaryingOperand
+    },
 
     /// Pop the two values of the stack, strict equal compares the two values,
     /// if true jumps to address, otherwise push the second pop'ed value.
@@ -2093,32 +2102,125 @@ generate_opcodes! {
     /// [spec]: https://tc39.es/ecma262/#sec-createglobalvarbinding
     CreateGlobalVarBinding { configurable: VaryingOperand, name_index: VaryingOperand },
 
-    /// Reserved [`Opcode`].
-    Reserved1 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved2 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved3 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved4 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved5 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved6 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved7 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved8 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved9 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved10 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved11 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved12 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved13 => Reserved,
+    /// Creates a new, empty dispose capability.
+    ///
+    /// - Registers:
+    ///   - Output: dst
+    CreateDisposeCapability { dst: VaryingOperand },
+
+    /// Performs [`AddDisposableResource ( disposeCapability, V, hint )`][spec]
+    ///
+    /// - Operands:
+    ///   - hint: `bool`, `true` for async-dispose and `false` for sync-dispose.
+    /// - Registers:
+    ///   - Input: capability, value
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource
+    AddDisposableResource { capability: VaryingOperand, value: VaryingOperand, hint: VaryingOperand },
+
+    /// Performs [`DisposeResources ( disposeCapability, completion )`][spec] on a
+    /// dispose capability that only contains sync-dispose resources.
+    ///
+    /// The completion is an exception if `has_error` is `true`, otherwise it is a normal completion.
+    ///
+    /// - Registers:
+    ///   - Input: capability, has_error, error
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    DisposeResources { capability: VaryingOperand, has_error: VaryingOperand, error: VaryingOperand },
+
+    /// Performs [`DisposeResources ( disposeCapability, completion )`][spec] on a
+    /// dispose capability that contains async-dispose resources.
+    ///
+    /// The completion is an exception if `has_error` is `true`, otherwise it is a normal completion.
+    /// The result is a promise that settles once all the resources have been disposed.
+    ///
+    /// - Registers:
+    ///   - Input: capability, has_error, error
+    ///   - Output: dst
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    DisposeResourcesAsync { dst: VaryingOperand, capability: VaryingOperand, has_error: VaryingOperand, error: VaryingOperand },
+
+    /// Creates the record that collects the decorators of a class and of its elements, along with
+    /// the metadata object of the class.
+    ///
+    /// - Registers:
+    ///   - Input: class, superclass
+    ///   - Output: dst
+    ///
+    /// [spec]: https://tc39.es/proposal-decorators/
+    CreateClassDecorations { dst: VaryingOperand, class: VaryingOperand, superclass: VaryingOperand },
+
+    /// Records the decorators of a class element with a public name.
+    ///
+    /// - Operands:
+    ///   - kind: `ClassElementKind`, with the static flag encoded in it.
+    /// - Registers:
+    ///   - Input: decorations, key, decorators
+    PushClassElementDecorators { decorations: VaryingOperand, key: VaryingOperand, decorators: VaryingOperand, kind: VaryingOperand },
+
+    /// Records the decorators of a class element with a private name.
+    ///
+    /// - Operands:
+    ///   - kind: `ClassElementKind`, with the static flag encoded in it.
+    ///   - `name_index`: `VaryingOperand`
+    /// - Registers:
+    ///   - Input: decorations, decorators
+    PushClassPrivateElementDecorators { decorations: VaryingOperand, decorators: VaryingOperand, kind: VaryingOperand, name_index: VaryingOperand },
+
+    /// Applies the decorators of the class elements and of the class, then runs the initializers
+    /// added by the decorators of static methods and accessors.
+    ///
+    /// - Operands:
+    ///   - `name_index`: `VaryingOperand`
+    /// - Registers:
+    ///   - Input: decorations, decorators
+    ///   - Output: dst
+    ApplyClassDecorators { dst: VaryingOperand, decorations: VaryingOperand, decorators: VaryingOperand, name_index: VaryingOperand },
+
+    /// Runs the initializers added by the class decorators.
+    ///
+    /// - Registers:
+    ///   - Input: decorations, class
+    RunClassInitializers { decorations: VaryingOperand, class: VaryingOperand },
+
+    /// Passes the value of a decorated static field through the initializers of its decorators.
+    ///
+    /// - Operands:
+    ///   - index: `VaryingOperand`
+    /// - Registers:
+    ///   - Input: decorations, value
+    ///   - Output: value
+    ApplyFieldInitializers { decorations: VaryingOperand, index: VaryingOperand, value: VaryingOperand },
+
+    /// Runs the initializers added by the decorators of a static field after it was defined.
+    ///
+    /// - Operands:
+    ///   - index: `VaryingOperand`
+    /// - Registers:
+    ///   - Input: decorations
+    RunFieldExtraInitializers { decorations: VaryingOperand, index: VaryingOperand },
+
+    /// Defines the getter and setter of an auto-accessor with a public name, backed by the
+    /// private storage `storage_index`.
+    ///
+    /// - Operands:
+    ///   - `storage_index`: `VaryingOperand`
+    /// - Registers:
+    ///   - Input: class, object, key
+    DefineAutoAccessor { class: VaryingOperand, object: VaryingOperand, key: VaryingOperand, storage_index: VaryingOperand },
+
+    /// Defines the getter and setter of an auto-accessor with a private name, backed by the
+    /// private storage `storage_index`.
+    ///
+    /// - Operands:
+    ///   - `name_index`: `VaryingOperand`
+    ///   - `storage_index`: `VaryingOperand`
+    ///   - `is_static`: `bool`
+    /// - Registers:
+    ///   - Input: class
+    DefinePrivateAutoAccessor { class: VaryingOperand, name_index: VaryingOperand, storage_index: VaryingOperand, is_static: VaryingOperand },
     /// Reserved [`Opcode`].
     Reserved14 => Reserved,
     /// Reserved [`Opcode`].
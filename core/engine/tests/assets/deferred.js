@@ -0,0 +1,3 @@
+globalThis.deferredEvaluated = true;
+
+export const value = "deferred";
@@ -45,3 +45,96 @@ fn subdirectories() {
         }
     }
 }
+
+/// Test that the simple module loader loads JSON modules from import attributes.
+#[test]
+fn json_modules() {
+    let assets_dir =
+        PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("tests/assets");
+
+    let loader = Rc::new(SimpleModuleLoader::new(assets_dir).unwrap());
+    let mut context = Context::builder()
+        .module_loader(loader.clone())
+        .build()
+        .unwrap();
+
+    let source = Source::from_bytes(
+        br#"
+        import config from "config.json" with { type: "json" };
+        const dynamic = await import("config.json", { with: { type: "json" } });
+        export const same = config === dynamic.default;
+        export const name = config.name;
+        export const error = await import("config.json").then(() => null, (e) => e.name);
+        "#,
+    );
+    let module = boa_engine::Module::parse(source, None, &mut context).unwrap();
+    let result = module.load_link_evaluate(&mut context);
+
+    context.run_jobs().unwrap();
+    match result.state() {
+        PromiseState::Pending => {}
+        PromiseState::Fulfilled(v) => {
+            assert!(v.is_undefined());
+
+            let namespace = module.namespace(&mut context);
+            let same = namespace.get(js_string!("same"), &mut context).unwrap();
+            let name = namespace.get(js_string!("name"), &mut context).unwrap();
+            let error = namespace.get(js_string!("error"), &mut context).unwrap();
+
+            assert_eq!(same, JsValue::new(true));
+            assert_eq!(name, js_string!("boa").into());
+            assert_eq!(error, js_string!("SyntaxError").into());
+        }
+        PromiseState::Rejected(reason) => {
+            panic!("Module failed to load: {}", reason.display());
+        }
+    }
+}
+
+/// Test that deferred imports only evaluate the imported module on first access.
+#[cfg(feature = "experimental")]
+#[test]
+fn deferred_imports() {
+    let assets_dir =
+        PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("tests/assets");
+
+    let loader = Rc::new(SimpleModuleLoader::new(assets_dir).unwrap());
+    let mut context = Context::builder()
+        .module_loader(loader.clone())
+        .build()
+        .unwrap();
+
+    let source = Source::from_bytes(
+        br#"
+        import defer * as ns from "deferred.js";
+        export const before = globalThis.deferredEvaluated ?? false;
+        export const tag = ns[Symbol.toStringTag];
+        export const value = ns.value;
+        export const after = globalThis.deferredEvaluated;
+        "#,
+    );
+    let module = boa_engine::Module::parse(source, None, &mut context).unwrap();
+    let result = module.load_link_evaluate(&mut context);
+
+    context.run_jobs().unwrap();
+    match result.state() {
+        PromiseState::Pending => {}
+        PromiseState::Fulfilled(v) => {
+            assert!(v.is_undefined());
+
+            let namespace = module.namespace(&mut context);
+            let before = namespace.get(js_string!("before"), &mut context).unwrap();
+            let tag = namespace.get(js_string!("tag"), &mut context).unwrap();
+            let value = namespace.get(js_string!("value"), &mut context).unwrap();
+            let after = namespace.get(js_string!("after"), &mut context).unwrap();
+
+            assert_eq!(before, JsValue::new(false));
+            assert_eq!(tag, js_string!("Deferred Module").into());
+            assert_eq!(value, js_string!("deferred").into());
+            assert_eq!(after, JsValue::new(true));
+        }
+        PromiseState::Rejected(reason) => {
+            panic!("Module failed to load: {}", reason.display());
+        }
+    }
+}
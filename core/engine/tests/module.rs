@@ -3,7 +3,7 @@
 use std::rc::Rc;
 
 use boa_engine::builtins::promise::PromiseState;
-use boa_engine::module::{ModuleLoader, Referrer};
+use boa_engine::module::{ModuleLoader, ModuleRequest, Referrer};
 use boa_engine::{js_string, Context, JsResult, JsString, Module, Source};
 
 #[test]
@@ -13,11 +13,12 @@ fn test_json_module_from_str() {
         fn load_imported_module(
             &self,
             _referrer: Referrer,
-            specifier: JsString,
+            request: ModuleRequest,
             finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
             context: &mut Context,
         ) {
-            assert_eq!(specifier.to_std_string_escaped(), "basic");
+            assert_eq!(request.specifier().to_std_string_escaped(), "basic");
+            assert_eq!(request.get_attribute("type"), Some(&js_string!("json")));
 
             finish_load(
                 Ok(Module::parse_json(self.0.clone(), context).unwrap()),
@@ -34,7 +35,7 @@ fn test_json_module_from_str() {
 
     let source = Source::from_bytes(
         b"
-        import basic_json from 'basic';
+        import basic_json from 'basic' with { type: 'json' };
         export let json = basic_json;
     ",
     );
@@ -541,6 +541,15 @@ pub fn force_collect() {
     });
 }
 
+/// Returns the number of garbage collections that have run on the current thread.
+///
+/// This can be used to detect if any weak references could have been invalidated since the
+/// last time this was checked.
+#[must_use]
+pub fn collections() -> usize {
+    BOA_GC.with(|current| current.borrow().runtime.collections)
+}
+
 #[cfg(test)]
 mod test;
 
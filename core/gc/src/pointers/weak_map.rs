@@ -54,6 +54,13 @@ impl<K: Trace + ?Sized, V: Trace + Clone> WeakMap<K, V> {
     pub fn get(&self, key: &Gc<K>) -> Option<V> {
         self.inner.borrow().get(key)
     }
+
+    /// Returns the value corresponding to the key, inserting `value` if the map did not contain
+    /// the key.
+    #[inline]
+    pub fn get_or_insert(&mut self, key: &Gc<K>, value: V) -> V {
+        self.inner.borrow_mut().get_or_insert(key, value)
+    }
 }
 
 /// A hash map where the bucket type is an <code>[Ephemeron]\<K, V\></code>.
@@ -313,6 +320,26 @@ where
         old
     }
 
+    /// Returns the value corresponding to the supplied key, inserting `v` if the map did not
+    /// have this key present.
+    pub(crate) fn get_or_insert(&mut self, k: &Gc<K>, v: V) -> V {
+        let hash = make_hash_from_gc(&self.hash_builder, k);
+        let hasher = make_hasher(&self.hash_builder);
+        let entry = self.table.entry(hash, equivalent_key(k), hasher);
+        let slot = match entry {
+            RawEntry::Occupied(occupied_entry) => {
+                if let Some(value) = occupied_entry.get().value() {
+                    return value;
+                }
+                occupied_entry.remove().1
+            }
+            RawEntry::Vacant(vacant_entry) => vacant_entry,
+        };
+
+        slot.insert(Ephemeron::new(k, v.clone()));
+        v
+    }
+
     /// Removes a key from the map, returning the value at the key if the key
     /// was previously in the map. Keeps the allocated memory for reuse.
     pub(crate) fn remove(&mut self, k: &Gc<K>) -> Option<V> {
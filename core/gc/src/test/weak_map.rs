@@ -136,3 +136,24 @@ fn weak_map_key_live() {
         assert!(map.contains_key(&key_copy));
     });
 }
+
+#[test]
+fn weak_map_get_or_insert() {
+    run_test(|| {
+        let key1 = Gc::new(String::from("key1"));
+        let key2 = Gc::new(String::from("key2"));
+
+        let mut map = WeakMap::new();
+
+        map.insert(&key1, 1);
+
+        assert_eq!(map.get_or_insert(&key1, 2), 1);
+        assert_eq!(map.get_or_insert(&key2, 3), 3);
+        assert_eq!(map.get(&key2), Some(3));
+
+        force_collect();
+
+        assert_eq!(map.get_or_insert(&key1, 4), 1);
+        assert_eq!(map.get_or_insert(&key2, 5), 3);
+    });
+}
@@ -113,15 +113,18 @@ macro_rules! provider_from_icu_crate {
 static PROVIDER: Lazy<LocaleFallbackProvider<MultiForkByMarkerProvider<LazyBufferProvider>>> =
     Lazy::new(|| {
         let provider = MultiForkByMarkerProvider::new(alloc::vec![
+            provider_from_icu_crate!(icu_calendar),
             provider_from_icu_crate!(icu_casemap),
             provider_from_icu_crate!(icu_collator),
             provider_from_icu_crate!(icu_datetime),
             provider_from_icu_crate!(icu_decimal),
+            provider_from_icu_crate!(icu_experimental),
             provider_from_icu_crate!(icu_list),
             provider_from_icu_crate!(icu_locale),
             provider_from_icu_crate!(icu_normalizer),
             provider_from_icu_crate!(icu_plurals),
             provider_from_icu_crate!(icu_segmenter),
+            provider_from_icu_crate!(icu_time),
         ]);
         let fallbacker = LocaleFallbacker::try_new_with_buffer_provider(&provider)
             .expect("The statically compiled data file should be valid.");
@@ -1,11 +1,12 @@
 //! Embedded module loader. Creates a `ModuleLoader` instance that contains
 //! files embedded in the binary at build time.
 
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::path::Path;
 
-use boa_engine::module::{ModuleLoader, Referrer};
+use boa_engine::module::{ModuleLoader, ModuleRequest, ModuleType, Referrer};
 use boa_engine::{Context, JsNativeError, JsResult, JsString, Module, Source};
 
 /// Create a module loader that embeds files from the filesystem at build
@@ -26,48 +27,59 @@ macro_rules! embed_module {
 }
 
 #[derive(Debug, Clone)]
-enum EmbeddedModuleEntry {
-    Source(CompressType, JsString, &'static [u8]),
-    Module(Module),
+struct EmbeddedModuleEntry {
+    compress_type: CompressType,
+    path: JsString,
+    source: &'static [u8],
+    module: Option<Module>,
+    json_module: Option<Module>,
 }
 
 impl EmbeddedModuleEntry {
     fn from_source(compress_type: CompressType, path: JsString, source: &'static [u8]) -> Self {
-        Self::Source(compress_type, path, source)
+        Self {
+            compress_type,
+            path,
+            source,
+            module: None,
+            json_module: None,
+        }
     }
 
-    fn cache(&mut self, context: &mut Context) -> JsResult<&Module> {
-        if let Self::Source(compress, path, source) = self {
-            let mut bytes: &[u8] = match compress {
-                CompressType::None => source,
+    fn cache(&mut self, module_type: ModuleType, context: &mut Context) -> JsResult<&Module> {
+        let cached = match module_type {
+            ModuleType::Json => &mut self.json_module,
+            _ => &mut self.module,
+        };
+
+        if cached.is_none() {
+            let bytes: Cow<'static, [u8]> = match self.compress_type {
+                CompressType::None => Cow::Borrowed(self.source),
 
                 #[cfg(feature = "embedded_lz4")]
-                CompressType::Lz4 => &lz4_flex::decompress_size_prepended(source)
-                    .map_err(|e| boa_engine::js_error!("Could not decompress module: {}", e))?,
+                CompressType::Lz4 => Cow::Owned(
+                    lz4_flex::decompress_size_prepended(self.source)
+                        .map_err(|e| boa_engine::js_error!("Could not decompress module: {}", e))?,
+                ),
             };
-            let path = path.to_std_string_escaped();
-            let source = Source::from_reader(&mut bytes, Some(Path::new(&path)));
-            match Module::parse(source, None, context) {
-                Ok(module) => {
-                    *self = Self::Module(module);
-                }
-                Err(err) => {
-                    return Err(err);
-                }
-            }
+            let module = if module_type == ModuleType::Json {
+                let json = std::str::from_utf8(&bytes).map_err(
+                    |e| boa_engine::js_error!(SyntaxError: "JSON module is not valid UTF-8: {}", e),
+                )?;
+                Module::parse_json(JsString::from(json), context)?
+            } else {
+                let path = self.path.to_std_string_escaped();
+                let source = Source::from_reader(&*bytes, Some(Path::new(&path)));
+                Module::parse(source, None, context)?
+            };
+            *cached = Some(module);
         }
 
-        match self {
-            Self::Module(module) => Ok(module),
-            EmbeddedModuleEntry::Source(_, _, _) => unreachable!(),
-        }
+        Ok(cached.as_ref().expect("module was cached above"))
     }
 
     fn as_module(&self) -> Option<&Module> {
-        match self {
-            Self::Module(module) => Some(module),
-            Self::Source(_, _, _) => None,
-        }
+        self.module.as_ref()
     }
 }
 
@@ -129,16 +141,22 @@ impl ModuleLoader for EmbeddedModuleLoader {
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
-        let Ok(specifier_path) = boa_engine::module::resolve_module_specifier(
-            None,
-            &specifier,
-            referrer.path(),
-            context,
-        ) else {
+        let specifier = request.specifier();
+        let module_type = match ModuleType::from_request(&request) {
+            Ok(module_type) => module_type,
+            Err(err) => {
+                finish_load(Err(err), context);
+                return;
+            }
+        };
+
+        let Ok(specifier_path) =
+            boa_engine::module::resolve_module_specifier(None, specifier, referrer.path(), context)
+        else {
             let err = JsNativeError::typ().with_message(format!(
                 "could not resolve module specifier `{}`",
                 specifier.to_std_string_escaped()
@@ -152,7 +170,7 @@ impl ModuleLoader for EmbeddedModuleLoader {
             .get(&JsString::from(specifier_path.to_string_lossy().as_ref()))
         {
             let mut embedded = module.borrow_mut();
-            let module = embedded.cache(context);
+            let module = embedded.cache(module_type, context);
 
             finish_load(module.cloned(), context);
         } else {
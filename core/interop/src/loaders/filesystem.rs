@@ -1,10 +1,14 @@
 //! Filesystem module loader. Loads modules from the filesystem.
 
-use boa_engine::module::{resolve_module_specifier, ModuleLoader, Referrer};
+use boa_engine::module::{
+    resolve_module_specifier, ModuleLoader, ModuleRequest, ModuleType, Referrer,
+};
 use boa_engine::{js_string, Context, JsError, JsNativeError, JsResult, JsString, Module, Source};
 use std::path::{Path, PathBuf};
 
 /// A module loader that loads modules from the filesystem.
+///
+/// Modules imported with a `type: "json"` attribute are parsed as JSON modules.
 #[derive(Clone, Debug)]
 pub struct FsModuleLoader {
     root: PathBuf,
@@ -32,14 +36,29 @@ impl ModuleLoader for FsModuleLoader {
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
         let result = (|| -> JsResult<Module> {
+            let specifier = request.specifier();
             let short_path = specifier.to_std_string_escaped();
             let path =
-                resolve_module_specifier(Some(&self.root), &specifier, referrer.path(), context)?;
+                resolve_module_specifier(Some(&self.root), specifier, referrer.path(), context)?;
+
+            if ModuleType::from_request(&request)? == ModuleType::Json {
+                let json = std::fs::read_to_string(&path).map_err(|err| {
+                    JsNativeError::typ()
+                        .with_message(format!("could not open file `{short_path}`"))
+                        .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
+                })?;
+                return Module::parse_json(JsString::from(json), context).map_err(|err| {
+                    JsNativeError::syntax()
+                        .with_message(format!("could not parse JSON module `{short_path}`"))
+                        .with_cause(err)
+                        .into()
+                });
+            }
 
             let source = Source::from_filepath(&path).map_err(|err| {
                 JsNativeError::typ()
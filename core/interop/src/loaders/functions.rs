@@ -1,9 +1,11 @@
 //! This module contains types that help create custom module loaders from functions.
-use boa_engine::module::{resolve_module_specifier, ModuleLoader, Referrer};
+use boa_engine::module::{
+    resolve_module_specifier, ModuleLoader, ModuleRequest, ModuleType, Referrer,
+};
 use boa_engine::{Context, JsError, JsNativeError, JsResult, JsString, Module, Source};
 use std::io::Cursor;
 
-/// Create a [`ModuleLoader`] from a function that takes a referrer and a path,
+/// Create a [`ModuleLoader`] from a function that takes a referrer and a module request,
 /// and returns a [Module] if it exists, or an error.
 ///
 /// This function cannot be `async` and must be blocking. An `async` version of
@@ -13,7 +15,7 @@ use std::io::Cursor;
 #[derive(Copy, Clone)]
 pub struct FnModuleLoader<F>
 where
-    F: Fn(&Referrer, &JsString) -> JsResult<Module>,
+    F: Fn(&Referrer, &ModuleRequest) -> JsResult<Module>,
 {
     factory: F,
     name: &'static str,
@@ -21,7 +23,7 @@ where
 
 impl<F> FnModuleLoader<F>
 where
-    F: Fn(&Referrer, &JsString) -> JsResult<Module>,
+    F: Fn(&Referrer, &ModuleRequest) -> JsResult<Module>,
 {
     /// Create a new [`FnModuleLoader`] from a function that takes a path and returns
     /// a [Module] if it exists.
@@ -38,7 +40,7 @@ where
 
 impl<F> std::fmt::Debug for FnModuleLoader<F>
 where
-    F: Fn(&Referrer, &JsString) -> JsResult<Module>,
+    F: Fn(&Referrer, &ModuleRequest) -> JsResult<Module>,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_tuple("FnModuleLoader").field(&self.name).finish()
@@ -47,16 +49,16 @@ where
 
 impl<F> ModuleLoader for FnModuleLoader<F>
 where
-    F: Fn(&Referrer, &JsString) -> JsResult<Module>,
+    F: Fn(&Referrer, &ModuleRequest) -> JsResult<Module>,
 {
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
-        finish_load((self.factory)(&referrer, &specifier), context);
+        finish_load((self.factory)(&referrer, &request), context);
     }
 }
 
@@ -65,6 +67,8 @@ where
 /// passing it. If the source cannot be found or would generate an
 /// error, the function should return `None`.
 ///
+/// Modules imported with a `type: "json"` attribute are parsed as JSON modules.
+///
 /// This function cannot be `async` and must be blocking. An `async` version of
 /// this code will likely exist as a separate function in the future.
 ///
@@ -107,23 +111,32 @@ where
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
-        match resolve_module_specifier(None, &specifier, referrer.path(), context) {
+        let module_type = match ModuleType::from_request(&request) {
+            Ok(module_type) => module_type,
+            Err(e) => return finish_load(Err(e), context),
+        };
+
+        match resolve_module_specifier(None, request.specifier(), referrer.path(), context) {
             Err(e) => finish_load(Err(e), context),
             Ok(p) => {
                 let m = match self.0(&p.to_string_lossy()) {
-                    Some(source) => Ok(Source::from_reader(
-                        Cursor::new(source.into_bytes()),
-                        Some(&p),
-                    )),
+                    Some(source) if module_type == ModuleType::Json => {
+                        Module::parse_json(JsString::from(source), context)
+                    }
+                    Some(source) => Module::parse(
+                        Source::from_reader(Cursor::new(source.into_bytes()), Some(&p)),
+                        None,
+                        context,
+                    ),
                     None => Err(JsError::from_native(
                         JsNativeError::error().with_message("Module not found"),
                     )),
                 };
-                finish_load(m.and_then(|s| Module::parse(s, None, context)), context);
+                finish_load(m, context);
             }
         }
     }
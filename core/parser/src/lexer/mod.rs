@@ -315,6 +315,11 @@ impl<R> Lexer<R> {
                     self.cursor.pos_group(),
                 )),
                 '#' => PrivateIdentifier::new().lex(&mut self.cursor, start, interner),
+                '@' => Ok(Token::new_by_position_group(
+                    Punctuator::At.into(),
+                    start,
+                    self.cursor.pos_group(),
+                )),
                 '/' => self.lex_slash_token(start, interner, false),
                 #[cfg(feature = "annex-b")]
                 // <!--
@@ -139,7 +139,15 @@ impl<R> Tokenizer<R> for RegexLiteral {
             }
         }
 
-        if let Err(error) = Regex::from_unicode(body.into_iter(), flags_str) {
+        let mut pattern = body;
+        if let Err(error) = rename_duplicate_named_groups(&mut pattern, flags_str.contains('v')) {
+            return Err(Error::syntax(
+                format!("Invalid regular expression literal: {error}"),
+                start_pos,
+            ));
+        }
+
+        if let Err(error) = Regex::from_unicode(pattern.into_iter(), flags_str) {
             return Err(Error::syntax(
                 format!("Invalid regular expression literal: {error}"),
                 start_pos,
@@ -273,3 +281,157 @@ impl From<RegExpFlags> for Flags {
         }
     }
 }
+
+/// Renames the duplicate named capturing groups of `pattern` to unique names, since `regress`
+/// does not support [duplicate named capturing groups][proposal].
+///
+/// Duplicate group names are only allowed in different alternatives of a disjunction, which
+/// means at most one of them can participate in a match. The first group with a given name keeps
+/// its name, while the rest are renamed in place to names that don't appear in the pattern.
+/// Backreferences to a duplicate name are rewritten to numbered backreferences to all the groups
+/// with that name.
+///
+/// Returns the `(new name, original name)` pairs of the renamed groups.
+///
+/// # Errors
+///
+/// Returns an error if two groups with the same name could both participate in a match.
+///
+/// [proposal]: https://github.com/tc39/proposal-duplicate-named-capturing-groups
+pub fn rename_duplicate_named_groups(
+    pattern: &mut Vec<u32>,
+    unicode_sets: bool,
+) -> Result<Vec<(String, String)>, &'static str> {
+    let is = |i: usize, c: char| pattern.get(i).copied() == Some(u32::from(c));
+
+    // The alternative of each enclosing group in which the current position is, as pairs of the
+    // identifier of the group and the index of the alternative.
+    let mut path = vec![(0usize, 0usize)];
+    let mut group_count = 0;
+    let mut capture_count = 0;
+    let mut class_depth = 0usize;
+    let mut groups = Vec::new();
+    let mut backreferences = Vec::new();
+
+    let mut i = 0;
+    while i < pattern.len() {
+        match char::from_u32(pattern[i]) {
+            Some('\\') => {
+                if class_depth == 0 && is(i + 1, 'k') && is(i + 2, '<') {
+                    let start = i + 3;
+                    if let Some(len) = pattern[start..].iter().position(|&c| c == u32::from('>')) {
+                        let name = pattern[start..start + len]
+                            .iter()
+                            .filter_map(|&c| char::from_u32(c))
+                            .collect::<String>();
+                        backreferences.push((name, i..start + len + 1));
+                    }
+                }
+                i += 1;
+            }
+            // Classes can only be nested in `v` mode.
+            Some('[') if class_depth == 0 || unicode_sets => class_depth += 1,
+            Some(']') if class_depth > 0 => class_depth -= 1,
+            _ if class_depth > 0 => {}
+            Some('(') => {
+                if !is(i + 1, '?') {
+                    capture_count += 1;
+                } else if is(i + 2, '<') && !is(i + 3, '=') && !is(i + 3, '!') {
+                    capture_count += 1;
+                    let start = i + 3;
+                    if let Some(len) = pattern[start..].iter().position(|&c| c == u32::from('>')) {
+                        let name = pattern[start..start + len]
+                            .iter()
+                            .filter_map(|&c| char::from_u32(c))
+                            .collect::<String>();
+                        groups.push((name, start..start + len, path.clone(), capture_count));
+                    }
+                }
+                group_count += 1;
+                path.push((group_count, 0));
+            }
+            Some(')') if path.len() > 1 => {
+                path.pop();
+            }
+            Some('|') => {
+                if let Some((_, alternative)) = path.last_mut() {
+                    *alternative += 1;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    // Two groups can both participate in a match unless they are in different alternatives of
+    // the innermost group that contains both of them.
+    let can_both_participate = |a: &[(usize, usize)], b: &[(usize, usize)]| {
+        for ((group_a, alternative_a), (group_b, alternative_b)) in a.iter().zip(b) {
+            if group_a != group_b {
+                return true;
+            }
+            if alternative_a != alternative_b {
+                return false;
+            }
+        }
+        true
+    };
+
+    let mut names = groups
+        .iter()
+        .map(|(name, _, _, _)| name.clone())
+        .collect::<std::collections::HashSet<_>>();
+    let mut renames = Vec::new();
+    for (index, (name, range, path, _)) in groups.iter().enumerate() {
+        let mut previous = groups[..index]
+            .iter()
+            .filter(|(other, _, _, _)| other == name)
+            .peekable();
+        if previous.peek().is_none() {
+            continue;
+        }
+        if previous.any(|(_, _, other, _)| can_both_participate(other, path)) {
+            return Err("Duplicate capture group name");
+        }
+
+        let mut suffix = 1;
+        while names.contains(&format!("{name}${suffix}")) {
+            suffix += 1;
+        }
+        let new_name = format!("{name}${suffix}");
+        names.insert(new_name.clone());
+        renames.push((range.clone(), new_name, name.clone()));
+    }
+
+    // A backreference refers to all the groups with its name. At most one of them participates
+    // in a match and the others match the empty string, so it is replaced by a backreference to
+    // each of them in sequence. The groups are referred to by number, since the renamed groups
+    // must not be visible through their new names.
+    let mut replacements = renames
+        .iter()
+        .map(|(range, new_name, _)| (range.clone(), new_name.clone()))
+        .collect::<Vec<_>>();
+    for (name, range) in backreferences {
+        if !renames.iter().any(|(_, _, original)| *original == name) {
+            continue;
+        }
+        let mut replacement = String::from("(?:");
+        for (_, _, _, number) in groups.iter().filter(|(other, _, _, _)| *other == name) {
+            replacement.push('\\');
+            replacement.push_str(&number.to_string());
+        }
+        replacement.push(')');
+        replacements.push((range, replacement));
+    }
+    replacements.sort_by_key(|(range, _)| range.start);
+
+    // Replace from last to first, so the ranges of the remaining replacements stay valid.
+    for (range, replacement) in replacements.into_iter().rev() {
+        pattern.splice(range, replacement.chars().map(u32::from));
+    }
+
+    Ok(renames
+        .into_iter()
+        .map(|(_, new_name, name)| (new_name, name))
+        .collect())
+}
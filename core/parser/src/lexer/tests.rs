@@ -1,6 +1,7 @@
 //! Tests for the lexer.
 
 use crate::lexer::{
+    regex::rename_duplicate_named_groups,
     template::TemplateString,
     token::{ContainsEscapeSequence, EscapeSequence, Numeric},
     Cursor, Error, Interner, Lexer, Punctuator, TokenKind,
@@ -1197,3 +1198,62 @@ mod carriage_return {
         expect_tokens_with_lines(3, "-\r\n\n\r3");
     }
 }
+
+#[test]
+fn regex_duplicate_named_groups() {
+    fn rename(pattern: &str) -> Result<String, &'static str> {
+        let mut pattern = pattern.chars().map(u32::from).collect();
+        rename_duplicate_named_groups(&mut pattern, false)?;
+        Ok(pattern.into_iter().filter_map(char::from_u32).collect())
+    }
+
+    assert_eq!(
+        rename(r"(?<y>\d{4})-\d{2}|\d{2}-(?<y>\d{4})").as_deref(),
+        Ok(r"(?<y>\d{4})-\d{2}|\d{2}-(?<y$1>\d{4})")
+    );
+    assert_eq!(
+        rename("(?:(?<a>x)|(?<a>y))(?<a$1>z)|(?<a>w)").as_deref(),
+        Ok("(?:(?<a>x)|(?<a$2>y))(?<a$1>z)|(?<a$3>w)")
+    );
+    assert_eq!(
+        rename("(?<a>x)(?<=y)[(?<a>]").as_deref(),
+        Ok("(?<a>x)(?<=y)[(?<a>]")
+    );
+    assert_eq!(
+        rename(r"(?:(?<a>x)|(?<a>y))\k<a>+[\k<a>]\k<b>").as_deref(),
+        Ok(r"(?:(?<a>x)|(?<a$1>y))(?:\1\2)+[\k<a>]\k<b>")
+    );
+    assert_eq!(
+        rename(r"\k<a>(?:x)(?=y)(z)((?<a>x)|(?<a>y))\k<a>").as_deref(),
+        Ok(r"(?:\3\4)(?:x)(?=y)(z)((?<a>x)|(?<a$1>y))(?:\3\4)")
+    );
+    assert_eq!(
+        rename("(?<a>x)(?<a>y)"),
+        Err("Duplicate capture group name")
+    );
+    assert_eq!(
+        rename("(?<a>x)|((?<a>y)(?<a>z))"),
+        Err("Duplicate capture group name")
+    );
+
+    let mut pattern = "(?<a>x)|(?<a>y)|(?<a>z)".chars().map(u32::from).collect();
+    assert_eq!(
+        rename_duplicate_named_groups(&mut pattern, false),
+        Ok(vec![
+            ("a$1".to_owned(), "a".to_owned()),
+            ("a$2".to_owned(), "a".to_owned())
+        ])
+    );
+
+    let mut lexer = Lexer::from(&br"/(?<a>x)|(?<a>y)/"[..]);
+    let interner = &mut Interner::default();
+    let expected = [TokenKind::regular_expression_literal(
+        interner.get_or_intern_static("(?<a>x)|(?<a>y)", utf16!("(?<a>x)|(?<a>y)")),
+        Sym::EMPTY_STRING,
+    )];
+    expect_tokens(&mut lexer, &expected, interner);
+
+    Lexer::from(&br"/(?<a>x)(?<a>y)/"[..])
+        .next(&mut Interner::default())
+        .expect_err("Lexer did not handle regex literal with error");
+}
@@ -21,7 +21,6 @@ use crate::{
     parser::{
         expression::{
             left_hand_side::{
-                arguments::Arguments,
                 call::{CallExpression, CallExpressionTail},
                 member::MemberExpression,
                 optional::OptionalExpression,
@@ -39,6 +38,8 @@ use boa_ast::{
 };
 use boa_interner::Interner;
 
+pub(in crate::parser) use arguments::Arguments;
+
 /// Parses a left hand side expression.
 ///
 /// More information:
@@ -128,6 +129,20 @@ where
             let arg = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
 
+            // `, options` with optional trailing commas.
+            let mut options = None;
+            if cursor.next_if(Punctuator::Comma, interner)?.is_some()
+                && !cursor
+                    .peek(0, interner)?
+                    .is_some_and(|tok| tok.kind() == &TokenKind::Punctuator(Punctuator::CloseParen))
+            {
+                options = Some(
+                    AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?,
+                );
+                cursor.next_if(Punctuator::Comma, interner)?;
+            }
+
             let end = cursor
                 .expect(
                     TokenKind::Punctuator(Punctuator::CloseParen),
@@ -140,7 +155,7 @@ where
             CallExpressionTail::new(
                 self.allow_yield,
                 self.allow_await,
-                ImportCall::new(arg, Span::new(start, end)).into(),
+                ImportCall::new(arg, options, Span::new(start, end)).into(),
             )
             .parse(cursor, interner)?
         } else {
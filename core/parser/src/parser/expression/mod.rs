@@ -44,8 +44,8 @@ use boa_interner::{Interner, Sym};
 
 pub(super) use self::{assignment::AssignmentExpression, primary::Initializer};
 pub(in crate::parser) use {
-    identifiers::{BindingIdentifier, LabelIdentifier},
-    left_hand_side::LeftHandSideExpression,
+    identifiers::{BindingIdentifier, IdentifierReference, LabelIdentifier},
+    left_hand_side::{Arguments, LeftHandSideExpression},
     primary::object_initializer::{
         AsyncGeneratorMethod, AsyncMethod, GeneratorMethod, PropertyName,
     },
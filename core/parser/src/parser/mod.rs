@@ -395,6 +395,11 @@ where
             self.directive_prologues,
             self.strict,
         )
+        // It is a Syntax Error if StatementList Contains UsingDeclaration.
+        // It is a Syntax Error if StatementList Contains AwaitUsingDeclaration.
+        .forbid_using_declarations(
+            "using declarations are not allowed at the top level of a script",
+        )
         .parse(cursor, interner)?;
 
         if !self.direct_eval {
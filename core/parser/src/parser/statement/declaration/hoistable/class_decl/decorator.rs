@@ -0,0 +1,181 @@
+use crate::{
+    lexer::TokenKind,
+    parser::{
+        expression::{Arguments, Expression, IdentifierReference},
+        AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
+    },
+    source::ReadChar,
+    Error,
+};
+use boa_ast::{
+    expression::{
+        access::{PrivatePropertyAccess, PropertyAccess, SimplePropertyAccess},
+        Call, Identifier, Parenthesized,
+    },
+    function::{Decorator, PrivateName},
+    Punctuator, Span,
+};
+use boa_interner::Interner;
+
+/// `DecoratorList` parsing.
+///
+/// Parses zero or more decorators, returning an empty list if the next token is not `@`.
+/// Decorators are only parsed if the `experimental` feature is enabled.
+///
+/// More information:
+///  - [TC39 proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-decorators/#prod-DecoratorList
+#[derive(Debug, Clone, Copy)]
+pub(in crate::parser) struct DecoratorList {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl DecoratorList {
+    /// Creates a new `DecoratorList` parser.
+    pub(in crate::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for DecoratorList
+where
+    R: ReadChar,
+{
+    type Output = Box<[Decorator]>;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        if cfg!(not(feature = "experimental")) {
+            return Ok(Box::default());
+        }
+
+        // All parts of a class, including its decorators, are strict mode code.
+        let strict = cursor.strict();
+        cursor.set_strict(true);
+
+        let mut decorators = Vec::new();
+        while cursor.next_if(Punctuator::At, interner)?.is_some() {
+            let decorator = DecoratorExpression::new(self.allow_yield, self.allow_await)
+                .parse(cursor, interner)?;
+            decorators.push(Decorator::new(decorator));
+        }
+
+        cursor.set_strict(strict);
+        Ok(decorators.into_boxed_slice())
+    }
+}
+
+/// Parses the expression of a single decorator, after the `@` token.
+///
+/// ```text
+/// Decorator :
+///     @ DecoratorMemberExpression
+///     @ DecoratorParenthesizedExpression
+///     @ DecoratorCallExpression
+/// ```
+///
+/// More information:
+///  - [TC39 proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-decorators/#prod-Decorator
+#[derive(Debug, Clone, Copy)]
+struct DecoratorExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl DecoratorExpression {
+    /// Creates a new `DecoratorExpression` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for DecoratorExpression
+where
+    R: ReadChar,
+{
+    type Output = boa_ast::Expression;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let token = cursor.peek(0, interner).or_abrupt()?;
+
+        // DecoratorParenthesizedExpression : ( Expression[+In, ?Yield, ?Await] )
+        if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
+            let start = token.span().start();
+            cursor.advance(interner);
+            let expression = Expression::new(true, self.allow_yield, self.allow_await)
+                .parse(cursor, interner)?;
+            let end = cursor
+                .expect(Punctuator::CloseParen, "decorator", interner)?
+                .span()
+                .end();
+            return Ok(Parenthesized::new(expression, Span::new(start, end)).into());
+        }
+
+        // DecoratorMemberExpression :
+        //     IdentifierReference
+        //     DecoratorMemberExpression . IdentifierName
+        //     DecoratorMemberExpression . PrivateIdentifier
+        let mut expression: boa_ast::Expression =
+            IdentifierReference::new(self.allow_yield, self.allow_await)
+                .parse(cursor, interner)?
+                .into();
+        while cursor.next_if(Punctuator::Dot, interner)?.is_some() {
+            let token = cursor.next(interner).or_abrupt()?;
+            let start = expression.span().start();
+            let access: PropertyAccess = match token.kind() {
+                TokenKind::IdentifierName((name, _)) => {
+                    SimplePropertyAccess::new(expression, Identifier::new(*name, token.span()))
+                        .into()
+                }
+                TokenKind::Keyword((keyword, _)) => SimplePropertyAccess::new(
+                    expression,
+                    Identifier::new(keyword.to_sym(), token.span()),
+                )
+                .into(),
+                TokenKind::PrivateIdentifier(name) => PrivatePropertyAccess::new(
+                    expression,
+                    PrivateName::new(*name, token.span()),
+                    Span::new(start, token.span().end()),
+                )
+                .into(),
+                _ => {
+                    return Err(Error::expected(
+                        ["identifier".to_owned()],
+                        token.to_string(interner),
+                        token.span(),
+                        "decorator",
+                    ))
+                }
+            };
+            expression = access.into();
+        }
+
+        // DecoratorCallExpression : DecoratorMemberExpression Arguments
+        if cursor.peek(0, interner).or_abrupt()?.kind()
+            == &TokenKind::Punctuator(Punctuator::OpenParen)
+        {
+            let (args, args_span) =
+                Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+            expression = Call::new(expression, args, args_span).into();
+        }
+
+        Ok(expression)
+    }
+}
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests;
 
+mod decorator;
+
 use crate::{
     lexer::{token::ContainsEscapeSequence, Error as LexError, TokenKind},
     parser::{
@@ -38,6 +40,8 @@ use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
 use rustc_hash::{FxHashMap, FxHashSet};
 
+pub(in crate::parser) use decorator::DecoratorList;
+
 /// Class declaration parsing.
 ///
 /// More information:
@@ -76,6 +80,8 @@ where
     type Output = ClassDeclarationNode;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let decorators =
+            DecoratorList::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
         let span = cursor
             .expect((Keyword::Class, false), "class declaration", interner)?
             .span();
@@ -112,6 +118,7 @@ where
             super_ref,
             constructor,
             elements.into_boxed_slice(),
+            decorators,
         ))
     }
 }
@@ -534,9 +541,12 @@ where
     type Output = (Option<FunctionExpression>, Option<function::ClassElement>);
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let decorators =
+            DecoratorList::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+
         let token = cursor.peek(0, interner).or_abrupt()?;
         let r#static = match token.kind() {
-            TokenKind::Punctuator(Punctuator::Semicolon) => {
+            TokenKind::Punctuator(Punctuator::Semicolon) if decorators.is_empty() => {
                 cursor.advance(interner);
                 return Ok((None, None));
             }
@@ -569,6 +579,49 @@ where
             _ => false,
         };
 
+        let token = cursor.peek(0, interner).or_abrupt()?;
+        if let TokenKind::IdentifierName((Sym::ACCESSOR, ContainsEscapeSequence(contains_escape))) =
+            token.kind()
+        {
+            let contains_escape = *contains_escape;
+            let skip_n = if cursor.peek_is_line_terminator(0, interner).or_abrupt()? {
+                2
+            } else {
+                1
+            };
+            let is_line_terminator = cursor
+                .peek_is_line_terminator(skip_n, interner)?
+                .unwrap_or(true);
+            let token = cursor.peek(1, interner).or_abrupt()?;
+            let is_accessor = cfg!(feature = "experimental")
+                && !is_line_terminator
+                && matches!(
+                    token.kind(),
+                    TokenKind::IdentifierName(_)
+                        | TokenKind::StringLiteral(_)
+                        | TokenKind::NumericLiteral(_)
+                        | TokenKind::Keyword(_)
+                        | TokenKind::NullLiteral(_)
+                        | TokenKind::BooleanLiteral(_)
+                        | TokenKind::PrivateIdentifier(_)
+                        | TokenKind::Punctuator(Punctuator::OpenBracket)
+                );
+            if is_accessor {
+                if contains_escape {
+                    return Err(Error::general(
+                        "keyword must not contain escaped characters",
+                        token.span().start(),
+                    ));
+                }
+                // this "accessor" is a keyword.
+                cursor.advance(interner);
+                let element =
+                    AutoAccessor::new(r#static, decorators, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?;
+                return Ok((None, Some(element)));
+            }
+        }
+
         let is_keyword = !matches!(
             cursor.peek(1, interner).or_abrupt()?.kind(),
             TokenKind::Punctuator(
@@ -585,6 +638,22 @@ where
 
         let position = token.span().start();
         let element = match token.kind() {
+            TokenKind::IdentifierName((Sym::CONSTRUCTOR, _))
+                if !r#static && !decorators.is_empty() =>
+            {
+                return Err(Error::general(
+                    "decorators are not valid on class constructors",
+                    position,
+                ));
+            }
+            TokenKind::Punctuator(Punctuator::OpenBlock | Punctuator::Semicolon)
+                if !decorators.is_empty() =>
+            {
+                return Err(Error::general(
+                    "decorators must be followed by a class element",
+                    position,
+                ));
+            }
             TokenKind::IdentifierName((Sym::CONSTRUCTOR, _)) if !r#static => {
                 cursor.advance(interner);
                 let strict = cursor.strict();
@@ -747,6 +816,7 @@ where
                     body,
                     MethodDefinitionKind::Generator,
                     r#static,
+                    decorators,
                     start_linear_pos,
                 ))
             }
@@ -809,6 +879,7 @@ where
                             body,
                             MethodDefinitionKind::AsyncGenerator,
                             r#static,
+                            decorators,
                             start_linear_pos,
                         ))
                     }
@@ -855,6 +926,7 @@ where
                             body,
                             MethodDefinitionKind::Async,
                             r#static,
+                            decorators,
                             start_linear_pos,
                         ))
                     }
@@ -906,6 +978,7 @@ where
                             body,
                             MethodDefinitionKind::Get,
                             r#static,
+                            decorators,
                             start_linear_pos,
                         ))
                     }
@@ -953,14 +1026,19 @@ where
                             body,
                             MethodDefinitionKind::Get,
                             r#static,
+                            decorators,
                             start_linear_pos,
                         ))
                     }
                     _ => {
                         let span = token.span();
                         cursor.expect_semicolon("expected semicolon", interner)?;
-                        let field =
-                            ClassFieldDefinition::new(Identifier::new(Sym::GET, span).into(), None);
+                        let field = ClassFieldDefinition::new(
+                            Identifier::new(Sym::GET, span).into(),
+                            None,
+                            false,
+                            decorators,
+                        );
                         if r#static {
                             function::ClassElement::StaticFieldDefinition(field)
                         } else {
@@ -1008,6 +1086,7 @@ where
                             body,
                             MethodDefinitionKind::Set,
                             r#static,
+                            decorators,
                             start_linear_pos,
                         ))
                     }
@@ -1054,14 +1133,19 @@ where
                             body,
                             MethodDefinitionKind::Set,
                             r#static,
+                            decorators,
                             start_linear_pos,
                         ))
                     }
                     _ => {
                         let span = token.span();
                         cursor.expect_semicolon("expected semicolon", interner)?;
-                        let field =
-                            ClassFieldDefinition::new(Identifier::new(Sym::SET, span).into(), None);
+                        let field = ClassFieldDefinition::new(
+                            Identifier::new(Sym::SET, span).into(),
+                            None,
+                            false,
+                            decorators,
+                        );
                         if r#static {
                             function::ClassElement::StaticFieldDefinition(field)
                         } else {
@@ -1104,6 +1188,8 @@ where
                         let field = PrivateFieldDefinition::new(
                             PrivateName::new(name, name_span),
                             Some(rhs),
+                            false,
+                            decorators,
                         );
                         if r#static {
                             function::ClassElement::PrivateStaticFieldDefinition(field)
@@ -1134,13 +1220,18 @@ where
                             body,
                             MethodDefinitionKind::Ordinary,
                             r#static,
+                            decorators,
                             start_linear_pos,
                         ))
                     }
                     _ => {
                         cursor.expect_semicolon("expected semicolon", interner)?;
-                        let field =
-                            PrivateFieldDefinition::new(PrivateName::new(name, name_span), None);
+                        let field = PrivateFieldDefinition::new(
+                            PrivateName::new(name, name_span),
+                            None,
+                            false,
+                            decorators,
+                        );
                         if r#static {
                             function::ClassElement::PrivateStaticFieldDefinition(field)
                         } else {
@@ -1187,7 +1278,7 @@ where
                         if let Some(name) = name.literal() {
                             rhs.set_anonymous_function_definition_name(&name);
                         }
-                        let field = ClassFieldDefinition::new(name, Some(rhs));
+                        let field = ClassFieldDefinition::new(name, Some(rhs), false, decorators);
                         if r#static {
                             function::ClassElement::StaticFieldDefinition(field)
                         } else {
@@ -1225,6 +1316,7 @@ where
                             body,
                             MethodDefinitionKind::Ordinary,
                             r#static,
+                            decorators,
                             start_linear_pos,
                         ))
                     }
@@ -1245,7 +1337,7 @@ where
                             }
                         }
                         cursor.expect_semicolon("expected semicolon", interner)?;
-                        let field = ClassFieldDefinition::new(name, None);
+                        let field = ClassFieldDefinition::new(name, None, false, decorators);
                         if r#static {
                             function::ClassElement::StaticFieldDefinition(field)
                         } else {
@@ -1289,3 +1381,140 @@ where
         Ok((None, Some(element)))
     }
 }
+
+/// `accessor` auto-accessor parsing, after the `accessor` keyword.
+///
+/// ```text
+/// FieldDefinition :
+///     accessor [no LineTerminator here] ClassElementName Initializer[opt]
+/// ```
+///
+/// More information:
+///  - [TC39 proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-decorators/#prod-FieldDefinition
+#[derive(Debug, Clone)]
+struct AutoAccessor {
+    r#static: bool,
+    decorators: Box<[function::Decorator]>,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl AutoAccessor {
+    /// Creates a new `AutoAccessor` parser.
+    fn new<Y, A>(
+        r#static: bool,
+        decorators: Box<[function::Decorator]>,
+        allow_yield: Y,
+        allow_await: A,
+    ) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            r#static,
+            decorators,
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for AutoAccessor
+where
+    R: ReadChar,
+{
+    type Output = function::ClassElement;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let token = cursor.peek(0, interner).or_abrupt()?;
+        let position = token.span().start();
+        let name = match token.kind() {
+            TokenKind::PrivateIdentifier(Sym::CONSTRUCTOR) => {
+                return Err(Error::general(
+                    "class constructor may not be a private method",
+                    position,
+                ))
+            }
+            TokenKind::PrivateIdentifier(name) => {
+                let name = PrivateName::new(*name, token.span());
+                cursor.advance(interner);
+                ClassElementName::PrivateName(name)
+            }
+            _ => {
+                let name = PropertyName::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                if let Some(name) = name.literal() {
+                    if self.r#static {
+                        if [Sym::CONSTRUCTOR, Sym::PROTOTYPE].contains(&name.sym()) {
+                            return Err(Error::general(
+                                "class may not have static field definitions named 'constructor' or 'prototype'",
+                                position,
+                            ));
+                        }
+                    } else if name == Sym::CONSTRUCTOR {
+                        return Err(Error::general(
+                            "class may not have field definitions named 'constructor'",
+                            position,
+                        ));
+                    }
+                }
+                ClassElementName::PropertyName(name)
+            }
+        };
+
+        let initializer = if cursor.next_if(Punctuator::Assign, interner)?.is_some() {
+            let strict = cursor.strict();
+            cursor.set_strict(true);
+            let mut rhs = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                .parse(cursor, interner)?;
+            cursor.set_strict(strict);
+            match &name {
+                ClassElementName::PropertyName(name) => {
+                    if let Some(name) = name.literal() {
+                        rhs.set_anonymous_function_definition_name(&name);
+                    }
+                }
+                ClassElementName::PrivateName(name) => {
+                    let function_name = interner.get_or_intern(
+                        [
+                            utf16!("#"),
+                            interner.resolve_expect(name.description()).utf16(),
+                        ]
+                        .concat()
+                        .as_slice(),
+                    );
+                    rhs.set_anonymous_function_definition_name(&Identifier::new(
+                        function_name,
+                        name.span(),
+                    ));
+                }
+            }
+            Some(rhs)
+        } else {
+            None
+        };
+        cursor.expect_semicolon("expected semicolon", interner)?;
+
+        Ok(match name {
+            ClassElementName::PropertyName(name) => {
+                let field = ClassFieldDefinition::new(name, initializer, true, self.decorators);
+                if self.r#static {
+                    function::ClassElement::StaticFieldDefinition(field)
+                } else {
+                    function::ClassElement::FieldDefinition(field)
+                }
+            }
+            ClassElementName::PrivateName(name) => {
+                let field = PrivateFieldDefinition::new(name, initializer, true, self.decorators);
+                if self.r#static {
+                    function::ClassElement::PrivateStaticFieldDefinition(field)
+                } else {
+                    function::ClassElement::PrivateFieldDefinition(field)
+                }
+            }
+        })
+    }
+}
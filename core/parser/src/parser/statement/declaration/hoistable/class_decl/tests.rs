@@ -1,4 +1,4 @@
-use crate::parser::tests::check_script_parser;
+use crate::parser::tests::{check_invalid_script, check_script_parser};
 use boa_ast::{
     declaration::{LexicalDeclaration, Variable, VariableList},
     expression::{
@@ -33,6 +33,7 @@ fn check_async_ordinary_method() {
         FunctionBody::new(StatementList::default(), Span::new((2, 13), (2, 16))),
         MethodDefinitionKind::Ordinary,
         false,
+        Box::default(),
         boa_ast::LinearPosition::default(),
     ))];
 
@@ -51,6 +52,7 @@ fn check_async_ordinary_method() {
                 None,
                 None,
                 elements.into(),
+                Box::default(),
             )
             .into(),
         )
@@ -70,6 +72,8 @@ fn check_async_field_initialization() {
         )
         .into(),
         Some(Literal::new(1, Span::new((3, 7), (3, 8))).into()),
+        false,
+        Box::default(),
     ))];
 
     check_script_parser(
@@ -88,6 +92,7 @@ fn check_async_field_initialization() {
                 None,
                 None,
                 elements.into(),
+                Box::default(),
             )))
             .into(),
         ],
@@ -106,6 +111,8 @@ fn check_async_field() {
         )
         .into(),
         None,
+        false,
+        Box::default(),
     ))];
 
     check_script_parser(
@@ -123,6 +130,7 @@ fn check_async_field() {
                 None,
                 None,
                 elements.into(),
+                Box::default(),
             )))
             .into(),
         ],
@@ -187,6 +195,7 @@ fn check_new_target_with_property_access() {
         None,
         Some(constructor),
         Box::default(),
+        Box::default(),
     );
 
     let instantiation = Expression::New(
@@ -248,6 +257,7 @@ fn check_non_reserved_keyword_as_identifier() {
                 None,
                 None,
                 vec![].into(),
+                Box::default(),
             )
             .into(),
         )
@@ -255,3 +265,88 @@ fn check_non_reserved_keyword_as_identifier() {
         interner,
     );
 }
+
+#[test]
+fn check_invalid_decorators() {
+    check_invalid_script("@dec static class A {}");
+    check_invalid_script("class A { @dec static { } }");
+    check_invalid_script("class A { @dec constructor() {} }");
+    check_invalid_script("class A { @dec }");
+    check_invalid_script("class A { accessor a() {} }");
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn check_decorators() {
+    use boa_ast::function::Decorator;
+
+    let interner = &mut Interner::default();
+    let dec = interner.get_or_intern_static("dec", utf16!("dec"));
+    let decorator = |line, column| -> Box<[Decorator]> {
+        Box::new([Decorator::new(
+            Identifier::new(dec, Span::new((line, column), (line, column + 3))).into(),
+        )])
+    };
+
+    let elements = vec![
+        ClassElement::MethodDefinition(ClassMethodDefinition::new(
+            boa_ast::function::ClassElementName::PropertyName(
+                Identifier::new(
+                    interner.get_or_intern_static("m", utf16!("m")),
+                    Span::new((2, 10), (2, 11)),
+                )
+                .into(),
+            ),
+            FormalParameterList::default(),
+            FunctionBody::new(StatementList::default(), Span::new((2, 14), (2, 16))),
+            MethodDefinitionKind::Ordinary,
+            false,
+            decorator(2, 6),
+            boa_ast::LinearPosition::default(),
+        )),
+        ClassElement::FieldDefinition(ClassFieldDefinition::new(
+            Identifier::new(
+                interner.get_or_intern_static("a", utf16!("a")),
+                Span::new((3, 10), (3, 11)),
+            )
+            .into(),
+            Some(Literal::new(1, Span::new((3, 14), (3, 15))).into()),
+            false,
+            decorator(3, 6),
+        )),
+        ClassElement::FieldDefinition(ClassFieldDefinition::new(
+            Identifier::new(
+                interner.get_or_intern_static("b", utf16!("b")),
+                Span::new((4, 19), (4, 20)),
+            )
+            .into(),
+            None,
+            true,
+            decorator(4, 6),
+        )),
+    ];
+
+    check_script_parser(
+        indoc! {"
+            @dec class A {
+                @dec m() {}
+                @dec a = 1;
+                @dec accessor b;
+            }
+        "},
+        [
+            Declaration::ClassDeclaration(Box::new(ClassDeclaration::new(
+                Identifier::new(
+                    interner.get_or_intern_static("A", utf16!("A")),
+                    Span::new((1, 12), (1, 13)),
+                ),
+                None,
+                None,
+                elements.into(),
+                decorator(1, 2),
+            )))
+            .into(),
+        ],
+        interner,
+    );
+}
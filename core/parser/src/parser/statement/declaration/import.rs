@@ -10,17 +10,20 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
 
 use crate::{
-    lexer::TokenKind,
+    lexer::{token::ContainsEscapeSequence, TokenKind},
     parser::{
         cursor::Cursor,
-        statement::{declaration::FromClause, BindingIdentifier},
+        statement::{
+            declaration::{FromClause, WithClause},
+            BindingIdentifier,
+        },
         Error, OrAbrupt, ParseResult, TokenParser,
     },
     source::ReadChar,
 };
 use boa_ast::{
     declaration::{
-        ImportDeclaration as AstImportDeclaration, ImportKind,
+        ImportAttribute, ImportDeclaration as AstImportDeclaration, ImportKind, ImportPhase,
         ImportSpecifier as AstImportSpecifier, ModuleSpecifier,
     },
     expression::Identifier,
@@ -77,6 +80,29 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         cursor.expect((Keyword::Import, false), "import declaration", interner)?;
 
+        // `import defer * as ns from "module-name"`, only with the `experimental` feature.
+        if cfg!(feature = "experimental")
+            && cursor.peek(0, interner).or_abrupt()?.kind()
+                == &TokenKind::IdentifierName((Sym::DEFER, ContainsEscapeSequence(false)))
+            && cursor.peek(1, interner).or_abrupt()?.kind()
+                == &TokenKind::Punctuator(Punctuator::Mul)
+        {
+            cursor.advance(interner);
+            let binding = NameSpaceImport.parse(cursor, interner)?;
+            let module_identifier =
+                FromClause::new("import declaration").parse(cursor, interner)?;
+            let attributes = WithClause::new("import declaration").parse(cursor, interner)?;
+            cursor.expect_semicolon("import declaration", interner)?;
+
+            return Ok(AstImportDeclaration::new(
+                None,
+                ImportKind::Namespaced { binding },
+                module_identifier,
+                attributes,
+                ImportPhase::Defer,
+            ));
+        }
+
         let tok = cursor.peek(0, interner).or_abrupt()?;
 
         let import_clause = match tok.kind() {
@@ -84,12 +110,15 @@ where
                 let module_identifier = *module_identifier;
 
                 cursor.advance(interner);
+                let attributes = WithClause::new("import declaration").parse(cursor, interner)?;
                 cursor.expect_semicolon("import declaration", interner)?;
 
                 return Ok(AstImportDeclaration::new(
                     None,
                     ImportKind::DefaultOrUnnamed,
                     ModuleSpecifier::new(module_identifier),
+                    attributes,
+                    ImportPhase::Evaluation,
                 ));
             }
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
@@ -152,8 +181,10 @@ where
         };
 
         let module_identifier = FromClause::new("import declaration").parse(cursor, interner)?;
+        let attributes = WithClause::new("import declaration").parse(cursor, interner)?;
+        cursor.expect_semicolon("import declaration", interner)?;
 
-        Ok(import_clause.with_specifier(module_identifier))
+        Ok(import_clause.with_specifier(module_identifier, attributes))
     }
 }
 
@@ -258,19 +289,29 @@ enum ImportClause {
 impl ImportClause {
     #[inline]
     #[allow(clippy::missing_const_for_fn)]
-    fn with_specifier(self, specifier: ModuleSpecifier) -> AstImportDeclaration {
-        match self {
-            Self::Namespace(default, binding) => {
-                AstImportDeclaration::new(default, ImportKind::Namespaced { binding }, specifier)
-            }
+    fn with_specifier(
+        self,
+        specifier: ModuleSpecifier,
+        attributes: Box<[ImportAttribute]>,
+    ) -> AstImportDeclaration {
+        let (default, kind) = match self {
+            Self::Namespace(default, binding) => (default, ImportKind::Namespaced { binding }),
             Self::ImportList(default, names) => {
                 if names.is_empty() {
-                    AstImportDeclaration::new(default, ImportKind::DefaultOrUnnamed, specifier)
+                    (default, ImportKind::DefaultOrUnnamed)
                 } else {
-                    AstImportDeclaration::new(default, ImportKind::Named { names }, specifier)
+                    (default, ImportKind::Named { names })
                 }
             }
-        }
+        };
+
+        AstImportDeclaration::new(
+            default,
+            kind,
+            specifier,
+            attributes,
+            ImportPhase::Evaluation,
+        )
     }
 }
 
@@ -1,14 +1,16 @@
 //! Lexical declaration parsing.
 //!
-//! This parses `let` and `const` declarations.
+//! This parses `let`, `const`, `using` and `await using` declarations.
 //!
 //! More information:
 //!  - [ECMAScript specification][spec]
+//!  - [Explicit Resource Management proposal][proposal]
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-let-and-const-declarations
+//! [proposal]: https://tc39.es/proposal-explicit-resource-management/#sec-let-const-using-and-await-using-declarations
 
 use crate::{
-    lexer::{Error as LexError, Token, TokenKind},
+    lexer::{token::ContainsEscapeSequence, Error as LexError, Token, TokenKind},
     parser::{
         cursor::{Cursor, SemicolonResult},
         expression::Initializer,
@@ -23,6 +25,15 @@ use boa_ast::{self as ast, declaration::Variable, Keyword, Punctuator};
 use boa_interner::{Interner, Sym};
 use rustc_hash::FxHashSet;
 
+/// The kind of the bindings declared by a lexical declaration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LexicalKind {
+    Let,
+    Const,
+    Using,
+    AwaitUsing,
+}
+
 /// Parses a lexical declaration.
 ///
 /// More information:
@@ -68,32 +79,38 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let tok = cursor.next(interner).or_abrupt()?;
 
-        let lexical_declaration = match tok.kind() {
+        let kind = match tok.kind() {
             TokenKind::Keyword((Keyword::Const | Keyword::Let, true)) => {
                 return Err(Error::general(
                     "Keyword must not contain escaped characters",
                     tok.span().start(),
                 ))
             }
-            TokenKind::Keyword((Keyword::Const, false)) => BindingList::new(
-                self.allow_in,
-                self.allow_yield,
-                self.allow_await,
-                true,
-                self.loop_init,
-            )
-            .parse(cursor, interner)?,
-            TokenKind::Keyword((Keyword::Let, false)) => BindingList::new(
-                self.allow_in,
-                self.allow_yield,
-                self.allow_await,
-                false,
-                self.loop_init,
-            )
-            .parse(cursor, interner)?,
+            TokenKind::Keyword((Keyword::Const, false)) => LexicalKind::Const,
+            TokenKind::Keyword((Keyword::Let, false)) => LexicalKind::Let,
+            TokenKind::IdentifierName((Sym::USING, ContainsEscapeSequence(false))) => {
+                LexicalKind::Using
+            }
+            TokenKind::Keyword((Keyword::Await, false)) => {
+                cursor.expect(
+                    TokenKind::IdentifierName((Sym::USING, ContainsEscapeSequence(false))),
+                    "await using declaration",
+                    interner,
+                )?;
+                LexicalKind::AwaitUsing
+            }
             _ => unreachable!("unknown token found: {:?}", tok),
         };
 
+        let lexical_declaration = BindingList::new(
+            self.allow_in,
+            self.allow_yield,
+            self.allow_await,
+            kind,
+            self.loop_init,
+        )
+        .parse(cursor, interner)?;
+
         if !self.loop_init {
             cursor.expect_semicolon("lexical declaration", interner)?;
         }
@@ -136,10 +153,98 @@ pub(crate) fn allowed_token_after_let(token: Option<&Token>) -> bool {
     )
 }
 
+/// Checks if the upcoming tokens start a `using` declaration.
+///
+/// This is the case if the next token is an unescaped `using`, followed by a binding identifier
+/// on the same line.
+pub(in crate::parser) fn is_using_declaration<R>(
+    cursor: &mut Cursor<R>,
+    interner: &mut Interner,
+) -> ParseResult<bool>
+where
+    R: ReadChar,
+{
+    let offset = usize::from(
+        cursor
+            .peek_is_line_terminator(0, interner)?
+            .unwrap_or(false),
+    );
+
+    if !matches!(
+        cursor.peek(0, interner)?.map(Token::kind),
+        Some(TokenKind::IdentifierName((
+            Sym::USING,
+            ContainsEscapeSequence(false)
+        )))
+    ) || cursor
+        .peek_is_line_terminator(offset + 1, interner)?
+        .unwrap_or(true)
+    {
+        return Ok(false);
+    }
+
+    Ok(is_binding_identifier_token(cursor.peek(1, interner)?))
+}
+
+/// Checks if the upcoming tokens start an `await using` declaration.
+///
+/// This is the case if the next tokens are an unescaped `await` and `using`, followed by a
+/// binding identifier, all of them on the same line.
+pub(in crate::parser) fn is_await_using_declaration<R>(
+    cursor: &mut Cursor<R>,
+    interner: &mut Interner,
+) -> ParseResult<bool>
+where
+    R: ReadChar,
+{
+    let offset = usize::from(
+        cursor
+            .peek_is_line_terminator(0, interner)?
+            .unwrap_or(false),
+    );
+
+    if !matches!(
+        cursor.peek(0, interner)?.map(Token::kind),
+        Some(TokenKind::Keyword((Keyword::Await, false)))
+    ) || cursor
+        .peek_is_line_terminator(offset + 1, interner)?
+        .unwrap_or(true)
+        || !matches!(
+            cursor.peek(1, interner)?.map(Token::kind),
+            Some(TokenKind::IdentifierName((
+                Sym::USING,
+                ContainsEscapeSequence(false)
+            )))
+        )
+        || cursor
+            .peek_is_line_terminator(offset + 2, interner)?
+            .unwrap_or(true)
+    {
+        return Ok(false);
+    }
+
+    Ok(is_binding_identifier_token(cursor.peek(2, interner)?))
+}
+
+/// Check if the given token can start a binding identifier.
+fn is_binding_identifier_token(token: Option<&Token>) -> bool {
+    matches!(
+        token.map(Token::kind),
+        Some(
+            TokenKind::IdentifierName(_)
+                | TokenKind::Keyword((
+                    Keyword::Await | Keyword::Yield | Keyword::Let | Keyword::Async | Keyword::Of,
+                    _
+                ))
+        )
+    )
+}
+
 /// Parses a binding list.
 ///
-/// It will return an error if a `const` declaration is being parsed and there is no
-/// initializer.
+/// It will return an error if a `const`, `using` or `await using` declaration is being parsed
+/// and there is no initializer, or if a `using` or `await using` declaration contains a binding
+/// pattern.
 ///
 /// More information:
 ///  - [ECMAScript specification][spec]
@@ -150,7 +255,7 @@ struct BindingList {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
-    is_const: bool,
+    kind: LexicalKind,
     loop_init: bool,
 }
 
@@ -160,7 +265,7 @@ impl BindingList {
         allow_in: I,
         allow_yield: Y,
         allow_await: A,
-        is_const: bool,
+        kind: LexicalKind,
         loop_init: bool,
     ) -> Self
     where
@@ -172,7 +277,7 @@ impl BindingList {
             allow_in: allow_in.into(),
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
-            is_const,
+            kind,
             loop_init,
         }
     }
@@ -190,23 +295,31 @@ where
         let mut decls = Vec::new();
 
         loop {
+            let position = cursor.peek(0, interner).or_abrupt()?.span().start();
             let decl = LexicalBinding::new(self.allow_in, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
 
-            if self.is_const {
-                let init_is_some = decl.init().is_some();
+            if matches!(self.kind, LexicalKind::Using | LexicalKind::AwaitUsing)
+                && matches!(decl.binding(), ast::declaration::Binding::Pattern(_))
+            {
+                return Err(Error::general(
+                    "using declarations cannot contain binding patterns",
+                    position,
+                ));
+            }
 
-                if init_is_some || self.loop_init {
-                    decls.push(decl);
-                } else {
-                    let next = cursor.next(interner).or_abrupt()?;
-                    return Err(Error::general(
-                        "Expected initializer for const declaration",
-                        next.span().start(),
-                    ));
-                }
-            } else {
+            if self.kind == LexicalKind::Let || decl.init().is_some() || self.loop_init {
                 decls.push(decl);
+            } else {
+                let next = cursor.next(interner).or_abrupt()?;
+                return Err(Error::general(
+                    if self.kind == LexicalKind::Const {
+                        "Expected initializer for const declaration"
+                    } else {
+                        "Expected initializer for using declaration"
+                    },
+                    next.span().start(),
+                ));
             }
 
             match cursor.peek_semicolon(interner)? {
@@ -249,11 +362,12 @@ where
             .try_into()
             .expect("`LexicalBinding` must return at least one variable");
 
-        if self.is_const {
-            Ok(ast::declaration::LexicalDeclaration::Const(decls))
-        } else {
-            Ok(ast::declaration::LexicalDeclaration::Let(decls))
-        }
+        Ok(match self.kind {
+            LexicalKind::Let => ast::declaration::LexicalDeclaration::Let(decls),
+            LexicalKind::Const => ast::declaration::LexicalDeclaration::Const(decls),
+            LexicalKind::Using => ast::declaration::LexicalDeclaration::Using(decls),
+            LexicalKind::AwaitUsing => ast::declaration::LexicalDeclaration::AwaitUsing(decls),
+        })
     }
 }
 
@@ -17,10 +17,14 @@ mod tests;
 pub(in crate::parser) use self::{
     export::ExportDeclaration,
     hoistable::{
-        class_decl::ClassTail, ClassDeclaration, FunctionDeclaration, HoistableDeclaration,
+        class_decl::{ClassTail, DecoratorList},
+        ClassDeclaration, FunctionDeclaration, HoistableDeclaration,
     },
     import::ImportDeclaration,
-    lexical::{allowed_token_after_let, LexicalDeclaration},
+    lexical::{
+        allowed_token_after_let, is_await_using_declaration, is_using_declaration,
+        LexicalDeclaration,
+    },
 };
 use crate::{
     lexer::TokenKind,
@@ -28,7 +32,7 @@ use crate::{
     source::ReadChar,
     Error,
 };
-use boa_ast::{self as ast, Keyword};
+use boa_ast::{self as ast, Keyword, Punctuator};
 use boa_interner::{Interner, Sym};
 
 /// Parses a declaration.
@@ -68,7 +72,8 @@ where
         let tok = cursor.peek(0, interner).or_abrupt()?;
 
         match tok.kind() {
-            TokenKind::Keyword((Keyword::Function | Keyword::Async | Keyword::Class, _)) => {
+            TokenKind::Keyword((Keyword::Function | Keyword::Async | Keyword::Class, _))
+            | TokenKind::Punctuator(Punctuator::At) => {
                 HoistableDeclaration::new(self.allow_yield, self.allow_await, false)
                     .parse(cursor, interner)
             }
@@ -135,3 +140,99 @@ where
         Ok((*from).into())
     }
 }
+
+/// Parses an optional `with` clause of an import or re-export declaration.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-WithClause
+#[derive(Debug, Clone, Copy)]
+struct WithClause {
+    context: &'static str,
+}
+
+impl WithClause {
+    /// Creates a new `with` clause parser
+    #[inline]
+    const fn new(context: &'static str) -> Self {
+        Self { context }
+    }
+}
+
+impl<R> TokenParser<R> for WithClause
+where
+    R: ReadChar,
+{
+    type Output = Box<[ast::declaration::ImportAttribute]>;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        if cursor.next_if((Keyword::With, false), interner)?.is_none() {
+            return Ok(Box::default());
+        }
+
+        cursor.expect(Punctuator::OpenBlock, self.context, interner)?;
+
+        let mut attributes: Vec<ast::declaration::ImportAttribute> = Vec::new();
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock, interner)?.is_some() {
+                break;
+            }
+
+            let tok = cursor.next(interner).or_abrupt()?;
+            let key = match tok.kind() {
+                TokenKind::StringLiteral((key, _)) | TokenKind::IdentifierName((key, _)) => *key,
+                TokenKind::Keyword((kw, _)) => kw.to_sym(),
+                TokenKind::BooleanLiteral((b, _)) => {
+                    if *b {
+                        Sym::TRUE
+                    } else {
+                        Sym::FALSE
+                    }
+                }
+                TokenKind::NullLiteral(_) => Sym::NULL,
+                _ => {
+                    return Err(Error::expected(
+                        ["identifier name".to_owned(), "string literal".to_owned()],
+                        tok.to_string(interner),
+                        tok.span(),
+                        self.context,
+                    ))
+                }
+            };
+
+            // It is a Syntax Error if WithClauseToAttributes of WithClause has two different
+            // entries a and b such that a.[[Key]] is b.[[Key]].
+            if attributes.iter().any(|attribute| attribute.key() == key) {
+                return Err(Error::general(
+                    format!(
+                        "duplicate import attribute key `{}`",
+                        interner.resolve_expect(key)
+                    ),
+                    tok.span().start(),
+                ));
+            }
+
+            cursor.expect(Punctuator::Colon, self.context, interner)?;
+
+            let tok = cursor.next(interner).or_abrupt()?;
+            let TokenKind::StringLiteral((value, _)) = tok.kind() else {
+                return Err(Error::expected(
+                    ["string literal".to_owned()],
+                    tok.to_string(interner),
+                    tok.span(),
+                    self.context,
+                ));
+            };
+
+            attributes.push(ast::declaration::ImportAttribute::new(key, *value));
+
+            if cursor.next_if(Punctuator::Comma, interner)?.is_none() {
+                cursor.expect(Punctuator::CloseBlock, self.context, interner)?;
+                break;
+            }
+        }
+
+        Ok(attributes.into_boxed_slice())
+    }
+}
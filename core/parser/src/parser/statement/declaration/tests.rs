@@ -1,13 +1,18 @@
-use crate::parser::tests::{check_invalid_script, check_module_parser, check_script_parser};
+use crate::{
+    parser::tests::{check_invalid_script, check_module_parser, check_script_parser},
+    Error, Parser, Source,
+};
 use boa_ast::{
     declaration::{
-        ExportDeclaration, ExportSpecifier, LexicalDeclaration, VarDeclaration, Variable,
+        ExportDeclaration, ExportSpecifier, ImportAttribute, ImportDeclaration, ImportKind,
+        ImportPhase, LexicalDeclaration, ModuleSpecifier, ReExportKind, VarDeclaration, Variable,
     },
     expression::{
         literal::{Literal, LiteralKind},
         Identifier,
     },
-    Declaration, ModuleItem, Span, Statement,
+    scope::Scope,
+    Declaration, ModuleItem, Position, Span, Statement,
 };
 use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
@@ -415,6 +420,95 @@ fn lexical_declaration_early_errors() {
     check_invalid_script("for (const a = 0, a = 0; ; ) {}");
 }
 
+/// Checks `using` and `await using` declaration parsing.
+#[test]
+fn using_declaration() {
+    let interner = &mut Interner::default();
+    let a = interner.get_or_intern_static("a", utf16!("a"));
+    let b = interner.get_or_intern_static("b", utf16!("b"));
+    check_module_parser(
+        indoc! {"
+            using a = null;
+            await using b = null;
+        "},
+        vec![
+            ModuleItem::StatementListItem(
+                Declaration::Lexical(LexicalDeclaration::Using(
+                    vec![Variable::from_identifier(
+                        Identifier::new(a, Span::new((1, 7), (1, 8))),
+                        Some(Literal::new(LiteralKind::Null, Span::new((1, 11), (1, 15))).into()),
+                    )]
+                    .try_into()
+                    .unwrap(),
+                ))
+                .into(),
+            ),
+            ModuleItem::StatementListItem(
+                Declaration::Lexical(LexicalDeclaration::AwaitUsing(
+                    vec![Variable::from_identifier(
+                        Identifier::new(b, Span::new((2, 13), (2, 14))),
+                        Some(Literal::new(LiteralKind::Null, Span::new((2, 17), (2, 21))).into()),
+                    )]
+                    .try_into()
+                    .unwrap(),
+                ))
+                .into(),
+            ),
+        ],
+        interner,
+    );
+}
+
+/// Checks `using` declarations in blocks, functions and `for-of` heads of scripts.
+#[test]
+fn using_declaration_nested() {
+    for js in [
+        "{ using a = null, b = null; }",
+        "function f() { using a = null; }",
+        "async function f() { await using a = null; }",
+        "for (using x of []) {}",
+        "for (using x = null; ; ) {}",
+        "async function f() { for (await using x of []) {} }",
+        "using = 1; var using; using[0] = 1;",
+        "var using, a, b; { using [a] = b; }",
+    ] {
+        assert!(
+            Parser::new(Source::from_bytes(js))
+                .parse_script(&Scope::new_global(), &mut Interner::default())
+                .is_ok(),
+            "failed to parse `{js}`"
+        );
+    }
+}
+
+/// Checks `using` and `await using` early errors.
+#[test]
+fn using_declaration_early_errors() {
+    check_invalid_script("{ using a; }");
+    check_invalid_script("{ using {a} = b; }");
+    check_invalid_script("async function f() { await using a; }");
+    check_invalid_script("async function f() { await using [a] = b; }");
+    check_invalid_script("for (using x in {}) {}");
+    check_invalid_script("switch (0) { case 0: using a = null; }");
+    check_invalid_script("using a = null;");
+}
+
+/// Checks that a `using` declaration at the top level of a script is reported at its position.
+#[test]
+fn using_declaration_script_top_level() {
+    let err = Parser::new(Source::from_bytes("let a = 1;\n  using b = null;"))
+        .parse_script(&Scope::new_global(), &mut Interner::default())
+        .expect_err("top level using declaration should be rejected");
+    let Error::General { message, position } = err else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(
+        &*message,
+        "using declarations are not allowed at the top level of a script"
+    );
+    assert_eq!(position, Position::new(2, 3));
+}
+
 /// Checks module exports with reserved keywords
 #[test]
 fn module_export_reserved() {
@@ -452,3 +546,106 @@ fn module_export_reserved() {
         interner,
     );
 }
+
+/// Checks import and re-export declarations with import attributes.
+#[test]
+fn module_import_attributes() {
+    let interner = &mut Interner::default();
+    let cfg = interner.get_or_intern_static("cfg", utf16!("cfg"));
+    let json = interner.get_or_intern_static("./x.json", utf16!("./x.json"));
+    let type_key = interner.get_or_intern_static("type", utf16!("type"));
+    let type_value = interner.get_or_intern_static("json", utf16!("json"));
+    let other = interner.get_or_intern_static("other", utf16!("other"));
+    check_module_parser(
+        indoc! {r#"
+            import cfg from "./x.json" with { type: "json" };
+            export * from "./x.json" with { "type": "json", other: "", };
+        "#},
+        vec![
+            ModuleItem::ImportDeclaration(ImportDeclaration::new(
+                Some(Identifier::new(cfg, Span::new((1, 8), (1, 11)))),
+                ImportKind::DefaultOrUnnamed,
+                ModuleSpecifier::new(json),
+                vec![ImportAttribute::new(type_key, type_value)].into(),
+                ImportPhase::Evaluation,
+            )),
+            ModuleItem::ExportDeclaration(
+                ExportDeclaration::ReExport {
+                    kind: ReExportKind::Namespaced { name: None },
+                    specifier: ModuleSpecifier::new(json),
+                    attributes: vec![
+                        ImportAttribute::new(type_key, type_value),
+                        ImportAttribute::new(other, Sym::EMPTY_STRING),
+                    ]
+                    .into(),
+                }
+                .into(),
+            ),
+        ],
+        interner,
+    );
+}
+
+/// Checks deferred namespace imports.
+#[cfg(feature = "experimental")]
+#[test]
+fn module_import_defer() {
+    let interner = &mut Interner::default();
+    let ns = interner.get_or_intern_static("ns", utf16!("ns"));
+    let defer = interner.get_or_intern_static("defer", utf16!("defer"));
+    let module = interner.get_or_intern_static("./mod.js", utf16!("./mod.js"));
+    check_module_parser(
+        indoc! {r#"
+            import defer * as ns from "./mod.js";
+            import defer from "./mod.js";
+        "#},
+        vec![
+            ModuleItem::ImportDeclaration(ImportDeclaration::new(
+                None,
+                ImportKind::Namespaced {
+                    binding: Identifier::new(ns, Span::new((1, 19), (1, 21))),
+                },
+                ModuleSpecifier::new(module),
+                Box::default(),
+                ImportPhase::Defer,
+            )),
+            ModuleItem::ImportDeclaration(ImportDeclaration::new(
+                Some(Identifier::new(defer, Span::new((2, 8), (2, 13)))),
+                ImportKind::DefaultOrUnnamed,
+                ModuleSpecifier::new(module),
+                Box::default(),
+                ImportPhase::Evaluation,
+            )),
+        ],
+        interner,
+    );
+}
+
+/// Checks that deferred imports only allow namespace imports.
+#[test]
+fn module_import_defer_invalid() {
+    for js in [
+        r#"import defer { a } from "./mod.js";"#,
+        r#"import defer x, * as ns from "./mod.js";"#,
+        r#"import defer "./mod.js";"#,
+        r#"import \u0064efer * as ns from "./mod.js";"#,
+    ] {
+        assert!(Parser::new(Source::from_bytes(js))
+            .parse_module(&Scope::new_global(), &mut Interner::default())
+            .is_err());
+    }
+}
+
+/// Checks that duplicate or non-string import attributes are rejected.
+#[test]
+fn module_import_attributes_invalid() {
+    for js in [
+        r#"import "./x.json" with { type: "json", type: "json" };"#,
+        r#"import "./x.json" with { type: json };"#,
+        r#"import "./x.json" with { type: "json" "#,
+    ] {
+        assert!(Parser::new(Source::from_bytes(js))
+            .parse_module(&Scope::new_global(), &mut Interner::default())
+            .is_err());
+    }
+}
@@ -8,11 +8,14 @@
 //! [spec]: https://tc39.es/ecma262/#sec-for-statement
 
 use crate::{
-    lexer::{Error as LexError, TokenKind},
+    lexer::{token::ContainsEscapeSequence, Error as LexError, Token, TokenKind},
     parser::{
         expression::{AssignmentExpression, Expression},
         statement::{
-            declaration::{allowed_token_after_let, LexicalDeclaration},
+            declaration::{
+                allowed_token_after_let, is_await_using_declaration, is_using_declaration,
+                LexicalDeclaration,
+            },
             variable::VariableDeclarationList,
             Statement,
         },
@@ -132,6 +135,31 @@ where
                     .parse(cursor, interner)?
                     .into(),
             ),
+            TokenKind::IdentifierName((Sym::USING, ContainsEscapeSequence(false)))
+                if is_using_declaration(cursor, interner)?
+                    && (!matches!(
+                        cursor.peek(1, interner).or_abrupt()?.kind(),
+                        TokenKind::Keyword((Keyword::Of, _))
+                    ) || matches!(
+                        cursor.peek(2, interner)?.map(Token::kind),
+                        Some(TokenKind::Keyword((Keyword::Of, _)))
+                    )) =>
+            {
+                Some(
+                    LexicalDeclaration::new(false, self.allow_yield, self.allow_await, true)
+                        .parse(cursor, interner)?
+                        .into(),
+                )
+            }
+            TokenKind::Keyword((Keyword::Await, false))
+                if self.allow_await.0 && is_await_using_declaration(cursor, interner)? =>
+            {
+                Some(
+                    LexicalDeclaration::new(false, self.allow_yield, self.allow_await, true)
+                        .parse(cursor, interner)?
+                        .into(),
+                )
+            }
             TokenKind::Keyword((Keyword::Async, false)) if !r#await => {
                 if matches!(
                     cursor.peek(1, interner).or_abrupt()?.kind(),
@@ -223,7 +251,10 @@ where
                 // Checks are only applicable to lexical bindings.
                 if matches!(
                     &init,
-                    IterableLoopInitializer::Const(_) | IterableLoopInitializer::Let(_)
+                    IterableLoopInitializer::Const(_)
+                        | IterableLoopInitializer::Let(_)
+                        | IterableLoopInitializer::Using(_)
+                        | IterableLoopInitializer::AwaitUsing(_)
                 ) {
                     // It is a Syntax Error if the BoundNames of ForDeclaration contains "let".
                     // It is a Syntax Error if any element of the BoundNames of ForDeclaration also occurs in the VarDeclaredNames of Statement.
@@ -261,11 +292,16 @@ where
         };
 
         if let Some(ForLoopInitializer::Lexical(initializer)) = &init {
-            if let ast::declaration::LexicalDeclaration::Const(list) = initializer.declaration() {
-                for decl in list.as_ref() {
+            let declaration = initializer.declaration();
+            if declaration.is_const() {
+                for decl in declaration.variable_list().as_ref() {
                     if decl.init().is_none() {
                         return Err(Error::general(
-                            "Expected initializer for const declaration",
+                            if declaration.is_using() {
+                                "Expected initializer for using declaration"
+                            } else {
+                                "Expected initializer for const declaration"
+                            },
                             position,
                         ));
                     }
@@ -395,6 +431,22 @@ fn initializer_to_iterable_loop_initializer(
                         ast::declaration::LexicalDeclaration::Let(_) => {
                             IterableLoopInitializer::Let(decl.binding().clone())
                         }
+                        ast::declaration::LexicalDeclaration::Using(_)
+                        | ast::declaration::LexicalDeclaration::AwaitUsing(_)
+                            if in_loop =>
+                        {
+                            return Err(Error::lex(LexError::Syntax(
+                                "using declarations are not allowed in the head of a for-in loop"
+                                    .into(),
+                                position,
+                            )));
+                        }
+                        ast::declaration::LexicalDeclaration::Using(_) => {
+                            IterableLoopInitializer::Using(decl.binding().clone())
+                        }
+                        ast::declaration::LexicalDeclaration::AwaitUsing(_) => {
+                            IterableLoopInitializer::AwaitUsing(decl.binding().clone())
+                        }
                     })
                 }
                 _ => Err(Error::lex(LexError::Syntax(
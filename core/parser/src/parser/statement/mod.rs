@@ -26,7 +26,10 @@ use self::{
     block::BlockStatement,
     break_stm::BreakStatement,
     continue_stm::ContinueStatement,
-    declaration::{allowed_token_after_let, Declaration, ExportDeclaration, ImportDeclaration},
+    declaration::{
+        allowed_token_after_let, is_await_using_declaration, is_using_declaration, Declaration,
+        ExportDeclaration, ImportDeclaration, LexicalDeclaration,
+    },
     expression::ExpressionStatement,
     if_stm::IfStatement,
     iteration::{DoWhileStatement, ForStatement, WhileStatement},
@@ -39,7 +42,10 @@ use self::{
     with::WithStatement,
 };
 use crate::{
-    lexer::{token::EscapeSequence, Error as LexError, InputElement, Token, TokenKind},
+    lexer::{
+        token::{ContainsEscapeSequence, EscapeSequence},
+        Error as LexError, InputElement, Token, TokenKind,
+    },
     parser::{
         expression::{BindingIdentifier, Initializer, PropertyName},
         AllowAwait, AllowReturn, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
@@ -56,10 +62,10 @@ use boa_ast::{
     pattern::{ArrayPattern, ArrayPatternElement, ObjectPattern, ObjectPatternElement},
     Keyword, Punctuator, Span,
 };
-use boa_interner::Interner;
+use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
 
-pub(in crate::parser) use declaration::ClassTail;
+pub(in crate::parser) use declaration::{ClassTail, DecoratorList};
 
 /// Statement parsing.
 ///
@@ -240,6 +246,7 @@ pub(super) struct StatementList {
     break_nodes: &'static [TokenKind],
     directive_prologues: bool,
     strict: bool,
+    using_error: Option<&'static str>,
 }
 
 impl StatementList {
@@ -264,8 +271,16 @@ impl StatementList {
             break_nodes,
             directive_prologues,
             strict,
+            using_error: None,
         }
     }
+
+    /// Makes the parser fail with `message` at the position of any `using` or `await using`
+    /// declaration directly contained in the list.
+    pub(super) const fn forbid_using_declarations(mut self, message: &'static str) -> Self {
+        self.using_error = Some(message);
+        self
+    }
 }
 
 impl<R> TokenParser<R> for StatementList
@@ -296,9 +311,11 @@ where
 
         loop {
             let peek_token = cursor.peek(0, interner)?;
+            let mut item_start = None;
             if let Some(peek_token) = peek_token {
                 linear_pos_end = peek_token.linear_span().end();
                 end_position = Some(peek_token.span().end());
+                item_start = Some(peek_token.span().start());
             }
 
             match peek_token {
@@ -316,6 +333,16 @@ where
                 StatementListItem::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor, interner)?;
 
+            if let (Some(message), Some(position)) = (self.using_error, item_start) {
+                if matches!(
+                    &item,
+                    ast::StatementListItem::Declaration(decl)
+                        if matches!(decl.as_ref(), ast::Declaration::Lexical(lex) if lex.is_using())
+                ) {
+                    return Err(Error::general(message, position));
+                }
+            }
+
             if directive_prologues {
                 if let ast::StatementListItem::Statement(statement) = &item {
                     if let ast::Statement::Expression(ast::Expression::Literal(lit)) =
@@ -421,7 +448,8 @@ where
         let tok = cursor.peek(0, interner).or_abrupt()?;
 
         match tok.kind().clone() {
-            TokenKind::Keyword((Keyword::Function | Keyword::Class | Keyword::Const, _)) => {
+            TokenKind::Keyword((Keyword::Function | Keyword::Class | Keyword::Const, _))
+            | TokenKind::Punctuator(Punctuator::At) => {
                 Declaration::new(self.allow_yield, self.allow_await)
                     .parse(cursor, interner)
                     .map(ast::StatementListItem::from)
@@ -454,6 +482,20 @@ where
                         .map(ast::StatementListItem::from),
                 }
             }
+            TokenKind::IdentifierName((Sym::USING, ContainsEscapeSequence(false)))
+                if is_using_declaration(cursor, interner)? =>
+            {
+                LexicalDeclaration::new(true, self.allow_yield, self.allow_await, false)
+                    .parse(cursor, interner)
+                    .map(|decl| ast::Declaration::from(decl).into())
+            }
+            TokenKind::Keyword((Keyword::Await, false))
+                if self.allow_await.0 && is_await_using_declaration(cursor, interner)? =>
+            {
+                LexicalDeclaration::new(true, self.allow_yield, self.allow_await, false)
+                    .parse(cursor, interner)
+                    .map(|decl| ast::Declaration::from(decl).into())
+            }
             _ => Statement::new(self.allow_yield, self.allow_await, self.allow_return)
                 .parse(cursor, interner)
                 .map(ast::StatementListItem::from),
@@ -461,6 +503,17 @@ where
     }
 }
 
+/// Checks if a statement list directly contains a `using` or `await using` declaration.
+pub(in crate::parser) fn directly_contains_using_declaration(list: &ast::StatementList) -> bool {
+    list.statements().iter().any(|item| {
+        matches!(
+            item,
+            ast::StatementListItem::Declaration(decl)
+                if matches!(decl.as_ref(), ast::Declaration::Lexical(lex) if lex.is_using())
+        )
+    })
+}
+
 /// `ObjectBindingPattern` pattern parsing.
 ///
 /// More information:
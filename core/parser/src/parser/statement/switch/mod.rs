@@ -4,8 +4,9 @@ mod tests;
 use crate::{
     lexer::TokenKind,
     parser::{
-        expression::Expression, statement::StatementList, AllowAwait, AllowReturn, AllowYield,
-        Cursor, OrAbrupt, ParseResult, TokenParser,
+        expression::Expression,
+        statement::{directly_contains_using_declaration, StatementList},
+        AllowAwait, AllowReturn, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
     source::ReadChar,
     Error,
@@ -175,6 +176,15 @@ where
                     )
                     .parse(cursor, interner)?;
 
+                    // It is a Syntax Error if StatementList Contains UsingDeclaration.
+                    // It is a Syntax Error if StatementList Contains AwaitUsingDeclaration.
+                    if directly_contains_using_declaration(&statement_list) {
+                        return Err(Error::general(
+                            "using declarations are not allowed directly in switch cases",
+                            token.span().start(),
+                        ));
+                    }
+
                     cases.push(statement::Case::new(cond, statement_list));
                 }
                 TokenKind::Keyword((Keyword::Default, false)) => {
@@ -199,6 +209,15 @@ where
                     )
                     .parse(cursor, interner)?;
 
+                    // It is a Syntax Error if StatementList Contains UsingDeclaration.
+                    // It is a Syntax Error if StatementList Contains AwaitUsingDeclaration.
+                    if directly_contains_using_declaration(&statement_list) {
+                        return Err(Error::general(
+                            "using declarations are not allowed directly in switch cases",
+                            token.span().start(),
+                        ));
+                    }
+
                     cases.push(statement::Case::default(statement_list));
 
                     has_default_case = true;
@@ -104,3 +104,21 @@ fn class_declaration_elements_private_static() {
         "#,
     );
 }
+
+#[cfg(feature = "experimental")]
+#[test]
+fn class_declaration_decorators() {
+    test_formatting(
+        r#"
+        @dec @ns.dec @dec(1, 2) class A {
+            @dec a;
+            @dec static b = 1;
+            @dec #c() {}
+            @dec get d() {}
+            @dec accessor e = 1;
+            @dec static accessor #f;
+            accessor;
+        }
+        "#,
+    );
+}
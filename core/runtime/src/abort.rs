@@ -0,0 +1,230 @@
+//! Boa's implementation of JavaScript's `AbortController` and `AbortSignal` Web API
+//! classes.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG DOM specification][spec]
+//!
+//! [spec]: https://dom.spec.whatwg.org/#aborting-ongoing-activities
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/AbortController
+#![allow(clippy::needless_pass_by_value)]
+
+#[cfg(test)]
+mod tests;
+
+use crate::event::{
+    define_to_string_tag, dom_exception, report_error, Event, EventTarget, EventTargetData,
+};
+use boa_engine::class::Class;
+use boa_engine::{
+    js_error, js_string, Context, Finalize, JsData, JsError, JsObject, JsResult, JsValue,
+    NativeFunction, Trace,
+};
+use boa_interop::boa_macros::boa_class;
+
+/// The largest integer which can be represented exactly by a number, `2^53 - 1`.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+/// Register the `AbortSignal` and `AbortController` classes into the realm. The
+/// `EventTarget` class must already be registered.
+///
+/// # Errors
+/// This will error if the context or realm cannot register the classes.
+pub fn register(context: &mut Context) -> JsResult<()> {
+    EventTarget::register_subclass::<AbortSignal>(context)?;
+    context.register_global_class::<AbortController>()?;
+
+    let prototype = context
+        .get_global_class::<AbortController>()
+        .ok_or_else(|| js_error!(TypeError: "The AbortController class is not registered"))?
+        .prototype();
+    define_to_string_tag(&prototype, js_string!("AbortController"), context)
+}
+
+/// The `AbortSignal` class, an `EventTarget` notifying that an operation was aborted.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct AbortSignal {
+    target: EventTarget,
+    reason: Option<JsValue>,
+    /// The listeners added with this signal, removed when it is aborted.
+    listeners: Vec<(EventTarget, u64)>,
+}
+
+impl EventTargetData for AbortSignal {
+    fn event_target(&self) -> &EventTarget {
+        &self.target
+    }
+}
+
+impl AbortSignal {
+    /// Create a new `AbortSignal` object which isn't aborted.
+    ///
+    /// # Errors
+    /// This will error if the `AbortSignal` class isn't registered.
+    pub fn create(context: &mut Context) -> JsResult<JsObject<Self>> {
+        let prototype = context
+            .get_global_class::<Self>()
+            .ok_or_else(|| js_error!(TypeError: "The AbortSignal class is not registered"))?
+            .prototype();
+        Ok(JsObject::new_unique(
+            Some(prototype),
+            Self {
+                target: EventTarget::new(),
+                reason: None,
+                listeners: Vec::new(),
+            },
+        ))
+    }
+
+    /// Abort `signal` with `reason`, or with an `AbortError` if `reason` is `undefined`,
+    /// and dispatch an `abort` event to it. Does nothing if `signal` is already aborted.
+    ///
+    /// The errors thrown by the listeners of the `abort` event are reported, see
+    /// [`EventTarget::set_error_reporter`].
+    pub fn abort(signal: &JsObject<Self>, reason: JsValue, context: &mut Context) {
+        if signal.borrow().data().aborted() {
+            return;
+        }
+        let reason = if reason.is_undefined() {
+            dom_exception(
+                js_string!("AbortError"),
+                "This operation was aborted",
+                context,
+            )
+            .to_opaque(context)
+        } else {
+            reason
+        };
+
+        let (target, listeners) = {
+            let mut signal = signal.borrow_mut();
+            let signal = signal.data_mut();
+            signal.reason = Some(reason);
+            (signal.target.clone(), std::mem::take(&mut signal.listeners))
+        };
+        for (listener_target, id) in listeners {
+            listener_target.remove_listener(id);
+        }
+
+        // The event is new, so this can only fail if the `Event` class isn't registered.
+        let result = Event::from_data(Event::trusted(js_string!("abort")), context)
+            .and_then(|event| target.dispatch(&signal.clone().upcast(), &event, context));
+        if let Err(err) = result {
+            report_error(err, context);
+        }
+    }
+
+    /// Record the listener `id` of `target`, to remove it when the signal is aborted.
+    pub(crate) fn add_listener_removal(&mut self, target: EventTarget, id: u64) {
+        self.listeners.push((target, id));
+    }
+}
+
+#[boa_class]
+#[boa(rename_all = "camelCase")]
+impl AbortSignal {
+    /// `AbortSignal` objects cannot be created from JavaScript with `new`.
+    #[boa(constructor)]
+    fn illegal_constructor() -> JsResult<Self> {
+        Err(js_error!(TypeError: "Illegal constructor"))
+    }
+
+    /// Whether the signal was aborted.
+    #[boa(getter)]
+    #[must_use]
+    pub fn aborted(&self) -> bool {
+        self.reason.is_some()
+    }
+
+    /// Get the reason the signal was aborted with, or `undefined` if it wasn't aborted.
+    #[boa(getter)]
+    #[must_use]
+    pub fn reason(&self) -> JsValue {
+        self.reason.clone().unwrap_or_default()
+    }
+
+    /// Throw the reason of the signal if it was aborted.
+    #[boa(rename = "throwIfAborted")]
+    fn throw_if_aborted(&self) -> JsResult<()> {
+        match &self.reason {
+            Some(reason) => Err(JsError::from_opaque(reason.clone())),
+            None => Ok(()),
+        }
+    }
+
+    #[boa(static)]
+    #[boa(rename = "abort")]
+    #[boa(length = 0)]
+    fn aborted_signal(reason: JsValue, context: &mut Context) -> JsResult<JsObject> {
+        let signal = Self::create(context)?;
+        Self::abort(&signal, reason, context);
+        Ok(signal.upcast())
+    }
+
+    #[boa(static)]
+    fn timeout(milliseconds: JsValue, context: &mut Context) -> JsResult<JsObject> {
+        let milliseconds = milliseconds.to_number(context)?;
+        if !milliseconds.is_finite() || !(0.0..=MAX_SAFE_INTEGER).contains(&milliseconds.trunc()) {
+            return Err(js_error!(TypeError: "Timeout must be a non-negative safe integer"));
+        }
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let delay = milliseconds.trunc() as u64;
+
+        let signal = Self::create(context)?;
+        let on_timeout = NativeFunction::from_copy_closure_with_captures(
+            |_, _, signal, context| {
+                let reason = dom_exception(
+                    js_string!("TimeoutError"),
+                    "The operation timed out",
+                    context,
+                )
+                .to_opaque(context);
+                Self::abort(signal, reason, context);
+                Ok(JsValue::undefined())
+            },
+            signal.clone(),
+        )
+        .to_js_function(context.realm());
+        crate::interval::schedule_timeout(on_timeout, delay, Vec::new(), context)?;
+
+        Ok(signal.upcast())
+    }
+}
+
+/// The `AbortController` class, which aborts its `AbortSignal`.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct AbortController {
+    signal: JsObject<AbortSignal>,
+}
+
+#[boa_class]
+#[boa(rename_all = "camelCase")]
+impl AbortController {
+    /// Create a new `AbortController` with a new signal.
+    ///
+    /// # Errors
+    /// This will error if the `AbortSignal` class isn't registered.
+    #[boa(constructor)]
+    #[boa(length = 0)]
+    pub fn new(context: &mut Context) -> JsResult<Self> {
+        Ok(Self {
+            signal: AbortSignal::create(context)?,
+        })
+    }
+
+    /// Get the signal of the controller.
+    #[boa(getter)]
+    #[must_use]
+    pub fn signal(&self) -> JsObject {
+        self.signal.clone().upcast()
+    }
+
+    /// Abort the signal of the controller with `reason`.
+    ///
+    /// The errors thrown by the listeners of the `abort` event are reported, see
+    /// [`EventTarget::set_error_reporter`].
+    #[boa(length = 0)]
+    pub fn abort(&self, reason: JsValue, context: &mut Context) {
+        AbortSignal::abort(&self.signal, reason, context);
+    }
+}
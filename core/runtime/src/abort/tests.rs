@@ -0,0 +1,191 @@
+use crate::event::EventTarget;
+use crate::test::{run_test_actions, run_test_actions_with, TestAction};
+use crate::{register, RegisterOptions};
+use boa_engine::context::time::FixedClock;
+use boa_engine::context::ContextBuilder;
+use boa_engine::{js_str, Context};
+use std::rc::Rc;
+
+const TEST_HARNESS: &str = r#"
+function assert(condition, message) {
+    if (!condition) {
+        if (!message) {
+            message = "Assertion failed";
+        }
+        throw new Error(message);
+    }
+}
+
+function assert_eq(a, b, message) {
+    if (a !== b) {
+        throw new Error(`${message} (${JSON.stringify(a)} !== ${JSON.stringify(b)})`);
+    }
+}
+
+function throws(f) {
+    try {
+        f();
+    } catch (e) {
+        return e;
+    }
+    throw new Error("expected an exception");
+}
+"#;
+
+#[test]
+fn abort_controller() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r#"
+                const controller = new AbortController();
+                const signal = controller.signal;
+                assert(signal instanceof AbortSignal);
+                assert(signal instanceof EventTarget);
+                assert_eq(controller.signal, signal);
+                assert_eq(signal.aborted, false);
+                assert_eq(signal.reason, undefined);
+                signal.throwIfAborted();
+
+                let events = [];
+                signal.addEventListener("abort", (event) => events.push(event));
+
+                controller.abort();
+                controller.abort("again");
+                assert_eq(events.length, 1);
+                assert_eq(events[0].type, "abort");
+                assert_eq(events[0].isTrusted, true);
+                assert_eq(events[0].target, signal);
+                assert_eq(signal.aborted, true);
+                assert(signal.reason instanceof Error);
+                assert_eq(signal.reason.name, "AbortError");
+                assert_eq(throws(() => signal.throwIfAborted()), signal.reason);
+
+                const other = new AbortController();
+                other.abort("reason");
+                assert_eq(other.signal.reason, "reason");
+
+                assert(throws(() => new AbortSignal()) instanceof TypeError);
+                assert_eq(Object.prototype.toString.call(signal), "[object AbortSignal]");
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn abort_reports_listener_errors() {
+    let context = &mut Context::default();
+    register(context, RegisterOptions::default()).unwrap();
+    EventTarget::set_error_reporter(context, |error, context| {
+        let error = error.to_opaque(context);
+        context
+            .global_object()
+            .set(js_str!("reported"), error, false, context)
+            .unwrap();
+    });
+
+    run_test_actions_with(
+        [
+            TestAction::run(TEST_HARNESS),
+            TestAction::run(
+                r#"
+                    const controller = new AbortController();
+                    controller.signal.addEventListener("abort", () => { throw new RangeError("listener"); });
+                    controller.abort();
+                    assert(controller.signal.aborted);
+                    assert(reported instanceof RangeError);
+                "#,
+            ),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn abort_static() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r#"
+                const signal = AbortSignal.abort();
+                assert_eq(signal.aborted, true);
+                assert_eq(signal.reason.name, "AbortError");
+                assert_eq(AbortSignal.abort(42).reason, 42);
+
+                let called = false;
+                const target = new EventTarget();
+                target.addEventListener("ping", () => { called = true; }, { signal });
+                target.dispatchEvent(new Event("ping"));
+                assert_eq(called, false);
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn listener_signal() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r#"
+                const controller = new AbortController();
+                const target = new EventTarget();
+                let count = 0;
+                target.addEventListener("ping", () => count++, { signal: controller.signal });
+
+                target.dispatchEvent(new Event("ping"));
+                controller.abort();
+                target.dispatchEvent(new Event("ping"));
+                assert_eq(count, 1);
+
+                assert(
+                    throws(() => target.addEventListener("ping", () => {}, { signal: {} }))
+                        instanceof TypeError,
+                );
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn timeout() {
+    let clock = Rc::new(FixedClock::default());
+    let context = &mut ContextBuilder::default()
+        .clock(clock.clone())
+        .build()
+        .unwrap();
+    register(context, RegisterOptions::default()).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(TEST_HARNESS),
+            TestAction::run(
+                r#"
+                    signal = AbortSignal.timeout(100);
+                    aborted = false;
+                    signal.addEventListener("abort", () => { aborted = true; });
+
+                    assert(throws(() => AbortSignal.timeout(-1)) instanceof TypeError);
+                    assert(throws(() => AbortSignal.timeout(Infinity)) instanceof TypeError);
+                "#,
+            ),
+            TestAction::inspect_context(move |ctx| {
+                ctx.run_jobs().unwrap();
+                let aborted = ctx.global_object().get(js_str!("aborted"), ctx).unwrap();
+                assert_eq!(aborted.as_boolean(), Some(false));
+
+                clock.forward(100);
+                ctx.run_jobs().unwrap();
+                let aborted = ctx.global_object().get(js_str!("aborted"), ctx).unwrap();
+                assert_eq!(aborted.as_boolean(), Some(true));
+            }),
+            TestAction::run(
+                r#"
+                    assert_eq(signal.aborted, true);
+                    assert_eq(signal.reason.name, "TimeoutError");
+                "#,
+            ),
+        ],
+        context,
+    );
+}
@@ -0,0 +1,418 @@
+//! Boa's implementation of JavaScript's `Event`, `CustomEvent` and `EventTarget` Web API
+//! classes.
+//!
+//! As the runtime doesn't have a tree of nodes, events are only dispatched to their
+//! target and never propagate to other targets.
+//!
+//! Rust classes can extend `EventTarget` (resp. `Event`) by keeping an [`EventTarget`]
+//! (resp. an [`Event`]) in their native data, implementing [`EventTargetData`] (resp.
+//! [`EventData`]) and being registered with [`EventTarget::register_subclass`] (resp.
+//! [`Event::register_subclass`]). The methods of `EventTarget.prototype` and
+//! `Event.prototype` then work on their instances.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG DOM specification][spec]
+//!
+//! [spec]: https://dom.spec.whatwg.org/#events
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Event
+#![allow(clippy::needless_pass_by_value)]
+
+#[cfg(test)]
+mod tests;
+
+mod custom_event;
+mod target;
+
+#[doc(inline)]
+pub use custom_event::CustomEvent;
+pub(crate) use target::report_error;
+#[doc(inline)]
+pub use target::{EventTarget, EventTargetData};
+
+use boa_engine::class::Class;
+use boa_engine::interop::JsThis;
+use boa_engine::object::builtins::JsArray;
+use boa_engine::property::PropertyDescriptor;
+use boa_engine::{
+    js_error, js_string, Context, Finalize, JsData, JsError, JsNativeError, JsObject, JsResult,
+    JsString, JsSymbol, JsValue, NativeObject, Trace,
+};
+use boa_gc::{Gc, GcRefCell};
+use boa_interop::boa_macros::boa_class;
+
+/// Register the `EventTarget`, `Event` and `CustomEvent` classes into the realm.
+///
+/// # Errors
+/// This will error if the context or realm cannot register the classes.
+pub fn register(context: &mut Context) -> JsResult<()> {
+    EventTarget::register(context)?;
+    Event::register(context)?;
+    Event::register_subclass::<CustomEvent>(context)?;
+    Ok(())
+}
+
+/// The native data of a class extending `Event`.
+pub trait EventData: NativeObject {
+    /// Get the event state of the object.
+    fn event(&self) -> &Event;
+}
+
+/// The options of a new event, the `EventInit` dictionary of the specification.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Trace, Finalize)]
+#[boa_gc(empty_trace)]
+pub struct EventInit {
+    /// Whether the event bubbles.
+    pub bubbles: bool,
+    /// Whether the event can be canceled with `preventDefault`.
+    pub cancelable: bool,
+    /// Whether the event propagates across shadow roots.
+    pub composed: bool,
+}
+
+impl EventInit {
+    /// Read the options from the `EventInit` dictionary `init`.
+    ///
+    /// # Errors
+    /// Throws a `TypeError` if `init` is not an object, `null` or `undefined`, and any
+    /// error thrown while reading its properties.
+    pub fn from_value(init: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let Some(init) = init.as_object() else {
+            if init.is_null_or_undefined() {
+                return Ok(Self::default());
+            }
+            return Err(js_error!(TypeError: "Event init must be an object"));
+        };
+        Ok(Self {
+            bubbles: init.get(js_string!("bubbles"), context)?.to_boolean(),
+            cancelable: init.get(js_string!("cancelable"), context)?.to_boolean(),
+            composed: init.get(js_string!("composed"), context)?.to_boolean(),
+        })
+    }
+}
+
+/// The `eventPhase` of an event that is not being dispatched.
+const NONE: u16 = 0;
+
+/// The `eventPhase` of an event being dispatched to its target.
+const AT_TARGET: u16 = 2;
+
+/// The state of an event, shared by the `Event` handles of the same event. The flags
+/// are the ones of the specification.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Trace, Finalize)]
+struct EventState {
+    event_type: JsString,
+    init: EventInit,
+    is_trusted: bool,
+    target: Option<JsObject>,
+    current_target: Option<JsObject>,
+    phase: u16,
+    stop_propagation: bool,
+    stop_immediate_propagation: bool,
+    canceled: bool,
+    in_passive_listener: bool,
+    dispatching: bool,
+}
+
+/// The `Event` class represents an event dispatched to an `EventTarget`.
+///
+/// This is a handle to the state of the event, cloning it doesn't create a new event.
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct Event {
+    state: Gc<GcRefCell<EventState>>,
+}
+
+/// The functions getting the event of the objects of the classes extending `Event`,
+/// kept in the context data.
+#[derive(Default, Trace, Finalize, JsData)]
+#[boa_gc(unsafe_empty_trace)]
+struct EventSubclasses(Vec<fn(&JsObject) -> Option<Event>>);
+
+impl Event {
+    /// Create an event of type `event_type`.
+    #[must_use]
+    pub fn with_type(event_type: JsString, init: EventInit) -> Self {
+        Self {
+            state: Gc::new(GcRefCell::new(EventState {
+                event_type,
+                init,
+                is_trusted: false,
+                target: None,
+                current_target: None,
+                phase: NONE,
+                stop_propagation: false,
+                stop_immediate_propagation: false,
+                canceled: false,
+                in_passive_listener: false,
+                dispatching: false,
+            })),
+        }
+    }
+
+    /// Create an event of type `event_type` dispatched by the runtime itself.
+    pub(crate) fn trusted(event_type: JsString) -> Self {
+        let event = Self::with_type(event_type, EventInit::default());
+        event.state.borrow_mut().is_trusted = true;
+        event
+    }
+
+    /// Get the event of `object`, if it is an `Event` or an instance of a class extending
+    /// `Event` registered with [`Event::register_subclass`].
+    #[must_use]
+    pub fn from_object(object: &JsObject, context: &Context) -> Option<Self> {
+        if let Some(event) = object.downcast_ref::<Self>() {
+            return Some(event.clone());
+        }
+        context
+            .get_data::<EventSubclasses>()?
+            .0
+            .iter()
+            .find_map(|downcast| downcast(object))
+    }
+
+    /// Register the class `C` into the realm, as a class extending `Event`.
+    ///
+    /// # Errors
+    /// This will error if the `Event` class isn't registered, or if `C` cannot be
+    /// registered.
+    pub fn register_subclass<C: Class + EventData>(context: &mut Context) -> JsResult<()> {
+        /// Get the event of `object` if it is an instance of `C`.
+        fn downcast<C: EventData>(object: &JsObject) -> Option<Event> {
+            object.downcast_ref::<C>().map(|data| data.event().clone())
+        }
+
+        context.register_global_class::<C>()?;
+        inherit::<Self, C>(context)?;
+
+        let mut subclasses = context
+            .get_data::<EventSubclasses>()
+            .map(|subclasses| subclasses.0.clone())
+            .unwrap_or_default();
+        subclasses.push(downcast::<C>);
+        context.insert_data(EventSubclasses(subclasses));
+        Ok(())
+    }
+
+    /// Get the type of the event.
+    #[must_use]
+    pub fn event_type(&self) -> JsString {
+        self.state.borrow().event_type.clone()
+    }
+
+    /// Whether `preventDefault` was called on a cancelable event.
+    #[must_use]
+    pub fn default_prevented(&self) -> bool {
+        self.state.borrow().canceled
+    }
+
+    /// Register the `Event` class, along with its constants.
+    fn register(context: &mut Context) -> JsResult<()> {
+        context.register_global_class::<Self>()?;
+
+        let class = context
+            .get_global_class::<Self>()
+            .ok_or_else(|| js_error!(TypeError: "The Event class is not registered"))?;
+        let (constructor, prototype) = (class.constructor(), class.prototype());
+        for (name, value) in [
+            (js_string!("NONE"), NONE),
+            (js_string!("CAPTURING_PHASE"), 1),
+            (js_string!("AT_TARGET"), AT_TARGET),
+            (js_string!("BUBBLING_PHASE"), 3),
+        ] {
+            for object in [&constructor, &prototype] {
+                object.define_property_or_throw(
+                    name.clone(),
+                    PropertyDescriptor::builder()
+                        .value(value)
+                        .writable(false)
+                        .enumerable(true)
+                        .configurable(false),
+                    context,
+                )?;
+            }
+        }
+        define_to_string_tag(&prototype, js_string!("Event"), context)
+    }
+
+    /// Get the event of the `this` object of a method.
+    fn from_this(this: &JsObject, context: &Context) -> JsResult<Self> {
+        Self::from_object(this, context)
+            .ok_or_else(|| js_error!(TypeError: "`this` is not an Event"))
+    }
+}
+
+#[boa_class]
+#[boa(rename_all = "camelCase")]
+impl Event {
+    /// Create a new `Event` object. Meant to be called from the JavaScript constructor.
+    ///
+    /// # Errors
+    /// Any errors that might occur while converting the arguments.
+    #[boa(constructor)]
+    #[boa(length = 1)]
+    pub fn new(event_type: JsValue, init: JsValue, context: &mut Context) -> JsResult<Self> {
+        let event_type = event_type.to_string(context)?;
+        let init = EventInit::from_value(&init, context)?;
+        Ok(Self::with_type(event_type, init))
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "type")]
+    fn get_type(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<JsString> {
+        Ok(Self::from_this(&this, context)?.event_type())
+    }
+
+    #[boa(getter)]
+    fn target(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<JsValue> {
+        let event = Self::from_this(&this, context)?;
+        let target = event.state.borrow().target.clone();
+        Ok(target.map_or_else(JsValue::null, JsValue::from))
+    }
+
+    #[boa(getter)]
+    fn current_target(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<JsValue> {
+        let event = Self::from_this(&this, context)?;
+        let current_target = event.state.borrow().current_target.clone();
+        Ok(current_target.map_or_else(JsValue::null, JsValue::from))
+    }
+
+    #[boa(getter)]
+    fn event_phase(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<u16> {
+        Ok(Self::from_this(&this, context)?.state.borrow().phase)
+    }
+
+    #[boa(getter)]
+    fn bubbles(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<bool> {
+        Ok(Self::from_this(&this, context)?.state.borrow().init.bubbles)
+    }
+
+    #[boa(getter)]
+    fn cancelable(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<bool> {
+        Ok(Self::from_this(&this, context)?
+            .state
+            .borrow()
+            .init
+            .cancelable)
+    }
+
+    #[boa(getter)]
+    fn composed(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<bool> {
+        Ok(Self::from_this(&this, context)?
+            .state
+            .borrow()
+            .init
+            .composed)
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "defaultPrevented")]
+    fn get_default_prevented(
+        JsThis(this): JsThis<JsObject>,
+        context: &mut Context,
+    ) -> JsResult<bool> {
+        Ok(Self::from_this(&this, context)?.default_prevented())
+    }
+
+    #[boa(getter)]
+    fn is_trusted(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<bool> {
+        Ok(Self::from_this(&this, context)?.state.borrow().is_trusted)
+    }
+
+    #[boa(length = 0)]
+    fn stop_propagation(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<()> {
+        Self::from_this(&this, context)?
+            .state
+            .borrow_mut()
+            .stop_propagation = true;
+        Ok(())
+    }
+
+    #[boa(length = 0)]
+    fn stop_immediate_propagation(
+        JsThis(this): JsThis<JsObject>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let event = Self::from_this(&this, context)?;
+        let mut state = event.state.borrow_mut();
+        state.stop_propagation = true;
+        state.stop_immediate_propagation = true;
+        Ok(())
+    }
+
+    #[boa(length = 0)]
+    fn prevent_default(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<()> {
+        let event = Self::from_this(&this, context)?;
+        let mut state = event.state.borrow_mut();
+        if state.init.cancelable && !state.in_passive_listener {
+            state.canceled = true;
+        }
+        Ok(())
+    }
+
+    #[boa(length = 0)]
+    fn composed_path(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<JsArray> {
+        let event = Self::from_this(&this, context)?;
+        let current_target = event.state.borrow().current_target.clone();
+        Ok(JsArray::from_iter(
+            current_target.into_iter().map(JsValue::from),
+            context,
+        ))
+    }
+}
+
+/// Make the class `C` extend the class `P`, and define the `@@toStringTag` of `C`. Both
+/// classes must be registered in the realm.
+fn inherit<P: Class, C: Class>(context: &mut Context) -> JsResult<()> {
+    let (Some(parent), Some(class)) = (
+        context.get_global_class::<P>(),
+        context.get_global_class::<C>(),
+    ) else {
+        return Err(js_error!(TypeError: "The {} class is not registered", P::NAME));
+    };
+    class.prototype().set_prototype(Some(parent.prototype()));
+    class
+        .constructor()
+        .set_prototype(Some(parent.constructor()));
+    define_to_string_tag(&class.prototype(), JsString::from(C::NAME), context)
+}
+
+/// Define the `@@toStringTag` property of the `prototype` of a class.
+///
+/// `boa_class` cannot define symbol-keyed properties, so they are added manually.
+pub(crate) fn define_to_string_tag(
+    prototype: &JsObject,
+    tag: JsString,
+    context: &mut Context,
+) -> JsResult<()> {
+    prototype.define_property_or_throw(
+        JsSymbol::to_string_tag(),
+        PropertyDescriptor::builder()
+            .value(tag)
+            .writable(false)
+            .enumerable(false)
+            .configurable(true),
+        context,
+    )?;
+    Ok(())
+}
+
+/// Create an error standing for the `DOMException` named `name`, which the runtime
+/// doesn't implement.
+pub(crate) fn dom_exception(name: JsString, message: &str, context: &mut Context) -> JsError {
+    let error = JsNativeError::error()
+        .with_message(message.to_owned())
+        .to_opaque(context);
+    error
+        .define_property_or_throw(
+            js_string!("name"),
+            PropertyDescriptor::builder()
+                .value(name)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )
+        .expect("defining a property of a new error object cannot fail");
+    JsError::from_opaque(error.into())
+}
@@ -0,0 +1,69 @@
+//! Boa's implementation of JavaScript's `CustomEvent` Web API class.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG DOM specification][spec]
+//!
+//! [spec]: https://dom.spec.whatwg.org/#interface-customevent
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/CustomEvent
+
+use super::{Event, EventData, EventInit};
+use boa_engine::{js_string, Context, Finalize, JsData, JsResult, JsString, JsValue, Trace};
+use boa_interop::boa_macros::boa_class;
+
+/// The `CustomEvent` class represents an event carrying arbitrary data in its `detail`.
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct CustomEvent {
+    event: Event,
+    detail: JsValue,
+}
+
+impl CustomEvent {
+    /// Create a custom event of type `event_type` carrying `detail`.
+    #[must_use]
+    pub fn with_detail(event_type: JsString, init: EventInit, detail: JsValue) -> Self {
+        Self {
+            event: Event::with_type(event_type, init),
+            detail,
+        }
+    }
+}
+
+impl EventData for CustomEvent {
+    fn event(&self) -> &Event {
+        &self.event
+    }
+}
+
+#[boa_class]
+#[boa(rename_all = "camelCase")]
+impl CustomEvent {
+    /// Create a new `CustomEvent` object. Meant to be called from the JavaScript
+    /// constructor.
+    ///
+    /// # Errors
+    /// Any errors that might occur while converting the arguments.
+    #[boa(constructor)]
+    #[boa(length = 1)]
+    pub fn new(event_type: JsValue, init: JsValue, context: &mut Context) -> JsResult<Self> {
+        let event_type = event_type.to_string(context)?;
+        let options = EventInit::from_value(&init, context)?;
+        let detail = match init.as_object() {
+            Some(init) => init.get(js_string!("detail"), context)?,
+            None => JsValue::null(),
+        };
+        let detail = if detail.is_undefined() {
+            JsValue::null()
+        } else {
+            detail
+        };
+        Ok(Self::with_detail(event_type, options, detail))
+    }
+
+    /// Get the data carried by the event.
+    #[boa(getter)]
+    #[must_use]
+    pub fn detail(&self) -> JsValue {
+        self.detail.clone()
+    }
+}
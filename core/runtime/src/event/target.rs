@@ -0,0 +1,437 @@
+//! Boa's implementation of JavaScript's `EventTarget` Web API class.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG DOM specification][spec]
+//!
+//! [spec]: https://dom.spec.whatwg.org/#interface-eventtarget
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/EventTarget
+
+use super::{define_to_string_tag, dom_exception, inherit, Event, AT_TARGET, NONE};
+use crate::abort::AbortSignal;
+use boa_engine::class::Class;
+use boa_engine::interop::JsThis;
+use boa_engine::{
+    js_error, js_string, Context, Finalize, JsData, JsError, JsObject, JsResult, JsString, JsValue,
+    NativeObject, Trace,
+};
+use boa_gc::{Gc, GcRefCell};
+use boa_interop::boa_macros::boa_class;
+
+/// The native data of a class extending `EventTarget`.
+pub trait EventTargetData: NativeObject {
+    /// Get the event target state of the object.
+    fn event_target(&self) -> &EventTarget;
+}
+
+/// A listener added with `addEventListener`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct Listener {
+    id: u64,
+    event_type: JsString,
+    callback: JsObject,
+    capture: bool,
+    passive: bool,
+    once: bool,
+}
+
+/// The event listener list of an event target.
+#[derive(Debug, Default, Trace, Finalize)]
+struct Listeners {
+    list: Vec<Listener>,
+    next_id: u64,
+}
+
+/// The options of `addEventListener`, the `AddEventListenerOptions` dictionary of the
+/// specification.
+#[derive(Default)]
+struct ListenerOptions {
+    capture: bool,
+    passive: bool,
+    once: bool,
+    signal: Option<JsObject<AbortSignal>>,
+}
+
+impl ListenerOptions {
+    /// Read the options of `addEventListener` or `removeEventListener`, which can be a
+    /// boolean standing for `capture`.
+    fn from_value(options: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let Some(options) = options.as_object() else {
+            return Ok(Self {
+                capture: options.to_boolean(),
+                ..Self::default()
+            });
+        };
+
+        let capture = options.get(js_string!("capture"), context)?.to_boolean();
+        let once = options.get(js_string!("once"), context)?.to_boolean();
+        let passive = options.get(js_string!("passive"), context)?.to_boolean();
+        let signal = options.get(js_string!("signal"), context)?;
+        let signal = if signal.is_undefined() {
+            None
+        } else {
+            let signal = signal
+                .as_object()
+                .and_then(|signal| signal.clone().downcast::<AbortSignal>().ok())
+                .ok_or_else(|| js_error!(TypeError: "signal option must be an AbortSignal"))?;
+            Some(signal)
+        };
+
+        Ok(Self {
+            capture,
+            passive,
+            once,
+            signal,
+        })
+    }
+}
+
+/// The `EventTarget` class represents an object to which events can be dispatched.
+///
+/// This is a handle to the listeners of the target, cloning it doesn't create a new
+/// target.
+#[derive(Debug, Clone, Default, Trace, Finalize, JsData)]
+pub struct EventTarget {
+    listeners: Gc<GcRefCell<Listeners>>,
+}
+
+/// The functions getting the event target of the objects of the classes extending
+/// `EventTarget`, kept in the context data.
+#[derive(Default, Trace, Finalize, JsData)]
+#[boa_gc(unsafe_empty_trace)]
+struct EventTargetSubclasses(Vec<fn(&JsObject) -> Option<EventTarget>>);
+
+/// The function reporting the errors thrown by event listeners, kept in the context data.
+#[derive(Trace, Finalize, JsData)]
+#[boa_gc(unsafe_empty_trace)]
+struct ErrorReporter(fn(JsError, &mut Context));
+
+impl EventTarget {
+    /// Get the event target of `object`, if it is an `EventTarget` or an instance of a
+    /// class extending `EventTarget` registered with [`EventTarget::register_subclass`].
+    #[must_use]
+    pub fn from_object(object: &JsObject, context: &Context) -> Option<Self> {
+        if let Some(target) = object.downcast_ref::<Self>() {
+            return Some(target.clone());
+        }
+        context
+            .get_data::<EventTargetSubclasses>()?
+            .0
+            .iter()
+            .find_map(|downcast| downcast(object))
+    }
+
+    /// Register the class `C` into the realm, as a class extending `EventTarget`.
+    ///
+    /// # Errors
+    /// This will error if the `EventTarget` class isn't registered, or if `C` cannot be
+    /// registered.
+    pub fn register_subclass<C: Class + EventTargetData>(context: &mut Context) -> JsResult<()> {
+        /// Get the event target of `object` if it is an instance of `C`.
+        fn downcast<C: EventTargetData>(object: &JsObject) -> Option<EventTarget> {
+            object
+                .downcast_ref::<C>()
+                .map(|data| data.event_target().clone())
+        }
+
+        context.register_global_class::<C>()?;
+        inherit::<Self, C>(context)?;
+
+        let mut subclasses = context
+            .get_data::<EventTargetSubclasses>()
+            .map(|subclasses| subclasses.0.clone())
+            .unwrap_or_default();
+        subclasses.push(downcast::<C>);
+        context.insert_data(EventTargetSubclasses(subclasses));
+        Ok(())
+    }
+
+    /// Set the function reporting the errors thrown by event listeners, which are not
+    /// propagated to the code dispatching the event. By default, the errors are logged
+    /// with `console.error`.
+    pub fn set_error_reporter(context: &mut Context, reporter: fn(JsError, &mut Context)) {
+        context.insert_data(ErrorReporter(reporter));
+    }
+
+    /// Dispatch `event` to `this`, the object holding this event target, and return
+    /// `false` if the event was canceled.
+    ///
+    /// The errors thrown by listeners are reported, see [`EventTarget::set_error_reporter`].
+    ///
+    /// # Errors
+    /// Throws if `event` isn't an `Event` or is already being dispatched.
+    pub fn dispatch(
+        &self,
+        this: &JsObject,
+        event: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<bool> {
+        let state = Event::from_object(event, context)
+            .ok_or_else(|| js_error!(TypeError: "dispatchEvent argument must be an Event"))?
+            .state
+            .clone();
+        {
+            let mut state = state.borrow_mut();
+            if state.dispatching {
+                return Err(dom_exception(
+                    js_string!("InvalidStateError"),
+                    "The event is already being dispatched",
+                    context,
+                ));
+            }
+            state.dispatching = true;
+            state.target = Some(this.clone());
+            state.current_target = Some(this.clone());
+            state.phase = AT_TARGET;
+        }
+
+        let event_type = state.borrow().event_type.clone();
+        let listeners = self.listeners.borrow().list.clone();
+
+        // Capturing listeners are invoked before the other ones at the target.
+        'phases: for capture in [true, false] {
+            for listener in listeners
+                .iter()
+                .filter(|listener| listener.capture == capture && listener.event_type == event_type)
+            {
+                // A listener removed by a previous listener isn't invoked.
+                if !self.remove_listener_if(listener.id, listener.once) {
+                    continue;
+                }
+
+                state.borrow_mut().in_passive_listener = listener.passive;
+                let result = call_listener(&listener.callback, this, event, context);
+                state.borrow_mut().in_passive_listener = false;
+                if let Err(err) = result {
+                    report_error(err, context);
+                }
+
+                if state.borrow().stop_immediate_propagation {
+                    break 'phases;
+                }
+            }
+        }
+
+        let mut state = state.borrow_mut();
+        state.dispatching = false;
+        state.phase = NONE;
+        state.current_target = None;
+        state.stop_propagation = false;
+        state.stop_immediate_propagation = false;
+        let not_canceled = !state.canceled;
+        drop(state);
+        Ok(not_canceled)
+    }
+
+    /// Remove the listener with the given `id`, if it wasn't already removed.
+    pub(crate) fn remove_listener(&self, id: u64) {
+        self.listeners
+            .borrow_mut()
+            .list
+            .retain(|listener| listener.id != id);
+    }
+
+    /// Return whether the listener with the given `id` is still in the list, removing it
+    /// if `remove` is `true`.
+    fn remove_listener_if(&self, id: u64, remove: bool) -> bool {
+        let mut listeners = self.listeners.borrow_mut();
+        let Some(index) = listeners.list.iter().position(|listener| listener.id == id) else {
+            return false;
+        };
+        if remove {
+            listeners.list.remove(index);
+        }
+        true
+    }
+
+    /// Register the `EventTarget` class.
+    pub(super) fn register(context: &mut Context) -> JsResult<()> {
+        context.register_global_class::<Self>()?;
+        let prototype = context
+            .get_global_class::<Self>()
+            .ok_or_else(|| js_error!(TypeError: "The EventTarget class is not registered"))?
+            .prototype();
+        define_to_string_tag(&prototype, js_string!("EventTarget"), context)
+    }
+
+    /// Get the event target of the `this` object of a method.
+    fn from_this(this: &JsObject, context: &Context) -> JsResult<Self> {
+        Self::from_object(this, context)
+            .ok_or_else(|| js_error!(TypeError: "`this` is not an EventTarget"))
+    }
+}
+
+/// Report `error` with the reporter set by [`EventTarget::set_error_reporter`], or log it
+/// with `console.error`.
+///
+/// See [report an exception](https://html.spec.whatwg.org/multipage/webappapis.html#report-an-exception).
+pub(crate) fn report_error(error: JsError, context: &mut Context) {
+    if let Some(reporter) = context.get_data::<ErrorReporter>() {
+        let reporter = reporter.0;
+        reporter(error, context);
+        return;
+    }
+
+    // Reporting has no way to fail, so errors thrown while logging are ignored.
+    log_error(&error, context).ok();
+}
+
+/// Log `error` with `console.error`, if the global object has a console.
+fn log_error(error: &JsError, context: &mut Context) -> JsResult<()> {
+    let console = context
+        .global_object()
+        .get(js_string!("console"), context)?;
+    let Some(console) = console.as_object() else {
+        return Ok(());
+    };
+    let log = console.get(js_string!("error"), context)?;
+    if let Some(log) = log.as_callable() {
+        let error = error.to_opaque(context);
+        log.call(&console.clone().into(), &[error], context)?;
+    }
+    Ok(())
+}
+
+/// Invoke the `callback` of a listener, which is either a function or an object with a
+/// `handleEvent` method.
+fn call_listener(
+    callback: &JsObject,
+    this: &JsObject,
+    event: &JsObject,
+    context: &mut Context,
+) -> JsResult<()> {
+    let args = [event.clone().into()];
+    if callback.is_callable() {
+        callback.call(&this.clone().into(), &args, context)?;
+        return Ok(());
+    }
+
+    let handle_event = callback.get(js_string!("handleEvent"), context)?;
+    let handle_event = handle_event
+        .as_callable()
+        .ok_or_else(|| js_error!(TypeError: "handleEvent is not a function"))?;
+    handle_event.call(&callback.clone().into(), &args, context)?;
+    Ok(())
+}
+
+#[boa_class]
+#[boa(rename_all = "camelCase")]
+impl EventTarget {
+    /// Create a new `EventTarget` without any listener.
+    #[boa(constructor)]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[boa(length = 2)]
+    fn add_event_listener(
+        JsThis(this): JsThis<JsObject>,
+        event_type: JsValue,
+        callback: JsValue,
+        options: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let target = Self::from_this(&this, context)?;
+        let event_type = event_type.to_string(context)?;
+        let options = ListenerOptions::from_value(&options, context)?;
+        let Some(callback) = listener_callback(&callback)? else {
+            return Ok(());
+        };
+
+        if let Some(signal) = &options.signal {
+            if signal.borrow().data().aborted() {
+                return Ok(());
+            }
+        }
+
+        let mut listeners = target.listeners.borrow_mut();
+        if listeners.list.iter().any(|listener| {
+            listener.event_type == event_type
+                && listener.callback == callback
+                && listener.capture == options.capture
+        }) {
+            return Ok(());
+        }
+
+        let id = listeners.next_id;
+        listeners.next_id += 1;
+        listeners.list.push(Listener {
+            id,
+            event_type,
+            callback,
+            capture: options.capture,
+            passive: options.passive,
+            once: options.once,
+        });
+        drop(listeners);
+
+        if let Some(signal) = options.signal {
+            signal
+                .borrow_mut()
+                .data_mut()
+                .add_listener_removal(target, id);
+        }
+        Ok(())
+    }
+
+    #[boa(length = 2)]
+    fn remove_event_listener(
+        JsThis(this): JsThis<JsObject>,
+        event_type: JsValue,
+        callback: JsValue,
+        options: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let target = Self::from_this(&this, context)?;
+        let event_type = event_type.to_string(context)?;
+        let capture = match options.as_object() {
+            Some(options) => options.get(js_string!("capture"), context)?.to_boolean(),
+            None => options.to_boolean(),
+        };
+        let Some(callback) = listener_callback(&callback)? else {
+            return Ok(());
+        };
+
+        target.listeners.borrow_mut().list.retain(|listener| {
+            listener.event_type != event_type
+                || listener.callback != callback
+                || listener.capture != capture
+        });
+        Ok(())
+    }
+
+    #[boa(length = 1)]
+    fn dispatch_event(
+        JsThis(this): JsThis<JsObject>,
+        event: JsValue,
+        context: &mut Context,
+    ) -> JsResult<bool> {
+        let target = Self::from_this(&this, context)?;
+        let event = event
+            .as_object()
+            .ok_or_else(|| js_error!(TypeError: "dispatchEvent argument must be an Event"))?;
+
+        // Events dispatched by scripts are never trusted.
+        if let Some(event) = Event::from_object(event, context) {
+            let mut state = event.state.borrow_mut();
+            if !state.dispatching {
+                state.is_trusted = false;
+            }
+        }
+        target.dispatch(&this, event, context)
+    }
+}
+
+/// Convert the `callback` argument of `addEventListener` and `removeEventListener`,
+/// which can be `null`.
+fn listener_callback(callback: &JsValue) -> JsResult<Option<JsObject>> {
+    if callback.is_null_or_undefined() {
+        return Ok(None);
+    }
+    callback
+        .as_object()
+        .cloned()
+        .map(Some)
+        .ok_or_else(|| js_error!(TypeError: "The listener must be an object"))
+}
@@ -0,0 +1,300 @@
+use crate::event::{Event, EventInit, EventTarget, EventTargetData};
+use crate::test::{run_test_actions, TestAction};
+use boa_engine::class::Class;
+use boa_engine::interop::JsThis;
+use boa_engine::{js_error, js_string, Context, Finalize, JsData, JsObject, JsResult, Trace};
+use boa_interop::boa_macros::boa_class;
+
+const TEST_HARNESS: &str = r#"
+function assert(condition, message) {
+    if (!condition) {
+        if (!message) {
+            message = "Assertion failed";
+        }
+        throw new Error(message);
+    }
+}
+
+function assert_eq(a, b, message) {
+    if (a !== b) {
+        throw new Error(`${message} (${JSON.stringify(a)} !== ${JSON.stringify(b)})`);
+    }
+}
+
+function throws(f) {
+    try {
+        f();
+    } catch (e) {
+        return e;
+    }
+    throw new Error("expected an exception");
+}
+"#;
+
+#[test]
+fn event() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r#"
+                const event = new Event("ping", { cancelable: true, bubbles: true });
+                assert_eq(event.type, "ping");
+                assert_eq(event.bubbles, true);
+                assert_eq(event.cancelable, true);
+                assert_eq(event.composed, false);
+                assert_eq(event.isTrusted, false);
+                assert_eq(event.target, null);
+                assert_eq(event.eventPhase, Event.NONE);
+                assert_eq(Event.AT_TARGET, 2);
+                assert_eq(event.BUBBLING_PHASE, 3);
+                assert_eq(Object.prototype.toString.call(event), "[object Event]");
+
+                event.preventDefault();
+                assert_eq(event.defaultPrevented, true);
+                assert_eq(new Event("ping").defaultPrevented, false);
+
+                assert(throws(() => new Event("ping", 1)) instanceof TypeError);
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn listeners() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r#"
+                const target = new EventTarget();
+                const calls = [];
+                function listener(event) {
+                    assert_eq(this, target);
+                    assert_eq(event.target, target);
+                    assert_eq(event.currentTarget, target);
+                    assert_eq(event.eventPhase, Event.AT_TARGET);
+                    calls.push("listener");
+                }
+
+                target.addEventListener("ping", listener);
+                target.addEventListener("ping", listener);
+                target.addEventListener("ping", () => calls.push("capture"), true);
+                target.addEventListener("ping", () => calls.push("once"), { once: true });
+                target.addEventListener("ping", {
+                    handleEvent(event) {
+                        assert(this !== target);
+                        calls.push("handleEvent");
+                    },
+                });
+                target.addEventListener("pong", () => calls.push("pong"));
+                target.addEventListener("ping", null);
+
+                const event = new Event("ping");
+                assert_eq(target.dispatchEvent(event), true);
+                assert_eq(calls.join(), "capture,listener,once,handleEvent");
+                assert_eq(event.eventPhase, Event.NONE);
+                assert_eq(event.currentTarget, null);
+                assert_eq(event.target, target);
+
+                calls.length = 0;
+                target.removeEventListener("ping", listener);
+                target.dispatchEvent(new Event("ping"));
+                assert_eq(calls.join(), "capture,handleEvent");
+
+                assert(throws(() => target.dispatchEvent({})) instanceof TypeError);
+                assert(throws(() => target.addEventListener("ping", 1)) instanceof TypeError);
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn dispatch() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r#"
+                const target = new EventTarget();
+                const calls = [];
+                target.addEventListener("ping", (event) => {
+                    calls.push(1);
+                    event.preventDefault();
+                    event.stopImmediatePropagation();
+                });
+                target.addEventListener("ping", () => calls.push(2));
+
+                assert_eq(target.dispatchEvent(new Event("ping")), true);
+                assert_eq(target.dispatchEvent(new Event("ping", { cancelable: true })), false);
+                assert_eq(calls.join(), "1,1");
+
+                const passive = new EventTarget();
+                passive.addEventListener("ping", (event) => event.preventDefault(), { passive: true });
+                assert_eq(passive.dispatchEvent(new Event("ping", { cancelable: true })), true);
+
+                const reentrant = new EventTarget();
+                let error;
+                reentrant.addEventListener("ping", (event) => {
+                    error = throws(() => reentrant.dispatchEvent(event));
+                });
+                reentrant.dispatchEvent(new Event("ping"));
+                assert_eq(error.name, "InvalidStateError");
+
+                // Errors thrown by listeners are reported instead of being rethrown.
+                const throwing = new EventTarget();
+                let called = false;
+                const reported = [];
+                const logError = console.error;
+                console.error = (e) => reported.push(e);
+                throwing.addEventListener("ping", () => { throw new RangeError("first"); });
+                throwing.addEventListener("ping", () => { called = true; });
+                assert_eq(throwing.dispatchEvent(new Event("ping")), true);
+                console.error = logError;
+                assert(called);
+                assert_eq(reported.length, 1);
+                assert(reported[0] instanceof RangeError);
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn custom_event() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r#"
+                const event = new CustomEvent("ping", { detail: { value: 1 }, cancelable: true });
+                assert(event instanceof Event);
+                assert_eq(event.type, "ping");
+                assert_eq(event.detail.value, 1);
+                assert_eq(event.cancelable, true);
+                assert_eq(new CustomEvent("ping").detail, null);
+                assert_eq(Object.prototype.toString.call(event), "[object CustomEvent]");
+
+                const target = new EventTarget();
+                let detail;
+                target.addEventListener("ping", (event) => { detail = event.detail; });
+                target.dispatchEvent(event);
+                assert_eq(detail.value, 1);
+                assert_eq(event.target, target);
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn javascript_subclass() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r#"
+                class Emitter extends EventTarget {
+                    emit(type) {
+                        return this.dispatchEvent(new PingEvent(type));
+                    }
+                }
+                class PingEvent extends Event {
+                    constructor(type) {
+                        super(type, { cancelable: true });
+                        this.count = 1;
+                    }
+                }
+
+                const emitter = new Emitter();
+                let received;
+                emitter.addEventListener("ping", (event) => {
+                    received = event;
+                    event.preventDefault();
+                });
+                assert_eq(emitter.emit("ping"), false);
+                assert(received instanceof PingEvent);
+                assert_eq(received.count, 1);
+                assert_eq(received.target, emitter);
+            "#,
+        ),
+    ]);
+}
+
+/// A native class extending `EventTarget`.
+#[derive(Debug, Trace, Finalize, JsData)]
+struct Emitter {
+    target: EventTarget,
+}
+
+impl EventTargetData for Emitter {
+    fn event_target(&self) -> &EventTarget {
+        &self.target
+    }
+}
+
+#[boa_class]
+#[boa(rename_all = "camelCase")]
+impl Emitter {
+    #[boa(constructor)]
+    fn new() -> Self {
+        Self {
+            target: EventTarget::new(),
+        }
+    }
+
+    #[boa(length = 0)]
+    fn emit(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<bool> {
+        let target = this
+            .downcast_ref::<Self>()
+            .map(|emitter| emitter.target.clone())
+            .ok_or_else(|| js_error!(TypeError: "`this` is not an Emitter"))?;
+        let event = Event::from_data(
+            Event::with_type(
+                js_string!("ping"),
+                EventInit {
+                    cancelable: true,
+                    ..EventInit::default()
+                },
+            ),
+            context,
+        )?;
+        target.dispatch(&this, &event, context)
+    }
+}
+
+#[test]
+fn native_subclass() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::inspect_context(|context| {
+            EventTarget::register_subclass::<Emitter>(context).unwrap();
+        }),
+        TestAction::run(
+            r#"
+                var emitter = new Emitter();
+                assert(emitter instanceof EventTarget);
+                assert_eq(Object.getPrototypeOf(Emitter), EventTarget);
+                assert_eq(Object.prototype.toString.call(emitter), "[object Emitter]");
+
+                let received;
+                emitter.addEventListener("ping", (event) => {
+                    received = event;
+                    event.preventDefault();
+                });
+                assert_eq(emitter.emit(), false);
+                assert_eq(received.target, emitter);
+
+                class Child extends Emitter {}
+                const child = new Child();
+                let count = 0;
+                child.addEventListener("ping", () => count++, { once: true });
+                child.emit();
+                child.emit();
+                assert_eq(count, 1);
+            "#,
+        ),
+        TestAction::inspect_context(|context| {
+            let emitter = context
+                .global_object()
+                .get(js_string!("emitter"), context)
+                .unwrap();
+            let emitter = emitter.as_object().unwrap();
+            assert!(EventTarget::from_object(emitter, context).is_some());
+            assert!(Event::from_object(emitter, context).is_none());
+        }),
+    ]);
+}
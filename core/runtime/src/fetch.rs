@@ -0,0 +1,275 @@
+//! Boa's implementation of JavaScript's `fetch` Web API function, and of the `Headers`,
+//! `Request` and `Response` classes it relies on.
+//!
+//! The runtime doesn't perform any network access by itself. Requests are forwarded to a
+//! [`Fetcher`] provided by the host, which can route them to an in-process handler or
+//! to a real HTTP client. This relies on the `fetch` feature.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG `fetch` specification][spec]
+//!
+//! [spec]: https://fetch.spec.whatwg.org/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Fetch_API
+#![cfg(feature = "fetch")]
+#![allow(clippy::needless_pass_by_value)]
+
+#[cfg(test)]
+mod tests;
+
+mod headers;
+mod request;
+mod response;
+
+#[doc(inline)]
+pub use headers::Headers;
+#[doc(inline)]
+pub use request::Request;
+#[doc(inline)]
+pub use response::Response;
+
+use crate::url::UrlSearchParams;
+use boa_engine::job::NativeAsyncJob;
+use boa_engine::object::builtins::{
+    JsArrayBuffer, JsDataView, JsPromise, JsSharedArrayBuffer, JsTypedArray,
+};
+use boa_engine::{
+    js_error, js_string, Context, Finalize, JsArgs, JsData, JsObject, JsResult, JsString, JsValue,
+    NativeFunction, Trace,
+};
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// The [`Future`] returned by [`Fetcher::fetch`].
+pub type FetchFuture<'a> = Pin<Box<dyn Future<Output = JsResult<http::Response<Vec<u8>>>> + 'a>>;
+
+/// A host-provided handler that performs the requests made with `fetch`.
+///
+/// The future returned by [`Fetcher::fetch`] is driven by the job executor of the context
+/// as a [`NativeAsyncJob`], so it can await on any runtime used by the executor. The
+/// `context` must not be borrowed across await points.
+///
+/// The fetcher is stored outside of the garbage collected heap, so it must not hold any
+/// garbage collected value.
+pub trait Fetcher {
+    /// Perform `request` and return its response.
+    ///
+    /// # Errors
+    /// Any error returned is used to reject the promise returned by `fetch`. Network
+    /// errors should be reported as a `TypeError`.
+    fn fetch<'a>(
+        &'a self,
+        request: http::Request<Vec<u8>>,
+        context: &'a RefCell<&mut Context>,
+    ) -> FetchFuture<'a>;
+}
+
+/// The fetcher used by the `fetch` function of a context, kept in the context data.
+#[derive(Trace, Finalize, JsData)]
+#[boa_gc(unsafe_empty_trace)]
+struct FetcherState(Rc<dyn Fetcher>);
+
+/// Register the `Headers`, `Request` and `Response` classes into the realm.
+///
+/// This doesn't register the `fetch` function, see [`register_with_fetcher`].
+///
+/// # Errors
+/// This will error if the context or realm cannot register the classes.
+pub fn register(context: &mut Context) -> JsResult<()> {
+    Headers::register(context)?;
+    context.register_global_class::<Request>()?;
+    context.register_global_class::<Response>()?;
+    Ok(())
+}
+
+/// Register the `fetch` function, along with the `Headers`, `Request` and `Response`
+/// classes, into the realm. Requests are performed by `fetcher`.
+///
+/// # Errors
+/// This will error if the context or realm cannot register the function or the classes.
+pub fn register_with_fetcher(
+    fetcher: impl Fetcher + 'static,
+    context: &mut Context,
+) -> JsResult<()> {
+    register(context)?;
+    register_fetch(Rc::new(fetcher), context)
+}
+
+/// Register the `fetch` function, using `fetcher` to perform the requests.
+pub(crate) fn register_fetch(fetcher: Rc<dyn Fetcher>, context: &mut Context) -> JsResult<()> {
+    context.insert_data(FetcherState(fetcher));
+    context.register_global_callable(js_string!("fetch"), 1, NativeFunction::from_fn_ptr(fetch))
+}
+
+/// `fetch ( input [ , init ] )`
+///
+/// Returns a promise for the `Response` of the request made with the same arguments as
+/// the `Request` constructor. Any error is reported by rejecting the promise.
+///
+/// See [MDN](https://developer.mozilla.org/en-US/docs/Web/API/Window/fetch).
+fn fetch(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let request = Request::new(
+        args.get_or_undefined(0).clone(),
+        args.get_or_undefined(1).clone(),
+        context,
+    )
+    .and_then(|mut request| {
+        let url = request.url().to_owned();
+        Ok((request.take_http_request()?, url))
+    });
+    let (request, url) = match request {
+        Ok(request) => request,
+        Err(e) => return Ok(JsPromise::reject(e, context).into()),
+    };
+
+    let fetcher = context
+        .get_data::<FetcherState>()
+        .map(|state| state.0.clone())
+        .ok_or_else(|| js_error!(TypeError: "fetch: no fetcher is registered"))?;
+
+    let (promise, resolvers) = JsPromise::new_pending(context);
+    context.enqueue_job(
+        NativeAsyncJob::new(move |context| {
+            Box::pin(async move {
+                let response = fetcher.fetch(request, context).await;
+
+                let context = &mut context.borrow_mut();
+                match response.and_then(|response| Response::from_http(response, url, context)) {
+                    Ok(response) => {
+                        resolvers
+                            .resolve
+                            .call(&JsValue::undefined(), &[response.into()], context)
+                    }
+                    Err(e) => {
+                        let e = e.to_opaque(context);
+                        resolvers.reject.call(&JsValue::undefined(), &[e], context)
+                    }
+                }
+            })
+        })
+        .into(),
+    );
+
+    Ok(promise.into())
+}
+
+/// The body of a `Request` or `Response`, which can only be read once.
+#[derive(Debug, Clone, Default)]
+struct Body {
+    bytes: Option<Vec<u8>>,
+    used: bool,
+}
+
+impl Body {
+    /// Create a body containing `bytes`, if any.
+    fn new(bytes: Option<Vec<u8>>) -> Self {
+        Self { bytes, used: false }
+    }
+
+    /// Whether the body was read.
+    fn used(&self) -> bool {
+        self.used
+    }
+
+    /// Take the bytes of the body, marking it as used. A `null` body stays unused.
+    fn take(&mut self) -> JsResult<Option<Vec<u8>>> {
+        if self.used {
+            return Err(js_error!(TypeError: "Body has already been consumed"));
+        }
+        self.used = self.bytes.is_some();
+        Ok(self.bytes.take())
+    }
+
+    /// Copy the body for a cloned `Request` or `Response`.
+    fn try_clone(&self) -> JsResult<Self> {
+        if self.used {
+            return Err(js_error!(TypeError: "Cannot clone a consumed body"));
+        }
+        Ok(self.clone())
+    }
+
+    /// Read the whole body as `kind`, returning a promise for the result.
+    fn consume(&mut self, kind: BodyKind, context: &mut Context) -> JsPromise {
+        let result = self.take().and_then(|bytes| {
+            let bytes = bytes.unwrap_or_default();
+            match kind {
+                BodyKind::Text => Ok(utf8_decode(&bytes).into()),
+                BodyKind::Json => json_parse(utf8_decode(&bytes), context),
+                BodyKind::ArrayBuffer => Ok(JsArrayBuffer::from_byte_block(bytes, context)?.into()),
+            }
+        });
+
+        match result {
+            Ok(value) => JsPromise::resolve(value, context),
+            Err(e) => JsPromise::reject(e, context),
+        }
+    }
+}
+
+/// The kind of value a body is read as.
+#[derive(Debug, Clone, Copy)]
+enum BodyKind {
+    Text,
+    Json,
+    ArrayBuffer,
+}
+
+/// Decode `bytes` as UTF-8, skipping a leading BOM and replacing invalid sequences.
+fn utf8_decode(bytes: &[u8]) -> JsString {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    JsString::from(String::from_utf8_lossy(bytes).as_ref())
+}
+
+/// Parse `text` with the `%JSON.parse%` intrinsic, which keeps the order of the keys.
+///
+/// See [parse JSON from bytes](https://infra.spec.whatwg.org/#parse-json-bytes-to-a-javascript-value).
+fn json_parse(text: JsString, context: &mut Context) -> JsResult<JsValue> {
+    let parse = context.intrinsics().objects().json_parse();
+    parse.call(&JsValue::undefined(), &[text.into()], context)
+}
+
+/// Serialize `value` with the `%JSON.stringify%` intrinsic, returning `None` if the value
+/// cannot be serialized.
+///
+/// See [serialize a JavaScript value to JSON bytes](https://infra.spec.whatwg.org/#serialize-a-javascript-value-to-json-bytes).
+fn json_stringify(value: JsValue, context: &mut Context) -> JsResult<Option<Vec<u8>>> {
+    let stringify = context.intrinsics().objects().json_stringify();
+    let result = stringify.call(&JsValue::undefined(), &[value], context)?;
+    Ok(result
+        .as_string()
+        .map(|result| result.to_std_string_escaped().into_bytes()))
+}
+
+/// Extract the bytes of a `BodyInit` value, along with the `Content-Type` it implies.
+///
+/// See [extract a body](https://fetch.spec.whatwg.org/#concept-bodyinit-extract).
+fn extract_body(
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<(Vec<u8>, Option<&'static str>)> {
+    if let Some(object) = value.as_object() {
+        if let Some(search_params) = object.downcast_ref::<UrlSearchParams>() {
+            return Ok((
+                search_params.serialize().into_bytes(),
+                Some("application/x-www-form-urlencoded;charset=UTF-8"),
+            ));
+        }
+        if is_buffer_source(object) {
+            return Ok((crate::text::buffer_source_bytes(value, context)?, None));
+        }
+    }
+
+    let text = value.to_string(context)?.to_std_string_lossy();
+    Ok((text.into_bytes(), Some("text/plain;charset=UTF-8")))
+}
+
+/// Whether `object` is an `ArrayBuffer`, a `SharedArrayBuffer`, a typed array or a
+/// `DataView`.
+fn is_buffer_source(object: &JsObject) -> bool {
+    JsArrayBuffer::from_object(object.clone()).is_ok()
+        || JsSharedArrayBuffer::from_object(object.clone()).is_ok()
+        || JsTypedArray::from_object(object.clone()).is_ok()
+        || JsDataView::from_object(object.clone()).is_ok()
+}
@@ -0,0 +1,441 @@
+//! Boa's implementation of JavaScript's `Headers` Web API class.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG `Headers` specification][spec]
+//!
+//! [spec]: https://fetch.spec.whatwg.org/#headers-class
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Headers
+
+use crate::iterable_to_vec;
+use boa_engine::builtins::iterable::create_iter_result_object;
+use boa_engine::interop::JsThis;
+use boa_engine::object::builtins::JsArray;
+use boa_engine::object::ObjectInitializer;
+use boa_engine::property::{Attribute, PropertyDescriptor};
+use boa_engine::{
+    js_error, js_string, Context, Finalize, JsData, JsObject, JsResult, JsString, JsSymbol,
+    JsValue, NativeFunction, Trace,
+};
+use boa_interop::boa_macros::boa_class;
+use http::header::{HeaderMap, HeaderName, HeaderValue, SET_COOKIE};
+
+/// The `Headers` class represents the header list of a `Request` or a `Response`.
+#[derive(Debug, Clone, Default, JsData, Trace, Finalize)]
+#[boa_gc(unsafe_empty_trace)]
+pub struct Headers {
+    map: HeaderMap,
+}
+
+impl Headers {
+    /// Register the `Headers` class into the realm.
+    ///
+    /// # Errors
+    /// This will error if the context or realm cannot register the class.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        context.register_global_class::<Self>()?;
+
+        // `boa_class` cannot define symbol-keyed properties, so add them to the
+        // prototype manually. `@@iterator` is the same function object as `entries`.
+        let prototype = Self::prototype(context)?;
+        let entries = prototype.get(js_string!("entries"), context)?;
+        prototype.define_property_or_throw(
+            JsSymbol::iterator(),
+            PropertyDescriptor::builder()
+                .value(entries)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+        prototype.define_property_or_throw(
+            JsSymbol::to_string_tag(),
+            PropertyDescriptor::builder()
+                .value(js_string!("Headers"))
+                .writable(false)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+
+        let iterator_prototype = HeadersIterator::create_prototype(context);
+        context.insert_data(HeadersIteratorPrototype(iterator_prototype));
+
+        Ok(())
+    }
+
+    /// Get the header map of this object.
+    #[must_use]
+    pub fn header_map(&self) -> &HeaderMap {
+        &self.map
+    }
+
+    /// Get the prototype of the registered `Headers` class.
+    fn prototype(context: &Context) -> JsResult<JsObject> {
+        context
+            .get_global_class::<Self>()
+            .map(|class| class.prototype())
+            .ok_or_else(|| js_error!(TypeError: "The Headers class is not registered"))
+    }
+
+    /// Create a `Headers` object containing `map`.
+    pub(crate) fn create(map: HeaderMap, context: &mut Context) -> JsResult<JsObject<Self>> {
+        Ok(JsObject::new_unique(
+            Self::prototype(context)?,
+            Self { map },
+        ))
+    }
+
+    /// Create the header map of a `HeadersInit` value, which can be an iterable of
+    /// name-value pairs, or a record of names to values.
+    ///
+    /// See [fill](https://fetch.spec.whatwg.org/#concept-headers-fill).
+    pub(crate) fn fill(init: &JsValue, context: &mut Context) -> JsResult<HeaderMap> {
+        let mut map = HeaderMap::new();
+        if init.is_undefined() {
+            return Ok(map);
+        }
+        let object = init
+            .as_object()
+            .ok_or_else(|| js_error!(TypeError: "Headers init must be an object"))?;
+
+        let method = object.get(JsSymbol::iterator(), context)?;
+        if method.is_null_or_undefined() {
+            let entries = boa_engine::builtins::object::OrdinaryObject::entries(
+                &JsValue::undefined(),
+                &[object.clone().into()],
+                context,
+            )?;
+            let entries: Vec<Vec<JsValue>> = entries.try_js_into(context)?;
+            for entry in entries {
+                let [name, value] = entry.as_slice() else {
+                    return Err(js_error!(TypeError: "invalid Headers record entry"));
+                };
+                append(&mut map, name, value, context)?;
+            }
+            return Ok(map);
+        }
+
+        let method = method
+            .as_callable()
+            .ok_or_else(|| js_error!(TypeError: "@@iterator is not callable"))?;
+        iterable_to_vec(
+            init,
+            method,
+            |pair, context| {
+                let pair_method = match pair.as_object() {
+                    Some(object) => object.get(JsSymbol::iterator(), context)?,
+                    None => JsValue::undefined(),
+                };
+                let pair_method = pair_method
+                    .as_callable()
+                    .ok_or_else(|| js_error!(TypeError: "Headers init pair is not iterable"))?;
+
+                let pair = iterable_to_vec(&pair, pair_method, |value, _| Ok(value), context)?;
+                let [name, value] = <[JsValue; 2]>::try_from(pair).map_err(
+                    |_| js_error!(TypeError: "Headers init pair must have exactly two items"),
+                )?;
+                append(&mut map, &name, &value, context)
+            },
+            context,
+        )?;
+        Ok(map)
+    }
+
+    /// Get the names and values of the list, sorted by name, with the values of a same
+    /// name combined. `Set-Cookie` values are never combined.
+    ///
+    /// See [sort and combine](https://fetch.spec.whatwg.org/#concept-header-list-sort-and-combine).
+    fn sort_and_combine(&self) -> Vec<(JsString, JsString)> {
+        let mut names = self.map.keys().collect::<Vec<_>>();
+        names.sort_unstable_by_key(|name| name.as_str());
+
+        let mut list = Vec::new();
+        for name in names {
+            let js_name = JsString::from(name.as_str());
+            if name == SET_COOKIE {
+                for value in self.map.get_all(name) {
+                    list.push((js_name.clone(), byte_string(value.as_bytes())));
+                }
+            } else {
+                list.push((js_name, self.combined(name)));
+            }
+        }
+        list
+    }
+
+    /// Get the values of `name` joined by `", "`.
+    fn combined(&self, name: &HeaderName) -> JsString {
+        let bytes = self
+            .map
+            .get_all(name)
+            .iter()
+            .map(HeaderValue::as_bytes)
+            .collect::<Vec<_>>()
+            .join(&b", "[..]);
+        byte_string(&bytes)
+    }
+
+    /// Create an iterator of `kind` over the list of `this`.
+    fn iterator(this: JsObject, kind: IterationKind, context: &mut Context) -> JsResult<JsObject> {
+        let headers = this
+            .downcast::<Self>()
+            .map_err(|_| js_error!(TypeError: "Invalid class for type"))?;
+        let prototype = context
+            .get_data::<HeadersIteratorPrototype>()
+            .map(|prototype| prototype.0.clone());
+
+        Ok(JsObject::new_unique(
+            prototype,
+            HeadersIterator {
+                headers,
+                kind,
+                index: 0,
+            },
+        )
+        .upcast())
+    }
+}
+
+#[boa_class]
+#[boa(rename_all = "camelCase")]
+impl Headers {
+    /// Create a new `Headers` object. Meant to be called from the JavaScript constructor.
+    ///
+    /// # Errors
+    /// Any errors that might occur while converting `init`, or if a name or a value is
+    /// invalid.
+    #[boa(constructor)]
+    #[boa(length = 0)]
+    pub fn new(init: JsValue, context: &mut Context) -> JsResult<Self> {
+        Ok(Self {
+            map: Self::fill(&init, context)?,
+        })
+    }
+
+    fn append(&mut self, name: JsValue, value: JsValue, context: &mut Context) -> JsResult<()> {
+        append(&mut self.map, &name, &value, context)
+    }
+
+    fn delete(&mut self, name: JsValue, context: &mut Context) -> JsResult<()> {
+        let name = header_name(&name, context)?;
+        self.map.remove(name);
+        Ok(())
+    }
+
+    fn get(&self, name: JsValue, context: &mut Context) -> JsResult<JsValue> {
+        let name = header_name(&name, context)?;
+        if !self.map.contains_key(&name) {
+            return Ok(JsValue::null());
+        }
+        Ok(self.combined(&name).into())
+    }
+
+    fn get_set_cookie(&self) -> Vec<JsString> {
+        self.map
+            .get_all(SET_COOKIE)
+            .iter()
+            .map(|value| byte_string(value.as_bytes()))
+            .collect()
+    }
+
+    fn has(&self, name: JsValue, context: &mut Context) -> JsResult<bool> {
+        let name = header_name(&name, context)?;
+        Ok(self.map.contains_key(name))
+    }
+
+    fn set(&mut self, name: JsValue, value: JsValue, context: &mut Context) -> JsResult<()> {
+        let name = header_name(&name, context)?;
+        let value = header_value(&value, context)?;
+        self.map.insert(name, value);
+        Ok(())
+    }
+
+    #[boa(length = 0)]
+    fn entries(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<JsObject> {
+        Self::iterator(this, IterationKind::KeyAndValue, context)
+    }
+
+    #[boa(length = 0)]
+    fn keys(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<JsObject> {
+        Self::iterator(this, IterationKind::Key, context)
+    }
+
+    #[boa(length = 0)]
+    fn values(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<JsObject> {
+        Self::iterator(this, IterationKind::Value, context)
+    }
+
+    #[boa(length = 1)]
+    fn for_each(
+        JsThis(this): JsThis<JsObject>,
+        callback: JsValue,
+        this_arg: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let headers = this
+            .clone()
+            .downcast::<Self>()
+            .map_err(|_| js_error!(TypeError: "Invalid class for type"))?;
+        let callback = callback
+            .as_callable()
+            .ok_or_else(|| js_error!(TypeError: "Headers.forEach: callback is not callable"))?;
+
+        // The callback may modify the list, so look it up again on every step.
+        let mut index = 0;
+        loop {
+            let Some((name, value)) = headers
+                .borrow()
+                .data()
+                .sort_and_combine()
+                .get(index)
+                .cloned()
+            else {
+                return Ok(());
+            };
+            callback.call(
+                &this_arg,
+                &[value.into(), name.into(), this.clone().into()],
+                context,
+            )?;
+            index += 1;
+        }
+    }
+}
+
+/// The kind of values produced by a `Headers` iterator.
+#[derive(Debug, Clone, Copy)]
+enum IterationKind {
+    Key,
+    Value,
+    KeyAndValue,
+}
+
+/// The `%HeadersIteratorPrototype%` object, kept in the context data.
+#[derive(Debug, Trace, Finalize, JsData)]
+struct HeadersIteratorPrototype(JsObject);
+
+/// An iterator over the sorted and combined list of a `Headers` object.
+#[derive(Debug, Trace, Finalize, JsData)]
+struct HeadersIterator {
+    headers: JsObject<Headers>,
+    #[unsafe_ignore_trace]
+    kind: IterationKind,
+    index: usize,
+}
+
+impl HeadersIterator {
+    /// Create the prototype shared by all `Headers` iterators.
+    fn create_prototype(context: &mut Context) -> JsObject {
+        let iterator_prototype = context
+            .intrinsics()
+            .objects()
+            .iterator_prototypes()
+            .iterator();
+
+        let prototype = ObjectInitializer::new(context)
+            .function(
+                NativeFunction::from_fn_ptr(Self::next),
+                js_string!("next"),
+                0,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Headers Iterator"),
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+        prototype.set_prototype(Some(iterator_prototype));
+        prototype
+    }
+
+    /// `%HeadersIteratorPrototype%.next( )`
+    fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let object = this.as_object();
+        let mut iterator = object
+            .as_ref()
+            .and_then(|o| o.downcast_mut::<Self>())
+            .ok_or_else(|| js_error!(TypeError: "`this` is not a Headers iterator"))?;
+
+        let Some((name, value)) = iterator
+            .headers
+            .borrow()
+            .data()
+            .sort_and_combine()
+            .get(iterator.index)
+            .cloned()
+        else {
+            return Ok(create_iter_result_object(
+                JsValue::undefined(),
+                true,
+                context,
+            ));
+        };
+        iterator.index += 1;
+        let kind = iterator.kind;
+        drop(iterator);
+
+        let result = match kind {
+            IterationKind::Key => name.into(),
+            IterationKind::Value => value.into(),
+            IterationKind::KeyAndValue => {
+                JsArray::from_iter([name.into(), value.into()], context).into()
+            }
+        };
+        Ok(create_iter_result_object(result, false, context))
+    }
+}
+
+/// Append the header `name` with `value` to `map`, after validating them.
+fn append(
+    map: &mut HeaderMap,
+    name: &JsValue,
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<()> {
+    let name = header_name(name, context)?;
+    let value = header_value(value, context)?;
+    map.append(name, value);
+    Ok(())
+}
+
+/// Convert `value` to a header name.
+fn header_name(value: &JsValue, context: &mut Context) -> JsResult<HeaderName> {
+    let bytes = to_byte_string(value, context)?;
+    HeaderName::from_bytes(&bytes).map_err(
+        |_| js_error!(TypeError: "Invalid header name: {}", String::from_utf8_lossy(&bytes)),
+    )
+}
+
+/// Convert `value` to a header value, removing its leading and trailing whitespace.
+fn header_value(value: &JsValue, context: &mut Context) -> JsResult<HeaderValue> {
+    let bytes = to_byte_string(value, context)?;
+    let bytes = bytes.trim_ascii();
+    HeaderValue::from_bytes(bytes).map_err(
+        |_| js_error!(TypeError: "Invalid header value: {}", String::from_utf8_lossy(bytes)),
+    )
+}
+
+/// Convert `value` to a string of bytes like the `ByteString` `WebIDL` type, which rejects
+/// code units above `0xFF`.
+fn to_byte_string(value: &JsValue, context: &mut Context) -> JsResult<Vec<u8>> {
+    value
+        .to_string(context)?
+        .iter()
+        .map(|unit| {
+            u8::try_from(unit).map_err(|_| {
+                js_error!(TypeError: "Cannot convert a string containing characters above U+00FF to a ByteString")
+            })
+        })
+        .collect()
+}
+
+/// Create a string from a string of bytes, mapping each byte to a code unit.
+pub(super) fn byte_string(bytes: &[u8]) -> JsString {
+    JsString::from(
+        &bytes
+            .iter()
+            .map(|&byte| u16::from(byte))
+            .collect::<Vec<_>>()[..],
+    )
+}
@@ -0,0 +1,199 @@
+//! Boa's implementation of JavaScript's `Request` Web API class.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG `Request` specification][spec]
+//!
+//! [spec]: https://fetch.spec.whatwg.org/#request-class
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Request
+
+use super::{extract_body, Body, BodyKind, Headers};
+use boa_engine::class::Class;
+use boa_engine::object::builtins::JsPromise;
+use boa_engine::{
+    js_error, js_string, Context, Finalize, JsData, JsObject, JsResult, JsString, JsValue, Trace,
+};
+use boa_interop::boa_macros::boa_class;
+use http::header::CONTENT_TYPE;
+use http::{HeaderValue, Method};
+
+/// The `Request` class represents a request made with `fetch`.
+#[derive(Debug, JsData, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
+pub struct Request {
+    #[unsafe_ignore_trace]
+    method: Method,
+    #[unsafe_ignore_trace]
+    url: url::Url,
+    headers: JsObject<Headers>,
+    #[unsafe_ignore_trace]
+    body: Body,
+}
+
+impl Request {
+    /// Get the URL of the request.
+    pub(crate) fn url(&self) -> &str {
+        self.url.as_str()
+    }
+
+    /// Take the body of the request to create the request passed to the `Fetcher`.
+    pub(crate) fn take_http_request(&mut self) -> JsResult<http::Request<Vec<u8>>> {
+        let body = self.body.take()?.unwrap_or_default();
+        let mut request = http::Request::builder()
+            .method(self.method.clone())
+            .uri(self.url.as_str())
+            .body(body)
+            .map_err(|e| js_error!(TypeError: "Failed to create request: {}", e))?;
+        *request.headers_mut() = self.headers.borrow().data().header_map().clone();
+        Ok(request)
+    }
+}
+
+#[boa_class]
+#[boa(rename_all = "camelCase")]
+impl Request {
+    /// Create a new `Request` object. Meant to be called from the JavaScript constructor.
+    ///
+    /// `input` is either a URL or another `Request`, whose body is then moved to the new
+    /// request unless `init` has a body.
+    ///
+    /// # Errors
+    /// Any errors that might occur while converting the arguments, or if they describe an
+    /// invalid request.
+    #[boa(constructor)]
+    #[boa(length = 1)]
+    pub fn new(input: JsValue, init: JsValue, context: &mut Context) -> JsResult<Self> {
+        // Copy what is needed from an input `Request` first, as converting `init` may
+        // run arbitrary code.
+        let input_request = input
+            .as_object()
+            .and_then(|object| object.clone().downcast::<Self>().ok());
+        let (mut method, url, mut headers) = if let Some(input) = &input_request {
+            let input = input.borrow();
+            let input = input.data();
+            let headers = input.headers.borrow().data().header_map().clone();
+            if input.body.used() {
+                return Err(js_error!(
+                    TypeError: "Cannot construct a Request from a Request whose body was used"
+                ));
+            }
+            (input.method.clone(), input.url.clone(), headers)
+        } else {
+            let url = input.to_string(context)?.to_std_string_lossy();
+            let url = url::Url::parse(&url)
+                .map_err(|e| js_error!(TypeError: "Failed to parse URL: {}", e))?;
+            if !url.username().is_empty() || url.password().is_some() {
+                return Err(js_error!(TypeError: "Request URL cannot include credentials"));
+            }
+            (Method::GET, url, http::HeaderMap::new())
+        };
+
+        let (init_body, init_headers, init_method) = match init.as_object() {
+            Some(init) => (
+                init.get(js_string!("body"), context)?,
+                init.get(js_string!("headers"), context)?,
+                init.get(js_string!("method"), context)?,
+            ),
+            None if init.is_null_or_undefined() => Default::default(),
+            None => return Err(js_error!(TypeError: "Request init must be an object")),
+        };
+
+        if !init_method.is_undefined() {
+            method = normalize_method(&init_method, context)?;
+        }
+        if !init_headers.is_undefined() {
+            headers = Headers::fill(&init_headers, context)?;
+        }
+
+        let body = if init_body.is_null_or_undefined() {
+            match &input_request {
+                Some(input) => input.borrow_mut().data_mut().body.take()?,
+                None => None,
+            }
+        } else {
+            let (body, content_type) = extract_body(&init_body, context)?;
+            if let Some(content_type) = content_type {
+                if !headers.contains_key(CONTENT_TYPE) {
+                    headers.insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+                }
+            }
+            Some(body)
+        };
+        if body.is_some() && (method == Method::GET || method == Method::HEAD) {
+            return Err(js_error!(
+                TypeError: "Request with GET/HEAD method cannot have a body"
+            ));
+        }
+
+        Ok(Self {
+            method,
+            url,
+            headers: Headers::create(headers, context)?,
+            body: Body::new(body),
+        })
+    }
+
+    #[boa(getter)]
+    fn method(&self) -> JsString {
+        JsString::from(self.method.as_str())
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "url")]
+    fn get_url(&self) -> JsString {
+        JsString::from(self.url.as_str())
+    }
+
+    #[boa(getter)]
+    fn headers(&self) -> JsObject {
+        self.headers.clone().upcast()
+    }
+
+    #[boa(getter)]
+    fn body_used(&self) -> bool {
+        self.body.used()
+    }
+
+    fn text(&mut self, context: &mut Context) -> JsPromise {
+        self.body.consume(BodyKind::Text, context)
+    }
+
+    fn json(&mut self, context: &mut Context) -> JsPromise {
+        self.body.consume(BodyKind::Json, context)
+    }
+
+    fn array_buffer(&mut self, context: &mut Context) -> JsPromise {
+        self.body.consume(BodyKind::ArrayBuffer, context)
+    }
+
+    #[boa(rename = "clone")]
+    fn clone_request(&self, context: &mut Context) -> JsResult<JsObject> {
+        let request = Self {
+            method: self.method.clone(),
+            url: self.url.clone(),
+            headers: Headers::create(self.headers.borrow().data().header_map().clone(), context)?,
+            body: self.body.try_clone()?,
+        };
+        Self::from_data(request, context)
+    }
+}
+
+/// Convert `value` to a request method, normalizing the case of the standard methods.
+///
+/// See [normalize a method](https://fetch.spec.whatwg.org/#concept-method-normalize).
+fn normalize_method(value: &JsValue, context: &mut Context) -> JsResult<Method> {
+    let method = value.to_string(context)?.to_std_string_lossy();
+    if ["CONNECT", "TRACE", "TRACK"]
+        .iter()
+        .any(|forbidden| forbidden.eq_ignore_ascii_case(&method))
+    {
+        return Err(js_error!(TypeError: "Forbidden request method: {}", method));
+    }
+
+    let normalized = ["DELETE", "GET", "HEAD", "OPTIONS", "POST", "PUT"]
+        .into_iter()
+        .find(|normalized| normalized.eq_ignore_ascii_case(&method))
+        .unwrap_or(&method);
+    Method::from_bytes(normalized.as_bytes())
+        .map_err(|_| js_error!(TypeError: "Invalid request method: {}", method))
+}
@@ -0,0 +1,291 @@
+//! Boa's implementation of JavaScript's `Response` Web API class.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG `Response` specification][spec]
+//!
+//! [spec]: https://fetch.spec.whatwg.org/#response-class
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Response
+
+use super::{extract_body, json_stringify, Body, BodyKind, Headers};
+use boa_engine::class::Class;
+use boa_engine::object::builtins::JsPromise;
+use boa_engine::value::Convert;
+use boa_engine::{
+    js_error, js_string, Context, Finalize, JsData, JsObject, JsResult, JsString, JsValue, Trace,
+};
+use boa_interop::boa_macros::boa_class;
+use http::header::{CONTENT_TYPE, LOCATION};
+use http::{HeaderMap, HeaderValue};
+
+/// The type of a `Response`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResponseType {
+    /// A response returned by `fetch`.
+    Basic,
+    /// A response created with the constructor.
+    Default,
+    /// A network error, created with `Response.error()`.
+    Error,
+}
+
+/// The `Response` class represents the response to a request made with `fetch`.
+#[derive(Debug, JsData, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
+pub struct Response {
+    #[unsafe_ignore_trace]
+    kind: ResponseType,
+    #[unsafe_ignore_trace]
+    url: String,
+    #[unsafe_ignore_trace]
+    status: u16,
+    #[unsafe_ignore_trace]
+    status_text: String,
+    headers: JsObject<Headers>,
+    #[unsafe_ignore_trace]
+    body: Body,
+}
+
+impl Response {
+    /// Create the `Response` object of the response returned by a `Fetcher` for the
+    /// request to `url`.
+    pub(crate) fn from_http(
+        response: http::Response<Vec<u8>>,
+        url: String,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        let (parts, body) = response.into_parts();
+        let response = Self {
+            kind: ResponseType::Basic,
+            url,
+            status: parts.status.as_u16(),
+            status_text: parts
+                .status
+                .canonical_reason()
+                .unwrap_or_default()
+                .to_owned(),
+            headers: Headers::create(parts.headers, context)?,
+            body: Body::new((!is_null_body_status(parts.status.as_u16())).then_some(body)),
+        };
+        Self::from_data(response, context)
+    }
+
+    /// Create a response from `init` with the extracted `body`, if any.
+    ///
+    /// See [initialize a response](https://fetch.spec.whatwg.org/#initialize-a-response).
+    fn initialize(
+        init: &JsValue,
+        body: Option<(Vec<u8>, Option<&'static str>)>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let (init_headers, init_status, init_status_text) = match init.as_object() {
+            Some(init) => (
+                init.get(js_string!("headers"), context)?,
+                init.get(js_string!("status"), context)?,
+                init.get(js_string!("statusText"), context)?,
+            ),
+            None if init.is_null_or_undefined() => Default::default(),
+            None => return Err(js_error!(TypeError: "Response init must be an object")),
+        };
+
+        let status = if init_status.is_undefined() {
+            200
+        } else {
+            init_status.to_uint16(context)?
+        };
+        if !(200..=599).contains(&status) {
+            return Err(js_error!(RangeError: "Response status must be between 200 and 599"));
+        }
+
+        let status_text = if init_status_text.is_undefined() {
+            String::new()
+        } else {
+            let status_text = init_status_text.to_string(context)?.to_std_string_lossy();
+            if status_text.contains(['\r', '\n']) {
+                return Err(js_error!(TypeError: "Invalid response status text"));
+            }
+            status_text
+        };
+
+        let mut headers = Headers::fill(&init_headers, context)?;
+
+        let body = match body {
+            Some(_) if is_null_body_status(status) => {
+                return Err(js_error!(
+                    TypeError: "Response with status {} cannot have a body", status
+                ));
+            }
+            Some((body, content_type)) => {
+                if let Some(content_type) = content_type {
+                    if !headers.contains_key(CONTENT_TYPE) {
+                        headers.insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+                    }
+                }
+                Some(body)
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            kind: ResponseType::Default,
+            url: String::new(),
+            status,
+            status_text,
+            headers: Headers::create(headers, context)?,
+            body: Body::new(body),
+        })
+    }
+}
+
+#[boa_class]
+#[boa(rename_all = "camelCase")]
+impl Response {
+    /// Create a new `Response` object. Meant to be called from the JavaScript constructor.
+    ///
+    /// # Errors
+    /// Any errors that might occur while converting the arguments, or if they describe an
+    /// invalid response.
+    #[boa(constructor)]
+    #[boa(length = 0)]
+    pub fn new(body: JsValue, init: JsValue, context: &mut Context) -> JsResult<Self> {
+        let body = if body.is_null_or_undefined() {
+            None
+        } else {
+            Some(extract_body(&body, context)?)
+        };
+        Self::initialize(&init, body, context)
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "type")]
+    fn get_type(&self) -> JsString {
+        match self.kind {
+            ResponseType::Basic => js_string!("basic"),
+            ResponseType::Default => js_string!("default"),
+            ResponseType::Error => js_string!("error"),
+        }
+    }
+
+    #[boa(getter)]
+    fn url(&self) -> JsString {
+        JsString::from(self.url.as_str())
+    }
+
+    #[boa(getter)]
+    fn redirected() -> bool {
+        false
+    }
+
+    #[boa(getter)]
+    fn status(&self) -> u16 {
+        self.status
+    }
+
+    #[boa(getter)]
+    fn ok(&self) -> bool {
+        (200..=299).contains(&self.status)
+    }
+
+    #[boa(getter)]
+    fn status_text(&self) -> JsString {
+        JsString::from(self.status_text.as_str())
+    }
+
+    #[boa(getter)]
+    fn headers(&self) -> JsObject {
+        self.headers.clone().upcast()
+    }
+
+    #[boa(getter)]
+    fn body_used(&self) -> bool {
+        self.body.used()
+    }
+
+    fn text(&mut self, context: &mut Context) -> JsPromise {
+        self.body.consume(BodyKind::Text, context)
+    }
+
+    fn json(&mut self, context: &mut Context) -> JsPromise {
+        self.body.consume(BodyKind::Json, context)
+    }
+
+    fn array_buffer(&mut self, context: &mut Context) -> JsPromise {
+        self.body.consume(BodyKind::ArrayBuffer, context)
+    }
+
+    #[boa(rename = "clone")]
+    fn clone_response(&self, context: &mut Context) -> JsResult<JsObject> {
+        let response = Self {
+            kind: self.kind,
+            url: self.url.clone(),
+            status: self.status,
+            status_text: self.status_text.clone(),
+            headers: Headers::create(self.headers.borrow().data().header_map().clone(), context)?,
+            body: self.body.try_clone()?,
+        };
+        Self::from_data(response, context)
+    }
+
+    #[boa(static)]
+    fn error(context: &mut Context) -> JsResult<JsObject> {
+        let response = Self {
+            kind: ResponseType::Error,
+            url: String::new(),
+            status: 0,
+            status_text: String::new(),
+            headers: Headers::create(HeaderMap::new(), context)?,
+            body: Body::default(),
+        };
+        Self::from_data(response, context)
+    }
+
+    #[boa(static)]
+    #[boa(rename = "json")]
+    #[boa(length = 1)]
+    fn from_json(data: JsValue, init: JsValue, context: &mut Context) -> JsResult<JsObject> {
+        let body = json_stringify(data, context)?
+            .ok_or_else(|| js_error!(TypeError: "Response.json: data is not JSON serializable"))?;
+        let response = Self::initialize(&init, Some((body, Some("application/json"))), context)?;
+        Self::from_data(response, context)
+    }
+
+    #[boa(static)]
+    #[boa(length = 1)]
+    fn redirect(
+        Convert(ref url): Convert<String>,
+        status: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        let url =
+            url::Url::parse(url).map_err(|e| js_error!(TypeError: "Failed to parse URL: {}", e))?;
+        let status = match status {
+            Some(status) if !status.is_undefined() => status.to_uint16(context)?,
+            _ => 302,
+        };
+        if !matches!(status, 301 | 302 | 303 | 307 | 308) {
+            return Err(js_error!(RangeError: "Invalid redirect status: {}", status));
+        }
+
+        let location = HeaderValue::from_str(url.as_str())
+            .map_err(|e| js_error!(TypeError: "Invalid redirect URL: {}", e))?;
+        let mut headers = HeaderMap::new();
+        headers.insert(LOCATION, location);
+
+        let response = Self {
+            kind: ResponseType::Default,
+            url: String::new(),
+            status,
+            status_text: String::new(),
+            headers: Headers::create(headers, context)?,
+            body: Body::default(),
+        };
+        Self::from_data(response, context)
+    }
+}
+
+/// Whether a response with `status` cannot have a body.
+///
+/// See [null body status](https://fetch.spec.whatwg.org/#null-body-status).
+fn is_null_body_status(status: u16) -> bool {
+    matches!(status, 101 | 103 | 204 | 205 | 304)
+}
@@ -0,0 +1,319 @@
+use super::{FetchFuture, Fetcher};
+use crate::test::{run_test_actions_with, TestAction};
+use crate::{register, RegisterOptions};
+use boa_engine::Context;
+use std::cell::RefCell;
+
+const TEST_HARNESS: &str = r#"
+function assert(condition, message) {
+    if (!condition) {
+        if (!message) {
+            message = "Assertion failed";
+        }
+        throw new Error(message);
+    }
+}
+
+function assert_eq(a, b, message) {
+    if (a !== b) {
+        throw new Error(`${message} (${JSON.stringify(a)} !== ${JSON.stringify(b)})`);
+    }
+}
+"#;
+
+/// A fetcher answering every request in-process, echoing the method, URL, headers and
+/// body of the request.
+#[derive(Debug)]
+struct EchoFetcher;
+
+impl Fetcher for EchoFetcher {
+    fn fetch<'a>(
+        &'a self,
+        request: http::Request<Vec<u8>>,
+        _context: &'a RefCell<&mut Context>,
+    ) -> FetchFuture<'a> {
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            if parts.uri.host() == Some("unreachable.test") {
+                return Err(boa_engine::js_error!(TypeError: "network error"));
+            }
+
+            let mut response = http::Response::builder()
+                .status(if parts.uri.path() == "/missing" {
+                    404
+                } else {
+                    200
+                })
+                .header("x-method", parts.method.as_str())
+                .header("x-url", parts.uri.to_string());
+            for (name, value) in &parts.headers {
+                response = response.header(format!("x-echo-{name}"), value);
+            }
+            response
+                .body(body)
+                .map_err(|e| boa_engine::js_error!(TypeError: "{}", e))
+        })
+    }
+}
+
+fn context_with_fetcher() -> Context {
+    let mut context = Context::default();
+    register(
+        &mut context,
+        RegisterOptions::new().with_fetcher(EchoFetcher),
+    )
+    .expect("failed to register WebAPI objects");
+    context
+}
+
+fn run_jobs() -> TestAction {
+    TestAction::inspect_context(|context| {
+        context.run_jobs().expect("failed to run jobs");
+    })
+}
+
+#[test]
+fn headers() {
+    run_test_actions_with(
+        [
+            TestAction::run(TEST_HARNESS),
+            TestAction::run(
+                r#"
+                    const headers = new Headers({ "Content-Type": "text/plain" });
+                    headers.append("Accept", "text/html");
+                    headers.append("accept", " application/json ");
+                    headers.append("Set-Cookie", "a=1");
+                    headers.append("Set-Cookie", "b=2");
+
+                    assert_eq(headers.get("content-type"), "text/plain");
+                    assert_eq(headers.get("ACCEPT"), "text/html, application/json");
+                    assert_eq(headers.get("x-missing"), null);
+                    assert(headers.has("Accept"));
+                    assert_eq(headers.getSetCookie().join(";"), "a=1;b=2");
+                    assert_eq(
+                        JSON.stringify([...headers]),
+                        JSON.stringify([
+                            ["accept", "text/html, application/json"],
+                            ["content-type", "text/plain"],
+                            ["set-cookie", "a=1"],
+                            ["set-cookie", "b=2"],
+                        ]),
+                    );
+
+                    headers.set("Accept", "*/*");
+                    headers.delete("Set-Cookie");
+                    assert_eq([...headers.keys()].join(), "accept,content-type");
+                    assert_eq([...headers.values()].join(), "*/*,text/plain");
+                    assert_eq(Object.prototype.toString.call(headers), "[object Headers]");
+
+                    const copy = new Headers(headers);
+                    copy.set("x-copy", "1");
+                    assert(!headers.has("x-copy"));
+                "#,
+            ),
+            TestAction::run(
+                r#"
+                    let error;
+                    try { new Headers([["invalid name", "value"]]); } catch (e) { error = e; }
+                    assert(error instanceof TypeError);
+                    error = undefined;
+                    try { new Headers({ "x-value": "Ā" }); } catch (e) { error = e; }
+                    assert(error instanceof TypeError);
+                "#,
+            ),
+        ],
+        &mut context_with_fetcher(),
+    );
+}
+
+#[test]
+fn request() {
+    run_test_actions_with(
+        [
+            TestAction::run(TEST_HARNESS),
+            TestAction::run(
+                r#"
+                    const request = new Request("https://example.com/path", {
+                        method: "post",
+                        headers: [["X-Custom", "value"]],
+                        body: "hello",
+                    });
+                    assert_eq(request.method, "POST");
+                    assert_eq(request.url, "https://example.com/path");
+                    assert_eq(request.headers.get("x-custom"), "value");
+                    assert_eq(request.headers.get("content-type"), "text/plain;charset=UTF-8");
+
+                    const copy = new Request(request);
+                    assert(request.bodyUsed);
+                    assert_eq(copy.method, "POST");
+
+                    let error;
+                    try { new Request("https://example.com", { body: "x" }); } catch (e) { error = e; }
+                    assert(error instanceof TypeError);
+
+                    result = undefined;
+                    copy.text().then((text) => { result = text; });
+                "#,
+            ),
+            run_jobs(),
+            TestAction::run(
+                r#"
+                    assert_eq(result, "hello");
+                    assert(copy.bodyUsed);
+                    rejected = false;
+                    copy.text().catch((e) => { rejected = e instanceof TypeError; });
+                "#,
+            ),
+            run_jobs(),
+            TestAction::run(r#"assert(rejected);"#),
+        ],
+        &mut context_with_fetcher(),
+    );
+}
+
+#[test]
+fn response() {
+    run_test_actions_with(
+        [
+            TestAction::run(TEST_HARNESS),
+            TestAction::run(
+                r#"
+                    const response = new Response('{"z": 0, "a": [1, 2]}', {
+                        status: 201,
+                        statusText: "Created",
+                        headers: { "x-custom": "value" },
+                    });
+                    assert_eq(response.status, 201);
+                    assert_eq(response.statusText, "Created");
+                    assert(response.ok);
+                    assert_eq(response.type, "default");
+                    assert_eq(response.headers.get("x-custom"), "value");
+
+                    const clone = response.clone();
+                    json = undefined;
+                    response.json().then((value) => { json = value; });
+                    bytes = undefined;
+                    clone.arrayBuffer().then((buffer) => { bytes = new Uint8Array(buffer); });
+
+                    const fromJson = Response.json({ b: true, a: undefined, 1: null });
+                    assert_eq(fromJson.headers.get("content-type"), "application/json");
+                    jsonText = undefined;
+                    fromJson.text().then((text) => { jsonText = text; });
+                    jsonError = undefined;
+                    new Response("{").json().catch((e) => { jsonError = e; });
+
+                    // Serialization does not go through a patched `JSON` global.
+                    const { stringify } = JSON;
+                    JSON.stringify = () => "patched";
+                    const unpatched = Response.json({ c: 1 });
+                    JSON.stringify = stringify;
+                    unpatchedText = undefined;
+                    unpatched.text().then((text) => { unpatchedText = text; });
+
+                    let typeError;
+                    try { Response.json(undefined); } catch (e) { typeError = e; }
+                    assert(typeError instanceof TypeError);
+
+                    const redirect = Response.redirect("https://example.com/", 301);
+                    assert_eq(redirect.status, 301);
+                    assert_eq(redirect.headers.get("location"), "https://example.com/");
+
+                    const error = Response.error();
+                    assert_eq(error.type, "error");
+                    assert_eq(error.status, 0);
+
+                    let rangeError;
+                    try { new Response(null, { status: 99 }); } catch (e) { rangeError = e; }
+                    assert(rangeError instanceof RangeError);
+                "#,
+            ),
+            run_jobs(),
+            TestAction::run(
+                r#"
+                    assert_eq(JSON.stringify(json), '{"z":0,"a":[1,2]}');
+                    assert_eq(bytes.length, 21);
+                    assert_eq(jsonText, '{"1":null,"b":true}');
+                    assert(jsonError instanceof SyntaxError);
+                    assert_eq(unpatchedText, '{"c":1}');
+                "#,
+            ),
+        ],
+        &mut context_with_fetcher(),
+    );
+}
+
+#[test]
+fn fetch() {
+    run_test_actions_with(
+        [
+            TestAction::run(TEST_HARNESS),
+            TestAction::run(
+                r#"
+                    response = undefined;
+                    body = undefined;
+                    fetch("https://example.com/echo?x=1", {
+                        method: "PUT",
+                        headers: { "x-custom": "value" },
+                        body: new URLSearchParams({ a: "1", b: "2" }),
+                    }).then((r) => {
+                        response = r;
+                        return r.text();
+                    }).then((text) => { body = text; });
+
+                    missing = undefined;
+                    fetch(new Request("https://example.com/missing")).then((r) => { missing = r; });
+
+                    networkError = undefined;
+                    fetch("https://unreachable.test/").catch((e) => { networkError = e; });
+
+                    invalidUrl = undefined;
+                    fetch("not a url").catch((e) => { invalidUrl = e; });
+                "#,
+            ),
+            run_jobs(),
+            TestAction::run(
+                r#"
+                    assert(response instanceof Response);
+                    assert_eq(response.type, "basic");
+                    assert_eq(response.url, "https://example.com/echo?x=1");
+                    assert_eq(response.status, 200);
+                    assert_eq(response.statusText, "OK");
+                    assert_eq(response.headers.get("x-method"), "PUT");
+                    assert_eq(response.headers.get("x-echo-x-custom"), "value");
+                    assert_eq(
+                        response.headers.get("x-echo-content-type"),
+                        "application/x-www-form-urlencoded;charset=UTF-8",
+                    );
+                    assert_eq(body, "a=1&b=2");
+
+                    assert_eq(missing.status, 404);
+                    assert(!missing.ok);
+
+                    assert(networkError instanceof TypeError);
+                    assert_eq(networkError.message, "network error");
+                    assert(invalidUrl instanceof TypeError);
+                "#,
+            ),
+        ],
+        &mut context_with_fetcher(),
+    );
+}
+
+#[test]
+fn fetch_without_fetcher() {
+    let context = &mut Context::default();
+    register(context, RegisterOptions::new()).expect("failed to register WebAPI objects");
+
+    run_test_actions_with(
+        [
+            TestAction::run(TEST_HARNESS),
+            TestAction::run(
+                r#"
+                    assert_eq(typeof fetch, "undefined");
+                    assert_eq(typeof Request, "function");
+                "#,
+            ),
+        ],
+        context,
+    );
+}
@@ -106,9 +106,6 @@ pub fn set_timeout(
     rest: JsRest<'_>,
     context: &mut Context,
 ) -> JsResult<u32> {
-    let handler_map = IntervalInnerState::from_context(context);
-    let id = handler_map.borrow_mut().new_interval()?;
-
     // Spec says if delay is not a number, it should be equal to 0.
     let delay = delay_in_msec
         .unwrap_or_default()
@@ -118,10 +115,25 @@ pub fn set_timeout(
     let delay = u64::from(delay.clamp_finite(0, u32::MAX));
 
     // Get ownership of rest arguments.
-    let rest = rest.to_vec();
+    schedule_timeout(function_ref, delay, rest.to_vec(), context)
+}
+
+/// Schedule a call to `function_ref` with `args` after `delay` milliseconds, returning
+/// the ID of the timeout, which can be cleared with `clearTimeout`.
+///
+/// # Errors
+/// Any errors when trying to read the context or enqueuing the job.
+pub(crate) fn schedule_timeout(
+    function_ref: JsFunction,
+    delay: u64,
+    args: Vec<JsValue>,
+    context: &mut Context,
+) -> JsResult<u32> {
+    let handler_map = IntervalInnerState::from_context(context);
+    let id = handler_map.borrow_mut().new_interval()?;
 
     let job = TimeoutJob::new(
-        NativeJob::new(move |context| handle(handler_map, id, function_ref, rest, None, context)),
+        NativeJob::new(move |context| handle(handler_map, id, function_ref, args, None, context)),
         delay,
     );
     context.enqueue_job(job.into());
@@ -51,6 +51,8 @@
     clippy::let_unit_value
 )]
 
+use boa_engine::{Context, JsObject, JsResult, JsValue};
+
 mod console;
 
 #[doc(inline)]
@@ -63,18 +65,39 @@ pub use text::{TextDecoder, TextEncoder};
 
 pub mod url;
 
+pub mod fetch;
+
 pub mod interval;
 
+pub mod structured_clone;
+
+pub mod event;
+
+pub mod abort;
+
 /// Options used when registering all built-in objects and functions of the `WebAPI` runtime.
-#[derive(Debug)]
 pub struct RegisterOptions<L: Logger> {
     console_logger: L,
+    #[cfg(feature = "fetch")]
+    fetcher: Option<std::rc::Rc<dyn fetch::Fetcher>>,
+}
+
+impl<L: Logger + std::fmt::Debug> std::fmt::Debug for RegisterOptions<L> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut options = f.debug_struct("RegisterOptions");
+        options.field("console_logger", &self.console_logger);
+        #[cfg(feature = "fetch")]
+        options.field("fetcher", &self.fetcher.as_ref().map(|_| "Fetcher"));
+        options.finish()
+    }
 }
 
 impl Default for RegisterOptions<console::DefaultLogger> {
     fn default() -> Self {
         Self {
             console_logger: console::DefaultLogger,
+            #[cfg(feature = "fetch")]
+            fetcher: None,
         }
     }
 }
@@ -92,6 +115,19 @@ impl<L: Logger> RegisterOptions<L> {
     pub fn with_console_logger<L2: Logger>(self, logger: L2) -> RegisterOptions<L2> {
         RegisterOptions::<L2> {
             console_logger: logger,
+            #[cfg(feature = "fetch")]
+            fetcher: self.fetcher,
+        }
+    }
+
+    /// Set the fetcher performing the requests made with `fetch`. The `fetch` function
+    /// is only registered if a fetcher is set.
+    #[cfg(feature = "fetch")]
+    #[must_use]
+    pub fn with_fetcher(self, fetcher: impl fetch::Fetcher + 'static) -> Self {
+        Self {
+            fetcher: Some(std::rc::Rc::new(fetcher)),
+            ..self
         }
     }
 }
@@ -101,9 +137,9 @@ impl<L: Logger> RegisterOptions<L> {
 /// # Errors
 /// This will error is any of the built-in objects or functions cannot be registered.
 pub fn register(
-    ctx: &mut boa_engine::Context,
+    ctx: &mut Context,
     options: RegisterOptions<impl Logger + 'static>,
-) -> boa_engine::JsResult<()> {
+) -> JsResult<()> {
     Console::register_with_logger(ctx, options.console_logger)?;
     TextDecoder::register(ctx)?;
     TextEncoder::register(ctx)?;
@@ -111,11 +147,46 @@ pub fn register(
     #[cfg(feature = "url")]
     url::Url::register(ctx)?;
 
+    #[cfg(feature = "fetch")]
+    {
+        fetch::register(ctx)?;
+        if let Some(fetcher) = options.fetcher {
+            fetch::register_fetch(fetcher, ctx)?;
+        }
+    }
+
     interval::register(ctx)?;
+    structured_clone::register(ctx)?;
+    event::register(ctx)?;
+    abort::register(ctx)?;
 
     Ok(())
 }
 
+/// Collect the values produced by the `@@iterator` `method` of `iterable`, converted with
+/// `convert`. The iterator is closed if a conversion throws.
+pub(crate) fn iterable_to_vec<T>(
+    iterable: &JsValue,
+    method: &JsObject,
+    mut convert: impl FnMut(JsValue, &mut Context) -> JsResult<T>,
+    context: &mut Context,
+) -> JsResult<Vec<T>> {
+    let mut iterator = iterable.get_iterator_from_method(method, context)?;
+
+    let mut values = Vec::new();
+    while let Some(value) = iterator.step_value(context)? {
+        match convert(value, context) {
+            Ok(value) => values.push(value),
+            Err(err) => {
+                return Err(iterator
+                    .close(Err(err), context)
+                    .expect_err("IteratorClose must return a throw completion"))
+            }
+        }
+    }
+    Ok(values)
+}
+
 #[cfg(test)]
 pub(crate) mod test {
     use crate::{register, RegisterOptions};
@@ -0,0 +1,81 @@
+//! Boa's implementation of JavaScript's `structuredClone` Web API function.
+//!
+//! The values are copied with the structured clone algorithm of the engine, see
+//! [`StructuredValue`].
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [HTML specification][spec]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#dom-structuredclone
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Window/structuredClone
+
+use crate::iterable_to_vec;
+use boa_engine::value::StructuredValue;
+use boa_engine::{
+    js_error, js_string, Context, JsArgs, JsObject, JsResult, JsSymbol, JsValue, NativeFunction,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Register the `structuredClone` function into the given context.
+///
+/// # Errors
+/// Any error returned by the context when registering the global function.
+pub fn register(context: &mut Context) -> JsResult<()> {
+    context.register_global_callable(
+        js_string!("structuredClone"),
+        1,
+        NativeFunction::from_fn_ptr(structured_clone),
+    )
+}
+
+/// `structuredClone ( value [ , options ] )`
+///
+/// Returns a deep copy of `value`. The `ArrayBuffer`s listed in the `transfer` option
+/// are moved to the copy, detaching the original buffers.
+///
+/// Values that cannot be cloned throw a `TypeError` rather than the `DataCloneError`
+/// `DOMException` of the specification, as the runtime doesn't implement `DOMException`.
+fn structured_clone(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let value = args.get_or_undefined(0);
+    let options = args.get_or_undefined(1);
+
+    let transfer = match options.as_object() {
+        Some(options) => options.get(js_string!("transfer"), context)?,
+        None if options.is_null_or_undefined() => JsValue::undefined(),
+        None => return Err(js_error!(TypeError: "structuredClone options must be an object")),
+    };
+    let transfer = if transfer.is_undefined() {
+        Vec::new()
+    } else {
+        transfer_list(&transfer, context)?
+    };
+
+    StructuredValue::serialize_with_transfer(value, &transfer, context)?.deserialize(context)
+}
+
+/// Convert the `transfer` option to a list of objects, like the `sequence<object>`
+/// `WebIDL` type.
+fn transfer_list(transfer: &JsValue, context: &mut Context) -> JsResult<Vec<JsObject>> {
+    let method = match transfer.as_object() {
+        Some(object) => object.get(JsSymbol::iterator(), context)?,
+        None => JsValue::undefined(),
+    };
+    let method = method
+        .as_callable()
+        .ok_or_else(|| js_error!(TypeError: "transfer option must be iterable"))?;
+
+    iterable_to_vec(
+        transfer,
+        method,
+        |value, _| {
+            value
+                .as_object()
+                .cloned()
+                .ok_or_else(|| js_error!(TypeError: "transferred values must be objects"))
+        },
+        context,
+    )
+}
@@ -0,0 +1,109 @@
+use crate::test::{run_test_actions, TestAction};
+
+const TEST_HARNESS: &str = r#"
+function assert(condition, message) {
+    if (!condition) {
+        if (!message) {
+            message = "Assertion failed";
+        }
+        throw new Error(message);
+    }
+}
+
+function assert_eq(a, b, message) {
+    if (a !== b) {
+        throw new Error(`${message} (${JSON.stringify(a)} !== ${JSON.stringify(b)})`);
+    }
+}
+"#;
+
+#[test]
+fn structured_clone() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r#"
+                const original = {
+                    date: new Date(0),
+                    map: new Map([["a", 1n]]),
+                    set: new Set([1, 2]),
+                    bytes: new Uint8Array([1, 2, 3]),
+                };
+                original.self = original;
+
+                const copy = structuredClone(original);
+                assert(copy !== original);
+                assert_eq(copy.self, copy);
+                assert_eq(copy.date.getTime(), 0);
+                assert_eq(copy.map.get("a"), 1n);
+                assert_eq(copy.set.size, 2);
+                assert_eq(copy.bytes.join(), "1,2,3");
+                assert(copy.bytes.buffer !== original.bytes.buffer);
+
+                assert_eq(structuredClone(1), 1);
+                assert_eq(structuredClone("text"), "text");
+                assert_eq(structuredClone(undefined), undefined);
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn transfer() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r#"
+                const buffer = new Uint8Array([1, 2, 3]).buffer;
+                const copy = structuredClone({ buffer }, { transfer: [buffer] });
+                assert_eq(buffer.byteLength, 0);
+                assert_eq(new Uint8Array(copy.buffer).join(), "1,2,3");
+
+                const other = new ArrayBuffer(4);
+                const moved = structuredClone(undefined, { transfer: new Set([other]) });
+                assert_eq(moved, undefined);
+                assert_eq(other.byteLength, 0);
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn errors() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r#"
+                function throws(f) {
+                    try {
+                        f();
+                    } catch (e) {
+                        return e;
+                    }
+                    throw new Error("expected an exception");
+                }
+
+                assert(throws(() => structuredClone(() => {})) instanceof TypeError);
+                assert_eq(throws(() => structuredClone(() => {})).message, "functions cannot be cloned");
+                assert(throws(() => structuredClone({ s: Symbol() })) instanceof TypeError);
+                assert(throws(() => structuredClone(1, 2)) instanceof TypeError);
+                assert(throws(() => structuredClone(1, { transfer: 1 })) instanceof TypeError);
+                assert(throws(() => structuredClone(1, { transfer: [{}] })) instanceof TypeError);
+
+                const buffer = new ArrayBuffer(1);
+                assert(
+                    throws(() => structuredClone(1, { transfer: [buffer, buffer] }))
+                        instanceof TypeError,
+                );
+                assert_eq(buffer.byteLength, 1);
+
+                const uncloneable = { buffer, f() {} };
+                assert(
+                    throws(() => structuredClone(uncloneable, { transfer: [buffer] }))
+                        instanceof TypeError,
+                );
+                assert_eq(buffer.byteLength, 1);
+            "#,
+        ),
+    ]);
+}
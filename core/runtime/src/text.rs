@@ -2,22 +2,76 @@
 //!
 //! See <https://developer.mozilla.org/en-US/docs/Web/API/Encoding_API> for more information.
 
-use boa_engine::object::builtins::JsUint8Array;
+use boa_engine::object::builtins::{
+    JsArrayBuffer, JsDataView, JsSharedArrayBuffer, JsTypedArray, JsUint8Array,
+};
+use boa_engine::object::ObjectInitializer;
+use boa_engine::property::Attribute;
 use boa_engine::string::CodePoint;
+use boa_engine::value::{Convert, TryFromJs};
 use boa_engine::{
-    js_string, Context, Finalize, JsData, JsNativeError, JsObject, JsResult, JsString, Trace,
+    js_error, js_string, Context, Finalize, JsData, JsError, JsNativeError, JsObject, JsResult,
+    JsString, JsValue, Trace,
 };
-use boa_interop::js_class;
+use boa_interop::{js_class, JsClass};
+use cow_utils::CowUtils;
+use encoding_rs::{Decoder, Encoding, UTF_8};
 
 #[cfg(test)]
 mod tests;
 
-/// The `TextDecoder`[mdn] class represents an encoder for a specific method, that is
-/// a specific character encoding, like `utf-8`.
+/// The `TextDecoder`[mdn] class represents a decoder for a specific method, that is
+/// a specific character encoding, like `utf-8` or `windows-1252`.
 ///
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder
-#[derive(Debug, Clone, JsData, Trace, Finalize)]
-pub struct TextDecoder;
+#[derive(JsData, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
+pub struct TextDecoder {
+    #[unsafe_ignore_trace]
+    encoding: &'static Encoding,
+    fatal: bool,
+    ignore_bom: bool,
+
+    /// The decoder of a streaming decode, kept between calls to [`TextDecoder::decode_bytes`]
+    /// to carry over incomplete byte sequences.
+    #[unsafe_ignore_trace]
+    decoder: Option<Decoder>,
+}
+
+impl std::fmt::Debug for TextDecoder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TextDecoder")
+            .field("encoding", &self.encoding)
+            .field("fatal", &self.fatal)
+            .field("ignore_bom", &self.ignore_bom)
+            .field("streaming", &self.decoder.is_some())
+            .finish()
+    }
+}
+
+impl Clone for TextDecoder {
+    /// Clones the configuration of the decoder. A pending streaming decode is not carried
+    /// over, so the clone starts a new stream.
+    fn clone(&self) -> Self {
+        Self {
+            encoding: self.encoding,
+            fatal: self.fatal,
+            ignore_bom: self.ignore_bom,
+            decoder: None,
+        }
+    }
+}
+
+impl Default for TextDecoder {
+    fn default() -> Self {
+        Self {
+            encoding: UTF_8,
+            fatal: false,
+            ignore_bom: false,
+            decoder: None,
+        }
+    }
+}
 
 impl TextDecoder {
     /// Register the `TextDecoder` class into the realm.
@@ -29,6 +83,37 @@ impl TextDecoder {
         Ok(())
     }
 
+    /// Create a `TextDecoder` for the encoding with the given [label][labels].
+    ///
+    /// If `fatal` is set, decoding throws on malformed input instead of replacing it
+    /// with the Unicode replacement character. If `ignore_bom` is set, a leading byte
+    /// order mark is kept in the output.
+    ///
+    /// # Errors
+    /// This will error if `label` is not the label of a supported encoding.
+    ///
+    /// [labels]: https://encoding.spec.whatwg.org/#names-and-labels
+    pub fn new(label: &str, fatal: bool, ignore_bom: bool) -> JsResult<Self> {
+        // The `replacement` encoding only exists to prevent some attacks on HTML forms,
+        // so it is rejected like unknown labels.
+        let encoding = Encoding::for_label_no_replacement(label.as_bytes()).ok_or_else(
+            || js_error!(RangeError: "The encoding label provided ('{}') is invalid", label),
+        )?;
+
+        Ok(Self {
+            encoding,
+            fatal,
+            ignore_bom,
+            decoder: None,
+        })
+    }
+
+    /// The name of the encoding of this decoder, in lowercase.
+    #[must_use]
+    pub fn encoding(&self) -> JsString {
+        JsString::from(&*self.encoding.name().cow_to_ascii_lowercase())
+    }
+
     /// The `decode()` method of the `TextDecoder` interface returns a `JsString` containing
     /// the given `Uint8Array` decoded in the specific method. This will replace any
     /// invalid characters with the Unicode replacement character.
@@ -37,29 +122,188 @@ impl TextDecoder {
         let string = String::from_utf8_lossy(&buffer);
         JsString::from(string.as_ref())
     }
+
+    /// Decodes `input` with the encoding and options of this decoder.
+    ///
+    /// If `stream` is set, incomplete byte sequences at the end of `input` are kept and
+    /// decoded with the input of the next call.
+    ///
+    /// # Errors
+    /// This will error if the decoder is fatal and `input` is malformed.
+    pub fn decode_bytes(&mut self, input: &[u8], stream: bool) -> JsResult<JsString> {
+        let mut decoder = self.decoder.take().unwrap_or_else(|| {
+            if self.ignore_bom {
+                self.encoding.new_decoder_without_bom_handling()
+            } else {
+                self.encoding.new_decoder_with_bom_removal()
+            }
+        });
+
+        let length = decoder
+            .max_utf16_buffer_length(input.len())
+            .ok_or_else(|| js_error!(RangeError: "Input is too large to decode"))?;
+        let mut output = vec![0; length];
+        let (_, _, written, had_errors) = decoder.decode_to_utf16(input, &mut output, !stream);
+
+        if had_errors && self.fatal {
+            return Err(js_error!(
+                TypeError: "The encoded data was not valid for encoding {}",
+                self.encoding.name()
+            ));
+        }
+
+        if stream {
+            self.decoder = Some(decoder);
+        }
+
+        Ok(JsString::from(&output[..written]))
+    }
+}
+
+/// The options of the `TextDecoder` constructor.
+#[derive(Debug, Default, Clone, Copy)]
+struct TextDecoderOptions {
+    fatal: bool,
+    ignore_bom: bool,
+}
+
+impl TryFromJs for TextDecoderOptions {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let Some(options) = dictionary(value)? else {
+            return Ok(Self::default());
+        };
+
+        Ok(Self {
+            fatal: options.get(js_string!("fatal"), context)?.to_boolean(),
+            ignore_bom: options.get(js_string!("ignoreBOM"), context)?.to_boolean(),
+        })
+    }
+}
+
+/// The options of `TextDecoder.prototype.decode`.
+#[derive(Debug, Default, Clone, Copy)]
+struct TextDecodeOptions {
+    stream: bool,
+}
+
+impl TryFromJs for TextDecodeOptions {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let Some(options) = dictionary(value)? else {
+            return Ok(Self::default());
+        };
+
+        Ok(Self {
+            stream: options.get(js_string!("stream"), context)?.to_boolean(),
+        })
+    }
+}
+
+/// Get the object of an options dictionary, which can also be `undefined` or `null`.
+fn dictionary(value: &JsValue) -> JsResult<Option<&JsObject>> {
+    if value.is_null_or_undefined() {
+        return Ok(None);
+    }
+    value
+        .as_object()
+        .map(Some)
+        .ok_or_else(|| js_error!(TypeError: "Options must be an object"))
+}
+
+/// Copy the bytes of a `BufferSource`, which is an `ArrayBuffer`, a `SharedArrayBuffer`,
+/// a typed array or a `DataView`. `undefined` and detached buffers are empty.
+pub(crate) fn buffer_source_bytes(value: &JsValue, context: &mut Context) -> JsResult<Vec<u8>> {
+    if value.is_undefined() {
+        return Ok(Vec::new());
+    }
+
+    let object = value.as_object().ok_or_else(
+        || js_error!(TypeError: "The provided value is not an ArrayBuffer or ArrayBufferView"),
+    )?;
+
+    // Find the buffer holding the bytes, and the range of the bytes for views.
+    let (buffer, range) = if JsArrayBuffer::from_object(object.clone()).is_ok()
+        || JsSharedArrayBuffer::from_object(object.clone()).is_ok()
+    {
+        (value.clone(), None)
+    } else if let Ok(array) = JsTypedArray::from_object(object.clone()) {
+        let offset = array.byte_offset(context)?;
+        (
+            array.buffer(context)?,
+            Some(offset..offset + array.byte_length(context)?),
+        )
+    } else if let Ok(view) = JsDataView::from_object(object.clone()) {
+        let offset = usize::try_from(view.byte_offset(context)?).map_err(JsError::from_rust)?;
+        let length = usize::try_from(view.byte_length(context)?).map_err(JsError::from_rust)?;
+        (view.buffer(context)?, Some(offset..offset + length))
+    } else {
+        return Err(js_error!(
+            TypeError: "The provided value is not an ArrayBuffer or ArrayBufferView"
+        ));
+    };
+
+    if let Some(buffer) = buffer
+        .as_object()
+        .and_then(|buffer| JsArrayBuffer::from_object(buffer.clone()).ok())
+    {
+        let Some(data) = buffer.data() else {
+            return Ok(Vec::new());
+        };
+        let range = range.unwrap_or(0..data.len());
+        return Ok(data.get(range).unwrap_or_default().to_vec());
+    }
+
+    // Shared buffers cannot be detached, so a `Uint8Array` over the same bytes can always
+    // be created to read them.
+    let mut args = vec![buffer];
+    if let Some(range) = range {
+        args.extend([range.start.into(), range.len().into()]);
+    }
+    let constructor = context
+        .intrinsics()
+        .constructors()
+        .typed_uint8_array()
+        .constructor();
+    let bytes = JsUint8Array::from_object(constructor.construct(&args, None, context)?)?;
+    Ok(bytes.iter(context).collect())
 }
 
 js_class! {
     class TextDecoder {
         property encoding {
-            fn get() -> JsString {
-                js_string!("utf-8")
+            fn get(this: JsClass<TextDecoder>) -> JsString {
+                this.borrow().encoding()
             }
         }
 
-        // Creates a new `TextEncoder` object. Encoding is optional but MUST BE
-        // "utf-8" if specified. Options is ignored.
-        constructor(encoding: Option<JsString>, _options: Option<JsObject>) {
-            if let Some(e) = encoding {
-                if e != js_string!("utf-8") {
-                    return Err(JsNativeError::typ().with_message("Only utf-8 encoding is supported").into());
-                }
+        property fatal {
+            fn get(this: JsClass<TextDecoder>) -> bool {
+                this.borrow().fatal
+            }
+        }
+
+        property ignore_bom as "ignoreBOM" {
+            fn get(this: JsClass<TextDecoder>) -> bool {
+                this.borrow().ignore_bom
             }
-            Ok(TextDecoder)
         }
 
-        fn decode(array: JsUint8Array, context: &mut Context) -> JsString {
-            TextDecoder::decode(&array, context)
+        // Creates a new `TextDecoder` object. The label defaults to "utf-8".
+        constructor(label: Option<Convert<JsString>>, options: TextDecoderOptions) {
+            let label = label.map_or_else(
+                || "utf-8".to_owned(),
+                |label| label.0.to_std_string_lossy(),
+            );
+            TextDecoder::new(&label, options.fatal, options.ignore_bom)
+        }
+
+        fn decode(
+            this: JsClass<TextDecoder>,
+            input: JsValue,
+            options: TextDecodeOptions,
+            context: &mut Context,
+        ) -> JsResult<JsString> {
+            let input = buffer_source_bytes(&input, context)?;
+            this.borrow_mut().decode_bytes(&input, options.stream)
         }
     }
 }
@@ -96,6 +340,67 @@ impl TextEncoder {
             context,
         )
     }
+
+    /// The `encodeInto()` method of the `TextEncoder` interface encodes as much of the
+    /// given string as fits into `destination`, without splitting a code point. It
+    /// returns the number of UTF-16 code units read from `source` and the number of
+    /// bytes written into `destination`.
+    ///
+    /// # Errors
+    /// This will error if there is an issue writing to the `Uint8Array`.
+    pub fn encode_into(
+        source: &JsString,
+        destination: &JsUint8Array,
+        context: &mut Context,
+    ) -> JsResult<(usize, usize)> {
+        let offset = destination.byte_offset(context)?;
+        let length = destination.length(context)?;
+        let buffer = destination.buffer(context)?;
+
+        if let Some(buffer) = buffer
+            .as_object()
+            .and_then(|buffer| JsArrayBuffer::from_object(buffer.clone()).ok())
+        {
+            // A detached buffer has no room for any byte.
+            let Some(mut data) = buffer.data_mut() else {
+                return Ok((0, 0));
+            };
+            let bytes = data.get_mut(offset..offset + length).unwrap_or_default();
+            return Ok(Self::encode_utf8_into(source, bytes));
+        }
+
+        // The bytes of shared buffers are only reachable through the engine, so encode into
+        // a scratch buffer and copy it over.
+        let mut bytes = vec![0; length];
+        let (read, written) = Self::encode_utf8_into(source, &mut bytes);
+        if written > 0 {
+            bytes.truncate(written);
+            let bytes = JsUint8Array::from_iter(bytes, context)?;
+            destination.set_values(bytes.into(), None, context)?;
+        }
+
+        Ok((read, written))
+    }
+
+    /// Encode as much of `source` as fits into `destination` as UTF-8, returning the number
+    /// of UTF-16 code units read and the number of bytes written.
+    fn encode_utf8_into(source: &JsString, destination: &mut [u8]) -> (usize, usize) {
+        let mut read = 0;
+        let mut written = 0;
+        for code_point in source.code_points() {
+            let c = match code_point {
+                CodePoint::Unicode(c) => c,
+                CodePoint::UnpairedSurrogate(_) => '\u{FFFD}',
+            };
+            let Some(bytes) = destination.get_mut(written..written + c.len_utf8()) else {
+                break;
+            };
+            c.encode_utf8(bytes);
+            read += c.len_utf16();
+            written += c.len_utf8();
+        }
+        (read, written)
+    }
 }
 
 js_class! {
@@ -120,5 +425,17 @@ js_class! {
         fn encode(text: JsString, context: &mut Context) -> JsResult<JsUint8Array> {
             TextEncoder::encode(&text, context)
         }
+
+        fn encode_into as "encodeInto"(
+            source: JsString,
+            destination: JsUint8Array,
+            context: &mut Context,
+        ) -> JsResult<JsObject> {
+            let (read, written) = TextEncoder::encode_into(&source, &destination, context)?;
+            Ok(ObjectInitializer::new(context)
+                .property(js_string!("read"), read, Attribute::all())
+                .property(js_string!("written"), written, Attribute::all())
+                .build())
+        }
     }
 }
@@ -1,8 +1,8 @@
 use crate::test::{run_test_actions_with, TestAction};
 use crate::{TextDecoder, TextEncoder};
-use boa_engine::object::builtins::JsUint8Array;
+use boa_engine::object::builtins::{JsArrayBuffer, JsUint8Array};
 use boa_engine::property::Attribute;
-use boa_engine::{js_str, js_string, Context, JsString};
+use boa_engine::{js_str, js_string, Context, JsString, JsValue};
 use indoc::indoc;
 
 #[test]
@@ -150,3 +150,208 @@ fn roundtrip() {
         context,
     );
 }
+
+#[test]
+fn decoder_encodings() {
+    let context = &mut Context::default();
+    TextDecoder::register(context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                const utf16 = new TextDecoder("UTF-16LE");
+                const latin1 = new TextDecoder(" latin1 ");
+                encodings = [new TextDecoder().encoding, utf16.encoding, latin1.encoding].join();
+                decoded = utf16.decode(Uint16Array.from([72, 105, 0xD83D, 0xDE00]))
+                    + latin1.decode(Uint8Array.from([0x80, 0xE9]).buffer)
+                    + new TextDecoder("utf-16be").decode(
+                        new DataView(Uint8Array.from([0, 0, 0, 33]).buffer, 2)
+                    );
+
+                invalid = [];
+                for (const label of ["utf-7", "replacement"]) {
+                    try {
+                        new TextDecoder(label);
+                    } catch (e) {
+                        invalid.push(e instanceof RangeError);
+                    }
+                }
+            "#}),
+            TestAction::inspect_context(|context| {
+                let global = context.global_object();
+                let encodings = global.get(js_str!("encodings"), context).unwrap();
+                assert_eq!(
+                    encodings.as_string(),
+                    Some(&js_string!("utf-8,utf-16le,windows-1252"))
+                );
+                let decoded = global.get(js_str!("decoded"), context).unwrap();
+                assert_eq!(
+                    decoded.as_string(),
+                    Some(&js_string!("Hi\u{1F600}\u{20AC}\u{E9}!"))
+                );
+                let invalid = global.get(js_str!("invalid"), context).unwrap();
+                assert_eq!(
+                    invalid.to_string(context).unwrap().to_std_string_escaped(),
+                    "true,true"
+                );
+            }),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn decoder_options() {
+    let context = &mut Context::default();
+    TextDecoder::register(context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                const bom = Uint8Array.from([0xEF, 0xBB, 0xBF, 65]);
+                const fatal = new TextDecoder("utf-8", { fatal: true });
+                options = [fatal.fatal, fatal.ignoreBOM].join();
+                decoded = new TextDecoder().decode(bom)
+                    + new TextDecoder("utf-8", { ignoreBOM: true }).decode(bom);
+
+                try {
+                    fatal.decode(Uint8Array.from([65, 0xFF]));
+                } catch (e) {
+                    error = e instanceof TypeError;
+                }
+            "#}),
+            TestAction::inspect_context(|context| {
+                let global = context.global_object();
+                let options = global.get(js_str!("options"), context).unwrap();
+                assert_eq!(options.as_string(), Some(&js_string!("true,false")));
+                let decoded = global.get(js_str!("decoded"), context).unwrap();
+                assert_eq!(decoded.as_string(), Some(&js_string!("A\u{FEFF}A")));
+                let error = global.get(js_str!("error"), context).unwrap();
+                assert_eq!(error.as_boolean(), Some(true));
+            }),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn decoder_stream() {
+    let context = &mut Context::default();
+    TextDecoder::register(context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                const d = new TextDecoder();
+                const euro = [0xE2, 0x82, 0xAC];
+                decoded = [
+                    d.decode(Uint8Array.from([65, euro[0]]), { stream: true }),
+                    d.decode(Uint8Array.from([euro[1]]), { stream: true }),
+                    d.decode(Uint8Array.from([euro[2], 66])),
+                    // A call without `stream` flushes the incomplete sequence.
+                    d.decode(Uint8Array.from([euro[0]]), { stream: true }),
+                    d.decode(),
+                ];
+            "#}),
+            TestAction::inspect_context(|context| {
+                let decoded = context
+                    .global_object()
+                    .get(js_str!("decoded"), context)
+                    .unwrap();
+                let decoded = decoded.as_object().unwrap();
+                let expected = ["A", "", "\u{20AC}B", "", "\u{FFFD}"];
+                for (i, expected) in expected.into_iter().enumerate() {
+                    assert_eq!(
+                        decoded.get(i, context).unwrap().as_string(),
+                        Some(&JsString::from(expected))
+                    );
+                }
+            }),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn encoder_encode_into() {
+    let context = &mut Context::default();
+    TextEncoder::register(context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                const encoder = new TextEncoder();
+                buffer = new Uint8Array(6).fill(0xFF);
+                // The emoji needs 4 bytes, so it does not fit after "a€".
+                const { read, written } = encoder.encodeInto("a€😀", buffer);
+                result = [read, written];
+            "#}),
+            TestAction::inspect_context(|context| {
+                let global = context.global_object();
+                let result = global.get(js_str!("result"), context).unwrap();
+                assert_eq!(
+                    result.to_string(context).unwrap().to_std_string_escaped(),
+                    "2,4"
+                );
+                let buffer = global.get(js_str!("buffer"), context).unwrap();
+                let array = JsUint8Array::from_object(buffer.as_object().unwrap().clone()).unwrap();
+                let buffer = array.iter(context).collect::<Vec<_>>();
+                assert_eq!(buffer, [0x61, 0xE2, 0x82, 0xAC, 0xFF, 0xFF]);
+            }),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn encode_into_view_and_detached_buffers() {
+    let context = &mut Context::default();
+    TextEncoder::register(context).unwrap();
+    TextDecoder::register(context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                const encoder = new TextEncoder();
+                buffer = new Uint8Array(5);
+                written = encoder.encodeInto("hi!", buffer.subarray(1, 3)).written;
+                detached = new Uint8Array(4);
+            "#}),
+            TestAction::inspect_context(|context| {
+                let global = context.global_object();
+                let buffer = global.get(js_str!("buffer"), context).unwrap();
+                let array = JsUint8Array::from_object(buffer.as_object().unwrap().clone()).unwrap();
+                let buffer = array.iter(context).collect::<Vec<_>>();
+                assert_eq!(buffer, [0, b'h', b'i', 0, 0]);
+
+                let detached = global.get(js_str!("detached"), context).unwrap();
+                let array =
+                    JsUint8Array::from_object(detached.as_object().unwrap().clone()).unwrap();
+                let buffer = array.buffer(context).unwrap();
+                JsArrayBuffer::from_object(buffer.as_object().unwrap().clone())
+                    .unwrap()
+                    .detach(&JsValue::undefined())
+                    .unwrap();
+            }),
+            TestAction::run(indoc! {r#"
+                result = [
+                    written,
+                    encoder.encodeInto("hi", detached).written,
+                    new TextDecoder().decode(detached).length,
+                    new TextDecoder().decode(detached.buffer).length,
+                ];
+            "#}),
+            TestAction::inspect_context(|context| {
+                let result = context
+                    .global_object()
+                    .get(js_str!("result"), context)
+                    .unwrap();
+                assert_eq!(
+                    result.to_string(context).unwrap().to_std_string_escaped(),
+                    "2,0,0,0"
+                );
+            }),
+        ],
+        context,
+    );
+}
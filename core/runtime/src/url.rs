@@ -15,43 +15,81 @@
 #[cfg(test)]
 mod tests;
 
+mod search_params;
+
+#[doc(inline)]
+pub use search_params::UrlSearchParams;
+
 use boa_engine::class::Class;
+use boa_engine::interop::JsThis;
 use boa_engine::value::Convert;
-use boa_engine::{js_error, Context, Finalize, JsData, JsResult, JsString, JsValue, Trace};
+use boa_engine::{
+    js_error, Context, Finalize, JsData, JsObject, JsResult, JsString, JsValue, Trace,
+};
 use boa_interop::boa_macros::boa_class;
 use std::fmt::Display;
 
 /// The `URL` class represents a (properly parsed) Uniform Resource Locator.
-#[derive(Debug, Clone, JsData, Trace, Finalize)]
+#[derive(Debug, JsData, Trace, Finalize)]
 #[boa_gc(unsafe_no_drop)]
-pub struct Url(#[unsafe_ignore_trace] url::Url);
+pub struct Url {
+    #[unsafe_ignore_trace]
+    inner: url::Url,
+
+    /// The `URLSearchParams` object of this URL, created the first time
+    /// `searchParams` is accessed.
+    search_params: Option<JsObject<UrlSearchParams>>,
+}
 
 impl Url {
-    /// Register the `URL` class into the realm.
+    /// Register the `URL` class, and the `URLSearchParams` class it depends on, into the realm.
     ///
     /// # Errors
-    /// This will error if the context or realm cannot register the class.
+    /// This will error if the context or realm cannot register the classes.
     pub fn register(context: &mut Context) -> JsResult<()> {
+        UrlSearchParams::register(context)?;
         context.register_global_class::<Self>()?;
         Ok(())
     }
+
+    /// Update the list of the `URLSearchParams` object of this URL, if any, after its
+    /// query changed.
+    fn update_search_params(&self) {
+        if let Some(search_params) = &self.search_params {
+            search_params
+                .borrow_mut()
+                .data_mut()
+                .set_list_from_query(self.inner.query());
+        }
+    }
+}
+
+impl Clone for Url {
+    /// Clones the URL. The clone gets its own `URLSearchParams` object when it is
+    /// first requested, as that object is linked to a single URL.
+    fn clone(&self) -> Self {
+        Self::from(self.inner.clone())
+    }
 }
 
 impl Display for Url {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.inner)
     }
 }
 
 impl From<url::Url> for Url {
     fn from(url: url::Url) -> Self {
-        Self(url)
+        Self {
+            inner: url,
+            search_params: None,
+        }
     }
 }
 
 impl From<Url> for url::Url {
     fn from(url: Url) -> url::Url {
-        url.0
+        url.inner
     }
 }
 
@@ -74,141 +112,153 @@ impl Url {
             let url = base_url
                 .join(url)
                 .map_err(|e| js_error!(TypeError: "Failed to parse URL: {}", e))?;
-            Ok(Self(url))
+            Ok(Self::from(url))
         } else {
             let url = url::Url::parse(url)
                 .map_err(|e| js_error!(TypeError: "Failed to parse URL: {}", e))?;
-            Ok(Self(url))
+            Ok(Self::from(url))
         }
     }
 
     #[boa(getter)]
     fn hash(&self) -> JsString {
-        JsString::from(url::quirks::hash(&self.0))
+        JsString::from(url::quirks::hash(&self.inner))
     }
 
     #[boa(setter)]
     #[boa(rename = "hash")]
     fn set_hash(&mut self, value: Convert<String>) {
-        url::quirks::set_hash(&mut self.0, &value.0);
+        url::quirks::set_hash(&mut self.inner, &value.0);
     }
 
     #[boa(getter)]
     fn hostname(&self) -> JsString {
-        JsString::from(url::quirks::hostname(&self.0))
+        JsString::from(url::quirks::hostname(&self.inner))
     }
 
     #[boa(setter)]
     #[boa(rename = "hostname")]
     fn set_hostname(&mut self, value: Convert<String>) {
-        let _ = url::quirks::set_hostname(&mut self.0, &value.0);
+        let _ = url::quirks::set_hostname(&mut self.inner, &value.0);
     }
 
     #[boa(getter)]
     fn host(&self) -> JsString {
-        JsString::from(url::quirks::host(&self.0))
+        JsString::from(url::quirks::host(&self.inner))
     }
 
     #[boa(setter)]
     #[boa(rename = "host")]
     fn set_host(&mut self, value: Convert<String>) {
-        let _ = url::quirks::set_host(&mut self.0, &value.0);
+        let _ = url::quirks::set_host(&mut self.inner, &value.0);
     }
 
     #[boa(getter)]
     fn href(&self) -> JsString {
-        JsString::from(url::quirks::href(&self.0))
+        JsString::from(url::quirks::href(&self.inner))
     }
 
     #[boa(setter)]
     #[boa(rename = "href")]
     fn set_href(&mut self, value: Convert<String>) -> JsResult<()> {
-        url::quirks::set_href(&mut self.0, &value.0)
-            .map_err(|e| js_error!(TypeError: "Failed to set href: {}", e))
+        url::quirks::set_href(&mut self.inner, &value.0)
+            .map_err(|e| js_error!(TypeError: "Failed to set href: {}", e))?;
+        self.update_search_params();
+        Ok(())
     }
 
     #[boa(getter)]
     fn origin(&self) -> JsString {
-        JsString::from(url::quirks::origin(&self.0))
+        JsString::from(url::quirks::origin(&self.inner))
     }
 
     #[boa(getter)]
     fn password(&self) -> JsString {
-        JsString::from(url::quirks::password(&self.0))
+        JsString::from(url::quirks::password(&self.inner))
     }
 
     #[boa(setter)]
     #[boa(rename = "password")]
     fn set_password(&mut self, value: Convert<String>) {
-        let _ = url::quirks::set_password(&mut self.0, &value.0);
+        let _ = url::quirks::set_password(&mut self.inner, &value.0);
     }
 
     #[boa(getter)]
     fn pathname(&self) -> JsString {
-        JsString::from(url::quirks::pathname(&self.0))
+        JsString::from(url::quirks::pathname(&self.inner))
     }
 
     #[boa(setter)]
     #[boa(rename = "pathname")]
     fn set_pathname(&mut self, value: Convert<String>) {
-        let () = url::quirks::set_pathname(&mut self.0, &value.0);
+        let () = url::quirks::set_pathname(&mut self.inner, &value.0);
     }
 
     #[boa(getter)]
     fn port(&self) -> JsString {
-        JsString::from(url::quirks::port(&self.0))
+        JsString::from(url::quirks::port(&self.inner))
     }
 
     #[boa(setter)]
     #[boa(rename = "port")]
     fn set_port(&mut self, value: Convert<JsString>) {
-        let _ = url::quirks::set_port(&mut self.0, &value.0.to_std_string_lossy());
+        let _ = url::quirks::set_port(&mut self.inner, &value.0.to_std_string_lossy());
     }
 
     #[boa(getter)]
     fn protocol(&self) -> JsString {
-        JsString::from(url::quirks::protocol(&self.0))
+        JsString::from(url::quirks::protocol(&self.inner))
     }
 
     #[boa(setter)]
     #[boa(rename = "protocol")]
     fn set_protocol(&mut self, value: Convert<String>) {
-        let _ = url::quirks::set_protocol(&mut self.0, &value.0);
+        let _ = url::quirks::set_protocol(&mut self.inner, &value.0);
     }
 
     #[boa(getter)]
     fn search(&self) -> JsString {
-        JsString::from(url::quirks::search(&self.0))
+        JsString::from(url::quirks::search(&self.inner))
     }
 
     #[boa(setter)]
     #[boa(rename = "search")]
     fn set_search(&mut self, value: Convert<String>) {
-        url::quirks::set_search(&mut self.0, &value.0);
+        url::quirks::set_search(&mut self.inner, &value.0);
+        self.update_search_params();
     }
 
     #[boa(getter)]
-    fn search_params() -> JsResult<()> {
-        Err(js_error!(Error: "URL.searchParams is not implemented"))
+    fn search_params(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<JsObject> {
+        let url = this
+            .downcast::<Self>()
+            .map_err(|_| js_error!(TypeError: "Invalid class for type"))?;
+        if let Some(search_params) = &url.borrow().data().search_params {
+            return Ok(search_params.clone().upcast());
+        }
+
+        let search_params = UrlSearchParams::linked_to(url.clone(), context)?;
+        url.borrow_mut().data_mut().search_params = Some(search_params.clone());
+        Ok(search_params.upcast())
     }
 
     #[boa(getter)]
     fn username(&self) -> JsString {
-        JsString::from(self.0.username())
+        JsString::from(self.inner.username())
     }
 
     #[boa(setter)]
     #[boa(rename = "username")]
     fn set_username(&mut self, value: Convert<String>) {
-        let _ = self.0.set_username(&value.0);
+        let _ = self.inner.set_username(&value.0);
     }
 
     fn to_string(&self) -> JsString {
-        JsString::from(format!("{}", self.0))
+        JsString::from(format!("{}", self.inner))
     }
 
     fn to_json(&self) -> JsString {
-        JsString::from(format!("{}", self.0))
+        JsString::from(format!("{}", self.inner))
     }
 
     #[boa(static)]
@@ -0,0 +1,457 @@
+//! Boa's implementation of JavaScript's `URLSearchParams` Web API class.
+//!
+//! A `URLSearchParams` object is either standalone, or the `searchParams` object
+//! of a `URL`. In the latter case, any change to one of them is reflected in the other.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG `URLSearchParams` specification][spec]
+//!
+//! [spec]: https://url.spec.whatwg.org/#interface-urlsearchparams
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams
+
+use super::Url;
+use crate::iterable_to_vec;
+use boa_engine::builtins::iterable::create_iter_result_object;
+use boa_engine::interop::JsThis;
+use boa_engine::object::builtins::JsArray;
+use boa_engine::object::ObjectInitializer;
+use boa_engine::property::{Attribute, PropertyDescriptor};
+use boa_engine::value::Convert;
+use boa_engine::{
+    js_error, js_string, Context, Finalize, JsData, JsObject, JsResult, JsString, JsSymbol,
+    JsValue, NativeFunction, Trace,
+};
+use boa_interop::boa_macros::boa_class;
+use url::form_urlencoded;
+
+/// The `URLSearchParams` class represents the query string of a URL as a list of
+/// name-value pairs.
+#[derive(Debug, Clone, JsData, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
+pub struct UrlSearchParams {
+    #[unsafe_ignore_trace]
+    list: Vec<(String, String)>,
+
+    /// The `URL` object whose query is represented by this object, if any.
+    url: Option<JsObject<Url>>,
+}
+
+impl UrlSearchParams {
+    /// Register the `URLSearchParams` class into the realm.
+    ///
+    /// # Errors
+    /// This will error if the context or realm cannot register the class.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        context.register_global_class::<Self>()?;
+
+        // `boa_class` cannot define symbol-keyed properties, so add them to the
+        // prototype manually. `@@iterator` is the same function object as `entries`.
+        let prototype = Self::prototype(context)?;
+        let entries = prototype.get(js_string!("entries"), context)?;
+        prototype.define_property_or_throw(
+            JsSymbol::iterator(),
+            PropertyDescriptor::builder()
+                .value(entries)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+        prototype.define_property_or_throw(
+            JsSymbol::to_string_tag(),
+            PropertyDescriptor::builder()
+                .value(js_string!("URLSearchParams"))
+                .writable(false)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+
+        let iterator_prototype = UrlSearchParamsIterator::create_prototype(context);
+        context.insert_data(UrlSearchParamsIteratorPrototype(iterator_prototype));
+
+        Ok(())
+    }
+
+    /// Get the prototype of the registered `URLSearchParams` class.
+    fn prototype(context: &Context) -> JsResult<JsObject> {
+        context
+            .get_global_class::<Self>()
+            .map(|class| class.prototype())
+            .ok_or_else(|| js_error!(TypeError: "The URLSearchParams class is not registered"))
+    }
+
+    /// Create the `URLSearchParams` object of `url`, initialized from its query.
+    pub(crate) fn linked_to(url: JsObject<Url>, context: &mut Context) -> JsResult<JsObject<Self>> {
+        let list = parse(url.borrow().data().inner.query());
+        Ok(JsObject::new_unique(
+            Self::prototype(context)?,
+            Self {
+                list,
+                url: Some(url),
+            },
+        ))
+    }
+
+    /// Replace the list with the pairs of `query`, after the query of the linked `URL`
+    /// changed.
+    pub(crate) fn set_list_from_query(&mut self, query: Option<&str>) {
+        self.list = parse(query);
+    }
+
+    /// Set the query of the linked `URL`, if any, after the list changed.
+    ///
+    /// See the [update steps](https://url.spec.whatwg.org/#concept-urlsearchparams-update).
+    fn update(&self) {
+        let Some(url) = &self.url else {
+            return;
+        };
+
+        let query = self.serialize();
+        url.borrow_mut()
+            .data_mut()
+            .inner
+            .set_query((!query.is_empty()).then_some(&query));
+    }
+
+    /// Serialize the list using the `application/x-www-form-urlencoded` format.
+    pub(crate) fn serialize(&self) -> String {
+        form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(&self.list)
+            .finish()
+    }
+
+    /// Create an iterator of `kind` over the list of `this`.
+    fn iterator(this: JsObject, kind: IterationKind, context: &mut Context) -> JsResult<JsObject> {
+        let search_params = this
+            .downcast::<Self>()
+            .map_err(|_| js_error!(TypeError: "Invalid class for type"))?;
+        let prototype = context
+            .get_data::<UrlSearchParamsIteratorPrototype>()
+            .map(|prototype| prototype.0.clone());
+
+        Ok(JsObject::new_unique(
+            prototype,
+            UrlSearchParamsIterator {
+                search_params,
+                kind,
+                index: 0,
+            },
+        )
+        .upcast())
+    }
+}
+
+#[boa_class(rename = "URLSearchParams")]
+#[boa(rename_all = "camelCase")]
+impl UrlSearchParams {
+    /// Create a new `URLSearchParams` object. Meant to be called from the JavaScript
+    /// constructor.
+    ///
+    /// `init` can be a query string, an iterable of name-value pairs, or a record of
+    /// names to values.
+    ///
+    /// # Errors
+    /// Any errors that might occur while converting `init`.
+    #[boa(constructor)]
+    #[boa(length = 0)]
+    pub fn new(init: JsValue, context: &mut Context) -> JsResult<Self> {
+        let list = if init.is_undefined() {
+            Vec::new()
+        } else if let Some(object) = init.as_object() {
+            let method = object.get(JsSymbol::iterator(), context)?;
+            if method.is_null_or_undefined() {
+                record_to_list(object, context)?
+            } else {
+                let method = method
+                    .as_callable()
+                    .ok_or_else(|| js_error!(TypeError: "@@iterator is not callable"))?;
+                sequence_to_list(&init, method, context)?
+            }
+        } else {
+            let init = to_usv_string(&init, context)?;
+            parse(Some(init.strip_prefix('?').unwrap_or(&init)))
+        };
+
+        Ok(Self { list, url: None })
+    }
+
+    #[boa(getter)]
+    fn size(&self) -> usize {
+        self.list.len()
+    }
+
+    fn append(&mut self, name: Convert<JsString>, value: Convert<JsString>) {
+        self.list
+            .push((name.0.to_std_string_lossy(), value.0.to_std_string_lossy()));
+        self.update();
+    }
+
+    #[boa(length = 1)]
+    fn delete(&mut self, name: Convert<JsString>, value: Option<Convert<JsString>>) {
+        let name = name.0.to_std_string_lossy();
+        let value = value.map(|value| value.0.to_std_string_lossy());
+        self.list
+            .retain(|(n, v)| n != &name || value.as_ref().is_some_and(|value| v != value));
+        self.update();
+    }
+
+    fn get(&self, name: Convert<JsString>) -> JsValue {
+        let name = name.0.to_std_string_lossy();
+        self.list
+            .iter()
+            .find(|(n, _)| n == &name)
+            .map_or_else(JsValue::null, |(_, v)| JsString::from(v.as_str()).into())
+    }
+
+    fn get_all(&self, name: Convert<JsString>) -> Vec<JsString> {
+        let name = name.0.to_std_string_lossy();
+        self.list
+            .iter()
+            .filter(|(n, _)| n == &name)
+            .map(|(_, v)| JsString::from(v.as_str()))
+            .collect()
+    }
+
+    #[boa(length = 1)]
+    fn has(&self, name: Convert<JsString>, value: Option<Convert<JsString>>) -> bool {
+        let name = name.0.to_std_string_lossy();
+        let value = value.map(|value| value.0.to_std_string_lossy());
+        self.list
+            .iter()
+            .any(|(n, v)| n == &name && value.as_ref().is_none_or(|value| v == value))
+    }
+
+    fn set(&mut self, name: Convert<JsString>, value: Convert<JsString>) {
+        let name = name.0.to_std_string_lossy();
+        // Set the value of the first pair with this name, and remove the others.
+        let mut value = Some(value.0.to_std_string_lossy());
+        self.list.retain_mut(|(n, v)| {
+            if n != &name {
+                return true;
+            }
+            value.take().map(|value| *v = value).is_some()
+        });
+        if let Some(value) = value {
+            self.list.push((name, value));
+        }
+        self.update();
+    }
+
+    fn sort(&mut self) {
+        // Names are compared by their UTF-16 code units, and the sort must be stable.
+        self.list
+            .sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+        self.update();
+    }
+
+    #[boa(length = 0)]
+    fn entries(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<JsObject> {
+        Self::iterator(this, IterationKind::KeyAndValue, context)
+    }
+
+    #[boa(length = 0)]
+    fn keys(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<JsObject> {
+        Self::iterator(this, IterationKind::Key, context)
+    }
+
+    #[boa(length = 0)]
+    fn values(JsThis(this): JsThis<JsObject>, context: &mut Context) -> JsResult<JsObject> {
+        Self::iterator(this, IterationKind::Value, context)
+    }
+
+    #[boa(length = 1)]
+    fn for_each(
+        JsThis(this): JsThis<JsObject>,
+        callback: JsValue,
+        this_arg: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let search_params = this
+            .clone()
+            .downcast::<Self>()
+            .map_err(|_| js_error!(TypeError: "Invalid class for type"))?;
+        let callback = callback.as_callable().ok_or_else(
+            || js_error!(TypeError: "URLSearchParams.forEach: callback is not callable"),
+        )?;
+
+        // The callback may modify the list, so look it up again on every step.
+        let mut index = 0;
+        loop {
+            let Some((name, value)) = search_params.borrow().data().list.get(index).cloned() else {
+                return Ok(());
+            };
+            callback.call(
+                &this_arg,
+                &[
+                    JsString::from(value).into(),
+                    JsString::from(name).into(),
+                    this.clone().into(),
+                ],
+                context,
+            )?;
+            index += 1;
+        }
+    }
+
+    fn to_string(&self) -> JsString {
+        JsString::from(self.serialize())
+    }
+}
+
+/// The kind of values produced by a `URLSearchParams` iterator.
+#[derive(Debug, Clone, Copy)]
+enum IterationKind {
+    Key,
+    Value,
+    KeyAndValue,
+}
+
+/// The `%URLSearchParamsIteratorPrototype%` object, kept in the context data.
+#[derive(Debug, Trace, Finalize, JsData)]
+struct UrlSearchParamsIteratorPrototype(JsObject);
+
+/// An iterator over the list of a `URLSearchParams` object.
+///
+/// Like the other iterators of JavaScript, it sees changes made to the list during
+/// the iteration.
+#[derive(Debug, Trace, Finalize, JsData)]
+struct UrlSearchParamsIterator {
+    search_params: JsObject<UrlSearchParams>,
+    #[unsafe_ignore_trace]
+    kind: IterationKind,
+    index: usize,
+}
+
+impl UrlSearchParamsIterator {
+    /// Create the prototype shared by all `URLSearchParams` iterators.
+    fn create_prototype(context: &mut Context) -> JsObject {
+        let iterator_prototype = context
+            .intrinsics()
+            .objects()
+            .iterator_prototypes()
+            .iterator();
+
+        let prototype = ObjectInitializer::new(context)
+            .function(
+                NativeFunction::from_fn_ptr(Self::next),
+                js_string!("next"),
+                0,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("URLSearchParams Iterator"),
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+        prototype.set_prototype(Some(iterator_prototype));
+        prototype
+    }
+
+    /// `%URLSearchParamsIteratorPrototype%.next( )`
+    fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let object = this.as_object();
+        let mut iterator = object
+            .as_ref()
+            .and_then(|o| o.downcast_mut::<Self>())
+            .ok_or_else(|| js_error!(TypeError: "`this` is not a URLSearchParams iterator"))?;
+
+        let Some((name, value)) = iterator
+            .search_params
+            .borrow()
+            .data()
+            .list
+            .get(iterator.index)
+            .cloned()
+        else {
+            return Ok(create_iter_result_object(
+                JsValue::undefined(),
+                true,
+                context,
+            ));
+        };
+        iterator.index += 1;
+        let kind = iterator.kind;
+        drop(iterator);
+
+        let result = match kind {
+            IterationKind::Key => JsString::from(name).into(),
+            IterationKind::Value => JsString::from(value).into(),
+            IterationKind::KeyAndValue => JsArray::from_iter(
+                [JsString::from(name).into(), JsString::from(value).into()],
+                context,
+            )
+            .into(),
+        };
+        Ok(create_iter_result_object(result, false, context))
+    }
+}
+
+/// Parse `query` using the `application/x-www-form-urlencoded` format.
+fn parse(query: Option<&str>) -> Vec<(String, String)> {
+    query.map_or_else(Vec::new, |query| {
+        form_urlencoded::parse(query.as_bytes())
+            .into_owned()
+            .collect()
+    })
+}
+
+/// Convert `value` to a string, replacing lone surrogates by U+FFFD like the
+/// `USVString` `WebIDL` type.
+fn to_usv_string(value: &JsValue, context: &mut Context) -> JsResult<String> {
+    Ok(value.to_string(context)?.to_std_string_lossy())
+}
+
+/// Convert an iterable of name-value pairs to a list.
+fn sequence_to_list(
+    init: &JsValue,
+    method: &JsObject,
+    context: &mut Context,
+) -> JsResult<Vec<(String, String)>> {
+    iterable_to_vec(
+        init,
+        method,
+        |pair, context| {
+            let pair_method = match pair.as_object() {
+                Some(object) => object.get(JsSymbol::iterator(), context)?,
+                None => JsValue::undefined(),
+            };
+            let pair_method = pair_method
+                .as_callable()
+                .ok_or_else(|| js_error!(TypeError: "URLSearchParams init pair is not iterable"))?;
+
+            let pair = iterable_to_vec(&pair, pair_method, |value, _| Ok(value), context)?;
+            let [name, value] = <[JsValue; 2]>::try_from(pair).map_err(
+                |_| js_error!(TypeError: "URLSearchParams init pair must have exactly two items"),
+            )?;
+            Ok((
+                to_usv_string(&name, context)?,
+                to_usv_string(&value, context)?,
+            ))
+        },
+        context,
+    )
+}
+
+/// Convert a record of names to values to a list, in the order of its enumerable
+/// own string keys.
+fn record_to_list(object: &JsObject, context: &mut Context) -> JsResult<Vec<(String, String)>> {
+    let entries = boa_engine::builtins::object::OrdinaryObject::entries(
+        &JsValue::undefined(),
+        &[object.clone().into()],
+        context,
+    )?;
+    let entries: Vec<Vec<JsValue>> = entries.try_js_into(context)?;
+
+    entries
+        .into_iter()
+        .map(|entry| match entry.as_slice() {
+            [name, value] => Ok((
+                to_usv_string(name, context)?,
+                to_usv_string(value, context)?,
+            )),
+            _ => Err(js_error!(TypeError: "invalid URLSearchParams record entry")),
+        })
+        .collect()
+}
@@ -111,3 +111,145 @@ fn url_static_methods() {
         ),
     ]);
 }
+
+#[test]
+fn url_search_params() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r##"
+                params = new URLSearchParams("?a=1&b=2&a=3");
+                assert_eq(params.size, 3);
+                assert_eq(params.get("a"), "1");
+                assert_eq(params.get("c"), null);
+                assert_eq(params.getAll("a").join(), "1,3");
+                assert(params.has("b"));
+                assert(params.has("a", "3"));
+                assert(!params.has("a", "2"));
+
+                params.append("c", "a b&");
+                assert_eq(params.toString(), "a=1&b=2&a=3&c=a+b%26");
+
+                params.set("a", "4");
+                assert_eq(params.toString(), "a=4&b=2&c=a+b%26");
+
+                params.delete("c");
+                params.append("b", "5");
+                params.delete("b", "2");
+                assert_eq(params.toString(), "a=4&b=5");
+
+                params = new URLSearchParams("z=1&a=2&z=0&\u{1F600}=3&\u{FFFD}=4");
+                params.sort();
+                assert_eq(params.toString(), "a=2&z=1&z=0&%F0%9F%98%80=3&%EF%BF%BD=4");
+                assert_eq(Object.prototype.toString.call(params), "[object URLSearchParams]");
+            "##,
+        ),
+    ]);
+}
+
+#[test]
+fn url_search_params_init() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r##"
+                assert_eq(new URLSearchParams().toString(), "");
+                assert_eq(new URLSearchParams([["a", "1"], ["b", 2]]).toString(), "a=1&b=2");
+                assert_eq(new URLSearchParams({ a: "1", b: 2 }).toString(), "a=1&b=2");
+                assert_eq(new URLSearchParams(new Map([["a", "1"]])).toString(), "a=1");
+
+                let error;
+                try {
+                    new URLSearchParams([["a"]]);
+                } catch (e) {
+                    error = e;
+                }
+                assert(error instanceof TypeError);
+
+                // The iterator is closed when a pair is rejected.
+                let closed = false;
+                const pairs = {
+                    [Symbol.iterator]() {
+                        return {
+                            next: () => ({ done: false, value: ["a"] }),
+                            return() {
+                                closed = true;
+                                return {};
+                            },
+                        };
+                    },
+                };
+                error = undefined;
+                try {
+                    new URLSearchParams(pairs);
+                } catch (e) {
+                    error = e;
+                }
+                assert(error instanceof TypeError);
+                assert(closed, "iterator was not closed");
+            "##,
+        ),
+    ]);
+}
+
+#[test]
+fn url_search_params_iteration() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r##"
+                params = new URLSearchParams("b=2&a=1");
+                assert_eq(params[Symbol.iterator], params.entries);
+                assert_eq(params.entries.length, 0);
+                assert_eq(params.forEach.length, 1);
+                assert_eq(JSON.stringify([...params]), '[["b","2"],["a","1"]]');
+                assert_eq([...params.keys()].join(), "b,a");
+                assert_eq([...params.values()].join(), "2,1");
+
+                const iterator = params.keys();
+                assert_eq(Object.prototype.toString.call(iterator), "[object URLSearchParams Iterator]");
+                assert_eq(typeof iterator[Symbol.iterator], "function");
+                assert_eq(iterator.next().value, "b");
+                params.append("c", "3");
+                assert_eq(iterator.next().value, "a");
+                assert_eq(iterator.next().value, "c");
+                assert(iterator.next().done);
+
+                const seen = [];
+                params.forEach(function (value, name, object) {
+                    assert_eq(object, params);
+                    assert_eq(this.tag, "this");
+                    seen.push(`${name}=${value}`);
+                }, { tag: "this" });
+                assert_eq(seen.join("&"), "b=2&a=1&c=3");
+            "##,
+        ),
+    ]);
+}
+
+#[test]
+fn url_search_params_linked() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r##"
+                url = new URL("https://example.com/path?a=1#hash");
+                params = url.searchParams;
+                assert_eq(url.searchParams, params);
+                assert_eq(params.get("a"), "1");
+
+                params.append("b", "x y");
+                assert_eq(url.href, "https://example.com/path?a=1&b=x+y#hash");
+                params.delete("a");
+                params.delete("b");
+                assert_eq(url.href, "https://example.com/path#hash");
+
+                url.search = "?c=3";
+                assert_eq(params.get("c"), "3");
+                url.href = "https://example.org/?d=4";
+                assert_eq(params.toString(), "d=4");
+                assert(!params.has("c"));
+            "##,
+        ),
+    ]);
+}
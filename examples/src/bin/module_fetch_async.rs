@@ -2,10 +2,10 @@ use std::{cell::RefCell, collections::VecDeque, future::Future, pin::Pin, rc::Rc
 
 use boa_engine::{
     builtins::promise::PromiseState,
-    job::{Job, JobExecutor, NativeAsyncJob, PromiseJob},
+    job::{GenericJob, Job, JobExecutor, NativeAsyncJob, PromiseJob},
     js_string,
-    module::ModuleLoader,
-    Context, JsNativeError, JsResult, JsString, JsValue, Module,
+    module::{ModuleLoader, ModuleRequest},
+    Context, JsNativeError, JsResult, JsValue, Module,
 };
 use boa_parser::Source;
 use futures_concurrency::future::FutureGroup;
@@ -22,11 +22,11 @@ impl ModuleLoader for HttpModuleLoader {
     fn load_imported_module(
         &self,
         _referrer: boa_engine::module::Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
-        let url = specifier.to_std_string_escaped();
+        let url = request.specifier().to_std_string_escaped();
 
         // Just enqueue the future for now. We'll advance all the enqueued futures inside our custom
         // `JobExecutor`.
@@ -174,6 +174,7 @@ fn main() -> JsResult<()> {
 struct Queue {
     async_jobs: RefCell<VecDeque<NativeAsyncJob>>,
     promise_jobs: RefCell<VecDeque<PromiseJob>>,
+    generic_jobs: RefCell<VecDeque<GenericJob>>,
 }
 
 impl Queue {
@@ -181,6 +182,7 @@ impl Queue {
         Self {
             async_jobs: RefCell::default(),
             promise_jobs: RefCell::default(),
+            generic_jobs: RefCell::default(),
         }
     }
 
@@ -191,6 +193,14 @@ impl Queue {
                 eprintln!("Uncaught {e}");
             }
         }
+
+        // Generic jobs only run after all promise jobs have been drained.
+        let jobs = std::mem::take(&mut *self.generic_jobs.borrow_mut());
+        for job in jobs {
+            if let Err(e) = job.call(context) {
+                eprintln!("Uncaught {e}");
+            }
+        }
     }
 }
 
@@ -199,6 +209,7 @@ impl JobExecutor for Queue {
         match job {
             Job::PromiseJob(job) => self.promise_jobs.borrow_mut().push_back(job),
             Job::AsyncJob(job) => self.async_jobs.borrow_mut().push_back(job),
+            Job::GenericJob(job) => self.generic_jobs.borrow_mut().push_back(job),
             _ => panic!("unsupported job type"),
         }
     }
@@ -219,7 +230,10 @@ impl JobExecutor for Queue {
     {
         Box::pin(async move {
             // Early return in case there were no jobs scheduled.
-            if self.promise_jobs.borrow().is_empty() && self.async_jobs.borrow().is_empty() {
+            if self.promise_jobs.borrow().is_empty()
+                && self.async_jobs.borrow().is_empty()
+                && self.generic_jobs.borrow().is_empty()
+            {
                 return Ok(());
             }
             let mut group = FutureGroup::new();
@@ -228,9 +242,9 @@ impl JobExecutor for Queue {
                     group.insert(job.call(context));
                 }
 
-                if self.promise_jobs.borrow().is_empty() {
+                if self.promise_jobs.borrow().is_empty() && self.generic_jobs.borrow().is_empty() {
                     let Some(result) = group.next().await else {
-                        // Both queues are empty. We can exit.
+                        // All the queues are empty. We can exit.
                         return Ok(());
                     };
 
@@ -2,7 +2,7 @@ use boa_engine::context::time::{JsDuration, JsInstant};
 use boa_engine::job::TimeoutJob;
 use boa_engine::{
     context::ContextBuilder,
-    job::{Job, JobExecutor, NativeAsyncJob, PromiseJob},
+    job::{GenericJob, Job, JobExecutor, NativeAsyncJob, PromiseJob},
     js_string,
     native_function::NativeFunction,
     property::Attribute,
@@ -39,6 +39,7 @@ fn main() -> JsResult<()> {
 struct Queue {
     async_jobs: RefCell<VecDeque<NativeAsyncJob>>,
     promise_jobs: RefCell<VecDeque<PromiseJob>>,
+    generic_jobs: RefCell<VecDeque<GenericJob>>,
     timeout_jobs: RefCell<BTreeMap<JsInstant, TimeoutJob>>,
 }
 
@@ -47,6 +48,7 @@ impl Queue {
         Self {
             async_jobs: RefCell::default(),
             promise_jobs: RefCell::default(),
+            generic_jobs: RefCell::default(),
             timeout_jobs: RefCell::default(),
         }
     }
@@ -79,6 +81,14 @@ impl Queue {
                 eprintln!("Uncaught {e}");
             }
         }
+
+        // Generic jobs only run after all promise jobs have been drained.
+        let jobs = std::mem::take(&mut *self.generic_jobs.borrow_mut());
+        for job in jobs {
+            if let Err(e) = job.call(context) {
+                eprintln!("Uncaught {e}");
+            }
+        }
     }
 }
 
@@ -91,6 +101,7 @@ impl JobExecutor for Queue {
                 let now = context.clock().now();
                 self.timeout_jobs.borrow_mut().insert(now + t.timeout(), t);
             }
+            Job::GenericJob(job) => self.generic_jobs.borrow_mut().push_back(job),
             _ => panic!("unsupported job type"),
         }
     }
@@ -111,7 +122,10 @@ impl JobExecutor for Queue {
     {
         Box::pin(async move {
             // Early return in case there were no jobs scheduled.
-            if self.promise_jobs.borrow().is_empty() && self.async_jobs.borrow().is_empty() {
+            if self.promise_jobs.borrow().is_empty()
+                && self.async_jobs.borrow().is_empty()
+                && self.generic_jobs.borrow().is_empty()
+            {
                 return Ok(());
             }
             let mut group = FutureGroup::new();
@@ -120,9 +134,9 @@ impl JobExecutor for Queue {
                     group.insert(job.call(context));
                 }
 
-                if self.promise_jobs.borrow().is_empty() {
+                if self.promise_jobs.borrow().is_empty() && self.generic_jobs.borrow().is_empty() {
                     let Some(result) = group.next().await else {
-                        // Both queues are empty. We can exit.
+                        // All the queues are empty. We can exit.
                         return Ok(());
                     };
 
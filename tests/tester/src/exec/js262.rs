@@ -74,7 +74,8 @@ pub(super) fn register_js262(handles: WorkerHandles, context: &mut Context) -> J
 
     let agent = agent_obj(handles, context);
 
-    let js262 = ObjectInitializer::new(context)
+    let mut js262 = ObjectInitializer::new(context);
+    js262
         .function(
             NativeFunction::from_fn_ptr(create_realm),
             js_string!("createRealm"),
@@ -100,8 +101,20 @@ pub(super) fn register_js262(handles: WorkerHandles, context: &mut Context) -> J
             js_string!("agent"),
             agent,
             Attribute::WRITABLE | Attribute::CONFIGURABLE,
-        )
-        .build();
+        );
+
+    #[cfg(feature = "annex-b")]
+    {
+        let is_html_dda =
+            JsObject::new_html_dda(NativeFunction::from_fn_ptr(is_html_dda), js262.context());
+        js262.property(
+            js_string!("IsHTMLDDA"),
+            is_html_dda,
+            Attribute::WRITABLE | Attribute::CONFIGURABLE,
+        );
+    }
+
+    let js262 = js262.build();
 
     context
         .register_global_property(
@@ -127,6 +140,15 @@ fn create_realm(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue
     Ok(JsValue::new(js262))
 }
 
+/// The `$262.IsHTMLDDA()` function.
+///
+/// Returns `null`, as required when it is called with no arguments or with an empty string.
+#[cfg(feature = "annex-b")]
+#[allow(clippy::unnecessary_wraps)]
+fn is_html_dda(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    Ok(JsValue::null())
+}
+
 /// The `$262.detachArrayBuffer()` function.
 ///
 /// Implements the `DetachArrayBuffer` abstract operation.
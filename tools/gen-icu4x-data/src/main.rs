@@ -14,15 +14,18 @@ const EXPORT_PATH: &str = "core/icu_provider/data";
 ///
 /// This must be kept in sync with the list of implemented services for `Intl`.
 const SERVICES: &[(&str, &[DataMarkerInfo])] = &[
+    ("icu_calendar", icu_calendar::provider::MARKERS),
     ("icu_casemap", icu_casemap::provider::MARKERS),
     ("icu_collator", icu_collator::provider::MARKERS),
     ("icu_datetime", icu_datetime::provider::MARKERS),
     ("icu_decimal", icu_decimal::provider::MARKERS),
+    ("icu_experimental", icu_experimental::provider::MARKERS),
     ("icu_list", icu_list::provider::MARKERS),
     ("icu_locale", icu_locale::provider::MARKERS),
     ("icu_normalizer", icu_normalizer::provider::MARKERS),
     ("icu_plurals", icu_plurals::provider::MARKERS),
     ("icu_segmenter", icu_segmenter::provider::MARKERS),
+    ("icu_time", icu_time::provider::MARKERS),
 ];
 
 fn export_for_service(